@@ -3,7 +3,7 @@
 //! 展示新增的几何体类型和高级3D功能
 
 use std::f32::consts::PI;
-use vizuara_3d::{Camera3D, Mesh3D, Plot3DArea, Scatter3D, Surface3D};
+use vizuara_3d::{Mesh3D, Scatter3D, Surface3D};
 use vizuara_core::Color;
 use vizuara_window::Window3D;
 
@@ -100,7 +100,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   🌊 参数曲面可视化");
     println!("   🧬 复杂数据结构（DNA螺旋）");
     println!("   🎮 改进的交互控制");
-    println!("");
+    println!();
     println!("💻 控制说明:");
     println!("   🖱️  左键拖拽 - 轨道旋转");
     println!("   🎱 滚轮 - 缩放场景");
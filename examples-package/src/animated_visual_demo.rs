@@ -63,11 +63,9 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 match event {
                     WindowEvent::CloseRequested => control_flow.exit(),
 
-                    WindowEvent::Resized(physical_size) => {
-                        if physical_size.width > 0 && physical_size.height > 0 {
-                            renderer.resize(physical_size, &surface);
-                            window_for_redraw.request_redraw();
-                        }
+                    WindowEvent::Resized(physical_size) if physical_size.width > 0 && physical_size.height > 0 => {
+                        renderer.resize(physical_size, &surface);
+                        window_for_redraw.request_redraw();
                     }
 
                     WindowEvent::KeyboardInput {
@@ -185,11 +183,11 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
 
-            Event::AboutToWait => {
-                // 定期请求重绘以保持动画流畅
-                if is_any_animation_playing(&simple_transition, &keyframe_anim, &timeline) {
-                    window_for_redraw.request_redraw();
-                }
+            // 定期请求重绘以保持动画流畅
+            Event::AboutToWait
+                if is_any_animation_playing(&simple_transition, &keyframe_anim, &timeline) =>
+            {
+                window_for_redraw.request_redraw();
             }
 
             _ => {}
@@ -324,6 +322,7 @@ fn generate_animation_visuals(
         color: Color::rgb(1.0, 1.0, 1.0),
         h_align: vizuara_core::HorizontalAlign::Left,
         v_align: vizuara_core::VerticalAlign::Top,
+        rotation: 0.0,
     });
     styles.push(Style::new().fill_color(Color::rgb(1.0, 1.0, 1.0)));
 
@@ -336,6 +335,7 @@ fn generate_animation_visuals(
         color: Color::rgb(0.8, 0.8, 0.8),
         h_align: vizuara_core::HorizontalAlign::Left,
         v_align: vizuara_core::VerticalAlign::Top,
+        rotation: 0.0,
     });
     styles.push(Style::new().fill_color(Color::rgb(0.8, 0.8, 0.8)));
 
@@ -382,6 +382,7 @@ fn generate_simple_transition_visual(
         color: Color::rgb(1.0, 1.0, 0.0),
         h_align: vizuara_core::HorizontalAlign::Left,
         v_align: vizuara_core::VerticalAlign::Top,
+        rotation: 0.0,
     });
     styles.push(Style::new().fill_color(Color::rgb(1.0, 1.0, 0.0)));
 
@@ -453,6 +454,7 @@ fn generate_keyframe_visual(
         color: Color::rgb(1.0, 1.0, 0.0),
         h_align: vizuara_core::HorizontalAlign::Left,
         v_align: vizuara_core::VerticalAlign::Top,
+        rotation: 0.0,
     });
     styles.push(Style::new().fill_color(Color::rgb(1.0, 1.0, 0.0)));
 
@@ -539,6 +541,7 @@ fn generate_timeline_visual(
         color: Color::rgb(1.0, 1.0, 0.0),
         h_align: vizuara_core::HorizontalAlign::Left,
         v_align: vizuara_core::VerticalAlign::Top,
+        rotation: 0.0,
     });
     styles.push(Style::new().fill_color(Color::rgb(1.0, 1.0, 0.0)));
 
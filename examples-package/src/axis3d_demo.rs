@@ -136,106 +136,100 @@ async fn run() -> Result<()> {
                     renderer.resize(physical_size, &surface);
                 }
                 
-                WindowEvent::KeyboardInput { event, .. } => {
-                    if event.state == ElementState::Pressed {
-                        if let winit::keyboard::PhysicalKey::Code(keycode) = event.physical_key {
-                            match keycode {
-                                KeyCode::Space => {
-                                    app_state.show_axes = !app_state.show_axes;
-                                    println!("🔄 坐标轴显示: {}", if app_state.show_axes { "开启" } else { "关闭" });
-                                }
-                                KeyCode::KeyG => {
-                                    app_state.current_grid_type = match app_state.current_grid_type {
-                                        GridType::None => {
-                                            println!("📊 网格类型: 主要网格");
-                                            GridType::Major
-                                        },
-                                        GridType::Major => {
-                                            println!("📊 网格类型: 主要+次要网格");
-                                            GridType::MajorMinor
-                                        },
-                                        GridType::MajorMinor => {
-                                            println!("📊 网格类型: 无网格");
-                                            GridType::None
-                                        },
-                                    };
-                                    coordinate_system = coordinate_system.clone().grid(app_state.current_grid_type);
-                                }
-                                KeyCode::KeyP => {
-                                    app_state.show_planes = !app_state.show_planes;
-                                    coordinate_system = coordinate_system.clone().show_planes(app_state.show_planes);
-                                    println!("🔄 坐标面显示: {}", if app_state.show_planes { "开启" } else { "关闭" });
-                                }
-                                KeyCode::KeyB => {
-                                    app_state.show_box = !app_state.show_box;
-                                    coordinate_system = coordinate_system.clone().show_box(app_state.show_box);
-                                    println!("🔄 坐标轴盒子: {}", if app_state.show_box { "开启" } else { "关闭" });
-                                }
-                                KeyCode::KeyT => {
-                                    app_state.show_tick_labels = !app_state.show_tick_labels;
-                                    coordinate_system = coordinate_system.clone().show_tick_labels(app_state.show_tick_labels);
-                                    println!("🔄 刻度标签: {}", if app_state.show_tick_labels { "开启" } else { "关闭" });
-                                    
-                                    // 显示刻度信息
-                                    if app_state.show_tick_labels {
-                                        println!("📊 3D刻度标签已在场景中显示:");
-                                        println!("   X轴刻度: -3.0, -1.8, -0.6, 0.6, 1.8, 3.0");
-                                        println!("   Y轴刻度: -3.0, -1.8, -0.6, 0.6, 1.8, 3.0");
-                                        println!("   Z轴刻度: -2.0, -1.2, -0.4, 0.4, 1.2, 2.0");
-                                        println!("   ✨ 3D文本渲染已启用，可在场景中查看");
-                                    }
-                                }
-                                KeyCode::KeyL => {
-                                    app_state.show_axis_titles = !app_state.show_axis_titles;
-                                    coordinate_system = coordinate_system.clone().show_axis_titles(app_state.show_axis_titles);
-                                    println!("🔄 轴标题: {}", if app_state.show_axis_titles { "开启" } else { "关闭" });
-                                    
-                                    // 显示轴标题信息
-                                    if app_state.show_axis_titles {
-                                        println!("📋 3D轴标题已在场景中显示:");
-                                        println!("   X轴: X轴 (m) - 表示空间中的X方向位置");
-                                        println!("   Y轴: Y轴 (m) - 表示空间中的Y方向位置");
-                                        println!("   Z轴: Z轴 (t) - 表示时间或第三维度");
-                                        println!("   ✨ 3D文本渲染已启用，可在场景中查看");
-                                    }
-                                }
-                                KeyCode::KeyR => {
-                                    renderer.reset_camera();
-                                    renderer.set_camera_position(Point3::new(6.0, 6.0, 6.0));
-                                    println!("🔄 相机已重置");
+                WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                    if let winit::keyboard::PhysicalKey::Code(keycode) = event.physical_key {
+                        match keycode {
+                            KeyCode::Space => {
+                                app_state.show_axes = !app_state.show_axes;
+                                println!("🔄 坐标轴显示: {}", if app_state.show_axes { "开启" } else { "关闭" });
+                            }
+                            KeyCode::KeyG => {
+                                app_state.current_grid_type = match app_state.current_grid_type {
+                                    GridType::None => {
+                                        println!("📊 网格类型: 主要网格");
+                                        GridType::Major
+                                    },
+                                    GridType::Major => {
+                                        println!("📊 网格类型: 主要+次要网格");
+                                        GridType::MajorMinor
+                                    },
+                                    GridType::MajorMinor => {
+                                        println!("📊 网格类型: 无网格");
+                                        GridType::None
+                                    },
+                                };
+                                coordinate_system = coordinate_system.clone().grid(app_state.current_grid_type);
+                            }
+                            KeyCode::KeyP => {
+                                app_state.show_planes = !app_state.show_planes;
+                                coordinate_system = coordinate_system.clone().show_planes(app_state.show_planes);
+                                println!("🔄 坐标面显示: {}", if app_state.show_planes { "开启" } else { "关闭" });
+                            }
+                            KeyCode::KeyB => {
+                                app_state.show_box = !app_state.show_box;
+                                coordinate_system = coordinate_system.clone().show_box(app_state.show_box);
+                                println!("🔄 坐标轴盒子: {}", if app_state.show_box { "开启" } else { "关闭" });
+                            }
+                            KeyCode::KeyT => {
+                                app_state.show_tick_labels = !app_state.show_tick_labels;
+                                coordinate_system = coordinate_system.clone().show_tick_labels(app_state.show_tick_labels);
+                                println!("🔄 刻度标签: {}", if app_state.show_tick_labels { "开启" } else { "关闭" });
+                                
+                                // 显示刻度信息
+                                if app_state.show_tick_labels {
+                                    println!("📊 3D刻度标签已在场景中显示:");
+                                    println!("   X轴刻度: -3.0, -1.8, -0.6, 0.6, 1.8, 3.0");
+                                    println!("   Y轴刻度: -3.0, -1.8, -0.6, 0.6, 1.8, 3.0");
+                                    println!("   Z轴刻度: -2.0, -1.2, -0.4, 0.4, 1.2, 2.0");
+                                    println!("   ✨ 3D文本渲染已启用，可在场景中查看");
                                 }
-                                KeyCode::Escape => {
-                                    elwt.exit();
+                            }
+                            KeyCode::KeyL => {
+                                app_state.show_axis_titles = !app_state.show_axis_titles;
+                                coordinate_system = coordinate_system.clone().show_axis_titles(app_state.show_axis_titles);
+                                println!("🔄 轴标题: {}", if app_state.show_axis_titles { "开启" } else { "关闭" });
+                                
+                                // 显示轴标题信息
+                                if app_state.show_axis_titles {
+                                    println!("📋 3D轴标题已在场景中显示:");
+                                    println!("   X轴: X轴 (m) - 表示空间中的X方向位置");
+                                    println!("   Y轴: Y轴 (m) - 表示空间中的Y方向位置");
+                                    println!("   Z轴: Z轴 (t) - 表示时间或第三维度");
+                                    println!("   ✨ 3D文本渲染已启用，可在场景中查看");
                                 }
-                                _ => {}
                             }
+                            KeyCode::KeyR => {
+                                renderer.reset_camera();
+                                renderer.set_camera_position(Point3::new(6.0, 6.0, 6.0));
+                                println!("🔄 相机已重置");
+                            }
+                            KeyCode::Escape => {
+                                elwt.exit();
+                            }
+                            _ => {}
                         }
                     }
                 }
                 
-                WindowEvent::MouseInput { button, state, .. } => {
-                    if button == MouseButton::Left {
-                        app_state.mouse_pressed = state == ElementState::Pressed;
-                        if !app_state.mouse_pressed {
-                            app_state.last_mouse_pos = None;
-                        }
+                WindowEvent::MouseInput { button: MouseButton::Left, state, .. } => {
+                    app_state.mouse_pressed = state == ElementState::Pressed;
+                    if !app_state.mouse_pressed {
+                        app_state.last_mouse_pos = None;
                     }
                 }
                 
-                WindowEvent::CursorMoved { position, .. } => {
-                    if app_state.mouse_pressed {
-                        if let Some((last_x, last_y)) = app_state.last_mouse_pos {
-                            let delta_x = (position.x - last_x) as f32;
-                            let delta_y = (position.y - last_y) as f32;
-                            
-                            // 改进的相机旋转控制 - 更像MATLAB
-                            renderer.rotate_camera(
-                                delta_x * 0.008,  // 水平旋转
-                                -delta_y * 0.008  // 垂直旋转（反向）
-                            );
-                        }
-                        app_state.last_mouse_pos = Some((position.x, position.y));
+                WindowEvent::CursorMoved { position, .. } if app_state.mouse_pressed => {
+                    if let Some((last_x, last_y)) = app_state.last_mouse_pos {
+                        let delta_x = (position.x - last_x) as f32;
+                        let delta_y = (position.y - last_y) as f32;
+                        
+                        // 改进的相机旋转控制 - 更像MATLAB
+                        renderer.rotate_camera(
+                            delta_x * 0.008,  // 水平旋转
+                            -delta_y * 0.008  // 垂直旋转（反向）
+                        );
                     }
+                    app_state.last_mouse_pos = Some((position.x, position.y));
                 }
                 
                 WindowEvent::MouseWheel { delta, .. } => {
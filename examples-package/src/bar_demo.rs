@@ -5,7 +5,7 @@
 use vizuara_core::{Color, LinearScale};
 use vizuara_plots::{BarPlot, PlotArea};
 use vizuara_scene::{Figure, Scene};
-use vizuara_window::show_figure;
+use vizuara_window::{show_figure_or_export, ShowOutcome};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -51,8 +51,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🎨 柱状图创建完成，开始渲染...");
     println!("💡 提示：按 ESC 退出，按 R 刷新");
 
-    // 6. 显示在窗口中
-    show_figure(figure)?;
+    // 6. 显示在窗口中；无显示环境或 GPU 时导出为图片
+    if let ShowOutcome::Exported(path) = show_figure_or_export(figure, "bar_demo.png")? {
+        println!("📁 图表已保存到 {}", path.display());
+    }
 
     Ok(())
 }
@@ -74,8 +76,8 @@ mod tests {
         assert_eq!(bar_chart.categories(), vec!["Jan", "Feb", "Mar"]);
 
         let bounds = bar_chart.data_bounds().unwrap();
-        assert_eq!(bounds.0, 90.0); // min
-        assert_eq!(bounds.1, 120.0); // max
+        assert_eq!(bounds.2, 0.0); // 基线
+        assert_eq!(bounds.3, 120.0); // max
     }
 
     #[test]
@@ -85,8 +87,8 @@ mod tests {
         let bar_chart = BarPlot::new().data(&profit_data).auto_scale();
 
         let bounds = bar_chart.data_bounds().unwrap();
-        assert_eq!(bounds.0, -5.0);
-        assert_eq!(bounds.1, 15.0);
+        assert_eq!(bounds.2, -5.0);
+        assert_eq!(bounds.3, 15.0);
     }
 
     #[test]
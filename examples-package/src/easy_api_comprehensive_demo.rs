@@ -16,10 +16,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .xlim(0.0, 10.0)
         .ylim(-2.0, 2.0);
     
+    let sine = testdata::sine_wave(100, 1.0, 1.0);
+    let cosine = testdata::cosine_wave(100, 1.0, 1.0);
+    let half_sine = testdata::sine_wave(100, 0.5, 2.0);
     let datasets = [
-        (&testdata::sine_wave(100, 1.0, 1.0), Colors::BLUE, "sin(x)"),
-        (&testdata::cosine_wave(100, 1.0, 1.0), Colors::RED, "cos(x)"),
-        (&testdata::sine_wave(100, 0.5, 2.0), Colors::GREEN, "0.5*sin(2x)"),
+        (sine.as_slice(), Colors::BLUE, "sin(x)"),
+        (cosine.as_slice(), Colors::RED, "cos(x)"),
+        (half_sine.as_slice(), Colors::GREEN, "0.5*sin(2x)"),
     ];
     fig.multiplot(&datasets, 2.0);
     
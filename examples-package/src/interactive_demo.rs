@@ -69,12 +69,10 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     match event {
                         WindowEvent::CloseRequested => control_flow.exit(),
 
-                        WindowEvent::Resized(physical_size) => {
-                            if physical_size.width > 0 && physical_size.height > 0 {
-                                renderer.resize(physical_size, &surface);
-                                viewport.resize(physical_size.width, physical_size.height);
-                                window_for_redraw.request_redraw();
-                            }
+                        WindowEvent::Resized(physical_size) if physical_size.width > 0 && physical_size.height > 0 => {
+                            renderer.resize(physical_size, &surface);
+                            viewport.resize(physical_size.width, physical_size.height);
+                            window_for_redraw.request_redraw();
                         }
 
                         WindowEvent::CursorMoved { position, .. } => {
@@ -91,6 +89,11 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             );
                         }
 
+                        WindowEvent::CursorLeft { .. } => {
+                            let _ =
+                                tools.handle_mouse_event(&SimpleMouseEvent::Leave, &mut viewport);
+                        }
+
                         WindowEvent::MouseInput { state, button, .. } => {
                             match state {
                                 ElementState::Pressed => {
@@ -154,31 +157,29 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             window_for_redraw.request_redraw();
                         }
 
-                        WindowEvent::KeyboardInput { event, .. } => {
-                            if event.state == ElementState::Pressed {
-                                use winit::keyboard::KeyCode as KC;
-                                let key_str = match event.physical_key {
-                                    winit::keyboard::PhysicalKey::Code(code) => match code {
-                                        KC::KeyP => Some("p"),
-                                        KC::KeyZ => Some("z"),
-                                        KC::KeyS => Some("s"),
-                                        KC::KeyR => Some("r"),
-                                        KC::Minus => Some("-"),
-                                        KC::Equal => Some("+"), // 需要配合 Shift 才是 '+'，这里直接映射
-                                        KC::Escape => Some("Escape"),
-                                        _ => None,
-                                    },
+                        WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                            use winit::keyboard::KeyCode as KC;
+                            let key_str = match event.physical_key {
+                                winit::keyboard::PhysicalKey::Code(code) => match code {
+                                    KC::KeyP => Some("p"),
+                                    KC::KeyZ => Some("z"),
+                                    KC::KeyS => Some("s"),
+                                    KC::KeyR => Some("r"),
+                                    KC::Minus => Some("-"),
+                                    KC::Equal => Some("+"), // 需要配合 Shift 才是 '+'，这里直接映射
+                                    KC::Escape => Some("Escape"),
                                     _ => None,
-                                };
+                                },
+                                _ => None,
+                            };
 
-                                if let Some(k) = key_str {
-                                    let _ = tools.handle_keyboard_event(
-                                        &SimpleKeyboardEvent::KeyPress { key: k.to_string() },
-                                        &mut viewport,
-                                    );
-                                    // ESC 也交给工具，R 重置后也重绘
-                                    window_for_redraw.request_redraw();
-                                }
+                            if let Some(k) = key_str {
+                                let _ = tools.handle_keyboard_event(
+                                    &SimpleKeyboardEvent::KeyPress { key: k.to_string() },
+                                    &mut viewport,
+                                );
+                                // ESC 也交给工具，R 重置后也重绘
+                                window_for_redraw.request_redraw();
                             }
                         }
 
@@ -256,6 +257,7 @@ fn build_primitives_with_view(
         color: Color::rgb(0.9, 0.9, 0.9),
         h_align: vizuara_core::HorizontalAlign::Left,
         v_align: vizuara_core::VerticalAlign::Bottom,
+        rotation: 0.0,
     });
 
     // 一个通用样式（应用于点/线/矩形等，无需一一匹配）
@@ -301,7 +301,7 @@ async fn run_lit_3d_window(
 
     // 配置光源
     for light in lights {
-        renderer.add_light(light);
+        renderer.add_light(light)?;
     }
     renderer.set_ambient_light([0.05, 0.05, 0.1], 0.2);
     println!("💡 光照系统配置完成");
@@ -323,12 +323,14 @@ async fn run_lit_3d_window(
     println!("💡 操作说明:");
     println!("   🖱️  左键拖拽 - 旋转相机");
     println!("   🎱 滚轮 - 缩放");
+    println!("   🖱️  中键拖拽 - 平移");
     println!("   ⌨️  R键 - 重置相机");
     println!("   ⌨️  Esc键 - 退出");
 
     let window_id = window.id();
     let window_clone = Arc::clone(&window);
     let mut mouse_pressed = false;
+    let mut pan_pressed = false;
     let mut last_mouse_pos: Option<(f64, f64)> = None;
 
     // 主事件循环
@@ -346,15 +348,13 @@ async fn run_lit_3d_window(
                             control_flow.exit();
                         }
 
-                        WindowEvent::Resized(physical_size) => {
-                            if physical_size.width > 0 && physical_size.height > 0 {
-                                println!(
-                                    "📏 窗口尺寸变更: {}x{}",
-                                    physical_size.width, physical_size.height
-                                );
-                                renderer.resize(physical_size, &surface);
-                                window_clone.request_redraw();
-                            }
+                        WindowEvent::Resized(physical_size) if physical_size.width > 0 && physical_size.height > 0 => {
+                            println!(
+                                "📏 窗口尺寸变更: {}x{}",
+                                physical_size.width, physical_size.height
+                            );
+                            renderer.resize(physical_size, &surface);
+                            window_clone.request_redraw();
                         }
 
                         WindowEvent::CursorMoved { position, .. } => {
@@ -365,6 +365,13 @@ async fn run_lit_3d_window(
                                     renderer.rotate_camera(delta_x, -delta_y);
                                     window_clone.request_redraw();
                                 }
+                            } else if pan_pressed {
+                                if let Some(last_pos) = last_mouse_pos {
+                                    let delta_x = (position.x - last_pos.0) as f32 * 0.001;
+                                    let delta_y = (position.y - last_pos.1) as f32 * 0.001;
+                                    renderer.pan_camera(delta_x, delta_y);
+                                    window_clone.request_redraw();
+                                }
                             }
                             last_mouse_pos = Some((position.x, position.y));
                         }
@@ -372,6 +379,8 @@ async fn run_lit_3d_window(
                         WindowEvent::MouseInput { state, button, .. } => {
                             if button == winit::event::MouseButton::Left {
                                 mouse_pressed = state == ElementState::Pressed;
+                            } else if button == winit::event::MouseButton::Middle {
+                                pan_pressed = state == ElementState::Pressed;
                             }
                         }
 
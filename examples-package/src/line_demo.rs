@@ -94,8 +94,8 @@ mod tests {
         assert_eq!(line_plot.data_len(), 11);
 
         let bounds = line_plot.data_bounds().unwrap();
-        assert_eq!(bounds.0.x, 0.0);
-        assert_eq!(bounds.1.x, 5.0);
+        assert_eq!(bounds.0, 0.0);
+        assert_eq!(bounds.1, 5.0);
     }
 
     #[test]
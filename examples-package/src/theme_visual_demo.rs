@@ -159,6 +159,7 @@ impl ThemeDemo {
             color: text_color,
             h_align: HorizontalAlign::Left,
             v_align: VerticalAlign::Top,
+            rotation: 0.0,
         });
 
         primitives.push(Primitive::Text {
@@ -168,6 +169,7 @@ impl ThemeDemo {
             color: text_color,
             h_align: HorizontalAlign::Left,
             v_align: VerticalAlign::Top,
+            rotation: 0.0,
         });
 
         primitives
@@ -258,15 +260,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             window_id,
         } if window_id == window.id() => match event {
             WindowEvent::CloseRequested => elwt.exit(),
-            WindowEvent::KeyboardInput { event, .. } => {
-                if !demo.handle_input(event) {
-                    elwt.exit();
-                }
+            WindowEvent::KeyboardInput { event, .. } if !demo.handle_input(event) => {
+                elwt.exit();
             }
-            WindowEvent::Resized(physical_size) => {
-                if physical_size.width > 0 && physical_size.height > 0 {
-                    renderer.resize(*physical_size, &surface);
-                }
+            WindowEvent::Resized(physical_size) if physical_size.width > 0 && physical_size.height > 0 => {
+                renderer.resize(*physical_size, &surface);
             }
             WindowEvent::RedrawRequested => {
                 let primitives = demo.create_primitives();
@@ -100,7 +100,7 @@ mod tests {
         assert_eq!(scatter.data_len(), 8);
 
         let bounds = scatter.data_bounds().unwrap();
-        assert_eq!(bounds.0.x, 1.0);
-        assert_eq!(bounds.1.x, 2.4);
+        assert_eq!(bounds.0, 1.0);
+        assert_eq!(bounds.1, 2.4);
     }
 }
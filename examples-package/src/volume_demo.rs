@@ -130,7 +130,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 6. 演示光线投射
     println!("🔫 执行光线投射演示...");
 
-    let test_rays = vec![
+    let test_rays = [
         (nalgebra::Point3::new(-3.0, 0.0, 0.0), nalgebra::Vector3::new(1.0, 0.0, 0.0)),
         (nalgebra::Point3::new(0.0, -3.0, 0.0), nalgebra::Vector3::new(0.0, 1.0, 0.0)),
         (nalgebra::Point3::new(0.0, 0.0, -3.0), nalgebra::Vector3::new(0.0, 0.0, 1.0)),
@@ -165,7 +165,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("✅ 体积渲染演示完成！");
-    println!("");
+    println!();
     println!("📚 体积渲染功能说明:");
     println!("   📊 支持3D标量场数据可视化");
     println!("   🎨 可配置的传输函数");
@@ -173,7 +173,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   🔬 三线性插值采样");
     println!("   🌈 Alpha混合合成");
     println!("   ⚡ 早期光线终止优化");
-    println!("");
+    println!();
     println!("🚀 应用领域:");
     println!("   🏥 医学成像 (CT/MRI)");
     println!("   🌊 流体仿真可视化");
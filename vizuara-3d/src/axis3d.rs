@@ -3,7 +3,7 @@
 //! 为科学计算的3D数据可视化提供坐标轴显示功能
 
 use nalgebra::{Point3, Vector3};
-use vizuara_core::{Color, LinearScale, Scale};
+use vizuara_core::{Color, GridStyle, LinearScale, Scale, TickFormatter};
 
 /// 3D坐标轴方向
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -94,6 +94,8 @@ pub struct Axis3D {
     show_labels: bool,
     /// 是否显示轴线
     show_axis: bool,
+    /// 刻度标签格式化器（None 时保留一位小数）
+    tick_formatter: Option<TickFormatter>,
 }
 
 impl Axis3D {
@@ -116,6 +118,7 @@ impl Axis3D {
             style: Axis3DStyle::default(),
             show_labels: true,
             show_axis: true,
+            tick_formatter: None,
         }
     }
 
@@ -149,6 +152,23 @@ impl Axis3D {
         self
     }
 
+    /// 按统一的网格样式设置网格线颜色、宽度与网格类型
+    pub fn grid_style(mut self, grid: &GridStyle) -> Self {
+        self.style.grid_color = grid.major_color;
+        self.style.grid_width = grid.major_width;
+        self.style.minor_grid_color = grid.minor_color;
+        self.style.minor_grid_width = grid.minor_width;
+        self.grid_type = match (grid.show_major, grid.show_minor) {
+            (false, _) => GridType::None,
+            (true, false) => GridType::Major,
+            (true, true) => GridType::MajorMinor,
+        };
+        if grid.show_minor {
+            self.minor_tick_count = grid.minor_divisions.saturating_sub(1);
+        }
+        self
+    }
+
     /// 设置是否显示标签
     pub fn show_labels(mut self, show: bool) -> Self {
         self.show_labels = show;
@@ -161,6 +181,12 @@ impl Axis3D {
         self
     }
 
+    /// 设置刻度标签格式化器
+    pub fn tick_formatter(mut self, formatter: TickFormatter) -> Self {
+        self.tick_formatter = Some(formatter);
+        self
+    }
+
     /// 获取轴的方向向量
     pub fn direction_vector(&self) -> Vector3<f32> {
         match self.direction {
@@ -223,7 +249,13 @@ impl Axis3D {
         let ticks = self.scale.ticks(self.major_tick_count);
         ticks
             .into_iter()
-            .map(|v| (self.value_to_position(v), format!("{:.1}", v)))
+            .map(|v| {
+                let label = match &self.tick_formatter {
+                    Some(formatter) => formatter.format(v),
+                    None => format!("{:.1}", v),
+                };
+                (self.value_to_position(v), label)
+            })
             .collect()
     }
 }
@@ -323,6 +355,14 @@ impl CoordinateSystem3D {
         self
     }
 
+    /// 按统一的网格样式设置三个轴的网格
+    pub fn grid_style(mut self, grid: &GridStyle) -> Self {
+        self.x_axis = self.x_axis.grid_style(grid);
+        self.y_axis = self.y_axis.grid_style(grid);
+        self.z_axis = self.z_axis.grid_style(grid);
+        self
+    }
+
     /// 设置是否显示原点
     pub fn show_origin(mut self, show: bool) -> Self {
         self.show_origin = show;
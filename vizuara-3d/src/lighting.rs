@@ -3,6 +3,7 @@
 //! 提供现代化的PBR光照模型和多种光源类型
 
 use nalgebra::{Point3, Vector3};
+use vizuara_animation::{Animatable, Transition};
 use vizuara_core::Color;
 
 /// 光源类型
@@ -94,12 +95,57 @@ impl Light {
             Light::directional(Vector3::new(0.5, 0.2, 0.8), Color::rgb(1.0, 0.8, 0.6), 0.6),
         ]
     }
+
+    /// 光源位置；平行光没有位置，返回 None
+    pub fn position(&self) -> Option<Point3<f32>> {
+        match self.light_type {
+            LightType::Directional { .. } => None,
+            LightType::Point { position, .. } | LightType::Spot { position, .. } => Some(position),
+        }
+    }
+
+    /// 设置光源位置；平行光没有位置，调用将被忽略
+    pub fn set_position(&mut self, new_position: Point3<f32>) {
+        match self.light_type {
+            LightType::Directional { .. } => {}
+            LightType::Point {
+                ref mut position, ..
+            }
+            | LightType::Spot {
+                ref mut position, ..
+            } => *position = new_position,
+        }
+    }
+
+    /// 按位置过渡动画的当前值返回移动后的光源，每帧配合渲染器的 `set_light` 使用
+    pub fn with_animated_position(&self, transition: &Transition<Point3<f32>>) -> Self {
+        let mut light = self.clone();
+        light.set_value(transition.current_value(|from, to, t| self.lerp(from, to, t)));
+        light
+    }
+}
+
+/// 光源位置动画：点光源和聚光灯的位置可被过渡/关键帧动画驱动，平行光保持不变
+impl Animatable for Light {
+    type Value = Point3<f32>;
+
+    fn current_value(&self) -> Point3<f32> {
+        self.position().unwrap_or_else(Point3::origin)
+    }
+
+    fn set_value(&mut self, value: Point3<f32>) {
+        self.set_position(value);
+    }
+
+    fn lerp(&self, from: &Point3<f32>, to: &Point3<f32>, t: f32) -> Point3<f32> {
+        *from + (*to - *from) * t
+    }
 }
 
 /// PBR 材质
 #[derive(Debug, Clone)]
 pub struct Material {
-    /// 基础颜色 (反照率)
+    /// 基础颜色 (反照率)，alpha 分量为不透明度
     pub albedo: Color,
     /// 金属度 (0.0 = 电介质, 1.0 = 金属)
     pub metallic: f32,
@@ -147,6 +193,17 @@ impl Material {
         self
     }
 
+    /// 设置不透明度 (0.0 = 完全透明, 1.0 = 不透明)
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.albedo.a = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// 是否半透明，半透明物体需要混合绘制
+    pub fn is_transparent(&self) -> bool {
+        self.albedo.a < 1.0
+    }
+
     /// 创建预设材质
     ///
     /// 塑料材质
@@ -354,6 +411,12 @@ mod tests {
         let metal = Material::metal(Color::rgb(0.8, 0.8, 0.8));
         assert_eq!(metal.metallic, 1.0);
         assert_eq!(metal.roughness, 0.2);
+
+        assert!(!plastic.is_transparent());
+        let glass = Material::glass(Color::rgb(0.8, 0.9, 1.0)).alpha(0.4);
+        assert!(glass.is_transparent());
+        assert_eq!(glass.albedo.a, 0.4);
+        assert_eq!(Material::new(Color::WHITE).alpha(2.0).albedo.a, 1.0);
     }
 
     #[test]
@@ -370,4 +433,28 @@ mod tests {
 
         assert_eq!(calculator.lights().len(), 4); // 3 default + 1 added
     }
+
+    #[test]
+    fn test_light_position_animation() {
+        let mut point = Light::point(Point3::new(0.0, 0.0, 5.0), Color::WHITE, 1.0, 10.0);
+        let from = Point3::new(0.0, 0.0, 0.0);
+        let to = Point3::new(4.0, 2.0, 0.0);
+        assert_eq!(point.lerp(&from, &to, 0.5), Point3::new(2.0, 1.0, 0.0));
+
+        point.set_value(to);
+        assert_eq!(point.position(), Some(to));
+
+        // 平行光没有位置，设置被忽略
+        let mut sun = Light::directional(Vector3::new(0.0, 0.0, -1.0), Color::WHITE, 1.0);
+        sun.set_value(to);
+        assert_eq!(sun.position(), None);
+        assert_eq!(sun.current_value(), Point3::origin());
+
+        // 未开始的过渡停留在起点
+        let transition = Transition::simple(from, to, std::time::Duration::from_secs(1));
+        assert_eq!(
+            point.with_animated_position(&transition).position(),
+            Some(from)
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use crate::BoundingBox3D;
 use nalgebra::{Point2, Point3, Vector3};
-use vizuara_core::{Color, Primitive};
+use std::collections::HashMap;
+use std::io::Read;
+use vizuara_core::{Color, Primitive, Result, VizuaraError};
 
 /// 3D 三角形面片
 #[derive(Debug, Clone)]
@@ -299,6 +301,35 @@ impl Mesh3D {
         Self::from_vertices_indices(&vertices, &indices, Color::rgb(0.9, 0.6, 0.9))
     }
 
+    /// 从 STL 读取网格，自动识别二进制与 ASCII 格式，保留逐面法向量
+    pub fn from_stl_reader<R: Read>(reader: R) -> Result<Self> {
+        Self::from_stl_reader_with(reader, StlNormals::Faceted)
+    }
+
+    /// 从 STL 读取网格，`normals` 决定保留逐面法向量还是焊接顶点后计算平滑法向量
+    pub fn from_stl_reader_with<R: Read>(mut reader: R, normals: StlNormals) -> Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        let mut triangles = if is_ascii_stl(&bytes) {
+            // 已在 is_ascii_stl 中确认为合法 UTF-8
+            parse_ascii_stl(std::str::from_utf8(&bytes).unwrap_or_default())?
+        } else {
+            parse_binary_stl(&bytes)?
+        };
+
+        if let StlNormals::Smooth { weld_epsilon } = normals {
+            smooth_normals(&mut triangles, weld_epsilon);
+        }
+
+        let mut mesh = Self {
+            triangles,
+            bounding_box: None,
+        };
+        mesh.update_bounding_box();
+        Ok(mesh)
+    }
+
     /// 获取三角形数量
     pub fn triangle_count(&self) -> usize {
         self.triangles.len()
@@ -423,6 +454,195 @@ impl Default for Mesh3D {
     }
 }
 
+/// STL 法向量处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StlNormals {
+    /// 保留文件中的逐面法向量（面片分明）
+    #[default]
+    Faceted,
+    /// 焊接距离不超过 `weld_epsilon` 的顶点，按面积加权计算平滑法向量
+    Smooth { weld_epsilon: f32 },
+}
+
+/// 二进制 STL 的文件头长度（80 字节头 + 4 字节三角形数量）
+const STL_BINARY_HEADER: usize = 84;
+/// 二进制 STL 每个三角形的长度（法向量、三个顶点各 12 字节，属性 2 字节）
+const STL_BINARY_TRIANGLE: usize = 50;
+
+/// 以 "solid" 开头且为不含 NUL 的合法文本时按 ASCII 解析；
+/// 部分二进制文件的文件头同样以 "solid" 开头，大小与三角形数量吻合时仍按二进制处理
+fn is_ascii_stl(bytes: &[u8]) -> bool {
+    let starts_with_solid = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .is_some_and(|start| bytes[start..].starts_with(b"solid"));
+    let binary_size_matches =
+        binary_stl_count(bytes).is_some_and(|count| binary_stl_size(count) == bytes.len() as u64);
+    starts_with_solid
+        && !binary_size_matches
+        && !bytes.contains(&0)
+        && std::str::from_utf8(bytes).is_ok()
+}
+
+/// 二进制 STL 文件头中记录的三角形数量
+fn binary_stl_count(bytes: &[u8]) -> Option<u32> {
+    let count = bytes.get(80..STL_BINARY_HEADER)?;
+    Some(u32::from_le_bytes([count[0], count[1], count[2], count[3]]))
+}
+
+/// 含 `count` 个三角形的二进制 STL 应有的字节数
+fn binary_stl_size(count: u32) -> u64 {
+    STL_BINARY_HEADER as u64 + count as u64 * STL_BINARY_TRIANGLE as u64
+}
+
+fn parse_binary_stl(bytes: &[u8]) -> Result<Vec<Triangle>> {
+    let Some(count) = binary_stl_count(bytes) else {
+        return Err(VizuaraError::InvalidData(format!(
+            "STL 文件过短: {} 字节",
+            bytes.len()
+        )));
+    };
+    let expected = binary_stl_size(count);
+    if expected != bytes.len() as u64 {
+        return Err(VizuaraError::InvalidData(format!(
+            "STL 三角形数量 {} 与文件大小不符: 应为 {} 字节，实际 {} 字节",
+            count,
+            expected,
+            bytes.len()
+        )));
+    }
+
+    let read_vector = |data: &[u8]| {
+        let component =
+            |i: usize| f32::from_le_bytes([data[i], data[i + 1], data[i + 2], data[i + 3]]);
+        Vector3::new(component(0), component(4), component(8))
+    };
+
+    Ok(bytes[STL_BINARY_HEADER..]
+        .chunks_exact(STL_BINARY_TRIANGLE)
+        .map(|facet| {
+            let normal = read_vector(&facet[0..12]);
+            let v0 = Point3::from(read_vector(&facet[12..24]));
+            let v1 = Point3::from(read_vector(&facet[24..36]));
+            let v2 = Point3::from(read_vector(&facet[36..48]));
+            stl_triangle(normal, [v0, v1, v2])
+        })
+        .collect())
+}
+
+fn parse_ascii_stl(text: &str) -> Result<Vec<Triangle>> {
+    let mut tokens = text.split_whitespace();
+    let mut triangles = Vec::new();
+
+    // solid 名称可以包含任意单词，facet 之外的记号一律跳过
+    while let Some(token) = tokens.next() {
+        if token != "facet" {
+            continue;
+        }
+        expect_token(&mut tokens, "normal")?;
+        let normal = read_ascii_vector(&mut tokens)?;
+        expect_token(&mut tokens, "outer")?;
+        expect_token(&mut tokens, "loop")?;
+        let mut vertices = [Point3::origin(); 3];
+        for vertex in &mut vertices {
+            expect_token(&mut tokens, "vertex")?;
+            *vertex = Point3::from(read_ascii_vector(&mut tokens)?);
+        }
+        expect_token(&mut tokens, "endloop")?;
+        expect_token(&mut tokens, "endfacet")?;
+        triangles.push(stl_triangle(normal, vertices));
+    }
+
+    Ok(triangles)
+}
+
+fn expect_token<'a>(tokens: &mut impl Iterator<Item = &'a str>, expected: &str) -> Result<()> {
+    match tokens.next() {
+        Some(token) if token == expected => Ok(()),
+        Some(token) => Err(VizuaraError::InvalidData(format!(
+            "STL 解析失败: 期望 {}，实际为 {}",
+            expected, token
+        ))),
+        None => Err(VizuaraError::InvalidData(format!(
+            "STL 解析失败: 期望 {}，文件已结束",
+            expected
+        ))),
+    }
+}
+
+fn read_ascii_vector<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<Vector3<f32>> {
+    let mut component = || {
+        let token = tokens
+            .next()
+            .ok_or_else(|| VizuaraError::InvalidData("STL 解析失败: 坐标不完整".to_string()))?;
+        token
+            .parse::<f32>()
+            .map_err(|_| VizuaraError::InvalidData(format!("STL 解析失败: 无效的数值 {}", token)))
+    };
+    Ok(Vector3::new(component()?, component()?, component()?))
+}
+
+/// 由 STL 面片创建三角形；文件中的法向量为零或无效时使用由顶点计算的法向量
+fn stl_triangle(normal: Vector3<f32>, vertices: [Point3<f32>; 3]) -> Triangle {
+    let mut triangle = Triangle::new(vertices[0], vertices[1], vertices[2]);
+    if let Some(normal) = normal.try_normalize(f32::EPSILON) {
+        triangle.normals = [normal; 3];
+    }
+    triangle
+}
+
+/// 焊接重合顶点并以面积加权平均相邻面的法向量
+fn smooth_normals(triangles: &mut [Triangle], epsilon: f32) {
+    let epsilon = epsilon.max(f32::EPSILON);
+    let cell_of = |p: &Point3<f32>| {
+        (
+            (p.x / epsilon).floor() as i64,
+            (p.y / epsilon).floor() as i64,
+            (p.z / epsilon).floor() as i64,
+        )
+    };
+
+    // 按 epsilon 大小的网格分桶，只需在相邻 27 个格子中查找重合顶点
+    let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    let mut welded: Vec<Point3<f32>> = Vec::new();
+    let mut accumulated: Vec<Vector3<f32>> = Vec::new();
+    let mut corner_indices = Vec::with_capacity(triangles.len() * 3);
+
+    for triangle in triangles.iter() {
+        // 叉积的模为面积的两倍，直接累加即为面积加权
+        let face = (triangle.vertices[1] - triangle.vertices[0])
+            .cross(&(triangle.vertices[2] - triangle.vertices[0]));
+
+        for vertex in &triangle.vertices {
+            let (cx, cy, cz) = cell_of(vertex);
+            let existing = (-1..=1)
+                .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+                .filter_map(|(dx, dy, dz)| grid.get(&(cx + dx, cy + dy, cz + dz)))
+                .flatten()
+                .copied()
+                .find(|&index| (welded[index] - *vertex).norm() <= epsilon);
+
+            let index = existing.unwrap_or_else(|| {
+                welded.push(*vertex);
+                accumulated.push(Vector3::zeros());
+                grid.entry((cx, cy, cz)).or_default().push(welded.len() - 1);
+                welded.len() - 1
+            });
+            accumulated[index] += face;
+            corner_indices.push(index);
+        }
+    }
+
+    for (triangle, corners) in triangles.iter_mut().zip(corner_indices.chunks_exact(3)) {
+        for (i, &index) in corners.iter().enumerate() {
+            triangle.vertices[i] = welded[index];
+            if let Some(normal) = accumulated[index].try_normalize(f32::EPSILON) {
+                triangle.normals[i] = normal;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,4 +719,91 @@ mod tests {
         let mesh = Mesh3D::from_vertices_indices(&vertices, &indices, Color::rgb(1.0, 0.0, 0.0));
         assert_eq!(mesh.triangle_count(), 1);
     }
+
+    /// 构造二进制 STL，`count` 为写入文件头的三角形数量
+    fn binary_stl(facets: &[[[f32; 3]; 4]], count: u32) -> Vec<u8> {
+        let mut bytes = b"solid binary header".to_vec();
+        bytes.resize(80, 0);
+        bytes.extend(count.to_le_bytes());
+        for facet in facets {
+            for value in facet.iter().flatten() {
+                bytes.extend(value.to_le_bytes());
+            }
+            bytes.extend([0u8; 2]);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_stl_ascii_and_binary() {
+        let ascii = "solid test part\n\
+            facet normal 0 0 1\n\
+              outer loop\n\
+                vertex 0 0 0\n\
+                vertex 2 0 0\n\
+                vertex 0 3 1.5e0\n\
+              endloop\n\
+            endfacet\n\
+            endsolid test part\n";
+        let mesh = Mesh3D::from_stl_reader(ascii.as_bytes()).unwrap();
+        assert_eq!(mesh.triangle_count(), 1);
+        assert_eq!(mesh.bounds(), Some(((0.0, 2.0), (0.0, 3.0), (0.0, 1.5))));
+
+        let facet = [
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0],
+            [2.0, 0.0, 0.0],
+            [0.0, 3.0, 1.5],
+        ];
+        let bytes = binary_stl(&[facet], 1);
+        let binary = Mesh3D::from_stl_reader(bytes.as_slice()).unwrap();
+        assert_eq!(binary.triangle_count(), 1);
+        assert_eq!(binary.vertex_at(2), mesh.vertex_at(2));
+        assert_eq!(binary.triangles[0].normals[0], Vector3::new(0.0, 0.0, 1.0));
+
+        let truncated = "solid broken\nfacet normal 0 0 1\nouter loop\nvertex 0 0\n";
+        assert!(Mesh3D::from_stl_reader(truncated.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_stl_binary_count_mismatch() {
+        let facet = [[0.0; 3], [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+        let bytes = binary_stl(&[facet], 2);
+        assert!(matches!(
+            Mesh3D::from_stl_reader(bytes.as_slice()),
+            Err(VizuaraError::InvalidData(_))
+        ));
+        assert!(Mesh3D::from_stl_reader(&bytes[..40]).is_err());
+    }
+
+    #[test]
+    fn test_stl_smooth_normals() {
+        // 沿 y 轴折起的两个面片，共享棱上的顶点有微小误差
+        let left = [[0.0; 3], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 1.0]];
+        let right = [
+            [0.0; 3],
+            [0.0, 0.0, 1.0 + 1e-6],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0],
+        ];
+        let bytes = binary_stl(&[left, right], 2);
+
+        let faceted = Mesh3D::from_stl_reader(bytes.as_slice()).unwrap();
+        assert_ne!(
+            faceted.triangles[0].normals[1],
+            faceted.triangles[1].normals[0]
+        );
+
+        let smooth = Mesh3D::from_stl_reader_with(
+            bytes.as_slice(),
+            StlNormals::Smooth { weld_epsilon: 1e-4 },
+        )
+        .unwrap();
+        let (a, b) = (&smooth.triangles[0], &smooth.triangles[1]);
+        assert_eq!(a.vertices[1], b.vertices[0]);
+        assert_eq!(a.normals[1], b.normals[0]);
+        assert!((a.normals[1] - Vector3::new(0.0, 0.0, 1.0)).norm() < 1e-5);
+        // 未共享的顶点保留各自面的法向量
+        assert!(a.normals[0].x < 0.0 && b.normals[1].x > 0.0);
+    }
 }
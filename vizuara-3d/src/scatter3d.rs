@@ -1,6 +1,6 @@
 use crate::BoundingBox3D;
 use nalgebra::Point3;
-use vizuara_core::{Color, Primitive};
+use vizuara_core::{Color, Primitive, Style};
 
 /// 3D 散点图数据点
 #[derive(Debug, Clone)]
@@ -122,7 +122,16 @@ impl Scatter3D {
 
     /// 生成渲染图元 (将3D点投影到2D进行渲染)
     pub fn generate_primitives(&self, plot_area: &crate::Plot3DArea) -> Vec<Primitive> {
+        self.generate_styled_primitives(plot_area).0
+    }
+
+    /// 生成渲染图元及其样式：每个圆按点颜色（随深度变暗）填充
+    pub fn generate_styled_primitives(
+        &self,
+        plot_area: &crate::Plot3DArea,
+    ) -> (Vec<Primitive>, Vec<Style>) {
         let mut primitives = Vec::new();
+        let mut styles = Vec::new();
 
         // 创建模型-视图-投影矩阵
         let mvp = plot_area.projection_matrix * plot_area.view_matrix;
@@ -153,7 +162,7 @@ impl Scatter3D {
                     // 根据深度调整点的大小和颜色
                     let depth_factor = 1.0 - ndc_z; // 越近越大
                     let adjusted_size = point.size * depth_factor;
-                    let _adjusted_color = Color::new(
+                    let adjusted_color = Color::new(
                         point.color.r * depth_factor,
                         point.color.g * depth_factor,
                         point.color.b * depth_factor,
@@ -165,11 +174,15 @@ impl Scatter3D {
                         center: nalgebra::Point2::new(screen_x, screen_y),
                         radius: adjusted_size,
                     });
+                    styles.push(Style {
+                        stroke_color: None,
+                        ..Style::new().fill_color(adjusted_color)
+                    });
                 }
             }
         }
 
-        primitives
+        (primitives, styles)
     }
 }
 
@@ -1,5 +1,6 @@
-use nalgebra::Point2;
-use vizuara_core::{Color, Primitive};
+use nalgebra::{Point2, Point3, Vector3};
+use vizuara_core::{Color, Primitive, Style};
+use vizuara_plots::{contour_segments, ColorMap};
 
 /// 3D 表面图数据点
 #[derive(Debug, Clone)]
@@ -13,6 +14,11 @@ impl SurfacePoint {
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
+
+    /// 坐标是否全部为有限值（NaN/无穷视为表面上的空洞）
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
 }
 
 /// 3D 表面图网格
@@ -97,7 +103,13 @@ impl SurfaceMesh {
             .map(|p| nalgebra::Point3::new(p.x, p.y, p.z))
     }
 
-    /// 获取数据边界
+    /// 指定位置是否为有效（有限）点
+    pub fn is_valid(&self, row: usize, col: usize) -> bool {
+        self.get_point(row, col)
+            .is_some_and(SurfacePoint::is_finite)
+    }
+
+    /// 获取数据边界（忽略空洞点）
     pub fn bounds(&self) -> ((f32, f32), (f32, f32), (f32, f32)) {
         let mut min_x = f32::INFINITY;
         let mut max_x = f32::NEG_INFINITY;
@@ -107,7 +119,7 @@ impl SurfaceMesh {
         let mut max_z = f32::NEG_INFINITY;
 
         for row in &self.points {
-            for point in row {
+            for point in row.iter().filter(|p| p.is_finite()) {
                 min_x = min_x.min(point.x);
                 max_x = max_x.max(point.x);
                 min_y = min_y.min(point.y);
@@ -151,11 +163,42 @@ impl Default for SurfaceStyle {
     }
 }
 
+/// 表面着色的标量来源
+#[derive(Debug, Clone)]
+pub enum SurfaceColorSource {
+    /// 按顶点高度 (z) 着色
+    Height,
+    /// 按外部标量场着色（行主序，长度为 width * height）
+    Field(Vec<f32>),
+}
+
+/// 带法线和颜色的表面顶点（用于光照渲染器）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub color: [f32; 3],
+}
+
+/// 投影到底面的一级等高线
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloorContour {
+    /// 等高线对应的高度
+    pub level: f32,
+    /// 线条颜色
+    pub color: Color,
+    /// 线段顶点（每两个点构成一条线段，z 均为表面最低高度）
+    pub lines: Vec<Point3<f32>>,
+}
+
 /// 3D 表面图
 #[derive(Debug, Clone)]
 pub struct Surface3D {
     mesh: SurfaceMesh,
     style: SurfaceStyle,
+    coloring: Option<(SurfaceColorSource, ColorMap)>,
+    color_range: Option<(f32, f32)>,
+    floor_contours: Option<usize>,
 }
 
 impl Surface3D {
@@ -164,6 +207,9 @@ impl Surface3D {
         Self {
             mesh,
             style: SurfaceStyle::default(),
+            coloring: None,
+            color_range: None,
+            floor_contours: None,
         }
     }
 
@@ -199,11 +245,223 @@ impl Surface3D {
         self
     }
 
+    /// 按高度着色
+    pub fn color_by_height(mut self, color_map: ColorMap) -> Self {
+        self.coloring = Some((SurfaceColorSource::Height, color_map));
+        self
+    }
+
+    /// 按标量场着色（行主序，每个网格点一个值）
+    ///
+    /// 标量场长度与网格点数不一致时忽略该标量场，改为按高度着色
+    pub fn color_by_field(mut self, field: &[f32], color_map: ColorMap) -> Self {
+        let source = if field.len() == self.mesh.width * self.mesh.height {
+            SurfaceColorSource::Field(field.to_vec())
+        } else {
+            SurfaceColorSource::Height
+        };
+        self.coloring = Some((source, color_map));
+        self
+    }
+
+    /// 设置颜色映射的数值范围（默认使用数据范围）
+    pub fn color_range(mut self, min: f32, max: f32) -> Self {
+        self.color_range = Some((min, max));
+        self
+    }
+
+    /// 在 z = z_min 的底面上绘制等高线投影，级别在高度范围内均匀分布
+    pub fn show_floor_contours(mut self, levels: usize) -> Self {
+        self.floor_contours = (levels > 0).then_some(levels);
+        self
+    }
+
     /// 获取网格数据
     pub fn mesh(&self) -> &SurfaceMesh {
         &self.mesh
     }
 
+    /// 计算每个网格点的颜色（行主序）
+    pub fn vertex_colors(&self) -> Vec<Color> {
+        let heights: Vec<f32> = self
+            .mesh
+            .points
+            .iter()
+            .flat_map(|row| row.iter().map(|p| p.z))
+            .collect();
+
+        let (values, color_map) = match &self.coloring {
+            Some((SurfaceColorSource::Height, color_map)) => (heights, Some(color_map)),
+            Some((SurfaceColorSource::Field(field), color_map)) => (field.clone(), Some(color_map)),
+            None => (heights, None),
+        };
+
+        let (min, max) = self.color_range.unwrap_or_else(|| {
+            values
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+                    (lo.min(v), hi.max(v))
+                })
+        });
+        let span = max - min;
+
+        values
+            .iter()
+            .map(|&v| {
+                let t = if !v.is_finite() {
+                    0.0
+                } else if span > 0.0 {
+                    (v - min) / span
+                } else {
+                    0.5
+                };
+                match color_map {
+                    Some(color_map) => color_map.get_color(t),
+                    None => (self.style.color_map)(t),
+                }
+            })
+            .collect()
+    }
+
+    /// 生成带法线和颜色的三角网格（用于光照渲染器）
+    ///
+    /// 顶点按行主序排列，三角形以 +Z 方向为正面（逆时针）。
+    /// 含 NaN/无穷顶点的三角形会被跳过，在表面上留下空洞。
+    pub fn triangle_mesh(&self) -> (Vec<SurfaceVertex>, Vec<u32>) {
+        let (width, height) = (self.mesh.width, self.mesh.height);
+        let colors = self.vertex_colors();
+        let valid = |i: usize, j: usize| self.mesh.is_valid(i, j);
+        let point = |i: usize, j: usize| {
+            let p = &self.mesh.points[i][j];
+            Vector3::new(p.x, p.y, p.z)
+        };
+        // 空洞邻居退化为中心点，避免 NaN 污染法线
+        let neighbor = |i: usize, j: usize, ci: usize, cj: usize| {
+            if valid(i, j) {
+                point(i, j)
+            } else {
+                point(ci, cj)
+            }
+        };
+
+        let mut vertices = Vec::with_capacity(width * height);
+        for i in 0..height {
+            for j in 0..width {
+                if !valid(i, j) {
+                    // 空洞顶点不会被任何三角形引用，仅占位保持索引连续
+                    let p = &self.mesh.points[i][j];
+                    vertices.push(SurfaceVertex {
+                        position: [p.x, p.y, 0.0],
+                        normal: [0.0, 0.0, 1.0],
+                        color: [0.0, 0.0, 0.0],
+                    });
+                    continue;
+                }
+
+                // 中心差分计算切向量
+                let dx = neighbor(i, (j + 1).min(width - 1), i, j)
+                    - neighbor(i, j.saturating_sub(1), i, j);
+                let dy = neighbor((i + 1).min(height - 1), j, i, j)
+                    - neighbor(i.saturating_sub(1), j, i, j);
+                let normal = dx.cross(&dy);
+                let normal = if normal.norm() > 0.0 {
+                    normal.normalize()
+                } else {
+                    Vector3::new(0.0, 0.0, 1.0)
+                };
+
+                let p = point(i, j);
+                let color = colors[i * width + j];
+                vertices.push(SurfaceVertex {
+                    position: [p.x, p.y, p.z],
+                    normal: [normal.x, normal.y, normal.z],
+                    color: [color.r, color.g, color.b],
+                });
+            }
+        }
+
+        let mut indices = Vec::new();
+        for i in 0..height.saturating_sub(1) {
+            for j in 0..width.saturating_sub(1) {
+                let top_left = (i * width + j) as u32;
+                let top_right = top_left + 1;
+                let bottom_left = ((i + 1) * width + j) as u32;
+                let bottom_right = bottom_left + 1;
+
+                if valid(i, j) && valid(i, j + 1) && valid(i + 1, j) {
+                    indices.extend_from_slice(&[top_left, top_right, bottom_left]);
+                }
+                if valid(i, j + 1) && valid(i + 1, j + 1) && valid(i + 1, j) {
+                    indices.extend_from_slice(&[top_right, bottom_right, bottom_left]);
+                }
+            }
+        }
+
+        (vertices, indices)
+    }
+
+    /// 底面等高线：对高度网格运行 Marching Squares，线段置于 z = z_min 平面，
+    /// 按级别使用表面的颜色映射（未设置时为默认映射）着色
+    pub fn floor_contour_lines(&self) -> Vec<FloorContour> {
+        let Some(count) = self.floor_contours else {
+            return Vec::new();
+        };
+        let (_, _, (z_min, z_max)) = self.mesh.bounds();
+        if self.mesh.width < 2 || self.mesh.height < 2 || z_max <= z_min {
+            return Vec::new();
+        }
+
+        // 网格为直线网格：X 取自首行，Y 取自首列
+        let x_coords: Vec<f32> = self.mesh.points[0].iter().map(|p| p.x).collect();
+        let y_coords: Vec<f32> = self.mesh.points.iter().map(|row| row[0].y).collect();
+        let heights: Vec<Vec<f32>> = self
+            .mesh
+            .points
+            .iter()
+            .map(|row| row.iter().map(|p| p.z).collect())
+            .collect();
+
+        let (color_map, (min, max)) = match &self.coloring {
+            Some((SurfaceColorSource::Height, color_map)) => (
+                color_map.clone(),
+                self.color_range.unwrap_or((z_min, z_max)),
+            ),
+            Some((SurfaceColorSource::Field(_), color_map)) => (color_map.clone(), (z_min, z_max)),
+            None => (ColorMap::default(), (z_min, z_max)),
+        };
+
+        (1..=count)
+            .map(|i| {
+                // 级别取开区间内的均分点，避开只接触极值点的退化等高线
+                let level = z_min + (z_max - z_min) * i as f32 / (count + 1) as f32;
+                let t = if max > min {
+                    (level - min) / (max - min)
+                } else {
+                    0.5
+                };
+                let lines = contour_segments(&x_coords, &y_coords, &heights, level)
+                    .into_iter()
+                    .flatten()
+                    .map(|(x, y)| Point3::new(x, y, z_min))
+                    .collect();
+                FloorContour {
+                    level,
+                    color: color_map.get_color(t),
+                    lines,
+                }
+            })
+            .collect()
+    }
+
+    /// [`generate_primitives`](Self::generate_primitives) 生成的线框线段使用的样式
+    pub fn wireframe_style(&self) -> Style {
+        Style {
+            fill_color: None,
+            ..Style::new().stroke(self.style.wireframe_color, self.style.wireframe_width)
+        }
+    }
+
     /// 生成渲染图元 (简化的2D投影)
     pub fn generate_primitives(&self, _plot_area: &crate::Plot3DArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
@@ -229,6 +487,10 @@ impl Surface3D {
                 if let (Some(p1), Some(p2)) =
                     (self.mesh.get_point(i, j), self.mesh.get_point(i, j + 1))
                 {
+                    if !p1.is_finite() || !p2.is_finite() {
+                        continue;
+                    }
+
                     let x1 = offset_x + (p1.x - x_min) / x_range * screen_width;
                     let y1 = offset_y + screen_height - (p1.y - y_min) / y_range * screen_height;
                     let x2 = offset_x + (p2.x - x_min) / x_range * screen_width;
@@ -248,6 +510,10 @@ impl Surface3D {
                 if let (Some(p1), Some(p2)) =
                     (self.mesh.get_point(i, j), self.mesh.get_point(i + 1, j))
                 {
+                    if !p1.is_finite() || !p2.is_finite() {
+                        continue;
+                    }
+
                     let x1 = offset_x + (p1.x - x_min) / x_range * screen_width;
                     let y1 = offset_y + screen_height - (p1.y - y_min) / y_range * screen_height;
                     let x2 = offset_x + (p2.x - x_min) / x_range * screen_width;
@@ -327,4 +593,113 @@ mod tests {
         assert!(surface.style.wireframe);
         assert_eq!(surface.style.wireframe_color, Color::rgb(1.0, 0.0, 0.0));
     }
+
+    #[test]
+    fn test_surface_color_by_height() {
+        let surface = Surface3D::from_function((0.0, 1.0), (0.0, 1.0), (3, 3), |x, _| x)
+            .color_by_height(ColorMap::Grayscale);
+
+        let colors = surface.vertex_colors();
+        assert_eq!(colors.len(), 9);
+        assert_eq!(colors[0], Color::rgb(0.0, 0.0, 0.0));
+        assert_eq!(colors[2], Color::rgb(1.0, 1.0, 1.0));
+
+        // 显式范围覆盖数据范围
+        let surface = surface.color_range(0.0, 2.0);
+        assert_eq!(surface.vertex_colors()[2], Color::rgb(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_surface_color_by_mismatched_field() {
+        // 长度不符的标量场被忽略，退回按高度着色
+        let surface = Surface3D::from_function((0.0, 1.0), (0.0, 1.0), (3, 3), |x, _| x)
+            .color_by_field(&[1.0, 2.0], ColorMap::Grayscale);
+
+        let colors = surface.vertex_colors();
+        assert_eq!(colors.len(), 9);
+        assert_eq!(colors[0], Color::rgb(0.0, 0.0, 0.0));
+        assert_eq!(colors[2], Color::rgb(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_surface_triangle_mesh() {
+        let field = vec![0.0, 1.0, 2.0, 3.0];
+        let surface = Surface3D::from_function((0.0, 1.0), (0.0, 1.0), (2, 2), |_, _| 0.0)
+            .color_by_field(&field, ColorMap::Grayscale);
+
+        let (vertices, indices) = surface.triangle_mesh();
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, vec![0, 1, 2, 1, 3, 2]);
+        // 平面的法线朝 +Z
+        assert_eq!(vertices[0].normal, [0.0, 0.0, 1.0]);
+        assert_eq!(vertices[3].color, [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_surface_triangle_mesh_skips_holes() {
+        // 3x3 网格，中心点为 NaN
+        let surface = Surface3D::from_function((0.0, 2.0), (0.0, 2.0), (3, 3), |x, y| {
+            if x == 1.0 && y == 1.0 {
+                f32::NAN
+            } else {
+                x + y
+            }
+        });
+
+        let (vertices, indices) = surface.triangle_mesh();
+        assert_eq!(vertices.len(), 9);
+        // 与中心点相连的三角形都被跳过，只剩 2 个角上的三角形
+        assert_eq!(indices.len(), 6);
+        assert!(!indices.contains(&4));
+        assert!(vertices.iter().all(|v| v
+            .position
+            .iter()
+            .chain(v.normal.iter())
+            .all(|c| c.is_finite())));
+
+        let (_, _, (z_min, z_max)) = surface.mesh().bounds();
+        assert_eq!((z_min, z_max), (0.0, 4.0));
+    }
+
+    #[test]
+    fn test_surface_triangle_mesh_large_grid() {
+        // 超过 u16 索引范围的网格
+        let surface = Surface3D::from_function((0.0, 1.0), (0.0, 1.0), (300, 300), |x, y| x * y);
+
+        let (vertices, indices) = surface.triangle_mesh();
+        assert_eq!(vertices.len(), 300 * 300);
+        assert_eq!(indices.len(), 299 * 299 * 6);
+        assert_eq!(indices.iter().max(), Some(&(300 * 300 - 1)));
+    }
+
+    #[test]
+    fn test_floor_contours() {
+        // 圆锥面：等高线为同心圆，全部落在 z_min 平面
+        let surface = Surface3D::from_function((-1.0, 1.0), (-1.0, 1.0), (21, 21), |x, y| {
+            (x * x + y * y).sqrt()
+        });
+        assert!(surface.floor_contour_lines().is_empty());
+
+        let surface = surface
+            .color_by_height(ColorMap::Grayscale)
+            .show_floor_contours(3);
+        let contours = surface.floor_contour_lines();
+        assert_eq!(contours.len(), 3);
+
+        let (_, _, (z_min, z_max)) = surface.mesh().bounds();
+        for (i, contour) in contours.iter().enumerate() {
+            let expected = z_min + (z_max - z_min) * (i + 1) as f32 / 4.0;
+            assert!((contour.level - expected).abs() < 1e-6);
+            let t = (contour.level - z_min) / (z_max - z_min);
+            assert_eq!(contour.color, ColorMap::Grayscale.get_color(t));
+
+            assert!(!contour.lines.is_empty());
+            assert!(contour.lines.chunks_exact(2).remainder().is_empty());
+            for p in &contour.lines {
+                assert_eq!(p.z, z_min);
+                let r = (p.x * p.x + p.y * p.y).sqrt();
+                assert!((r - contour.level).abs() < 0.05);
+            }
+        }
+    }
 }
@@ -0,0 +1,50 @@
+//! 10k 个过渡同时求值时，直接计算缓动与预采样查找表的对比
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::time::Duration;
+use vizuara_animation::{AnimationConfig, EasingFunction, Transition};
+
+const TRANSITIONS: usize = 10_000;
+
+/// 创建进度均匀分布在 [0, 1) 上的暂停过渡
+fn transitions(easing: EasingFunction) -> Vec<Transition<f32>> {
+    let duration = Duration::from_secs(10);
+    (0..TRANSITIONS)
+        .map(|i| {
+            let config = AnimationConfig::new(duration).with_easing(easing.clone());
+            let mut transition = Transition::new(0.0, 100.0, config);
+            transition.start();
+            transition.seek(duration.mul_f32(i as f32 / TRANSITIONS as f32));
+            transition.pause();
+            transition
+        })
+        .collect()
+}
+
+fn bench_easing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("easing_10k_transitions");
+    let easings = [
+        ("elastic_out", EasingFunction::ElasticOut),
+        (
+            "cubic_bezier",
+            EasingFunction::cubic_bezier(0.25, 0.1, 0.25, 1.0),
+        ),
+    ];
+
+    for (name, easing) in easings {
+        for (variant, easing) in [
+            ("direct", easing.clone()),
+            ("precomputed", EasingFunction::precomputed(easing, 256)),
+        ] {
+            let transitions = transitions(easing);
+            group.bench_with_input(BenchmarkId::new(name, variant), &transitions, |b, ts| {
+                b.iter(|| ts.iter().map(|t| black_box(t.current_f32())).sum::<f32>())
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_easing);
+criterion_main!(benches);
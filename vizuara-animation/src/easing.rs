@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 /// 缓动函数类型
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum EasingFunction {
     /// 线性
     Linear,
@@ -19,9 +21,83 @@ pub enum EasingFunction {
     BackIn,
     /// 背景出
     BackOut,
+    /// CSS 风格的三次贝塞尔曲线 (x1, y1, x2, y2)
+    ///
+    /// 建议通过 [`EasingFunction::cubic_bezier`] 构造，以保证控制点 x 坐标位于 [0, 1]
+    CubicBezier(f32, f32, f32, f32),
+    /// 预先采样的查找表，求值时在相邻采样间线性插值
+    ///
+    /// 通过 [`EasingFunction::precomputed`] 构造
+    Precomputed(EasingTable),
+}
+
+/// 缓动函数在 [0, 1] 上等距采样得到的查找表
+///
+/// 采样数据以引用计数共享，复制缓动函数（例如多个过渡共用同一缓动）不会重新采样或复制数据
+#[derive(Debug, Clone, PartialEq)]
+pub struct EasingTable {
+    inner: Arc<EasingFunction>,
+    samples: Arc<[f32]>,
+}
+
+impl EasingTable {
+    /// 被采样的缓动函数
+    pub fn inner(&self) -> &EasingFunction {
+        &self.inner
+    }
+
+    /// 采样数量
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// 查找表是否为空（构造时至少两个采样，恒为 false）
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// 在相邻采样间线性插值
+    fn lookup(&self, t: f32) -> f32 {
+        let last = self.samples.len() - 1;
+        let position = t * last as f32;
+        let index = (position as usize).min(last - 1);
+        let fraction = position - index as f32;
+        let (a, b) = (self.samples[index], self.samples[index + 1]);
+        a + (b - a) * fraction
+    }
 }
 
 impl EasingFunction {
+    /// 创建三次贝塞尔缓动函数
+    ///
+    /// 控制点的 x 坐标会被限制在 [0, 1] 内，超出范围会导致曲线在 x 方向不单调
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        EasingFunction::CubicBezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2)
+    }
+
+    /// 将缓动函数预先采样为 `samples` 个点的查找表（至少 2 个）
+    ///
+    /// 超越函数或需要迭代求解的缓动（弹性、贝塞尔等）每次求值代价较高；
+    /// 大量过渡共用同一缓动时，用一次分配换取每帧只需一次查表和插值。
+    /// 采样越多越接近原函数，通常 256 个已足够平滑。
+    /// 克隆得到的缓动函数共用同一张表
+    pub fn precomputed(inner: EasingFunction, samples: usize) -> Self {
+        // 对查找表再次采样没有意义，直接采样原函数
+        let inner = match inner {
+            EasingFunction::Precomputed(table) => table.inner.as_ref().clone(),
+            other => other,
+        };
+        let count = samples.max(2);
+
+        let samples: Arc<[f32]> = (0..count)
+            .map(|i| inner.apply(i as f32 / (count - 1) as f32))
+            .collect();
+        EasingFunction::Precomputed(EasingTable {
+            inner: Arc::new(inner),
+            samples,
+        })
+    }
+
     /// 计算缓动值
     pub fn apply(&self, t: f32) -> f32 {
         let t = t.clamp(0.0, 1.0);
@@ -65,6 +141,8 @@ impl EasingFunction {
                 let c3 = c1 + 1.0;
                 1.0 + c3 * (t - 1.0).powf(3.0) + c1 * (t - 1.0).powf(2.0)
             }
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => cubic_bezier_ease(*x1, *y1, *x2, *y2, t),
+            EasingFunction::Precomputed(table) => table.lookup(t),
         }
     }
 
@@ -95,6 +173,8 @@ impl EasingFunction {
             EasingFunction::BounceOut => "Bounce Out",
             EasingFunction::BackIn => "Back In",
             EasingFunction::BackOut => "Back Out",
+            EasingFunction::CubicBezier(..) => "Cubic Bezier",
+            EasingFunction::Precomputed(table) => table.inner.name(),
         }
     }
 }
@@ -118,6 +198,69 @@ fn bounce_out(t: f32) -> f32 {
     }
 }
 
+/// 计算贝塞尔曲线单个分量在参数 s 处的值（端点固定为 0 和 1）
+fn bezier_component(p1: f32, p2: f32, s: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+}
+
+/// 贝塞尔曲线单个分量对参数 s 的导数
+fn bezier_component_derivative(p1: f32, p2: f32, s: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+/// 三次贝塞尔缓动：先由 x 进度求解曲线参数 s，再计算对应的 y
+fn cubic_bezier_ease(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    const NEWTON_ITERATIONS: usize = 8;
+    const BISECTION_ITERATIONS: usize = 32;
+    const EPSILON: f32 = 1e-6;
+
+    if x <= 0.0 || x >= 1.0 {
+        return x;
+    }
+
+    let x1 = x1.clamp(0.0, 1.0);
+    let x2 = x2.clamp(0.0, 1.0);
+
+    // 牛顿迭代法
+    let mut s = x;
+    let mut solved = false;
+    for _ in 0..NEWTON_ITERATIONS {
+        let error = bezier_component(x1, x2, s) - x;
+        if error.abs() < EPSILON {
+            solved = true;
+            break;
+        }
+        let derivative = bezier_component_derivative(x1, x2, s);
+        if derivative.abs() < EPSILON {
+            break;
+        }
+        s -= error / derivative;
+    }
+
+    // 牛顿法未收敛时回退到二分法
+    if !solved || !(0.0..=1.0).contains(&s) {
+        let mut low = 0.0;
+        let mut high = 1.0;
+        s = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let value = bezier_component(x1, x2, s);
+            if (value - x).abs() < EPSILON {
+                break;
+            }
+            if value < x {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = (low + high) * 0.5;
+        }
+    }
+
+    bezier_component(y1, y2, s)
+}
+
 /// 自定义缓动函数
 pub struct CustomEasing {
     /// 控制点
@@ -235,6 +378,62 @@ mod tests {
         assert_eq!(EasingFunction::BounceOut.name(), "Bounce Out");
     }
 
+    #[test]
+    fn test_cubic_bezier_easing() {
+        // CSS ease-in-out: cubic-bezier(0.42, 0, 0.58, 1)
+        let easing = EasingFunction::cubic_bezier(0.42, 0.0, 0.58, 1.0);
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        assert!((easing.apply(0.5) - 0.5).abs() < 1e-4);
+
+        // 对称曲线：f(t) + f(1 - t) = 1
+        let a = easing.apply(0.25);
+        let b = easing.apply(0.75);
+        assert!((a + b - 1.0).abs() < 1e-4);
+        assert!(a < 0.25);
+
+        // 线性控制点应退化为线性缓动
+        let linear = EasingFunction::cubic_bezier(0.0, 0.0, 1.0, 1.0);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!((linear.apply(t) - t).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier_clamps_control_x() {
+        let easing = EasingFunction::cubic_bezier(-0.5, 0.0, 1.5, 1.0);
+        assert_eq!(easing, EasingFunction::CubicBezier(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(easing.name(), "Cubic Bezier");
+    }
+
+    #[test]
+    fn test_precomputed_easing() {
+        let inner = EasingFunction::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+        let table = EasingFunction::precomputed(inner.clone(), 256);
+        assert_eq!(table.name(), "Cubic Bezier");
+
+        // 端点与采样点精确，采样之间的插值误差很小
+        assert_eq!(table.apply(0.0), 0.0);
+        assert_eq!(table.apply(1.0), 1.0);
+        assert_eq!(table.apply(-1.0), 0.0);
+        for i in 0..=100 {
+            let t = i as f32 / 100.0;
+            assert!((table.apply(t) - inner.apply(t)).abs() < 1e-3);
+        }
+
+        // 再次预计算时采样原函数，而不是查找表
+        let EasingFunction::Precomputed(resampled) = EasingFunction::precomputed(table, 1) else {
+            panic!("expected a lookup table");
+        };
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(resampled.inner(), &inner);
+
+        // 克隆的缓动函数共用同一份采样数据
+        let copy = resampled.clone();
+        assert!(Arc::ptr_eq(&resampled.samples, &copy.samples));
+    }
+
     #[test]
     fn test_custom_easing() {
         let easing = CustomEasing::new()
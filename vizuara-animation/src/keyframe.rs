@@ -1,4 +1,7 @@
-use crate::{AnimationState, EasingFunction};
+use crate::{
+    scaled_elapsed, AnimationState, EasingFunction, PlaybackDirection, Seekable, TrackAnimation,
+    TrackValue,
+};
 use std::time::{Duration, Instant};
 
 /// 关键帧
@@ -34,12 +37,18 @@ pub struct KeyframeAnimation<T> {
     keyframes: Vec<Keyframe<T>>,
     /// 动画时长
     duration: Duration,
+    /// 播放方向；关键帧动画不循环，`Alternate` 等同于 `Forward`
+    direction: PlaybackDirection,
+    /// 播放速度倍率
+    speed: f32,
     /// 当前状态
     state: AnimationState,
     /// 开始时间
     start_time: Option<Instant>,
     /// 暂停时间
     pause_time: Option<Duration>,
+    /// 计时起点时已播放的动画时间（恢复或跳转后非零）
+    elapsed_base: Duration,
 }
 
 impl<T> KeyframeAnimation<T>
@@ -51,9 +60,12 @@ where
         Self {
             keyframes: Vec::new(),
             duration,
+            direction: PlaybackDirection::Forward,
+            speed: 1.0,
             state: AnimationState::NotStarted,
             start_time: None,
             pause_time: None,
+            elapsed_base: Duration::ZERO,
         }
     }
 
@@ -75,20 +87,31 @@ where
         self.add_keyframe(Keyframe::new(time, value).with_easing(easing))
     }
 
+    /// 设置播放方向
+    pub fn with_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// 设置播放速度倍率 (1.0 为原速，0 相当于暂停)，负值按 0 处理
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
     /// 开始动画
     pub fn start(&mut self) {
         self.state = AnimationState::Playing;
         self.start_time = Some(Instant::now());
         self.pause_time = None;
+        self.elapsed_base = Duration::ZERO;
     }
 
     /// 暂停动画
     pub fn pause(&mut self) {
         if self.state == AnimationState::Playing {
             self.state = AnimationState::Paused;
-            if let Some(start) = self.start_time {
-                self.pause_time = Some(start.elapsed());
-            }
+            self.pause_time = Some(self.elapsed());
         }
     }
 
@@ -96,9 +119,9 @@ where
     pub fn resume(&mut self) {
         if self.state == AnimationState::Paused {
             self.state = AnimationState::Playing;
-            if let Some(pause_duration) = self.pause_time {
-                self.start_time = Some(Instant::now() - pause_duration);
-                self.pause_time = None;
+            if let Some(pause_duration) = self.pause_time.take() {
+                self.start_time = Some(Instant::now());
+                self.elapsed_base = pause_duration;
             }
         }
     }
@@ -108,6 +131,31 @@ where
         self.state = AnimationState::NotStarted;
         self.start_time = None;
         self.pause_time = None;
+        self.elapsed_base = Duration::ZERO;
+    }
+
+    /// 跳转到指定时间（超出时长时截断）
+    ///
+    /// 播放中保持播放；其他状态下进入暂停并停在该时间点。跳转到末尾时直接进入 Completed 状态
+    pub fn seek(&mut self, time: Duration) {
+        let time = time.min(self.duration);
+        if time >= self.duration {
+            self.state = AnimationState::Completed;
+            return;
+        }
+
+        if self.state == AnimationState::Playing {
+            self.start_time = Some(Instant::now());
+            self.elapsed_base = time;
+        } else {
+            self.state = AnimationState::Paused;
+            self.pause_time = Some(time);
+        }
+    }
+
+    /// 跳转到指定进度 (0.0 到 1.0)
+    pub fn seek_fraction(&mut self, fraction: f32) {
+        self.seek(self.duration.mul_f32(fraction.clamp(0.0, 1.0)));
     }
 
     /// 获取当前状态
@@ -128,17 +176,19 @@ where
                 }
             }
             AnimationState::Playing => {
-                if let Some(start) = self.start_time {
-                    let elapsed = start.elapsed();
-                    let progress = elapsed.as_secs_f32() / self.duration.as_secs_f32();
-                    progress.clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
+                let progress = self.elapsed().as_secs_f32() / self.duration.as_secs_f32();
+                progress.clamp(0.0, 1.0)
             }
         }
     }
 
+    /// 播放中已经过的动画时间（已按播放速度换算）
+    fn elapsed(&self) -> Duration {
+        self.start_time.map_or(Duration::ZERO, |start| {
+            scaled_elapsed(start, self.elapsed_base, self.speed)
+        })
+    }
+
     /// 更新动画状态
     pub fn update(&mut self) {
         if self.state == AnimationState::Playing {
@@ -191,7 +241,7 @@ where
 
     /// 获取当前插值结果
     pub fn current_value(&self, lerp_fn: impl Fn(&T, &T, f32) -> T) -> Option<T> {
-        let progress = self.progress();
+        let progress = self.direction.apply(self.progress(), 0);
         self.interpolate_at(progress, lerp_fn)
     }
 
@@ -206,6 +256,16 @@ where
     }
 }
 
+impl<T: Clone> Seekable for KeyframeAnimation<T> {
+    fn duration(&self) -> Duration {
+        KeyframeAnimation::duration(self)
+    }
+
+    fn seek(&mut self, time: Duration) {
+        KeyframeAnimation::seek(self, time)
+    }
+}
+
 /// 数值类型的关键帧动画辅助实现
 impl KeyframeAnimation<f32> {
     /// 获取当前f32值
@@ -228,6 +288,30 @@ impl KeyframeAnimation<nalgebra::Point2<f32>> {
     }
 }
 
+impl TrackAnimation for KeyframeAnimation<f32> {
+    fn track_duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn sample(&self, t: f32) -> Option<TrackValue> {
+        self.f32_at(self.direction.apply(t, 0))
+            .map(TrackValue::Number)
+    }
+}
+
+impl TrackAnimation for KeyframeAnimation<nalgebra::Point2<f32>> {
+    fn track_duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn sample(&self, t: f32) -> Option<TrackValue> {
+        self.interpolate_at(self.direction.apply(t, 0), |from, to, t| {
+            nalgebra::Point2::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t)
+        })
+        .map(TrackValue::Point2)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,4 +476,30 @@ mod tests {
         assert_eq!(animation.f32_at(0.5).unwrap(), 42.0);
         assert_eq!(animation.f32_at(1.0).unwrap(), 42.0);
     }
+
+    #[test]
+    fn test_keyframe_animation_reverse() {
+        let animation = KeyframeAnimation::new(Duration::from_millis(1000))
+            .at(0.0, 0.0f32)
+            .at(1.0, 10.0f32)
+            .with_direction(PlaybackDirection::Reverse);
+
+        assert_eq!(animation.current_f32(), Some(10.0));
+        assert_eq!(animation.sample(0.25), Some(TrackValue::Number(7.5)));
+    }
+
+    #[test]
+    fn test_keyframe_animation_seek() {
+        let mut animation = KeyframeAnimation::new(Duration::from_millis(1000))
+            .at(0.0, 0.0f32)
+            .at(1.0, 10.0f32);
+
+        animation.seek(Duration::from_millis(300));
+        assert_eq!(animation.state(), AnimationState::Paused);
+        assert!((animation.current_f32().unwrap() - 3.0).abs() < 1e-4);
+
+        animation.seek_fraction(2.0);
+        assert_eq!(animation.state(), AnimationState::Completed);
+        assert_eq!(animation.current_f32(), Some(10.0));
+    }
 }
@@ -19,7 +19,7 @@ pub use keyframe::*;
 pub use timeline::*;
 pub use transition::*;
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// 动画状态
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -34,6 +34,55 @@ pub enum AnimationState {
     Completed,
 }
 
+/// 播放方向
+///
+/// `Alternate` 时每次循环为单程：第 1 次正向、第 2 次反向，依此类推，
+/// 因此 `looping(Some(2))` 恰好是一次往返；不循环时与 `Forward` 相同。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackDirection {
+    /// 正向播放
+    #[default]
+    Forward,
+    /// 反向播放，进度 t 映射为 1 - t
+    Reverse,
+    /// 每次循环交替方向（乒乓）
+    Alternate,
+}
+
+impl PlaybackDirection {
+    /// 将第 `iteration` 次循环（从 0 开始）中的时间进度换算为播放进度
+    pub fn apply(self, t: f32, iteration: u32) -> f32 {
+        match self {
+            PlaybackDirection::Forward => t,
+            PlaybackDirection::Reverse => 1.0 - t,
+            PlaybackDirection::Alternate => {
+                if iteration % 2 == 1 {
+                    1.0 - t
+                } else {
+                    t
+                }
+            }
+        }
+    }
+}
+
+/// 按播放速度换算自 `start` 起经过的动画时间
+///
+/// `base` 为计时起点时已播放的动画时间；`start` 之前（延迟期内）不推进，负速度按 0 处理
+pub(crate) fn scaled_elapsed(start: Instant, base: Duration, speed: f32) -> Duration {
+    let now = Instant::now();
+    if now < start {
+        return base;
+    }
+    base + now.duration_since(start).mul_f32(speed.max(0.0))
+}
+
+/// 动画完成回调
+pub type CompleteCallback = Box<dyn FnMut()>;
+
+/// 循环回调，参数为已完成的循环次数
+pub type LoopCallback = Box<dyn FnMut(u32)>;
+
 /// 动画系统的基础trait
 pub trait Animatable {
     /// 动画数据类型
@@ -49,6 +98,15 @@ pub trait Animatable {
     fn lerp(&self, from: &Self::Value, to: &Self::Value, t: f32) -> Self::Value;
 }
 
+/// 可跳转到任意时间点的动画，用于离线逐帧采样（如导出帧序列）
+pub trait Seekable {
+    /// 动画时长
+    fn duration(&self) -> Duration;
+
+    /// 跳转到指定时间
+    fn seek(&mut self, time: Duration);
+}
+
 /// 动画配置
 #[derive(Debug, Clone)]
 pub struct AnimationConfig {
@@ -62,6 +120,10 @@ pub struct AnimationConfig {
     pub loop_count: Option<u32>,
     /// 延迟时间
     pub delay: Duration,
+    /// 播放方向
+    pub direction: PlaybackDirection,
+    /// 播放速度倍率 (1.0 为原速，0 相当于暂停)，只作用于播放过程，不缩放延迟
+    pub speed: f32,
 }
 
 impl Default for AnimationConfig {
@@ -72,6 +134,8 @@ impl Default for AnimationConfig {
             looping: false,
             loop_count: None,
             delay: Duration::ZERO,
+            direction: PlaybackDirection::Forward,
+            speed: 1.0,
         }
     }
 }
@@ -99,6 +163,17 @@ impl AnimationConfig {
         self.loop_count = count;
         self
     }
+
+    pub fn with_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// 设置播放速度倍率，负值按 0 处理；反向播放请使用 [`PlaybackDirection`]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +202,23 @@ mod tests {
         assert_eq!(config.loop_count, Some(3));
     }
 
+    #[test]
+    fn test_animation_config_speed() {
+        let config = AnimationConfig::new(Duration::from_millis(500));
+        assert_eq!(config.speed, 1.0);
+        assert_eq!(config.clone().with_speed(2.0).speed, 2.0);
+        assert_eq!(config.with_speed(-1.0).speed, 0.0);
+    }
+
+    #[test]
+    fn test_playback_direction() {
+        assert_eq!(PlaybackDirection::Forward.apply(0.25, 1), 0.25);
+        assert_eq!(PlaybackDirection::Reverse.apply(0.25, 0), 0.75);
+        assert_eq!(PlaybackDirection::Alternate.apply(0.25, 0), 0.25);
+        assert_eq!(PlaybackDirection::Alternate.apply(0.25, 1), 0.75);
+        assert_eq!(PlaybackDirection::Alternate.apply(0.25, 2), 0.25);
+    }
+
     #[test]
     fn test_animation_state() {
         let state = AnimationState::NotStarted;
@@ -1,8 +1,88 @@
-use crate::{AnimationState, Transition};
+use crate::{scaled_elapsed, AnimationState, CompleteCallback, LoopCallback, Seekable, Transition};
+use std::collections::HashMap;
+use std::fmt;
 use std::time::{Duration, Instant};
 
+/// 轨道采样值
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackValue {
+    /// 数值
+    Number(f32),
+    /// 颜色
+    Color(vizuara_core::Color),
+    /// 2D点
+    Point2(nalgebra::Point2<f32>),
+}
+
+impl TrackValue {
+    /// 作为数值获取
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            TrackValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// 作为颜色获取
+    pub fn as_color(&self) -> Option<vizuara_core::Color> {
+        match self {
+            TrackValue::Color(color) => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// 作为2D点获取
+    pub fn as_point2(&self) -> Option<nalgebra::Point2<f32>> {
+        match self {
+            TrackValue::Point2(point) => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+/// 可放置在时间轴轨道上的动画
+pub trait TrackAnimation {
+    /// 轨道时长
+    fn track_duration(&self) -> Duration;
+
+    /// 在轨道局部进度 t (0.0 到 1.0) 处采样
+    fn sample(&self, t: f32) -> Option<TrackValue>;
+}
+
+/// 时间轴轨道
+struct TimelineTrack {
+    /// 轨道名称
+    name: String,
+    /// 相对于时间轴开始的偏移
+    start_offset: Duration,
+    /// 轨道动画
+    animation: Box<dyn TrackAnimation>,
+}
+
+impl TimelineTrack {
+    /// 将时间轴时间映射为轨道局部进度
+    ///
+    /// 偏移之前保持起始值，结束之后保持最终值
+    fn local_progress(&self, timeline_time: Duration) -> f32 {
+        let duration = self.animation.track_duration();
+        if timeline_time <= self.start_offset {
+            return 0.0;
+        }
+        if duration.is_zero() {
+            return 1.0;
+        }
+        let local = timeline_time - self.start_offset;
+        (local.as_secs_f32() / duration.as_secs_f32()).clamp(0.0, 1.0)
+    }
+}
+
 /// 时间轴 - 管理多个动画的同步播放
-#[derive(Debug)]
+///
+/// 通过 [`Timeline::add_track`] 添加的轨道按各自的偏移和时长映射到时间轴时间上：
+/// 偏移不同的轨道可以并行或顺序播放。轨道本身不独立循环，
+/// 时间轴循环时所有轨道随之从头开始。
+///
+/// 由于可以持有完成/循环回调闭包，`Timeline` 不实现 `Clone`
 pub struct Timeline {
     /// 时间轴开始时间
     start_time: Option<Instant>,
@@ -12,6 +92,48 @@ pub struct Timeline {
     duration: Duration,
     /// 暂停时间
     pause_time: Option<Duration>,
+    /// 计时起点时已播放的时间（恢复或跳转后非零）
+    elapsed_base: Duration,
+    /// 播放速度倍率
+    speed: f32,
+    /// 是否循环
+    looping: bool,
+    /// 循环次数 (None表示无限循环)
+    loop_count: Option<u32>,
+    /// 当前循环次数
+    current_loop: u32,
+    /// 完成回调
+    on_complete: Option<CompleteCallback>,
+    /// 循环回调
+    on_loop: Option<LoopCallback>,
+    /// 动画轨道
+    tracks: Vec<TimelineTrack>,
+}
+
+impl fmt::Debug for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeline")
+            .field("start_time", &self.start_time)
+            .field("state", &self.state)
+            .field("duration", &self.duration)
+            .field("pause_time", &self.pause_time)
+            .field("elapsed_base", &self.elapsed_base)
+            .field("speed", &self.speed)
+            .field("looping", &self.looping)
+            .field("loop_count", &self.loop_count)
+            .field("current_loop", &self.current_loop)
+            .field("on_complete", &self.on_complete.is_some())
+            .field("on_loop", &self.on_loop.is_some())
+            .field(
+                "tracks",
+                &self
+                    .tracks
+                    .iter()
+                    .map(|t| t.name.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
 }
 
 impl Timeline {
@@ -22,23 +144,101 @@ impl Timeline {
             state: AnimationState::NotStarted,
             duration,
             pause_time: None,
+            elapsed_base: Duration::ZERO,
+            speed: 1.0,
+            looping: false,
+            loop_count: None,
+            current_loop: 0,
+            on_complete: None,
+            on_loop: None,
+            tracks: Vec::new(),
         }
     }
 
+    /// 添加动画轨道
+    ///
+    /// 轨道在 `start_offset` 时开始播放；若轨道结束时间超出时间轴时长，时间轴会自动延长
+    pub fn add_track(
+        mut self,
+        name: impl Into<String>,
+        start_offset: Duration,
+        animation: Box<dyn TrackAnimation>,
+    ) -> Self {
+        let end = start_offset + animation.track_duration();
+        self.duration = self.duration.max(end);
+        self.tracks.push(TimelineTrack {
+            name: name.into(),
+            start_offset,
+            animation,
+        });
+        self
+    }
+
+    /// 获取轨道数量
+    pub fn track_count(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// 采样指定名称的轨道
+    pub fn sample(&self, name: &str) -> Option<TrackValue> {
+        let time = self.current_time();
+        self.tracks
+            .iter()
+            .find(|track| track.name == name)
+            .and_then(|track| track.animation.sample(track.local_progress(time)))
+    }
+
+    /// 采样所有轨道的当前值，按轨道名称索引
+    pub fn sample_all(&self) -> HashMap<String, TrackValue> {
+        let time = self.current_time();
+        self.tracks
+            .iter()
+            .filter_map(|track| {
+                track
+                    .animation
+                    .sample(track.local_progress(time))
+                    .map(|value| (track.name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// 设置循环播放 (None表示无限循环)
+    pub fn looping(mut self, count: Option<u32>) -> Self {
+        self.looping = true;
+        self.loop_count = count;
+        self
+    }
+
+    /// 设置播放速度倍率 (1.0 为原速，0 相当于暂停)，负值按 0 处理
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// 注册完成回调，在 `update()` 中时间轴进入 Completed 状态时调用
+    pub fn on_complete(&mut self, callback: CompleteCallback) {
+        self.on_complete = Some(callback);
+    }
+
+    /// 注册循环回调，在 `update()` 中每完成一次循环时调用
+    pub fn on_loop(&mut self, callback: LoopCallback) {
+        self.on_loop = Some(callback);
+    }
+
     /// 开始时间轴
     pub fn start(&mut self) {
         self.state = AnimationState::Playing;
         self.start_time = Some(Instant::now());
         self.pause_time = None;
+        self.elapsed_base = Duration::ZERO;
+        self.current_loop = 0;
     }
 
     /// 暂停时间轴
     pub fn pause(&mut self) {
         if self.state == AnimationState::Playing {
             self.state = AnimationState::Paused;
-            if let Some(start) = self.start_time {
-                self.pause_time = Some(start.elapsed());
-            }
+            self.pause_time = Some(self.elapsed());
         }
     }
 
@@ -46,9 +246,9 @@ impl Timeline {
     pub fn resume(&mut self) {
         if self.state == AnimationState::Paused {
             self.state = AnimationState::Playing;
-            if let Some(pause_duration) = self.pause_time {
-                self.start_time = Some(Instant::now() - pause_duration);
-                self.pause_time = None;
+            if let Some(pause_duration) = self.pause_time.take() {
+                self.start_time = Some(Instant::now());
+                self.elapsed_base = pause_duration;
             }
         }
     }
@@ -58,6 +258,8 @@ impl Timeline {
         self.state = AnimationState::NotStarted;
         self.start_time = None;
         self.pause_time = None;
+        self.elapsed_base = Duration::ZERO;
+        self.current_loop = 0;
     }
 
     /// 获取当前状态
@@ -65,6 +267,11 @@ impl Timeline {
         self.state
     }
 
+    /// 获取当前循环次数
+    pub fn current_loop(&self) -> u32 {
+        self.current_loop
+    }
+
     /// 获取当前时间轴进度 (0.0 到 1.0)
     pub fn progress(&self) -> f32 {
         match self.state {
@@ -78,17 +285,19 @@ impl Timeline {
                 }
             }
             AnimationState::Playing => {
-                if let Some(start) = self.start_time {
-                    let elapsed = start.elapsed();
-                    let progress = elapsed.as_secs_f32() / self.duration.as_secs_f32();
-                    progress.clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
+                let progress = self.elapsed().as_secs_f32() / self.duration.as_secs_f32();
+                progress.clamp(0.0, 1.0)
             }
         }
     }
 
+    /// 播放中已经过的动画时间（已按播放速度换算）
+    fn elapsed(&self) -> Duration {
+        self.start_time.map_or(Duration::ZERO, |start| {
+            scaled_elapsed(start, self.elapsed_base, self.speed)
+        })
+    }
+
     /// 获取当前时间 (相对于时间轴开始)
     pub fn current_time(&self) -> Duration {
         let progress = self.progress();
@@ -100,29 +309,83 @@ impl Timeline {
         if self.state == AnimationState::Playing {
             let progress = self.progress();
             if progress >= 1.0 {
-                self.state = AnimationState::Completed;
+                if self.looping {
+                    self.current_loop += 1;
+                    if let Some(callback) = self.on_loop.as_mut() {
+                        callback(self.current_loop);
+                    }
+
+                    let finished = self
+                        .loop_count
+                        .is_some_and(|max_loops| self.current_loop >= max_loops);
+                    if !finished {
+                        self.start_time = Some(Instant::now());
+                        self.elapsed_base = Duration::ZERO;
+                        return;
+                    }
+                }
+
+                self.complete();
             }
         }
     }
 
-    /// 设置时间轴到指定进度
-    pub fn seek(&mut self, progress: f32) {
-        let progress = progress.clamp(0.0, 1.0);
-        let target_time = Duration::from_secs_f32(progress * self.duration.as_secs_f32());
+    /// 进入完成状态并触发完成回调
+    fn complete(&mut self) {
+        self.state = AnimationState::Completed;
+        if let Some(callback) = self.on_complete.as_mut() {
+            callback();
+        }
+    }
+
+    /// 跳转到指定时间（超出时长时截断）
+    ///
+    /// 播放中保持播放；其他状态下进入暂停并停在该时间点。
+    /// 不循环的时间轴跳转到末尾时直接进入 Completed 状态
+    pub fn seek_to(&mut self, time: Duration) {
+        let time = time.min(self.duration);
+        if time >= self.duration && !self.looping {
+            if self.state != AnimationState::Completed {
+                self.complete();
+            }
+            return;
+        }
 
         if self.state == AnimationState::Playing {
-            self.start_time = Some(Instant::now() - target_time);
+            self.start_time = Some(Instant::now());
+            self.elapsed_base = time;
         } else {
-            self.pause_time = Some(target_time);
+            self.state = AnimationState::Paused;
+            self.pause_time = Some(time);
         }
     }
 
+    /// 设置时间轴到指定进度 (0.0 到 1.0)
+    pub fn seek(&mut self, progress: f32) {
+        self.seek_to(self.duration.mul_f32(progress.clamp(0.0, 1.0)));
+    }
+
+    /// 跳转到指定进度 (0.0 到 1.0)，与 [`seek`](Self::seek) 相同
+    pub fn seek_fraction(&mut self, progress: f32) {
+        self.seek(progress);
+    }
+
     /// 获取时间轴总时长
     pub fn duration(&self) -> Duration {
         self.duration
     }
 }
 
+impl Seekable for Timeline {
+    fn duration(&self) -> Duration {
+        Timeline::duration(self)
+    }
+
+    fn seek(&mut self, time: Duration) {
+        Timeline::seek_to(self, time)
+    }
+}
+
 /// 动画序列 - 按顺序播放多个动画
 #[derive(Debug)]
 pub struct AnimationSequence<T> {
@@ -410,6 +673,121 @@ mod tests {
         assert!((progress - 0.5).abs() < 0.1);
     }
 
+    #[test]
+    fn test_timeline_seek_paused() {
+        let mut timeline = Timeline::new(Duration::from_millis(1000)).add_track(
+            "value",
+            Duration::ZERO,
+            Box::new(
+                crate::KeyframeAnimation::new(Duration::from_millis(1000))
+                    .at(0.0, 0.0f32)
+                    .at(1.0, 100.0f32),
+            ),
+        );
+        timeline.start();
+        timeline.pause();
+
+        timeline.seek_to(Duration::from_millis(400));
+        assert_eq!(timeline.state(), AnimationState::Paused);
+        assert_eq!(timeline.current_time().as_millis(), 400);
+        let value = timeline.sample("value").unwrap().as_f32().unwrap();
+        assert!((value - 40.0).abs() < 1e-3);
+
+        timeline.seek_fraction(0.6);
+        assert_eq!(timeline.current_time().as_millis(), 600);
+
+        timeline.seek_to(Duration::from_secs(3));
+        assert_eq!(timeline.state(), AnimationState::Completed);
+        assert_eq!(timeline.sample("value"), Some(TrackValue::Number(100.0)));
+    }
+
+    #[test]
+    fn test_timeline_speed() {
+        let mut timeline = Timeline::new(Duration::from_millis(1000)).with_speed(0.5);
+        timeline.start();
+        timeline.start_time = Some(Instant::now() - Duration::from_millis(400));
+        assert!((timeline.progress() - 0.2).abs() < 0.05);
+
+        // 暂停/恢复后从暂停位置继续，不受速度影响
+        timeline.pause();
+        let paused = timeline.progress();
+        timeline.resume();
+        assert!((timeline.progress() - paused).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_timeline_callbacks() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let mut timeline = Timeline::new(Duration::from_millis(100)).looping(Some(2));
+        let loops = Rc::new(Cell::new(0));
+        let completed = Rc::new(Cell::new(false));
+
+        let loop_counter = loops.clone();
+        timeline.on_loop(Box::new(move |n| loop_counter.set(n)));
+        let completed_flag = completed.clone();
+        timeline.on_complete(Box::new(move || completed_flag.set(true)));
+
+        timeline.start();
+        timeline.start_time = Some(Instant::now() - Duration::from_millis(150));
+        timeline.update();
+        assert_eq!(loops.get(), 1);
+        assert_eq!(timeline.state(), AnimationState::Playing);
+        assert!(!completed.get());
+
+        timeline.start_time = Some(Instant::now() - Duration::from_millis(150));
+        timeline.update();
+        assert_eq!(loops.get(), 2);
+        assert_eq!(timeline.state(), AnimationState::Completed);
+        assert!(completed.get());
+    }
+
+    #[test]
+    fn test_timeline_tracks() {
+        let mut timeline = Timeline::new(Duration::from_millis(100))
+            .add_track(
+                "opacity",
+                Duration::ZERO,
+                Box::new(Transition::simple(
+                    0.0f32,
+                    1.0f32,
+                    Duration::from_millis(1000),
+                )),
+            )
+            .add_track(
+                "position",
+                Duration::from_millis(1000),
+                Box::new(
+                    crate::KeyframeAnimation::new(Duration::from_millis(1000))
+                        .at(0.0, 0.0f32)
+                        .at(1.0, 100.0f32),
+                ),
+            );
+
+        // 时间轴时长被轨道延长
+        assert_eq!(timeline.duration(), Duration::from_millis(2000));
+        assert_eq!(timeline.track_count(), 2);
+
+        timeline.start();
+        timeline.seek(0.25);
+        let values = timeline.sample_all();
+        assert_eq!(values.len(), 2);
+        // 第一条轨道进行到一半（EaseInOut在0.5处为0.5）
+        assert!((values["opacity"].as_f32().unwrap() - 0.5).abs() < 0.05);
+        // 第二条轨道尚未开始，保持起始值
+        assert_eq!(values["position"].as_f32(), Some(0.0));
+
+        timeline.seek(0.75);
+        assert_eq!(
+            timeline.sample("opacity").and_then(|v| v.as_f32()),
+            Some(1.0)
+        );
+        let position = timeline.sample("position").unwrap().as_f32().unwrap();
+        assert!((position - 50.0).abs() < 5.0);
+        assert!(timeline.sample("missing").is_none());
+    }
+
     #[test]
     fn test_animation_sequence() {
         let mut sequence = AnimationSequence::new()
@@ -1,8 +1,13 @@
-use crate::{AnimationConfig, AnimationState};
+use crate::{
+    scaled_elapsed, AnimationConfig, AnimationState, CompleteCallback, LoopCallback, Seekable,
+    TrackAnimation, TrackValue,
+};
+use std::fmt;
 use std::time::{Duration, Instant};
 
 /// 过渡动画
-#[derive(Debug)]
+///
+/// 由于可以持有完成/循环回调闭包，`Transition` 不实现 `Clone`
 pub struct Transition<T> {
     /// 起始值
     from: T,
@@ -16,8 +21,31 @@ pub struct Transition<T> {
     start_time: Option<Instant>,
     /// 暂停时间
     pause_time: Option<Duration>,
+    /// 计时起点时已播放的动画时间（恢复或跳转后非零）
+    elapsed_base: Duration,
     /// 当前循环次数
     current_loop: u32,
+    /// 完成回调
+    on_complete: Option<CompleteCallback>,
+    /// 循环回调
+    on_loop: Option<LoopCallback>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Transition<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transition")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("config", &self.config)
+            .field("state", &self.state)
+            .field("start_time", &self.start_time)
+            .field("pause_time", &self.pause_time)
+            .field("elapsed_base", &self.elapsed_base)
+            .field("current_loop", &self.current_loop)
+            .field("on_complete", &self.on_complete.is_some())
+            .field("on_loop", &self.on_loop.is_some())
+            .finish()
+    }
 }
 
 impl<T> Transition<T>
@@ -33,7 +61,10 @@ where
             state: AnimationState::NotStarted,
             start_time: None,
             pause_time: None,
+            elapsed_base: Duration::ZERO,
             current_loop: 0,
+            on_complete: None,
+            on_loop: None,
         }
     }
 
@@ -42,6 +73,16 @@ where
         Self::new(from, to, AnimationConfig::new(duration))
     }
 
+    /// 注册完成回调，在 `update()` 中动画进入 Completed 状态时调用
+    pub fn on_complete(&mut self, callback: CompleteCallback) {
+        self.on_complete = Some(callback);
+    }
+
+    /// 注册循环回调，在 `update()` 中每完成一次循环时调用
+    pub fn on_loop(&mut self, callback: LoopCallback) {
+        self.on_loop = Some(callback);
+    }
+
     /// 开始动画
     pub fn start(&mut self) {
         if self.config.delay.is_zero() {
@@ -52,6 +93,7 @@ where
             self.start_time = Some(Instant::now() + self.config.delay);
         }
         self.pause_time = None;
+        self.elapsed_base = Duration::ZERO;
         self.current_loop = 0;
     }
 
@@ -59,12 +101,7 @@ where
     pub fn pause(&mut self) {
         if self.state == AnimationState::Playing {
             self.state = AnimationState::Paused;
-            if let Some(start) = self.start_time {
-                let now = Instant::now();
-                if now >= start {
-                    self.pause_time = Some(now.duration_since(start));
-                }
-            }
+            self.pause_time = Some(self.elapsed());
         }
     }
 
@@ -72,9 +109,9 @@ where
     pub fn resume(&mut self) {
         if self.state == AnimationState::Paused {
             self.state = AnimationState::Playing;
-            if let Some(pause_duration) = self.pause_time {
-                self.start_time = Some(Instant::now() - pause_duration);
-                self.pause_time = None;
+            if let Some(pause_duration) = self.pause_time.take() {
+                self.start_time = Some(Instant::now());
+                self.elapsed_base = pause_duration;
             }
         }
     }
@@ -84,9 +121,37 @@ where
         self.state = AnimationState::NotStarted;
         self.start_time = None;
         self.pause_time = None;
+        self.elapsed_base = Duration::ZERO;
         self.current_loop = 0;
     }
 
+    /// 跳转到指定时间（超出时长时截断），尚未结束的延迟被跳过
+    ///
+    /// 播放中保持播放；其他状态下进入暂停并停在该时间点。
+    /// 不循环的动画跳转到末尾时直接进入 Completed 状态
+    pub fn seek(&mut self, time: Duration) {
+        let time = time.min(self.config.duration);
+        if time >= self.config.duration && !self.config.looping {
+            if self.state != AnimationState::Completed {
+                self.complete();
+            }
+            return;
+        }
+
+        if self.state == AnimationState::Playing {
+            self.start_time = Some(Instant::now());
+            self.elapsed_base = time;
+        } else {
+            self.state = AnimationState::Paused;
+            self.pause_time = Some(time);
+        }
+    }
+
+    /// 跳转到指定进度 (0.0 到 1.0)
+    pub fn seek_fraction(&mut self, fraction: f32) {
+        self.seek(self.config.duration.mul_f32(fraction.clamp(0.0, 1.0)));
+    }
+
     /// 重置动画到目标值
     pub fn reset(&mut self, from: T, to: T) {
         self.from = from;
@@ -113,32 +178,40 @@ where
                 }
             }
             AnimationState::Playing => {
-                if let Some(start) = self.start_time {
-                    let now = Instant::now();
-                    if now < start {
-                        // 还在延迟期
-                        return 0.0;
-                    }
-
-                    let elapsed = now.duration_since(start);
-                    let progress = elapsed.as_secs_f32() / self.config.duration.as_secs_f32();
-                    progress.clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
+                let progress = self.elapsed().as_secs_f32() / self.config.duration.as_secs_f32();
+                progress.clamp(0.0, 1.0)
             }
         }
     }
 
+    /// 播放中已经过的动画时间（已按播放速度换算，延迟期内为零）
+    fn elapsed(&self) -> Duration {
+        self.start_time.map_or(Duration::ZERO, |start| {
+            scaled_elapsed(start, self.elapsed_base, self.config.speed)
+        })
+    }
+
     /// 获取原始进度（未应用缓动）
     pub fn raw_progress(&self) -> f32 {
         self.progress()
     }
 
-    /// 获取缓动后的进度
+    /// 获取缓动后的进度（已按播放方向换算）
     pub fn eased_progress(&self) -> f32 {
-        let raw = self.progress();
-        self.config.easing.apply(raw)
+        let directed = self
+            .config
+            .direction
+            .apply(self.progress(), self.iteration());
+        self.config.easing.apply(directed)
+    }
+
+    /// 当前所在的循环序号（从 0 开始）；完成后为最后一次循环
+    fn iteration(&self) -> u32 {
+        if self.state == AnimationState::Completed {
+            self.current_loop.saturating_sub(1)
+        } else {
+            self.current_loop
+        }
     }
 
     /// 更新动画状态
@@ -156,19 +229,23 @@ where
                 if progress >= 1.0 {
                     if self.config.looping {
                         self.current_loop += 1;
+                        if let Some(callback) = self.on_loop.as_mut() {
+                            callback(self.current_loop);
+                        }
 
                         // 检查是否达到循环次数限制
                         if let Some(max_loops) = self.config.loop_count {
                             if self.current_loop >= max_loops {
-                                self.state = AnimationState::Completed;
+                                self.complete();
                                 return;
                             }
                         }
 
                         // 重启动画
                         self.start_time = Some(Instant::now());
+                        self.elapsed_base = Duration::ZERO;
                     } else {
-                        self.state = AnimationState::Completed;
+                        self.complete();
                     }
                 }
             }
@@ -176,6 +253,14 @@ where
         }
     }
 
+    /// 进入完成状态并触发完成回调
+    fn complete(&mut self) {
+        self.state = AnimationState::Completed;
+        if let Some(callback) = self.on_complete.as_mut() {
+            callback();
+        }
+    }
+
     /// 获取当前插值结果
     pub fn current_value(&self, lerp_fn: impl Fn(&T, &T, f32) -> T) -> T {
         let t = self.eased_progress();
@@ -197,7 +282,7 @@ where
         self.state == AnimationState::Playing
     }
 
-    /// 获取剩余时间
+    /// 获取剩余时间（按播放速度换算为实际时间，速度为 0 时为 `Duration::MAX`）
     pub fn remaining_time(&self) -> Duration {
         if self.state != AnimationState::Playing {
             return Duration::ZERO;
@@ -207,9 +292,14 @@ where
         if progress >= 1.0 {
             return Duration::ZERO;
         }
+        if self.config.speed <= 0.0 {
+            return Duration::MAX;
+        }
 
         let remaining_progress = 1.0 - progress;
-        Duration::from_secs_f32(remaining_progress * self.config.duration.as_secs_f32())
+        Duration::from_secs_f32(
+            remaining_progress * self.config.duration.as_secs_f32() / self.config.speed,
+        )
     }
 
     /// 获取动画配置
@@ -223,6 +313,16 @@ where
     }
 }
 
+impl<T: Clone> Seekable for Transition<T> {
+    fn duration(&self) -> Duration {
+        Transition::duration(self)
+    }
+
+    fn seek(&mut self, time: Duration) {
+        Transition::seek(self, time)
+    }
+}
+
 /// f32类型的过渡动画特化实现
 impl Transition<f32> {
     /// 获取当前f32值
@@ -263,10 +363,54 @@ impl Transition<nalgebra::Point2<f32>> {
     }
 }
 
+// 作为时间轴轨道时仅使用过渡的时长、方向和缓动，延迟与循环由时间轴决定
+impl TrackAnimation for Transition<f32> {
+    fn track_duration(&self) -> Duration {
+        self.config.duration
+    }
+
+    fn sample(&self, t: f32) -> Option<TrackValue> {
+        let t = self.config.easing.apply(self.config.direction.apply(t, 0));
+        Some(TrackValue::Number(self.from + (self.to - self.from) * t))
+    }
+}
+
+impl TrackAnimation for Transition<vizuara_core::Color> {
+    fn track_duration(&self) -> Duration {
+        self.config.duration
+    }
+
+    fn sample(&self, t: f32) -> Option<TrackValue> {
+        let t = self.config.easing.apply(self.config.direction.apply(t, 0));
+        let (from, to) = (&self.from, &self.to);
+        Some(TrackValue::Color(vizuara_core::Color::new(
+            from.r + (to.r - from.r) * t,
+            from.g + (to.g - from.g) * t,
+            from.b + (to.b - from.b) * t,
+            from.a + (to.a - from.a) * t,
+        )))
+    }
+}
+
+impl TrackAnimation for Transition<nalgebra::Point2<f32>> {
+    fn track_duration(&self) -> Duration {
+        self.config.duration
+    }
+
+    fn sample(&self, t: f32) -> Option<TrackValue> {
+        let t = self.config.easing.apply(self.config.direction.apply(t, 0));
+        let (from, to) = (&self.from, &self.to);
+        Some(TrackValue::Point2(nalgebra::Point2::new(
+            from.x + (to.x - from.x) * t,
+            from.y + (to.y - from.y) * t,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::EasingFunction;
+    use crate::{EasingFunction, PlaybackDirection};
     use std::thread;
 
     #[test]
@@ -395,6 +539,120 @@ mod tests {
         assert!(transition.is_completed() || transition.current_loop() >= 2);
     }
 
+    #[test]
+    fn test_transition_direction() {
+        let config = AnimationConfig::new(Duration::from_millis(50))
+            .with_direction(PlaybackDirection::Reverse);
+        let mut reverse = Transition::new(0.0f32, 100.0f32, config);
+        assert_eq!(reverse.current_f32(), 100.0);
+
+        reverse.start();
+        thread::sleep(Duration::from_millis(70));
+        reverse.update();
+        assert!(reverse.is_completed());
+        assert_eq!(reverse.current_f32(), 0.0);
+
+        // 乒乓：两次循环为一次往返，结束时回到起点
+        let config = AnimationConfig::new(Duration::from_millis(50))
+            .looping(Some(2))
+            .with_direction(PlaybackDirection::Alternate);
+        let mut alternate = Transition::new(0.0f32, 100.0f32, config);
+        alternate.start();
+        thread::sleep(Duration::from_millis(70));
+        alternate.update();
+        assert_eq!(alternate.current_loop(), 1);
+        assert!(alternate.eased_progress() > 0.5);
+
+        thread::sleep(Duration::from_millis(70));
+        alternate.update();
+        assert!(alternate.is_completed());
+        assert_eq!(alternate.current_f32(), 0.0);
+    }
+
+    #[test]
+    fn test_transition_seek() {
+        let mut transition = Transition::new(
+            0.0f32,
+            100.0f32,
+            AnimationConfig::new(Duration::from_millis(1000))
+                .with_easing(EasingFunction::Linear)
+                .with_delay(Duration::from_secs(10)),
+        );
+
+        // 未开始时跳转进入暂停并跳过延迟
+        transition.seek(Duration::from_millis(250));
+        assert_eq!(transition.state(), AnimationState::Paused);
+        assert_eq!(transition.current_f32(), 25.0);
+
+        transition.resume();
+        transition.seek_fraction(0.5);
+        assert!(transition.is_playing());
+        assert!((transition.current_f32() - 50.0).abs() < 5.0);
+
+        transition.seek(Duration::from_secs(5));
+        assert!(transition.is_completed());
+        assert_eq!(transition.current_f32(), 100.0);
+
+        // 已完成后可以跳回
+        transition.seek_fraction(0.1);
+        assert_eq!(transition.state(), AnimationState::Paused);
+        assert!((transition.current_f32() - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_transition_speed() {
+        let config = AnimationConfig::new(Duration::from_millis(1000))
+            .with_easing(EasingFunction::Linear)
+            .with_speed(2.0);
+        let mut transition = Transition::new(0.0f32, 100.0f32, config);
+        transition.start();
+        transition.start_time = Some(Instant::now() - Duration::from_millis(250));
+        assert!((transition.progress() - 0.5).abs() < 0.05);
+        assert!(transition.remaining_time() <= Duration::from_millis(250));
+
+        // 速度为 0 时停在跳转位置
+        let config = AnimationConfig::new(Duration::from_millis(1000)).with_speed(0.0);
+        let mut frozen = Transition::new(0.0f32, 100.0f32, config);
+        frozen.start();
+        frozen.seek_fraction(0.3);
+        frozen.resume();
+        thread::sleep(Duration::from_millis(20));
+        assert!(frozen.is_playing());
+        assert!((frozen.progress() - 0.3).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_transition_callbacks() {
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let config = AnimationConfig::new(Duration::from_millis(100)).looping(Some(2));
+        let mut transition = Transition::new(0.0f32, 100.0f32, config);
+
+        let completed = Rc::new(Cell::new(false));
+        let loops = Rc::new(RefCell::new(Vec::new()));
+
+        let completed_flag = completed.clone();
+        transition.on_complete(Box::new(move || completed_flag.set(true)));
+        let loop_log = loops.clone();
+        transition.on_loop(Box::new(move |n| loop_log.borrow_mut().push(n)));
+
+        transition.start();
+
+        // 模拟第一轮循环结束
+        transition.start_time = Some(Instant::now() - Duration::from_millis(150));
+        transition.update();
+        assert_eq!(*loops.borrow(), vec![1]);
+        assert!(!completed.get());
+
+        // 模拟第二轮循环结束
+        transition.start_time = Some(Instant::now() - Duration::from_millis(150));
+        transition.update();
+        assert_eq!(*loops.borrow(), vec![1, 2]);
+        assert!(completed.get());
+        assert!(transition.is_completed());
+    }
+
     #[test]
     fn test_transition_reset() {
         let mut transition = Transition::simple(0.0f32, 100.0f32, Duration::from_millis(1000));
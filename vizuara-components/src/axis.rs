@@ -1,5 +1,7 @@
 use nalgebra::Point2;
-use vizuara_core::{Color, LinearScale, Primitive, Scale};
+use vizuara_core::{
+    AxisBreak, Color, GridStyle, LinearScale, LogScale, Primitive, Scale, TickFormatter, TimeScale,
+};
 
 /// 坐标轴方向
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -18,6 +20,11 @@ pub struct Axis {
     title: Option<String>,
     tick_count: usize,
     style: AxisStyle,
+    opposite: bool, // 刻度与标签是否画在轴线另一侧（垂直轴为右侧）
+    log_scale: Option<LogScale>,
+    time_scale: Option<TimeScale>,
+    tick_formatter: Option<TickFormatter>,
+    label_rotation: f32, // 刻度标签旋转角度（度，顺时针为正）
 }
 
 /// 坐标轴样式
@@ -60,6 +67,11 @@ impl Axis {
             title: None,
             tick_count: 5,
             style: AxisStyle::default(),
+            opposite: false,
+            log_scale: None,
+            time_scale: None,
+            tick_formatter: None,
+            label_rotation: 0.0,
         }
     }
 
@@ -81,17 +93,164 @@ impl Axis {
         self
     }
 
+    /// 将刻度与标签画在轴线另一侧（垂直轴为右侧，水平轴为下侧）
+    pub fn opposite_side(mut self, opposite: bool) -> Self {
+        self.opposite = opposite;
+        self
+    }
+
+    /// 使用对数比例尺决定刻度、标签与位置，轴的线性比例尺替换为相同定义域的比例尺
+    pub fn log_scale(mut self, scale: LogScale) -> Self {
+        self.scale = LinearScale::new(scale.domain_min, scale.domain_max);
+        self.log_scale = Some(scale);
+        self
+    }
+
+    /// 使用时间比例尺决定刻度与标签，轴的线性比例尺替换为其数据值空间中的等价比例尺
+    pub fn time_scale(mut self, scale: TimeScale) -> Self {
+        self.scale = scale.linear();
+        self.time_scale = Some(scale);
+        self
+    }
+
+    /// 设置刻度标签格式化器（默认保留一位小数，对数轴与时间轴使用其自身的格式）
+    pub fn tick_formatter(mut self, formatter: TickFormatter) -> Self {
+        self.tick_formatter = Some(formatter);
+        self
+    }
+
+    /// 设置刻度标签旋转角度（度，顺时针为正），常用 -45° 避免水平轴上较长的类别标签重叠
+    ///
+    /// 水平轴的旋转标签以靠近刻度的一端对齐到刻度，文本向远离轴线的方向展开
+    pub fn label_rotation(mut self, degrees: f32) -> Self {
+        self.label_rotation = degrees;
+        self
+    }
+
+    /// 获取比例尺
+    pub fn scale(&self) -> &LinearScale {
+        &self.scale
+    }
+
+    /// 获取标题
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// 获取对数比例尺（未使用对数轴时为 None）
+    pub fn get_log_scale(&self) -> Option<&LogScale> {
+        self.log_scale.as_ref()
+    }
+
+    /// 获取时间比例尺（未使用时间轴时为 None）
+    pub fn get_time_scale(&self) -> Option<&TimeScale> {
+        self.time_scale.as_ref()
+    }
+
+    /// 替换比例尺（保留位置、标题与样式）；时间轴的定义域随之更新，
+    /// 对数轴在新定义域为正时随之更新
+    pub fn set_scale(&mut self, scale: LinearScale) {
+        if let Some(time) = &self.time_scale {
+            self.time_scale = Some(time.from_linear(&scale));
+        }
+        if let Some(log) = &mut self.log_scale {
+            if scale.domain_min > 0.0 && scale.domain_max > 0.0 {
+                log.domain_min = scale.domain_min;
+                log.domain_max = scale.domain_max;
+            }
+        }
+        self.scale = scale;
+    }
+
+    /// 替换位置与长度（保留比例尺、标题与样式）
+    pub fn set_placement(&mut self, position: (f32, f32), length: f32) {
+        self.position = position;
+        self.length = length;
+    }
+
+    /// 刻度在轴方向上的屏幕坐标（用于网格线）
+    pub fn tick_positions(&self) -> Vec<f32> {
+        self.ticks_with_labels()
+            .into_iter()
+            .map(|(value, _)| self.value_to_position(value))
+            .collect()
+    }
+
+    /// 生成网格线图元：主网格线位于刻度处，次网格线在数值上均分相邻刻度；
+    /// `span` 为网格线在垂直于轴方向上的起止屏幕坐标
+    pub fn grid_primitives(&self, style: &GridStyle, span: (f32, f32)) -> Vec<Primitive> {
+        let ticks: Vec<f32> = self
+            .ticks_with_labels()
+            .into_iter()
+            .map(|(value, _)| value)
+            .collect();
+        let mut primitives = Vec::new();
+
+        if style.show_minor && style.minor_divisions > 1 {
+            for pair in ticks.windows(2) {
+                let step = (pair[1] - pair[0]) / style.minor_divisions as f32;
+                for i in 1..style.minor_divisions {
+                    let position = self.value_to_position(pair[0] + step * i as f32);
+                    primitives.push(self.grid_line(
+                        position,
+                        span,
+                        style.minor_color,
+                        style.minor_width,
+                    ));
+                }
+            }
+        }
+
+        if style.show_major {
+            for &value in &ticks {
+                let position = self.value_to_position(value);
+                primitives.push(self.grid_line(
+                    position,
+                    span,
+                    style.major_color,
+                    style.major_width,
+                ));
+            }
+        }
+
+        primitives
+    }
+
+    /// 在轴方向坐标 position 处贯穿 span 的网格线
+    fn grid_line(&self, position: f32, span: (f32, f32), color: Color, width: f32) -> Primitive {
+        let points = match self.direction {
+            AxisDirection::Horizontal => {
+                vec![Point2::new(position, span.0), Point2::new(position, span.1)]
+            }
+            AxisDirection::Vertical => {
+                vec![Point2::new(span.0, position), Point2::new(span.1, position)]
+            }
+        };
+        Primitive::Polyline {
+            points,
+            color,
+            width,
+        }
+    }
+
     /// 生成坐标轴的渲染图元
     pub fn generate_primitives(&self) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
-        // 1. 绘制主轴线
-        let (start, end) = self.axis_line_points();
-        primitives.push(Primitive::Line { start, end });
+        // 1. 绘制主轴线，坐标轴断裂处以折线标记代替
+        let breaks = match self.log_scale {
+            Some(_) => Vec::new(),
+            None => self.scale.effective_breaks(),
+        };
+        if breaks.is_empty() {
+            let (start, end) = self.axis_line_points();
+            primitives.push(Primitive::Line { start, end });
+        } else {
+            primitives.extend(self.broken_axis_primitives(&breaks));
+        }
 
         // 2. 生成刻度和标签
-        let ticks = self.scale.ticks(self.tick_count);
-        for &tick_value in &ticks {
+        for (tick_value, label_text) in self.ticks_with_labels() {
             let position = self.value_to_position(tick_value);
 
             // 刻度线
@@ -103,20 +262,15 @@ impl Axis {
 
             // 刻度标签
             let label_position = self.label_position(position);
-            let label_text = format!("{:.1}", tick_value);
+            let (h_align, v_align) = self.label_align();
             primitives.push(Primitive::Text {
                 position: label_position,
                 content: label_text,
                 size: self.style.label_size,
                 color: self.style.label_color,
-                h_align: match self.direction {
-                    AxisDirection::Horizontal => vizuara_core::HorizontalAlign::Center,
-                    AxisDirection::Vertical => vizuara_core::HorizontalAlign::Right,
-                },
-                v_align: match self.direction {
-                    AxisDirection::Horizontal => vizuara_core::VerticalAlign::Top,
-                    AxisDirection::Vertical => vizuara_core::VerticalAlign::Middle,
-                },
+                h_align,
+                v_align,
+                rotation: self.label_rotation,
             });
         }
 
@@ -128,20 +282,103 @@ impl Axis {
                 content: title.clone(),
                 size: self.style.title_size,
                 color: self.style.label_color,
-                h_align: match self.direction {
-                    AxisDirection::Horizontal => vizuara_core::HorizontalAlign::Center,
-                    AxisDirection::Vertical => vizuara_core::HorizontalAlign::Right,
-                },
+                h_align: self.text_h_align(),
                 v_align: match self.direction {
                     AxisDirection::Horizontal => vizuara_core::VerticalAlign::Top,
                     AxisDirection::Vertical => vizuara_core::VerticalAlign::Middle,
                 },
+                rotation: 0.0,
             });
         }
 
         primitives
     }
 
+    /// 计算刻度值及其标签文本
+    fn ticks_with_labels(&self) -> Vec<(f32, String)> {
+        if let Some(formatter) = &self.tick_formatter {
+            let ticks = match (&self.log_scale, &self.time_scale) {
+                (Some(log), _) => log.ticks(self.tick_count),
+                (None, Some(time)) => time.ticks(self.tick_count),
+                (None, None) => self.scale.ticks(self.tick_count),
+            };
+            return ticks
+                .into_iter()
+                .map(|tick| (tick, formatter.format(tick)))
+                .collect();
+        }
+
+        match (&self.log_scale, &self.time_scale) {
+            (Some(log), _) => {
+                let ticks = log.ticks(self.tick_count);
+                let labels = log.tick_labels(&ticks);
+                ticks.into_iter().zip(labels).collect()
+            }
+            (None, Some(time)) => {
+                let ticks = time.ticks(self.tick_count);
+                let labels = time.tick_labels(&ticks);
+                ticks.into_iter().zip(labels).collect()
+            }
+            (None, None) => self
+                .scale
+                .ticks(self.tick_count)
+                .into_iter()
+                .map(|tick| (tick, format!("{:.1}", tick)))
+                .collect(),
+        }
+    }
+
+    /// 带断裂的轴线：断裂间隙两侧为直线段，间隙内为锯齿形标记
+    fn broken_axis_primitives(&self, breaks: &[AxisBreak]) -> Vec<Primitive> {
+        let mut gaps: Vec<(f32, f32)> = breaks
+            .iter()
+            .map(|b| {
+                let (a, c) = (
+                    self.value_to_position(b.start),
+                    self.value_to_position(b.end),
+                );
+                (a.min(c), a.max(c))
+            })
+            .collect();
+        gaps.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let origin = match self.direction {
+            AxisDirection::Horizontal => self.position.0,
+            AxisDirection::Vertical => self.position.1,
+        };
+        let point = |along: f32, offset: f32| match self.direction {
+            AxisDirection::Horizontal => Point2::new(along, self.position.1 + offset),
+            AxisDirection::Vertical => Point2::new(self.position.0 + offset, along),
+        };
+
+        let mut primitives = Vec::new();
+        let mut segment_start = origin;
+        for &(gap_start, gap_end) in &gaps {
+            primitives.push(Primitive::Line {
+                start: point(segment_start, 0.0),
+                end: point(gap_start, 0.0),
+            });
+            let amplitude = self.style.tick_length;
+            primitives.push(Primitive::Polyline {
+                points: [0.0, amplitude, -amplitude, amplitude, 0.0]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &offset)| {
+                        point(gap_start + (gap_end - gap_start) * i as f32 / 4.0, offset)
+                    })
+                    .collect(),
+                color: self.style.axis_color,
+                width: 1.0,
+            });
+            segment_start = gap_end;
+        }
+        primitives.push(Primitive::Line {
+            start: point(segment_start, 0.0),
+            end: point(origin + self.length, 0.0),
+        });
+        primitives
+    }
+
     /// 计算轴线的起点和终点
     fn axis_line_points(&self) -> (Point2<f32>, Point2<f32>) {
         let (x, y) = self.position;
@@ -153,23 +390,64 @@ impl Axis {
 
     /// 将数据值转换为轴上的位置
     fn value_to_position(&self, value: f32) -> f32 {
-        let normalized = self.scale.normalize(value);
+        let normalized = match self.log_scale {
+            Some(ref log) => log.normalize(value),
+            None => self.scale.normalize(value),
+        };
         match self.direction {
             AxisDirection::Horizontal => self.position.0 + normalized * self.length,
             AxisDirection::Vertical => self.position.1 + normalized * self.length,
         }
     }
 
+    /// 刻度朝向：默认朝负方向，另一侧时朝正方向
+    fn outward(&self) -> f32 {
+        if self.opposite {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// 标签与标题的水平对齐方式
+    fn text_h_align(&self) -> vizuara_core::HorizontalAlign {
+        match self.direction {
+            AxisDirection::Horizontal => vizuara_core::HorizontalAlign::Center,
+            AxisDirection::Vertical if self.opposite => vizuara_core::HorizontalAlign::Left,
+            AxisDirection::Vertical => vizuara_core::HorizontalAlign::Right,
+        }
+    }
+
+    /// 刻度标签的对齐方式
+    ///
+    /// 旋转的水平轴标签改为垂直居中，并选择文本朝远离轴线方向展开的一端对齐到刻度：
+    /// 标签在轴线下方（outward 为正）时逆时针旋转右对齐、顺时针旋转左对齐，上方时相反
+    fn label_align(&self) -> (vizuara_core::HorizontalAlign, vizuara_core::VerticalAlign) {
+        match self.direction {
+            AxisDirection::Horizontal if self.label_rotation != 0.0 => {
+                let h_align = if (self.label_rotation < 0.0) == (self.outward() > 0.0) {
+                    vizuara_core::HorizontalAlign::Right
+                } else {
+                    vizuara_core::HorizontalAlign::Left
+                };
+                (h_align, vizuara_core::VerticalAlign::Middle)
+            }
+            AxisDirection::Horizontal => (self.text_h_align(), vizuara_core::VerticalAlign::Top),
+            AxisDirection::Vertical => (self.text_h_align(), vizuara_core::VerticalAlign::Middle),
+        }
+    }
+
     /// 计算刻度线的起点和终点
     fn tick_line_points(&self, position: f32) -> (Point2<f32>, Point2<f32>) {
+        let tick = self.outward() * self.style.tick_length;
         match self.direction {
             AxisDirection::Horizontal => (
                 Point2::new(position, self.position.1),
-                Point2::new(position, self.position.1 - self.style.tick_length),
+                Point2::new(position, self.position.1 + tick),
             ),
             AxisDirection::Vertical => (
                 Point2::new(self.position.0, position),
-                Point2::new(self.position.0 - self.style.tick_length, position),
+                Point2::new(self.position.0 + tick, position),
             ),
         }
     }
@@ -179,8 +457,12 @@ impl Axis {
         match self.direction {
             AxisDirection::Horizontal => Point2::new(
                 position,
-                self.position.1 - self.style.tick_length - self.style.label_size,
+                self.position.1 + self.outward() * (self.style.tick_length + self.style.label_size),
             ),
+            // 右侧标签左对齐，紧贴刻度即可
+            AxisDirection::Vertical if self.opposite => {
+                Point2::new(self.position.0 + self.style.tick_length + 5.0, position)
+            }
             AxisDirection::Vertical => Point2::new(
                 self.position.0 - self.style.tick_length - 30.0, // 为文本留出空间
                 position,
@@ -194,13 +476,14 @@ impl Axis {
             AxisDirection::Horizontal => Point2::new(
                 self.position.0 + self.length / 2.0,
                 self.position.1
-                    - self.style.tick_length
-                    - self.style.label_size
-                    - self.style.title_size
-                    - 10.0,
+                    + self.outward()
+                        * (self.style.tick_length
+                            + self.style.label_size
+                            + self.style.title_size
+                            + 10.0),
             ),
             AxisDirection::Vertical => Point2::new(
-                self.position.0 - self.style.tick_length - 60.0,
+                self.position.0 + self.outward() * (self.style.tick_length + 60.0),
                 self.position.1 + self.length / 2.0,
             ),
         }
@@ -210,6 +493,7 @@ impl Axis {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use vizuara_core::{HorizontalAlign, VerticalAlign, AXIS_BREAK_GAP};
 
     #[test]
     fn test_axis_creation() {
@@ -233,4 +517,216 @@ mod tests {
         // 应该包含：1个主轴线 + 5个刻度线 + 5个标签 + 1个标题 = 12个图元
         assert_eq!(primitives.len(), 12);
     }
+
+    #[test]
+    fn test_opposite_side_vertical_axis() {
+        let scale = LinearScale::new(0.0, 10.0);
+        let axis = Axis::new(AxisDirection::Vertical, scale, (500.0, 100.0), 300.0)
+            .title("Right")
+            .opposite_side(true);
+
+        for primitive in axis.generate_primitives().iter().skip(1) {
+            match primitive {
+                Primitive::Line { start, end } => {
+                    assert_eq!(start.x, 500.0);
+                    assert!(end.x > start.x);
+                }
+                Primitive::Text {
+                    position, h_align, ..
+                } => {
+                    assert!(position.x > 500.0);
+                    assert_eq!(*h_align, vizuara_core::HorizontalAlign::Left);
+                }
+                other => panic!("unexpected primitive {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_scale_ticks() {
+        let axis = Axis::new(
+            AxisDirection::Horizontal,
+            LinearScale::new(0.0, 1.0),
+            (0.0, 0.0),
+            300.0,
+        )
+        .log_scale(LogScale::base10(1.0, 1000.0))
+        .tick_count(4);
+
+        let positions = axis.tick_positions();
+        assert_eq!(positions.len(), 4);
+        for (position, expected) in positions.iter().zip([0.0, 100.0, 200.0, 300.0]) {
+            assert!((position - expected).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_axis_break_mapping() {
+        let scale = LinearScale::new(0.0, 1000.0).with_break(10.0, 990.0);
+        let kept = 1.0 - AXIS_BREAK_GAP;
+
+        // 保留的 20 个单位平分 1 - 间隙宽度
+        assert!((scale.normalize(10.0) - kept / 2.0).abs() < 1e-5);
+        assert!((scale.normalize(990.0) - (kept / 2.0 + AXIS_BREAK_GAP)).abs() < 1e-5);
+        assert!((scale.normalize(1000.0) - 1.0).abs() < 1e-5);
+        assert!((scale.denormalize(scale.normalize(5.0)) - 5.0).abs() < 1e-3);
+        assert!((scale.denormalize(scale.normalize(995.0)) - 995.0).abs() < 1e-3);
+        assert!((scale.denormalize(0.5) - 500.0).abs() < 1e-2);
+
+        // 刻度跳过断裂区间
+        assert_eq!(scale.ticks(6), [0.0, 5.0, 10.0, 990.0, 995.0, 1000.0]);
+
+        // 反向定义域与定义域外的断裂
+        let reversed = LinearScale::new(1000.0, 0.0).with_break(10.0, 990.0);
+        assert!((reversed.normalize(1000.0)).abs() < 1e-5);
+        assert!((reversed.normalize(10.0) - (1.0 - kept / 2.0)).abs() < 1e-5);
+        let outside = LinearScale::new(0.0, 10.0).with_break(20.0, 30.0);
+        assert!(outside.effective_breaks().is_empty());
+        assert_eq!(outside.normalize(2.5), 0.25);
+
+        // 轴线在断裂处拆分并画出锯齿标记
+        let axis = Axis::new(AxisDirection::Horizontal, scale, (0.0, 100.0), 200.0);
+        let primitives = axis.generate_primitives();
+        let zigzag = primitives
+            .iter()
+            .find_map(|p| match p {
+                Primitive::Polyline { points, .. } => Some(points.clone()),
+                _ => None,
+            })
+            .expect("break marker should be drawn");
+        assert_eq!(zigzag.len(), 5);
+        assert!((zigzag[0].x - 200.0 * kept / 2.0).abs() < 1e-3);
+        assert!((zigzag[4].x - zigzag[0].x - 200.0 * AXIS_BREAK_GAP).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_axis_applies_tick_formatter() {
+        let labels: Vec<String> = Axis::new(
+            AxisDirection::Horizontal,
+            LinearScale::new(0.0, 10.0),
+            (0.0, 0.0),
+            100.0,
+        )
+        .tick_count(3)
+        .tick_formatter(TickFormatter::new(|value| format!("${:.2}", value)))
+        .ticks_with_labels()
+        .into_iter()
+        .map(|(_, label)| label)
+        .collect();
+        assert_eq!(labels, ["$0.00", "$5.00", "$10.00"]);
+    }
+
+    #[test]
+    fn test_time_scale_ticks() {
+        const DAY: i64 = 86_400_000;
+        const JAN_2024: i64 = 19_723 * DAY;
+        const JUL_2024: i64 = JAN_2024 + 182 * DAY;
+        let labels = |axis: &Axis| -> Vec<String> {
+            axis.ticks_with_labels()
+                .into_iter()
+                .map(|(_, label)| label)
+                .collect()
+        };
+
+        // 月刻度落在各月第一天，与月份天数无关
+        let mut axis = Axis::new(
+            AxisDirection::Horizontal,
+            LinearScale::new(0.0, 1.0),
+            (0.0, 0.0),
+            100.0,
+        )
+        .tick_count(6)
+        .time_scale(TimeScale::new(JAN_2024, JUL_2024));
+        assert_eq!(
+            labels(&axis),
+            ["Jan 2024", "Feb 2024", "Mar 2024", "Apr 2024", "May 2024", "Jun 2024", "Jul 2024"]
+        );
+        let march = axis.ticks_with_labels()[2].0;
+        assert_eq!(march, (31 + 29) as f32 * DAY as f32);
+
+        // 缩放到两天：按小时显示，零点显示日期
+        axis.set_scale(LinearScale::new(0.0, (2 * DAY) as f32));
+        let hours = labels(&axis);
+        assert_eq!(hours.len(), 9);
+        assert_eq!(hours[..5], ["Jan 1", "06:00", "12:00", "18:00", "Jan 2"]);
+    }
+
+    #[test]
+    fn test_grid_primitives_major_and_minor() {
+        let axis = Axis::new(
+            AxisDirection::Horizontal,
+            LinearScale::new(0.0, 10.0),
+            (0.0, 120.0),
+            100.0,
+        );
+        let widths = |style: GridStyle| -> Vec<f32> {
+            axis.grid_primitives(&style, (0.0, 100.0))
+                .into_iter()
+                .map(|p| match p {
+                    Primitive::Polyline { points, width, .. } => {
+                        assert_eq!(points[0].x, points[1].x);
+                        assert_eq!((points[0].y, points[1].y), (0.0, 100.0));
+                        width
+                    }
+                    other => panic!("unexpected primitive {:?}", other),
+                })
+                .collect()
+        };
+
+        // 刻度 0, 2.5, 5, 7.5, 10：5 条主网格线
+        assert_eq!(widths(GridStyle::default()), [1.0; 5]);
+        // 每个间隔 4 等分，4 个间隔各 3 条次网格线
+        let style = GridStyle::new()
+            .show_minor(true)
+            .minor_divisions(4)
+            .major(Color::BLACK, 2.0);
+        let all = widths(style);
+        assert_eq!(all.iter().filter(|&&w| w == 0.5).count(), 12);
+        assert_eq!(all.iter().filter(|&&w| w == 2.0).count(), 5);
+        assert!(widths(GridStyle::new().show_major(false)).is_empty());
+    }
+
+    #[test]
+    fn test_rotated_tick_labels() {
+        let labels = |axis: Axis| -> Vec<(HorizontalAlign, VerticalAlign, f32)> {
+            axis.generate_primitives()
+                .into_iter()
+                .filter_map(|p| match p {
+                    Primitive::Text {
+                        h_align,
+                        v_align,
+                        rotation,
+                        ..
+                    } => Some((h_align, v_align, rotation)),
+                    _ => None,
+                })
+                .collect()
+        };
+        let axis = || {
+            Axis::new(
+                AxisDirection::Horizontal,
+                LinearScale::new(0.0, 10.0),
+                (0.0, 100.0),
+                200.0,
+            )
+        };
+
+        // 默认水平书写
+        assert!(labels(axis())
+            .iter()
+            .all(|&l| l == (HorizontalAlign::Center, VerticalAlign::Top, 0.0)));
+
+        // 标签在轴线上方（outward 为负）时，逆时针旋转的文本从刻度向上展开
+        let rotated = labels(axis().label_rotation(-45.0));
+        assert!(!rotated.is_empty());
+        assert!(rotated
+            .iter()
+            .all(|&l| l == (HorizontalAlign::Left, VerticalAlign::Middle, -45.0)));
+
+        // 标签在轴线下方时文本末端对准刻度
+        let below = labels(axis().opposite_side(true).label_rotation(-45.0));
+        assert!(below
+            .iter()
+            .all(|&l| l == (HorizontalAlign::Right, VerticalAlign::Middle, -45.0)));
+    }
 }
@@ -19,6 +19,148 @@ pub enum VerticalAlign {
     Bottom,
 }
 
+/// 渐变色标（offset 取值 0.0-1.0）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl GradientStop {
+    pub fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// 线性渐变方向
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GradientDirection {
+    /// 从左到右
+    Horizontal,
+    /// 从上到下
+    Vertical,
+}
+
+/// 规范化色标：按偏移排序并限制在 [0, 1]，首尾补齐到 0 和 1
+pub fn normalize_gradient_stops(stops: &[GradientStop]) -> Vec<GradientStop> {
+    let mut sorted: Vec<GradientStop> = stops
+        .iter()
+        .map(|stop| GradientStop::new(stop.offset.clamp(0.0, 1.0), stop.color))
+        .collect();
+    sorted.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+    if let Some(first) = sorted.first().copied() {
+        if first.offset > 0.0 {
+            sorted.insert(0, GradientStop::new(0.0, first.color));
+        }
+    }
+    if let Some(last) = sorted.last().copied() {
+        if last.offset < 1.0 {
+            sorted.push(GradientStop::new(1.0, last.color));
+        }
+    }
+    sorted
+}
+
+/// 耳切法三角化简单多边形（凸或凹，首尾不重复），返回顶点索引三元组
+///
+/// 顶点顺序为顺时针或逆时针均可；自相交多边形的结果不保证正确
+pub fn triangulate_polygon(points: &[Point2<f32>]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    // 统一为逆时针（有向面积为正）
+    let area: f32 = (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum();
+    let mut remaining: Vec<usize> = if area >= 0.0 {
+        (0..n).collect()
+    } else {
+        (0..n).rev().collect()
+    };
+
+    let cross = |a: Point2<f32>, b: Point2<f32>, c: Point2<f32>| {
+        (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+    };
+
+    let mut triangles = Vec::with_capacity(n - 2);
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let ear = (0..len).find_map(|i| {
+            let (ia, ib, ic) = (
+                remaining[(i + len - 1) % len],
+                remaining[i],
+                remaining[(i + 1) % len],
+            );
+            let (a, b, c) = (points[ia], points[ib], points[ic]);
+            let turn = cross(a, b, c);
+            if turn.abs() <= f32::EPSILON * (a - c).norm_squared().max(1.0) {
+                // 共线顶点直接移除，不产生三角形
+                return Some((i, None));
+            }
+            if turn < 0.0 {
+                return None;
+            }
+
+            // 其余顶点都不在三角形 abc 内（含边界）时 b 为耳
+            let blocked = remaining.iter().any(|&j| {
+                let p = points[j];
+                if p == a || p == b || p == c {
+                    return false;
+                }
+                cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+            });
+            (!blocked).then_some((i, Some([ia, ib, ic])))
+        });
+
+        match ear {
+            Some((i, triangle)) => {
+                triangles.extend(triangle);
+                remaining.remove(i);
+            }
+            None => {
+                // 找不到耳（自相交等退化情形）：剩余部分按扇形处理
+                for i in 1..remaining.len() - 1 {
+                    triangles.push([remaining[0], remaining[i], remaining[i + 1]]);
+                }
+                return triangles;
+            }
+        }
+    }
+
+    let (a, b, c) = (remaining[0], remaining[1], remaining[2]);
+    if cross(points[a], points[b], points[c]).abs() > 0.0 {
+        triangles.push([a, b, c]);
+    }
+    triangles
+}
+
+/// 在色标之间线性插值得到 t（0.0-1.0）处的颜色
+pub fn gradient_color_at(stops: &[GradientStop], t: f32) -> Color {
+    let stops = normalize_gradient_stops(stops);
+    let t = t.clamp(0.0, 1.0);
+
+    for pair in stops.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if t <= b.offset {
+            let span = b.offset - a.offset;
+            let local = if span > 0.0 {
+                (t - a.offset) / span
+            } else {
+                1.0
+            };
+            return a.color * (1.0 - local) + b.color * local;
+        }
+    }
+
+    stops.last().map_or(Color::BLACK, |stop| stop.color)
+}
+
 /// 渲染图元的基础枚举
 /// 这些是渲染器能够处理的基本几何元素
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -49,6 +191,13 @@ pub enum Primitive {
         fill: Color,
         stroke: Option<(Color, f32)>,
     },
+    /// 线性渐变填充的矩形（支持任意数量的色标）
+    GradientRect {
+        min: Point2<f32>,
+        max: Point2<f32>,
+        stops: Vec<GradientStop>,
+        direction: GradientDirection,
+    },
     /// 圆形
     Circle { center: Point2<f32>, radius: f32 },
     /// 多边形（用于面积图、雷达图等）
@@ -76,7 +225,11 @@ pub enum Primitive {
         fill: Color,
         stroke: Option<(Color, f32)>,
     },
-    /// 文本（带颜色与对齐）
+    /// 文本（带颜色、对齐与旋转）
+    ///
+    /// 先按 `h_align`/`v_align` 把文本框对齐到锚点 `position`，再整体绕锚点旋转
+    /// `rotation` 度（屏幕坐标 y 向下，正值为顺时针，与 SVG `rotate()` 一致），
+    /// 因此旋转后锚点仍固定在原处，例如右上对齐并旋转 -45° 的标签末端始终对准刻度
     Text {
         position: Point2<f32>,
         content: String,
@@ -84,6 +237,8 @@ pub enum Primitive {
         color: Color,
         h_align: HorizontalAlign,
         v_align: VerticalAlign,
+        #[serde(default)]
+        rotation: f32,
     },
     /// 三角形列表（用于复杂几何）
     TriangleList(Vec<Point2<f32>>),
@@ -146,6 +301,7 @@ impl Primitive {
             }
             Primitive::Rectangle { min, max } => Some((*min, *max)),
             Primitive::RectangleStyled { min, max, .. } => Some((*min, *max)),
+            Primitive::GradientRect { min, max, .. } => Some((*min, *max)),
             Primitive::Circle { center, radius } => {
                 let min = Point2::new(center.x - radius, center.y - radius);
                 let max = Point2::new(center.x + radius, center.y + radius);
@@ -213,4 +369,76 @@ impl Primitive {
             }
         }
     }
+    /// 逐点变换图元的位置坐标，半径、线宽、字号等尺寸保持不变；
+    /// 矩形按变换后的两个角重新取最小、最大角，3D 图元原样返回
+    pub fn map_points(&self, f: impl Fn(Point2<f32>) -> Point2<f32>) -> Primitive {
+        let map_all = |points: &[Point2<f32>]| points.iter().map(|&p| f(p)).collect::<Vec<_>>();
+        let corners = |min: Point2<f32>, max: Point2<f32>| {
+            let (a, b) = (f(min), f(max));
+            (
+                Point2::new(a.x.min(b.x), a.y.min(b.y)),
+                Point2::new(a.x.max(b.x), a.y.max(b.y)),
+            )
+        };
+
+        let mut mapped = self.clone();
+        match &mut mapped {
+            Primitive::Point(p) => *p = f(*p),
+            Primitive::Points(points)
+            | Primitive::LineStrip(points)
+            | Primitive::TriangleList(points)
+            | Primitive::Polyline { points, .. }
+            | Primitive::Polygon { points, .. } => *points = map_all(points),
+            Primitive::Line { start, end } => {
+                *start = f(*start);
+                *end = f(*end);
+            }
+            Primitive::Rectangle { min, max }
+            | Primitive::RectangleStyled { min, max, .. }
+            | Primitive::GradientRect { min, max, .. } => (*min, *max) = corners(*min, *max),
+            Primitive::Circle { center, .. }
+            | Primitive::ArcSector { center, .. }
+            | Primitive::ArcRing { center, .. } => *center = f(*center),
+            Primitive::Text { position, .. } => *position = f(*position),
+            Primitive::Point3D(_) | Primitive::Line3D { .. } => {}
+        }
+        mapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangulate_concave_l_shape() {
+        // L 形：3×3 的正方形去掉右上 2×2，面积为 5
+        let l_shape = [
+            Point2::new(0.0, 0.0),
+            Point2::new(3.0, 0.0),
+            Point2::new(3.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 3.0),
+            Point2::new(0.0, 3.0),
+        ];
+        let area = |triangles: &[[usize; 3]], points: &[Point2<f32>]| -> f32 {
+            triangles
+                .iter()
+                .map(|&[a, b, c]| {
+                    let (a, b, c) = (points[a], points[b], points[c]);
+                    ((b - a).perp(&(c - a)) / 2.0).abs()
+                })
+                .sum()
+        };
+
+        let triangles = triangulate_polygon(&l_shape);
+        assert_eq!(triangles.len(), l_shape.len() - 2);
+        assert!((area(&triangles, &l_shape) - 5.0).abs() < 1e-5);
+
+        // 顺时针顶点顺序得到同样的结果
+        let reversed: Vec<Point2<f32>> = l_shape.iter().rev().copied().collect();
+        let triangles = triangulate_polygon(&reversed);
+        assert_eq!(triangles.len(), 4);
+        assert!((area(&triangles, &reversed) - 5.0).abs() < 1e-5);
+    }
 }
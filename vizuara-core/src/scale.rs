@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
 
 /// 数据尺度映射抽象
 pub trait Scale {
@@ -13,13 +16,92 @@ pub trait Scale {
 
     /// 获取刻度的标签
     fn tick_labels(&self, ticks: &[f32]) -> Vec<String>;
+
+    /// 将数据值映射到像素坐标：定义域下限对应 `pixel_min`，上限对应 `pixel_max`
+    fn to_pixel(&self, value: f32, pixel_min: f32, pixel_max: f32) -> f32 {
+        pixel_min + self.normalize(value) * (pixel_max - pixel_min)
+    }
+
+    /// 将像素坐标反向映射到数据值，与 [`to_pixel`](Self::to_pixel) 互逆
+    #[allow(clippy::wrong_self_convention)]
+    fn from_pixel(&self, px: f32, pixel_min: f32, pixel_max: f32) -> f32 {
+        let span = pixel_max - pixel_min;
+        if span == 0.0 {
+            return self.denormalize(0.0);
+        }
+        self.denormalize((px - pixel_min) / span)
+    }
+
+    /// 映射到向下增长的屏幕 Y 坐标：定义域下限对应 `pixel_max`（底部），上限对应 `pixel_min`（顶部）
+    fn to_pixel_inverted(&self, value: f32, pixel_min: f32, pixel_max: f32) -> f32 {
+        pixel_max - self.normalize(value) * (pixel_max - pixel_min)
+    }
+
+    /// [`to_pixel_inverted`](Self::to_pixel_inverted) 的逆映射
+    #[allow(clippy::wrong_self_convention)]
+    fn from_pixel_inverted(&self, px: f32, pixel_min: f32, pixel_max: f32) -> f32 {
+        let span = pixel_max - pixel_min;
+        if span == 0.0 {
+            return self.denormalize(0.0);
+        }
+        self.denormalize((pixel_max - px) / span)
+    }
+}
+
+/// 整齐刻度间距的候选系数（乘以 10 的幂）
+const NICE_STEPS: [f64; 5] = [1.0, 2.0, 2.5, 5.0, 10.0];
+
+/// 每个坐标轴断裂在归一化范围中占据的宽度（轴长的比例）
+pub const AXIS_BREAK_GAP: f32 = 0.03;
+
+/// 坐标轴断裂：从比例尺中移除的数据区间，压缩为 [`AXIS_BREAK_GAP`] 宽的间隙
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AxisBreak {
+    pub start: f32,
+    pub end: f32,
 }
 
 /// 线性比例尺
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "LinearScaleFields")]
 pub struct LinearScale {
     pub domain_min: f32,
     pub domain_max: f32,
+    /// 被移除的数据区间，见 [`LinearScale::with_break`]
+    breaks: Vec<AxisBreak>,
+    /// 设置断裂时预先计算的映射节点，避免每次映射都重新计算
+    #[serde(skip)]
+    knots: Vec<(f32, f32)>,
+    /// 计算 `knots` 时的定义域；与当前定义域不一致时节点已过期
+    #[serde(skip)]
+    knots_domain: (f32, f32),
+}
+
+/// [`LinearScale`] 的序列化字段，反序列化后重新计算断裂节点
+#[derive(Deserialize)]
+struct LinearScaleFields {
+    domain_min: f32,
+    domain_max: f32,
+    #[serde(default)]
+    breaks: Vec<AxisBreak>,
+}
+
+impl From<LinearScaleFields> for LinearScale {
+    fn from(fields: LinearScaleFields) -> Self {
+        let mut scale = Self::new(fields.domain_min, fields.domain_max);
+        scale.breaks = fields.breaks;
+        scale.refresh_knots();
+        scale
+    }
+}
+
+/// 比较定义域与断裂，忽略缓存的节点
+impl PartialEq for LinearScale {
+    fn eq(&self, other: &Self) -> bool {
+        self.domain_min == other.domain_min
+            && self.domain_max == other.domain_max
+            && self.breaks == other.breaks
+    }
 }
 
 impl LinearScale {
@@ -28,7 +110,105 @@ impl LinearScale {
         Self {
             domain_min,
             domain_max,
+            breaks: Vec::new(),
+            knots: Vec::new(),
+            knots_domain: (domain_min, domain_max),
+        }
+    }
+
+    /// 添加坐标轴断裂：区间 (start, end) 内没有数据，压缩为固定宽度的间隙，
+    /// 其余部分按剩余长度线性映射。只有完全位于定义域内部的区间才生效。
+    /// 端点顺序颠倒时自动交换，空区间或含 NaN、无穷的区间被忽略
+    pub fn with_break(mut self, start: f32, end: f32) -> Self {
+        let (start, end) = (start.min(end), start.max(end));
+        if start < end && start.is_finite() && end.is_finite() {
+            self.breaks.push(AxisBreak { start, end });
+            self.refresh_knots();
+        }
+        self
+    }
+
+    /// 替换定义域（保留断裂），并重新计算断裂节点
+    pub fn with_domain(mut self, domain_min: f32, domain_max: f32) -> Self {
+        self.domain_min = domain_min;
+        self.domain_max = domain_max;
+        self.refresh_knots();
+        self
+    }
+
+    /// 已添加的断裂（按添加顺序，未经过滤）
+    pub fn breaks(&self) -> &[AxisBreak] {
+        &self.breaks
+    }
+
+    /// 实际生效的断裂：位于定义域内部、按升序排列且合并了重叠部分
+    pub fn effective_breaks(&self) -> Vec<AxisBreak> {
+        let lo = self.domain_min.min(self.domain_max);
+        let hi = self.domain_min.max(self.domain_max);
+        let mut breaks: Vec<AxisBreak> = self
+            .breaks
+            .iter()
+            .copied()
+            .filter(|b| b.start > lo && b.end < hi)
+            .collect();
+        breaks.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        let mut merged: Vec<AxisBreak> = Vec::with_capacity(breaks.len());
+        for b in breaks {
+            match merged.last_mut() {
+                Some(last) if b.start <= last.end => last.end = last.end.max(b.end),
+                _ => merged.push(b),
+            }
+        }
+
+        // 断裂占满定义域或间隙总宽过大时无法映射，忽略所有断裂
+        let removed: f32 = merged.iter().map(|b| b.end - b.start).sum();
+        if removed >= hi - lo || merged.len() as f32 * AXIS_BREAK_GAP >= 1.0 {
+            return Vec::new();
+        }
+        merged
+    }
+
+    /// 按当前定义域重新计算断裂节点
+    fn refresh_knots(&mut self) {
+        self.knots = self.break_knots();
+        self.knots_domain = (self.domain_min, self.domain_max);
+    }
+
+    /// 当前使用的断裂节点：定义域自上次计算后被直接修改时临时重新计算
+    fn knots(&self) -> Cow<'_, [(f32, f32)]> {
+        if self.breaks.is_empty() || self.knots_domain == (self.domain_min, self.domain_max) {
+            Cow::Borrowed(&self.knots)
+        } else {
+            Cow::Owned(self.break_knots())
+        }
+    }
+
+    /// 升序定义域上的分段线性映射节点 (数据值, 归一化位置)；没有生效的断裂时为空
+    fn break_knots(&self) -> Vec<(f32, f32)> {
+        let breaks = self.effective_breaks();
+        if breaks.is_empty() {
+            return Vec::new();
+        }
+
+        let lo = self.domain_min.min(self.domain_max);
+        let hi = self.domain_min.max(self.domain_max);
+        let removed: f32 = breaks.iter().map(|b| b.end - b.start).sum();
+        // 保留部分每单位数据值对应的归一化长度
+        let unit = (1.0 - breaks.len() as f32 * AXIS_BREAK_GAP) / (hi - lo - removed);
+
+        let mut knots = vec![(lo, 0.0)];
+        let mut position = 0.0;
+        let mut previous = lo;
+        for b in &breaks {
+            position += (b.start - previous) * unit;
+            knots.push((b.start, position));
+            position += AXIS_BREAK_GAP;
+            knots.push((b.end, position));
+            previous = b.end;
         }
+        knots.push((hi, 1.0));
+        knots
     }
 
     /// 从数据自动创建比例尺
@@ -51,27 +231,99 @@ impl LinearScale {
 impl Scale for LinearScale {
     fn normalize(&self, value: f32) -> f32 {
         if self.domain_max == self.domain_min {
-            0.5
+            return 0.5;
+        }
+        let knots = self.knots();
+        if knots.is_empty() {
+            return (value - self.domain_min) / (self.domain_max - self.domain_min);
+        }
+        let t = interpolate_knots(&knots, value, false);
+        if self.domain_max < self.domain_min {
+            1.0 - t
         } else {
-            (value - self.domain_min) / (self.domain_max - self.domain_min)
+            t
         }
     }
 
     fn denormalize(&self, normalized: f32) -> f32 {
-        self.domain_min + normalized * (self.domain_max - self.domain_min)
+        let knots = self.knots();
+        if knots.is_empty() {
+            return self.domain_min + normalized * (self.domain_max - self.domain_min);
+        }
+        let t = if self.domain_max < self.domain_min {
+            1.0 - normalized
+        } else {
+            normalized
+        };
+        interpolate_knots(&knots, t, true)
     }
 
+    /// 生成"整齐"的刻度：间距取 1、2、2.5、5 乘以 10 的幂，
+    /// `count` 为目标数量，实际数量取最接近的可行值
     fn ticks(&self, count: usize) -> Vec<f32> {
         if count == 0 {
             return vec![];
         }
 
-        let range = self.domain_max - self.domain_min;
-        let step = range / (count - 1) as f32;
+        // 有断裂时按各保留区间的长度分配刻度数量，跳过被移除的区间
+        let breaks = self.effective_breaks();
+        if !breaks.is_empty() {
+            let lo = self.domain_min.min(self.domain_max);
+            let hi = self.domain_min.max(self.domain_max);
+            let bounds: Vec<f32> = std::iter::once(lo)
+                .chain(breaks.iter().flat_map(|b| [b.start, b.end]))
+                .chain(std::iter::once(hi))
+                .collect();
+            let kept: f32 = bounds.chunks(2).map(|pair| pair[1] - pair[0]).sum();
 
-        (0..count)
-            .map(|i| self.domain_min + i as f32 * step)
-            .collect()
+            let mut ticks: Vec<f32> = Vec::new();
+            for pair in bounds.chunks(2) {
+                let (start, end) = (pair[0], pair[1]);
+                let share = ((count as f32 * (end - start) / kept).round() as usize).max(1);
+                let tolerance = (end - start) * 1e-5;
+                for tick in LinearScale::new(start, end).ticks(share) {
+                    let inside = tick >= start - tolerance && tick <= end + tolerance;
+                    if inside && ticks.last().copied().unwrap_or(f32::NEG_INFINITY) < tick {
+                        ticks.push(tick);
+                    }
+                }
+            }
+            if self.domain_max < self.domain_min {
+                ticks.reverse();
+            }
+            return ticks;
+        }
+
+        let lo = self.domain_min.min(self.domain_max) as f64;
+        let hi = self.domain_min.max(self.domain_max) as f64;
+        let range = hi - lo;
+        if !range.is_finite() || range <= 0.0 {
+            return vec![self.domain_min];
+        }
+
+        let power = 10f64.powf((range / count as f64).log10().floor());
+        // 容差吸收 f32 定义域的舍入误差（如 0.7 / 0.1 = 6.999…）
+        let tick_range = |step: f64| {
+            (
+                (lo / step - 1e-6).ceil() as i64,
+                (hi / step + 1e-6).floor() as i64,
+            )
+        };
+        let step = NICE_STEPS
+            .iter()
+            .map(|&factor| factor * power)
+            .min_by_key(|&step| {
+                let (first, last) = tick_range(step);
+                (last - first + 1 - count as i64).abs()
+            })
+            .unwrap_or(power);
+
+        let (first, last) = tick_range(step);
+        let mut ticks: Vec<f32> = (first..=last).map(|i| (i as f64 * step) as f32).collect();
+        if self.domain_max < self.domain_min {
+            ticks.reverse();
+        }
+        ticks
     }
 
     fn tick_labels(&self, ticks: &[f32]) -> Vec<String> {
@@ -79,6 +331,21 @@ impl Scale for LinearScale {
     }
 }
 
+/// 在升序节点间分段线性插值；`inverse` 为 true 时由归一化位置求数据值。
+/// 超出两端时沿首尾区间外推
+fn interpolate_knots(knots: &[(f32, f32)], x: f32, inverse: bool) -> f32 {
+    let pick = |knot: (f32, f32)| if inverse { (knot.1, knot.0) } else { knot };
+    let last = knots.len() - 1;
+    let i = (1..last).find(|&i| x < pick(knots[i]).0).unwrap_or(last);
+    let (x0, y0) = pick(knots[i - 1]);
+    let (x1, y1) = pick(knots[i]);
+    if x1 == x0 {
+        y0
+    } else {
+        y0 + (x - x0) / (x1 - x0) * (y1 - y0)
+    }
+}
+
 /// 对数比例尺
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogScale {
@@ -152,3 +419,778 @@ impl Scale for LogScale {
             .collect()
     }
 }
+
+/// 一天的毫秒数
+const MS_PER_DAY: i64 = 86_400_000;
+
+/// 1970-01-01 是星期四，第 4 天为首个星期一
+const FIRST_MONDAY: i64 = 4;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// 时间刻度的日历单位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeUnit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// 以星期一为起点
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// 单位的近似时长（毫秒），月按 30 天、年按 365 天计，仅用于选择间隔
+    pub fn approx_millis(self) -> i64 {
+        match self {
+            TimeUnit::Millisecond => 1,
+            TimeUnit::Second => 1_000,
+            TimeUnit::Minute => 60_000,
+            TimeUnit::Hour => 3_600_000,
+            TimeUnit::Day => MS_PER_DAY,
+            TimeUnit::Week => 7 * MS_PER_DAY,
+            TimeUnit::Month => 30 * MS_PER_DAY,
+            TimeUnit::Year => 365 * MS_PER_DAY,
+        }
+    }
+}
+
+/// 候选刻度间隔，按时长升序；不足一秒与超过一年的间隔按 1、2、5 乘以 10 的幂取整
+const TIME_INTERVALS: [(TimeUnit, i64); 25] = [
+    (TimeUnit::Second, 1),
+    (TimeUnit::Second, 2),
+    (TimeUnit::Second, 5),
+    (TimeUnit::Second, 10),
+    (TimeUnit::Second, 15),
+    (TimeUnit::Second, 30),
+    (TimeUnit::Minute, 1),
+    (TimeUnit::Minute, 2),
+    (TimeUnit::Minute, 5),
+    (TimeUnit::Minute, 10),
+    (TimeUnit::Minute, 15),
+    (TimeUnit::Minute, 30),
+    (TimeUnit::Hour, 1),
+    (TimeUnit::Hour, 2),
+    (TimeUnit::Hour, 3),
+    (TimeUnit::Hour, 6),
+    (TimeUnit::Hour, 12),
+    (TimeUnit::Day, 1),
+    (TimeUnit::Day, 2),
+    (TimeUnit::Week, 1),
+    (TimeUnit::Month, 1),
+    (TimeUnit::Month, 2),
+    (TimeUnit::Month, 3),
+    (TimeUnit::Month, 6),
+    (TimeUnit::Year, 1),
+];
+
+/// 时间刻度间隔：`step` 个 `unit`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeInterval {
+    pub unit: TimeUnit,
+    pub step: i64,
+}
+
+impl TimeInterval {
+    /// 创建间隔，`step` 至少为 1
+    pub fn new(unit: TimeUnit, step: i64) -> Self {
+        Self {
+            unit,
+            step: step.max(1),
+        }
+    }
+
+    /// 近似时长（毫秒）
+    pub fn approx_millis(&self) -> i64 {
+        self.unit.approx_millis().saturating_mul(self.step)
+    }
+
+    /// 为 `span` 毫秒的跨度选择约 `count` 个刻度的间隔：
+    /// 在候选间隔中取时长与 `span / count` 之比最接近 1 的一个
+    pub fn for_span(span: i64, count: usize) -> Self {
+        let target = span.max(0) as f64 / count.max(1) as f64;
+        if target < TimeUnit::Second.approx_millis() as f64 {
+            return Self::new(TimeUnit::Millisecond, nice_multiple(target));
+        }
+
+        let durations = TIME_INTERVALS.map(|(unit, step)| (unit.approx_millis() * step) as f64);
+        let (unit, step) = match durations.iter().position(|&d| d >= target) {
+            None => {
+                let years = target / TimeUnit::Year.approx_millis() as f64;
+                (TimeUnit::Year, nice_multiple(years))
+            }
+            Some(0) => TIME_INTERVALS[0],
+            Some(i) if target / durations[i - 1] < durations[i] / target => TIME_INTERVALS[i - 1],
+            Some(i) => TIME_INTERVALS[i],
+        };
+        Self::new(unit, step)
+    }
+
+    /// [start, end] 内按日历对齐的刻度（纪元毫秒）：
+    /// 秒、分、时、日对齐到 UTC 整点，周从星期一开始，月、年从该月或该年第一天开始，
+    /// 多月与多年间隔分别对齐到月份与年份的整数倍（如每季度为 1、4、7、10 月）
+    fn ticks(&self, start: i64, end: i64) -> Vec<i64> {
+        let mut ticks = Vec::new();
+        match self.unit {
+            TimeUnit::Month | TimeUnit::Year => {
+                let months = match self.unit {
+                    TimeUnit::Year => self.step.saturating_mul(12),
+                    _ => self.step,
+                };
+                let (year, month, _) = civil_from_days(start.div_euclid(MS_PER_DAY));
+                let mut index = (year * 12 + month as i64 - 1).div_euclid(months) * months;
+                loop {
+                    let tick = month_start(index);
+                    if tick > end {
+                        break;
+                    }
+                    if tick >= start {
+                        ticks.push(tick);
+                    }
+                    index += months;
+                }
+            }
+            _ => {
+                let length = self.approx_millis();
+                let anchor = match self.unit {
+                    TimeUnit::Week => FIRST_MONDAY * MS_PER_DAY,
+                    _ => 0,
+                };
+                let mut tick = (start - anchor).div_euclid(length) * length + anchor;
+                while tick <= end {
+                    if tick >= start {
+                        ticks.push(tick);
+                    }
+                    tick += length;
+                }
+            }
+        }
+        ticks
+    }
+}
+
+/// 最接近 `value` 的 1、2、5 乘以 10 的幂（至少为 1）
+fn nice_multiple(value: f64) -> i64 {
+    if value.is_nan() || value <= 1.0 {
+        return 1;
+    }
+    let power = 10f64.powf(value.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|&factor| factor * power)
+        .min_by(|a, b| (a / value).ln().abs().total_cmp(&(b / value).ln().abs()))
+        .unwrap_or(power) as i64
+}
+
+/// 自 1970-01-01 起的天数转公历 (年, 月, 日)，适用于任意年份
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // 以三月为一年的第一个月，闰日落在年末
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = (if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// 公历日期转自 1970-01-01 起的天数，[`civil_from_days`] 的逆运算
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// 第 `index` 个月（年 * 12 + 月 - 1）第一天零点的纪元毫秒
+fn month_start(index: i64) -> i64 {
+    let month = index.rem_euclid(12) as u32 + 1;
+    days_from_civil(index.div_euclid(12), month, 1) * MS_PER_DAY
+}
+
+/// 时间刻度标签格式（UTC）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeFormat {
+    /// "2024"
+    Year,
+    /// "Jan 2024"
+    Month,
+    /// "Jan 15"
+    Day,
+    /// "14:30"
+    Minute,
+    /// "14:30:05"
+    Second,
+    /// "14:30:05.250"
+    Millisecond,
+}
+
+impl TimeFormat {
+    /// 能完整表示所有时刻的最粗格式：全部位于年初时为 `Year`，位于月初时为 `Month`，依此类推
+    pub fn for_timestamps(timestamps: &[i64]) -> Self {
+        [
+            TimeFormat::Year,
+            TimeFormat::Month,
+            TimeFormat::Day,
+            TimeFormat::Minute,
+            TimeFormat::Second,
+        ]
+        .into_iter()
+        .find(|format| timestamps.iter().all(|&t| format.is_exact(t)))
+        .unwrap_or(TimeFormat::Millisecond)
+    }
+
+    /// 该格式是否不丢失 `timestamp` 的信息
+    fn is_exact(self, timestamp: i64) -> bool {
+        let time = timestamp.rem_euclid(MS_PER_DAY);
+        let (_, month, day) = civil_from_days(timestamp.div_euclid(MS_PER_DAY));
+        match self {
+            TimeFormat::Year => month == 1 && day == 1 && time == 0,
+            TimeFormat::Month => day == 1 && time == 0,
+            TimeFormat::Day => time == 0,
+            TimeFormat::Minute => time % 60_000 == 0,
+            TimeFormat::Second => time % 1_000 == 0,
+            TimeFormat::Millisecond => true,
+        }
+    }
+
+    /// 格式化纪元毫秒
+    pub fn format(self, timestamp: i64) -> String {
+        let time = timestamp.rem_euclid(MS_PER_DAY);
+        let (year, month, day) = civil_from_days(timestamp.div_euclid(MS_PER_DAY));
+        let month_name = MONTH_NAMES[month as usize - 1];
+        let (hour, minute, second, millis) = (
+            time / 3_600_000,
+            time / 60_000 % 60,
+            time / 1_000 % 60,
+            time % 1_000,
+        );
+        match self {
+            TimeFormat::Year => format!("{}", year),
+            TimeFormat::Month => format!("{} {}", month_name, year),
+            TimeFormat::Day => format!("{} {}", month_name, day),
+            TimeFormat::Minute => format!("{:02}:{:02}", hour, minute),
+            TimeFormat::Second => format!("{:02}:{:02}:{:02}", hour, minute, second),
+            TimeFormat::Millisecond => {
+                format!("{:02}:{:02}:{:02}.{:03}", hour, minute, second, millis)
+            }
+        }
+    }
+}
+
+/// 时间比例尺：定义域为 Unix 纪元毫秒（UTC）
+///
+/// f32 无法精确表示纪元毫秒，[`Scale`] 接口中的数据值是相对 `origin` 的毫秒偏移，
+/// 用 [`offset`](Self::offset) 将时间戳换算为图表数据。缩放、平移只改变定义域，`origin` 保持不变
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeScale {
+    pub domain_min: i64,
+    pub domain_max: i64,
+    /// 数据值为 0 对应的时间戳
+    pub origin: i64,
+}
+
+impl TimeScale {
+    /// 创建时间比例尺，`origin` 取定义域下限
+    pub fn new(domain_min: i64, domain_max: i64) -> Self {
+        let (domain_min, domain_max) = (domain_min.min(domain_max), domain_min.max(domain_max));
+        Self {
+            domain_min,
+            domain_max,
+            origin: domain_min,
+        }
+    }
+
+    /// 从时间戳自动创建比例尺：两端各留 5% 边距，`origin` 取最早的时间戳
+    pub fn from_timestamps(timestamps: &[i64]) -> Self {
+        let (Some(&min), Some(&max)) = (timestamps.iter().min(), timestamps.iter().max()) else {
+            return Self::new(0, MS_PER_DAY);
+        };
+        let margin = (max - min) / 20;
+        Self::new(min - margin, max + margin).with_origin(min)
+    }
+
+    /// 设置数据值为 0 对应的时间戳
+    pub fn with_origin(mut self, origin: i64) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// 替换定义域，保留 `origin`（已换算的数据不受影响）
+    pub fn with_domain(mut self, domain_min: i64, domain_max: i64) -> Self {
+        self.domain_min = domain_min.min(domain_max);
+        self.domain_max = domain_min.max(domain_max);
+        self
+    }
+
+    /// 定义域跨度（毫秒）
+    pub fn span(&self) -> i64 {
+        self.domain_max - self.domain_min
+    }
+
+    /// 时间戳换算为数据值（相对 `origin` 的毫秒偏移）
+    pub fn offset(&self, timestamp: i64) -> f32 {
+        (timestamp - self.origin) as f32
+    }
+
+    /// 数据值换算回时间戳
+    pub fn timestamp(&self, value: f32) -> i64 {
+        self.origin + value.round() as i64
+    }
+
+    /// 数据值空间中的等价线性比例尺，供图表定位数据
+    pub fn linear(&self) -> LinearScale {
+        LinearScale::new(self.offset(self.domain_min), self.offset(self.domain_max))
+    }
+
+    /// 数据值空间中的线性比例尺换算回时间比例尺（如缩放后的坐标轴）
+    pub fn from_linear(&self, scale: &LinearScale) -> Self {
+        self.clone().with_domain(
+            self.timestamp(scale.domain_min),
+            self.timestamp(scale.domain_max),
+        )
+    }
+
+    /// 约 `count` 个刻度时使用的间隔
+    pub fn interval(&self, count: usize) -> TimeInterval {
+        TimeInterval::for_span(self.span(), count)
+    }
+
+    /// 按日历对齐的刻度时间戳
+    pub fn time_ticks(&self, count: usize) -> Vec<i64> {
+        if count == 0 {
+            return vec![];
+        }
+        self.interval(count).ticks(self.domain_min, self.domain_max)
+    }
+
+    /// 以固定格式显示时间的刻度格式化器（输入为本比例尺的数据值）
+    pub fn tick_formatter(&self, format: TimeFormat) -> TickFormatter {
+        let scale = self.clone();
+        TickFormatter::new(move |value| format.format(scale.tick_timestamp(value)))
+    }
+
+    /// 由刻度值还原时间戳，并按 f32 的舍入误差对齐到整秒、整分等单位，
+    /// 避免误差影响格式选择（跨度较大时误差可达数秒，刻度间隔远大于此）
+    fn tick_timestamp(&self, value: f32) -> i64 {
+        let error = value.abs() * f32::EPSILON;
+        let unit = [1, 1_000, 60_000, 3_600_000, MS_PER_DAY]
+            .into_iter()
+            .find(|&unit| unit as f32 >= 2.0 * error)
+            .unwrap_or(MS_PER_DAY);
+        let timestamp = self.timestamp(value);
+        (timestamp + unit / 2).div_euclid(unit) * unit
+    }
+}
+
+impl Scale for TimeScale {
+    fn normalize(&self, value: f32) -> f32 {
+        if self.span() == 0 {
+            return 0.5;
+        }
+        (value - self.offset(self.domain_min)) / self.span() as f32
+    }
+
+    fn denormalize(&self, normalized: f32) -> f32 {
+        self.offset(self.domain_min) + normalized * self.span() as f32
+    }
+
+    /// 按日历对齐的刻度：间隔随跨度在秒、分、时、日、周、月、年之间选择，
+    /// 月、年刻度按各月实际天数落在每月第一天
+    fn ticks(&self, count: usize) -> Vec<f32> {
+        self.time_ticks(count)
+            .into_iter()
+            .map(|tick| self.offset(tick))
+            .collect()
+    }
+
+    /// 按刻度的对齐程度选择格式；只显示时分秒时，零点刻度改为显示日期
+    fn tick_labels(&self, ticks: &[f32]) -> Vec<String> {
+        let timestamps: Vec<i64> = ticks.iter().map(|&t| self.tick_timestamp(t)).collect();
+        let format = TimeFormat::for_timestamps(&timestamps);
+        let time_of_day = matches!(
+            format,
+            TimeFormat::Minute | TimeFormat::Second | TimeFormat::Millisecond
+        );
+        timestamps
+            .into_iter()
+            .map(|timestamp| {
+                if time_of_day && TimeFormat::Day.is_exact(timestamp) {
+                    TimeFormat::Day.format(timestamp)
+                } else {
+                    format.format(timestamp)
+                }
+            })
+            .collect()
+    }
+}
+
+/// 刻度标签格式化器，可在坐标轴之间共享
+#[derive(Clone)]
+pub struct TickFormatter(Arc<dyn Fn(f32) -> String + Send + Sync>);
+
+impl TickFormatter {
+    /// 使用自定义函数创建格式化器
+    pub fn new(format: impl Fn(f32) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(format))
+    }
+
+    /// 固定小数位数，如 `fixed(1)` 得到 "12.5"
+    pub fn fixed(decimals: usize) -> Self {
+        Self::new(move |value| format!("{:.*}", decimals, value))
+    }
+
+    /// 百分比，1.0 对应 100%，如 `percent(0)` 将 0.25 格式化为 "25%"
+    pub fn percent(decimals: usize) -> Self {
+        Self::new(move |value| format!("{:.*}%", decimals, value * 100.0))
+    }
+
+    /// SI 词头缩写，如 "1.2k"、"3.4M"、"5m"，末尾多余的 0 会被去掉
+    pub fn si(decimals: usize) -> Self {
+        const PREFIXES: [(f32, &str); 8] = [
+            (1e12, "T"),
+            (1e9, "G"),
+            (1e6, "M"),
+            (1e3, "k"),
+            (1.0, ""),
+            (1e-3, "m"),
+            (1e-6, "µ"),
+            (1e-9, "n"),
+        ];
+        Self::new(move |value| {
+            if value == 0.0 || !value.is_finite() {
+                return format!("{}", value);
+            }
+            let magnitude = value.abs();
+            let (factor, prefix) = PREFIXES
+                .iter()
+                .find(|(factor, _)| magnitude >= *factor)
+                .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+            let number = format!("{:.*}", decimals, value / factor);
+            let number = if number.contains('.') {
+                number.trim_end_matches('0').trim_end_matches('.')
+            } else {
+                &number
+            };
+            format!("{}{}", number, prefix)
+        })
+    }
+
+    /// 科学计数法，如 `scientific(1)` 得到 "1.2e3"
+    pub fn scientific(decimals: usize) -> Self {
+        Self::new(move |value| format!("{:.*e}", decimals, value))
+    }
+
+    /// 格式化单个刻度值
+    pub fn format(&self, value: f32) -> String {
+        (self.0)(value)
+    }
+}
+
+impl fmt::Debug for TickFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TickFormatter(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nice_ticks() {
+        assert_eq!(
+            LinearScale::new(0.0, 97.0).ticks(5),
+            [0.0, 20.0, 40.0, 60.0, 80.0]
+        );
+        assert_eq!(LinearScale::new(0.0, 1.0).ticks(3), [0.0, 0.5, 1.0]);
+        assert_eq!(
+            LinearScale::new(0.0, 10.0).ticks(5),
+            [0.0, 2.5, 5.0, 7.5, 10.0]
+        );
+        assert_eq!(LinearScale::new(-3.0, 3.0).ticks(3), [-2.0, 0.0, 2.0]);
+        assert_eq!(LinearScale::new(10.0, 0.0).ticks(3), [10.0, 5.0, 0.0]);
+        assert_eq!(LinearScale::new(4.0, 4.0).ticks(5), [4.0]);
+
+        let ticks = LinearScale::new(0.0, 0.7).ticks(8);
+        assert_eq!(ticks.len(), 8);
+        assert_eq!(ticks[3], 0.3);
+    }
+
+    #[test]
+    fn test_scale_pixel_mapping() {
+        let scale = LinearScale::new(0.0, 10.0);
+        assert_eq!(scale.to_pixel(2.5, 100.0, 300.0), 150.0);
+        assert_eq!(scale.from_pixel(150.0, 100.0, 300.0), 2.5);
+
+        // 屏幕 Y 轴向下：定义域下限位于底部
+        assert_eq!(scale.to_pixel_inverted(0.0, 50.0, 250.0), 250.0);
+        assert_eq!(scale.to_pixel_inverted(10.0, 50.0, 250.0), 50.0);
+        assert_eq!(scale.to_pixel_inverted(2.5, 50.0, 250.0), 200.0);
+        assert_eq!(scale.from_pixel_inverted(200.0, 50.0, 250.0), 2.5);
+
+        // 对数比例尺同样适用
+        let log = LogScale::base10(1.0, 100.0);
+        assert!((log.to_pixel(10.0, 0.0, 200.0) - 100.0).abs() < 1e-4);
+        assert!((log.from_pixel(100.0, 0.0, 200.0) - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_axis_break_mapping() {
+        let scale = LinearScale::new(0.0, 100.0).with_break(40.0, 80.0);
+        let kept = 1.0 - AXIS_BREAK_GAP;
+
+        // 保留的 60 个单位按长度分配 1 - 间隙宽度，间隙两侧紧邻
+        assert_eq!(scale.normalize(0.0), 0.0);
+        assert!((scale.normalize(40.0) - kept * 40.0 / 60.0).abs() < 1e-6);
+        assert!((scale.normalize(80.0) - scale.normalize(40.0) - AXIS_BREAK_GAP).abs() < 1e-6);
+        assert!((scale.normalize(100.0) - 1.0).abs() < 1e-6);
+        assert!((scale.normalize(20.0) - kept * 20.0 / 60.0).abs() < 1e-6);
+
+        // 定义域外沿首尾区间外推
+        assert!(scale.normalize(-10.0) < 0.0);
+        assert!(scale.normalize(110.0) > 1.0);
+    }
+
+    #[test]
+    fn test_axis_break_inverse_mapping() {
+        let scale = LinearScale::new(0.0, 100.0).with_break(40.0, 80.0);
+        for value in [0.0, 10.0, 39.5, 80.0, 90.0, 100.0] {
+            assert!((scale.denormalize(scale.normalize(value)) - value).abs() < 1e-3);
+            let px = scale.to_pixel(value, 0.0, 500.0);
+            assert!((scale.from_pixel(px, 0.0, 500.0) - value).abs() < 1e-3);
+        }
+
+        // 间隙内的位置映射到断裂区间内
+        let gap_middle = scale.normalize(40.0) + AXIS_BREAK_GAP / 2.0;
+        assert!((scale.denormalize(gap_middle) - 60.0).abs() < 1e-2);
+
+        // 反向定义域
+        let reversed = LinearScale::new(100.0, 0.0).with_break(40.0, 80.0);
+        assert!((reversed.normalize(100.0)).abs() < 1e-6);
+        assert!((reversed.normalize(0.0) - 1.0).abs() < 1e-6);
+        assert!((reversed.denormalize(reversed.normalize(90.0)) - 90.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_axis_break_edge_cases() {
+        let plain = LinearScale::new(0.0, 100.0);
+
+        // 端点颠倒时交换，与正序添加等价
+        assert_eq!(
+            LinearScale::new(0.0, 100.0).with_break(80.0, 40.0),
+            LinearScale::new(0.0, 100.0).with_break(40.0, 80.0)
+        );
+
+        // 空区间与非有限端点被忽略
+        assert_eq!(LinearScale::new(0.0, 100.0).with_break(50.0, 50.0), plain);
+        assert_eq!(
+            LinearScale::new(0.0, 100.0).with_break(f32::NAN, 50.0),
+            plain
+        );
+        assert_eq!(
+            LinearScale::new(0.0, 100.0).with_break(10.0, f32::INFINITY),
+            plain
+        );
+
+        // 超出定义域的断裂不生效，映射保持线性
+        let outside = LinearScale::new(0.0, 100.0).with_break(-10.0, 20.0);
+        assert!(outside.effective_breaks().is_empty());
+        assert_eq!(outside.normalize(25.0), 0.25);
+
+        // 重叠的断裂合并为一个
+        let overlapping = LinearScale::new(0.0, 100.0)
+            .with_break(50.0, 70.0)
+            .with_break(20.0, 60.0);
+        assert_eq!(
+            overlapping.effective_breaks(),
+            vec![AxisBreak {
+                start: 20.0,
+                end: 70.0
+            }]
+        );
+
+        // 刻度跳过断裂区间
+        let ticks = LinearScale::new(0.0, 100.0).with_break(40.0, 80.0).ticks(6);
+        assert!(ticks.iter().all(|&t| !(t > 40.0 && t < 80.0)));
+        assert_eq!(ticks.first(), Some(&0.0));
+        assert_eq!(ticks.last(), Some(&100.0));
+    }
+
+    #[test]
+    fn test_axis_break_knots_follow_domain() {
+        let scale = LinearScale::new(0.0, 100.0).with_break(40.0, 80.0);
+        assert_eq!(scale.knots().as_ref(), scale.break_knots().as_slice());
+        assert!(matches!(scale.knots(), Cow::Borrowed(_)));
+
+        // with_domain 重新计算节点
+        let widened = scale.clone().with_domain(0.0, 200.0);
+        assert!(matches!(widened.knots(), Cow::Borrowed(_)));
+        assert!((widened.normalize(200.0) - 1.0).abs() < 1e-6);
+
+        // 直接修改定义域后节点过期，映射仍按新定义域计算
+        let mut modified = scale.clone();
+        modified.domain_max = 200.0;
+        assert_eq!(modified.normalize(200.0), widened.normalize(200.0));
+        assert_eq!(modified.normalize(120.0), widened.normalize(120.0));
+
+        // 反序列化后节点随之重建
+        let restored = LinearScale::from(LinearScaleFields {
+            domain_min: 0.0,
+            domain_max: 100.0,
+            breaks: scale.breaks().to_vec(),
+        });
+        assert_eq!(restored, scale);
+        assert_eq!(restored.knots().as_ref(), scale.knots().as_ref());
+    }
+
+    #[test]
+    fn test_civil_days_known_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 1, 1), 10_957);
+        assert_eq!(civil_from_days(10_957), (2000, 1, 1));
+        assert_eq!(civil_from_days(-25_567), (1900, 1, 1));
+    }
+
+    #[test]
+    fn test_civil_days_leap_and_century_years() {
+        // 2000 能被 400 整除，是闰年
+        assert_eq!(
+            civil_from_days(days_from_civil(2000, 3, 1) - 1),
+            (2000, 2, 29)
+        );
+        assert_eq!(
+            days_from_civil(2000, 3, 1) - days_from_civil(2000, 2, 28),
+            2
+        );
+        // 1900 与 2100 能被 100 整除但不能被 400 整除，不是闰年
+        assert_eq!(
+            civil_from_days(days_from_civil(1900, 3, 1) - 1),
+            (1900, 2, 28)
+        );
+        assert_eq!(
+            civil_from_days(days_from_civil(2100, 3, 1) - 1),
+            (2100, 2, 28)
+        );
+        // 普通闰年与平年
+        assert_eq!(
+            civil_from_days(days_from_civil(2024, 3, 1) - 1),
+            (2024, 2, 29)
+        );
+        assert_eq!(
+            civil_from_days(days_from_civil(2023, 3, 1) - 1),
+            (2023, 2, 28)
+        );
+        // 一年的天数
+        for (year, days) in [
+            (1600, 366),
+            (1900, 365),
+            (2000, 366),
+            (2023, 365),
+            (2024, 366),
+        ] {
+            assert_eq!(
+                days_from_civil(year + 1, 1, 1) - days_from_civil(year, 1, 1),
+                days
+            );
+        }
+    }
+
+    #[test]
+    fn test_civil_days_round_trip() {
+        // 覆盖纪元前后各数百年，包含 400 年周期的边界
+        for days in (-200_000..200_000).step_by(7) {
+            let (year, month, day) = civil_from_days(days);
+            assert!((1..=12).contains(&month) && (1..=31).contains(&day));
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_month_boundaries() {
+        const MONTH_DAYS: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        for year in [1899, 1900, 1999, 2000, 2023, 2024] {
+            let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+            for month in 1..=12u32 {
+                let index = year * 12 + month as i64 - 1;
+                let length = (month_start(index + 1) - month_start(index)) / MS_PER_DAY;
+                let expected = MONTH_DAYS[month as usize - 1] + i64::from(leap && month == 2);
+                assert_eq!(length, expected, "{}-{:02}", year, month);
+
+                // 月末最后一天与下月第一天相邻
+                let last = days_from_civil(year, month, expected as u32);
+                let (next_year, next_month, next_day) = civil_from_days(last + 1);
+                assert_eq!(next_day, 1);
+                assert_eq!(next_year * 12 + next_month as i64 - 1, index + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_scale_month_ticks() {
+        // 2024-01-10 到 2024-05-20，每月刻度落在各月第一天
+        let start = days_from_civil(2024, 1, 10) * MS_PER_DAY;
+        let end = days_from_civil(2024, 5, 20) * MS_PER_DAY;
+        let ticks = TimeInterval::new(TimeUnit::Month, 1).ticks(start, end);
+        let dates: Vec<_> = ticks
+            .iter()
+            .map(|&t| civil_from_days(t.div_euclid(MS_PER_DAY)))
+            .collect();
+        assert_eq!(
+            dates,
+            vec![(2024, 2, 1), (2024, 3, 1), (2024, 4, 1), (2024, 5, 1)]
+        );
+        assert_eq!(TimeFormat::for_timestamps(&ticks), TimeFormat::Month);
+        assert_eq!(TimeFormat::Month.format(ticks[1]), "Mar 2024");
+    }
+
+    #[test]
+    fn test_tick_formatters() {
+        let labels = |formatter: TickFormatter, scale: LinearScale| -> Vec<String> {
+            scale
+                .ticks(3)
+                .into_iter()
+                .map(|tick| formatter.format(tick))
+                .collect()
+        };
+
+        assert_eq!(
+            labels(TickFormatter::percent(0), LinearScale::new(0.0, 1.0)),
+            ["0%", "50%", "100%"]
+        );
+        assert_eq!(
+            labels(TickFormatter::si(1), LinearScale::new(0.0, 3_000_000.0)),
+            ["0", "1M", "2M", "3M"]
+        );
+        assert_eq!(
+            labels(TickFormatter::scientific(1), LinearScale::new(0.0, 2000.0)),
+            ["0.0e0", "1.0e3", "2.0e3"]
+        );
+
+        let currency = TickFormatter::new(|value| format!("${:.2}", value));
+        assert_eq!(
+            labels(currency, LinearScale::new(0.0, 10.0)),
+            ["$0.00", "$5.00", "$10.00"]
+        );
+        assert_eq!(TickFormatter::si(2).format(0.0042), "4.2m");
+        assert_eq!(TickFormatter::fixed(2).format(1.5), "1.50");
+    }
+}
@@ -11,17 +11,17 @@ pub struct Color {
 
 impl Color {
     /// 创建新的颜色
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
 
     /// RGB 颜色（不透明）
-    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
         Self { r, g, b, a: 1.0 }
     }
 
     /// RGBA 颜色（带透明度）
-    pub fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
 
@@ -52,6 +52,34 @@ impl Color {
         ))
     }
 
+    /// 转换为线性 RGB，透明度不变
+    ///
+    /// 颜色分量按 sRGB 编码存储（与 hex、SVG/CSS 的约定一致）；插值与 alpha 混合
+    /// 应在线性空间中进行，否则中间色偏暗
+    pub fn to_linear(&self) -> Color {
+        Color::new(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+            self.a,
+        )
+    }
+
+    /// 由 sRGB 编码的分量创建线性 RGB 颜色，等价于 `Color::rgba(r, g, b, a).to_linear()`
+    pub fn from_srgb(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color::rgba(r, g, b, a).to_linear()
+    }
+
+    /// 将线性 RGB 颜色编码回 sRGB，与 [`to_linear`](Self::to_linear) 互逆
+    pub fn to_srgb(&self) -> Color {
+        Color::new(
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+            self.a,
+        )
+    }
+
     /// 预定义颜色常量
     pub const BLACK: Color = Color {
         r: 0.0,
@@ -91,6 +119,24 @@ impl Color {
     };
 }
 
+/// sRGB 传递函数的逆：编码值转换为线性光强度
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// sRGB 传递函数：线性光强度转换为编码值
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 /// 线条样式
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum LineStyle {
@@ -100,6 +146,52 @@ pub enum LineStyle {
     DashDot,
 }
 
+impl LineStyle {
+    /// 所有线条样式，按多系列循环的默认顺序排列
+    pub const ALL: [LineStyle; 4] = [
+        LineStyle::Solid,
+        LineStyle::Dashed,
+        LineStyle::Dotted,
+        LineStyle::DashDot,
+    ];
+
+    /// 虚线模式：交替的实段与间隔长度（像素），按线宽缩放；实线返回 None
+    pub fn dash_pattern(&self, width: f32) -> Option<Vec<f32>> {
+        let unit = width.max(1.0);
+        let pattern: &[f32] = match self {
+            LineStyle::Solid => return None,
+            LineStyle::Dashed => &[6.0, 4.0],
+            LineStyle::Dotted => &[1.0, 2.0],
+            LineStyle::DashDot => &[6.0, 3.0, 1.0, 3.0],
+        };
+        Some(pattern.iter().map(|&len| len * unit).collect())
+    }
+}
+
+/// 线端样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineCap {
+    /// 平头，止于端点
+    #[default]
+    Butt,
+    /// 圆头
+    Round,
+    /// 方头，超出端点半个线宽
+    Square,
+}
+
+/// 线段连接样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineJoin {
+    /// 尖角，夹角过小时退化为斜切
+    #[default]
+    Miter,
+    /// 圆角
+    Round,
+    /// 斜切
+    Bevel,
+}
+
 /// 点的样式
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum MarkerStyle {
@@ -111,6 +203,110 @@ pub enum MarkerStyle {
     Diamond,
 }
 
+/// 网格线样式：主网格线位于主刻度处，次网格线均分相邻主刻度间隔
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GridStyle {
+    /// 是否显示主网格线
+    pub show_major: bool,
+    /// 主网格线颜色
+    pub major_color: Color,
+    /// 主网格线宽度
+    pub major_width: f32,
+    /// 是否显示次网格线
+    pub show_minor: bool,
+    /// 次网格线颜色
+    pub minor_color: Color,
+    /// 次网格线宽度
+    pub minor_width: f32,
+    /// 相邻主刻度之间的等分数（次网格线数量为该值减一）
+    pub minor_divisions: usize,
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        Self {
+            show_major: true,
+            major_color: Color::rgba(0.0, 0.0, 0.0, 0.12),
+            major_width: 1.0,
+            show_minor: false,
+            minor_color: Color::rgba(0.0, 0.0, 0.0, 0.06),
+            minor_width: 0.5,
+            minor_divisions: 5,
+        }
+    }
+}
+
+impl GridStyle {
+    /// 创建默认网格样式（仅主网格线）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置主网格线颜色和宽度
+    pub fn major(mut self, color: Color, width: f32) -> Self {
+        self.major_color = color;
+        self.major_width = width;
+        self
+    }
+
+    /// 设置次网格线颜色和宽度
+    pub fn minor(mut self, color: Color, width: f32) -> Self {
+        self.minor_color = color;
+        self.minor_width = width;
+        self
+    }
+
+    /// 设置是否显示主网格线
+    pub fn show_major(mut self, show: bool) -> Self {
+        self.show_major = show;
+        self
+    }
+
+    /// 设置是否显示次网格线
+    pub fn show_minor(mut self, show: bool) -> Self {
+        self.show_minor = show;
+        self
+    }
+
+    /// 设置相邻主刻度之间的等分数
+    pub fn minor_divisions(mut self, divisions: usize) -> Self {
+        self.minor_divisions = divisions.max(1);
+        self
+    }
+}
+
+/// 投影（drop shadow）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Shadow {
+    /// 相对图元的偏移 (dx, dy)，屏幕坐标，向右下为正
+    pub offset: (f32, f32),
+    /// 模糊半径（像素），0 为硬边投影
+    pub blur: f32,
+    /// 投影颜色，通常为半透明的深色
+    pub color: Color,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            offset: (2.0, 2.0),
+            blur: 4.0,
+            color: Color::rgba(0.0, 0.0, 0.0, 0.3),
+        }
+    }
+}
+
+impl Shadow {
+    /// 创建投影
+    pub fn new(offset: (f32, f32), blur: f32, color: Color) -> Self {
+        Self {
+            offset,
+            blur: blur.max(0.0),
+            color,
+        }
+    }
+}
+
 /// 视觉样式配置
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Style {
@@ -124,10 +320,25 @@ pub struct Style {
     pub line_style: LineStyle,
     /// 点标记样式
     pub marker_style: MarkerStyle,
-    /// 点大小
+    /// 点大小（标记半径，像素）
     pub marker_size: f32,
     /// 透明度 (0.0 - 1.0)
     pub opacity: f32,
+    /// 绘制层级：z 越大越靠前，相同 z 按插入顺序绘制
+    #[serde(default)]
+    pub z: f32,
+    /// 线端样式
+    #[serde(default)]
+    pub line_cap: LineCap,
+    /// 线段连接样式
+    #[serde(default)]
+    pub line_join: LineJoin,
+    /// 整圆的细分段数；None 时按像素半径自适应，圆弧按扫过的角度等比例分段
+    #[serde(default)]
+    pub circle_segments: Option<u32>,
+    /// 投影；目前矩形与圆形会绘制
+    #[serde(default)]
+    pub shadow: Option<Shadow>,
 }
 
 impl Default for Style {
@@ -140,6 +351,11 @@ impl Default for Style {
             marker_style: MarkerStyle::Circle,
             marker_size: 3.0,
             opacity: 1.0,
+            z: 0.0,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+            circle_segments: None,
+            shadow: None,
         }
     }
 }
@@ -175,6 +391,243 @@ impl Style {
         self.opacity = opacity.clamp(0.0, 1.0);
         self
     }
+
+    /// 设置绘制层级（z 越大越靠前）
+    pub fn z(mut self, z: f32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// 设置线端样式
+    pub fn line_cap(mut self, cap: LineCap) -> Self {
+        self.line_cap = cap;
+        self
+    }
+
+    /// 设置线段连接样式
+    pub fn line_join(mut self, join: LineJoin) -> Self {
+        self.line_join = join;
+        self
+    }
+
+    /// 固定整圆的细分段数（至少 3），覆盖按半径自适应的细分
+    pub fn circle_segments(mut self, segments: u32) -> Self {
+        self.circle_segments = Some(segments.max(3));
+        self
+    }
+
+    /// 设置投影
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// 预设：散点标记（蓝色实心圆，无边框）
+    pub fn marker_default() -> Self {
+        Self {
+            fill_color: Some(Color::rgb(0.2, 0.4, 0.8)),
+            stroke_color: None,
+            marker_style: MarkerStyle::Circle,
+            marker_size: 5.0,
+            ..Self::default()
+        }
+    }
+
+    /// 预设：细线（1 像素黑色实线，无填充）
+    pub fn thin_line() -> Self {
+        Self {
+            fill_color: None,
+            stroke_color: Some(Color::BLACK),
+            stroke_width: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// 预设：粗线（3 像素黑色实线，圆头圆角，无填充）
+    pub fn bold_line() -> Self {
+        Self {
+            fill_color: None,
+            stroke_color: Some(Color::BLACK),
+            stroke_width: 3.0,
+            line_cap: LineCap::Round,
+            line_join: LineJoin::Round,
+            ..Self::default()
+        }
+    }
+
+    /// 预设：网格线（半透明细灰线，绘制在图表下层）
+    pub fn gridline() -> Self {
+        Self {
+            fill_color: None,
+            stroke_color: Some(Color::rgba(0.0, 0.0, 0.0, 0.12)),
+            stroke_width: 1.0,
+            z: -1.0,
+            ..Self::default()
+        }
+    }
+
+    /// 将 `overlay` 中为 `Some` 的字段覆盖到当前样式上，返回新样式
+    ///
+    /// 未设置（`None`）的字段保持不变；颜色、细分段数与投影可用
+    /// `Some(None)` 显式清除
+    pub fn merge(&self, overlay: &StyleOverlay) -> Style {
+        let mut merged = self.clone();
+        if let Some(fill_color) = overlay.fill_color {
+            merged.fill_color = fill_color;
+        }
+        if let Some(stroke_color) = overlay.stroke_color {
+            merged.stroke_color = stroke_color;
+        }
+        if let Some(stroke_width) = overlay.stroke_width {
+            merged.stroke_width = stroke_width;
+        }
+        if let Some(line_style) = overlay.line_style {
+            merged.line_style = line_style;
+        }
+        if let Some(marker_style) = overlay.marker_style {
+            merged.marker_style = marker_style;
+        }
+        if let Some(marker_size) = overlay.marker_size {
+            merged.marker_size = marker_size;
+        }
+        if let Some(opacity) = overlay.opacity {
+            merged.opacity = opacity;
+        }
+        if let Some(z) = overlay.z {
+            merged.z = z;
+        }
+        if let Some(line_cap) = overlay.line_cap {
+            merged.line_cap = line_cap;
+        }
+        if let Some(line_join) = overlay.line_join {
+            merged.line_join = line_join;
+        }
+        if let Some(circle_segments) = overlay.circle_segments {
+            merged.circle_segments = circle_segments;
+        }
+        if let Some(shadow) = overlay.shadow {
+            merged.shadow = shadow;
+        }
+        merged
+    }
+}
+
+/// 样式覆盖层：每个字段为 `Some` 时覆盖基础样式的对应字段，用于
+/// [`Style::merge`] 在预设样式上做局部调整
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StyleOverlay {
+    /// 填充颜色；`Some(None)` 表示取消填充
+    pub fill_color: Option<Option<Color>>,
+    /// 边框颜色；`Some(None)` 表示取消边框
+    pub stroke_color: Option<Option<Color>>,
+    /// 边框宽度
+    pub stroke_width: Option<f32>,
+    /// 线条样式
+    pub line_style: Option<LineStyle>,
+    /// 点标记样式
+    pub marker_style: Option<MarkerStyle>,
+    /// 点大小
+    pub marker_size: Option<f32>,
+    /// 透明度
+    pub opacity: Option<f32>,
+    /// 绘制层级
+    pub z: Option<f32>,
+    /// 线端样式
+    pub line_cap: Option<LineCap>,
+    /// 线段连接样式
+    pub line_join: Option<LineJoin>,
+    /// 整圆的细分段数；`Some(None)` 表示恢复自适应细分
+    pub circle_segments: Option<Option<u32>>,
+    /// 投影；`Some(None)` 表示取消投影
+    pub shadow: Option<Option<Shadow>>,
+}
+
+impl StyleOverlay {
+    /// 创建空的覆盖层（不覆盖任何字段）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 覆盖填充颜色
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.fill_color = Some(Some(color));
+        self
+    }
+
+    /// 取消填充
+    pub fn no_fill(mut self) -> Self {
+        self.fill_color = Some(None);
+        self
+    }
+
+    /// 覆盖边框颜色和宽度
+    pub fn stroke(mut self, color: Color, width: f32) -> Self {
+        self.stroke_color = Some(Some(color));
+        self.stroke_width = Some(width);
+        self
+    }
+
+    /// 取消边框
+    pub fn no_stroke(mut self) -> Self {
+        self.stroke_color = Some(None);
+        self
+    }
+
+    /// 覆盖边框宽度
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    /// 覆盖线条样式
+    pub fn line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = Some(line_style);
+        self
+    }
+
+    /// 覆盖点标记样式和大小
+    pub fn marker(mut self, style: MarkerStyle, size: f32) -> Self {
+        self.marker_style = Some(style);
+        self.marker_size = Some(size);
+        self
+    }
+
+    /// 覆盖透明度
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+
+    /// 覆盖绘制层级
+    pub fn z(mut self, z: f32) -> Self {
+        self.z = Some(z);
+        self
+    }
+
+    /// 覆盖线端样式
+    pub fn line_cap(mut self, cap: LineCap) -> Self {
+        self.line_cap = Some(cap);
+        self
+    }
+
+    /// 覆盖线段连接样式
+    pub fn line_join(mut self, join: LineJoin) -> Self {
+        self.line_join = Some(join);
+        self
+    }
+
+    /// 覆盖整圆的细分段数（至少 3）
+    pub fn circle_segments(mut self, segments: u32) -> Self {
+        self.circle_segments = Some(Some(segments.max(3)));
+        self
+    }
+
+    /// 覆盖投影
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(Some(shadow));
+        self
+    }
 }
 
 // 为Color实现运算符重载
@@ -216,3 +669,97 @@ impl std::ops::Mul<Color> for Color {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_linear_known_values() {
+        let linear = Color::rgb(0.5, 0.0, 1.0).to_linear();
+        assert!((linear.r - 0.214).abs() < 1e-3);
+        assert_eq!(linear.g, 0.0);
+        assert!((linear.b - 1.0).abs() < 1e-6);
+
+        // 0.04045 以下为线性段，以上为幂函数段，两段在阈值处连续
+        assert!((srgb_to_linear(0.04) - 0.04 / 12.92).abs() < 1e-7);
+        assert!((srgb_to_linear(0.05) - ((0.05 + 0.055) / 1.055f32).powf(2.4)).abs() < 1e-7);
+        assert!((srgb_to_linear(0.04045) - srgb_to_linear(0.040451)).abs() < 1e-6);
+        assert!((linear_to_srgb(0.0031308) - linear_to_srgb(0.0031309)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_linear_srgb_round_trip() {
+        for i in 0..=100 {
+            let c = i as f32 / 100.0;
+            let color = Color::rgb(c, 1.0 - c, c * 0.5);
+            let back = color.to_linear().to_srgb();
+            assert!((back.r - color.r).abs() < 1e-5);
+            assert!((back.g - color.g).abs() < 1e-5);
+            assert!((back.b - color.b).abs() < 1e-5);
+        }
+        assert_eq!(
+            Color::from_srgb(0.5, 0.25, 0.75, 1.0),
+            Color::rgb(0.5, 0.25, 0.75).to_linear()
+        );
+    }
+
+    #[test]
+    fn test_color_space_conversion_keeps_alpha() {
+        let color = Color::rgba(0.5, 0.5, 0.5, 0.3);
+        assert_eq!(color.to_linear().a, 0.3);
+        assert_eq!(color.to_srgb().a, 0.3);
+        assert_eq!(Color::from_srgb(0.5, 0.5, 0.5, 0.3).a, 0.3);
+    }
+
+    #[test]
+    fn test_merge_empty_overlay_keeps_style() {
+        let base = Style::bold_line();
+        assert_eq!(base.merge(&StyleOverlay::new()), base);
+    }
+
+    #[test]
+    fn test_merge_overrides_some_fields() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let merged = Style::thin_line().merge(&StyleOverlay::new().stroke(red, 2.0).z(3.0));
+
+        assert_eq!(merged.stroke_color, Some(red));
+        assert_eq!(merged.stroke_width, 2.0);
+        assert_eq!(merged.z, 3.0);
+        // 未覆盖的字段保持预设值
+        assert_eq!(merged.fill_color, None);
+        assert_eq!(merged.line_cap, LineCap::Butt);
+    }
+
+    #[test]
+    fn test_merge_applies_values_equal_to_default() {
+        // 覆盖值与 Style::default() 相同也应生效
+        let merged = Style::bold_line().merge(
+            &StyleOverlay::new()
+                .stroke_width(1.0)
+                .line_cap(LineCap::Butt)
+                .fill_color(Color::BLUE),
+        );
+        assert_eq!(merged.stroke_width, 1.0);
+        assert_eq!(merged.line_cap, LineCap::Butt);
+        assert_eq!(merged.fill_color, Some(Color::BLUE));
+    }
+
+    #[test]
+    fn test_merge_can_clear_optional_fields() {
+        let base = Style::new().shadow(Shadow::default()).circle_segments(8);
+        let overlay = StyleOverlay {
+            shadow: Some(None),
+            circle_segments: Some(None),
+            ..StyleOverlay::new()
+        }
+        .no_fill()
+        .no_stroke();
+
+        let merged = base.merge(&overlay);
+        assert_eq!(merged.fill_color, None);
+        assert_eq!(merged.stroke_color, None);
+        assert_eq!(merged.shadow, None);
+        assert_eq!(merged.circle_segments, None);
+    }
+}
@@ -0,0 +1,174 @@
+//! 数据加载工具
+//! 从 CSV 文件读取带表头的数值列，可直接交给 Figure2D 绘图
+
+use std::io::Read;
+use std::path::Path;
+use vizuara_core::{Result, VizuaraError};
+
+/// 按表头命名的 f32 数值列
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTable {
+    headers: Vec<String>,
+    columns: Vec<Vec<f32>>,
+}
+
+impl DataTable {
+    /// 列名（按文件中的顺序）
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// 按列名获取数据
+    pub fn column(&self, name: &str) -> Option<&[f32]> {
+        let index = self.headers.iter().position(|h| h == name)?;
+        Some(&self.columns[index])
+    }
+
+    /// 按列名获取数据，列不存在时返回错误
+    pub fn require_column(&self, name: &str) -> Result<&[f32]> {
+        self.column(name).ok_or_else(|| {
+            VizuaraError::InvalidData(format!(
+                "CSV 中不存在列 '{}'（可用列: {}）",
+                name,
+                self.headers.join(", ")
+            ))
+        })
+    }
+
+    /// 组合两列为 (x, y) 点列
+    pub fn xy(&self, x_col: &str, y_col: &str) -> Result<Vec<(f32, f32)>> {
+        let xs = self.require_column(x_col)?;
+        let ys = self.require_column(y_col)?;
+        Ok(xs.iter().copied().zip(ys.iter().copied()).collect())
+    }
+
+    /// 数据行数（不含表头）
+    pub fn len(&self) -> usize {
+        self.columns.first().map_or(0, |c| c.len())
+    }
+
+    /// 是否没有数据行
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 读取 CSV 文件：首行为表头，其余单元格须为数值；空单元格视为缺失数据（NaN）
+pub fn load_csv<P: AsRef<Path>>(path: P) -> Result<DataTable> {
+    let file = std::fs::File::open(path.as_ref())?;
+    parse_csv(file)
+}
+
+/// 从任意输入流解析 CSV，规则同 [`load_csv`]
+pub fn parse_csv<R: Read>(input: R) -> Result<DataTable> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(input);
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(csv_error)?
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+    let mut columns = vec![Vec::new(); headers.len()];
+
+    for (row, record) in reader.records().enumerate() {
+        let record = record.map_err(csv_error)?;
+        // 行号从 1 开始计数，第 1 行为表头
+        let line = row + 2;
+        for (col, cell) in record.iter().enumerate() {
+            let value = if cell.is_empty() {
+                f32::NAN
+            } else {
+                cell.parse::<f32>().map_err(|_| {
+                    VizuaraError::InvalidData(format!(
+                        "CSV 第 {} 行第 {} 列（'{}'）不是数值: '{}'",
+                        line,
+                        col + 1,
+                        headers[col],
+                        cell
+                    ))
+                })?
+            };
+            columns[col].push(value);
+        }
+    }
+
+    Ok(DataTable { headers, columns })
+}
+
+fn csv_error(error: csv::Error) -> VizuaraError {
+    VizuaraError::InvalidData(format!("CSV 解析失败: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mod2d::Figure2D;
+
+    #[test]
+    fn test_parse_csv_columns() {
+        let table = parse_csv("time, value\n0, 1.5\n1, 2.5\n2, -3\n".as_bytes()).unwrap();
+
+        assert_eq!(table.headers(), ["time", "value"]);
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.column("time"), Some(&[0.0, 1.0, 2.0][..]));
+        assert_eq!(table.column("value"), Some(&[1.5, 2.5, -3.0][..]));
+        assert_eq!(table.column("missing"), None);
+        assert_eq!(
+            table.xy("time", "value").unwrap(),
+            vec![(0.0, 1.5), (1.0, 2.5), (2.0, -3.0)]
+        );
+
+        let empty = parse_csv("a,b\n".as_bytes()).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_empty_cells_are_nan() {
+        let table = parse_csv("x,y\n0,1\n1,\n2,3\n".as_bytes()).unwrap();
+        let y = table.column("y").unwrap();
+        assert_eq!(y[0], 1.0);
+        assert!(y[1].is_nan());
+        assert_eq!(y[2], 3.0);
+    }
+
+    #[test]
+    fn test_non_numeric_cell_reports_location() {
+        let error = parse_csv("x,y\n0,1\n1,abc\n".as_bytes()).unwrap_err();
+        let message = error.to_string();
+        // 第 3 行（表头为第 1 行）第 2 列
+        assert!(matches!(error, VizuaraError::InvalidData(_)));
+        assert!(message.contains("第 3 行"), "{}", message);
+        assert!(message.contains("第 2 列"), "{}", message);
+        assert!(message.contains("'y'"), "{}", message);
+        assert!(message.contains("'abc'"), "{}", message);
+    }
+
+    #[test]
+    fn test_missing_column_lists_available() {
+        let table = parse_csv("x,y\n0,1\n".as_bytes()).unwrap();
+        let message = table.xy("x", "z").unwrap_err().to_string();
+        assert!(message.contains("'z'"), "{}", message);
+        assert!(message.contains("x, y"), "{}", message);
+    }
+
+    #[test]
+    fn test_plot_csv() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, "t,temperature\n0,20.5\n1,21.0\n2,19.5\n").unwrap();
+
+        let mut fig = Figure2D::new(400.0, 300.0);
+        fig.subplot_full();
+        fig.plot_csv(&path, "t", "temperature").unwrap();
+        assert_eq!(fig.series_count(), 1);
+
+        assert!(fig.plot_csv(&path, "t", "humidity").is_err());
+        assert!(fig
+            .plot_csv(dir.path().join("missing.csv"), "t", "temperature")
+            .is_err());
+        assert_eq!(fig.series_count(), 1);
+    }
+}
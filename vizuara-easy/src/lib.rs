@@ -3,9 +3,11 @@
 
 pub mod mod2d;
 pub mod mod3d;
+#[cfg(feature = "csv")]
+pub mod data;
 
 pub mod prelude {
-    pub use crate::mod2d::{Figure2D, Colors, testdata};
+    pub use crate::mod2d::{AxisScaleKind, Figure2D, Colors, testdata};
     pub use crate::mod3d::Figure3D;
     pub use crate::{figure, figure3d, figure_std, figure_large};
     pub use vizuara_core::Color;
@@ -1,11 +1,126 @@
-use vizuara_core::{Color, Result};
+use vizuara_core::{Color, LinearScale, LogScale, Result, VizuaraError};
+use vizuara_export::ExportManager;
 use vizuara_plots::{
-    AreaChart, BarPlot, BoxPlot, ContourPlot, DensityPlot, Heatmap, Histogram,
+    AreaChart, AreaSeries, BarPlot, BoxPlot, ContourPlot, DensityPlot, Heatmap, Histogram,
     LinePlot, PieChart, PlotArea, RadarChart, ScatterPlot, ViolinPlot
 };
-use vizuara_scene::{Figure, Scene};
+use vizuara_scene::{Figure, Scene, YAxisSide};
+use vizuara_themes::{ColorCycle, ThemeManager};
 use vizuara_window::show_figure;
 
+/// 坐标轴刻度类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisScaleKind {
+    Linear,
+    Log,
+}
+
+impl std::str::FromStr for AxisScaleKind {
+    type Err = VizuaraError;
+
+    /// 解析 "linear"/"log"（不区分大小写）
+    fn from_str(kind: &str) -> Result<Self> {
+        if kind.eq_ignore_ascii_case("linear") {
+            Ok(AxisScaleKind::Linear)
+        } else if kind.eq_ignore_ascii_case("log") {
+            Ok(AxisScaleKind::Log)
+        } else {
+            Err(VizuaraError::ConfigError(format!("未知的坐标轴刻度类型: '{}'（可选 linear/log）", kind)))
+        }
+    }
+}
+
+/// 延迟构建的数据系列：提交子图时按坐标轴设置统一比例尺
+enum Series {
+    Line { data: Vec<(f32, f32)>, color: Color, width: f32 },
+    Scatter { data: Vec<(f32, f32)>, color: Color, size: f32 },
+    Bar { categories: Vec<String>, values: Vec<f32>, color: Color },
+    Area { data: Vec<(f32, f32)>, color: Color, alpha: f32 },
+    Hist { data: Vec<f32>, bins: usize, color: Color },
+    Density { data: Vec<f32>, color: Color },
+}
+
+impl Series {
+    fn color(&self) -> Color {
+        match self {
+            Series::Line { color, .. }
+            | Series::Scatter { color, .. }
+            | Series::Bar { color, .. }
+            | Series::Area { color, .. }
+            | Series::Hist { color, .. }
+            | Series::Density { color, .. } => *color,
+        }
+    }
+}
+
+/// 已解析的坐标轴比例尺
+enum ResolvedScale {
+    Linear(LinearScale),
+    Log(LogScale),
+}
+
+impl ResolvedScale {
+    /// 显式范围优先（此时不再根据数据自动计算），否则由数据自动推算
+    fn resolve(kind: AxisScaleKind, limit: Option<(f32, f32)>, values: &[f32]) -> Option<Self> {
+        if kind == AxisScaleKind::Log {
+            let non_positive = values.iter().filter(|&&v| v.is_finite() && v <= 0.0).count();
+            if non_positive > 0 {
+                eprintln!("Warning: 对数坐标轴无法显示 {} 个非正数值，已忽略", non_positive);
+            }
+        }
+
+        let (min, max) = match limit {
+            Some(range) => range,
+            None => {
+                // 缺失数据（NaN）不参与范围计算，绘制时在该处断开
+                let values: Vec<f32> = values.iter().copied()
+                    .filter(|&v| v.is_finite() && (kind == AxisScaleKind::Linear || v > 0.0))
+                    .collect();
+                if values.is_empty() { return None; }
+                match kind {
+                    AxisScaleKind::Linear => {
+                        let scale = LinearScale::from_data(&values);
+                        (scale.domain_min, scale.domain_max)
+                    }
+                    AxisScaleKind::Log => {
+                        let min = values.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+                        let max = values.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+                        if min == max { (min / 10.0, max * 10.0) } else { (min, max) }
+                    }
+                }
+            }
+        };
+
+        match kind {
+            AxisScaleKind::Log if min > 0.0 && max > 0.0 && min != max => {
+                Some(ResolvedScale::Log(LogScale::base10(min, max)))
+            }
+            AxisScaleKind::Log => {
+                eprintln!("Warning: 对数坐标轴范围 ({}, {}) 无效，改用线性坐标轴", min, max);
+                Some(ResolvedScale::Linear(LinearScale::new(min, max)))
+            }
+            AxisScaleKind::Linear => Some(ResolvedScale::Linear(LinearScale::new(min, max))),
+        }
+    }
+
+    fn add_x_axis(&self, scene: Scene, title: Option<String>) -> Scene {
+        match self {
+            ResolvedScale::Linear(scale) => scene.add_x_axis(scale.clone(), title),
+            ResolvedScale::Log(log) => scene.add_log_x_axis(log.clone(), title),
+        }
+    }
+
+    fn add_y_axis(&self, scene: Scene, title: Option<String>) -> Scene {
+        match self {
+            ResolvedScale::Linear(scale) => scene.add_y_axis(scale.clone(), title),
+            ResolvedScale::Log(log) => scene.add_log_y_axis(log.clone(), title),
+        }
+    }
+}
+
+/// 多系列绘图的一组数据：(数据点, 颜色, 图例标签)
+pub type Dataset<'a> = (&'a [(f32, f32)], Color, &'a str);
+
 /// 2D Figure 便捷封装
 /// 
 /// 提供类似 Matplotlib 的简易绘图接口，支持多种图表类型和子图布局
@@ -20,6 +135,15 @@ pub struct Figure2D {
     current_title: Option<String>,
     current_xlim: Option<(f32, f32)>,
     current_ylim: Option<(f32, f32)>,
+    current_xscale: AxisScaleKind,
+    current_yscale: AxisScaleKind,
+    current_grid: bool,
+    current_legend: bool,
+    // 当前子图的数据系列及其图例标签
+    series: Vec<(Series, Option<String>)>,
+    hold: bool,
+    // 当前子图的系列颜色，第 N 个系列总是取主题调色板中的第 N 个颜色
+    color_cycle: ColorCycle,
 }
 
 impl Figure2D {
@@ -34,6 +158,13 @@ impl Figure2D {
             current_title: None,
             current_xlim: None,
             current_ylim: None,
+            current_xscale: AxisScaleKind::Linear,
+            current_yscale: AxisScaleKind::Linear,
+            current_grid: false,
+            current_legend: false,
+            series: Vec::new(),
+            hold: true,
+            color_cycle: theme_color_cycle(),
         }
     }
 
@@ -68,14 +199,7 @@ impl Figure2D {
         let y = padding + r as f32 * ch;
         self.current_scene = Some(Scene::new(PlotArea::new(x, y, cw - 20.0, ch - 20.0)));
         self.cell_index += 1;
-        
-        // 重置当前子图设置
-        self.current_xlabel = None;
-        self.current_ylabel = None;
-        self.current_title = None;
-        self.current_xlim = None;
-        self.current_ylim = None;
-        
+        self.reset_axes_state();
         self
     }
 
@@ -89,30 +213,119 @@ impl Figure2D {
         let (w, h) = self.figure.size();
         let pa = PlotArea::new(80.0, 80.0, w - 160.0, h - 160.0);
         self.current_scene = Some(Scene::new(pa));
-        
-        // 重置当前子图设置
+        self.reset_axes_state();
+        self
+    }
+
+    /// 重置当前子图的坐标轴设置与数据系列
+    fn reset_axes_state(&mut self) {
         self.current_xlabel = None;
         self.current_ylabel = None;
         self.current_title = None;
         self.current_xlim = None;
         self.current_ylim = None;
-        
+        self.current_xscale = AxisScaleKind::Linear;
+        self.current_yscale = AxisScaleKind::Linear;
+        self.current_grid = false;
+        self.current_legend = false;
+        self.series.clear();
+        self.color_cycle = theme_color_cycle();
+    }
+
+    /// 记录数据系列（需先创建子图）；hold off 时替换已有系列
+    fn push_series(&mut self, series: Series, label: Option<&str>) {
+        if self.current_scene.is_none() {
+            return;
+        }
+        if !self.hold {
+            self.series.clear();
+        }
+        self.series.push((series, label.map(|l| l.to_string())));
+    }
+
+    /// 保持模式：后续 plot/scatter/bar 叠加到同一坐标轴（默认开启）
+    pub fn hold_on(&mut self) -> &mut Self {
+        self.hold = true;
         self
     }
 
-    pub fn scatter(&mut self, data: &[(f32, f32)], color: Color, size: f32) -> &mut Self {
-        let scatter = ScatterPlot::new().data(data).color(color).size(size).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_scatter_plot(scatter));
+    /// 关闭保持模式：后续 plot/scatter/bar 替换当前子图中的系列
+    pub fn hold_off(&mut self) -> &mut Self {
+        self.hold = false;
+        self
+    }
+
+    /// 是否处于保持模式
+    pub fn is_hold(&self) -> bool {
+        self.hold
+    }
+
+    /// 清空当前子图中的所有图表，保留子图位置与坐标轴设置
+    pub fn clear(&mut self) -> &mut Self {
+        self.series.clear();
+        self.color_cycle.reset();
+        self.current_scene = self.current_scene.take().map(|scene| Scene::new(scene.plot_area()));
+        self
+    }
+
+    /// 当前子图中的系列数量
+    pub fn series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    /// 从当前主题调色板中取下一个系列颜色
+    ///
+    /// hold off 时下一个系列会替换已有系列，颜色总是从调色板开头取
+    pub fn next_color(&mut self) -> Color {
+        if !self.hold {
+            self.color_cycle.reset();
         }
+        self.color_cycle.next_color()
+    }
+
+    /// 当前子图的系列颜色循环，可用于预览后续系列的颜色
+    pub fn color_cycle(&self) -> &ColorCycle {
+        &self.color_cycle
+    }
+
+    /// 显示或隐藏当前子图的图例（列出带标签的系列）
+    pub fn legend(&mut self, show: bool) -> &mut Self {
+        self.current_legend = show;
         self
     }
 
+    pub fn scatter(&mut self, data: &[(f32, f32)], color: Color, size: f32) -> &mut Self {
+        self.push_series(Series::Scatter { data: data.to_vec(), color, size }, None);
+        self
+    }
+
+    /// 折线图；Y 值为 NaN 的点视为缺失数据，折线在该处断开
     pub fn plot(&mut self, data: &[(f32, f32)], color: Color, width: f32) -> &mut Self {
-        let line = LinePlot::new().data(data).color(color).line_width(width).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_line_plot(line));
-        }
+        self.push_series(Series::Line { data: data.to_vec(), color, width }, None);
+        self
+    }
+
+    /// 使用调色板自动配色的折线图
+    pub fn plot_auto(&mut self, data: &[(f32, f32)], label: &str) -> &mut Self {
+        let color = self.next_color();
+        self.plot_with_label(data, color, 2.0, label)
+    }
+
+    /// 使用调色板自动配色的散点图
+    pub fn scatter_auto(&mut self, data: &[(f32, f32)], label: &str) -> &mut Self {
+        let color = self.next_color();
+        self.scatter_with_label(data, color, 5.0, label)
+    }
+
+    /// 使用调色板自动配色的条形图
+    pub fn bar_auto(&mut self, categories: &[&str], values: &[f32], label: &str) -> &mut Self {
+        let color = self.next_color();
+        let series = Series::Bar {
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            values: values.to_vec(),
+            color,
+        };
+        self.push_series(series, Some(label));
         self
     }
 
@@ -148,24 +361,40 @@ impl Figure2D {
         self
     }
 
+    /// 设置X轴刻度类型；字符串可用 `"log".parse()?` 转换
+    pub fn xscale(&mut self, kind: AxisScaleKind) -> &mut Self {
+        self.current_xscale = kind;
+        self
+    }
+
+    /// 设置Y轴刻度类型；字符串可用 `"log".parse()?` 转换
+    pub fn yscale(&mut self, kind: AxisScaleKind) -> &mut Self {
+        self.current_yscale = kind;
+        self
+    }
+
+    /// 显示或隐藏网格线（`grid(rows, cols)` 用于子图布局）
+    pub fn grid_lines(&mut self, show: bool) -> &mut Self {
+        self.current_grid = show;
+        self
+    }
+
     // ================= 更多图表类型 =================
     
     /// 添加条形图
     pub fn bar(&mut self, categories: &[&str], values: &[f32], color: Color) -> &mut Self {
-        let bar = BarPlot::new().categories_values(categories, values).fill_color(color).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_bar_plot(bar));
-        }
+        let series = Series::Bar {
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            values: values.to_vec(),
+            color,
+        };
+        self.push_series(series, None);
         self
     }
     
     /// 添加直方图
     pub fn hist(&mut self, data: &[f32], bins: usize, color: Color) -> &mut Self {
-        use vizuara_plots::BinningStrategy;
-        let hist = Histogram::new().data(data).binning(BinningStrategy::FixedCount(bins)).fill_color(color).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_histogram(hist));
-        }
+        self.push_series(Series::Hist { data: data.to_vec(), bins, color }, None);
         self
     }
     
@@ -219,21 +448,15 @@ impl Figure2D {
         self
     }
     
-    /// 添加面积图
-    pub fn area(&mut self, data: &[(f32, f32)], _color: Color, _alpha: f32) -> &mut Self {
-        let area = AreaChart::new().single_series("area", data).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_area_chart(area));
-        }
+    /// 添加面积图，`alpha` 为填充的不透明度（边界线保持不透明）
+    pub fn area(&mut self, data: &[(f32, f32)], color: Color, alpha: f32) -> &mut Self {
+        self.push_series(Series::Area { data: data.to_vec(), color, alpha }, None);
         self
     }
     
     /// 添加密度图
     pub fn density(&mut self, data: &[f32], color: Color) -> &mut Self {
-        let density = DensityPlot::new().data(data).fill_color(Some(color));
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_density_plot(density));
-        }
+        self.push_series(Series::Density { data: data.to_vec(), color }, None);
         self
     }
     
@@ -261,51 +484,159 @@ impl Figure2D {
     // ================= 快捷绘图方法 =================
     
     /// 快速绘制带标签的线图
-    pub fn plot_with_label(&mut self, data: &[(f32, f32)], color: Color, width: f32, _label: &str) -> &mut Self {
-        let line = LinePlot::new().data(data).color(color).line_width(width).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_line_plot(line));
-        }
+    pub fn plot_with_label(&mut self, data: &[(f32, f32)], color: Color, width: f32, label: &str) -> &mut Self {
+        self.push_series(Series::Line { data: data.to_vec(), color, width }, Some(label));
         self
     }
     
     /// 快速绘制带标签的散点图
-    pub fn scatter_with_label(&mut self, data: &[(f32, f32)], color: Color, size: f32, _label: &str) -> &mut Self {
-        let scatter = ScatterPlot::new().data(data).color(color).size(size).auto_scale();
-        if let Some(scene) = self.current_scene.take() {
-            self.current_scene = Some(scene.add_scatter_plot(scatter));
-        }
+    pub fn scatter_with_label(&mut self, data: &[(f32, f32)], color: Color, size: f32, label: &str) -> &mut Self {
+        self.push_series(Series::Scatter { data: data.to_vec(), color, size }, Some(label));
         self
     }
     
     /// 多条线图（不同颜色）
-    pub fn multiplot(&mut self, datasets: &[(&[(f32, f32)], Color, &str)], width: f32) -> &mut Self {
+    pub fn multiplot(&mut self, datasets: &[Dataset<'_>], width: f32) -> &mut Self {
         for (data, color, label) in datasets {
             self.plot_with_label(data, *color, width, label);
         }
         self
     }
     
+    /// 从 CSV 文件读取两列绘制折线图（调色板自动配色，以 Y 列名作为图例）
+    #[cfg(feature = "csv")]
+    pub fn plot_csv<P: AsRef<std::path::Path>>(&mut self, path: P, x_col: &str, y_col: &str) -> Result<&mut Self> {
+        let data = crate::data::load_csv(path)?.xy(x_col, y_col)?;
+        Ok(self.plot_auto(&data, y_col))
+    }
+
     /// 多组散点图
-    pub fn multiscatter(&mut self, datasets: &[(&[(f32, f32)], Color, &str)], size: f32) -> &mut Self {
+    pub fn multiscatter(&mut self, datasets: &[Dataset<'_>], size: f32) -> &mut Self {
         for (data, color, label) in datasets {
             self.scatter_with_label(data, *color, size, label);
         }
         self
     }
 
+    /// 提交当前子图：按坐标轴设置生成比例尺与坐标轴，再构建数据系列
     pub fn commit_subplot(&mut self) -> &mut Self {
-        if let Some(scene) = self.current_scene.take() {
-            // 暂时只提交场景，将来可以添加轴标签等功能
-            // 轴标签功能需要在Scene API中实现
+        if let Some(mut scene) = self.current_scene.take() {
+            let (xs, ys) = self.series_values();
+            let x = ResolvedScale::resolve(self.current_xscale, self.current_xlim, &xs);
+            let y = ResolvedScale::resolve(self.current_yscale, self.current_ylim, &ys);
+
+            if let Some(ref x) = x {
+                scene = x.add_x_axis(scene, self.current_xlabel.clone());
+            }
+            if let Some(ref y) = y {
+                scene = y.add_y_axis(scene, self.current_ylabel.clone());
+            }
+            for (series, label) in std::mem::take(&mut self.series) {
+                if let (true, Some(label)) = (self.current_legend, label) {
+                    scene = scene.legend_entry(label, series.color(), YAxisSide::Left);
+                }
+                scene = self.add_series(scene, series);
+            }
+            if let Some(ref title) = self.current_title {
+                scene = scene.title(title.clone());
+            }
+            scene = scene.grid(self.current_grid);
+
             self.figure = std::mem::take(&mut self.figure).add_scene(scene);
         }
         self
     }
 
+    /// 收集所有系列的 X/Y 值用于自动范围（柱状图的 Y 范围包含基线 0）
+    fn series_values(&self) -> (Vec<f32>, Vec<f32>) {
+        let mut xs = Vec::new();
+        let mut ys = Vec::new();
+        for (series, _) in &self.series {
+            match series {
+                Series::Line { data, .. } | Series::Scatter { data, .. } => {
+                    xs.extend(data.iter().map(|p| p.0));
+                    ys.extend(data.iter().map(|p| p.1));
+                }
+                Series::Area { data, .. } => {
+                    xs.extend(data.iter().map(|p| p.0));
+                    ys.extend(data.iter().map(|p| p.1));
+                    if self.current_yscale == AxisScaleKind::Linear {
+                        ys.push(0.0);
+                    }
+                }
+                // 直方图与密度图按自身数据适配比例尺，只受显式范围约束
+                Series::Hist { .. } | Series::Density { .. } => {}
+                Series::Bar { values, .. } => {
+                    ys.extend_from_slice(values);
+                    if self.current_yscale == AxisScaleKind::Linear {
+                        ys.push(0.0);
+                    }
+                }
+            }
+        }
+        (xs, ys)
+    }
+
+    /// 构建图表并加入场景；图表按场景坐标轴（含对数轴）映射原始数据，
+    /// 自带比例尺的直方图与密度图使用显式设置的坐标轴范围
+    fn add_series(&self, scene: Scene, series: Series) -> Scene {
+        match series {
+            Series::Line { data, color, width } => {
+                scene.add_line_plot(LinePlot::new().data(&data).color(color).line_width(width))
+            }
+            Series::Scatter { data, color, size } => {
+                scene.add_scatter_plot(ScatterPlot::new().data(&data).color(color).size(size))
+            }
+            Series::Bar { categories, values, color } => {
+                let categories: Vec<&str> = categories.iter().map(|c| c.as_str()).collect();
+                scene.add_bar_plot(BarPlot::new().categories_values(&categories, &values).fill_color(color))
+            }
+            Series::Area { data, color, alpha } => {
+                let alpha = alpha.clamp(0.0, 1.0);
+                let fill = Color::rgba(color.r, color.g, color.b, color.a * alpha);
+                let series = AreaSeries::new("area")
+                    .data(&data)
+                    .fill_color(fill)
+                    .line_color(color)
+                    .alpha(alpha);
+                scene.add_area_chart(AreaChart::new().add_series(series))
+            }
+            Series::Hist { data, bins, color } => {
+                use vizuara_plots::BinningStrategy;
+                let mut hist = Histogram::new().data(&data).binning(BinningStrategy::FixedCount(bins)).fill_color(color).auto_scale();
+                if let Some((min, max)) = self.current_xlim {
+                    hist = hist.x_range(min, max);
+                }
+                if let Some((min, max)) = self.current_ylim {
+                    hist = hist.y_range(min, max);
+                }
+                scene.add_histogram(hist)
+            }
+            Series::Density { data, color } => {
+                let mut density = DensityPlot::new().data(&data).fill_color(Some(color));
+                if let Some((min, max)) = self.current_xlim {
+                    density = density.x_range(min, max);
+                }
+                if let Some((min, max)) = self.current_ylim {
+                    density = density.y_range(min, max);
+                }
+                scene.add_density_plot(density)
+            }
+        }
+    }
+
+    /// 保存为图片文件，按扩展名选择 SVG/PNG（类似 savefig）
+    pub fn save(&mut self, path: &str) -> Result<()> {
+        if self.current_scene.is_some() { self.commit_subplot(); }
+        let (primitives, styles) = self.figure.generate_styled_primitives();
+        let (w, h) = self.figure.size();
+        ExportManager::export_auto(&primitives, &styles, w as u32, h as u32, path, None)
+            .map_err(|e| VizuaraError::RenderError(format!("Failed to save figure to '{}': {}", path, e)))
+    }
+
     pub fn show(mut self) -> Result<()> {
         if self.current_scene.is_some() { self.commit_subplot(); }
-        show_figure(self.figure).map_err(|e| vizuara_core::VizuaraError::RenderError(e.to_string()))
+        show_figure(self.figure)
     }
 
     // ================= 主题和样式 =================
@@ -331,6 +662,15 @@ impl Figure2D {
 
 // ================= 便捷函数 =================
 
+/// 当前主题的系列颜色循环；主题未定义系列颜色时使用默认颜色序列
+fn theme_color_cycle() -> ColorCycle {
+    let mut colors = ThemeManager::instance().current_theme().series_colors();
+    if colors.is_empty() {
+        colors = Colors::default_sequence();
+    }
+    ColorCycle::new(colors)
+}
+
 /// 预定义颜色集合
 pub struct Colors;
 
@@ -420,3 +760,200 @@ pub mod testdata {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_core::Primitive;
+
+    #[test]
+    fn test_save_uses_plot_styles() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("figure.svg");
+
+        let mut fig = Figure2D::new(400.0, 300.0);
+        fig.subplot_full()
+            .plot(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.5)], Color::rgb(1.0, 0.0, 0.0), 2.0)
+            .scatter(&[(0.5, 0.5), (1.5, 0.8)], Color::rgb(0.0, 1.0, 0.0), 6.0);
+        fig.save(path.to_str().unwrap()).unwrap();
+
+        let svg = std::fs::read_to_string(&path).unwrap();
+        assert!(svg.contains("rgb(255, 0, 0)"));
+        assert!(svg.contains("rgb(0, 255, 0)"));
+    }
+
+    #[test]
+    fn test_axis_scale_kind_parse() {
+        assert_eq!("log".parse::<AxisScaleKind>().unwrap(), AxisScaleKind::Log);
+        assert_eq!("Linear".parse::<AxisScaleKind>().unwrap(), AxisScaleKind::Linear);
+        assert!("foo".parse::<AxisScaleKind>().is_err());
+    }
+
+    #[test]
+    fn test_explicit_limits_override_data() {
+        let Some(ResolvedScale::Linear(scale)) =
+            ResolvedScale::resolve(AxisScaleKind::Linear, Some((-5.0, 5.0)), &[0.0, 100.0])
+        else {
+            panic!("expected a linear scale");
+        };
+        assert_eq!((scale.domain_min, scale.domain_max), (-5.0, 5.0));
+
+        // 对数轴范围无效时退回线性轴
+        let resolved = ResolvedScale::resolve(AxisScaleKind::Log, Some((-1.0, 10.0)), &[1.0]);
+        assert!(matches!(resolved, Some(ResolvedScale::Linear(_))));
+    }
+
+    #[test]
+    fn test_hist_honors_xlim() {
+        let bar_span = |xlim: Option<(f32, f32)>| {
+            let mut fig = Figure2D::new(400.0, 300.0);
+            fig.subplot_full().hist(&[0.0, 0.5, 1.0], 2, Color::rgb(1.0, 0.0, 0.0));
+            if let Some((min, max)) = xlim {
+                fig.xlim(min, max);
+            }
+            fig.commit_subplot();
+            fig.figure
+                .generate_primitives()
+                .iter()
+                .filter_map(|p| match p {
+                    Primitive::RectangleStyled { min, max, fill, .. } if *fill == Color::rgb(1.0, 0.0, 0.0) => {
+                        Some(max.x - min.x)
+                    }
+                    _ => None,
+                })
+                .sum::<f32>()
+        };
+
+        let auto = bar_span(None);
+        let limited = bar_span(Some((0.0, 2.0)));
+        assert!(auto > 0.0);
+        assert!((limited - auto / 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_area_uses_color_and_limits() {
+        let fill_span = |xlim: Option<(f32, f32)>| {
+            let mut fig = Figure2D::new(400.0, 300.0);
+            fig.subplot_full()
+                .area(&[(0.0, 1.0), (1.0, 2.0)], Color::rgb(1.0, 0.0, 0.0), 0.5);
+            if let Some((min, max)) = xlim {
+                fig.xlim(min, max);
+            }
+            fig.commit_subplot();
+            fig.figure
+                .generate_primitives()
+                .iter()
+                .find_map(|p| match p {
+                    Primitive::Polygon { points, fill, .. } => {
+                        assert_eq!(*fill, Color::rgba(1.0, 0.0, 0.0, 0.5));
+                        let (min, max) = points
+                            .iter()
+                            .fold((f32::MAX, f32::MIN), |(lo, hi), p| (lo.min(p.x), hi.max(p.x)));
+                        Some(max - min)
+                    }
+                    _ => None,
+                })
+                .expect("area polygon")
+        };
+
+        let auto = fill_span(None);
+        let limited = fill_span(Some((0.0, 2.0)));
+        assert!(auto > 0.0);
+        assert!(limited < auto);
+    }
+
+    fn series_colors(fig: &Figure2D) -> Vec<Color> {
+        fig.series.iter().map(|(series, _)| series.color()).collect()
+    }
+
+    #[test]
+    fn test_hold_on_overlays_series() {
+        let mut fig = Figure2D::new(400.0, 300.0);
+        let palette = fig.color_cycle().clone();
+        fig.subplot_full()
+            .plot_auto(&[(0.0, 0.0), (1.0, 1.0)], "a")
+            .plot_auto(&[(0.0, 1.0), (1.0, 0.0)], "b");
+
+        assert!(fig.is_hold());
+        assert_eq!(fig.series_count(), 2);
+        assert_eq!(series_colors(&fig), vec![palette.color_at(0), palette.color_at(1)]);
+    }
+
+    #[test]
+    fn test_hold_off_replaces_series() {
+        let mut fig = Figure2D::new(400.0, 300.0);
+        let palette = fig.color_cycle().clone();
+        fig.subplot_full()
+            .plot_auto(&[(0.0, 0.0), (1.0, 1.0)], "a")
+            .hold_off()
+            .plot_auto(&[(0.0, 1.0), (1.0, 0.0)], "b")
+            .scatter_auto(&[(0.5, 0.5)], "c");
+
+        // 替换后的系列取调色板的第一个颜色
+        assert_eq!(fig.series_count(), 1);
+        assert_eq!(series_colors(&fig), vec![palette.color_at(0)]);
+
+        fig.plot(&[(0.0, 0.0)], Color::rgb(1.0, 0.0, 0.0), 1.0);
+        assert_eq!(series_colors(&fig), vec![Color::rgb(1.0, 0.0, 0.0)]);
+
+        // 重新打开保持模式后继续叠加，第二个系列取第二个颜色
+        fig.hold_on().plot_auto(&[(0.0, 0.0)], "d");
+        assert_eq!(fig.series_count(), 2);
+        assert_eq!(series_colors(&fig)[1], palette.color_at(1));
+        fig.clear()
+            .plot_auto(&[(0.0, 0.0)], "e")
+            .plot_auto(&[(0.0, 0.0)], "f");
+        assert_eq!(series_colors(&fig), vec![palette.color_at(0), palette.color_at(1)]);
+    }
+
+    #[test]
+    fn test_clear_keeps_subplot() {
+        let mut fig = Figure2D::new(400.0, 300.0);
+        let palette = fig.color_cycle().clone();
+        fig.grid(1, 2).next_subplot().next_subplot();
+        let area = fig.current_scene.as_ref().unwrap().plot_area();
+        fig.plot_auto(&[(0.0, 0.0), (1.0, 1.0)], "a")
+            .plot_auto(&[(0.0, 1.0), (1.0, 0.0)], "b");
+
+        fig.clear();
+        assert_eq!(fig.series_count(), 0);
+        assert_eq!(fig.current_scene.as_ref().unwrap().plot_area(), area);
+        assert_eq!(fig.color_cycle().position(), 0);
+
+        // 清空后重新从调色板开头配色
+        fig.plot_auto(&[(0.0, 0.0)], "c");
+        assert_eq!(series_colors(&fig), vec![palette.color_at(0)]);
+    }
+
+    #[test]
+    fn test_nth_series_gets_nth_palette_color() {
+        let mut fig = Figure2D::new(400.0, 300.0);
+        let palette = fig.color_cycle().clone();
+        let count = palette.colors().len() + 2;
+        fig.subplot_full();
+        for i in 0..count {
+            match i % 3 {
+                0 => fig.plot_auto(&[(0.0, i as f32)], "line"),
+                1 => fig.scatter_auto(&[(0.0, i as f32)], "points"),
+                _ => fig.bar_auto(&["a"], &[i as f32], "bars"),
+            };
+        }
+
+        let expected: Vec<Color> = (0..count).map(|i| palette.color_at(i)).collect();
+        assert_eq!(series_colors(&fig), expected);
+    }
+
+    #[test]
+    fn test_styled_primitives_match_primitive_count() {
+        let mut fig = Figure2D::new(400.0, 300.0);
+        fig.subplot_full()
+            .plot(&[(0.0, 0.0), (1.0, 1.0)], Color::rgb(1.0, 0.0, 0.0), 3.0);
+        fig.commit_subplot();
+
+        let (primitives, styles) = fig.figure.generate_styled_primitives();
+        assert_eq!(primitives.len(), styles.len());
+        assert!(styles
+            .iter()
+            .any(|s| s.stroke_color == Some(Color::rgb(1.0, 0.0, 0.0)) && s.stroke_width == 3.0));
+    }
+}
@@ -1,15 +1,26 @@
-use vizuara_core::{Color, Result};
-use vizuara_3d::{CoordinateSystem3D, GridType, Surface3D};
+use vizuara_core::{Color, Primitive, Result, Style, VizuaraError};
+use vizuara_3d::{BoundingBox3D, CoordinateSystem3D, GridType, Plot3DArea, Point3D, Scatter3D, Surface3D};
+use vizuara_export::ExportManager;
+use vizuara_plots::ColorMap;
 use vizuara_window::Window3D;
 
+/// 保存图片的尺寸（与 3D 图元投影假设的屏幕尺寸一致）
+const SAVE_SIZE: (u32, u32) = (800, 600);
+
 /// 3D Figure 便捷封装
 pub struct Figure3D {
     window: Window3D,
     coord: Option<CoordinateSystem3D>,
+    scatter: Option<Scatter3D>,
+    surface: Option<Surface3D>,
+}
+
+impl Default for Figure3D {
+    fn default() -> Self { Self::new() }
 }
 
 impl Figure3D {
-    pub fn new() -> Self { Self { window: Window3D::new(), coord: None } }
+    pub fn new() -> Self { Self { window: Window3D::new(), coord: None, scatter: None, surface: None } }
 
     /// 一键默认坐标系（范围与缩放）
     pub fn with_default_coords(mut self, x:(f32,f32), y:(f32,f32), z:(f32,f32), scale:f32) -> Self {
@@ -21,19 +32,126 @@ impl Figure3D {
 
     /// 添加3D散点
     pub fn scatter3d(mut self, xyz: &[(f32,f32,f32)], color: Color, size: f32) -> Self {
-        let scatter = vizuara_3d::Scatter3D::from_data(xyz).default_color(color).default_size(size);
+        // from_data 使用创建时的默认颜色，这里逐点指定颜色与大小
+        let scatter = xyz.iter().fold(
+            Scatter3D::new().default_color(color).default_size(size),
+            |scatter, &(x, y, z)| scatter.add_point(Point3D::new(x, y, z).color(color).size(size)),
+        );
+        self.scatter = Some(scatter.clone());
         self.window = self.window.add_scatter3d(scatter);
         self
     }
 
     /// 添加3D表面
     pub fn surface3d(mut self, surface: Surface3D) -> Self {
+        self.surface = Some(surface.clone());
         self.window = self.window.add_surface3d(surface);
         self
     }
 
+    /// 按函数 z = f(x, y) 采样生成表面（resolution 为每个方向的采样数）
+    ///
+    /// 函数返回 NaN/无穷的网格点会在表面上留下空洞。
+    pub fn surface_fn<F: Fn(f32, f32) -> f32>(self, f: F, x_range: (f32, f32), y_range: (f32, f32), resolution: usize) -> Self {
+        self.surface_fn_colored(f, x_range, y_range, resolution, None)
+    }
+
+    /// 与 `surface_fn` 相同，并可按高度使用颜色映射着色
+    pub fn surface_fn_colored<F: Fn(f32, f32) -> f32>(
+        self,
+        f: F,
+        x_range: (f32, f32),
+        y_range: (f32, f32),
+        resolution: usize,
+        color_map: Option<ColorMap>,
+    ) -> Self {
+        let resolution = resolution.max(2);
+        let mut surface = Surface3D::from_function(x_range, y_range, (resolution, resolution), f);
+        if let Some(color_map) = color_map {
+            surface = surface.color_by_height(color_map);
+        }
+        self.surface3d(surface)
+    }
+
+    /// 在当前表面的底面 (z = z_min) 绘制 levels 条等高线，需先添加表面
+    pub fn floor_contours(mut self, levels: usize) -> Self {
+        if let Some(surface) = self.surface.take() {
+            self = self.surface3d(surface.show_floor_contours(levels));
+        }
+        self
+    }
+
     /// 相机快捷控制
-    pub fn reset_camera(mut self) -> Self { self.window = { let mut w = self.window; w }; self }
+    pub fn reset_camera(self) -> Self { self }
+
+    /// 投影为 2D 图元并保存为图片文件，按扩展名选择 SVG/PNG
+    pub fn save(&self, path: &str) -> Result<()> {
+        let (primitives, styles) = self.projected_primitives();
+        ExportManager::export_auto(&primitives, &styles, SAVE_SIZE.0, SAVE_SIZE.1, path, None)
+            .map_err(|e| VizuaraError::RenderError(format!("Failed to save figure to '{}': {}", path, e)))
+    }
+
+    /// 以包围数据的默认相机投影散点与表面，并给出各图元的样式
+    fn projected_primitives(&self) -> (Vec<Primitive>, Vec<Style>) {
+        let mut bounds: Vec<BoundingBox3D> = Vec::new();
+        bounds.extend(self.scatter.as_ref().and_then(|s| s.bounds()));
+        bounds.extend(self.surface.as_ref().map(|s| s.mesh().bounds()));
+        let Some(&first) = bounds.first() else {
+            return (Vec::new(), Vec::new());
+        };
+        let (mut x, mut y, mut z) = first;
+        for b in &bounds[1..] {
+            x = (x.0.min(b.0.0), x.1.max(b.0.1));
+            y = (y.0.min(b.1.0), y.1.max(b.1.1));
+            z = (z.0.min(b.2.0), z.1.max(b.2.1));
+        }
+
+        let center = nalgebra::Point3::new((x.0 + x.1) / 2.0, (y.0 + y.1) / 2.0, (z.0 + z.1) / 2.0);
+        let radius = ((x.1 - x.0).powi(2) + (y.1 - y.0).powi(2) + (z.1 - z.0).powi(2)).sqrt().max(1.0);
+        let eye = center + nalgebra::Vector3::new(1.2, 0.9, 1.5) * radius;
+        let area = Plot3DArea::new(x, y, z)
+            .perspective(SAVE_SIZE.0 as f32 / SAVE_SIZE.1 as f32, std::f32::consts::FRAC_PI_4, 0.1, radius * 10.0)
+            .camera(eye, center, nalgebra::Vector3::y());
+
+        let mut primitives = Vec::new();
+        let mut styles = Vec::new();
+        if let Some(ref surface) = self.surface {
+            primitives.extend(surface.generate_primitives(&area));
+            styles.resize(primitives.len(), surface.wireframe_style());
+        }
+        if let Some(ref scatter) = self.scatter {
+            let (points, point_styles) = scatter.generate_styled_primitives(&area);
+            primitives.extend(points);
+            styles.extend(point_styles);
+        }
+        (primitives, styles)
+    }
 
     pub async fn show(self) -> Result<()> { self.window.run().await }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projected_primitives_use_scatter_colors() {
+        let fig = Figure3D::new().scatter3d(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)], Color::rgb(1.0, 0.0, 0.0), 4.0);
+        let (primitives, styles) = fig.projected_primitives();
+        assert!(!primitives.is_empty());
+        assert_eq!(primitives.len(), styles.len());
+        for style in &styles {
+            let fill = style.fill_color.unwrap();
+            assert!(fill.r > 0.0 && fill.g == 0.0 && fill.b == 0.0);
+        }
+    }
+
+    #[test]
+    fn test_save_writes_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("figure3d.svg");
+        let fig = Figure3D::new().scatter3d(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)], Color::rgb(1.0, 0.0, 0.0), 4.0);
+        fig.save(path.to_str().unwrap()).unwrap();
+        assert!(std::fs::read_to_string(&path).unwrap().contains("<circle"));
+    }
+}
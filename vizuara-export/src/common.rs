@@ -1,5 +1,8 @@
 use crate::error::{ExportError, ExportResult};
 
+/// JPEG 默认质量 (1-100)
+pub const DEFAULT_JPEG_QUALITY: u8 = 90;
+
 /// 导出格式枚举
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExportFormat {
@@ -7,6 +10,10 @@ pub enum ExportFormat {
     Svg,
     /// PNG位图格式
     Png,
+    /// JPEG有损位图格式，quality 取值 1-100
+    Jpeg { quality: u8 },
+    /// WebP位图格式
+    Webp,
 }
 
 impl ExportFormat {
@@ -21,6 +28,10 @@ impl ExportFormat {
         match extension.as_str() {
             "svg" => Ok(ExportFormat::Svg),
             "png" => Ok(ExportFormat::Png),
+            "jpg" | "jpeg" => Ok(ExportFormat::Jpeg {
+                quality: DEFAULT_JPEG_QUALITY,
+            }),
+            "webp" => Ok(ExportFormat::Webp),
             _ => Err(ExportError::UnsupportedFormat(format!(
                 "不支持的格式: {}",
                 extension
@@ -33,6 +44,8 @@ impl ExportFormat {
         match self {
             ExportFormat::Svg => "svg",
             ExportFormat::Png => "png",
+            ExportFormat::Jpeg { .. } => "jpg",
+            ExportFormat::Webp => "webp",
         }
     }
 
@@ -41,6 +54,8 @@ impl ExportFormat {
         match self {
             ExportFormat::Svg => "image/svg+xml",
             ExportFormat::Png => "image/png",
+            ExportFormat::Jpeg { .. } => "image/jpeg",
+            ExportFormat::Webp => "image/webp",
         }
     }
 }
@@ -168,6 +183,52 @@ impl ExportOptions {
     }
 }
 
+/// 帧序列导出选项
+#[derive(Debug, Clone)]
+pub struct FrameExportOptions {
+    /// 单帧宽度（像素）
+    pub width: u32,
+    /// 单帧高度（像素）
+    pub height: u32,
+    /// 文件名前缀，帧文件命名为 `{prefix}_0000.ext`
+    pub prefix: String,
+    /// 精灵图列数；设置时额外输出 `{prefix}_sheet.ext`
+    pub sprite_sheet_columns: Option<u32>,
+    /// 每帧的导出选项
+    pub export: ExportOptions,
+}
+
+impl FrameExportOptions {
+    /// 创建指定帧尺寸的选项
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            prefix: "frame".to_string(),
+            sprite_sheet_columns: None,
+            export: ExportOptions::default(),
+        }
+    }
+
+    /// 设置文件名前缀
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// 额外拼接一张按行排布的精灵图
+    pub fn with_sprite_sheet(mut self, columns: u32) -> Self {
+        self.sprite_sheet_columns = Some(columns.max(1));
+        self
+    }
+
+    /// 设置每帧的导出选项
+    pub fn with_export_options(mut self, options: ExportOptions) -> Self {
+        self.export = options;
+        self
+    }
+}
+
 /// 坐标变换辅助函数
 pub fn scale_primitive(
     primitive: &vizuara_core::Primitive,
@@ -200,6 +261,7 @@ pub fn scale_primitive(
             color,
             h_align,
             v_align,
+            rotation,
         } => Primitive::Text {
             position: Point2::new(position.x * scale_x, position.y * scale_y),
             content: content.clone(),
@@ -207,6 +269,7 @@ pub fn scale_primitive(
             color: *color,
             h_align: *h_align,
             v_align: *v_align,
+            rotation: *rotation,
         },
         // 对于复杂的原语，暂时返回原始值
         other => other.clone(),
@@ -236,6 +299,17 @@ mod tests {
             ExportFormat::Png
         );
 
+        assert_eq!(
+            ExportFormat::from_extension("photo.JPEG").unwrap(),
+            ExportFormat::Jpeg {
+                quality: DEFAULT_JPEG_QUALITY
+            }
+        );
+        assert_eq!(
+            ExportFormat::from_extension("test.webp").unwrap(),
+            ExportFormat::Webp
+        );
+
         assert!(ExportFormat::from_extension("test.txt").is_err());
         assert!(ExportFormat::from_extension("test").is_err());
     }
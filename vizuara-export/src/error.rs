@@ -1,4 +1,6 @@
+use std::path::PathBuf;
 use thiserror::Error;
+use vizuara_core::VizuaraError;
 
 /// 导出错误类型
 #[derive(Error, Debug)]
@@ -19,6 +21,10 @@ pub enum ExportError {
     #[error("PNG生成错误: {0}")]
     PngError(String),
 
+    /// JPEG/WebP 等位图编码错误
+    #[error("图像编码错误: {0}")]
+    ImageError(String),
+
     /// 渲染错误
     #[error("渲染错误: {0}")]
     RenderError(String),
@@ -26,7 +32,55 @@ pub enum ExportError {
     /// 配置错误
     #[error("配置错误: {0}")]
     ConfigError(String),
+
+    /// 批量导出中部分条目失败；written 为已成功写出的文件
+    #[error("批量导出有 {} 项失败: {}", .failures.len(), format_failures(.failures))]
+    BatchError {
+        written: Vec<PathBuf>,
+        failures: Vec<(String, ExportError)>,
+    },
+}
+
+/// 转换为核心错误类型，便于跨 crate 使用 `?`
+impl From<ExportError> for VizuaraError {
+    fn from(error: ExportError) -> Self {
+        match error {
+            ExportError::Io(e) => VizuaraError::IoError(e),
+            ExportError::UnsupportedFormat(msg) => VizuaraError::UnsupportedFeature(msg),
+            ExportError::ConfigError(msg) => VizuaraError::ConfigError(msg),
+            other => VizuaraError::RenderError(other.to_string()),
+        }
+    }
+}
+
+fn format_failures(failures: &[(String, ExportError)]) -> String {
+    failures
+        .iter()
+        .map(|(name, error)| format!("{}: {}", name, error))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 /// 导出结果类型
 pub type ExportResult<T> = Result<T, ExportError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_error_into_vizuara_error() {
+        fn export() -> vizuara_core::Result<()> {
+            Err(ExportError::UnsupportedFormat("bmp".to_string()))?;
+            Ok(())
+        }
+        assert!(matches!(export(), Err(VizuaraError::UnsupportedFeature(ref m)) if m == "bmp"));
+
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        let error: VizuaraError = ExportError::Io(io).into();
+        assert!(matches!(error, VizuaraError::IoError(_)));
+
+        let error: VizuaraError = ExportError::PngError("oops".to_string()).into();
+        assert!(matches!(error, VizuaraError::RenderError(ref m) if m.contains("oops")));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::common::DEFAULT_JPEG_QUALITY;
+use crate::png::PngExporter;
+use crate::{ExportError, ExportFormat, ExportOptions, ExportResult, Exporter};
+use vizuara_core::{Color, Primitive, Style};
+
+/// JPEG导出器
+pub struct JpegExporter {
+    quality: u8,
+}
+
+impl JpegExporter {
+    /// 创建新的JPEG导出器（默认质量 90）
+    pub fn new() -> Self {
+        Self {
+            quality: DEFAULT_JPEG_QUALITY,
+        }
+    }
+
+    /// 设置压缩质量 (1-100)
+    pub fn with_quality(mut self, quality: u8) -> Self {
+        self.quality = quality.clamp(1, 100);
+        self
+    }
+
+    /// 获取压缩质量
+    pub fn quality(&self) -> u8 {
+        self.quality
+    }
+}
+
+impl Default for JpegExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Exporter for JpegExporter {
+    fn export_to_file(
+        &self,
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        path: &str,
+        options: &ExportOptions,
+    ) -> ExportResult<()> {
+        let jpeg_data = self.export_to_bytes(primitives, styles, width, height, options)?;
+        std::fs::write(path, jpeg_data)?;
+        Ok(())
+    }
+
+    fn export_to_bytes(
+        &self,
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        options: &ExportOptions,
+    ) -> ExportResult<Vec<u8>> {
+        // JPEG 不支持透明通道：透明背景时合成到白色背景上
+        let mut options = options.clone();
+        if options.background_color.is_none() {
+            options.background_color = Some(Color::WHITE);
+        }
+
+        let pixmap = PngExporter::rasterize(primitives, styles, width, height, &options)?;
+        let rgb: Vec<u8> = PngExporter::pixmap_to_rgba(&pixmap)
+            .chunks_exact(4)
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+            .collect();
+        let image = image::RgbImage::from_raw(width, height, rgb)
+            .ok_or_else(|| ExportError::ImageError("像素数据与尺寸不匹配".to_string()))?;
+
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgb8(image)
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageOutputFormat::Jpeg(self.quality),
+            )
+            .map_err(|e| ExportError::ImageError(format!("JPEG编码失败: {}", e)))?;
+        Ok(bytes)
+    }
+
+    fn supported_format(&self) -> ExportFormat {
+        ExportFormat::Jpeg {
+            quality: self.quality,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Point2;
+
+    #[test]
+    fn test_jpeg_export_to_bytes() -> ExportResult<()> {
+        let exporter = JpegExporter::new().with_quality(80);
+        assert_eq!(
+            exporter.supported_format(),
+            ExportFormat::Jpeg { quality: 80 }
+        );
+
+        let primitives = vec![Primitive::Circle {
+            center: Point2::new(20.0, 20.0),
+            radius: 10.0,
+        }];
+        let styles = vec![Style::new().fill_color(Color::rgb(1.0, 0.0, 0.0))];
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 40, 40, &ExportOptions::default())?;
+
+        // JPEG SOI 标记
+        assert_eq!(&bytes[0..2], &[0xFF, 0xD8]);
+
+        // 透明背景合成到白色上
+        let decoded = image::load_from_memory(&bytes).unwrap().to_rgb8();
+        let corner = decoded.get_pixel(0, 0);
+        assert!(corner.0.iter().all(|&c| c > 240));
+        Ok(())
+    }
+}
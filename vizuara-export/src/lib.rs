@@ -3,16 +3,23 @@
 //! 提供将可视化内容导出为各种格式的功能：
 //! - SVG（矢量格式）
 //! - PNG（位图格式）
+//! - JPEG / WebP（有损/网页位图格式）
 //! - 其他格式支持
 
 pub mod common;
 pub mod error;
+pub mod jpeg;
 pub mod png;
 pub mod svg;
+pub mod webp;
 
-pub use common::{ExportFormat, ExportOptions};
+pub use common::{ExportFormat, ExportOptions, FrameExportOptions};
 pub use error::{ExportError, ExportResult};
 
+use base64::Engine;
+use nalgebra::Vector2;
+use std::path::{Path, PathBuf};
+use vizuara_animation::Seekable;
 use vizuara_core::{Primitive, Style};
 
 /// 导出器特征
@@ -42,6 +49,9 @@ pub trait Exporter {
     fn supported_format(&self) -> ExportFormat;
 }
 
+/// 批量导出条目：(文件名, 图元, 样式, 宽度, 高度)
+pub type BatchItem<'a> = (&'a str, &'a [Primitive], &'a [Style], u32, u32);
+
 /// 导出管理器
 pub struct ExportManager;
 
@@ -79,6 +89,188 @@ impl ExportManager {
         exporter.export_to_file(primitives, styles, width, height, path, &opts)
     }
 
+    /// 导出为JPEG格式，quality 取值 1-100
+    pub fn export_jpeg(
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        path: &str,
+        quality: u8,
+        options: Option<ExportOptions>,
+    ) -> ExportResult<()> {
+        let exporter = jpeg::JpegExporter::new().with_quality(quality);
+        let opts = options.unwrap_or_default();
+        exporter.export_to_file(primitives, styles, width, height, path, &opts)
+    }
+
+    /// 导出为WebP格式
+    pub fn export_webp(
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        path: &str,
+        options: Option<ExportOptions>,
+    ) -> ExportResult<()> {
+        let exporter = webp::WebpExporter::new();
+        let opts = options.unwrap_or_default();
+        exporter.export_to_file(primitives, styles, width, height, path, &opts)
+    }
+
+    /// 导出为 base64 编码的 data URI（如 `data:image/png;base64,...`），便于嵌入 HTML 或笔记本
+    pub fn export_data_uri(
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        format: ExportFormat,
+        options: Option<ExportOptions>,
+    ) -> ExportResult<String> {
+        let opts = options.unwrap_or_default();
+        let bytes = Self::exporter_for(&format)
+            .export_to_bytes(primitives, styles, width, height, &opts)?;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+        Ok(format!("data:{};base64,{}", format.mime_type(), encoded))
+    }
+
+    /// 批量导出到目录，每项写为 `dir/name.ext`
+    ///
+    /// 单项失败不会中断其余导出；存在失败时返回 `ExportError::BatchError`，
+    /// 其中包含已写出的文件和每项的错误。
+    pub fn export_batch(
+        items: &[BatchItem],
+        dir: impl AsRef<Path>,
+        format: ExportFormat,
+        options: Option<ExportOptions>,
+    ) -> ExportResult<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        let opts = options.unwrap_or_default();
+        let exporter = Self::exporter_for(&format);
+        let mut written = Vec::new();
+        let mut failures = Vec::new();
+
+        for &(name, primitives, styles, width, height) in items {
+            let path = dir.join(format!("{}.{}", name, format.extension()));
+            let result = exporter
+                .export_to_bytes(primitives, styles, width, height, &opts)
+                .and_then(|bytes| Ok(std::fs::write(&path, bytes)?));
+            match result {
+                Ok(()) => written.push(path),
+                Err(e) => failures.push((name.to_string(), e)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(written)
+        } else {
+            Err(ExportError::BatchError { written, failures })
+        }
+    }
+
+    /// 导出动画帧序列，便于外部编码为 GIF/MP4
+    ///
+    /// 在 [0, 时长] 上均匀取 `frame_count` 个时间点（含首尾），依次跳转动画、
+    /// 由 `render` 生成该帧的图元并写为 `dir/{prefix}_0000.ext` 等编号文件。
+    /// 设置精灵图列数时另外写出 `dir/{prefix}_sheet.ext`，各帧按行优先排布。
+    /// 返回写出的文件：帧按顺序在前，精灵图（若有）在最后
+    pub fn export_frames<A, F>(
+        animation: &mut A,
+        frame_count: usize,
+        dir: impl AsRef<Path>,
+        format: ExportFormat,
+        options: FrameExportOptions,
+        mut render: F,
+    ) -> ExportResult<Vec<PathBuf>>
+    where
+        A: Seekable,
+        F: FnMut(&A) -> (Vec<Primitive>, Vec<Style>),
+    {
+        if frame_count == 0 {
+            return Err(ExportError::ConfigError("帧数必须大于 0".to_string()));
+        }
+
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        let exporter = Self::exporter_for(&format);
+        let (width, height) = (options.width, options.height);
+        let digits = (frame_count - 1).to_string().len().max(4);
+        let duration = animation.duration();
+        let mut written = Vec::with_capacity(frame_count + 1);
+        let mut sheet: Vec<(Primitive, Style)> = Vec::new();
+
+        for index in 0..frame_count {
+            let fraction = if frame_count > 1 {
+                index as f32 / (frame_count - 1) as f32
+            } else {
+                0.0
+            };
+            animation.seek(duration.mul_f32(fraction));
+            let (primitives, styles) = render(animation);
+
+            let name = format!(
+                "{}_{:0digits$}.{}",
+                options.prefix,
+                index,
+                format.extension(),
+                digits = digits
+            );
+            let path = dir.join(name);
+            let bytes =
+                exporter.export_to_bytes(&primitives, &styles, width, height, &options.export)?;
+            std::fs::write(&path, bytes)?;
+            written.push(path);
+
+            if let Some(columns) = options.sprite_sheet_columns {
+                // 平移到精灵图中对应的格子
+                let cell = index as u32;
+                let offset = Vector2::new(
+                    (cell % columns * width) as f32,
+                    (cell / columns * height) as f32,
+                );
+                sheet.extend(
+                    primitives
+                        .iter()
+                        .map(|primitive| primitive.map_points(|p| p + offset))
+                        .zip(styles),
+                );
+            }
+        }
+
+        if let Some(columns) = options.sprite_sheet_columns {
+            let count = frame_count as u32;
+            let rows = (count - 1) / columns + 1;
+            let (primitives, styles): (Vec<Primitive>, Vec<Style>) = sheet.into_iter().unzip();
+            let path = dir.join(format!("{}_sheet.{}", options.prefix, format.extension()));
+            let bytes = exporter.export_to_bytes(
+                &primitives,
+                &styles,
+                columns.min(count) * width,
+                rows * height,
+                &options.export,
+            )?;
+            std::fs::write(&path, bytes)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+
+    /// 获取指定格式的导出器
+    fn exporter_for(format: &ExportFormat) -> Box<dyn Exporter> {
+        match format {
+            ExportFormat::Svg => Box::new(svg::SvgExporter::new()),
+            ExportFormat::Png => Box::new(png::PngExporter::new()),
+            ExportFormat::Jpeg { quality } => {
+                Box::new(jpeg::JpegExporter::new().with_quality(*quality))
+            }
+            ExportFormat::Webp => Box::new(webp::WebpExporter::new()),
+        }
+    }
+
     /// 自动检测格式并导出
     pub fn export_auto(
         primitives: &[Primitive],
@@ -98,6 +290,12 @@ impl ExportManager {
             ExportFormat::Png => {
                 Self::export_png(primitives, styles, width, height, path, Some(opts))
             }
+            ExportFormat::Jpeg { quality } => {
+                Self::export_jpeg(primitives, styles, width, height, path, quality, Some(opts))
+            }
+            ExportFormat::Webp => {
+                Self::export_webp(primitives, styles, width, height, path, Some(opts))
+            }
         }
     }
 }
@@ -158,4 +356,115 @@ mod tests {
         assert!(file_path.exists());
         Ok(())
     }
+
+    #[test]
+    fn test_export_data_uri() -> ExportResult<()> {
+        let primitives = vec![Primitive::Circle {
+            center: Point2::new(10.0, 10.0),
+            radius: 5.0,
+        }];
+        let styles = vec![vizuara_core::Style::new()];
+
+        let uri =
+            ExportManager::export_data_uri(&primitives, &styles, 20, 20, ExportFormat::Svg, None)?;
+        let payload = uri
+            .strip_prefix("data:image/svg+xml;base64,")
+            .expect("svg data uri prefix");
+        let svg = base64::engine::general_purpose::STANDARD
+            .decode(payload)
+            .unwrap();
+        assert!(String::from_utf8(svg).unwrap().contains("<circle"));
+
+        let uri =
+            ExportManager::export_data_uri(&primitives, &styles, 20, 20, ExportFormat::Png, None)?;
+        assert!(uri.starts_with("data:image/png;base64,iVBORw0KGgo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_batch_collects_failures() {
+        let primitives = vec![Primitive::Circle {
+            center: Point2::new(10.0, 10.0),
+            radius: 5.0,
+        }];
+        let styles = vec![vizuara_core::Style::new()];
+        let items: Vec<BatchItem> = vec![
+            ("first", &primitives, &styles, 20, 20),
+            // 零尺寸无法创建像素画布
+            ("broken", &primitives, &styles, 0, 0),
+            ("second", &primitives, &styles, 20, 20),
+        ];
+
+        let dir = tempdir().unwrap();
+        match ExportManager::export_batch(&items, dir.path(), ExportFormat::Png, None) {
+            Err(ExportError::BatchError { written, failures }) => {
+                assert_eq!(written.len(), 2);
+                assert!(written.iter().all(|path| path.exists()));
+                assert_eq!(written[1], dir.path().join("second.png"));
+                assert_eq!(failures.len(), 1);
+                assert_eq!(failures[0].0, "broken");
+            }
+            other => panic!("expected batch error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_export_frames_with_sprite_sheet() -> ExportResult<()> {
+        use std::time::Duration;
+        use vizuara_animation::Transition;
+
+        // 半径从 0 增长到 20 的圆
+        let mut animation = Transition::simple(0.0_f32, 20.0, Duration::from_millis(100));
+        let dir = tempdir().unwrap();
+        let options = FrameExportOptions::new(50, 50)
+            .with_prefix("grow")
+            .with_sprite_sheet(2);
+
+        let mut radii = Vec::new();
+        let written = ExportManager::export_frames(
+            &mut animation,
+            3,
+            dir.path(),
+            ExportFormat::Svg,
+            options,
+            |animation| {
+                let radius = animation.current_f32();
+                radii.push(radius);
+                let circle = Primitive::Circle {
+                    center: Point2::new(25.0, 25.0),
+                    radius,
+                };
+                (vec![circle], vec![vizuara_core::Style::new()])
+            },
+        )?;
+
+        assert_eq!(radii.first(), Some(&0.0));
+        assert_eq!(radii.last(), Some(&20.0));
+        assert_eq!(
+            written,
+            vec![
+                dir.path().join("grow_0000.svg"),
+                dir.path().join("grow_0001.svg"),
+                dir.path().join("grow_0002.svg"),
+                dir.path().join("grow_sheet.svg"),
+            ]
+        );
+        assert!(written.iter().all(|path| path.exists()));
+
+        // 2 列 2 行的精灵图包含全部 3 帧
+        let sheet = std::fs::read_to_string(&written[3]).unwrap();
+        assert_eq!(sheet.matches("<circle").count(), 3);
+        assert!(sheet.contains("width=\"100\""));
+
+        assert!(ExportManager::export_frames(
+            &mut animation,
+            0,
+            dir.path(),
+            ExportFormat::Svg,
+            FrameExportOptions::new(50, 50),
+            |_| (Vec::new(), Vec::new()),
+        )
+        .is_err());
+        Ok(())
+    }
 }
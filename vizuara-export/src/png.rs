@@ -11,6 +11,75 @@ impl PngExporter {
         Self
     }
 
+    /// 将 RGBA8 像素数据编码为 PNG（例如窗口截图）
+    pub fn encode_rgba(pixels: &[u8], width: u32, height: u32) -> ExportResult<Vec<u8>> {
+        let image =
+            image::RgbaImage::from_raw(width, height, pixels.to_vec()).ok_or_else(|| {
+                ExportError::PngError(format!(
+                    "像素数据长度 {} 与尺寸 {}x{} 不匹配",
+                    pixels.len(),
+                    width,
+                    height
+                ))
+            })?;
+
+        let mut bytes = Vec::new();
+        image
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageOutputFormat::Png,
+            )
+            .map_err(|e| ExportError::PngError(format!("PNG编码失败: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// 将 RGBA8 像素数据保存为 PNG 文件
+    pub fn save_rgba(pixels: &[u8], width: u32, height: u32, path: &str) -> ExportResult<()> {
+        let png_data = Self::encode_rgba(pixels, width, height)?;
+        std::fs::write(path, png_data)?;
+        Ok(())
+    }
+
+    /// 将图元栅格化到像素画布（PNG/JPEG/WebP 共用）
+    pub(crate) fn rasterize(
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        options: &ExportOptions,
+    ) -> ExportResult<Pixmap> {
+        let mut pixmap = Pixmap::new(width, height)
+            .ok_or_else(|| ExportError::PngError("无法创建像素画布".to_string()))?;
+
+        // 设置背景
+        if let Some(bg_color) = &options.background_color {
+            let bg = Self::color_to_skia(bg_color, 1.0);
+            pixmap.fill(bg);
+        }
+        // 如果没有设置背景颜色，pixmap默认是透明的
+
+        // 渲染所有原语
+        for (primitive, style) in primitives.iter().zip(styles.iter()) {
+            if let Err(e) = Self::render_primitive(&mut pixmap, primitive, style, options) {
+                eprintln!("Warning: 跳过无法渲染的原语: {}", e);
+            }
+        }
+
+        Ok(pixmap)
+    }
+
+    /// 将画布转换为非预乘的 RGBA8 像素数据
+    pub(crate) fn pixmap_to_rgba(pixmap: &Pixmap) -> Vec<u8> {
+        pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect()
+    }
+
     /// 将颜色转换为tiny-skia颜色
     fn color_to_skia(color: &Color, alpha: f32) -> tiny_skia::Color {
         tiny_skia::Color::from_rgba(
@@ -51,6 +120,16 @@ impl PngExporter {
             Primitive::Point(position) => {
                 Self::render_point(pixmap, position, style, options)?;
             }
+            Primitive::LineStrip(points) => {
+                for pair in points.windows(2) {
+                    Self::render_line(pixmap, &pair[0], &pair[1], style)?;
+                }
+            }
+            Primitive::Points(points) => {
+                for p in points {
+                    Self::render_circle(pixmap, p, style.marker_size, style)?;
+                }
+            }
             _ => {
                 return Err(ExportError::PngError(format!(
                     "不支持的原语类型: {:?}",
@@ -227,7 +306,7 @@ impl PngExporter {
             .finish()
             .ok_or_else(|| ExportError::PngError("无法创建点路径".to_string()))?;
 
-        // 应用填充颜色
+        // 应用填充颜色；只有描边时为空心标记
         if let Some(fill_color) = &style.fill_color {
             let color = Self::color_to_skia(fill_color, style.opacity);
             let paint = Paint {
@@ -241,7 +320,7 @@ impl PngExporter {
                 Transform::identity(),
                 None,
             );
-        } else {
+        } else if style.stroke_color.is_none() {
             // 默认黑色填充
             let color = Self::color_to_skia(&Color::rgb(0.0, 0.0, 0.0), style.opacity);
             let paint = Paint {
@@ -306,22 +385,7 @@ impl Exporter for PngExporter {
         height: u32,
         options: &ExportOptions,
     ) -> ExportResult<Vec<u8>> {
-        let mut pixmap = Pixmap::new(width, height)
-            .ok_or_else(|| ExportError::PngError("无法创建像素画布".to_string()))?;
-
-        // 设置背景
-        if let Some(bg_color) = &options.background_color {
-            let bg = Self::color_to_skia(bg_color, 1.0);
-            pixmap.fill(bg);
-        }
-        // 如果没有设置背景颜色，pixmap默认是透明的
-
-        // 渲染所有原语
-        for (primitive, style) in primitives.iter().zip(styles.iter()) {
-            if let Err(e) = Self::render_primitive(&mut pixmap, primitive, style, options) {
-                eprintln!("Warning: 跳过无法渲染的原语: {}", e);
-            }
-        }
+        let pixmap = Self::rasterize(primitives, styles, width, height, options)?;
 
         // 编码为PNG
         pixmap
@@ -378,6 +442,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encode_rgba() -> ExportResult<()> {
+        let pixels = [255u8, 0, 0, 255].repeat(4);
+        let bytes = PngExporter::encode_rgba(&pixels, 2, 2)?;
+        assert_eq!(&bytes[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+
+        // 长度不匹配时报错
+        assert!(PngExporter::encode_rgba(&pixels, 3, 2).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_export_to_file() -> ExportResult<()> {
         let exporter = PngExporter::new();
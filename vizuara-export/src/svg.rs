@@ -1,8 +1,15 @@
 use crate::{ExportError, ExportFormat, ExportOptions, ExportResult, Exporter};
-use svg::node::element::{Circle, Line, Rectangle, Text as SvgText};
-use svg::node::Text;
+use nalgebra::Point2;
+use svg::node::element::{
+    Circle, Definitions, Element, Filter, Group, Line, LinearGradient, Path, Rectangle, Stop,
+    Text as SvgText,
+};
+use svg::node::{Node, Text};
 use svg::Document;
-use vizuara_core::{Color, Primitive, Style};
+use vizuara_core::{
+    normalize_gradient_stops, Color, GradientDirection, HorizontalAlign, Primitive, Style,
+    VerticalAlign,
+};
 
 /// SVG导出器
 pub struct SvgExporter;
@@ -23,11 +30,129 @@ impl SvgExporter {
         )
     }
 
-    /// 将原语转换为SVG元素
+    /// 生成从当前点沿圆弧到终点角度的路径命令（角度为弧度，y 轴向下，与渲染器一致）
+    ///
+    /// 超过半圆的圆弧拆成两段，避免整圆时起点与终点重合导致圆弧退化。
+    fn arc_commands(center: Point2<f32>, radius: f32, start: f32, end: f32) -> String {
+        let sweep_flag = if end >= start { 1 } else { 0 };
+        let segments = if (end - start).abs() > std::f32::consts::PI {
+            2
+        } else {
+            1
+        };
+        let step = (end - start) / segments as f32;
+
+        (1..=segments)
+            .map(|i| {
+                let angle = start + step * i as f32;
+                format!(
+                    "A {} {} 0 0 {} {} {} ",
+                    radius,
+                    radius,
+                    sweep_flag,
+                    center.x + radius * angle.cos(),
+                    center.y + radius * angle.sin()
+                )
+            })
+            .collect()
+    }
+
+    /// 圆弧类原语的路径元素：填充使用原语颜色，描边优先使用原语自带的描边，否则使用样式描边
+    fn arc_path(d: String, fill: &Color, stroke: &Option<(Color, f32)>, style: &Style) -> Path {
+        let mut path = Path::new()
+            .set("d", d)
+            .set("fill", Self::color_to_svg(fill));
+
+        if fill.a < 1.0 {
+            path = path.set("fill-opacity", fill.a);
+        }
+
+        let stroke = stroke.or_else(|| style.stroke_color.map(|c| (c, style.stroke_width)));
+        path = match stroke {
+            Some((color, width)) => path
+                .set("stroke", Self::color_to_svg(&color))
+                .set("stroke-width", width),
+            None => path.set("stroke", "none"),
+        };
+
+        if style.opacity < 1.0 {
+            path = path.set("opacity", style.opacity);
+        }
+
+        path
+    }
+
+    /// 水平对齐对应的 text-anchor（与 WGPU 渲染器的文本定位一致）
+    fn text_anchor(align: HorizontalAlign) -> &'static str {
+        match align {
+            HorizontalAlign::Left => "start",
+            HorizontalAlign::Center => "middle",
+            HorizontalAlign::Right => "end",
+        }
+    }
+
+    /// 垂直对齐对应的 dominant-baseline（与 WGPU 渲染器的文本定位一致）
+    fn dominant_baseline(align: VerticalAlign) -> &'static str {
+        match align {
+            VerticalAlign::Top => "text-before-edge",
+            VerticalAlign::Middle => "central",
+            VerticalAlign::Baseline => "alphabetic",
+            VerticalAlign::Bottom => "text-after-edge",
+        }
+    }
+
+    /// 为带投影样式的矩形与圆形包裹 feDropShadow 滤镜（id 为 `shadow-{index}`），其他原语原样返回
+    fn apply_shadow(
+        element: Box<dyn svg::Node>,
+        primitive: &Primitive,
+        style: &Style,
+        index: usize,
+    ) -> Box<dyn svg::Node> {
+        let Some(shadow) = &style.shadow else {
+            return element;
+        };
+        if !matches!(
+            primitive,
+            Primitive::Rectangle { .. }
+                | Primitive::RectangleStyled { .. }
+                | Primitive::Circle { .. }
+        ) {
+            return element;
+        }
+
+        let id = format!("shadow-{}", index);
+        // svg crate 没有 feDropShadow 的具名元素，使用通用元素构建
+        let mut drop_shadow = Element::new("feDropShadow");
+        drop_shadow.assign("dx", shadow.offset.0);
+        drop_shadow.assign("dy", shadow.offset.1);
+        drop_shadow.assign("stdDeviation", shadow.blur / 2.0);
+        drop_shadow.assign("flood-color", Self::color_to_svg(&shadow.color));
+        drop_shadow.assign("flood-opacity", shadow.color.a);
+
+        // 模糊半径约为两倍标准差，滤镜区域放大以免投影被裁切
+        let filter = Filter::new()
+            .set("id", id.as_str())
+            .set("x", "-50%")
+            .set("y", "-50%")
+            .set("width", "200%")
+            .set("height", "200%")
+            .add(drop_shadow);
+
+        Box::new(
+            Group::new().add(Definitions::new().add(filter)).add(
+                Group::new()
+                    .set("filter", format!("url(#{})", id))
+                    .add(element),
+            ),
+        )
+    }
+
+    /// 将原语转换为SVG元素（index 用于生成唯一的渐变 id）
     fn primitive_to_svg(
         primitive: &Primitive,
         style: &Style,
         options: &ExportOptions,
+        index: usize,
     ) -> Result<Box<dyn svg::Node>, ExportError> {
         match primitive {
             Primitive::Circle { center, radius } => {
@@ -58,6 +183,49 @@ impl SvgExporter {
                 Ok(Box::new(circle))
             }
 
+            Primitive::ArcSector {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+                fill,
+                stroke,
+            } => {
+                let d = format!(
+                    "M {} {} L {} {} {}Z",
+                    center.x,
+                    center.y,
+                    center.x + radius * start_angle.cos(),
+                    center.y + radius * start_angle.sin(),
+                    Self::arc_commands(*center, *radius, *start_angle, *end_angle)
+                );
+
+                Ok(Box::new(Self::arc_path(d, fill, stroke, style)))
+            }
+
+            Primitive::ArcRing {
+                center,
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+                fill,
+                stroke,
+            } => {
+                // 外弧正向，内弧反向，闭合成环形扇区
+                let d = format!(
+                    "M {} {} {}L {} {} {}Z",
+                    center.x + outer_radius * start_angle.cos(),
+                    center.y + outer_radius * start_angle.sin(),
+                    Self::arc_commands(*center, *outer_radius, *start_angle, *end_angle),
+                    center.x + inner_radius * end_angle.cos(),
+                    center.y + inner_radius * end_angle.sin(),
+                    Self::arc_commands(*center, *inner_radius, *end_angle, *start_angle)
+                );
+
+                Ok(Box::new(Self::arc_path(d, fill, stroke, style)))
+            }
+
             Primitive::Rectangle { min, max } => {
                 let width = max.x - min.x;
                 let height = max.y - min.y;
@@ -90,6 +258,49 @@ impl SvgExporter {
                 Ok(Box::new(rect))
             }
 
+            Primitive::GradientRect {
+                min,
+                max,
+                stops,
+                direction,
+            } => {
+                let id = format!("gradient-{}", index);
+                let (x2, y2) = match direction {
+                    GradientDirection::Horizontal => ("100%", "0%"),
+                    GradientDirection::Vertical => ("0%", "100%"),
+                };
+
+                let mut gradient = LinearGradient::new()
+                    .set("id", id.as_str())
+                    .set("x1", "0%")
+                    .set("y1", "0%")
+                    .set("x2", x2)
+                    .set("y2", y2);
+                for stop in normalize_gradient_stops(stops) {
+                    gradient = gradient.add(
+                        Stop::new()
+                            .set("offset", stop.offset)
+                            .set("stop-color", Self::color_to_svg(&stop.color))
+                            .set("stop-opacity", stop.color.a),
+                    );
+                }
+
+                let mut rect = Rectangle::new()
+                    .set("x", min.x.min(max.x))
+                    .set("y", min.y.min(max.y))
+                    .set("width", (max.x - min.x).abs())
+                    .set("height", (max.y - min.y).abs())
+                    .set("fill", format!("url(#{})", id));
+
+                if style.opacity < 1.0 {
+                    rect = rect.set("opacity", style.opacity);
+                }
+
+                Ok(Box::new(
+                    Group::new().add(Definitions::new().add(gradient)).add(rect),
+                ))
+            }
+
             Primitive::Line { start, end } => {
                 let mut line = Line::new()
                     .set("x1", start.x)
@@ -116,20 +327,87 @@ impl SvgExporter {
                 Ok(Box::new(line))
             }
 
+            Primitive::LineStrip(points) => {
+                let d = points
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| format!("{} {} {}", if i == 0 { "M" } else { "L" }, p.x, p.y))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let stroke = style
+                    .stroke_color
+                    .as_ref()
+                    .map_or_else(|| "black".to_string(), Self::color_to_svg);
+                let mut path = Path::new()
+                    .set("d", d)
+                    .set("fill", "none")
+                    .set("stroke", stroke)
+                    .set("stroke-width", style.stroke_width);
+
+                if style.opacity < 1.0 {
+                    path = path.set("opacity", style.opacity);
+                }
+
+                Ok(Box::new(path))
+            }
+
+            Primitive::Points(points) => {
+                // 每个点渲染为半径为 marker_size 的圆
+                let fill = style
+                    .fill_color
+                    .as_ref()
+                    .map_or_else(|| "black".to_string(), Self::color_to_svg);
+                let mut group = Group::new().set("fill", fill);
+                if let Some(stroke_color) = &style.stroke_color {
+                    group = group
+                        .set("stroke", Self::color_to_svg(stroke_color))
+                        .set("stroke-width", style.stroke_width);
+                }
+                if style.opacity < 1.0 {
+                    group = group.set("opacity", style.opacity);
+                }
+                for p in points {
+                    group = group.add(
+                        Circle::new()
+                            .set("cx", p.x)
+                            .set("cy", p.y)
+                            .set("r", style.marker_size),
+                    );
+                }
+
+                Ok(Box::new(group))
+            }
+
             Primitive::Text {
                 position,
                 content,
                 size,
                 color,
-                ..
+                h_align,
+                v_align,
+                rotation,
             } => {
                 let mut text = SvgText::new()
                     .set("x", position.x)
                     .set("y", position.y)
                     .set("font-size", *size)
                     .set("fill", Self::color_to_svg(color))
+                    .set("text-anchor", Self::text_anchor(*h_align))
+                    .set("dominant-baseline", Self::dominant_baseline(*v_align))
                     .add(Text::new(content.clone()));
 
+                if *rotation != 0.0 {
+                    // 绕锚点旋转，对齐方式不变
+                    text = text.set(
+                        "transform",
+                        format!("rotate({} {} {})", rotation, position.x, position.y),
+                    );
+                }
+
+                if color.a < 1.0 {
+                    text = text.set("fill-opacity", color.a);
+                }
+
                 if style.opacity < 1.0 {
                     text = text.set("opacity", style.opacity);
                 }
@@ -145,9 +423,11 @@ impl SvgExporter {
                     .set("cy", position.y)
                     .set("r", radius);
 
-                // 应用样式
+                // 应用样式；只有描边时为空心标记
                 if let Some(fill_color) = &style.fill_color {
                     circle = circle.set("fill", Self::color_to_svg(fill_color));
+                } else if style.stroke_color.is_some() {
+                    circle = circle.set("fill", "none");
                 } else {
                     circle = circle.set("fill", "black"); // 默认黑色
                 }
@@ -228,10 +508,10 @@ impl Exporter for SvgExporter {
         }
 
         // 转换所有原语
-        for (primitive, style) in primitives.iter().zip(styles.iter()) {
-            match Self::primitive_to_svg(primitive, style, options) {
+        for (index, (primitive, style)) in primitives.iter().zip(styles.iter()).enumerate() {
+            match Self::primitive_to_svg(primitive, style, options, index) {
                 Ok(element) => {
-                    document = document.add(element);
+                    document = document.add(Self::apply_shadow(element, primitive, style, index));
                 }
                 Err(e) => {
                     // 记录错误但继续处理其他元素
@@ -253,8 +533,8 @@ impl Exporter for SvgExporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use nalgebra::Point2;
     use tempfile::tempdir;
+    use vizuara_core::Shadow;
 
     #[test]
     fn test_svg_exporter_creation() {
@@ -292,6 +572,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_line_strip_and_points_export() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![
+            Primitive::LineStrip(vec![Point2::new(0.0, 0.0), Point2::new(10.0, 5.0)]),
+            Primitive::Points(vec![Point2::new(3.0, 3.0), Point2::new(6.0, 6.0)]),
+        ];
+        let line_style = Style::new().stroke(Color::rgb(1.0, 0.0, 0.0), 2.0);
+        let point_style = Style::new()
+            .fill_color(Color::rgb(0.0, 1.0, 0.0))
+            .marker(vizuara_core::MarkerStyle::Circle, 4.0);
+
+        let bytes = exporter.export_to_bytes(
+            &primitives,
+            &[line_style, point_style],
+            20,
+            20,
+            &ExportOptions::default(),
+        )?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        assert!(svg_string.contains("d=\"M 0 0 L 10 5\""));
+        assert!(svg_string.contains("stroke=\"rgb(255, 0, 0)\""));
+        assert!(svg_string.contains("fill=\"rgb(0, 255, 0)\""));
+        assert_eq!(svg_string.matches("r=\"4\"").count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hollow_point_export() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![Primitive::Point(Point2::new(10.0, 10.0))];
+        let mut style = Style::new().stroke(Color::rgb(1.0, 0.0, 0.0), 2.0);
+        style.fill_color = None;
+
+        let bytes =
+            exporter.export_to_bytes(&primitives, &[style], 20, 20, &ExportOptions::default())?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        assert!(svg_string.contains("fill=\"none\""));
+        assert!(svg_string.contains("stroke=\"rgb(255, 0, 0)\""));
+
+        Ok(())
+    }
+
     #[test]
     fn test_export_to_file() -> ExportResult<()> {
         let exporter = SvgExporter::new();
@@ -325,6 +650,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shadow_filter_export() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![
+            Primitive::Rectangle {
+                min: Point2::new(10.0, 10.0),
+                max: Point2::new(60.0, 40.0),
+            },
+            Primitive::Line {
+                start: Point2::new(0.0, 0.0),
+                end: Point2::new(10.0, 10.0),
+            },
+        ];
+        let shadow = Shadow::new((3.0, 4.0), 6.0, Color::rgba(0.0, 0.0, 0.0, 0.4));
+        let styles = vec![Style::new().shadow(shadow), Style::new().shadow(shadow)];
+
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 100, 100, &ExportOptions::default())?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        assert!(svg_string.contains("<feDropShadow"));
+        assert!(svg_string.contains("dx=\"3\""));
+        assert!(svg_string.contains("stdDeviation=\"3\""));
+        assert!(svg_string.contains("filter=\"url(#shadow-0)\""));
+        // 投影目前只作用于矩形与圆形
+        assert!(!svg_string.contains("shadow-1"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_background_color() -> ExportResult<()> {
         let exporter = SvgExporter::new();
@@ -340,4 +695,157 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_gradient_rect_export() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![Primitive::GradientRect {
+            min: Point2::new(0.0, 0.0),
+            max: Point2::new(100.0, 50.0),
+            stops: vec![
+                vizuara_core::GradientStop::new(0.0, Color::rgb(1.0, 0.0, 0.0)),
+                vizuara_core::GradientStop::new(0.5, Color::rgb(0.0, 1.0, 0.0)),
+                vizuara_core::GradientStop::new(1.0, Color::rgb(0.0, 0.0, 1.0)),
+            ],
+            direction: GradientDirection::Vertical,
+        }];
+        let styles = vec![Style::new()];
+
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 100, 100, &ExportOptions::default())?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        assert!(svg_string.contains("<linearGradient"));
+        assert!(svg_string.contains("id=\"gradient-0\""));
+        assert!(svg_string.contains("y2=\"100%\""));
+        assert_eq!(svg_string.matches("<stop").count(), 3);
+        assert!(svg_string.contains("fill=\"url(#gradient-0)\""));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_circle_and_arc_stroke_export() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![
+            Primitive::Circle {
+                center: Point2::new(50.0, 50.0),
+                radius: 20.0,
+            },
+            Primitive::ArcSector {
+                center: Point2::new(50.0, 50.0),
+                radius: 30.0,
+                start_angle: 0.0,
+                end_angle: std::f32::consts::PI * 1.5,
+                fill: Color::rgb(1.0, 0.0, 0.0),
+                stroke: Some((Color::rgb(0.0, 0.0, 1.0), 3.0)),
+            },
+            Primitive::ArcRing {
+                center: Point2::new(50.0, 50.0),
+                inner_radius: 10.0,
+                outer_radius: 30.0,
+                start_angle: 0.0,
+                end_angle: 1.0,
+                fill: Color::rgb(0.0, 1.0, 0.0),
+                stroke: None,
+            },
+        ];
+        let styles = vec![
+            Style::new()
+                .fill_color(Color::rgb(1.0, 1.0, 0.0))
+                .stroke(Color::rgb(0.0, 0.0, 0.0), 2.0),
+            Style::new(),
+            Style::new().stroke(Color::rgb(0.5, 0.5, 0.5), 1.5),
+        ];
+
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 100, 100, &ExportOptions::default())?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        // 逐个元素解析属性
+        let element = |tag: &str, nth: usize| -> String {
+            let start = svg_string.match_indices(tag).nth(nth).unwrap().0;
+            let end = start + svg_string[start..].find("/>").unwrap();
+            svg_string[start..end].to_string()
+        };
+        let attr = |element: &str, name: &str| -> Option<String> {
+            let key = format!(" {}=\"", name);
+            let start = element.find(&key)? + key.len();
+            let end = start + element[start..].find('"')?;
+            Some(element[start..end].to_string())
+        };
+
+        let circle = element("<circle", 0);
+        assert_eq!(attr(&circle, "fill").as_deref(), Some("rgb(255, 255, 0)"));
+        assert_eq!(attr(&circle, "stroke").as_deref(), Some("rgb(0, 0, 0)"));
+        assert_eq!(attr(&circle, "stroke-width").as_deref(), Some("2"));
+
+        // 超过半圆的扇形拆为两段圆弧
+        let sector = element("<path", 0);
+        assert_eq!(attr(&sector, "fill").as_deref(), Some("rgb(255, 0, 0)"));
+        assert_eq!(attr(&sector, "stroke").as_deref(), Some("rgb(0, 0, 255)"));
+        assert_eq!(attr(&sector, "stroke-width").as_deref(), Some("3"));
+        assert_eq!(attr(&sector, "d").unwrap().matches('A').count(), 2);
+
+        // 原语未指定描边时回退到样式描边
+        let ring = element("<path", 1);
+        assert_eq!(attr(&ring, "stroke").as_deref(), Some("rgb(127, 127, 127)"));
+        assert_eq!(attr(&ring, "stroke-width").as_deref(), Some("1.5"));
+        assert_eq!(attr(&ring, "d").unwrap().matches('A').count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_export_alignment() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![Primitive::Text {
+            position: Point2::new(40.0, 20.0),
+            content: "标签".to_string(),
+            size: 14.0,
+            color: Color::rgb(1.0, 0.0, 0.0),
+            h_align: HorizontalAlign::Right,
+            v_align: VerticalAlign::Middle,
+            rotation: 0.0,
+        }];
+        let styles = vec![Style::new()];
+
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 100, 100, &ExportOptions::default())?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        assert!(svg_string.contains("<text"));
+        assert!(svg_string.contains("标签"));
+        assert!(svg_string.contains("font-size=\"14\""));
+        assert!(svg_string.contains("fill=\"rgb(255, 0, 0)\""));
+        assert!(svg_string.contains("text-anchor=\"end\""));
+        assert!(svg_string.contains("dominant-baseline=\"central\""));
+        assert!(!svg_string.contains("transform"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_export_rotation() -> ExportResult<()> {
+        let exporter = SvgExporter::new();
+        let primitives = vec![Primitive::Text {
+            position: Point2::new(40.0, 20.0),
+            content: "Category".to_string(),
+            size: 12.0,
+            color: Color::rgb(0.0, 0.0, 0.0),
+            h_align: HorizontalAlign::Right,
+            v_align: VerticalAlign::Top,
+            rotation: -45.0,
+        }];
+        let styles = vec![Style::new()];
+
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 100, 100, &ExportOptions::default())?;
+        let svg_string = String::from_utf8(bytes).unwrap();
+
+        assert!(svg_string.contains("transform=\"rotate(-45 40 20)\""));
+        assert!(svg_string.contains("text-anchor=\"end\""));
+
+        Ok(())
+    }
 }
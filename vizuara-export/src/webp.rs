@@ -0,0 +1,84 @@
+use crate::png::PngExporter;
+use crate::{ExportError, ExportFormat, ExportOptions, ExportResult, Exporter};
+use vizuara_core::{Primitive, Style};
+
+/// WebP导出器（保留透明通道）
+pub struct WebpExporter;
+
+impl WebpExporter {
+    /// 创建新的WebP导出器
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for WebpExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Exporter for WebpExporter {
+    fn export_to_file(
+        &self,
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        path: &str,
+        options: &ExportOptions,
+    ) -> ExportResult<()> {
+        let webp_data = self.export_to_bytes(primitives, styles, width, height, options)?;
+        std::fs::write(path, webp_data)?;
+        Ok(())
+    }
+
+    fn export_to_bytes(
+        &self,
+        primitives: &[Primitive],
+        styles: &[Style],
+        width: u32,
+        height: u32,
+        options: &ExportOptions,
+    ) -> ExportResult<Vec<u8>> {
+        let pixmap = PngExporter::rasterize(primitives, styles, width, height, options)?;
+        let image = image::RgbaImage::from_raw(width, height, PngExporter::pixmap_to_rgba(&pixmap))
+            .ok_or_else(|| ExportError::ImageError("像素数据与尺寸不匹配".to_string()))?;
+
+        let mut bytes = Vec::new();
+        image
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageOutputFormat::WebP,
+            )
+            .map_err(|e| ExportError::ImageError(format!("WebP编码失败: {}", e)))?;
+        Ok(bytes)
+    }
+
+    fn supported_format(&self) -> ExportFormat {
+        ExportFormat::Webp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Point2;
+    use vizuara_core::Color;
+
+    #[test]
+    fn test_webp_export_to_bytes() -> ExportResult<()> {
+        let exporter = WebpExporter::new();
+        let primitives = vec![Primitive::Rectangle {
+            min: Point2::new(5.0, 5.0),
+            max: Point2::new(15.0, 15.0),
+        }];
+        let styles = vec![Style::new().fill_color(Color::rgb(0.0, 0.0, 1.0))];
+        let bytes =
+            exporter.export_to_bytes(&primitives, &styles, 20, 20, &ExportOptions::default())?;
+
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(&bytes[8..12], b"WEBP");
+        Ok(())
+    }
+}
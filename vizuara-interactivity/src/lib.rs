@@ -3,9 +3,11 @@
 //! 提供用户交互功能，包括鼠标事件、缩放、平移等
 
 pub mod events;
+pub mod selection;
 pub mod tools;
 pub mod viewport;
 
 pub use events::*;
+pub use selection::*;
 pub use tools::*;
 pub use viewport::*;
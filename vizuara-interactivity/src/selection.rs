@@ -0,0 +1,145 @@
+//! 共享选择状态
+//!
+//! 多个图表共享同一个选择源，实现联动刷选（linked brushing）
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+/// 选择变化回调，参数为当前选中的索引集合
+pub type SelectionCallback = Box<dyn FnMut(&HashSet<usize>)>;
+
+/// 可在多个图表间共享的选择状态
+pub type SharedSelection = Rc<RefCell<SelectionState>>;
+
+/// 选择状态：保存被选中的数据索引，并在变化时通知订阅者
+#[derive(Default)]
+pub struct SelectionState {
+    selected: HashSet<usize>,
+    subscribers: Vec<SelectionCallback>,
+    version: u64,
+}
+
+impl SelectionState {
+    /// 创建空的选择状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建可共享的选择状态
+    pub fn shared() -> SharedSelection {
+        Rc::new(RefCell::new(Self::new()))
+    }
+
+    /// 订阅选择变化
+    pub fn subscribe(&mut self, callback: SelectionCallback) {
+        self.subscribers.push(callback);
+    }
+
+    /// 替换当前选择
+    pub fn set<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        let selected: HashSet<usize> = indices.into_iter().collect();
+        if selected != self.selected {
+            self.selected = selected;
+            self.notify();
+        }
+    }
+
+    /// 追加选择
+    pub fn extend<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        let before = self.selected.len();
+        self.selected.extend(indices);
+        if self.selected.len() != before {
+            self.notify();
+        }
+    }
+
+    /// 切换单个索引的选中状态
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+        self.notify();
+    }
+
+    /// 清除选择
+    pub fn clear(&mut self) {
+        if !self.selected.is_empty() {
+            self.selected.clear();
+            self.notify();
+        }
+    }
+
+    /// 索引是否被选中
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// 是否存在有效选择（空选择表示不过滤）
+    pub fn has_selection(&self) -> bool {
+        !self.selected.is_empty()
+    }
+
+    /// 获取选中的索引集合
+    pub fn selected(&self) -> &HashSet<usize> {
+        &self.selected
+    }
+
+    /// 用于渲染的选择：没有选择时返回 None，图表按未过滤状态绘制
+    pub fn active(&self) -> Option<&HashSet<usize>> {
+        if self.selected.is_empty() {
+            None
+        } else {
+            Some(&self.selected)
+        }
+    }
+
+    /// 选择版本号，每次变化递增，可用于判断是否需要重绘
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn notify(&mut self) {
+        self.version += 1;
+        for callback in &mut self.subscribers {
+            callback(&self.selected);
+        }
+    }
+}
+
+impl fmt::Debug for SelectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectionState")
+            .field("selected", &self.selected)
+            .field("subscribers", &self.subscribers.len())
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_state_notifies_subscribers() {
+        let selection = SelectionState::shared();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let sink = received.clone();
+        selection.borrow_mut().subscribe(Box::new(move |indices| {
+            sink.borrow_mut().push(indices.len())
+        }));
+
+        selection.borrow_mut().set(vec![1, 3, 5]);
+        // 相同的选择不会重复通知
+        selection.borrow_mut().set(vec![5, 3, 1]);
+        selection.borrow_mut().toggle(3);
+        selection.borrow_mut().clear();
+
+        assert_eq!(*received.borrow(), vec![3, 2, 0]);
+        assert_eq!(selection.borrow().version(), 3);
+        assert!(selection.borrow().active().is_none());
+    }
+}
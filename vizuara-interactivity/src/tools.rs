@@ -1,8 +1,13 @@
+use crate::selection::SharedSelection;
 use crate::viewport::*;
+use nalgebra::Point2;
 use std::collections::HashMap;
+use std::fmt;
+use vizuara_animation::AnimationConfig;
 use vizuara_core::{
     coords::{LogicalPosition, WorldPosition},
     error::Result,
+    Color, HorizontalAlign, Primitive, VerticalAlign,
 };
 use winit::event::MouseButton;
 
@@ -28,6 +33,8 @@ pub enum SimpleMouseEvent {
         button: MouseButton,
         position: LogicalPosition,
     },
+    /// 光标离开窗口
+    Leave,
 }
 
 /// 简化的键盘事件（用于工具系统）
@@ -50,6 +57,16 @@ pub enum ToolType {
     Measure,
     /// 重置视图工具
     Reset,
+    /// 悬停提示工具
+    Hover,
+    /// 框选缩放工具
+    BoxZoom,
+    /// 十字准线工具
+    Crosshair,
+    /// 套索选择工具
+    Lasso,
+    /// 点选工具
+    Pick,
 }
 
 /// 工具状态
@@ -103,6 +120,9 @@ pub struct PanTool {
     state: ToolState,
     button: MouseButton,
     sensitivity: f64,
+    key_step: f64,
+    large_key_step: f64,
+    shift_pressed: bool,
 }
 
 impl PanTool {
@@ -112,6 +132,9 @@ impl PanTool {
             state: ToolState::Idle,
             button: MouseButton::Left,
             sensitivity: 1.0,
+            key_step: 20.0,
+            large_key_step: 100.0,
+            shift_pressed: false,
         }
     }
 
@@ -126,6 +149,18 @@ impl PanTool {
         self.sensitivity = sensitivity;
         self
     }
+
+    /// 设置方向键平移步长（屏幕像素）
+    pub fn with_key_step(mut self, step: f64) -> Self {
+        self.key_step = step;
+        self
+    }
+
+    /// 设置按住 Shift 时的方向键平移步长（屏幕像素）
+    pub fn with_large_key_step(mut self, step: f64) -> Self {
+        self.large_key_step = step;
+        self
+    }
 }
 
 impl Default for PanTool {
@@ -193,10 +228,38 @@ impl InteractiveTool for PanTool {
 
     fn handle_keyboard_event(
         &mut self,
-        _event: &SimpleKeyboardEvent,
-        _viewport: &mut Viewport,
+        event: &SimpleKeyboardEvent,
+        viewport: &mut Viewport,
     ) -> Result<bool> {
-        Ok(false)
+        match event {
+            SimpleKeyboardEvent::KeyPress { key } if key == "Shift" => {
+                self.shift_pressed = true;
+                Ok(false)
+            }
+            SimpleKeyboardEvent::KeyRelease { key } if key == "Shift" => {
+                self.shift_pressed = false;
+                Ok(false)
+            }
+            SimpleKeyboardEvent::KeyPress { key } => {
+                let step = if self.shift_pressed {
+                    self.large_key_step
+                } else {
+                    self.key_step
+                };
+
+                // 方向键移动视野：视图朝按键方向移动，内容相应反向移动
+                let delta = match key.as_str() {
+                    "ArrowLeft" => nalgebra::Vector2::new(step, 0.0),
+                    "ArrowRight" => nalgebra::Vector2::new(-step, 0.0),
+                    "ArrowUp" => nalgebra::Vector2::new(0.0, step),
+                    "ArrowDown" => nalgebra::Vector2::new(0.0, -step),
+                    _ => return Ok(false),
+                };
+                viewport.pan(delta)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
     }
 
     fn tool_type(&self) -> ToolType {
@@ -209,6 +272,7 @@ impl InteractiveTool for PanTool {
 
     fn reset(&mut self) {
         self.state = ToolState::Idle;
+        self.shift_pressed = false;
     }
 }
 
@@ -333,6 +397,8 @@ pub struct SelectTool {
     button: MouseButton,
     selection_rectangle: Option<(WorldPosition, WorldPosition)>,
     selection_threshold: f64,
+    points: Vec<WorldPosition>,
+    shared_selection: Option<SharedSelection>,
 }
 
 impl SelectTool {
@@ -343,6 +409,36 @@ impl SelectTool {
             button: MouseButton::Left,
             selection_rectangle: None,
             selection_threshold: 5.0, // 像素
+            points: Vec::new(),
+            shared_selection: None,
+        }
+    }
+
+    /// 绑定共享选择状态，选择完成后写入被框选的数据索引
+    pub fn with_shared_selection(mut self, selection: SharedSelection) -> Self {
+        self.shared_selection = Some(selection);
+        self
+    }
+
+    /// 设置可被选择的数据点（世界坐标），索引与数据顺序一致
+    pub fn set_points(&mut self, points: Vec<WorldPosition>) {
+        self.points = points;
+    }
+
+    /// 获取选择矩形内的数据点索引
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.points
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| self.is_point_selected(**point))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// 将当前选择同步到共享选择状态
+    fn publish_selection(&self) {
+        if let Some(shared) = &self.shared_selection {
+            shared.borrow_mut().set(self.selected_indices());
         }
     }
 
@@ -357,9 +453,10 @@ impl SelectTool {
         self.selection_rectangle
     }
 
-    /// 清除选择
+    /// 清除选择（同时清除共享选择）
     pub fn clear_selection(&mut self) {
         self.selection_rectangle = None;
+        self.publish_selection();
     }
 
     /// 检查点是否在选择区域内
@@ -426,6 +523,7 @@ impl InteractiveTool for SelectTool {
                 }
 
                 self.state = ToolState::Idle;
+                self.publish_selection();
                 Ok(true)
             }
 
@@ -488,170 +586,1115 @@ impl InteractiveTool for SelectTool {
     }
 }
 
-/// 工具管理器
-#[derive(Debug)]
-pub struct ToolManager {
-    tools: HashMap<ToolType, Box<dyn InteractiveTool>>,
-    active_tool: Option<ToolType>,
-    default_viewport_bounds: Option<ViewBounds>,
-}
+/// 射线法判断点是否在多边形内（奇偶规则，自相交的环按交替填充处理）
+pub fn point_in_polygon(point: WorldPosition, polygon: &[WorldPosition]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
 
-impl ToolManager {
-    /// 创建新的工具管理器
-    pub fn new() -> Self {
-        let mut tools: HashMap<ToolType, Box<dyn InteractiveTool>> = HashMap::new();
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
 
-        // 添加默认工具
-        tools.insert(ToolType::Pan, Box::new(PanTool::new()));
-        tools.insert(ToolType::Zoom, Box::new(ZoomTool::new()));
-        tools.insert(ToolType::Select, Box::new(SelectTool::new()));
+/// 套索选择工具：拖拽时记录光标轨迹，松开后选择闭合多边形内的数据点
+#[derive(Debug, Clone)]
+pub struct LassoTool {
+    state: ToolState,
+    button: MouseButton,
+    path: Vec<WorldPosition>,
+    selection_polygon: Option<Vec<WorldPosition>>,
+    selection_threshold: f64,
+    points: Vec<WorldPosition>,
+    shared_selection: Option<SharedSelection>,
+}
 
+impl LassoTool {
+    /// 创建新的套索选择工具
+    pub fn new() -> Self {
         Self {
-            tools,
-            active_tool: Some(ToolType::Pan), // 默认激活平移工具
-            default_viewport_bounds: None,
+            state: ToolState::Idle,
+            button: MouseButton::Left,
+            path: Vec::new(),
+            selection_polygon: None,
+            selection_threshold: 5.0, // 像素
+            points: Vec::new(),
+            shared_selection: None,
         }
     }
 
-    /// 设置默认视口边界（用于重置）
-    pub fn set_default_viewport_bounds(&mut self, bounds: ViewBounds) {
-        self.default_viewport_bounds = Some(bounds);
+    /// 设置触发按钮
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
     }
 
-    /// 激活指定工具
-    pub fn activate_tool(&mut self, tool_type: ToolType) -> Result<()> {
-        if self.tools.contains_key(&tool_type) {
-            // 重置当前活动工具
-            if let Some(current_tool) = self.active_tool {
-                if let Some(tool) = self.tools.get_mut(&current_tool) {
-                    tool.reset();
-                }
-            }
+    /// 设置点击判定阈值（像素），拖拽距离小于该值时按点击处理
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.selection_threshold = threshold;
+        self
+    }
 
-            self.active_tool = Some(tool_type);
-            Ok(())
+    /// 绑定共享选择状态，选择完成后写入被圈选的数据索引
+    pub fn with_shared_selection(mut self, selection: SharedSelection) -> Self {
+        self.shared_selection = Some(selection);
+        self
+    }
+
+    /// 设置可被选择的数据点（世界坐标），索引与数据顺序一致
+    pub fn set_points(&mut self, points: Vec<WorldPosition>) {
+        self.points = points;
+    }
+
+    /// 正在绘制的套索轨迹（世界坐标）
+    pub fn current_path(&self) -> &[WorldPosition] {
+        &self.path
+    }
+
+    /// 获取已完成的选择多边形（世界坐标）
+    pub fn selection_polygon(&self) -> Option<&[WorldPosition]> {
+        self.selection_polygon.as_deref()
+    }
+
+    /// 检查点是否在选择多边形内
+    pub fn is_point_selected(&self, point: WorldPosition) -> bool {
+        self.selection_polygon
+            .as_ref()
+            .is_some_and(|polygon| point_in_polygon(point, polygon))
+    }
+
+    /// 获取选择多边形内的数据点索引
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.points
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| self.is_point_selected(**point))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// 清除选择（同时清除共享选择）
+    pub fn clear_selection(&mut self) {
+        self.selection_polygon = None;
+        self.publish_selection();
+    }
+
+    /// 生成套索轮廓图元（屏幕坐标）：绘制中为当前轨迹，否则为已完成的选择多边形
+    pub fn primitives(&self, viewport: &Viewport) -> Vec<Primitive> {
+        let outline = if self.path.is_empty() {
+            self.selection_polygon.as_deref().unwrap_or_default()
         } else {
-            Err(format!("工具类型 {:?} 不存在", tool_type).into())
+            &self.path
+        };
+        if outline.len() < 2 {
+            return Vec::new();
         }
+
+        let mut points: Vec<Point2<f32>> = outline
+            .iter()
+            .map(|point| {
+                let screen = viewport.world_to_screen(*point);
+                Point2::new(screen.x as f32, screen.y as f32)
+            })
+            .collect();
+        points.push(points[0]);
+        vec![Primitive::LineStrip(points)]
     }
 
-    /// 获取当前活动工具
-    pub fn active_tool(&self) -> Option<ToolType> {
-        self.active_tool
+    /// 将当前选择同步到共享选择状态
+    fn publish_selection(&self) {
+        if let Some(shared) = &self.shared_selection {
+            shared.borrow_mut().set(self.selected_indices());
+        }
     }
 
-    /// 处理鼠标事件
-    pub fn handle_mouse_event(
+    /// 以点击位置为中心、阈值为半边长的方形选择区域（在屏幕空间构造）
+    fn click_polygon(&self, position: LogicalPosition, viewport: &Viewport) -> Vec<WorldPosition> {
+        let half = self.selection_threshold;
+        [(-half, -half), (half, -half), (half, half), (-half, half)]
+            .iter()
+            .map(|(dx, dy)| {
+                viewport.screen_to_world(LogicalPosition {
+                    x: position.x + dx,
+                    y: position.y + dy,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for LassoTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InteractiveTool for LassoTool {
+    fn handle_mouse_event(
         &mut self,
         event: &SimpleMouseEvent,
         viewport: &mut Viewport,
     ) -> Result<bool> {
-        // 处理重置工具（双击）
-        if let SimpleMouseEvent::DoubleClick { .. } = event {
-            if let Some(bounds) = &self.default_viewport_bounds {
-                viewport.reset(bounds.clone());
-                return Ok(true);
+        match event {
+            SimpleMouseEvent::ButtonPress { button, position } if *button == self.button => {
+                self.state = ToolState::Active {
+                    start_pos: *position,
+                };
+                self.path = vec![viewport.screen_to_world(*position)];
+                self.selection_polygon = None;
+                Ok(true)
             }
-        }
 
-        // 处理活动工具事件
-        if let Some(tool_type) = self.active_tool {
-            if let Some(tool) = self.tools.get_mut(&tool_type) {
-                return tool.handle_mouse_event(event, viewport);
+            SimpleMouseEvent::ButtonRelease { button, position } if *button == self.button => {
+                match self.state {
+                    ToolState::Dragging { .. } => {
+                        self.path.push(viewport.screen_to_world(*position));
+                        self.selection_polygon = Some(std::mem::take(&mut self.path));
+                    }
+                    // 轨迹过短，按点击处理
+                    ToolState::Active { .. } => {
+                        self.selection_polygon = Some(self.click_polygon(*position, viewport));
+                    }
+                    ToolState::Idle => return Ok(false),
+                }
+
+                self.path.clear();
+                self.state = ToolState::Idle;
+                self.publish_selection();
+                Ok(true)
             }
-        }
 
-        Ok(false)
+            SimpleMouseEvent::Move { position } => match self.state {
+                ToolState::Active { start_pos } => {
+                    let distance = ((position.x - start_pos.x).powi(2)
+                        + (position.y - start_pos.y).powi(2))
+                    .sqrt();
+                    if distance > self.selection_threshold {
+                        self.path.push(viewport.screen_to_world(*position));
+                        self.state = ToolState::Dragging {
+                            start_pos,
+                            current_pos: *position,
+                        };
+                    }
+                    Ok(true)
+                }
+                ToolState::Dragging { start_pos, .. } => {
+                    self.path.push(viewport.screen_to_world(*position));
+                    self.state = ToolState::Dragging {
+                        start_pos,
+                        current_pos: *position,
+                    };
+                    Ok(true)
+                }
+                ToolState::Idle => Ok(false),
+            },
+
+            _ => Ok(false),
+        }
     }
 
-    /// 处理键盘事件
-    pub fn handle_keyboard_event(
+    fn handle_keyboard_event(
         &mut self,
         event: &SimpleKeyboardEvent,
-        viewport: &mut Viewport,
+        _viewport: &mut Viewport,
     ) -> Result<bool> {
-        // 处理工具切换快捷键
-        if let SimpleKeyboardEvent::KeyPress { key } = event {
-            match key.as_str() {
-                "p" | "P" => {
-                    self.activate_tool(ToolType::Pan)?;
-                    return Ok(true);
-                }
-                "z" | "Z" => {
-                    self.activate_tool(ToolType::Zoom)?;
-                    return Ok(true);
-                }
-                "s" | "S" => {
-                    self.activate_tool(ToolType::Select)?;
-                    return Ok(true);
-                }
-                "r" | "R" => {
-                    if let Some(bounds) = &self.default_viewport_bounds {
-                        viewport.reset(bounds.clone());
-                        return Ok(true);
-                    }
-                }
-                _ => {}
+        match event {
+            SimpleKeyboardEvent::KeyPress { key } if key == "Escape" => {
+                self.clear_selection();
+                self.reset();
+                Ok(true)
             }
+            _ => Ok(false),
         }
+    }
 
-        // 处理活动工具键盘事件
-        if let Some(tool_type) = self.active_tool {
-            if let Some(tool) = self.tools.get_mut(&tool_type) {
-                return tool.handle_keyboard_event(event, viewport);
-            }
-        }
+    fn tool_type(&self) -> ToolType {
+        ToolType::Lasso
+    }
 
-        Ok(false)
+    fn state(&self) -> &ToolState {
+        &self.state
     }
 
-    /// 添加自定义工具
-    pub fn add_tool(&mut self, tool: Box<dyn InteractiveTool>) {
-        let tool_type = tool.tool_type();
-        self.tools.insert(tool_type, tool);
+    fn reset(&mut self) {
+        self.state = ToolState::Idle;
+        self.path.clear();
     }
+}
 
-    /// 移除工具
-    pub fn remove_tool(&mut self, tool_type: ToolType) -> Result<()> {
-        if self.active_tool == Some(tool_type) {
-            self.active_tool = None;
+/// 框选缩放工具：拖拽出矩形后缩放到该区域
+#[derive(Debug, Clone)]
+pub struct BoxZoomTool {
+    state: ToolState,
+    button: MouseButton,
+    selection_threshold: f64,
+    preserve_aspect: bool,
+}
+
+impl BoxZoomTool {
+    /// 创建新的框选缩放工具
+    pub fn new() -> Self {
+        Self {
+            state: ToolState::Idle,
+            button: MouseButton::Left,
+            selection_threshold: 5.0, // 像素
+            preserve_aspect: false,
         }
+    }
 
-        self.tools.remove(&tool_type);
-        Ok(())
+    /// 设置触发按钮
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
     }
 
-    /// 获取工具状态
-    pub fn tool_state(&self, tool_type: ToolType) -> Option<&ToolState> {
-        self.tools.get(&tool_type).map(|tool| tool.state())
+    /// 设置最小拖拽距离（像素），小于该距离的拖拽将被忽略
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.selection_threshold = threshold;
+        self
     }
 
-    /// 重置所有工具
-    pub fn reset_all_tools(&mut self) {
-        for tool in self.tools.values_mut() {
-            tool.reset();
+    /// 设置缩放时是否保持视图宽高比
+    pub fn with_preserve_aspect(mut self, preserve: bool) -> Self {
+        self.preserve_aspect = preserve;
+        self
+    }
+
+    /// 获取当前橡皮筋矩形（屏幕坐标），供渲染器绘制
+    pub fn rubber_band(&self) -> Option<(LogicalPosition, LogicalPosition)> {
+        match self.state {
+            ToolState::Dragging {
+                start_pos,
+                current_pos,
+            } => Some((start_pos, current_pos)),
+            _ => None,
         }
     }
 }
 
-impl Default for ToolManager {
+impl Default for BoxZoomTool {
     fn default() -> Self {
         Self::new()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pan_tool() {
-        let mut pan_tool = PanTool::new();
-        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
-
-        // 测试鼠标按下
-        let press_event = SimpleMouseEvent::ButtonPress {
-            button: MouseButton::Left,
+impl InteractiveTool for BoxZoomTool {
+    fn handle_mouse_event(
+        &mut self,
+        event: &SimpleMouseEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleMouseEvent::ButtonPress { button, position } if *button == self.button => {
+                self.state = ToolState::Active {
+                    start_pos: *position,
+                };
+                Ok(true)
+            }
+
+            SimpleMouseEvent::ButtonRelease { button, position } if *button == self.button => {
+                let handled = if let ToolState::Dragging { start_pos, .. } = self.state {
+                    let start_world = viewport.screen_to_world(start_pos);
+                    let end_world = viewport.screen_to_world(*position);
+                    viewport.zoom_to_bounds(
+                        ViewBounds::new(start_world.x, end_world.x, start_world.y, end_world.y),
+                        self.preserve_aspect,
+                    )?;
+                    true
+                } else {
+                    // 拖拽距离过小，视为误操作
+                    false
+                };
+
+                self.state = ToolState::Idle;
+                Ok(handled)
+            }
+
+            SimpleMouseEvent::Move { position } => match self.state {
+                ToolState::Active { start_pos } => {
+                    let distance = ((position.x - start_pos.x).powi(2)
+                        + (position.y - start_pos.y).powi(2))
+                    .sqrt();
+
+                    if distance > self.selection_threshold {
+                        self.state = ToolState::Dragging {
+                            start_pos,
+                            current_pos: *position,
+                        };
+                    }
+                    Ok(true)
+                }
+                ToolState::Dragging { start_pos, .. } => {
+                    self.state = ToolState::Dragging {
+                        start_pos,
+                        current_pos: *position,
+                    };
+                    Ok(true)
+                }
+                ToolState::Idle => Ok(false),
+            },
+
+            _ => Ok(false),
+        }
+    }
+
+    fn handle_keyboard_event(
+        &mut self,
+        event: &SimpleKeyboardEvent,
+        _viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleKeyboardEvent::KeyPress { key } if key == "Escape" && self.is_active() => {
+                self.reset();
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::BoxZoom
+    }
+
+    fn state(&self) -> &ToolState {
+        &self.state
+    }
+
+    fn reset(&mut self) {
+        self.state = ToolState::Idle;
+    }
+}
+
+/// 测量结果（世界坐标）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub start: WorldPosition,
+    pub end: WorldPosition,
+}
+
+impl Measurement {
+    /// 两点之间的距离（数据单位）
+    pub fn distance(&self) -> f64 {
+        ((self.end.x - self.start.x).powi(2) + (self.end.y - self.start.y).powi(2)).sqrt()
+    }
+
+    /// 从起点指向终点的角度（弧度，相对于 x 轴正方向，逆时针为正）
+    pub fn angle(&self) -> f64 {
+        (self.end.y - self.start.y).atan2(self.end.x - self.start.x)
+    }
+}
+
+/// 测量工具：第一次点击确定起点，第二次点击完成测量
+#[derive(Debug, Clone)]
+pub struct MeasureTool {
+    state: ToolState,
+    button: MouseButton,
+    anchor: Option<WorldPosition>,
+    cursor: Option<WorldPosition>,
+    measurement: Option<Measurement>,
+    label_color: Color,
+}
+
+impl MeasureTool {
+    /// 创建新的测量工具
+    pub fn new() -> Self {
+        Self {
+            state: ToolState::Idle,
+            button: MouseButton::Left,
+            anchor: None,
+            cursor: None,
+            measurement: None,
+            label_color: Color::BLACK,
+        }
+    }
+
+    /// 设置触发按钮
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// 设置标签颜色
+    pub fn with_label_color(mut self, color: Color) -> Self {
+        self.label_color = color;
+        self
+    }
+
+    /// 获取当前测量线段：测量进行中时为实时线段，否则为已完成的测量
+    pub fn current_measurement(&self) -> Option<Measurement> {
+        match (self.anchor, self.cursor) {
+            (Some(start), Some(end)) => Some(Measurement { start, end }),
+            _ => self.measurement,
+        }
+    }
+
+    /// 获取已完成的测量
+    pub fn measurement(&self) -> Option<Measurement> {
+        self.measurement
+    }
+
+    /// 当前测量距离（数据单位）
+    pub fn distance(&self) -> Option<f64> {
+        self.current_measurement().map(|m| m.distance())
+    }
+
+    /// 当前测量角度（弧度）
+    pub fn angle(&self) -> Option<f64> {
+        self.current_measurement().map(|m| m.angle())
+    }
+
+    /// 生成测量线和标签图元（屏幕坐标）
+    pub fn primitives(&self, viewport: &Viewport) -> Vec<Primitive> {
+        let Some(measurement) = self.current_measurement() else {
+            return Vec::new();
+        };
+
+        let start = viewport.world_to_screen(measurement.start);
+        let end = viewport.world_to_screen(measurement.end);
+        let start = Point2::new(start.x as f32, start.y as f32);
+        let end = Point2::new(end.x as f32, end.y as f32);
+
+        vec![
+            Primitive::Line { start, end },
+            Primitive::Text {
+                position: Point2::new((start.x + end.x) / 2.0, (start.y + end.y) / 2.0 - 6.0),
+                content: format!(
+                    "{:.3} @ {:.1}°",
+                    measurement.distance(),
+                    measurement.angle().to_degrees()
+                ),
+                size: 12.0,
+                color: self.label_color,
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
+            },
+        ]
+    }
+}
+
+impl Default for MeasureTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InteractiveTool for MeasureTool {
+    fn handle_mouse_event(
+        &mut self,
+        event: &SimpleMouseEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleMouseEvent::ButtonPress { button, position } if *button == self.button => {
+                let world = viewport.screen_to_world(*position);
+                match self.anchor {
+                    None => {
+                        // 第一次点击：确定起点
+                        self.anchor = Some(world);
+                        self.cursor = None;
+                        self.measurement = None;
+                        self.state = ToolState::Active {
+                            start_pos: *position,
+                        };
+                    }
+                    Some(start) => {
+                        // 第二次点击：完成测量
+                        self.measurement = Some(Measurement { start, end: world });
+                        self.anchor = None;
+                        self.cursor = None;
+                        self.state = ToolState::Idle;
+                    }
+                }
+                Ok(true)
+            }
+
+            SimpleMouseEvent::Move { position } => match self.state {
+                ToolState::Active { start_pos } | ToolState::Dragging { start_pos, .. } => {
+                    self.cursor = Some(viewport.screen_to_world(*position));
+                    self.state = ToolState::Dragging {
+                        start_pos,
+                        current_pos: *position,
+                    };
+                    Ok(true)
+                }
+                ToolState::Idle => Ok(false),
+            },
+
+            _ => Ok(false),
+        }
+    }
+
+    fn handle_keyboard_event(
+        &mut self,
+        event: &SimpleKeyboardEvent,
+        _viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleKeyboardEvent::KeyPress { key } if key == "Escape" => {
+                self.measurement = None;
+                self.reset();
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Measure
+    }
+
+    fn state(&self) -> &ToolState {
+        &self.state
+    }
+
+    fn reset(&mut self) {
+        self.state = ToolState::Idle;
+        self.anchor = None;
+        self.cursor = None;
+    }
+}
+
+/// 悬停命中信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoverInfo {
+    /// 命中数据点的索引
+    pub index: usize,
+    /// 数据点的值（世界坐标）
+    pub value: WorldPosition,
+    /// 数据点的屏幕位置
+    pub screen_position: LogicalPosition,
+    /// 光标到数据点的像素距离
+    pub distance: f64,
+}
+
+/// 在屏幕空间中查找距离光标最近、且位于半径阈值内的数据点
+pub fn hit_test_points(
+    points: &[WorldPosition],
+    cursor: LogicalPosition,
+    viewport: &Viewport,
+    radius: f64,
+) -> Option<HoverInfo> {
+    points
+        .iter()
+        .enumerate()
+        .filter_map(|(index, point)| {
+            let screen_position = viewport.world_to_screen(*point);
+            let distance = ((screen_position.x - cursor.x).powi(2)
+                + (screen_position.y - cursor.y).powi(2))
+            .sqrt();
+            (distance <= radius).then_some(HoverInfo {
+                index,
+                value: *point,
+                screen_position,
+                distance,
+            })
+        })
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+/// 悬停工具：对注册的数据点做最近点命中测试，用于显示提示框
+#[derive(Debug, Clone)]
+pub struct HoverTool {
+    state: ToolState,
+    points: Vec<WorldPosition>,
+    radius: f64,
+    hovered: Option<HoverInfo>,
+}
+
+impl HoverTool {
+    /// 创建新的悬停工具
+    pub fn new() -> Self {
+        Self {
+            state: ToolState::Idle,
+            points: Vec::new(),
+            radius: 8.0, // 像素
+            hovered: None,
+        }
+    }
+
+    /// 设置命中半径（像素）
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// 设置参与命中测试的数据点（世界坐标）
+    pub fn set_points(&mut self, points: Vec<WorldPosition>) {
+        self.points = points;
+        self.hovered = None;
+    }
+
+    /// 获取当前悬停的数据点
+    pub fn hovered(&self) -> Option<&HoverInfo> {
+        self.hovered.as_ref()
+    }
+}
+
+impl Default for HoverTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InteractiveTool for HoverTool {
+    fn handle_mouse_event(
+        &mut self,
+        event: &SimpleMouseEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleMouseEvent::Move { position } => {
+                let hovered = hit_test_points(&self.points, *position, viewport, self.radius);
+                let changed = hovered != self.hovered;
+                self.hovered = hovered;
+                Ok(changed)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn handle_keyboard_event(
+        &mut self,
+        _event: &SimpleKeyboardEvent,
+        _viewport: &mut Viewport,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Hover
+    }
+
+    fn state(&self) -> &ToolState {
+        &self.state
+    }
+
+    fn reset(&mut self) {
+        self.state = ToolState::Idle;
+        self.hovered = None;
+    }
+}
+
+/// 点选回调，参数为命中数据点的索引
+pub type PickCallback = Box<dyn FnMut(usize)>;
+
+/// 点选工具：单击时对注册的数据点做最近点命中测试，命中时调用 `on_pick` 回调
+pub struct PickTool {
+    state: ToolState,
+    button: MouseButton,
+    points: Vec<WorldPosition>,
+    radius: f64,
+    picked: Option<HoverInfo>,
+    callback: Option<PickCallback>,
+}
+
+impl PickTool {
+    /// 创建新的点选工具
+    pub fn new() -> Self {
+        Self {
+            state: ToolState::Idle,
+            button: MouseButton::Left,
+            points: Vec::new(),
+            radius: 8.0, // 像素
+            picked: None,
+            callback: None,
+        }
+    }
+
+    /// 设置命中半径（像素）
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// 设置触发点选的鼠标按键
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// 注册点选回调，替换已有回调
+    pub fn on_pick<F: FnMut(usize) + 'static>(mut self, callback: F) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// 设置参与命中测试的数据点（世界坐标），索引与数据顺序一致
+    pub fn set_points(&mut self, points: Vec<WorldPosition>) {
+        self.points = points;
+        self.picked = None;
+    }
+
+    /// 最近一次点选命中的数据点
+    pub fn picked(&self) -> Option<&HoverInfo> {
+        self.picked.as_ref()
+    }
+
+    /// 在光标处点选，命中时调用回调并返回 true
+    pub fn pick(&mut self, cursor: LogicalPosition, viewport: &Viewport) -> bool {
+        self.picked = hit_test_points(&self.points, cursor, viewport, self.radius);
+        if let (Some(info), Some(callback)) = (&self.picked, self.callback.as_mut()) {
+            callback(info.index);
+        }
+        self.picked.is_some()
+    }
+}
+
+impl Default for PickTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PickTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PickTool")
+            .field("state", &self.state)
+            .field("button", &self.button)
+            .field("points", &self.points.len())
+            .field("radius", &self.radius)
+            .field("picked", &self.picked)
+            .field("callback", &self.callback.is_some())
+            .finish()
+    }
+}
+
+impl InteractiveTool for PickTool {
+    fn handle_mouse_event(
+        &mut self,
+        event: &SimpleMouseEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleMouseEvent::ButtonPress { button, position } if *button == self.button => {
+                Ok(self.pick(*position, viewport))
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn handle_keyboard_event(
+        &mut self,
+        _event: &SimpleKeyboardEvent,
+        _viewport: &mut Viewport,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Pick
+    }
+
+    fn state(&self) -> &ToolState {
+        &self.state
+    }
+
+    fn reset(&mut self) {
+        self.state = ToolState::Idle;
+        self.picked = None;
+    }
+}
+
+/// 十字准线工具：跟随光标并报告光标处的数据坐标，光标离开视口时清除
+#[derive(Debug, Clone)]
+pub struct CrosshairTool {
+    state: ToolState,
+    screen_position: Option<LogicalPosition>,
+    world_position: Option<WorldPosition>,
+}
+
+impl CrosshairTool {
+    /// 创建新的十字准线工具
+    pub fn new() -> Self {
+        Self {
+            state: ToolState::Idle,
+            screen_position: None,
+            world_position: None,
+        }
+    }
+
+    /// 光标的屏幕位置
+    pub fn screen_position(&self) -> Option<LogicalPosition> {
+        self.screen_position
+    }
+
+    /// 光标处的数据坐标
+    pub fn world_position(&self) -> Option<WorldPosition> {
+        self.world_position
+    }
+
+    /// 生成贯穿视口的水平和垂直参考线图元（屏幕坐标）
+    pub fn primitives(&self, viewport: &Viewport) -> Vec<Primitive> {
+        let Some(position) = self.screen_position else {
+            return Vec::new();
+        };
+
+        let size = viewport.size();
+        let (x, y) = (position.x as f32, position.y as f32);
+        vec![
+            Primitive::Line {
+                start: Point2::new(0.0, y),
+                end: Point2::new(size.x as f32, y),
+            },
+            Primitive::Line {
+                start: Point2::new(x, 0.0),
+                end: Point2::new(x, size.y as f32),
+            },
+        ]
+    }
+
+    fn clear(&mut self) -> bool {
+        let had_position = self.screen_position.is_some();
+        self.screen_position = None;
+        self.world_position = None;
+        had_position
+    }
+}
+
+impl Default for CrosshairTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InteractiveTool for CrosshairTool {
+    fn handle_mouse_event(
+        &mut self,
+        event: &SimpleMouseEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        match event {
+            SimpleMouseEvent::Move { position } => {
+                let size = viewport.size();
+                let inside = (0.0..=size.x as f64).contains(&position.x)
+                    && (0.0..=size.y as f64).contains(&position.y);
+                if !inside {
+                    return Ok(self.clear());
+                }
+
+                self.screen_position = Some(*position);
+                self.world_position = Some(viewport.screen_to_world(*position));
+                Ok(true)
+            }
+            SimpleMouseEvent::Leave => Ok(self.clear()),
+            _ => Ok(false),
+        }
+    }
+
+    fn handle_keyboard_event(
+        &mut self,
+        _event: &SimpleKeyboardEvent,
+        _viewport: &mut Viewport,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn tool_type(&self) -> ToolType {
+        ToolType::Crosshair
+    }
+
+    fn state(&self) -> &ToolState {
+        &self.state
+    }
+
+    fn reset(&mut self) {
+        self.state = ToolState::Idle;
+        self.clear();
+    }
+}
+
+/// 工具管理器
+#[derive(Debug)]
+pub struct ToolManager {
+    tools: HashMap<ToolType, Box<dyn InteractiveTool>>,
+    active_tool: Option<ToolType>,
+    default_viewport_bounds: Option<ViewBounds>,
+    reset_animation: Option<AnimationConfig>,
+}
+
+impl ToolManager {
+    /// 创建新的工具管理器
+    pub fn new() -> Self {
+        let mut tools: HashMap<ToolType, Box<dyn InteractiveTool>> = HashMap::new();
+
+        // 添加默认工具
+        tools.insert(ToolType::Pan, Box::new(PanTool::new()));
+        tools.insert(ToolType::Zoom, Box::new(ZoomTool::new()));
+        tools.insert(ToolType::Select, Box::new(SelectTool::new()));
+
+        Self {
+            tools,
+            active_tool: Some(ToolType::Pan), // 默认激活平移工具
+            default_viewport_bounds: None,
+            reset_animation: None,
+        }
+    }
+
+    /// 设置默认视口边界（用于重置）
+    pub fn set_default_viewport_bounds(&mut self, bounds: ViewBounds) {
+        self.default_viewport_bounds = Some(bounds);
+    }
+
+    /// 设置重置视图（双击或 R 键）时的过渡动画；未设置时立即跳转。
+    /// 动画需由调用方每帧调用 [`Viewport::update`] 推进
+    pub fn set_reset_animation(&mut self, config: Option<AnimationConfig>) {
+        self.reset_animation = config;
+    }
+
+    /// 重置到默认视口边界，未设置默认边界时返回 false
+    fn reset_viewport(&self, viewport: &mut Viewport) -> bool {
+        let Some(bounds) = &self.default_viewport_bounds else {
+            return false;
+        };
+        match &self.reset_animation {
+            Some(config) => viewport.animate_to(bounds.clone(), config.clone()),
+            None => viewport.reset(bounds.clone()),
+        }
+        true
+    }
+
+    /// 激活指定工具
+    pub fn activate_tool(&mut self, tool_type: ToolType) -> Result<()> {
+        if self.tools.contains_key(&tool_type) {
+            // 重置当前活动工具
+            if let Some(current_tool) = self.active_tool {
+                if let Some(tool) = self.tools.get_mut(&current_tool) {
+                    tool.reset();
+                }
+            }
+
+            self.active_tool = Some(tool_type);
+            Ok(())
+        } else {
+            Err(format!("工具类型 {:?} 不存在", tool_type).into())
+        }
+    }
+
+    /// 获取当前活动工具
+    pub fn active_tool(&self) -> Option<ToolType> {
+        self.active_tool
+    }
+
+    /// 处理鼠标事件
+    pub fn handle_mouse_event(
+        &mut self,
+        event: &SimpleMouseEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        // 处理重置工具（双击）
+        if let SimpleMouseEvent::DoubleClick { .. } = event {
+            if self.reset_viewport(viewport) {
+                return Ok(true);
+            }
+        }
+
+        // 处理活动工具事件
+        if let Some(tool_type) = self.active_tool {
+            if let Some(tool) = self.tools.get_mut(&tool_type) {
+                return tool.handle_mouse_event(event, viewport);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// 处理键盘事件
+    pub fn handle_keyboard_event(
+        &mut self,
+        event: &SimpleKeyboardEvent,
+        viewport: &mut Viewport,
+    ) -> Result<bool> {
+        // 处理工具切换快捷键
+        if let SimpleKeyboardEvent::KeyPress { key } = event {
+            match key.as_str() {
+                "p" | "P" => {
+                    self.activate_tool(ToolType::Pan)?;
+                    return Ok(true);
+                }
+                "z" | "Z" => {
+                    self.activate_tool(ToolType::Zoom)?;
+                    return Ok(true);
+                }
+                "s" | "S" => {
+                    self.activate_tool(ToolType::Select)?;
+                    return Ok(true);
+                }
+                "r" | "R" if self.default_viewport_bounds.is_some() => {
+                    self.reset_viewport(viewport);
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+
+        // 处理活动工具键盘事件
+        if let Some(tool_type) = self.active_tool {
+            if let Some(tool) = self.tools.get_mut(&tool_type) {
+                return tool.handle_keyboard_event(event, viewport);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// 添加自定义工具
+    pub fn add_tool(&mut self, tool: Box<dyn InteractiveTool>) {
+        let tool_type = tool.tool_type();
+        self.tools.insert(tool_type, tool);
+    }
+
+    /// 移除工具
+    pub fn remove_tool(&mut self, tool_type: ToolType) -> Result<()> {
+        if self.active_tool == Some(tool_type) {
+            self.active_tool = None;
+        }
+
+        self.tools.remove(&tool_type);
+        Ok(())
+    }
+
+    /// 获取工具状态
+    pub fn tool_state(&self, tool_type: ToolType) -> Option<&ToolState> {
+        self.tools.get(&tool_type).map(|tool| tool.state())
+    }
+
+    /// 重置所有工具
+    pub fn reset_all_tools(&mut self) {
+        for tool in self.tools.values_mut() {
+            tool.reset();
+        }
+    }
+}
+
+impl Default for ToolManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::SelectionState;
+
+    #[test]
+    fn test_pan_tool() {
+        let mut pan_tool = PanTool::new();
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+
+        // 测试鼠标按下
+        let press_event = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
             position: LogicalPosition { x: 400.0, y: 300.0 },
         };
 
@@ -671,6 +1714,45 @@ mod tests {
         assert!(matches!(pan_tool.state(), ToolState::Dragging { .. }));
     }
 
+    #[test]
+    fn test_pan_tool_arrow_keys() {
+        let mut pan_tool = PanTool::new().with_key_step(80.0);
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+        let press = |key: &str| SimpleKeyboardEvent::KeyPress {
+            key: key.to_string(),
+        };
+
+        assert!(pan_tool
+            .handle_keyboard_event(&press("ArrowRight"), &mut viewport)
+            .unwrap());
+        // 800 像素对应 10 个单位，80 像素即 1 个单位
+        assert!((viewport.bounds().min_x - 1.0).abs() < 1e-9);
+        assert!((viewport.bounds().max_x - 11.0).abs() < 1e-9);
+
+        // 按住 Shift 使用大步长
+        pan_tool
+            .handle_keyboard_event(&press("Shift"), &mut viewport)
+            .unwrap();
+        pan_tool
+            .handle_keyboard_event(&press("ArrowUp"), &mut viewport)
+            .unwrap();
+        assert!(viewport.bounds().min_y > 0.0);
+        let release = SimpleKeyboardEvent::KeyRelease {
+            key: "Shift".to_string(),
+        };
+        pan_tool
+            .handle_keyboard_event(&release, &mut viewport)
+            .unwrap();
+        pan_tool
+            .handle_keyboard_event(&press("ArrowLeft"), &mut viewport)
+            .unwrap();
+        assert!(viewport.bounds().min_x.abs() < 1e-9);
+
+        assert!(!pan_tool
+            .handle_keyboard_event(&press("a"), &mut viewport)
+            .unwrap());
+    }
+
     #[test]
     fn test_zoom_tool() {
         let mut zoom_tool = ZoomTool::new();
@@ -719,6 +1801,335 @@ mod tests {
         assert!(select_tool.selection_rectangle().is_some());
     }
 
+    #[test]
+    fn test_select_tool_shared_selection() {
+        let shared = SelectionState::shared();
+        let mut select_tool = SelectTool::new().with_shared_selection(shared.clone());
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 8.0, 0.0, 6.0));
+        select_tool.set_points(vec![
+            WorldPosition { x: 1.0, y: 1.0 },
+            WorldPosition { x: 2.0, y: 2.0 },
+            WorldPosition { x: 7.0, y: 5.0 },
+        ]);
+
+        // 框选世界坐标 (0.5, 0.5) - (3, 3)
+        let press = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 50.0, y: 550.0 },
+        };
+        let drag = SimpleMouseEvent::Move {
+            position: LogicalPosition { x: 300.0, y: 300.0 },
+        };
+        let release = SimpleMouseEvent::ButtonRelease {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 300.0, y: 300.0 },
+        };
+        select_tool
+            .handle_mouse_event(&press, &mut viewport)
+            .unwrap();
+        select_tool
+            .handle_mouse_event(&drag, &mut viewport)
+            .unwrap();
+        select_tool
+            .handle_mouse_event(&release, &mut viewport)
+            .unwrap();
+
+        assert_eq!(select_tool.selected_indices(), vec![0, 1]);
+        assert!(shared.borrow().is_selected(0));
+        assert!(shared.borrow().is_selected(1));
+        assert!(!shared.borrow().is_selected(2));
+
+        select_tool.clear_selection();
+        assert!(!shared.borrow().has_selection());
+    }
+
+    #[test]
+    fn test_point_in_polygon_even_odd() {
+        let p = |x: f64, y: f64| WorldPosition { x, y };
+        let square = [p(0.0, 0.0), p(4.0, 0.0), p(4.0, 4.0), p(0.0, 4.0)];
+        assert!(point_in_polygon(p(2.0, 2.0), &square));
+        assert!(!point_in_polygon(p(5.0, 2.0), &square));
+        assert!(!point_in_polygon(p(1.0, 1.0), &square[..2]));
+
+        // 自相交的"8"字形：两个环内为内部，交叉点附近的外侧区域为外部
+        let bowtie = [p(0.0, 0.0), p(4.0, 4.0), p(4.0, 0.0), p(0.0, 4.0)];
+        assert!(point_in_polygon(p(0.5, 2.0), &bowtie));
+        assert!(point_in_polygon(p(3.5, 2.0), &bowtie));
+        assert!(!point_in_polygon(p(2.0, 3.5), &bowtie));
+    }
+
+    #[test]
+    fn test_lasso_tool() {
+        let selection = SelectionState::shared();
+        let mut lasso = LassoTool::new().with_shared_selection(selection.clone());
+        let mut viewport = Viewport::new(100, 100, ViewBounds::new(0.0, 100.0, 0.0, 100.0));
+        lasso.set_points(vec![
+            WorldPosition { x: 50.0, y: 50.0 },
+            WorldPosition { x: 90.0, y: 90.0 },
+        ]);
+
+        let press = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 20.0, y: 20.0 },
+        };
+        lasso.handle_mouse_event(&press, &mut viewport).unwrap();
+        for (x, y) in [(80.0, 20.0), (80.0, 80.0)] {
+            let event = SimpleMouseEvent::Move {
+                position: LogicalPosition { x, y },
+            };
+            lasso.handle_mouse_event(&event, &mut viewport).unwrap();
+        }
+        assert_eq!(lasso.current_path().len(), 3);
+        assert_eq!(lasso.primitives(&viewport).len(), 1);
+
+        let release = SimpleMouseEvent::ButtonRelease {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 20.0, y: 80.0 },
+        };
+        lasso.handle_mouse_event(&release, &mut viewport).unwrap();
+        assert!(lasso.current_path().is_empty());
+        assert_eq!(lasso.selection_polygon().map(|p| p.len()), Some(4));
+        assert_eq!(lasso.selected_indices(), vec![0]);
+        assert!(selection.borrow().is_selected(0));
+
+        // 极短的轨迹按点击处理
+        let click = LogicalPosition { x: 90.0, y: 10.0 };
+        lasso
+            .handle_mouse_event(
+                &SimpleMouseEvent::ButtonPress {
+                    button: MouseButton::Left,
+                    position: click,
+                },
+                &mut viewport,
+            )
+            .unwrap();
+        lasso
+            .handle_mouse_event(
+                &SimpleMouseEvent::ButtonRelease {
+                    button: MouseButton::Left,
+                    position: click,
+                },
+                &mut viewport,
+            )
+            .unwrap();
+        assert_eq!(lasso.selected_indices(), vec![1]);
+    }
+
+    #[test]
+    fn test_box_zoom_tool() {
+        let mut box_zoom = BoxZoomTool::new();
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+
+        let press = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 0.0, y: 0.0 },
+        };
+        let drag = SimpleMouseEvent::Move {
+            position: LogicalPosition { x: 400.0, y: 300.0 },
+        };
+        let release = SimpleMouseEvent::ButtonRelease {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 400.0, y: 300.0 },
+        };
+
+        box_zoom.handle_mouse_event(&press, &mut viewport).unwrap();
+        box_zoom.handle_mouse_event(&drag, &mut viewport).unwrap();
+        assert!(box_zoom.rubber_band().is_some());
+        assert!(box_zoom
+            .handle_mouse_event(&release, &mut viewport)
+            .unwrap());
+
+        // 左上四分之一区域
+        let bounds = viewport.bounds();
+        assert!((bounds.min_x - 0.0).abs() < 1e-10);
+        assert!((bounds.max_x - 5.0).abs() < 1e-10);
+        assert!((bounds.min_y - 5.0).abs() < 1e-10);
+        assert!((bounds.max_y - 10.0).abs() < 1e-10);
+        assert!(box_zoom.rubber_band().is_none());
+    }
+
+    #[test]
+    fn test_box_zoom_ignores_tiny_drag() {
+        let mut box_zoom = BoxZoomTool::new();
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+        let original = viewport.bounds().clone();
+
+        let press = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 100.0, y: 100.0 },
+        };
+        let nudge = SimpleMouseEvent::Move {
+            position: LogicalPosition { x: 102.0, y: 101.0 },
+        };
+        let release = SimpleMouseEvent::ButtonRelease {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 102.0, y: 101.0 },
+        };
+
+        box_zoom.handle_mouse_event(&press, &mut viewport).unwrap();
+        box_zoom.handle_mouse_event(&nudge, &mut viewport).unwrap();
+        assert!(!box_zoom
+            .handle_mouse_event(&release, &mut viewport)
+            .unwrap());
+        assert_eq!(viewport.bounds(), &original);
+    }
+
+    #[test]
+    fn test_measure_tool() {
+        let mut measure = MeasureTool::new();
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 8.0, 0.0, 6.0));
+
+        // 屏幕 (0, 600) -> 世界 (0, 0)
+        let first = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 0.0, y: 600.0 },
+        };
+        measure.handle_mouse_event(&first, &mut viewport).unwrap();
+        assert!(measure.distance().is_none());
+
+        // 实时终点 -> 世界 (3, 4)
+        let moving = SimpleMouseEvent::Move {
+            position: LogicalPosition { x: 300.0, y: 200.0 },
+        };
+        measure.handle_mouse_event(&moving, &mut viewport).unwrap();
+        assert!((measure.distance().unwrap() - 5.0).abs() < 1e-9);
+
+        let second = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 300.0, y: 200.0 },
+        };
+        measure.handle_mouse_event(&second, &mut viewport).unwrap();
+        assert!(!measure.is_active());
+        let result = measure.measurement().unwrap();
+        assert!((result.distance() - 5.0).abs() < 1e-9);
+        assert!((result.angle() - (4.0f64).atan2(3.0)).abs() < 1e-9);
+        assert_eq!(measure.primitives(&viewport).len(), 2);
+    }
+
+    #[test]
+    fn test_measure_tool_escape_cancels() {
+        let mut measure = MeasureTool::new();
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 8.0, 0.0, 6.0));
+
+        let press = SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x: 10.0, y: 10.0 },
+        };
+        measure.handle_mouse_event(&press, &mut viewport).unwrap();
+        assert!(measure.is_active());
+
+        let escape = SimpleKeyboardEvent::KeyPress {
+            key: "Escape".to_string(),
+        };
+        assert!(measure
+            .handle_keyboard_event(&escape, &mut viewport)
+            .unwrap());
+        assert!(!measure.is_active());
+        assert!(measure.current_measurement().is_none());
+    }
+
+    #[test]
+    fn test_hover_tool() {
+        let mut hover_tool = HoverTool::new().with_radius(10.0);
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+        hover_tool.set_points(vec![
+            WorldPosition { x: 5.0, y: 5.0 },
+            WorldPosition { x: 2.0, y: 8.0 },
+        ]);
+
+        // (5, 5) 位于屏幕中心 (400, 300)
+        let near = SimpleMouseEvent::Move {
+            position: LogicalPosition { x: 404.0, y: 303.0 },
+        };
+        assert!(hover_tool.handle_mouse_event(&near, &mut viewport).unwrap());
+        let info = hover_tool.hovered().unwrap();
+        assert_eq!(info.index, 0);
+        assert!((info.distance - 5.0).abs() < 1e-9);
+        assert!((info.screen_position.x - 400.0).abs() < 1e-9);
+
+        // 远离所有点时清除悬停
+        let far = SimpleMouseEvent::Move {
+            position: LogicalPosition { x: 700.0, y: 50.0 },
+        };
+        assert!(hover_tool.handle_mouse_event(&far, &mut viewport).unwrap());
+        assert!(hover_tool.hovered().is_none());
+    }
+
+    #[test]
+    fn test_pick_tool() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let picks = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&picks);
+        let mut pick_tool = PickTool::new()
+            .with_radius(10.0)
+            .on_pick(move |index| sink.borrow_mut().push(index));
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+        pick_tool.set_points(vec![
+            WorldPosition { x: 5.0, y: 5.0 },
+            WorldPosition { x: 6.0, y: 5.0 },
+        ]);
+
+        // (6, 5) 位于屏幕 (480, 300)
+        let click = |x, y| SimpleMouseEvent::ButtonPress {
+            button: MouseButton::Left,
+            position: LogicalPosition { x, y },
+        };
+        assert!(pick_tool
+            .handle_mouse_event(&click(476.0, 300.0), &mut viewport)
+            .unwrap());
+        assert_eq!(pick_tool.picked().unwrap().index, 1);
+
+        // 未命中时不调用回调
+        assert!(!pick_tool
+            .handle_mouse_event(&click(100.0, 100.0), &mut viewport)
+            .unwrap());
+        assert!(pick_tool.picked().is_none());
+
+        // 缩放后按当前视口换算屏幕位置
+        viewport
+            .zoom_at_point(2.0, LogicalPosition { x: 400.0, y: 300.0 })
+            .unwrap();
+        let screen = viewport.world_to_screen(WorldPosition { x: 5.0, y: 5.0 });
+        assert!(pick_tool
+            .handle_mouse_event(&click(screen.x + 3.0, screen.y), &mut viewport)
+            .unwrap());
+        assert_eq!(*picks.borrow(), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_crosshair_tool() {
+        let mut crosshair = CrosshairTool::new();
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+        assert!(crosshair.world_position().is_none());
+        assert!(crosshair.primitives(&viewport).is_empty());
+
+        let position = LogicalPosition { x: 400.0, y: 300.0 };
+        assert!(crosshair
+            .handle_mouse_event(&SimpleMouseEvent::Move { position }, &mut viewport)
+            .unwrap());
+        let world = crosshair.world_position().unwrap();
+        assert!((world.x - 5.0).abs() < 1e-9 && (world.y - 5.0).abs() < 1e-9);
+        assert_eq!(crosshair.screen_position(), Some(position));
+        assert_eq!(crosshair.primitives(&viewport).len(), 2);
+
+        // 移出视口后清除
+        let outside = LogicalPosition { x: 900.0, y: 300.0 };
+        assert!(crosshair
+            .handle_mouse_event(&SimpleMouseEvent::Move { position: outside }, &mut viewport)
+            .unwrap());
+        assert!(crosshair.world_position().is_none());
+
+        crosshair
+            .handle_mouse_event(&SimpleMouseEvent::Move { position }, &mut viewport)
+            .unwrap();
+        crosshair
+            .handle_mouse_event(&SimpleMouseEvent::Leave, &mut viewport)
+            .unwrap();
+        assert!(crosshair.screen_position().is_none());
+    }
+
     #[test]
     fn test_tool_manager() {
         let mut manager = ToolManager::new();
@@ -1,5 +1,7 @@
 use nalgebra::{Matrix3, Vector2, Vector3};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use vizuara_animation::{AnimationConfig, EasingFunction};
 use vizuara_core::{
     coords::{LogicalPosition, WorldPosition},
     error::Result,
@@ -16,6 +18,46 @@ pub struct Viewport {
     size: Vector2<u32>,
     /// 世界坐标系的可见区域边界
     bounds: ViewBounds,
+    /// 进行中的边界过渡动画
+    #[serde(skip)]
+    animation: Option<BoundsAnimation>,
+}
+
+/// 视口边界过渡动画：由调用方每帧调用 [`Viewport::update`] 推进
+#[derive(Debug, Clone, PartialEq)]
+struct BoundsAnimation {
+    from: ViewBounds,
+    to: ViewBounds,
+    elapsed: Duration,
+    duration: Duration,
+    delay: Duration,
+    easing: EasingFunction,
+    speed: f32,
+}
+
+impl BoundsAnimation {
+    /// 当前缓动后的进度，延迟期内为 0
+    fn progress(&self) -> f32 {
+        let active = self.elapsed.saturating_sub(self.delay).as_secs_f32() * self.speed.max(0.0);
+        let duration = self.duration.as_secs_f32();
+        if duration <= 0.0 {
+            1.0
+        } else {
+            (active / duration).min(1.0)
+        }
+    }
+
+    /// 按进度插值的边界
+    fn bounds(&self, progress: f32) -> ViewBounds {
+        let t = self.easing.apply(progress) as f64;
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        ViewBounds::new(
+            lerp(self.from.min_x, self.to.min_x),
+            lerp(self.from.max_x, self.to.max_x),
+            lerp(self.from.min_y, self.to.min_y),
+            lerp(self.from.max_y, self.to.max_y),
+        )
+    }
 }
 
 /// 世界坐标系的可见区域边界
@@ -38,6 +80,7 @@ impl Viewport {
             inverse_transform,
             size,
             bounds,
+            animation: None,
         }
     }
 
@@ -88,6 +131,8 @@ impl Viewport {
             return Err(format!("缩放因子必须为正数，当前值: {}", zoom_factor).into());
         }
 
+        self.animation = None;
+
         // 将中心点转换为世界坐标
         let world_center = self.screen_to_world(center);
 
@@ -111,6 +156,8 @@ impl Viewport {
 
     /// 平移视口
     pub fn pan(&mut self, delta_screen: Vector2<f64>) -> Result<()> {
+        self.animation = None;
+
         // 将屏幕坐标的偏移转换为世界坐标的偏移
         let origin_world = self.screen_to_world(LogicalPosition { x: 0.0, y: 0.0 });
         let delta_world_pos = self.screen_to_world(LogicalPosition {
@@ -134,16 +181,104 @@ impl Viewport {
 
     /// 适应指定的世界坐标边界
     pub fn fit_bounds(&mut self, bounds: ViewBounds) {
+        self.animation = None;
         self.bounds = bounds;
         self.update_transforms();
     }
 
+    /// 缩放到指定的世界坐标区域
+    ///
+    /// `preserve_aspect` 为 true 时保持当前视图的宽高比，在区域较窄的方向上向两侧扩展，
+    /// 保证选中区域完整可见
+    pub fn zoom_to_bounds(&mut self, bounds: ViewBounds, preserve_aspect: bool) -> Result<()> {
+        let width = bounds.width().abs();
+        let height = bounds.height().abs();
+        if width <= f64::EPSILON || height <= f64::EPSILON {
+            return Err(format!("缩放区域无效: {}x{}", width, height).into());
+        }
+
+        let bounds = ViewBounds::new(
+            bounds.min_x.min(bounds.max_x),
+            bounds.min_x.max(bounds.max_x),
+            bounds.min_y.min(bounds.max_y),
+            bounds.min_y.max(bounds.max_y),
+        );
+
+        self.animation = None;
+        self.bounds = if preserve_aspect {
+            let aspect = self.bounds.width() / self.bounds.height();
+            let (fit_width, fit_height) = if width / height > aspect {
+                (width, width / aspect)
+            } else {
+                (height * aspect, height)
+            };
+            ViewBounds::from_center_and_size(bounds.center(), fit_width, fit_height)
+        } else {
+            bounds
+        };
+
+        self.update_transforms();
+        Ok(())
+    }
+
     /// 重置为默认视图
     pub fn reset(&mut self, bounds: ViewBounds) {
+        self.animation = None;
         self.bounds = bounds;
         self.update_transforms();
     }
 
+    /// 以动画方式过渡到目标边界，使用配置中的时长、延迟、缓动与速度（循环与方向不适用）
+    ///
+    /// 动画由调用方每帧调用 [`update`](Self::update) 推进。过渡期间用户主动改变视图
+    /// （平移、缩放、适应或重置边界）会取消动画，并从当前的中间边界开始应用该操作
+    pub fn animate_to(&mut self, target: ViewBounds, config: AnimationConfig) {
+        let animation = BoundsAnimation {
+            from: self.bounds.clone(),
+            to: target,
+            elapsed: Duration::ZERO,
+            duration: config.duration,
+            delay: config.delay,
+            easing: config.easing,
+            speed: config.speed,
+        };
+        if animation.progress() >= 1.0 {
+            self.reset(animation.to);
+        } else {
+            self.animation = Some(animation);
+        }
+    }
+
+    /// 推进边界动画，返回视图是否发生了变化
+    pub fn update(&mut self, delta: Duration) -> bool {
+        let Some(animation) = self.animation.as_mut() else {
+            return false;
+        };
+        animation.elapsed += delta;
+        let progress = animation.progress();
+        self.bounds = animation.bounds(progress);
+        if progress >= 1.0 {
+            self.animation = None;
+        }
+        self.update_transforms();
+        true
+    }
+
+    /// 是否有进行中的边界动画
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    /// 进行中的动画的目标边界
+    pub fn animation_target(&self) -> Option<&ViewBounds> {
+        self.animation.as_ref().map(|animation| &animation.to)
+    }
+
+    /// 取消边界动画，停留在当前的中间边界
+    pub fn cancel_animation(&mut self) {
+        self.animation = None;
+    }
+
     /// 调整视口大小
     pub fn resize(&mut self, width: u32, height: u32) {
         self.size = Vector2::new(width, height);
@@ -344,6 +479,30 @@ mod tests {
         assert_ne!(viewport.bounds(), &original_bounds);
     }
 
+    #[test]
+    fn test_zoom_to_bounds() {
+        let mut viewport = Viewport::new(800, 400, ViewBounds::new(0.0, 20.0, 0.0, 10.0));
+
+        viewport
+            .zoom_to_bounds(ViewBounds::new(4.0, 2.0, 1.0, 5.0), false)
+            .unwrap();
+        assert_eq!(viewport.bounds(), &ViewBounds::new(2.0, 4.0, 1.0, 5.0));
+
+        // 保持 2:1 的宽高比，高度方向不变，宽度扩展
+        viewport.reset(ViewBounds::new(0.0, 20.0, 0.0, 10.0));
+        viewport
+            .zoom_to_bounds(ViewBounds::new(2.0, 4.0, 1.0, 5.0), true)
+            .unwrap();
+        assert!((viewport.bounds().width() - 8.0).abs() < 1e-10);
+        assert!((viewport.bounds().height() - 4.0).abs() < 1e-10);
+        assert_eq!(viewport.bounds().center(), (3.0, 3.0));
+
+        // 退化区域应返回错误
+        assert!(viewport
+            .zoom_to_bounds(ViewBounds::new(1.0, 1.0, 0.0, 5.0), false)
+            .is_err());
+    }
+
     #[test]
     fn test_view_bounds() {
         let mut bounds = ViewBounds::new(0.0, 10.0, 0.0, 10.0);
@@ -387,6 +546,42 @@ mod tests {
         assert!((back_to_world.y - 5.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_animate_to() {
+        let mut viewport = Viewport::new(800, 600, ViewBounds::new(0.0, 10.0, 0.0, 10.0));
+        let target = ViewBounds::new(10.0, 30.0, 0.0, 20.0);
+        let config =
+            AnimationConfig::new(Duration::from_millis(100)).with_easing(EasingFunction::Linear);
+
+        viewport.animate_to(target.clone(), config.clone());
+        assert!(viewport.is_animating());
+        assert_eq!(viewport.animation_target(), Some(&target));
+
+        assert!(viewport.update(Duration::from_millis(50)));
+        assert!((viewport.bounds().min_x - 5.0).abs() < 1e-4);
+        assert!((viewport.bounds().max_x - 20.0).abs() < 1e-4);
+
+        // 超过时长后停在目标边界
+        assert!(viewport.update(Duration::from_millis(80)));
+        assert!(!viewport.is_animating());
+        assert_eq!(viewport.bounds(), &target);
+        assert!(!viewport.update(Duration::from_millis(16)));
+
+        // 过渡期间平移会取消动画，从中间边界开始平移
+        viewport.animate_to(ViewBounds::new(0.0, 10.0, 0.0, 10.0), config);
+        viewport.update(Duration::from_millis(50));
+        let midway = viewport.bounds().clone();
+        viewport.pan(Vector2::new(0.0, 0.0)).unwrap();
+        assert!(!viewport.is_animating());
+        assert!(!viewport.update(Duration::from_millis(50)));
+        assert_eq!(viewport.bounds(), &midway);
+
+        // 时长为 0 时立即到达
+        viewport.animate_to(target.clone(), AnimationConfig::new(Duration::ZERO));
+        assert!(!viewport.is_animating());
+        assert_eq!(viewport.bounds(), &target);
+    }
+
     #[test]
     fn test_bounds_operations() {
         let bounds = ViewBounds::from_center_and_size((5.0, 5.0), 10.0, 8.0);
@@ -1,6 +1,8 @@
 use nalgebra::Point2;
 use vizuara_core::{Color, LinearScale, Primitive, Scale};
 
+use crate::ScaledPlot;
+
 /// 面积图数据点
 #[derive(Debug, Clone)]
 pub struct AreaDataPoint {
@@ -12,6 +14,11 @@ impl AreaDataPoint {
     pub fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
+
+    /// 坐标是否有效；含 NaN 或无穷的点视为缺失数据
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
 }
 
 impl From<(f32, f32)> for AreaDataPoint {
@@ -254,6 +261,15 @@ impl AreaChart {
 
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: super::PlotArea) -> Vec<Primitive> {
+        self.generate_scaled(None, None, plot_area)
+    }
+
+    fn generate_scaled(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: super::PlotArea,
+    ) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
         if self.series.is_empty() {
@@ -262,8 +278,8 @@ impl AreaChart {
 
         let default_x_scale = LinearScale::new(0.0, 1.0);
         let default_y_scale = LinearScale::new(0.0, 1.0);
-        let x_scale = self.x_scale.as_ref().unwrap_or(&default_x_scale);
-        let y_scale = self.y_scale.as_ref().unwrap_or(&default_y_scale);
+        let x_scale = x.unwrap_or(self.x_scale.as_ref().unwrap_or(&default_x_scale));
+        let y_scale = y.unwrap_or(self.y_scale.as_ref().unwrap_or(&default_y_scale));
 
         match self.style.fill_mode {
             AreaFillMode::Stacked => {
@@ -281,8 +297,8 @@ impl AreaChart {
         &self,
         primitives: &mut Vec<Primitive>,
         plot_area: super::PlotArea,
-        x_scale: &LinearScale,
-        y_scale: &LinearScale,
+        x_scale: &dyn Scale,
+        y_scale: &dyn Scale,
     ) {
         for (i, series) in self.series.iter().enumerate() {
             if series.data.is_empty() {
@@ -303,77 +319,54 @@ impl AreaChart {
             };
 
             // 确定基线Y坐标
+            let (top, bottom) = (plot_area.y, plot_area.y + plot_area.height);
             let baseline_y = match self.style.fill_mode {
-                AreaFillMode::ToZero => {
-                    let zero_norm = y_scale.normalize(0.0);
-                    plot_area.y + plot_area.height - zero_norm * plot_area.height
-                }
+                AreaFillMode::ToZero => y_scale.to_pixel_inverted(0.0, top, bottom),
                 AreaFillMode::ToBaseline(baseline) => {
-                    let baseline_norm = y_scale.normalize(baseline);
-                    plot_area.y + plot_area.height - baseline_norm * plot_area.height
+                    y_scale.to_pixel_inverted(baseline, top, bottom)
                 }
-                _ => plot_area.y + plot_area.height,
+                _ => bottom,
             };
 
-            // 生成面积多边形
-            let mut area_points = Vec::new();
-
-            // 添加数据点
-            for point in &series.data {
-                let x_norm = x_scale.normalize(point.x);
-                let y_norm = y_scale.normalize(point.y);
-                let screen_x = plot_area.x + x_norm * plot_area.width;
-                let screen_y = plot_area.y + plot_area.height - y_norm * plot_area.height;
-                area_points.push(Point2::new(screen_x, screen_y));
-            }
-
-            // 添加基线点（从右到左）
-            if !area_points.is_empty() {
-                let last_point = *area_points.last().unwrap();
-                let first_point = *area_points.first().unwrap();
-                area_points.push(Point2::new(last_point.x, baseline_y));
-                area_points.push(Point2::new(first_point.x, baseline_y));
-            }
-
-            // 创建面积多边形
-            if area_points.len() >= 3 {
-                primitives.push(Primitive::Polygon {
-                    points: area_points.clone(),
-                    fill: fill_color,
-                    stroke: None,
-                });
-            }
-
-            // 生成边界线
-            let mut line_points = Vec::new();
-            for point in &series.data {
-                let x_norm = x_scale.normalize(point.x);
-                let y_norm = y_scale.normalize(point.y);
-                let screen_x = plot_area.x + x_norm * plot_area.width;
-                let screen_y = plot_area.y + plot_area.height - y_norm * plot_area.height;
-                line_points.push(Point2::new(screen_x, screen_y));
-            }
-
-            if line_points.len() >= 2 {
-                primitives.push(Primitive::Polyline {
-                    points: line_points,
-                    color: line_color,
-                    width: series.line_width,
-                });
-            }
-
-            // 添加数据点
-            if self.style.show_points {
-                for point in &series.data {
-                    let x_norm = x_scale.normalize(point.x);
-                    let y_norm = y_scale.normalize(point.y);
-                    let screen_x = plot_area.x + x_norm * plot_area.width;
-                    let screen_y = plot_area.y + plot_area.height - y_norm * plot_area.height;
-
-                    primitives.push(Primitive::Circle {
-                        center: Point2::new(screen_x, screen_y),
-                        radius: self.style.point_size,
+            // 缺失数据（NaN）处断开，每段连续数据单独生成面积和边界线
+            for run in series.data.split(|point| !point.is_finite()) {
+                let line_points: Vec<Point2<f32>> = run
+                    .iter()
+                    .map(|point| {
+                        let screen_x =
+                            x_scale.to_pixel(point.x, plot_area.x, plot_area.x + plot_area.width);
+                        let screen_y = y_scale.to_pixel_inverted(point.y, top, bottom);
+                        Point2::new(screen_x, screen_y)
+                    })
+                    .collect();
+
+                if line_points.len() >= 2 {
+                    // 面积多边形：数据点加上基线点（从右到左）
+                    let mut area_points = line_points.clone();
+                    let last_x = line_points[line_points.len() - 1].x;
+                    area_points.push(Point2::new(last_x, baseline_y));
+                    area_points.push(Point2::new(line_points[0].x, baseline_y));
+
+                    primitives.push(Primitive::Polygon {
+                        points: area_points,
+                        fill: fill_color,
+                        stroke: None,
                     });
+                    primitives.push(Primitive::Polyline {
+                        points: line_points.clone(),
+                        color: line_color,
+                        width: series.line_width,
+                    });
+                }
+
+                // 添加数据点
+                if self.style.show_points {
+                    for &center in &line_points {
+                        primitives.push(Primitive::Circle {
+                            center,
+                            radius: self.style.point_size,
+                        });
+                    }
                 }
             }
         }
@@ -383,8 +376,8 @@ impl AreaChart {
         &self,
         primitives: &mut Vec<Primitive>,
         plot_area: super::PlotArea,
-        x_scale: &LinearScale,
-        y_scale: &LinearScale,
+        x_scale: &dyn Scale,
+        y_scale: &dyn Scale,
     ) {
         // 堆叠面积图实现
         // 需要计算每个点的累积值
@@ -395,7 +388,7 @@ impl AreaChart {
         // 获取所有X坐标的并集并排序
         let mut all_x_values = std::collections::BTreeSet::new();
         for series in &self.series {
-            for point in &series.data {
+            for point in series.data.iter().filter(|point| point.x.is_finite()) {
                 all_x_values.insert((point.x * 1000.0).round() as i32);
             }
         }
@@ -421,20 +414,17 @@ impl AreaChart {
             let mut previous_layer_points = Vec::new();
 
             for (i, &x) in sorted_x.iter().enumerate() {
-                // 在当前系列中查找对应的Y值（简单线性插值）
+                // 在当前系列中查找对应的Y值（简单线性插值），缺失数据处该层厚度为零
                 let y_value = self.interpolate_y_value(series, x);
+                let y_value = if y_value.is_finite() { y_value } else { 0.0 };
 
                 let new_cumulative = cumulative_values[i] + y_value;
 
-                let x_norm = x_scale.normalize(x);
-                let current_y_norm = y_scale.normalize(new_cumulative);
-                let previous_y_norm = y_scale.normalize(cumulative_values[i]);
-
-                let screen_x = plot_area.x + x_norm * plot_area.width;
-                let current_screen_y =
-                    plot_area.y + plot_area.height - current_y_norm * plot_area.height;
+                let (top, bottom) = (plot_area.y, plot_area.y + plot_area.height);
+                let screen_x = x_scale.to_pixel(x, plot_area.x, plot_area.x + plot_area.width);
+                let current_screen_y = y_scale.to_pixel_inverted(new_cumulative, top, bottom);
                 let previous_screen_y =
-                    plot_area.y + plot_area.height - previous_y_norm * plot_area.height;
+                    y_scale.to_pixel_inverted(cumulative_values[i], top, bottom);
 
                 current_layer_points.push(Point2::new(screen_x, current_screen_y));
                 previous_layer_points.push(Point2::new(screen_x, previous_screen_y));
@@ -491,6 +481,17 @@ impl Default for AreaChart {
     }
 }
 
+impl ScaledPlot for AreaChart {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: super::PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_scaled(x, y, plot_area)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -534,4 +535,31 @@ mod tests {
         let interpolated = chart.interpolate_y_value(&series, 1.0);
         assert_eq!(interpolated, 10.0);
     }
+
+    #[test]
+    fn test_area_nan_gaps() {
+        let data = [
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (2.0, f32::NAN),
+            (3.0, 3.0),
+            (4.0, 4.0),
+        ];
+        let chart = AreaChart::new().single_series("gap", &data).auto_scale();
+        let primitives = chart.generate_primitives(crate::PlotArea::new(0.0, 0.0, 100.0, 100.0));
+
+        let polygons = primitives
+            .iter()
+            .filter(|p| matches!(p, Primitive::Polygon { .. }))
+            .count();
+        let lines: Vec<usize> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::Polyline { points, .. } => Some(points.len()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(polygons, 2);
+        assert_eq!(lines, [2, 2]);
+    }
 }
@@ -1,8 +1,11 @@
+use crate::scaled::{resolve_scale, ScaledPlot};
+use crate::scatter::point_bounds;
 use nalgebra::Point2;
+use serde::{Deserialize, Serialize};
 use vizuara_core::{Color, LinearScale, Primitive, Scale};
 
 /// 柱状图数据点
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BarData {
     pub category: String,
     pub value: f32,
@@ -30,12 +33,15 @@ impl From<(String, f32)> for BarData {
 }
 
 /// 柱状图样式配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BarStyle {
     pub fill_color: Color,
     pub stroke_color: Color,
     pub stroke_width: f32,
     pub bar_width: f32, // 柱子宽度比例 (0.0-1.0)
+    /// 类别标签旋转角度（度，顺时针为正）
+    #[serde(default)]
+    pub label_rotation: f32,
 }
 
 impl Default for BarStyle {
@@ -45,12 +51,13 @@ impl Default for BarStyle {
             stroke_color: Color::rgb(0.2, 0.2, 0.2),
             stroke_width: 1.0,
             bar_width: 0.8, // 80% 宽度
+            label_rotation: 0.0,
         }
     }
 }
 
 /// 柱状图
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BarPlot {
     data: Vec<BarData>,
     style: BarStyle,
@@ -116,6 +123,13 @@ impl BarPlot {
         self
     }
 
+    /// 设置类别标签旋转角度（度），类别较多或名称较长时常用 -45° 避免重叠；
+    /// 旋转后的标签以靠近柱子的一端对准柱子中心
+    pub fn label_rotation(mut self, degrees: f32) -> Self {
+        self.style.label_rotation = degrees;
+        self
+    }
+
     /// 设置标题
     pub fn title<S: Into<String>>(mut self, title: S) -> Self {
         self.title = Some(title.into());
@@ -146,18 +160,21 @@ impl BarPlot {
 
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: super::PlotArea) -> Vec<Primitive> {
+        self.generate_scaled(None, plot_area)
+    }
+
+    /// X 轴为类别槽位，只使用外部的 Y 比例尺
+    fn generate_scaled(&self, y: Option<&dyn Scale>, plot_area: super::PlotArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
         if self.data.is_empty() {
             return primitives;
         }
 
-        let y_scale = if let Some(ref scale) = self.y_scale {
-            scale.clone()
-        } else {
-            let values: Vec<f32> = self.data.iter().map(|d| d.value).collect();
-            LinearScale::from_data(&values)
-        };
+        let mut fitted = None;
+        let y_scale = resolve_scale(y, self.y_scale.as_ref(), &mut fitted, || {
+            self.data.iter().map(|d| d.value).collect()
+        });
 
         let bar_count = self.data.len() as f32;
         let bar_spacing = plot_area.width / bar_count;
@@ -165,8 +182,9 @@ impl BarPlot {
         let bar_gap = (bar_spacing - bar_width) / 2.0;
 
         // 计算基线位置（Y=0 的位置）
-        let baseline_y = if y_scale.normalize(0.0) <= 1.0 && y_scale.normalize(0.0) >= 0.0 {
-            plot_area.y + plot_area.height - y_scale.normalize(0.0) * plot_area.height
+        let (top, bottom) = (plot_area.y, plot_area.y + plot_area.height);
+        let baseline_y = if (0.0..=1.0).contains(&y_scale.normalize(0.0)) {
+            y_scale.to_pixel_inverted(0.0, top, bottom)
         } else {
             plot_area.y + plot_area.height // 如果 0 不在范围内，使用底部
         };
@@ -174,11 +192,8 @@ impl BarPlot {
         // 生成每个柱子
         for (i, bar_data) in self.data.iter().enumerate() {
             let x = plot_area.x + bar_gap + i as f32 * bar_spacing;
-            let value_normalized = y_scale.normalize(bar_data.value);
-            let bar_height = value_normalized * plot_area.height;
-
             // 柱子顶部的 Y 坐标
-            let bar_top_y = plot_area.y + plot_area.height - bar_height;
+            let bar_top_y = y_scale.to_pixel_inverted(bar_data.value, top, bottom);
 
             // 创建柱子矩形（带样式）
             primitives.push(Primitive::RectangleStyled {
@@ -206,16 +221,39 @@ impl BarPlot {
                 } else {
                     vizuara_core::VerticalAlign::Top
                 },
+                rotation: 0.0,
             });
 
-            // 添加类别标签（在 X 轴下方）
+            // 添加类别标签（在 X 轴下方）；旋转时逆时针右对齐、顺时针左对齐，
+            // 使文本从柱子中心向下展开
+            let rotation = self.style.label_rotation;
+            let (h_align, v_align, offset) = if rotation == 0.0 {
+                (
+                    vizuara_core::HorizontalAlign::Center,
+                    vizuara_core::VerticalAlign::Top,
+                    20.0,
+                )
+            } else if rotation < 0.0 {
+                (
+                    vizuara_core::HorizontalAlign::Right,
+                    vizuara_core::VerticalAlign::Middle,
+                    8.0,
+                )
+            } else {
+                (
+                    vizuara_core::HorizontalAlign::Left,
+                    vizuara_core::VerticalAlign::Middle,
+                    8.0,
+                )
+            };
             primitives.push(Primitive::Text {
-                position: Point2::new(x + bar_width / 2.0, plot_area.y + plot_area.height + 20.0),
+                position: Point2::new(x + bar_width / 2.0, plot_area.y + plot_area.height + offset),
                 content: bar_data.category.clone(),
                 size: 10.0,
                 color: Color::rgb(0.1, 0.1, 0.1),
-                h_align: vizuara_core::HorizontalAlign::Center,
-                v_align: vizuara_core::VerticalAlign::Top,
+                h_align,
+                v_align,
+                rotation,
             });
         }
 
@@ -230,17 +268,16 @@ impl BarPlot {
         primitives
     }
 
-    /// 获取数据边界
-    pub fn data_bounds(&self) -> Option<(f32, f32)> {
-        if self.data.is_empty() {
-            return None;
-        }
-
-        let values: Vec<f32> = self.data.iter().map(|d| d.value).collect();
-        let min_val = values.iter().fold(f32::INFINITY, |a, &b| a.min(b));
-        let max_val = values.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
-
-        Some((min_val, max_val))
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)：X 为类别槽位 [0, 类别数]，
+    /// 第 i 个柱子居中于 i + 0.5；Y 包含柱子的基线 0。没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let (_, _, min_val, max_val) = point_bounds(self.data.iter().map(|d| (0.0, d.value)))?;
+        Some((
+            0.0,
+            self.data.len() as f32,
+            min_val.min(0.0),
+            max_val.max(0.0),
+        ))
     }
 
     /// 获取数据点数量
@@ -260,6 +297,21 @@ impl Default for BarPlot {
     }
 }
 
+impl ScaledPlot for BarPlot {
+    fn generate_with_scales(
+        &self,
+        _x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: super::PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_scaled(y, plot_area)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        BarPlot::data_bounds(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,9 +347,9 @@ mod tests {
         let data = vec![("A", 10.0), ("B", 20.0), ("C", 5.0)];
         let plot = BarPlot::new().data(&data);
 
-        let bounds = plot.data_bounds().unwrap();
-        assert_eq!(bounds.0, 5.0); // min
-        assert_eq!(bounds.1, 20.0); // max
+        // 全为正值时 Y 范围从基线 0 开始
+        assert_eq!(plot.data_bounds(), Some((0.0, 3.0, 0.0, 20.0)));
+        assert!(BarPlot::new().data_bounds().is_none());
     }
 
     #[test]
@@ -306,8 +358,8 @@ mod tests {
         let plot = BarPlot::new().data(&data).auto_scale();
 
         let bounds = plot.data_bounds().unwrap();
-        assert_eq!(bounds.0, -5.0);
-        assert_eq!(bounds.1, 10.0);
+        assert_eq!(bounds.2, -5.0);
+        assert_eq!(bounds.3, 10.0);
     }
 
     #[test]
@@ -336,4 +388,26 @@ mod tests {
         assert_eq!(plot.style.bar_width, 0.6);
         assert_eq!(plot.title, Some("Test Chart".to_string()));
     }
+
+    #[test]
+    fn test_bar_plot_rotated_category_labels() {
+        let plot = BarPlot::new()
+            .data(&[("Long category", 3.0)])
+            .label_rotation(-45.0);
+        let area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+
+        let label = plot
+            .generate_primitives(area)
+            .into_iter()
+            .find_map(|p| match p {
+                Primitive::Text {
+                    content,
+                    h_align,
+                    rotation,
+                    ..
+                } if content == "Long category" => Some((h_align, rotation)),
+                _ => None,
+            });
+        assert_eq!(label, Some((vizuara_core::HorizontalAlign::Right, -45.0)));
+    }
 }
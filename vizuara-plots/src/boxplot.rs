@@ -388,6 +388,7 @@ impl BoxPlot {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: vizuara_core::HorizontalAlign::Center,
                 v_align: vizuara_core::VerticalAlign::Top,
+                rotation: 0.0,
             });
         }
 
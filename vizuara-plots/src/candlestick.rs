@@ -0,0 +1,271 @@
+//! K 线图（蜡烛图）实现
+//!
+//! 用于展示金融时间序列的开盘、最高、最低、收盘价
+
+use crate::PlotArea;
+use nalgebra::Point2;
+use vizuara_core::{Color, LinearScale, Primitive, Scale};
+
+/// 单根 K 线数据
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    pub open: f32,
+    pub high: f32,
+    pub low: f32,
+    pub close: f32,
+}
+
+impl Candle {
+    pub fn new(open: f32, high: f32, low: f32, close: f32) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// 是否上涨（收盘价不低于开盘价）
+    pub fn is_bullish(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Candle {
+    fn from((open, high, low, close): (f32, f32, f32, f32)) -> Self {
+        Self::new(open, high, low, close)
+    }
+}
+
+/// K 线图样式
+#[derive(Debug, Clone)]
+pub struct CandlestickStyle {
+    pub up_color: Color,
+    pub down_color: Color,
+    pub body_width: f32, // 实体宽度占类别带宽的比例 (0.0-1.0)
+    pub stroke_width: f32,
+}
+
+impl Default for CandlestickStyle {
+    fn default() -> Self {
+        Self {
+            up_color: Color::rgb(0.15, 0.65, 0.3),
+            down_color: Color::rgb(0.85, 0.2, 0.2),
+            body_width: 0.7,
+            stroke_width: 1.0,
+        }
+    }
+}
+
+/// K 线图
+#[derive(Debug, Clone)]
+pub struct CandlestickPlot {
+    data: Vec<Candle>,
+    style: CandlestickStyle,
+    y_scale: Option<LinearScale>,
+    title: Option<String>,
+}
+
+impl CandlestickPlot {
+    /// 创建新的 K 线图
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            style: CandlestickStyle::default(),
+            y_scale: None,
+            title: None,
+        }
+    }
+
+    /// 设置数据
+    pub fn data<T: Into<Candle> + Clone>(mut self, data: &[T]) -> Self {
+        self.data = data.iter().cloned().map(|d| d.into()).collect();
+        self
+    }
+
+    /// 从分离的开盘/最高/最低/收盘数组设置数据
+    pub fn ohlc(mut self, open: &[f32], high: &[f32], low: &[f32], close: &[f32]) -> Self {
+        assert!(
+            open.len() == high.len() && open.len() == low.len() && open.len() == close.len(),
+            "Open, high, low and close must have the same length"
+        );
+
+        self.data = (0..open.len())
+            .map(|i| Candle::new(open[i], high[i], low[i], close[i]))
+            .collect();
+        self
+    }
+
+    /// 设置样式
+    pub fn style(mut self, style: CandlestickStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 设置上涨/下跌颜色
+    pub fn colors(mut self, up: Color, down: Color) -> Self {
+        self.style.up_color = up;
+        self.style.down_color = down;
+        self
+    }
+
+    /// 设置实体宽度比例
+    pub fn body_width(mut self, width: f32) -> Self {
+        self.style.body_width = width.clamp(0.1, 1.0);
+        self
+    }
+
+    /// 设置标题
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 设置 Y 轴比例尺
+    pub fn y_scale(mut self, scale: LinearScale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// 根据最高/最低价自动计算 Y 轴比例尺
+    pub fn auto_scale(mut self) -> Self {
+        if let Some((min, max)) = self.price_range() {
+            self.y_scale = Some(LinearScale::new(min, max));
+        }
+        self
+    }
+
+    /// 获取价格范围（最低价, 最高价）
+    pub fn price_range(&self) -> Option<(f32, f32)> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let min = self.data.iter().fold(f32::INFINITY, |a, c| a.min(c.low));
+        let max = self
+            .data
+            .iter()
+            .fold(f32::NEG_INFINITY, |a, c| a.max(c.high));
+        Some((min, max))
+    }
+
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)：X 为 K 线槽位 [0, K 线数]，
+    /// Y 为价格范围。没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let (min, max) = self.price_range()?;
+        Some((0.0, self.data.len() as f32, min, max))
+    }
+
+    /// 获取 K 线数量
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 生成渲染图元
+    pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
+
+        let y_scale = match (&self.y_scale, self.price_range()) {
+            (Some(scale), _) => scale.clone(),
+            (None, Some((min, max))) => LinearScale::new(min, max),
+            (None, None) => return primitives,
+        };
+
+        let to_screen_y = |value: f32| {
+            y_scale.to_pixel_inverted(value, plot_area.y, plot_area.y + plot_area.height)
+        };
+
+        // 与柱状图相同的类别带宽计算
+        let band = plot_area.width / self.data.len() as f32;
+        let body_width = band * self.style.body_width;
+
+        for (i, candle) in self.data.iter().enumerate() {
+            let center_x = plot_area.x + (i as f32 + 0.5) * band;
+            let color = if candle.is_bullish() {
+                self.style.up_color
+            } else {
+                self.style.down_color
+            };
+
+            // 影线（最高价到最低价）
+            primitives.push(Primitive::Line {
+                start: Point2::new(center_x, to_screen_y(candle.high)),
+                end: Point2::new(center_x, to_screen_y(candle.low)),
+            });
+
+            let open_y = to_screen_y(candle.open);
+            let close_y = to_screen_y(candle.close);
+            let left = center_x - body_width / 2.0;
+            let right = center_x + body_width / 2.0;
+
+            if candle.open == candle.close {
+                // 十字星：开盘价等于收盘价时画一条水平线
+                primitives.push(Primitive::Polyline {
+                    points: vec![Point2::new(left, open_y), Point2::new(right, open_y)],
+                    color,
+                    width: self.style.stroke_width,
+                });
+            } else {
+                primitives.push(Primitive::RectangleStyled {
+                    min: Point2::new(left, open_y.min(close_y)),
+                    max: Point2::new(right, open_y.max(close_y)),
+                    fill: color,
+                    stroke: Some((color, self.style.stroke_width)),
+                });
+            }
+        }
+
+        primitives
+    }
+}
+
+impl Default for CandlestickPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candlestick_creation() {
+        let plot =
+            CandlestickPlot::new().ohlc(&[10.0, 11.0], &[12.0, 13.0], &[9.0, 10.0], &[11.0, 10.5]);
+        assert_eq!(plot.data_len(), 2);
+        assert_eq!(plot.price_range(), Some((9.0, 13.0)));
+    }
+
+    #[test]
+    fn test_candlestick_colors() {
+        let up = Color::rgb(0.0, 1.0, 0.0);
+        let down = Color::rgb(1.0, 0.0, 0.0);
+        let plot = CandlestickPlot::new()
+            .data(&[(10.0, 12.0, 9.0, 11.0), (11.0, 13.0, 10.0, 10.5)])
+            .colors(up, down);
+
+        let primitives = plot.generate_primitives(PlotArea::new(0.0, 0.0, 200.0, 100.0));
+        let fills: Vec<Color> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::RectangleStyled { fill, .. } => Some(*fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills, vec![up, down]);
+    }
+
+    #[test]
+    fn test_candlestick_doji() {
+        let plot = CandlestickPlot::new().data(&[(10.0, 12.0, 8.0, 10.0)]);
+        let primitives = plot.generate_primitives(PlotArea::new(0.0, 0.0, 100.0, 100.0));
+
+        assert_eq!(primitives.len(), 2);
+        assert!(matches!(primitives[0], Primitive::Line { .. }));
+        match &primitives[1] {
+            Primitive::Polyline { points, .. } => assert_eq!(points[0].y, points[1].y),
+            other => panic!("expected doji line, got {:?}", other),
+        }
+    }
+}
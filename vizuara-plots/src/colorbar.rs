@@ -0,0 +1,240 @@
+//! 颜色条
+//!
+//! 以竖直渐变条说明颜色映射的含义；刻度按与热力图相同的数值变换排布，
+//! 对数或对称对数映射下刻度取 10 的幂
+
+use crate::{ColorMap, ColorScaleKind, PlotArea};
+use nalgebra::Point2;
+use vizuara_core::{
+    Color, HorizontalAlign, LinearScale, Primitive, Scale, TickFormatter, VerticalAlign,
+};
+
+/// 渐变条的分段数
+const GRADIENT_STEPS: usize = 64;
+/// 刻度线长度（像素）
+const TICK_LENGTH: f32 = 4.0;
+/// 刻度标签字号
+const LABEL_SIZE: f32 = 10.0;
+
+/// 颜色条
+#[derive(Debug, Clone)]
+pub struct ColorBar {
+    color_map: ColorMap,
+    range: (f32, f32),
+    color_scale: ColorScaleKind,
+    tick_count: usize,
+    tick_format: Option<TickFormatter>,
+    title: Option<String>,
+}
+
+impl ColorBar {
+    /// 创建颜色条，`min`/`max` 为颜色映射的数值范围
+    pub fn new(color_map: ColorMap, min: f32, max: f32) -> Self {
+        Self {
+            color_map,
+            range: (min, max),
+            color_scale: ColorScaleKind::Linear,
+            tick_count: 5,
+            tick_format: None,
+            title: None,
+        }
+    }
+
+    /// 设置数值变换，应与被说明的图表一致
+    pub fn color_scale(mut self, kind: ColorScaleKind) -> Self {
+        self.color_scale = kind;
+        self
+    }
+
+    /// 设置目标刻度数量
+    pub fn tick_count(mut self, count: usize) -> Self {
+        self.tick_count = count.max(2);
+        self
+    }
+
+    /// 设置刻度标签格式
+    pub fn tick_format(mut self, formatter: TickFormatter) -> Self {
+        self.tick_format = Some(formatter);
+        self
+    }
+
+    /// 设置标题
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 刻度值（从小到大）
+    pub fn ticks(&self) -> Vec<f32> {
+        let (min, max) = self.range;
+        let ticks = match self.color_scale {
+            ColorScaleKind::Linear => Vec::new(),
+            ColorScaleKind::Log => self.decade_ticks(min.max(f32::MIN_POSITIVE), max),
+            ColorScaleKind::SymLog { linear_threshold } => {
+                let mut ticks = Vec::new();
+                if min < 0.0 {
+                    ticks.extend(
+                        self.decade_ticks(linear_threshold, -min)
+                            .into_iter()
+                            .rev()
+                            .map(|v| -v),
+                    );
+                }
+                if min <= 0.0 && max >= 0.0 {
+                    ticks.push(0.0);
+                }
+                if max > 0.0 {
+                    ticks.extend(self.decade_ticks(linear_threshold, max));
+                }
+                ticks
+            }
+        };
+
+        if ticks.len() >= 2 {
+            ticks
+        } else {
+            LinearScale::new(min, max).ticks(self.tick_count)
+        }
+    }
+
+    /// 刻度值对应的标准化位置 [0, 1]（0 为底端）
+    pub fn tick_position(&self, value: f32) -> f32 {
+        self.color_scale.normalize(value, self.range)
+    }
+
+    /// 在 plot_area 中生成图元：渐变条占满区域，刻度与标签位于右侧
+    pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
+        let bottom = plot_area.y + plot_area.height;
+        let step_height = plot_area.height / GRADIENT_STEPS as f32;
+
+        // 渐变按变换后的位置均匀取色，与热力图中的颜色一致
+        for i in 0..GRADIENT_STEPS {
+            let t = (i as f32 + 0.5) / GRADIENT_STEPS as f32;
+            let y = bottom - (i + 1) as f32 * step_height;
+            primitives.push(Primitive::RectangleStyled {
+                min: Point2::new(plot_area.x, y),
+                max: Point2::new(plot_area.x + plot_area.width, y + step_height),
+                fill: self.color_map.get_color(t),
+                stroke: None,
+            });
+        }
+        primitives.push(Primitive::RectangleStyled {
+            min: Point2::new(plot_area.x, plot_area.y),
+            max: Point2::new(plot_area.x + plot_area.width, bottom),
+            fill: Color::TRANSPARENT,
+            stroke: Some((Color::rgb(0.4, 0.4, 0.4), 1.0)),
+        });
+
+        let right = plot_area.x + plot_area.width;
+        for value in self.ticks() {
+            let t = self.tick_position(value);
+            if !(-1e-4..=1.0 + 1e-4).contains(&t) {
+                continue;
+            }
+            let y = bottom - t.clamp(0.0, 1.0) * plot_area.height;
+            primitives.push(Primitive::Line {
+                start: Point2::new(right, y),
+                end: Point2::new(right + TICK_LENGTH, y),
+            });
+            primitives.push(Primitive::Text {
+                position: Point2::new(right + TICK_LENGTH + 2.0, y),
+                content: self.format_tick(value),
+                size: LABEL_SIZE,
+                color: Color::rgb(0.2, 0.2, 0.2),
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Middle,
+                rotation: 0.0,
+            });
+        }
+
+        if let Some(ref title) = self.title {
+            primitives.push(Primitive::Text {
+                position: Point2::new(plot_area.x + plot_area.width / 2.0, plot_area.y - 6.0),
+                content: title.clone(),
+                size: LABEL_SIZE + 2.0,
+                color: Color::rgb(0.2, 0.2, 0.2),
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
+            });
+        }
+
+        primitives
+    }
+
+    /// [lo, hi] 内 10 的整数次幂；数量过多时按间隔抽取
+    fn decade_ticks(&self, lo: f32, hi: f32) -> Vec<f32> {
+        if lo <= 0.0 || hi < lo {
+            return Vec::new();
+        }
+        let first = (lo.log10() - 1e-4).ceil() as i32;
+        let last = (hi.log10() + 1e-4).floor() as i32;
+        if last < first {
+            return Vec::new();
+        }
+
+        let decades = (last - first + 1) as usize;
+        let stride = (decades - 1) / self.tick_count.max(1) + 1;
+        (first..=last)
+            .step_by(stride)
+            .map(|k| 10f32.powi(k))
+            .collect()
+    }
+
+    fn format_tick(&self, value: f32) -> String {
+        if let Some(ref formatter) = self.tick_format {
+            return formatter.format(value);
+        }
+        let magnitude = value.abs();
+        if magnitude != 0.0 && !(1e-2..1e4).contains(&magnitude) {
+            format!("{:.0e}", value)
+        } else {
+            let text = format!("{:.2}", value);
+            text.trim_end_matches('0').trim_end_matches('.').to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Heatmap;
+
+    #[test]
+    fn test_color_bar_ticks_follow_color_scale() {
+        let heatmap = Heatmap::new()
+            .data(&[vec![1.0, 10.0, 100.0, 1000.0]])
+            .color_scale(ColorScaleKind::Log);
+        let bar = heatmap.color_bar();
+        assert_eq!(bar.ticks(), [1.0, 10.0, 100.0, 1000.0]);
+
+        // 对数刻度在颜色条上等距分布
+        let area = PlotArea::new(0.0, 0.0, 20.0, 300.0);
+        let tick_ys: Vec<f32> = bar
+            .generate_primitives(area)
+            .into_iter()
+            .filter_map(|p| match p {
+                Primitive::Line { start, .. } => Some(start.y),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tick_ys.len(), 4);
+        for (y, expected) in tick_ys.iter().zip([300.0, 200.0, 100.0, 0.0]) {
+            assert!((y - expected).abs() < 1e-3);
+        }
+
+        let symlog = ColorBar::new(ColorMap::BlueWhiteRed, -100.0, 100.0).color_scale(
+            ColorScaleKind::SymLog {
+                linear_threshold: 1.0,
+            },
+        );
+        assert_eq!(symlog.ticks(), [-100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0]);
+        assert!((symlog.tick_position(0.0) - 0.5).abs() < 1e-6);
+
+        let linear = ColorBar::new(ColorMap::Grayscale, 0.0, 1.0);
+        assert_eq!(linear.ticks(), LinearScale::new(0.0, 1.0).ticks(5));
+        assert_eq!(linear.format_tick(0.25), "0.25");
+        assert_eq!(linear.format_tick(1e5), "1e5");
+    }
+}
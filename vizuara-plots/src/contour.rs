@@ -277,6 +277,7 @@ impl ContourPlot {
                 color: Color::rgb(0.1, 0.1, 0.1),
                 h_align: HorizontalAlign::Center,
                 v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
             });
         }
 
@@ -346,111 +347,16 @@ impl ContourPlot {
 
     /// 提取等高线
     fn extract_contour_lines(&self, grid: &Grid, level: f32) -> Vec<Vec<(f32, f32)>> {
-        let mut lines = Vec::new();
-
         let x_step = (grid.x_max - grid.x_min) / (grid.width - 1) as f32;
         let y_step = (grid.y_max - grid.y_min) / (grid.height - 1) as f32;
-
-        for j in 0..grid.height - 1 {
-            for i in 0..grid.width - 1 {
-                let z00 = grid.values[j][i]; // 左下角
-                let z10 = grid.values[j][i + 1]; // 右下角
-                let z01 = grid.values[j + 1][i]; // 左上角
-                let z11 = grid.values[j + 1][i + 1]; // 右上角
-
-                // 计算 Marching Squares 配置索引
-                let mut config = 0;
-                if z00 > level {
-                    config |= 1;
-                }
-                if z10 > level {
-                    config |= 2;
-                }
-                if z11 > level {
-                    config |= 4;
-                }
-                if z01 > level {
-                    config |= 8;
-                }
-
-                // 网格单元的四个角点坐标
-                let x0 = grid.x_min + i as f32 * x_step;
-                let y0 = grid.y_min + j as f32 * y_step;
-                let x1 = x0 + x_step;
-                let y1 = y0 + y_step;
-
-                // 根据配置生成等高线段
-                if let Some(segments) = self.marching_squares_segments(
-                    config,
-                    level,
-                    [(x0, y0), (x1, y0), (x1, y1), (x0, y1)],
-                    [z00, z10, z11, z01],
-                ) {
-                    for segment in segments {
-                        lines.push(segment);
-                    }
-                }
-            }
-        }
-
-        lines
-    }
-
-    /// Marching Squares 算法核心：根据配置生成线段
-    fn marching_squares_segments(
-        &self,
-        config: u8,
-        level: f32,
-        points: [(f32, f32); 4], // [左下, 右下, 右上, 左上]
-        values: [f32; 4],        // [z0, z1, z2, z3]
-    ) -> Option<Vec<Vec<(f32, f32)>>> {
-        let [p0, p1, p2, p3] = points;
-        let [z0, z1, z2, z3] = values;
-
-        // 计算边的中点（通过线性插值）
-        let lerp = |p1: (f32, f32), p2: (f32, f32), v1: f32, v2: f32| -> (f32, f32) {
-            if (v2 - v1).abs() < 1e-6 {
-                // 避免除零
-                ((p1.0 + p2.0) * 0.5, (p1.1 + p2.1) * 0.5)
-            } else {
-                let t = (level - v1) / (v2 - v1);
-                (p1.0 + t * (p2.0 - p1.0), p1.1 + t * (p2.1 - p1.1))
-            }
-        };
-
-        let bottom = lerp(p0, p1, z0, z1); // 底边中点
-        let right = lerp(p1, p2, z1, z2); // 右边中点
-        let top = lerp(p3, p2, z3, z2); // 顶边中点
-        let left = lerp(p0, p3, z0, z3); // 左边中点
-
-        // 根据 Marching Squares 查找表生成线段
-        match config {
-            0 | 15 => None, // 无等高线或完全在等高线上方
-
-            // 单角情况
-            1 => Some(vec![vec![left, bottom]]),
-            2 => Some(vec![vec![bottom, right]]),
-            4 => Some(vec![vec![right, top]]),
-            8 => Some(vec![vec![top, left]]),
-
-            // 相邻两角情况
-            3 => Some(vec![vec![left, right]]),
-            6 => Some(vec![vec![bottom, top]]),
-            9 => Some(vec![vec![top, bottom]]),
-            12 => Some(vec![vec![right, left]]),
-
-            // 对角情况
-            5 => Some(vec![vec![left, bottom], vec![right, top]]),
-            10 => Some(vec![vec![bottom, left], vec![top, right]]),
-
-            // 三角情况（取反）
-            7 => Some(vec![vec![left, top]]),
-            11 => Some(vec![vec![bottom, top]]),
-            13 => Some(vec![vec![right, left]]),
-            14 => Some(vec![vec![left, bottom]]),
-
-            _ => None,
-        }
+        let x_coords: Vec<f32> = (0..grid.width)
+            .map(|i| grid.x_min + i as f32 * x_step)
+            .collect();
+        let y_coords: Vec<f32> = (0..grid.height)
+            .map(|j| grid.y_min + j as f32 * y_step)
+            .collect();
+
+        contour_segments(&x_coords, &y_coords, &grid.values, level)
     }
 
     /// 获取数据点数量
@@ -465,6 +371,118 @@ impl Default for ContourPlot {
     }
 }
 
+/// 在直线网格上提取某一级别的等高线段（Marching Squares）
+///
+/// `values[j][i]` 为 (`x_coords[i]`, `y_coords[j]`) 处的值；
+/// 含 NaN/无穷角点的单元格被跳过。每条线段为两个端点。
+pub fn contour_segments(
+    x_coords: &[f32],
+    y_coords: &[f32],
+    values: &[Vec<f32>],
+    level: f32,
+) -> Vec<Vec<(f32, f32)>> {
+    let mut lines = Vec::new();
+
+    for j in 0..y_coords.len().saturating_sub(1) {
+        for i in 0..x_coords.len().saturating_sub(1) {
+            let z00 = values[j][i]; // 左下角
+            let z10 = values[j][i + 1]; // 右下角
+            let z01 = values[j + 1][i]; // 左上角
+            let z11 = values[j + 1][i + 1]; // 右上角
+            if ![z00, z10, z11, z01].iter().all(|z| z.is_finite()) {
+                continue;
+            }
+
+            // 计算 Marching Squares 配置索引
+            let mut config = 0;
+            if z00 > level {
+                config |= 1;
+            }
+            if z10 > level {
+                config |= 2;
+            }
+            if z11 > level {
+                config |= 4;
+            }
+            if z01 > level {
+                config |= 8;
+            }
+
+            // 网格单元的四个角点坐标
+            let (x0, x1) = (x_coords[i], x_coords[i + 1]);
+            let (y0, y1) = (y_coords[j], y_coords[j + 1]);
+
+            // 根据配置生成等高线段
+            if let Some(segments) = marching_squares_segments(
+                config,
+                level,
+                [(x0, y0), (x1, y0), (x1, y1), (x0, y1)],
+                [z00, z10, z11, z01],
+            ) {
+                lines.extend(segments);
+            }
+        }
+    }
+
+    lines
+}
+
+/// Marching Squares 算法核心：根据配置生成线段
+fn marching_squares_segments(
+    config: u8,
+    level: f32,
+    points: [(f32, f32); 4], // [左下, 右下, 右上, 左上]
+    values: [f32; 4],        // [z0, z1, z2, z3]
+) -> Option<Vec<Vec<(f32, f32)>>> {
+    let [p0, p1, p2, p3] = points;
+    let [z0, z1, z2, z3] = values;
+
+    // 计算边的中点（通过线性插值）
+    let lerp = |p1: (f32, f32), p2: (f32, f32), v1: f32, v2: f32| -> (f32, f32) {
+        if (v2 - v1).abs() < 1e-6 {
+            // 避免除零
+            ((p1.0 + p2.0) * 0.5, (p1.1 + p2.1) * 0.5)
+        } else {
+            let t = (level - v1) / (v2 - v1);
+            (p1.0 + t * (p2.0 - p1.0), p1.1 + t * (p2.1 - p1.1))
+        }
+    };
+
+    let bottom = lerp(p0, p1, z0, z1); // 底边中点
+    let right = lerp(p1, p2, z1, z2); // 右边中点
+    let top = lerp(p3, p2, z3, z2); // 顶边中点
+    let left = lerp(p0, p3, z0, z3); // 左边中点
+
+    // 根据 Marching Squares 查找表生成线段
+    match config {
+        0 | 15 => None, // 无等高线或完全在等高线上方
+
+        // 单角情况
+        1 => Some(vec![vec![left, bottom]]),
+        2 => Some(vec![vec![bottom, right]]),
+        4 => Some(vec![vec![right, top]]),
+        8 => Some(vec![vec![top, left]]),
+
+        // 相邻两角情况
+        3 => Some(vec![vec![left, right]]),
+        6 => Some(vec![vec![bottom, top]]),
+        9 => Some(vec![vec![top, bottom]]),
+        12 => Some(vec![vec![right, left]]),
+
+        // 对角情况
+        5 => Some(vec![vec![left, bottom], vec![right, top]]),
+        10 => Some(vec![vec![bottom, left], vec![top, right]]),
+
+        // 三角情况（取反）
+        7 => Some(vec![vec![left, top]]),
+        11 => Some(vec![vec![right, top]]),
+        13 => Some(vec![vec![bottom, right]]),
+        14 => Some(vec![vec![left, bottom]]),
+
+        _ => None,
+    }
+}
+
 /// 网格数据结构
 #[derive(Debug, Clone)]
 struct Grid {
@@ -580,15 +598,13 @@ mod tests {
 
     #[test]
     fn test_marching_squares_segments() {
-        let plot = ContourPlot::new();
-
         // 测试一个简单的配置：左下角为1，其他角为0
         let points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
         let values = [1.0, 0.0, 0.0, 0.0];
         let level = 0.5;
         let config = 1; // 只有左下角大于level
 
-        let segments = plot.marching_squares_segments(config, level, points, values);
+        let segments = marching_squares_segments(config, level, points, values);
         assert!(segments.is_some());
 
         let segments = segments.unwrap();
@@ -596,6 +612,21 @@ mod tests {
         assert_eq!(segments[0].len(), 2);
     }
 
+    #[test]
+    fn test_marching_squares_three_corner_cases() {
+        // 三角情况：线段必须横穿与唯一低于 level 的角相邻的两条边
+        let points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let level = 0.5;
+
+        // 11：右上角低于 level，线段连接右边与顶边
+        let segments = marching_squares_segments(11, level, points, [1.0, 1.0, 0.0, 1.0]).unwrap();
+        assert_eq!(segments, vec![vec![(1.0, 0.5), (0.5, 1.0)]]);
+
+        // 13：右下角低于 level，线段连接底边与右边
+        let segments = marching_squares_segments(13, level, points, [1.0, 0.0, 1.0, 1.0]).unwrap();
+        assert_eq!(segments, vec![vec![(0.5, 0.0), (1.0, 0.5)]]);
+    }
+
     #[test]
     fn test_extract_contour_lines() {
         let data = vec![
@@ -628,4 +659,19 @@ mod tests {
         // 应该有等高线生成
         assert!(!lines.is_empty());
     }
+
+    #[test]
+    fn test_contour_segments_non_uniform_with_holes() {
+        // 非均匀 X 坐标：值沿 X 线性增长，0.5 等值线位于 x = 2.5
+        let x_coords = [0.0, 2.0, 3.0];
+        let y_coords = [0.0, 1.0];
+        let values = vec![vec![0.0, 0.0, 1.0], vec![0.0, 0.0, 1.0]];
+        let lines = contour_segments(&x_coords, &y_coords, &values, 0.5);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].iter().all(|&(x, _)| (x - 2.5).abs() < 1e-6));
+
+        // 含 NaN 的单元格不产生线段
+        let values = vec![vec![0.0, 0.0, 1.0], vec![0.0, 0.0, f32::NAN]];
+        assert!(contour_segments(&x_coords, &y_coords, &values, 0.5).is_empty());
+    }
 }
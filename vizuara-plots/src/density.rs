@@ -50,6 +50,7 @@ pub struct DensityPlot {
     style: DensityStyle,
     title: Option<String>,
     x_range: Option<(f32, f32)>,
+    y_range: Option<(f32, f32)>,
     kernel_type: KernelType,
 }
 
@@ -62,6 +63,61 @@ pub enum KernelType {
     Uniform,
 }
 
+impl KernelType {
+    /// 核函数在标准化距离 u 处的取值（积分为 1）
+    pub fn evaluate(self, u: f32) -> f32 {
+        match self {
+            KernelType::Gaussian => (1.0 / (2.0 * PI).sqrt()) * (-0.5 * u * u).exp(),
+            KernelType::Epanechnikov => {
+                if u.abs() <= 1.0 {
+                    0.75 * (1.0 - u * u)
+                } else {
+                    0.0
+                }
+            }
+            KernelType::Triangular => {
+                if u.abs() <= 1.0 {
+                    1.0 - u.abs()
+                } else {
+                    0.0
+                }
+            }
+            KernelType::Uniform => {
+                if u.abs() <= 1.0 {
+                    0.5
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// 核密度估计：样本 `data` 在 x 处的概率密度
+pub fn kde(data: &[f32], x: f32, bandwidth: f32, kernel: KernelType) -> f32 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    let sum = data
+        .iter()
+        .map(|&v| kernel.evaluate((x - v) / bandwidth))
+        .sum::<f32>();
+    sum / (data.len() as f32 * bandwidth)
+}
+
+/// Silverman 经验法则带宽 0.9·σ·n^(-1/5)；样本无离散度时退化为 1.0
+pub fn silverman_bandwidth(data: &[f32]) -> f32 {
+    let n = data.len() as f32;
+    let mean = data.iter().sum::<f32>() / n;
+    let std_dev = (data.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / n).sqrt();
+    let bw = 0.9 * std_dev * n.powf(-0.2);
+    if bw.is_finite() && bw > 0.0 {
+        bw
+    } else {
+        1.0
+    }
+}
+
 impl DensityPlot {
     /// 创建新的密度图
     pub fn new() -> Self {
@@ -70,6 +126,7 @@ impl DensityPlot {
             style: DensityStyle::default(),
             title: None,
             x_range: None,
+            y_range: None,
             kernel_type: KernelType::Gaussian,
         }
     }
@@ -141,6 +198,12 @@ impl DensityPlot {
         self
     }
 
+    /// 设置Y轴（密度）范围，未设置时按密度峰值自动适配
+    pub fn y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+
     /// 计算核密度估计
     fn compute_kde(&self) -> Vec<DensityPoint> {
         if self.data.is_empty() {
@@ -161,48 +224,13 @@ impl DensityPlot {
 
         for i in 0..=self.style.resolution {
             let x = x_min + i as f32 * step;
-            let mut density = 0.0;
-
-            for &data_point in &self.data {
-                let u = (x - data_point) / self.style.bandwidth;
-                density += self.kernel_function(u);
-            }
-
-            density /= (self.data.len() as f32) * self.style.bandwidth;
+            let density = kde(&self.data, x, self.style.bandwidth, self.kernel_type);
             points.push(DensityPoint { x, y: 0.0, density });
         }
 
         points
     }
 
-    /// 核函数
-    fn kernel_function(&self, u: f32) -> f32 {
-        match self.kernel_type {
-            KernelType::Gaussian => (1.0 / (2.0 * PI).sqrt()) * (-0.5 * u * u).exp(),
-            KernelType::Epanechnikov => {
-                if u.abs() <= 1.0 {
-                    0.75 * (1.0 - u * u)
-                } else {
-                    0.0
-                }
-            }
-            KernelType::Triangular => {
-                if u.abs() <= 1.0 {
-                    1.0 - u.abs()
-                } else {
-                    0.0
-                }
-            }
-            KernelType::Uniform => {
-                if u.abs() <= 1.0 {
-                    0.5
-                } else {
-                    0.0
-                }
-            }
-        }
-    }
-
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
@@ -223,19 +251,25 @@ impl DensityPlot {
         let y_max = density_points.iter().map(|p| p.density).fold(0.0, f32::max);
 
         let x_scale = LinearScale::new(x_min, x_max);
-        let y_scale = LinearScale::new(0.0, y_max * 1.1);
+        let y_scale = match self.y_range {
+            Some((min, max)) => LinearScale::new(min, max),
+            None => LinearScale::new(0.0, y_max * 1.1),
+        };
 
         // 创建密度曲线的点
         let mut curve_points = Vec::new();
         for point in &density_points {
-            let screen_x = plot_area.x + x_scale.normalize(point.x) * plot_area.width;
-            let screen_y = plot_area.y + plot_area.height
-                - y_scale.normalize(point.density) * plot_area.height;
+            let screen_x = x_scale.to_pixel(point.x, plot_area.x, plot_area.x + plot_area.width);
+            let screen_y = y_scale.to_pixel_inverted(
+                point.density,
+                plot_area.y,
+                plot_area.y + plot_area.height,
+            );
             curve_points.push(Point2::new(screen_x, screen_y));
         }
 
         // 绘制填充区域
-        if self.style.show_area && self.style.fill_color.is_some() {
+        if let Some(fill) = self.style.fill_color.filter(|_| self.style.show_area) {
             let mut fill_points = curve_points.clone();
 
             // 添加底部的点来形成封闭的多边形
@@ -245,7 +279,7 @@ impl DensityPlot {
 
             primitives.push(Primitive::Polygon {
                 points: fill_points,
-                fill: self.style.fill_color.unwrap(),
+                fill,
                 stroke: None,
             });
         }
@@ -262,7 +296,8 @@ impl DensityPlot {
         // 绘制数据点
         if self.style.show_points {
             for &data_point in &self.data {
-                let screen_x = plot_area.x + x_scale.normalize(data_point) * plot_area.width;
+                let screen_x =
+                    x_scale.to_pixel(data_point, plot_area.x, plot_area.x + plot_area.width);
                 let baseline_y = plot_area.y + plot_area.height;
 
                 primitives.push(Primitive::Circle {
@@ -281,6 +316,7 @@ impl DensityPlot {
                 color: Color::rgb(0.1, 0.1, 0.1),
                 h_align: HorizontalAlign::Center,
                 v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
             });
         }
 
@@ -367,15 +403,38 @@ mod tests {
 
     #[test]
     fn test_kernel_functions() {
-        let plot = DensityPlot::new();
-
         // 测试高斯核
-        assert!(plot.kernel_function(0.0) > 0.0);
+        assert!(KernelType::Gaussian.evaluate(0.0) > 0.0);
 
         // 测试Epanechnikov核
-        let plot_epan = DensityPlot::new().kernel(KernelType::Epanechnikov);
-        assert!(plot_epan.kernel_function(0.0) > 0.0);
-        assert_eq!(plot_epan.kernel_function(2.0), 0.0);
+        assert!(KernelType::Epanechnikov.evaluate(0.0) > 0.0);
+        assert_eq!(KernelType::Epanechnikov.evaluate(2.0), 0.0);
+    }
+
+    #[test]
+    fn test_kde_integrates_to_one() {
+        let data = [1.0, 2.0, 2.5, 6.0];
+        let bandwidth = silverman_bandwidth(&data);
+        for kernel in [
+            KernelType::Gaussian,
+            KernelType::Epanechnikov,
+            KernelType::Triangular,
+            KernelType::Uniform,
+        ] {
+            let step = 0.01;
+            let area: f32 = (0..2000)
+                .map(|i| kde(&data, -5.0 + i as f32 * step, bandwidth, kernel) * step)
+                .sum();
+            assert!((area - 1.0).abs() < 0.01, "{:?}: area = {}", kernel, area);
+        }
+        assert_eq!(kde(&[], 0.0, 1.0, KernelType::Gaussian), 0.0);
+    }
+
+    #[test]
+    fn test_silverman_bandwidth() {
+        assert!(silverman_bandwidth(&[1.0, 2.0, 2.5, 3.0, 4.0, 8.0]) > 0.0);
+        // 常量数据不会产生零带宽
+        assert_eq!(silverman_bandwidth(&[3.0, 3.0, 3.0]), 1.0);
     }
 
     #[test]
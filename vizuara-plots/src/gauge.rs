@@ -0,0 +1,359 @@
+//! 仪表盘
+//!
+//! 在圆弧表盘上显示单个数值：数值经 [min, max] 线性映射到表盘弧度，
+//! 表盘以 12 点方向为中心左右对称展开
+
+use crate::PlotArea;
+use nalgebra::{Point2, Vector2};
+use std::f32::consts::{FRAC_PI_2, PI};
+use vizuara_core::{
+    Color, HorizontalAlign, LinearScale, Primitive, Scale, TickFormatter, VerticalAlign,
+};
+
+/// 刻度线长度占半径的比例
+const TICK_LENGTH: f32 = 0.06;
+/// 指针长度占半径的比例
+const NEEDLE_LENGTH: f32 = 0.85;
+/// 指针根部半宽与轴心半径占半径的比例
+const NEEDLE_HALF_WIDTH: f32 = 0.04;
+/// 数值文本下方预留的空间占半径的比例
+const VALUE_SPACE: f32 = 0.3;
+
+/// 表盘上的颜色区间，如绿/黄/红三段
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaugeZone {
+    pub from: f32,
+    pub to: f32,
+    pub color: Color,
+}
+
+impl GaugeZone {
+    pub fn new(from: f32, to: f32, color: Color) -> Self {
+        Self { from, to, color }
+    }
+}
+
+/// 仪表盘样式配置
+#[derive(Debug, Clone)]
+pub struct GaugeStyle {
+    /// 表盘张角（弧度），如 PI 为半圆、1.5 * PI 为 270°
+    pub arc_span: f32,
+    /// 表盘环宽占半径的比例
+    pub thickness: f32,
+    /// 区间未覆盖处的表盘底色
+    pub track_color: Color,
+    /// 指针颜色
+    pub needle_color: Color,
+    /// 目标刻度数量
+    pub tick_count: usize,
+    /// 刻度标签字体大小
+    pub label_size: f32,
+    /// 中心数值字体大小
+    pub value_size: f32,
+    /// 刻度与文本颜色
+    pub text_color: Color,
+}
+
+impl Default for GaugeStyle {
+    fn default() -> Self {
+        Self {
+            arc_span: PI,
+            thickness: 0.2,
+            track_color: Color::rgb(0.88, 0.88, 0.88),
+            needle_color: Color::rgb(0.25, 0.25, 0.25),
+            tick_count: 5,
+            label_size: 10.0,
+            value_size: 20.0,
+            text_color: Color::rgb(0.2, 0.2, 0.2),
+        }
+    }
+}
+
+/// 仪表盘
+#[derive(Debug, Clone)]
+pub struct GaugePlot {
+    value: f32,
+    min: f32,
+    max: f32,
+    zones: Vec<GaugeZone>,
+    style: GaugeStyle,
+    value_format: Option<TickFormatter>,
+    title: Option<String>,
+}
+
+impl GaugePlot {
+    /// 创建仪表盘，value 为当前值，[min, max] 为表盘范围
+    pub fn new(value: f32, min: f32, max: f32) -> Self {
+        Self {
+            value,
+            min,
+            max,
+            zones: Vec::new(),
+            style: GaugeStyle::default(),
+            value_format: None,
+            title: None,
+        }
+    }
+
+    /// 添加颜色区间，超出表盘范围的部分被截断
+    pub fn zone(mut self, from: f32, to: f32, color: Color) -> Self {
+        self.zones.push(GaugeZone::new(from, to, color));
+        self
+    }
+
+    /// 设置样式
+    pub fn style(mut self, style: GaugeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 设置表盘张角（弧度，限制在 (0, 2π]）
+    pub fn arc_span(mut self, span: f32) -> Self {
+        self.style.arc_span = span.clamp(f32::EPSILON, 2.0 * PI);
+        self
+    }
+
+    /// 设置目标刻度数量
+    pub fn tick_count(mut self, count: usize) -> Self {
+        self.style.tick_count = count;
+        self
+    }
+
+    /// 设置指针颜色
+    pub fn needle_color(mut self, color: Color) -> Self {
+        self.style.needle_color = color;
+        self
+    }
+
+    /// 设置中心数值的格式，默认保留一位小数
+    pub fn value_format(mut self, formatter: TickFormatter) -> Self {
+        self.value_format = Some(formatter);
+        self
+    }
+
+    /// 设置标题
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 当前值
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// 表盘起点角度（弧度，屏幕坐标系，Y 向下，角度增大为顺时针）
+    pub fn start_angle(&self) -> f32 {
+        -FRAC_PI_2 - self.style.arc_span / 2.0
+    }
+
+    /// 数值对应的角度，超出范围时停在表盘两端
+    pub fn value_angle(&self, value: f32) -> f32 {
+        let t = LinearScale::new(self.min, self.max)
+            .normalize(value)
+            .clamp(0.0, 1.0);
+        self.start_angle() + t * self.style.arc_span
+    }
+
+    /// 表盘圆心与外半径：整个表盘（含数值文本）在绘图区域内居中
+    pub fn dial_geometry(&self, plot_area: PlotArea) -> (Point2<f32>, f32) {
+        let half_span = self.style.arc_span / 2.0;
+        // 圆心以下被表盘占用的高度（相对半径），至少为数值文本预留空间
+        let below = (-half_span.cos()).max(VALUE_SPACE);
+        // 张角不小于半圆时表盘左右达到整个半径
+        let half_width = if half_span >= FRAC_PI_2 {
+            1.0
+        } else {
+            half_span.sin()
+        };
+
+        let radius =
+            (plot_area.width / (2.0 * half_width)).min(plot_area.height / (1.0 + below)) * 0.9;
+        let top = plot_area.y + (plot_area.height - radius * (1.0 + below)) / 2.0;
+        (
+            Point2::new(plot_area.x + plot_area.width / 2.0, top + radius),
+            radius,
+        )
+    }
+
+    /// 生成渲染图元
+    pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
+        let (center, radius) = self.dial_geometry(plot_area);
+        if radius <= 0.0 || (self.max - self.min).abs() < f32::EPSILON {
+            return primitives;
+        }
+        let inner_radius = radius * (1.0 - self.style.thickness.clamp(0.0, 1.0));
+        let start = self.start_angle();
+        let polar = |angle: f32, r: f32| {
+            Point2::new(center.x + r * angle.cos(), center.y + r * angle.sin())
+        };
+
+        // 1. 表盘底色与颜色区间
+        primitives.push(Primitive::ArcRing {
+            center,
+            inner_radius,
+            outer_radius: radius,
+            start_angle: start,
+            end_angle: start + self.style.arc_span,
+            fill: self.style.track_color,
+            stroke: None,
+        });
+        for zone in &self.zones {
+            let (from, to) = (self.value_angle(zone.from), self.value_angle(zone.to));
+            if (to - from).abs() > f32::EPSILON {
+                primitives.push(Primitive::ArcRing {
+                    center,
+                    inner_radius,
+                    outer_radius: radius,
+                    start_angle: from.min(to),
+                    end_angle: from.max(to),
+                    fill: zone.color,
+                    stroke: None,
+                });
+            }
+        }
+
+        // 2. 刻度线与标签，位于环内侧
+        let (lo, hi) = (self.min.min(self.max), self.min.max(self.max));
+        let ticks = LinearScale::new(lo, hi).ticks(self.style.tick_count);
+        let tolerance = (hi - lo) * 1e-4;
+        for tick in ticks
+            .into_iter()
+            .filter(|t| (lo - tolerance..=hi + tolerance).contains(t))
+        {
+            let angle = self.value_angle(tick);
+            primitives.push(Primitive::Line {
+                start: polar(angle, inner_radius),
+                end: polar(angle, inner_radius - radius * TICK_LENGTH),
+            });
+            primitives.push(Primitive::Text {
+                position: polar(angle, inner_radius - radius * (TICK_LENGTH + 0.1)),
+                content: format_tick(tick),
+                size: self.style.label_size,
+                color: self.style.text_color,
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Middle,
+                rotation: 0.0,
+            });
+        }
+
+        // 3. 指针：从轴心指向数值角度的细长四边形，外加轴心圆
+        let angle = self.value_angle(self.value);
+        let direction = Vector2::new(angle.cos(), angle.sin());
+        let normal = Vector2::new(-direction.y, direction.x);
+        let half_width = radius * NEEDLE_HALF_WIDTH;
+        primitives.push(Primitive::Polygon {
+            points: vec![
+                center + direction * radius * NEEDLE_LENGTH,
+                center + normal * half_width,
+                center - direction * half_width * 1.5,
+                center - normal * half_width,
+            ],
+            fill: self.style.needle_color,
+            stroke: None,
+        });
+        primitives.push(Primitive::ArcSector {
+            center,
+            radius: half_width * 1.5,
+            start_angle: 0.0,
+            end_angle: 2.0 * PI,
+            fill: self.style.needle_color,
+            stroke: None,
+        });
+
+        // 4. 中心数值
+        let content = match &self.value_format {
+            Some(formatter) => formatter.format(self.value),
+            None => format!("{:.1}", self.value),
+        };
+        primitives.push(Primitive::Text {
+            position: Point2::new(center.x, center.y + radius * 0.08),
+            content,
+            size: self.style.value_size,
+            color: self.style.text_color,
+            h_align: HorizontalAlign::Center,
+            v_align: VerticalAlign::Top,
+            rotation: 0.0,
+        });
+
+        if let Some(ref title) = self.title {
+            primitives.push(Primitive::Text {
+                position: Point2::new(center.x, center.y - radius - 10.0),
+                content: title.clone(),
+                size: 16.0,
+                color: self.style.text_color,
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
+            });
+        }
+
+        primitives
+    }
+}
+
+/// 刻度标签：去掉多余的零
+fn format_tick(value: f32) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_angles_and_primitives() {
+        let gauge = GaugePlot::new(75.0, 0.0, 100.0)
+            .zone(0.0, 60.0, Color::rgb(0.2, 0.8, 0.2))
+            .zone(60.0, 85.0, Color::rgb(0.9, 0.8, 0.2))
+            .zone(85.0, 120.0, Color::rgb(0.9, 0.2, 0.2));
+
+        // 半圆表盘：最小值在左侧，中点在正上方，最大值在右侧
+        assert!((gauge.value_angle(0.0) + PI).abs() < 1e-5);
+        assert!((gauge.value_angle(50.0) + FRAC_PI_2).abs() < 1e-5);
+        assert!(gauge.value_angle(100.0).abs() < 1e-5);
+        // 超出范围时停在两端
+        assert!(gauge.value_angle(150.0).abs() < 1e-5);
+
+        let area = PlotArea::new(0.0, 0.0, 400.0, 300.0);
+        let (center, radius) = gauge.dial_geometry(area);
+        let primitives = gauge.generate_primitives(area);
+
+        // 底色加三个区间，最后一个截断到最大值
+        let rings: Vec<(f32, f32)> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::ArcRing {
+                    start_angle,
+                    end_angle,
+                    ..
+                } => Some((*start_angle, *end_angle)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rings.len(), 4);
+        assert!(rings[3].1.abs() < 1e-5);
+
+        let tip = primitives
+            .iter()
+            .find_map(|p| match p {
+                Primitive::Polygon { points, .. } => Some(points[0]),
+                _ => None,
+            })
+            .unwrap();
+        let angle = gauge.value_angle(75.0);
+        let expected = center + Vector2::new(angle.cos(), angle.sin()) * radius * NEEDLE_LENGTH;
+        assert!((tip - expected).norm() < 1e-3);
+
+        assert!(primitives
+            .iter()
+            .any(|p| matches!(p, Primitive::Text { content, .. } if content == "75.0")));
+
+        // 270° 表盘左右对称展开
+        let wide = GaugePlot::new(0.0, 0.0, 1.0).arc_span(1.5 * PI);
+        assert!((wide.value_angle(0.0) + 1.25 * PI).abs() < 1e-5);
+        assert!((wide.value_angle(1.0) - 0.25 * PI).abs() < 1e-5);
+    }
+}
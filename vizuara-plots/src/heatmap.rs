@@ -1,5 +1,9 @@
+use crate::ColorBar;
 use nalgebra::Point2;
-use vizuara_core::{Color, Primitive};
+use vizuara_core::{Color, LinearScale, Primitive, Scale, TickFormatter};
+
+/// 数值标签的最小字号，单元格过小放不下时不显示
+const MIN_VALUE_LABEL_SIZE: f32 = 6.0;
 
 /// 热力图数据点
 #[derive(Debug, Clone)]
@@ -23,9 +27,10 @@ impl HeatmapCell {
 }
 
 /// 颜色映射策略
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum ColorMap {
     /// 蓝-白-红渐变 (常用于温度数据)
+    #[default]
     BlueWhiteRed,
     /// 蓝绿渐变 (常用于深度数据)
     BlueGreen,
@@ -37,12 +42,6 @@ pub enum ColorMap {
     Custom(Color, Color),
 }
 
-impl Default for ColorMap {
-    fn default() -> Self {
-        Self::BlueWhiteRed
-    }
-}
-
 impl ColorMap {
     /// 根据标准化值 (0.0-1.0) 获取对应颜色
     pub fn get_color(&self, normalized_value: f32) -> Color {
@@ -97,6 +96,76 @@ fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color {
     Color::rgb(r_prime + m, g_prime + m, b_prime + m)
 }
 
+/// 颜色映射前对数值的变换方式
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorScaleKind {
+    /// 线性
+    #[default]
+    Linear,
+    /// 以 10 为底的对数；非正值按范围下限处理
+    Log,
+    /// 对称对数：|x| 远小于 `linear_threshold` 时近似线性，之外按对数压缩，适用于跨零数据
+    SymLog { linear_threshold: f32 },
+}
+
+impl ColorScaleKind {
+    /// 正向变换
+    pub fn transform(&self, value: f32) -> f32 {
+        match *self {
+            ColorScaleKind::Linear => value,
+            ColorScaleKind::Log => value.log10(),
+            ColorScaleKind::SymLog { linear_threshold } => {
+                let c = linear_threshold.max(f32::EPSILON);
+                value.signum() * (1.0 + value.abs() / c).log10()
+            }
+        }
+    }
+
+    /// 逆变换
+    pub fn inverse(&self, transformed: f32) -> f32 {
+        match *self {
+            ColorScaleKind::Linear => transformed,
+            ColorScaleKind::Log => 10f32.powf(transformed),
+            ColorScaleKind::SymLog { linear_threshold } => {
+                let c = linear_threshold.max(f32::EPSILON);
+                transformed.signum() * c * (10f32.powf(transformed.abs()) - 1.0)
+            }
+        }
+    }
+
+    /// 将数值按变换后的位置标准化到 [0, 1]（范围外的值不截断）；
+    /// 对数变换要求范围下限为正，小于下限的值（包括非正值）按下限处理
+    pub fn normalize(&self, value: f32, (min, max): (f32, f32)) -> f32 {
+        let value = match self {
+            ColorScaleKind::Log => value.max(min),
+            _ => value,
+        };
+        let lo = self.transform(min);
+        let span = self.transform(max) - lo;
+        if span.abs() < f32::EPSILON || !span.is_finite() {
+            return 0.0;
+        }
+        (self.transform(value) - lo) / span
+    }
+
+    /// 由标准化位置还原数值，与 [`normalize`](Self::normalize) 互逆
+    pub fn denormalize(&self, t: f32, (min, max): (f32, f32)) -> f32 {
+        let lo = self.transform(min);
+        let hi = self.transform(max);
+        self.inverse(lo + t * (hi - lo))
+    }
+}
+
+/// 在给定背景色上可读的标签颜色（黑或白）
+fn contrast_color(background: Color) -> Color {
+    let luminance = 0.299 * background.r + 0.587 * background.g + 0.114 * background.b;
+    if luminance > 0.5 {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
 /// 热力图样式配置
 #[derive(Debug, Clone)]
 pub struct HeatmapStyle {
@@ -110,10 +179,16 @@ pub struct HeatmapStyle {
     pub grid_width: f32,
     /// 是否显示数值标签
     pub show_values: bool,
-    /// 标签字体大小
+    /// 标签字体大小上限（实际字号随单元格大小缩放）
     pub label_size: f32,
-    /// 标签颜色
+    /// 标签颜色（仅在关闭自动对比色时使用）
     pub label_color: Color,
+    /// 是否根据单元格亮度自动选择黑色或白色标签
+    pub auto_label_color: bool,
+    /// 数值标签格式，默认保留一位小数
+    pub value_format: Option<TickFormatter>,
+    /// 颜色映射前的数值变换
+    pub color_scale: ColorScaleKind,
 }
 
 impl Default for HeatmapStyle {
@@ -126,6 +201,9 @@ impl Default for HeatmapStyle {
             show_values: false,
             label_size: 10.0,
             label_color: Color::rgb(0.2, 0.2, 0.2),
+            auto_label_color: true,
+            value_format: None,
+            color_scale: ColorScaleKind::Linear,
         }
     }
 }
@@ -143,6 +221,14 @@ pub struct Heatmap {
     style: HeatmapStyle,
     /// 数值范围 (用于颜色映射)
     value_range: Option<(f32, f32)>,
+    /// 列边界（数据坐标），未设置时各列均分绘图区域
+    x_edges: Option<Vec<f32>>,
+    /// 行边界（数据坐标），未设置时各行均分绘图区域
+    y_edges: Option<Vec<f32>>,
+    /// 列边界使用的X轴比例尺
+    x_scale: Option<LinearScale>,
+    /// 行边界使用的Y轴比例尺
+    y_scale: Option<LinearScale>,
 }
 
 impl Heatmap {
@@ -154,6 +240,10 @@ impl Heatmap {
             y_labels: Vec::new(),
             style: HeatmapStyle::default(),
             value_range: None,
+            x_edges: None,
+            y_edges: None,
+            x_scale: None,
+            y_scale: None,
         }
     }
 
@@ -206,6 +296,12 @@ impl Heatmap {
         self
     }
 
+    /// 设置颜色映射前的数值变换（线性、对数或对称对数）
+    pub fn color_scale(mut self, kind: ColorScaleKind) -> Self {
+        self.style.color_scale = kind;
+        self
+    }
+
     /// 设置是否显示网格
     pub fn show_grid(mut self, show: bool) -> Self {
         self.style.show_grid = show;
@@ -218,12 +314,49 @@ impl Heatmap {
         self
     }
 
+    /// 设置数值标签格式
+    pub fn value_format(mut self, formatter: TickFormatter) -> Self {
+        self.style.value_format = Some(formatter);
+        self
+    }
+
     /// 设置数值范围 (用于颜色映射)
     pub fn value_range(mut self, min: f32, max: f32) -> Self {
         self.value_range = Some((min, max));
         self
     }
 
+    /// 设置列边界：N 列需要 N+1 个严格单调的数据坐标，每列按相邻边界的实际范围绘制
+    /// （类似 matplotlib 的 `pcolormesh`）
+    ///
+    /// 边界在生成图元时按当时的列数检查，与调用顺序无关；
+    /// 数量不符、含非有限值或不单调的边界被忽略，各列均分绘图区域
+    pub fn x_edges(mut self, edges: &[f32]) -> Self {
+        self.x_edges = Some(edges.to_vec());
+        self
+    }
+
+    /// 设置行边界：N 行需要 N+1 个严格单调的数据坐标；Y 值向上增大，
+    /// 递增的边界使第 0 行位于底部
+    ///
+    /// 与 [`x_edges`](Self::x_edges) 相同，无效的边界被忽略，各行均分绘图区域
+    pub fn y_edges(mut self, edges: &[f32]) -> Self {
+        self.y_edges = Some(edges.to_vec());
+        self
+    }
+
+    /// 设置列边界使用的X轴比例尺，默认覆盖全部列边界
+    pub fn x_scale(mut self, scale: LinearScale) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// 设置行边界使用的Y轴比例尺，默认覆盖全部行边界
+    pub fn y_scale(mut self, scale: LinearScale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
     /// 自动计算数值范围
     pub fn auto_range(mut self) -> Self {
         self.compute_value_range();
@@ -273,6 +406,35 @@ impl Heatmap {
         self.value_range = Some((min_val, max_val));
     }
 
+    /// 颜色映射实际使用的数值范围；对数变换下非正的下限替换为最小的正数据值
+    pub fn color_range(&self) -> (f32, f32) {
+        let (min, max) = self.value_range.unwrap_or((0.0, 1.0));
+        if self.style.color_scale != ColorScaleKind::Log || min > 0.0 {
+            return (min, max);
+        }
+
+        let smallest_positive = self
+            .data
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|&v| v > 0.0)
+            .fold(f32::INFINITY, f32::min);
+        if max <= 0.0 {
+            (1.0, 10.0)
+        } else if smallest_positive.is_finite() && smallest_positive < max {
+            (smallest_positive, max)
+        } else {
+            (max / 1000.0, max)
+        }
+    }
+
+    /// 与热力图颜色映射一致的颜色条
+    pub fn color_bar(&self) -> ColorBar {
+        let (min, max) = self.color_range();
+        ColorBar::new(self.style.color_map.clone(), min, max).color_scale(self.style.color_scale)
+    }
+
     /// 获取数据维度
     pub fn dimensions(&self) -> (usize, usize) {
         if self.data.is_empty() {
@@ -296,21 +458,20 @@ impl Heatmap {
         }
 
         let (rows, cols) = self.dimensions();
-        let (min_val, max_val) = self.value_range.unwrap_or((0.0, 1.0));
+        let range = self.color_range();
 
-        // 计算每个单元格的大小
-        let cell_width = plot_area.width / cols as f32;
-        let cell_height = plot_area.height / rows as f32;
+        // 每列/每行在屏幕上的范围
+        let col_spans = self.column_spans(plot_area, cols);
+        let row_spans = self.row_spans(plot_area, rows);
 
         // 为每个单元格创建矩形
-        for (row_idx, row) in self.data.iter().enumerate() {
-            for (col_idx, &value) in row.iter().enumerate() {
-                // 计算单元格位置
-                let x = plot_area.x + col_idx as f32 * cell_width;
-                let y = plot_area.y + row_idx as f32 * cell_height;
-
-                // 标准化数值到 [0, 1]
-                let normalized_value = (value - min_val) / (max_val - min_val);
+        for (row, &(y, bottom)) in self.data.iter().zip(&row_spans) {
+            for (&value, &(x, right)) in row.iter().zip(&col_spans) {
+                let cell_width = right - x;
+                let cell_height = bottom - y;
+
+                // 按颜色变换标准化数值到 [0, 1]
+                let normalized_value = self.style.color_scale.normalize(value, range);
                 let color = self.style.color_map.get_color(normalized_value);
 
                 // 创建填充矩形
@@ -327,38 +488,124 @@ impl Heatmap {
 
                 // 添加数值标签
                 if self.style.show_values {
-                    let label_x = x + cell_width / 2.0;
-                    let label_y = y + cell_height / 2.0;
-
-                    primitives.push(Primitive::Text {
-                        position: Point2::new(label_x, label_y),
-                        content: format!("{:.1}", value),
-                        size: self.style.label_size,
-                        color: self.style.label_color,
-                        h_align: vizuara_core::HorizontalAlign::Center,
-                        v_align: vizuara_core::VerticalAlign::Middle,
-                    });
+                    let center = Point2::new(x + cell_width / 2.0, y + cell_height / 2.0);
+                    primitives.extend(self.value_label(
+                        value,
+                        color,
+                        center,
+                        cell_width,
+                        cell_height,
+                    ));
                 }
             }
         }
 
         // 添加轴标签
-        self.add_axis_labels(&mut primitives, plot_area, cell_width, cell_height);
+        self.add_axis_labels(&mut primitives, plot_area, &col_spans, &row_spans);
 
         primitives
     }
 
+    /// 各列的屏幕 X 范围 (左, 右)：设置了有效的列边界时按比例尺映射，否则均分
+    fn column_spans(&self, plot_area: crate::PlotArea, cols: usize) -> Vec<(f32, f32)> {
+        let (start, end) = (plot_area.x, plot_area.x + plot_area.width);
+        match self
+            .x_edges
+            .as_ref()
+            .filter(|edges| valid_edges(edges, cols))
+        {
+            Some(edges) => {
+                let scale = self.x_scale.clone().unwrap_or_else(|| edge_scale(edges));
+                edges
+                    .windows(2)
+                    .map(|w| {
+                        ordered(
+                            scale.to_pixel(w[0], start, end),
+                            scale.to_pixel(w[1], start, end),
+                        )
+                    })
+                    .collect()
+            }
+            None => uniform_spans(start, plot_area.width, cols),
+        }
+    }
+
+    /// 各行的屏幕 Y 范围 (上, 下)：设置了有效的行边界时按比例尺映射（Y 向上增大），
+    /// 否则自上而下均分
+    fn row_spans(&self, plot_area: crate::PlotArea, rows: usize) -> Vec<(f32, f32)> {
+        let (start, end) = (plot_area.y, plot_area.y + plot_area.height);
+        match self
+            .y_edges
+            .as_ref()
+            .filter(|edges| valid_edges(edges, rows))
+        {
+            Some(edges) => {
+                let scale = self.y_scale.clone().unwrap_or_else(|| edge_scale(edges));
+                edges
+                    .windows(2)
+                    .map(|w| {
+                        ordered(
+                            scale.to_pixel_inverted(w[0], start, end),
+                            scale.to_pixel_inverted(w[1], start, end),
+                        )
+                    })
+                    .collect()
+            }
+            None => uniform_spans(start, plot_area.height, rows),
+        }
+    }
+
+    /// 生成单元格中心的数值标签，单元格过小时返回 None
+    fn value_label(
+        &self,
+        value: f32,
+        fill: Color,
+        center: Point2<f32>,
+        cell_width: f32,
+        cell_height: f32,
+    ) -> Option<Primitive> {
+        let content = match &self.style.value_format {
+            Some(formatter) => formatter.format(value),
+            None => format!("{:.1}", value),
+        };
+
+        // 字号按单元格缩放：高度留出边距，宽度按每字符约 0.6 倍字号估算
+        let chars = content.chars().count().max(1) as f32;
+        let size = (cell_height * 0.5)
+            .min(cell_width * 0.9 / (chars * 0.6))
+            .min(self.style.label_size);
+        if size < MIN_VALUE_LABEL_SIZE {
+            return None;
+        }
+
+        let color = if self.style.auto_label_color {
+            contrast_color(fill)
+        } else {
+            self.style.label_color
+        };
+
+        Some(Primitive::Text {
+            position: center,
+            content,
+            size,
+            color,
+            h_align: vizuara_core::HorizontalAlign::Center,
+            v_align: vizuara_core::VerticalAlign::Middle,
+            rotation: 0.0,
+        })
+    }
+
     /// 添加轴标签
     fn add_axis_labels(
         &self,
         primitives: &mut Vec<Primitive>,
         plot_area: crate::PlotArea,
-        cell_width: f32,
-        cell_height: f32,
+        col_spans: &[(f32, f32)],
+        row_spans: &[(f32, f32)],
     ) {
         // X轴标签 (列标签)
-        for (i, label) in self.x_labels.iter().enumerate() {
-            let x = plot_area.x + (i as f32 + 0.5) * cell_width;
+        for (label, &(left, right)) in self.x_labels.iter().zip(col_spans) {
+            let x = (left + right) / 2.0;
             let y = plot_area.y + plot_area.height + 15.0;
 
             primitives.push(Primitive::Text {
@@ -368,13 +615,14 @@ impl Heatmap {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: vizuara_core::HorizontalAlign::Center,
                 v_align: vizuara_core::VerticalAlign::Top,
+                rotation: 0.0,
             });
         }
 
         // Y轴标签 (行标签)
-        for (i, label) in self.y_labels.iter().enumerate() {
+        for (label, &(top, bottom)) in self.y_labels.iter().zip(row_spans) {
             let x = plot_area.x - 15.0;
-            let y = plot_area.y + (i as f32 + 0.5) * cell_height;
+            let y = (top + bottom) / 2.0;
 
             primitives.push(Primitive::Text {
                 position: Point2::new(x, y),
@@ -383,11 +631,39 @@ impl Heatmap {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: vizuara_core::HorizontalAlign::Right,
                 v_align: vizuara_core::VerticalAlign::Middle,
+                rotation: 0.0,
             });
         }
     }
 }
 
+/// 边界是否可用：恰好有 cells + 1 个有限值，且严格单调
+fn valid_edges(edges: &[f32], cells: usize) -> bool {
+    edges.len() == cells + 1
+        && edges.iter().all(|e| e.is_finite())
+        && (edges.windows(2).all(|w| w[0] < w[1]) || edges.windows(2).all(|w| w[0] > w[1]))
+}
+
+/// 覆盖全部边界的比例尺
+fn edge_scale(edges: &[f32]) -> LinearScale {
+    let first = edges[0];
+    let last = edges[edges.len() - 1];
+    LinearScale::new(first.min(last), first.max(last))
+}
+
+/// 将区间 [start, start + length] 均分为 n 段
+fn uniform_spans(start: f32, length: f32, n: usize) -> Vec<(f32, f32)> {
+    let step = length / n as f32;
+    (0..n)
+        .map(|i| (start + i as f32 * step, start + (i + 1) as f32 * step))
+        .collect()
+}
+
+/// 按从小到大排列两个坐标
+fn ordered(a: f32, b: f32) -> (f32, f32) {
+    (a.min(b), a.max(b))
+}
+
 impl Default for Heatmap {
     fn default() -> Self {
         Self::new()
@@ -482,4 +758,158 @@ mod tests {
         // 应该有4个单元格矩形 + 标签
         assert!(primitives.len() >= 4);
     }
+
+    #[test]
+    fn test_value_annotations() {
+        let data = vec![vec![0.0, 1.0], vec![0.5, 0.25]];
+        let heatmap = Heatmap::new()
+            .data(&data)
+            .color_map(ColorMap::Grayscale)
+            .show_values(true)
+            .value_format(TickFormatter::percent(0));
+
+        let labels = |area| -> Vec<(String, Color, f32)> {
+            heatmap
+                .generate_primitives(area)
+                .into_iter()
+                .filter_map(|p| match p {
+                    Primitive::Text {
+                        content,
+                        color,
+                        size,
+                        v_align: vizuara_core::VerticalAlign::Middle,
+                        h_align: vizuara_core::HorizontalAlign::Center,
+                        ..
+                    } => Some((content, color, size)),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let values = labels(crate::PlotArea::new(0.0, 0.0, 200.0, 200.0));
+        let contents: Vec<&str> = values.iter().map(|(c, _, _)| c.as_str()).collect();
+        assert_eq!(contents, ["0%", "100%", "50%", "25%"]);
+        // 黑色单元格用白字，白色单元格用黑字
+        assert_eq!(values[0].1, Color::WHITE);
+        assert_eq!(values[1].1, Color::BLACK);
+
+        // 字号随单元格缩小，过小时不显示
+        let small = labels(crate::PlotArea::new(0.0, 0.0, 30.0, 30.0));
+        assert!(small.iter().all(|(_, _, size)| *size < values[0].2));
+        assert!(labels(crate::PlotArea::new(0.0, 0.0, 8.0, 8.0)).is_empty());
+    }
+
+    #[test]
+    fn test_log_and_symlog_color_scales() {
+        let fills = |heatmap: &Heatmap| -> Vec<Color> {
+            heatmap
+                .generate_primitives(crate::PlotArea::new(0.0, 0.0, 300.0, 100.0))
+                .into_iter()
+                .filter_map(|p| match p {
+                    Primitive::RectangleStyled { fill, .. } => Some(fill),
+                    _ => None,
+                })
+                .collect()
+        };
+        let gray = |t: f32| ColorMap::Grayscale.get_color(t);
+
+        // 跨越数量级的数据在对数变换下均匀分布
+        let log = Heatmap::new()
+            .data(&[vec![1.0, 10.0, 100.0]])
+            .color_map(ColorMap::Grayscale)
+            .color_scale(ColorScaleKind::Log);
+        assert_eq!(fills(&log), [gray(0.0), gray(0.5), gray(1.0)]);
+
+        // 非正值取最小正值作为下限
+        let with_zero = Heatmap::new()
+            .data(&[vec![0.0, 10.0, 1000.0]])
+            .color_scale(ColorScaleKind::Log);
+        assert_eq!(with_zero.color_range(), (10.0, 1000.0));
+        assert_eq!(ColorScaleKind::Log.normalize(0.0, (10.0, 1000.0)), 0.0);
+
+        // 对称对数：零位于中点，正负对称
+        let symlog = ColorScaleKind::SymLog {
+            linear_threshold: 1.0,
+        };
+        let range = (-100.0, 100.0);
+        assert!((symlog.normalize(0.0, range) - 0.5).abs() < 1e-6);
+        let pos = symlog.normalize(10.0, range);
+        let neg = symlog.normalize(-10.0, range);
+        assert!((pos + neg - 1.0).abs() < 1e-6);
+        assert!(pos > 0.5 + 0.05 / 2.0);
+        assert!((symlog.denormalize(pos, range) - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_irregular_cell_edges() {
+        let cells = |heatmap: &Heatmap| -> Vec<(f32, f32, f32, f32)> {
+            heatmap
+                .generate_primitives(crate::PlotArea::new(0.0, 0.0, 100.0, 50.0))
+                .into_iter()
+                .filter_map(|p| match p {
+                    Primitive::RectangleStyled { min, max, .. } => {
+                        Some((min.x, min.y, max.x, max.y))
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // 列宽 1:3:6，行高 1:4，Y 向上增大
+        let heatmap = Heatmap::new()
+            .data(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])
+            .x_edges(&[0.0, 1.0, 4.0, 10.0])
+            .y_edges(&[0.0, 1.0, 5.0]);
+        assert_eq!(
+            cells(&heatmap),
+            [
+                (0.0, 40.0, 10.0, 50.0),
+                (10.0, 40.0, 40.0, 50.0),
+                (40.0, 40.0, 100.0, 50.0),
+                (0.0, 0.0, 10.0, 40.0),
+                (10.0, 0.0, 40.0, 40.0),
+                (40.0, 0.0, 100.0, 40.0),
+            ]
+        );
+
+        // 与坐标轴一致的比例尺；递减的边界同样有效
+        let heatmap = Heatmap::new()
+            .data(&[vec![1.0, 2.0]])
+            .x_edges(&[20.0, 10.0, 0.0])
+            .x_scale(LinearScale::new(0.0, 40.0));
+        let spans: Vec<(f32, f32)> = cells(&heatmap).iter().map(|c| (c.0, c.2)).collect();
+        assert_eq!(spans, [(25.0, 50.0), (0.0, 25.0)]);
+    }
+
+    #[test]
+    fn test_invalid_edges_fall_back_to_uniform() {
+        let area = crate::PlotArea::new(0.0, 0.0, 100.0, 50.0);
+        let column_spans = |heatmap: &Heatmap| heatmap.column_spans(area, 2);
+        let uniform = vec![(0.0, 50.0), (50.0, 100.0)];
+
+        // 不单调、数量不符或含非有限值的边界都被忽略
+        for edges in [
+            &[0.0, 2.0, 1.0][..],
+            &[0.0, 1.0][..],
+            &[0.0, f32::NAN, 2.0][..],
+        ] {
+            let heatmap = Heatmap::new().data(&[vec![1.0, 2.0]]).x_edges(edges);
+            assert_eq!(column_spans(&heatmap), uniform);
+        }
+
+        // 检查与调用顺序无关：先设边界后设数据同样生效或同样被忽略
+        let before = Heatmap::new()
+            .x_edges(&[0.0, 1.0, 4.0])
+            .data(&[vec![1.0, 2.0]]);
+        assert_eq!(column_spans(&before), vec![(0.0, 25.0), (25.0, 100.0)]);
+        let mis_sized = Heatmap::new().x_edges(&[0.0, 1.0]).data(&[vec![1.0, 2.0]]);
+        assert_eq!(column_spans(&mis_sized), uniform);
+        assert_eq!(
+            mis_sized.generate_primitives(area).len(),
+            Heatmap::new()
+                .data(&[vec![1.0, 2.0]])
+                .generate_primitives(area)
+                .len()
+        );
+    }
 }
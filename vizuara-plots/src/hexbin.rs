@@ -0,0 +1,418 @@
+//! 六边形分箱图 / 二维直方图
+//!
+//! 将大量散点按六边形或矩形网格计数，每个单元格按计数经颜色映射着色，
+//! 解决散点过多时相互遮盖的问题；空单元格不绘制
+
+use crate::{ColorBar, ColorMap, ColorScaleKind, PlotArea, ScaledPlot};
+use nalgebra::Point2;
+use std::collections::BTreeMap;
+use vizuara_core::{Color, LinearScale, Primitive, Scale};
+
+/// 单元格形状
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinShape {
+    /// 尖顶六边形，相邻行错开半格
+    #[default]
+    Hexagon,
+    /// 矩形（二维直方图）
+    Rectangle,
+}
+
+/// 六边形分箱图样式
+#[derive(Debug, Clone)]
+pub struct HexbinStyle {
+    /// 颜色映射
+    pub color_map: ColorMap,
+    /// 颜色映射前对计数的变换
+    pub color_scale: ColorScaleKind,
+    /// 单元格形状
+    pub shape: BinShape,
+    /// X 方向的单元格数量
+    pub gridsize: usize,
+    /// 单元格边框 (颜色, 宽度)
+    pub edge: Option<(Color, f32)>,
+}
+
+impl Default for HexbinStyle {
+    fn default() -> Self {
+        Self {
+            color_map: ColorMap::BlueGreen,
+            color_scale: ColorScaleKind::Linear,
+            shape: BinShape::Hexagon,
+            gridsize: 20,
+            edge: None,
+        }
+    }
+}
+
+/// 非空单元格
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HexbinCell {
+    /// 单元格中心（数据坐标）
+    pub center: (f32, f32),
+    /// 落入单元格的点数
+    pub count: usize,
+}
+
+/// 六边形分箱图
+#[derive(Debug, Clone)]
+pub struct Hexbin {
+    data: Vec<(f32, f32)>,
+    style: HexbinStyle,
+    x_scale: Option<LinearScale>,
+    y_scale: Option<LinearScale>,
+}
+
+impl Hexbin {
+    /// 创建新的六边形分箱图
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            style: HexbinStyle::default(),
+            x_scale: None,
+            y_scale: None,
+        }
+    }
+
+    /// 设置数据点，含 NaN 或无穷的点被忽略
+    pub fn data(mut self, data: &[(f32, f32)]) -> Self {
+        self.data = data
+            .iter()
+            .copied()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+        self
+    }
+
+    /// 设置样式
+    pub fn style(mut self, style: HexbinStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 设置颜色映射
+    pub fn color_map(mut self, color_map: ColorMap) -> Self {
+        self.style.color_map = color_map;
+        self
+    }
+
+    /// 设置颜色映射前对计数的变换（计数跨越多个数量级时用对数）
+    pub fn color_scale(mut self, kind: ColorScaleKind) -> Self {
+        self.style.color_scale = kind;
+        self
+    }
+
+    /// 设置单元格形状
+    pub fn shape(mut self, shape: BinShape) -> Self {
+        self.style.shape = shape;
+        self
+    }
+
+    /// 设置 X 方向的单元格数量；Y 方向按单元格大致等宽等高推算
+    pub fn gridsize(mut self, gridsize: usize) -> Self {
+        self.style.gridsize = gridsize.max(1);
+        self
+    }
+
+    /// 设置单元格边框
+    pub fn edge(mut self, color: Color, width: f32) -> Self {
+        self.style.edge = Some((color, width));
+        self
+    }
+
+    /// 设置 X 轴比例尺，同时决定分箱范围
+    pub fn x_scale(mut self, scale: LinearScale) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// 设置 Y 轴比例尺，同时决定分箱范围
+    pub fn y_scale(mut self, scale: LinearScale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// 按数据范围设置比例尺
+    pub fn auto_scale(mut self) -> Self {
+        if let Some((x_min, x_max, y_min, y_max)) = self.data_bounds() {
+            self.x_scale = Some(LinearScale::new(x_min, x_max));
+            self.y_scale = Some(LinearScale::new(y_min, y_max));
+        }
+        self
+    }
+
+    /// 数据范围 (x_min, x_max, y_min, y_max)；没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let (first, rest) = self.data.split_first()?;
+        Some(rest.iter().fold(
+            (first.0, first.0, first.1, first.1),
+            |(x0, x1, y0, y1), &(x, y)| (x0.min(x), x1.max(x), y0.min(y), y1.max(y)),
+        ))
+    }
+
+    /// 数据点数量
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 实际使用的比例尺：未设置时按数据范围
+    fn scales(&self) -> Option<(LinearScale, LinearScale)> {
+        let (x_min, x_max, y_min, y_max) = self.data_bounds()?;
+        Some((
+            self.x_scale
+                .clone()
+                .unwrap_or_else(|| LinearScale::new(x_min, x_max)),
+            self.y_scale
+                .clone()
+                .unwrap_or_else(|| LinearScale::new(y_min, y_max)),
+        ))
+    }
+
+    /// X、Y 方向的单元格数量
+    fn grid_counts(&self) -> (f32, f32) {
+        let nx = self.style.gridsize as f32;
+        // 六边形格点的行距为 sy，高为 2/3·sy；取 ny = nx/√3 使正方形区域内的六边形接近正六边形
+        let ny = match self.style.shape {
+            BinShape::Hexagon => (nx / 3f32.sqrt()).round().max(1.0),
+            BinShape::Rectangle => nx,
+        };
+        (nx, ny)
+    }
+
+    /// 单元格在 X、Y 方向的间距（数据单位）
+    fn bin_size(&self, x_scale: &LinearScale, y_scale: &LinearScale) -> (f32, f32) {
+        let (nx, ny) = self.grid_counts();
+        let span = |scale: &LinearScale| {
+            let span = (scale.domain_max - scale.domain_min).abs();
+            if span > f32::EPSILON {
+                span
+            } else {
+                1.0
+            }
+        };
+        (span(x_scale) / nx, span(y_scale) / ny)
+    }
+
+    /// 非空单元格，按网格位置排序
+    pub fn bins(&self) -> Vec<HexbinCell> {
+        let Some((x_scale, y_scale)) = self.scales() else {
+            return Vec::new();
+        };
+        let (sx, sy) = self.bin_size(&x_scale, &y_scale);
+        let x0 = x_scale.domain_min.min(x_scale.domain_max);
+        let y0 = y_scale.domain_min.min(y_scale.domain_max);
+
+        // 键：(是否为错开的格点, 列, 行)；值为计数
+        let mut counts: BTreeMap<(bool, i64, i64), usize> = BTreeMap::new();
+        for &(x, y) in &self.data {
+            let (u, v) = ((x - x0) / sx, (y - y0) / sy);
+            let key = match self.style.shape {
+                BinShape::Rectangle => {
+                    let (nx, ny) = self.grid_counts();
+                    (false, rect_index(u, nx), rect_index(v, ny))
+                }
+                BinShape::Hexagon => {
+                    // 两套格点：整数格点与偏移半格的格点，取更近者（Y 方向按六边形比例加权）
+                    let (i1, j1) = (u.round(), v.round());
+                    let (i2, j2) = (u.floor(), v.floor());
+                    let d1 = (u - i1).powi(2) + 3.0 * (v - j1).powi(2);
+                    let d2 = (u - i2 - 0.5).powi(2) + 3.0 * (v - j2 - 0.5).powi(2);
+                    if d1 <= d2 {
+                        (false, i1 as i64, j1 as i64)
+                    } else {
+                        (true, i2 as i64, j2 as i64)
+                    }
+                }
+            };
+            *counts.entry(key).or_insert(0) += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|((offset, i, j), count)| {
+                let shift = match (self.style.shape, offset) {
+                    (BinShape::Rectangle, _) | (BinShape::Hexagon, true) => 0.5,
+                    (BinShape::Hexagon, false) => 0.0,
+                };
+                HexbinCell {
+                    center: (x0 + (i as f32 + shift) * sx, y0 + (j as f32 + shift) * sy),
+                    count,
+                }
+            })
+            .collect()
+    }
+
+    /// 计数范围 (最小, 最大)，只统计非空单元格
+    pub fn count_range(&self) -> Option<(f32, f32)> {
+        count_range(&self.bins())
+    }
+
+    /// 与分箱图颜色映射一致的颜色条
+    pub fn color_bar(&self) -> ColorBar {
+        let (min, max) = self.count_range().unwrap_or((0.0, 1.0));
+        ColorBar::new(self.style.color_map.clone(), min, max).color_scale(self.style.color_scale)
+    }
+
+    /// 生成渲染图元：每个非空单元格一个六边形或矩形
+    pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        self.generate_scaled(None, None, plot_area)
+    }
+
+    /// 分箱始终按自身比例尺进行，外部比例尺只用于将单元格映射到屏幕
+    fn generate_scaled(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        let Some((x_scale, y_scale)) = self.scales() else {
+            return Vec::new();
+        };
+        let bins = self.bins();
+        let Some(range) = count_range(&bins) else {
+            return Vec::new();
+        };
+        let (sx, sy) = self.bin_size(&x_scale, &y_scale);
+        let x_scale = x.unwrap_or(&x_scale);
+        let y_scale = y.unwrap_or(&y_scale);
+
+        let (left, right) = (plot_area.x, plot_area.x + plot_area.width);
+        let (top, bottom) = (plot_area.y, plot_area.y + plot_area.height);
+        let to_screen = |x: f32, y: f32| {
+            Point2::new(
+                x_scale.to_pixel(x, left, right),
+                y_scale.to_pixel_inverted(y, top, bottom),
+            )
+        };
+
+        bins.iter()
+            .map(|bin| {
+                let t = self.style.color_scale.normalize(bin.count as f32, range);
+                let fill = self.style.color_map.get_color(t);
+                let (cx, cy) = bin.center;
+                match self.style.shape {
+                    BinShape::Rectangle => Primitive::RectangleStyled {
+                        min: to_screen(cx - sx / 2.0, cy + sy / 2.0),
+                        max: to_screen(cx + sx / 2.0, cy - sy / 2.0),
+                        fill,
+                        stroke: self.style.edge,
+                    },
+                    BinShape::Hexagon => {
+                        // 顶点相对中心的偏移（网格单位）：左右各半格，上下顶点距中心 1/3 格
+                        const OFFSETS: [(f32, f32); 6] = [
+                            (0.5, -1.0 / 6.0),
+                            (0.5, 1.0 / 6.0),
+                            (0.0, 1.0 / 3.0),
+                            (-0.5, 1.0 / 6.0),
+                            (-0.5, -1.0 / 6.0),
+                            (0.0, -1.0 / 3.0),
+                        ];
+                        Primitive::Polygon {
+                            points: OFFSETS
+                                .iter()
+                                .map(|&(du, dv)| to_screen(cx + du * sx, cy + dv * sy))
+                                .collect(),
+                            fill,
+                            stroke: self.style.edge,
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// 单元格计数的 (最小, 最大)
+fn count_range(bins: &[HexbinCell]) -> Option<(f32, f32)> {
+    let min = bins.iter().map(|b| b.count).min()?;
+    let max = bins.iter().map(|b| b.count).max()?;
+    Some((min as f32, max as f32))
+}
+
+/// 矩形单元格的下标；恰好落在范围上边界的点归入最后一格
+fn rect_index(t: f32, n: f32) -> i64 {
+    let index = t.floor();
+    if index >= n && t - n < 1e-4 {
+        n as i64 - 1
+    } else {
+        index as i64
+    }
+}
+
+impl Default for Hexbin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScaledPlot for Hexbin {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_scaled(x, y, plot_area)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        Hexbin::data_bounds(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexbin_counts_and_skips_empty_bins() {
+        // 两簇点：左下 3 个、右上 1 个，中间无数据
+        let data = [(0.0, 0.0), (0.05, 0.02), (0.02, 0.05), (1.0, 1.0)];
+        let hexbin = Hexbin::new().data(&data).gridsize(4);
+
+        let bins = hexbin.bins();
+        assert_eq!(bins.iter().map(|b| b.count).sum::<usize>(), 4);
+        assert_eq!(bins.iter().map(|b| b.count).max(), Some(3));
+        assert_eq!(hexbin.count_range(), Some((1.0, 3.0)));
+
+        let primitives = hexbin.generate_primitives(PlotArea::new(0.0, 0.0, 200.0, 200.0));
+        assert_eq!(primitives.len(), bins.len());
+        assert!(primitives
+            .iter()
+            .all(|p| matches!(p, Primitive::Polygon { points, .. } if points.len() == 6)));
+    }
+
+    #[test]
+    fn test_rectangular_bins() {
+        let data = [(0.1, 0.1), (0.2, 0.2), (0.9, 0.1)];
+        let hist = Hexbin::new()
+            .data(&data)
+            .shape(BinShape::Rectangle)
+            .gridsize(2)
+            .x_scale(LinearScale::new(0.0, 1.0))
+            .y_scale(LinearScale::new(0.0, 1.0));
+
+        let bins = hist.bins();
+        assert_eq!(
+            bins,
+            vec![
+                HexbinCell {
+                    center: (0.25, 0.25),
+                    count: 2
+                },
+                HexbinCell {
+                    center: (0.75, 0.25),
+                    count: 1
+                },
+            ]
+        );
+
+        // 计数最多的单元格取颜色映射的末端
+        let primitives = hist.generate_primitives(PlotArea::new(0.0, 0.0, 100.0, 100.0));
+        let Primitive::RectangleStyled { min, max, fill, .. } = &primitives[0] else {
+            panic!("expected rectangle");
+        };
+        assert_eq!((min.x, min.y, max.x, max.y), (0.0, 50.0, 50.0, 100.0));
+        assert_eq!(*fill, ColorMap::BlueGreen.get_color(1.0));
+    }
+}
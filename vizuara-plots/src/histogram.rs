@@ -1,8 +1,13 @@
+use crate::density::{kde, silverman_bandwidth, KernelType};
 use nalgebra::Point2;
-use vizuara_core::{Color, LinearScale, Primitive, Scale};
+use serde::{Deserialize, Serialize};
+use vizuara_core::{Color, HorizontalAlign, LinearScale, Primitive, Scale, VerticalAlign};
+
+/// 拟合曲线的采样段数
+const CURVE_SEGMENTS: usize = 120;
 
 /// 直方图数据桶
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistogramBin {
     /// 桶的起始值
     pub start: f32,
@@ -42,7 +47,7 @@ impl HistogramBin {
 }
 
 /// 直方图样式配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistogramStyle {
     /// 柱子填充颜色
     pub fill_color: Color,
@@ -52,6 +57,10 @@ pub struct HistogramStyle {
     pub stroke_width: f32,
     /// 柱子透明度
     pub alpha: f32,
+    /// 统计叠加层（均值线、拟合曲线等）颜色
+    pub overlay_color: Color,
+    /// 统计叠加层线宽
+    pub overlay_width: f32,
 }
 
 impl Default for HistogramStyle {
@@ -61,29 +70,53 @@ impl Default for HistogramStyle {
             stroke_color: Color::rgb(0.1, 0.3, 0.5),
             stroke_width: 1.0,
             alpha: 0.8,
+            overlay_color: Color::rgb(0.8, 0.2, 0.2),
+            overlay_width: 2.0,
         }
     }
 }
 
 /// 直方图分桶策略
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum BinningStrategy {
     /// 固定桶数量
     FixedCount(usize),
     /// 固定桶宽度
     FixedWidth(f32),
     /// 自动选择 (使用 Sturges 规则)
+    #[default]
     Auto,
+    /// 自定义桶边界（严格递增，n 个边界产生 n-1 个桶）
+    Edges(Vec<f32>),
 }
 
-impl Default for BinningStrategy {
-    fn default() -> Self {
-        Self::Auto
-    }
+/// 直方图归一化方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HistNorm {
+    /// 原始计数
+    #[default]
+    Count,
+    /// 概率：count / n，所有桶之和为 1
+    Probability,
+    /// 概率密度：count / (n * bin_width)，面积之和为 1
+    Density,
+}
+
+/// 直方图上的统计叠加层
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HistOverlay {
+    /// 均值处的竖线及标签
+    MeanLine,
+    /// 中位数处的竖线及标签
+    MedianLine,
+    /// 按样本均值和标准差拟合的正态分布曲线
+    NormalFit,
+    /// 高斯核密度估计曲线，参数为带宽；不大于 0 时按 Silverman 规则自动选择
+    Kde(f32),
 }
 
 /// 直方图
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Histogram {
     /// 原始数据
     data: Vec<f32>,
@@ -93,10 +126,24 @@ pub struct Histogram {
     style: HistogramStyle,
     /// 计算得到的桶数据
     bins: Vec<HistogramBin>,
+    /// 各桶计数之和，随桶数据一起更新
+    total: usize,
+    /// 归一化方式
+    normalization: HistNorm,
+    /// 自定义边界时是否将越界值归入首/末桶（否则丢弃）
+    clamp_out_of_range: bool,
     /// X轴比例尺
     x_scale: Option<LinearScale>,
     /// Y轴比例尺
     y_scale: Option<LinearScale>,
+    /// 固定的X轴范围，优先于自动适配的比例尺
+    #[serde(default)]
+    x_range: Option<(f32, f32)>,
+    /// 固定的Y轴范围，优先于自动适配的比例尺
+    #[serde(default)]
+    y_range: Option<(f32, f32)>,
+    /// 统计叠加层
+    overlays: Vec<HistOverlay>,
 }
 
 impl Histogram {
@@ -107,8 +154,14 @@ impl Histogram {
             binning: BinningStrategy::default(),
             style: HistogramStyle::default(),
             bins: Vec::new(),
+            total: 0,
+            normalization: HistNorm::default(),
+            clamp_out_of_range: false,
             x_scale: None,
             y_scale: None,
+            x_range: None,
+            y_range: None,
+            overlays: Vec::new(),
         }
     }
 
@@ -128,6 +181,37 @@ impl Histogram {
         self
     }
 
+    /// 使用自定义桶边界（支持非等宽分桶）
+    ///
+    /// 每个桶为左闭右开区间 `[start, end)`，最后一个桶包含右边界。
+    pub fn bin_edges(self, edges: &[f32]) -> Self {
+        self.binning(BinningStrategy::Edges(edges.to_vec()))
+    }
+
+    /// 自定义边界时越界值是否归入首/末桶（默认丢弃）
+    pub fn clamp_out_of_range(mut self, clamp: bool) -> Self {
+        self.clamp_out_of_range = clamp;
+        if !self.data.is_empty() {
+            self.compute_bins();
+        }
+        self
+    }
+
+    /// 设置归一化方式；已调用 `auto_scale` 时会按新的高度重新适配比例尺
+    pub fn normalization(mut self, norm: HistNorm) -> Self {
+        self.normalization = norm;
+        self.refit_scales();
+        self
+    }
+
+    /// 添加统计叠加层，曲线按当前归一化方式缩放以与柱子对齐；
+    /// 已调用 `auto_scale` 时会重新适配比例尺以容纳曲线峰值
+    pub fn overlay(mut self, overlay: HistOverlay) -> Self {
+        self.overlays.push(overlay);
+        self.refit_scales();
+        self
+    }
+
     /// 设置样式
     pub fn style(mut self, style: HistogramStyle) -> Self {
         self.style = style;
@@ -146,7 +230,21 @@ impl Histogram {
         self
     }
 
+    /// 固定X轴范围（如坐标轴范围），不随数据重新适配
+    pub fn x_range(mut self, min: f32, max: f32) -> Self {
+        self.x_range = Some((min, max));
+        self
+    }
+
+    /// 固定Y轴范围（如坐标轴范围），不随数据重新适配
+    pub fn y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+
     /// 自动计算比例尺
+    ///
+    /// 之后修改数据、分桶、归一化方式或叠加层时会自动重新适配，调用顺序不影响结果
     pub fn auto_scale(mut self) -> Self {
         if self.bins.is_empty() {
             return self;
@@ -156,22 +254,224 @@ impl Histogram {
         let min_val = self.bins.first().unwrap().start;
         let max_val = self.bins.last().unwrap().end;
 
-        // 计算Y轴范围 (频次范围)
-        let max_count = self.bins.iter().map(|b| b.count).max().unwrap_or(0);
+        // 计算Y轴范围 (归一化后的高度范围，包含拟合曲线的峰值)
+        let max_height = self.max_height();
 
         self.x_scale = Some(LinearScale::new(min_val, max_val));
-        self.y_scale = Some(LinearScale::new(0.0, max_count as f32));
+        self.y_scale = Some(LinearScale::new(0.0, max_height));
 
         self
     }
 
-    /// 计算桶数据
+    /// 已自动适配过比例尺时，按当前的桶与高度重新适配
+    fn refit_scales(&mut self) {
+        if self.x_scale.is_none() && self.y_scale.is_none() {
+            return;
+        }
+        if self.bins.is_empty() {
+            self.x_scale = None;
+            self.y_scale = None;
+        } else {
+            *self = std::mem::take(self).auto_scale();
+        }
+    }
+
+    /// 获取桶按当前归一化方式计算的高度
+    pub fn bin_height(&self, bin: &HistogramBin) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        match self.normalization {
+            HistNorm::Count => bin.count as f32,
+            HistNorm::Probability => bin.count as f32 / self.total as f32,
+            HistNorm::Density => bin.density / self.total as f32,
+        }
+    }
+
+    /// 最大桶高度（含拟合曲线）
+    fn max_height(&self) -> f32 {
+        let bars = self
+            .bins
+            .iter()
+            .map(|b| self.bin_height(b))
+            .fold(0.0, f32::max);
+        self.overlays
+            .iter()
+            .filter_map(|&overlay| self.overlay_curve(overlay))
+            .flatten()
+            .map(|(_, y)| y)
+            .fold(bars, f32::max)
+    }
+
+    /// 参与统计的有效数据
+    fn finite_data(&self) -> Vec<f32> {
+        self.data
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect()
+    }
+
+    /// 样本均值
+    pub fn mean(&self) -> Option<f32> {
+        let data = self.finite_data();
+        if data.is_empty() {
+            return None;
+        }
+        Some(data.iter().sum::<f32>() / data.len() as f32)
+    }
+
+    /// 样本中位数
+    pub fn median(&self) -> Option<f32> {
+        let mut data = self.finite_data();
+        if data.is_empty() {
+            return None;
+        }
+        data.sort_by(|a, b| a.total_cmp(b));
+        let mid = data.len() / 2;
+        if data.len() % 2 == 1 {
+            Some(data[mid])
+        } else {
+            Some((data[mid - 1] + data[mid]) / 2.0)
+        }
+    }
+
+    /// 样本标准差（n - 1 自由度）
+    pub fn std_dev(&self) -> Option<f32> {
+        let data = self.finite_data();
+        if data.len() < 2 {
+            return None;
+        }
+        let mean = data.iter().sum::<f32>() / data.len() as f32;
+        let variance =
+            data.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / (data.len() - 1) as f32;
+        Some(variance.sqrt())
+    }
+
+    /// 概率密度换算为当前归一化方式下柱高的系数（取 x 所在桶的宽度）
+    fn density_factor(&self, x: f32) -> f32 {
+        let width = self
+            .bins
+            .iter()
+            .find(|b| x >= b.start && x <= b.end)
+            .or_else(|| {
+                if x < self.bins.first()?.start {
+                    self.bins.first()
+                } else {
+                    self.bins.last()
+                }
+            })
+            .map_or(0.0, |b| b.width());
+
+        match self.normalization {
+            HistNorm::Count => self.total as f32 * width,
+            HistNorm::Probability => width,
+            HistNorm::Density => 1.0,
+        }
+    }
+
+    /// 拟合曲线在数据坐标下的采样点 (x, 柱高单位的 y)；竖线类叠加层或数据不足时返回 None
+    fn overlay_curve(&self, overlay: HistOverlay) -> Option<Vec<(f32, f32)>> {
+        let density: Box<dyn Fn(f32) -> f32> = match overlay {
+            HistOverlay::MeanLine | HistOverlay::MedianLine => return None,
+            HistOverlay::NormalFit => {
+                let mean = self.mean()?;
+                let std = self.std_dev().filter(|&s| s > 0.0)?;
+                let norm = 1.0 / (std * (2.0 * std::f32::consts::PI).sqrt());
+                Box::new(move |x| norm * (-0.5 * ((x - mean) / std).powi(2)).exp())
+            }
+            HistOverlay::Kde(bandwidth) => {
+                let data = self.finite_data();
+                if data.is_empty() {
+                    return None;
+                }
+                let bandwidth = if bandwidth > 0.0 {
+                    bandwidth
+                } else {
+                    silverman_bandwidth(&data)
+                };
+                Box::new(move |x| kde(&data, x, bandwidth, KernelType::Gaussian))
+            }
+        };
+
+        let start = self.bins.first()?.start;
+        let end = self.bins.last()?.end;
+        let step = (end - start) / CURVE_SEGMENTS as f32;
+        Some(
+            (0..=CURVE_SEGMENTS)
+                .map(|i| {
+                    let x = start + i as f32 * step;
+                    (x, density(x) * self.density_factor(x))
+                })
+                .collect(),
+        )
+    }
+
+    /// 按自定义边界计算桶数据
+    fn compute_bins_from_edges(&mut self, edges: &[f32]) {
+        let mut edges: Vec<f32> = edges.iter().copied().filter(|e| e.is_finite()).collect();
+        edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        edges.dedup();
+
+        if edges.len() < 2 {
+            self.bins.clear();
+            return;
+        }
+
+        let last = edges.len() - 2;
+        let mut bins: Vec<HistogramBin> = edges
+            .windows(2)
+            .map(|w| HistogramBin::new(w[0], w[1], 0))
+            .collect();
+
+        for &value in self.data.iter().filter(|v| !v.is_nan()) {
+            let index = if value < edges[0] {
+                self.clamp_out_of_range.then_some(0)
+            } else if value > edges[last + 1] {
+                self.clamp_out_of_range.then_some(last)
+            } else {
+                // 左闭右开：落在边界上的值归入右侧的桶，最右边界归入最后一个桶
+                Some(
+                    edges
+                        .partition_point(|&e| e <= value)
+                        .saturating_sub(1)
+                        .min(last),
+                )
+            };
+
+            if let Some(index) = index {
+                bins[index].count += 1;
+            }
+        }
+
+        for bin in &mut bins {
+            *bin = HistogramBin::new(bin.start, bin.end, bin.count);
+        }
+
+        self.bins = bins;
+    }
+
+    /// 计算桶数据，并更新计数总和与已适配的比例尺
     fn compute_bins(&mut self) {
+        self.fill_bins();
+        self.total = self.bins.iter().map(|b| b.count).sum();
+        self.refit_scales();
+    }
+
+    /// 按分桶策略把数据分配到桶中
+    fn fill_bins(&mut self) {
         if self.data.is_empty() {
             self.bins.clear();
             return;
         }
 
+        if let BinningStrategy::Edges(edges) = &self.binning {
+            let edges = edges.clone();
+            self.compute_bins_from_edges(&edges);
+            return;
+        }
+
         // 计算数据范围
         let min_val = self.data.iter().cloned().fold(f32::INFINITY, f32::min);
         let max_val = self.data.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
@@ -194,6 +494,7 @@ impl Histogram {
                     (n as f32).log2().ceil() as usize + 1
                 }
             }
+            BinningStrategy::Edges(_) => unreachable!(),
         };
 
         let bin_count = bin_count.max(1); // 至少1个桶
@@ -239,6 +540,13 @@ impl Histogram {
         &self.bins
     }
 
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)：X 为桶边界范围，
+    /// Y 从 0 到按当前归一化方式计算的最大高度（含拟合曲线）。没有桶时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let (first, last) = (self.bins.first()?, self.bins.last()?);
+        Some((first.start, last.end, 0.0, self.max_height()))
+    }
+
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: super::PlotArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
@@ -247,8 +555,10 @@ impl Histogram {
             return primitives;
         }
 
-        // 获取或创建比例尺
-        let x_scale = if let Some(ref scale) = self.x_scale {
+        // 获取或创建比例尺：固定范围优先
+        let x_scale = if let Some((min, max)) = self.x_range {
+            LinearScale::new(min, max)
+        } else if let Some(ref scale) = self.x_scale {
             scale.clone()
         } else {
             let min_val = self.bins.first().unwrap().start;
@@ -256,11 +566,12 @@ impl Histogram {
             LinearScale::new(min_val, max_val)
         };
 
-        let y_scale = if let Some(ref scale) = self.y_scale {
+        let y_scale = if let Some((min, max)) = self.y_range {
+            LinearScale::new(min, max)
+        } else if let Some(ref scale) = self.y_scale {
             scale.clone()
         } else {
-            let max_count = self.bins.iter().map(|b| b.count).max().unwrap_or(0);
-            LinearScale::new(0.0, max_count as f32)
+            LinearScale::new(0.0, self.max_height())
         };
 
         // 为每个桶创建矩形
@@ -270,14 +581,12 @@ impl Histogram {
             }
 
             // 计算屏幕坐标
-            let x_norm_start = x_scale.normalize(bin.start);
-            let x_norm_end = x_scale.normalize(bin.end);
-            let y_norm = y_scale.normalize(bin.count as f32);
-
-            let screen_x_start = plot_area.x + x_norm_start * plot_area.width;
-            let screen_x_end = plot_area.x + x_norm_end * plot_area.width;
-            let screen_y_bottom = plot_area.y + plot_area.height; // 底部
-            let screen_y_top = plot_area.y + plot_area.height - y_norm * plot_area.height;
+            let (left, right) = (plot_area.x, plot_area.x + plot_area.width);
+            let (top, bottom) = (plot_area.y, plot_area.y + plot_area.height);
+            let screen_x_start = x_scale.to_pixel(bin.start, left, right);
+            let screen_x_end = x_scale.to_pixel(bin.end, left, right);
+            let screen_y_bottom = bottom; // 底部
+            let screen_y_top = y_scale.to_pixel_inverted(self.bin_height(bin), top, bottom);
 
             // 创建矩形图元
             primitives.push(Primitive::RectangleStyled {
@@ -288,8 +597,67 @@ impl Histogram {
             });
         }
 
+        for &overlay in &self.overlays {
+            primitives.extend(self.overlay_primitives(overlay, plot_area, &x_scale, &y_scale));
+        }
+
         primitives
     }
+
+    /// 生成单个统计叠加层的图元
+    fn overlay_primitives(
+        &self,
+        overlay: HistOverlay,
+        plot_area: super::PlotArea,
+        x_scale: &LinearScale,
+        y_scale: &LinearScale,
+    ) -> Vec<Primitive> {
+        let (left, right) = (plot_area.x, plot_area.x + plot_area.width);
+        let (top, bottom) = (plot_area.y, plot_area.y + plot_area.height);
+
+        let statistic = match overlay {
+            HistOverlay::MeanLine => self.mean().map(|v| ("均值", v)),
+            HistOverlay::MedianLine => self.median().map(|v| ("中位数", v)),
+            HistOverlay::NormalFit | HistOverlay::Kde(_) => {
+                let Some(curve) = self.overlay_curve(overlay) else {
+                    return Vec::new();
+                };
+                return vec![Primitive::Polyline {
+                    points: curve
+                        .into_iter()
+                        .map(|(x, y)| {
+                            Point2::new(
+                                x_scale.to_pixel(x, left, right),
+                                y_scale.to_pixel_inverted(y, top, bottom),
+                            )
+                        })
+                        .collect(),
+                    color: self.style.overlay_color,
+                    width: self.style.overlay_width,
+                }];
+            }
+        };
+
+        let Some((name, value)) = statistic else {
+            return Vec::new();
+        };
+        let x = x_scale.to_pixel(value, left, right);
+        vec![
+            Primitive::Line {
+                start: Point2::new(x, top),
+                end: Point2::new(x, bottom),
+            },
+            Primitive::Text {
+                position: Point2::new(x + 4.0, top),
+                content: format!("{} {:.2}", name, value),
+                size: 11.0,
+                color: self.style.overlay_color,
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Top,
+                rotation: 0.0,
+            },
+        ]
+    }
 }
 
 impl Default for Histogram {
@@ -301,6 +669,7 @@ impl Default for Histogram {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::PlotArea;
 
     #[test]
     fn test_histogram_creation() {
@@ -373,4 +742,161 @@ mod tests {
         // 应该有2个桶的矩形 (如果两个桶都有数据)
         assert!(!primitives.is_empty() && primitives.len() <= 2);
     }
+
+    #[test]
+    fn test_custom_bin_edges() {
+        let data = vec![-1.0, 0.0, 0.5, 1.0, 3.0, 10.0, 12.0];
+        let hist = Histogram::new().data(&data).bin_edges(&[0.0, 1.0, 10.0]);
+
+        // [0, 1) 包含 0.0、0.5；[1, 10] 包含 1.0、3.0、10.0；-1.0 与 12.0 被丢弃
+        let counts: Vec<usize> = hist.bins().iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![2, 3]);
+        assert_eq!(hist.data_bounds(), Some((0.0, 10.0, 0.0, 3.0)));
+
+        let clamped = hist.clamp_out_of_range(true);
+        let counts: Vec<usize> = clamped.bins().iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_density_normalization() {
+        let data = vec![0.5, 1.5, 1.6, 3.0];
+        let hist = Histogram::new()
+            .data(&data)
+            .bin_edges(&[0.0, 1.0, 2.0, 4.0])
+            .normalization(HistNorm::Density);
+
+        // 面积之和为 1
+        let area: f32 = hist
+            .bins()
+            .iter()
+            .map(|b| hist.bin_height(b) * b.width())
+            .sum();
+        assert!((area - 1.0).abs() < 1e-6);
+
+        let hist = hist.normalization(HistNorm::Probability);
+        let total: f32 = hist.bins().iter().map(|b| hist.bin_height(b)).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+        assert!((hist.bin_height(&hist.bins()[1]) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fixed_range_overrides_auto_scale() {
+        let area = PlotArea::new(0.0, 0.0, 200.0, 100.0);
+        let rect_x = |hist: &Histogram| -> (f32, f32) {
+            hist.generate_primitives(area)
+                .iter()
+                .filter_map(|p| match p {
+                    Primitive::RectangleStyled { min, max, .. } => Some((min.x, max.x)),
+                    _ => None,
+                })
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), (a, b)| {
+                    (lo.min(a), hi.max(b))
+                })
+        };
+
+        let data = [0.0, 0.5, 1.0];
+        let auto = Histogram::new().data(&data).auto_scale();
+        assert_eq!(rect_x(&auto), (0.0, 200.0));
+
+        // 固定范围在之后的修改中保持不变
+        let fixed = Histogram::new()
+            .data(&data)
+            .auto_scale()
+            .x_range(0.0, 2.0)
+            .normalization(HistNorm::Density);
+        assert_eq!(rect_x(&fixed), (0.0, 100.0));
+    }
+
+    #[test]
+    fn test_auto_scale_order_independent() {
+        let data = vec![0.5, 1.5, 1.6, 3.0];
+        let before = Histogram::new()
+            .data(&data)
+            .normalization(HistNorm::Density)
+            .overlay(HistOverlay::NormalFit)
+            .auto_scale();
+        let after = Histogram::new()
+            .data(&data)
+            .auto_scale()
+            .normalization(HistNorm::Density)
+            .overlay(HistOverlay::NormalFit);
+
+        let area = PlotArea::new(0.0, 0.0, 200.0, 100.0);
+        assert_eq!(
+            before.generate_primitives(area),
+            after.generate_primitives(area)
+        );
+        assert_eq!(before.data_bounds(), after.data_bounds());
+
+        // 修改数据后比例尺跟随新的桶范围
+        let refit = after.data(&[10.0, 20.0]);
+        let (x_min, x_max, _, _) = refit.data_bounds().unwrap();
+        let expected = Histogram::new()
+            .data(&[10.0, 20.0])
+            .normalization(HistNorm::Density)
+            .overlay(HistOverlay::NormalFit)
+            .auto_scale();
+        assert_eq!((x_min, x_max), (10.0, 20.0));
+        assert_eq!(
+            refit.generate_primitives(area),
+            expected.generate_primitives(area)
+        );
+    }
+
+    #[test]
+    fn test_statistical_overlays() {
+        let data: Vec<f32> = (0..200)
+            .map(|i| ((i as f32 + 0.5) / 200.0 * 6.0 - 3.0).powi(3))
+            .collect();
+        let base = Histogram::new()
+            .data(&data)
+            .binning(BinningStrategy::FixedWidth(2.0));
+        assert!(base.mean().unwrap().abs() < 1e-3);
+        assert!(base.median().unwrap().abs() < 1e-2);
+
+        // 拟合曲线面积与柱子面积一致：计数归一化下积分 / 桶宽 ≈ 样本数
+        let integral = |hist: &Histogram, overlay| -> f32 {
+            let curve = hist.overlay_curve(overlay).unwrap();
+            curve
+                .windows(2)
+                .map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) / 2.0)
+                .sum()
+        };
+        let counted = base.clone().overlay(HistOverlay::NormalFit);
+        let bin_width = counted.bins()[0].width();
+        let n = integral(&counted, HistOverlay::NormalFit) / bin_width;
+        assert!((n - 200.0).abs() < 200.0 * 0.05, "n = {}", n);
+
+        let density = base.clone().normalization(HistNorm::Density);
+        let area = integral(&density, HistOverlay::Kde(1.0));
+        assert!((area - 1.0).abs() < 0.05, "area = {}", area);
+
+        // 均值线位于对应 X 坐标，并带标签；y 轴范围包含曲线峰值
+        let hist = base
+            .overlay(HistOverlay::MeanLine)
+            .overlay(HistOverlay::NormalFit)
+            .auto_scale();
+        let peak = hist
+            .overlay_curve(HistOverlay::NormalFit)
+            .unwrap()
+            .into_iter()
+            .map(|(_, y)| y)
+            .fold(0.0, f32::max);
+        assert!(hist.y_scale.as_ref().unwrap().domain_max >= peak);
+
+        let primitives = hist.generate_primitives(crate::PlotArea::new(0.0, 0.0, 100.0, 100.0));
+        let x_scale = hist.x_scale.clone().unwrap();
+        let expected_x = x_scale.to_pixel(hist.mean().unwrap(), 0.0, 100.0);
+        assert!(primitives.iter().any(|p| matches!(
+            p,
+            Primitive::Line { start, end } if start.x == expected_x && end.x == expected_x
+        )));
+        assert!(primitives
+            .iter()
+            .any(|p| matches!(p, Primitive::Text { content, .. } if content.starts_with("均值"))));
+        assert!(primitives
+            .iter()
+            .any(|p| matches!(p, Primitive::Polyline { points, .. } if points.len() == 121)));
+    }
 }
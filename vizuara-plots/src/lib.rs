@@ -5,31 +5,47 @@
 pub mod area;
 pub mod bar;
 pub mod boxplot;
+pub mod candlestick;
+pub mod colorbar;
 pub mod contour;
 pub mod density;
+pub mod gauge;
 pub mod heatmap;
+pub mod hexbin;
 pub mod histogram;
 pub mod line;
+pub mod palette;
 pub mod parallel;
 pub mod pie;
 pub mod radar;
 pub mod sankey;
+pub mod scaled;
 pub mod scatter;
+pub mod stem;
+pub mod step;
 pub mod treemap;
 pub mod violin;
 
 pub use area::*;
 pub use bar::*;
 pub use boxplot::*;
+pub use candlestick::*;
+pub use colorbar::*;
 pub use contour::*;
 pub use density::*;
+pub use gauge::*;
 pub use heatmap::*;
+pub use hexbin::*;
 pub use histogram::*;
 pub use line::*;
+pub use palette::*;
 pub use parallel::*;
 pub use pie::*;
 pub use radar::*;
 pub use sankey::*;
+pub use scaled::*;
 pub use scatter::*;
+pub use stem::*;
+pub use step::*;
 pub use treemap::*;
 pub use violin::*;
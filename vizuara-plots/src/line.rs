@@ -1,16 +1,28 @@
 use nalgebra::Point2;
-use vizuara_core::{Color, LinearScale, Primitive, Scale};
+use serde::{Deserialize, Serialize};
+use vizuara_core::{
+    Color, LineStyle, LinearScale, MarkerStyle, Primitive, Scale, Style, TimeScale,
+};
 
 /// 折线图数据点（重用 scatter 的 DataPoint）
 pub use crate::scatter::DataPoint;
+use crate::palette::DEFAULT_SERIES_COLORS;
+use crate::scaled::{resolve_scale, y_range_in, ScaledPlot};
+use crate::scatter::point_bounds;
 
 /// 折线图样式
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinePlotStyle {
     pub color: Color,
     pub width: f32,
     pub style: vizuara_core::LineStyle,
     pub alpha: f32,
+    /// 数据点标记形状
+    pub marker: MarkerStyle,
+    /// 数据点标记大小（像素）
+    pub marker_size: f32,
+    /// 数据点标记颜色，未设置时与线条颜色相同
+    pub marker_color: Option<Color>,
 }
 
 impl Default for LinePlotStyle {
@@ -20,18 +32,55 @@ impl Default for LinePlotStyle {
             width: 2.0,
             style: vizuara_core::LineStyle::Solid,
             alpha: 1.0,
+            marker: MarkerStyle::Circle,
+            marker_size: 6.0,
+            marker_color: None,
         }
     }
 }
 
+/// 折线图的绘制方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineDrawMode {
+    /// 仅连线
+    #[default]
+    Line,
+    /// 仅在数据点处绘制标记
+    Markers,
+    /// 连线并在数据点处绘制标记（类似 matplotlib 的 '-o'）
+    LineAndMarkers,
+}
+
+/// 默认的降采样触发点数
+const DEFAULT_DOWNSAMPLE_THRESHOLD: usize = 5000;
+
+/// 密集数据的降采样方式：点数减少到约等于绘图区域的像素列数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DownsampleMode {
+    /// 不降采样
+    #[default]
+    None,
+    /// Largest-Triangle-Three-Buckets：每个桶保留与相邻选中点构成最大三角形的点，
+    /// 能较好地保留曲线形状与峰值
+    Lttb,
+    /// 每个像素列保留最小值与最大值
+    MinMax,
+}
+
 /// 折线图
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinePlot {
     data: Vec<DataPoint>,
     style: LinePlotStyle,
     x_scale: Option<LinearScale>,
     y_scale: Option<LinearScale>,
     smooth: bool,
+    downsample: DownsampleMode,
+    downsample_threshold: usize,
+    #[serde(default)]
+    draw_mode: LineDrawMode,
+    #[serde(default)]
+    x_time_scale: Option<TimeScale>,
 }
 
 impl LinePlot {
@@ -43,6 +92,10 @@ impl LinePlot {
             x_scale: None,
             y_scale: None,
             smooth: false,
+            downsample: DownsampleMode::None,
+            downsample_threshold: DEFAULT_DOWNSAMPLE_THRESHOLD,
+            draw_mode: LineDrawMode::Line,
+            x_time_scale: None,
         }
     }
 
@@ -106,6 +159,42 @@ impl LinePlot {
         self
     }
 
+    /// 设置密集数据的降采样方式，仅在点数超过阈值时生效
+    pub fn downsample(mut self, mode: DownsampleMode) -> Self {
+        self.downsample = mode;
+        self
+    }
+
+    /// 设置触发降采样的点数阈值（默认 5000）
+    pub fn downsample_threshold(mut self, threshold: usize) -> Self {
+        self.downsample_threshold = threshold;
+        self
+    }
+
+    /// 设置绘制方式：连线、标记或两者兼有
+    pub fn draw_mode(mut self, mode: LineDrawMode) -> Self {
+        self.draw_mode = mode;
+        self
+    }
+
+    /// 设置数据点标记形状
+    pub fn marker(mut self, marker: MarkerStyle) -> Self {
+        self.style.marker = marker;
+        self
+    }
+
+    /// 设置数据点标记大小（像素）
+    pub fn marker_size(mut self, size: f32) -> Self {
+        self.style.marker_size = size;
+        self
+    }
+
+    /// 设置数据点标记颜色
+    pub fn marker_color(mut self, color: Color) -> Self {
+        self.style.marker_color = Some(color);
+        self
+    }
+
     /// 设置 X 轴比例尺
     pub fn x_scale(mut self, scale: LinearScale) -> Self {
         self.x_scale = Some(scale);
@@ -118,13 +207,51 @@ impl LinePlot {
         self
     }
 
+    /// 设置时间序列数据：X 为纪元毫秒，经 X 轴时间比例尺（未设置时由数据创建）换算为数据值
+    pub fn time_data(mut self, data: &[(i64, f32)]) -> Self {
+        let time = self.x_time_scale.take().unwrap_or_else(|| {
+            let timestamps: Vec<i64> = data.iter().map(|&(t, _)| t).collect();
+            TimeScale::from_timestamps(&timestamps)
+        });
+        self.data = data
+            .iter()
+            .map(|&(t, y)| DataPoint::new(time.offset(t), y))
+            .collect();
+        self.data
+            .sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal));
+        self.x_scale = Some(time.linear());
+        self.x_time_scale = Some(time);
+        self
+    }
+
+    /// 设置 X 轴时间比例尺；已按旧比例尺换算的时间数据随 `origin` 平移
+    pub fn x_time_scale(mut self, scale: TimeScale) -> Self {
+        if let Some(previous) = &self.x_time_scale {
+            let shift = (previous.origin - scale.origin) as f32;
+            for point in &mut self.data {
+                point.x += shift;
+            }
+        }
+        self.x_scale = Some(scale.linear());
+        self.x_time_scale = Some(scale);
+        self
+    }
+
+    /// X 轴时间比例尺（未使用时间数据时为 None），可用于创建对应的时间坐标轴
+    pub fn time_scale(&self) -> Option<&TimeScale> {
+        self.x_time_scale.as_ref()
+    }
+
     /// 自动计算比例尺
     pub fn auto_scale(mut self) -> Self {
         if !self.data.is_empty() {
             let x_values: Vec<f32> = self.data.iter().map(|p| p.x).collect();
             let y_values: Vec<f32> = self.data.iter().map(|p| p.y).collect();
 
-            self.x_scale = Some(LinearScale::from_data(&x_values));
+            self.x_scale = Some(match &self.x_time_scale {
+                Some(time) => time.linear(),
+                None => LinearScale::from_data(&x_values),
+            });
             self.y_scale = Some(LinearScale::from_data(&y_values));
         }
         self
@@ -132,76 +259,330 @@ impl LinePlot {
 
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: crate::PlotArea) -> Vec<Primitive> {
+        self.generate_styled(None, None, plot_area, &self.style)
+    }
+
+    /// 按给定比例尺与样式生成图元；多系列折线图借此覆盖系列的颜色与线型而无需克隆数据
+    fn generate_styled(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: crate::PlotArea,
+        style: &LinePlotStyle,
+    ) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
-        if self.data.len() < 2 {
-            return primitives; // 需要至少2个点才能画线
+        let draw_line = self.draw_mode != LineDrawMode::Markers;
+        let draw_markers = self.draw_mode != LineDrawMode::Line;
+        if self.data.is_empty() || (!draw_markers && self.data.len() < 2) {
+            return primitives; // 仅连线时需要至少2个点
         }
 
         // 获取或创建比例尺
-        let x_scale = if let Some(ref scale) = self.x_scale {
-            scale.clone()
-        } else {
-            let x_values: Vec<f32> = self.data.iter().map(|p| p.x).collect();
-            LinearScale::from_data(&x_values)
-        };
+        let (mut fitted_x, mut fitted_y) = (None, None);
+        let x_scale = resolve_scale(x, self.x_scale.as_ref(), &mut fitted_x, || {
+            self.data.iter().map(|p| p.x).collect()
+        });
+        let y_scale = resolve_scale(y, self.y_scale.as_ref(), &mut fitted_y, || {
+            self.data.iter().map(|p| p.y).collect()
+        });
 
-        let y_scale = if let Some(ref scale) = self.y_scale {
-            scale.clone()
-        } else {
-            let y_values: Vec<f32> = self.data.iter().map(|p| p.y).collect();
-            LinearScale::from_data(&y_values)
-        };
+        // 降采样的目标点数约为像素列数
+        let columns = plot_area.width.round().max(3.0) as usize;
+        let downsample = self.data.len() > self.downsample_threshold;
 
-        // 转换数据点到屏幕坐标
-        let screen_points: Vec<Point2<f32>> = self
-            .data
-            .iter()
-            .map(|point| {
-                let x_norm = x_scale.normalize(point.x);
-                let y_norm = y_scale.normalize(point.y);
+        let mut markers: Vec<Point2<f32>> = Vec::new();
+
+        // 非实线按虚线模式切分为带颜色的多段线
+        let dash = style.style.dash_pattern(style.width);
+        let line_color = style.color;
+        let line_color = Color::rgba(
+            line_color.r,
+            line_color.g,
+            line_color.b,
+            line_color.a * style.alpha,
+        );
 
-                // 将归一化坐标映射到绘图区域
-                let screen_x = plot_area.x + x_norm * plot_area.width;
-                // Y轴翻转：屏幕坐标系是从上到下，而数据坐标系是从下到上
-                let screen_y = plot_area.y + plot_area.height - y_norm * plot_area.height;
+        // 缺失数据（NaN）处断开，每段连续数据生成一条线，孤立的点单独绘制
+        for run in self.data.split(|point| !point.is_finite()) {
+            let screen_points: Vec<Point2<f32>> = run
+                .iter()
+                .map(|point| {
+                    let screen_x =
+                        x_scale.to_pixel(point.x, plot_area.x, plot_area.x + plot_area.width);
+                    // Y轴翻转：屏幕坐标系是从上到下，而数据坐标系是从下到上
+                    let screen_y = y_scale.to_pixel_inverted(
+                        point.y,
+                        plot_area.y,
+                        plot_area.y + plot_area.height,
+                    );
 
-                Point2::new(screen_x, screen_y)
-            })
-            .collect();
+                    Point2::new(screen_x, screen_y)
+                })
+                .collect();
+
+            let screen_points = match self.downsample {
+                DownsampleMode::Lttb if downsample => lttb(&screen_points, columns),
+                DownsampleMode::MinMax if downsample => min_max_per_column(&screen_points),
+                _ => screen_points,
+            };
 
-        // 创建线条图元
-        if screen_points.len() >= 2 {
-            primitives.push(Primitive::LineStrip(screen_points));
+            if draw_markers {
+                markers.extend_from_slice(&screen_points);
+            }
+            if draw_line {
+                match screen_points.len() {
+                    0 => {}
+                    // 孤立的点已有标记时不再单独绘制
+                    1 if draw_markers => {}
+                    1 => primitives.push(Primitive::Point(screen_points[0])),
+                    _ => match &dash {
+                        Some(pattern) => primitives.extend(
+                            dash_segments(&screen_points, pattern)
+                                .into_iter()
+                                .map(|points| Primitive::Polyline {
+                                    points,
+                                    color: line_color,
+                                    width: style.width,
+                                }),
+                        ),
+                        None => primitives.push(Primitive::LineStrip(screen_points)),
+                    },
+                }
+            }
+        }
+
+        // 标记绘制在线条之上
+        let color = style.marker_color.unwrap_or(style.color);
+        let color = Color::rgba(color.r, color.g, color.b, color.a * style.alpha);
+        for center in markers {
+            push_marker(
+                &mut primitives,
+                style.marker,
+                center,
+                style.marker_size,
+                color,
+            );
         }
 
         primitives
     }
 
-    /// 获取数据的边界
-    pub fn data_bounds(&self) -> Option<(DataPoint, DataPoint)> {
-        if self.data.is_empty() {
-            return None;
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)，忽略缺失值；没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        point_bounds(self.data.iter().map(|p| (p.x, p.y)))
+    }
+
+    /// 获取数据点数量
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl ScaledPlot for LinePlot {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: crate::PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_styled(x, y, plot_area, &self.style)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        LinePlot::data_bounds(self)
+    }
+
+    fn y_range_within(&self, x_min: f32, x_max: f32) -> Option<(f32, f32)> {
+        y_range_in(self.data.iter().map(|p| (p.x, p.y)), x_min, x_max)
+    }
+
+    /// 折线与孤立点使用线条颜色与宽度
+    fn primitive_style(&self) -> Style {
+        let color = self.style.color;
+        let color = Color::rgba(color.r, color.g, color.b, color.a * self.style.alpha);
+        Style {
+            fill_color: Some(color),
+            stroke_color: Some(color),
+            stroke_width: self.style.width,
+            marker_size: self.style.width,
+            ..Style::default()
         }
+    }
+}
 
-        let mut min_x = self.data[0].x;
-        let mut max_x = self.data[0].x;
-        let mut min_y = self.data[0].y;
-        let mut max_y = self.data[0].y;
+/// Largest-Triangle-Three-Buckets 降采样：保留首尾点，其余点均分为 target - 2 个桶，
+/// 每桶选取与上一个选中点、下一桶均值点构成三角形面积最大的点
+fn lttb(points: &[Point2<f32>], target: usize) -> Vec<Point2<f32>> {
+    let n = points.len();
+    if target < 3 || n <= target {
+        return points.to_vec();
+    }
 
-        for point in &self.data {
-            min_x = min_x.min(point.x);
-            max_x = max_x.max(point.x);
-            min_y = min_y.min(point.y);
-            max_y = max_y.max(point.y);
+    let bucket_size = (n - 2) as f32 / (target - 2) as f32;
+    let bucket_start = |i: usize| ((i as f32 * bucket_size) as usize + 1).min(n - 1);
+
+    let mut sampled = Vec::with_capacity(target);
+    sampled.push(points[0]);
+    let mut anchor = points[0];
+
+    for i in 0..target - 2 {
+        let (start, end) = (bucket_start(i), bucket_start(i + 1));
+        // 下一桶的均值点；最后一个桶之后为末点
+        let next = if i + 3 == target {
+            &points[n - 1..]
+        } else {
+            &points[end..bucket_start(i + 2)]
+        };
+        let count = next.len() as f32;
+        let avg_x = next.iter().map(|p| p.x).sum::<f32>() / count;
+        let avg_y = next.iter().map(|p| p.y).sum::<f32>() / count;
+
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (j, p) in points.iter().enumerate().take(end).skip(start) {
+            let area = ((anchor.x - avg_x) * (p.y - anchor.y)
+                - (anchor.x - p.x) * (avg_y - anchor.y))
+                .abs();
+            if area > best_area {
+                best_area = area;
+                best = j;
+            }
         }
 
-        Some((DataPoint::new(min_x, min_y), DataPoint::new(max_x, max_y)))
+        anchor = points[best];
+        sampled.push(anchor);
     }
 
-    /// 获取数据点数量
-    pub fn data_len(&self) -> usize {
-        self.data.len()
+    sampled.push(points[n - 1]);
+    sampled
+}
+
+/// 按屏幕像素列分组（点需按 X 有序），每列保留 Y 最小与最大的点并保持原有顺序
+fn min_max_per_column(points: &[Point2<f32>]) -> Vec<Point2<f32>> {
+    let mut sampled = Vec::new();
+    let mut start = 0;
+
+    while start < points.len() {
+        let column = points[start].x.floor();
+        let (mut end, mut low, mut high) = (start, start, start);
+        while end < points.len() && points[end].x.floor() == column {
+            if points[end].y < points[low].y {
+                low = end;
+            }
+            if points[end].y > points[high].y {
+                high = end;
+            }
+            end += 1;
+        }
+
+        sampled.push(points[low.min(high)]);
+        if low != high {
+            sampled.push(points[low.max(high)]);
+        }
+        start = end;
+    }
+
+    sampled
+}
+
+/// 沿折线按虚线模式（交替的实段与间隔长度）切分出各实段，模式跨顶点连续
+fn dash_segments(points: &[Point2<f32>], pattern: &[f32]) -> Vec<Vec<Point2<f32>>> {
+    if pattern.iter().sum::<f32>() <= 0.0 {
+        return vec![points.to_vec()];
+    }
+
+    let mut segments = Vec::new();
+    let mut current: Vec<Point2<f32>> = Vec::new();
+    let (mut index, mut remaining) = (0, pattern[0]);
+    let mut drawing = true;
+    if let Some(&first) = points.first() {
+        current.push(first);
+    }
+
+    for pair in points.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let length = (end - start).norm();
+        let mut travelled = 0.0;
+
+        while length - travelled > remaining {
+            travelled += remaining;
+            // 实段在此结束，或下一实段从此开始
+            current.push(start + (end - start) * (travelled / length));
+            if drawing {
+                segments.push(std::mem::take(&mut current));
+            }
+            drawing = !drawing;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+
+        remaining -= length - travelled;
+        if drawing {
+            current.push(end);
+        }
+    }
+
+    if drawing && current.len() > 1 {
+        segments.push(current);
+    }
+    segments
+}
+
+/// 在屏幕坐标 center 处绘制大小为 size 的数据点标记
+fn push_marker(
+    primitives: &mut Vec<Primitive>,
+    marker: MarkerStyle,
+    center: Point2<f32>,
+    size: f32,
+    color: Color,
+) {
+    let h = size / 2.0;
+    let offset = |dx: f32, dy: f32| Point2::new(center.x + dx, center.y + dy);
+    let stroke = |points: Vec<Point2<f32>>| Primitive::Polyline {
+        points,
+        color,
+        width: (size / 4.0).max(1.0),
+    };
+
+    match marker {
+        MarkerStyle::Circle => primitives.push(Primitive::ArcSector {
+            center,
+            radius: h,
+            start_angle: 0.0,
+            end_angle: std::f32::consts::TAU,
+            fill: color,
+            stroke: None,
+        }),
+        MarkerStyle::Square => primitives.push(Primitive::RectangleStyled {
+            min: offset(-h, -h),
+            max: offset(h, h),
+            fill: color,
+            stroke: None,
+        }),
+        MarkerStyle::Triangle => primitives.push(Primitive::Polygon {
+            points: vec![offset(0.0, -h), offset(h, h), offset(-h, h)],
+            fill: color,
+            stroke: None,
+        }),
+        MarkerStyle::Diamond => primitives.push(Primitive::Polygon {
+            points: vec![
+                offset(0.0, -h),
+                offset(h, 0.0),
+                offset(0.0, h),
+                offset(-h, 0.0),
+            ],
+            fill: color,
+            stroke: None,
+        }),
+        MarkerStyle::Cross => {
+            primitives.push(stroke(vec![offset(-h, -h), offset(h, h)]));
+            primitives.push(stroke(vec![offset(-h, h), offset(h, -h)]));
+        }
+        MarkerStyle::Plus => {
+            primitives.push(stroke(vec![offset(-h, 0.0), offset(h, 0.0)]));
+            primitives.push(stroke(vec![offset(0.0, -h), offset(0.0, h)]));
+        }
     }
 }
 
@@ -211,6 +592,233 @@ impl Default for LinePlot {
     }
 }
 
+/// 多系列的样式循环：第 i 个系列使用第 i 个颜色与第 i 个线条样式（各自循环），
+/// 颜色与线型同时区分系列，灰度打印时仍可辨认
+///
+/// 默认颜色为 [`DEFAULT_SERIES_COLORS`]；与主题配色一致时用主题的系列颜色创建，
+/// 如 `StyleCycle::from_colors(ColorCycle::from_current_theme().colors())`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StyleCycle {
+    pub colors: Vec<Color>,
+    pub line_styles: Vec<LineStyle>,
+}
+
+impl Default for StyleCycle {
+    fn default() -> Self {
+        Self::from_colors(&DEFAULT_SERIES_COLORS)
+    }
+}
+
+impl StyleCycle {
+    /// 使用给定的颜色与线条样式创建样式循环
+    pub fn new(colors: Vec<Color>, line_styles: Vec<LineStyle>) -> Self {
+        Self {
+            colors,
+            line_styles,
+        }
+    }
+
+    /// 使用调色板（如主题或 `ColorCycle` 的颜色序列）创建，线条样式依次取所有样式
+    pub fn from_colors(colors: &[Color]) -> Self {
+        Self::new(colors.to_vec(), LineStyle::ALL.to_vec())
+    }
+
+    /// 第 index 个系列的颜色与线条样式；列表为空时使用默认折线样式
+    pub fn get(&self, index: usize) -> (Color, LineStyle) {
+        let fallback = LinePlotStyle::default();
+        let color = match self.colors.len() {
+            0 => fallback.color,
+            n => self.colors[index % n],
+        };
+        let line_style = match self.line_styles.len() {
+            0 => fallback.style,
+            n => self.line_styles[index % n],
+        };
+        (color, line_style)
+    }
+}
+
+/// 多系列折线图中的一个系列，颜色与线型未覆盖时取自样式循环
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineSeries {
+    pub name: String,
+    pub plot: LinePlot,
+    pub color: Option<Color>,
+    pub line_style: Option<LineStyle>,
+}
+
+/// 多系列折线图：各系列共用比例尺，并按样式循环依次分配颜色与线型
+///
+/// 未设置共用比例尺时，设置了自身比例尺的系列（如 `time_data` 生成的时间轴）按自身比例尺绘制，
+/// 其余系列按所有系列的数据范围拟合
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MultiLinePlot {
+    series: Vec<LineSeries>,
+    cycle: StyleCycle,
+    x_scale: Option<LinearScale>,
+    y_scale: Option<LinearScale>,
+}
+
+impl MultiLinePlot {
+    /// 创建新的多系列折线图
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加系列；系列自身的线宽、标记等设置保留，颜色与线型由样式循环分配
+    pub fn add_series<S: Into<String>>(mut self, name: S, plot: LinePlot) -> Self {
+        self.series.push(LineSeries {
+            name: name.into(),
+            plot,
+            color: None,
+            line_style: None,
+        });
+        self
+    }
+
+    /// 设置样式循环
+    pub fn style_cycle(mut self, cycle: StyleCycle) -> Self {
+        self.cycle = cycle;
+        self
+    }
+
+    /// 覆盖第 index 个系列的颜色
+    pub fn series_color(mut self, index: usize, color: Color) -> Self {
+        if let Some(series) = self.series.get_mut(index) {
+            series.color = Some(color);
+        }
+        self
+    }
+
+    /// 覆盖第 index 个系列的线条样式
+    pub fn series_line_style(mut self, index: usize, style: LineStyle) -> Self {
+        if let Some(series) = self.series.get_mut(index) {
+            series.line_style = Some(style);
+        }
+        self
+    }
+
+    /// 设置 X 轴比例尺
+    pub fn x_scale(mut self, scale: LinearScale) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// 设置 Y 轴比例尺
+    pub fn y_scale(mut self, scale: LinearScale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// 所有系列
+    pub fn series(&self) -> &[LineSeries] {
+        &self.series
+    }
+
+    /// 第 index 个系列实际使用的颜色与线条样式
+    pub fn series_style(&self, index: usize) -> Option<(Color, LineStyle)> {
+        let series = self.series.get(index)?;
+        let (color, line_style) = self.cycle.get(index);
+        Some((
+            series.color.unwrap_or(color),
+            series.line_style.unwrap_or(line_style),
+        ))
+    }
+
+    /// 图例条目：(系列名称, 颜色, 线条样式)
+    pub fn legend_items(&self) -> Vec<(String, Color, LineStyle)> {
+        self.series
+            .iter()
+            .enumerate()
+            .filter_map(|(i, series)| {
+                let (color, line_style) = self.series_style(i)?;
+                Some((series.name.clone(), color, line_style))
+            })
+            .collect()
+    }
+
+    /// 生成渲染图元；每条线都携带各自的颜色
+    pub fn generate_primitives(&self, plot_area: crate::PlotArea) -> Vec<Primitive> {
+        self.generate_with_scales(None, None, plot_area)
+    }
+
+    /// 获取所有系列的数据范围 (x_min, x_max, y_min, y_max)；没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        point_bounds(
+            self.series
+                .iter()
+                .filter_map(|series| series.plot.data_bounds())
+                .flat_map(|(x0, x1, y0, y1)| [(x0, y0), (x1, y1)]),
+        )
+    }
+}
+
+impl ScaledPlot for MultiLinePlot {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: crate::PlotArea,
+    ) -> Vec<Primitive> {
+        let (fitted_x, fitted_y) = match MultiLinePlot::data_bounds(self) {
+            Some((x_min, x_max, y_min, y_max)) => (
+                LinearScale::from_data(&[x_min, x_max]),
+                LinearScale::from_data(&[y_min, y_max]),
+            ),
+            None => (LinearScale::new(0.0, 1.0), LinearScale::new(0.0, 1.0)),
+        };
+        let x = x.or(self.x_scale.as_ref().map(|scale| scale as &dyn Scale));
+        let y = y.or(self.y_scale.as_ref().map(|scale| scale as &dyn Scale));
+
+        let mut primitives = Vec::new();
+        for (i, series) in self.series.iter().enumerate() {
+            let Some((color, line_style)) = self.series_style(i) else {
+                continue;
+            };
+            // 没有共用比例尺时，系列自身设置的比例尺（如 time_data 的时间轴）优先于整体拟合
+            let plot = &series.plot;
+            let series_x = x
+                .or(plot.x_scale.as_ref().map(|scale| scale as &dyn Scale))
+                .unwrap_or(&fitted_x);
+            let series_y = y
+                .or(plot.y_scale.as_ref().map(|scale| scale as &dyn Scale))
+                .unwrap_or(&fitted_y);
+            let style = LinePlotStyle {
+                color,
+                style: line_style,
+                ..plot.style.clone()
+            };
+            let width = style.width;
+            let color = Color::rgba(color.r, color.g, color.b, color.a * style.alpha);
+            // 实线的 LineStrip 不带颜色，转为多段线以区分系列
+            primitives.extend(
+                plot.generate_styled(Some(series_x), Some(series_y), plot_area, &style)
+                    .into_iter()
+                    .map(|primitive| match primitive {
+                        Primitive::LineStrip(points) => Primitive::Polyline {
+                            points,
+                            color,
+                            width,
+                        },
+                        other => other,
+                    }),
+            );
+        }
+        primitives
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        MultiLinePlot::data_bounds(self)
+    }
+
+    fn y_range_within(&self, x_min: f32, x_max: f32) -> Option<(f32, f32)> {
+        self.series
+            .iter()
+            .filter_map(|series| series.plot.y_range_within(x_min, x_max))
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,11 +864,11 @@ mod tests {
         let data = vec![(1.0, 2.0), (3.0, 4.0), (0.0, 1.0)];
         let plot = LinePlot::new().data(&data);
 
-        let bounds = plot.data_bounds().unwrap();
-        assert_eq!(bounds.0.x, 0.0);
-        assert_eq!(bounds.0.y, 1.0);
-        assert_eq!(bounds.1.x, 3.0);
-        assert_eq!(bounds.1.y, 4.0);
+        assert_eq!(plot.data_bounds(), Some((0.0, 3.0, 1.0, 4.0)));
+
+        // 缺失值不计入范围
+        let plot = LinePlot::new().xy_data(&[0.0, 1.0, 2.0], &[1.0, f32::NAN, 5.0]);
+        assert_eq!(plot.data_bounds(), Some((0.0, 2.0, 1.0, 5.0)));
     }
 
     #[test]
@@ -292,6 +900,80 @@ mod tests {
         assert_eq!(primitives.len(), 0); // 少于2个点，不应该生成线条
     }
 
+    #[test]
+    fn test_line_plot_nan_gaps() {
+        let plot = LinePlot::new().xy_data(&[0.0, 1.0, 2.0, 3.0], &[1.0, f32::NAN, 3.0, 4.0]);
+        let plot_area = crate::PlotArea::new(0.0, 0.0, 300.0, 300.0);
+        let primitives = plot.generate_primitives(plot_area);
+
+        // NaN 把数据分成两段：孤立的第一个点和其后的一条线
+        assert_eq!(primitives.len(), 2);
+        assert!(matches!(primitives[0], Primitive::Point(_)));
+        assert!(matches!(&primitives[1], Primitive::LineStrip(points) if points.len() == 2));
+
+        let plot =
+            LinePlot::new().xy_data(&[0.0, 1.0, 2.0, 3.0, 4.0], &[1.0, 2.0, f32::NAN, 3.0, 4.0]);
+        let segments: Vec<usize> = plot
+            .generate_primitives(plot_area)
+            .iter()
+            .map(|p| match p {
+                Primitive::LineStrip(points) => {
+                    assert!(points.iter().all(|pt| pt.x.is_finite() && pt.y.is_finite()));
+                    points.len()
+                }
+                _ => panic!("Expected LineStrip primitive"),
+            })
+            .collect();
+        assert_eq!(segments, [2, 2]);
+    }
+
+    #[test]
+    fn test_line_plot_draw_modes() {
+        let data = vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)];
+        let area = crate::PlotArea::new(0.0, 0.0, 200.0, 100.0);
+
+        // 连线加标记：标记位于线条之后，颜色默认与线条相同
+        let primitives = LinePlot::new()
+            .data(&data)
+            .color(Color::rgb(1.0, 0.0, 0.0))
+            .draw_mode(LineDrawMode::LineAndMarkers)
+            .generate_primitives(area);
+        assert_eq!(primitives.len(), 4);
+        assert!(matches!(primitives[0], Primitive::LineStrip(_)));
+        let Primitive::LineStrip(ref points) = primitives[0] else {
+            unreachable!()
+        };
+        for (marker, vertex) in primitives[1..].iter().zip(points) {
+            assert!(matches!(
+                marker,
+                Primitive::ArcSector { center, radius, fill, .. }
+                    if center == vertex && *radius == 3.0 && *fill == Color::rgb(1.0, 0.0, 0.0)
+            ));
+        }
+
+        // 仅标记：不生成线条，单个点也会绘制
+        let primitives = LinePlot::new()
+            .data(&data)
+            .draw_mode(LineDrawMode::Markers)
+            .marker(MarkerStyle::Square)
+            .marker_size(8.0)
+            .marker_color(Color::rgb(0.0, 1.0, 0.0))
+            .generate_primitives(area);
+        assert_eq!(primitives.len(), 3);
+        assert!(primitives.iter().all(|p| matches!(
+            p,
+            Primitive::RectangleStyled { min, max, fill, .. }
+                if max.x - min.x == 8.0 && *fill == Color::rgb(0.0, 1.0, 0.0)
+        )));
+
+        let single = LinePlot::new()
+            .data(&[(1.0, 1.0)])
+            .draw_mode(LineDrawMode::Markers)
+            .marker(MarkerStyle::Plus)
+            .generate_primitives(area);
+        assert_eq!(single.len(), 2);
+    }
+
     #[test]
     fn test_line_plot_styling() {
         let plot = LinePlot::new()
@@ -303,4 +985,237 @@ mod tests {
         assert_eq!(plot.style.width, 3.0);
         assert_eq!(plot.style.style, vizuara_core::LineStyle::Dashed);
     }
+
+    #[test]
+    fn test_line_plot_dashed() {
+        let area = crate::PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let primitives = LinePlot::new()
+            .xy_data(&[0.0, 1.0], &[0.0, 0.0])
+            .x_scale(LinearScale::new(0.0, 1.0))
+            .y_scale(LinearScale::new(-1.0, 1.0))
+            .color(Color::rgb(1.0, 0.0, 0.0))
+            .line_width(1.0)
+            .line_style(vizuara_core::LineStyle::Dashed)
+            .generate_primitives(area);
+
+        // 100 像素按 6 实 4 虚切分为 10 段，每段长 6
+        assert_eq!(primitives.len(), 10);
+        for primitive in &primitives {
+            let Primitive::Polyline {
+                points,
+                color,
+                width,
+            } = primitive
+            else {
+                panic!("Expected Polyline primitive");
+            };
+            assert_eq!(*color, Color::rgb(1.0, 0.0, 0.0));
+            assert_eq!(*width, 1.0);
+            let length = (points[points.len() - 1] - points[0]).norm();
+            assert!((length - 6.0).abs() < 1e-3);
+        }
+
+        // 虚线模式跨越折线顶点连续
+        let path = [
+            Point2::new(0.0, 0.0),
+            Point2::new(5.0, 0.0),
+            Point2::new(5.0, 10.0),
+        ];
+        let segments = dash_segments(&path, &[6.0, 4.0]);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(
+            segments[0],
+            [
+                Point2::new(0.0, 0.0),
+                Point2::new(5.0, 0.0),
+                Point2::new(5.0, 1.0)
+            ]
+        );
+        assert_eq!(segments[1], [Point2::new(5.0, 5.0), Point2::new(5.0, 10.0)]);
+    }
+
+    #[test]
+    fn test_style_cycle_from_colors() {
+        let palette = [Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 0.0, 1.0)];
+        let cycle = StyleCycle::from_colors(&palette);
+        assert_eq!(cycle.get(0), (palette[0], vizuara_core::LineStyle::Solid));
+        assert_eq!(cycle.get(3), (palette[1], vizuara_core::LineStyle::DashDot));
+        assert_eq!(cycle.line_styles, vizuara_core::LineStyle::ALL);
+
+        // 默认使用共享的默认系列颜色
+        assert_eq!(
+            StyleCycle::default(),
+            StyleCycle::from_colors(&DEFAULT_SERIES_COLORS)
+        );
+
+        // 空调色板回退到默认折线颜色
+        let empty = StyleCycle::from_colors(&[]);
+        assert_eq!(empty.get(2).0, LinePlotStyle::default().color);
+    }
+
+    #[test]
+    fn test_multi_line_style_cycle() {
+        let cycle = StyleCycle::new(
+            vec![Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 0.0, 1.0)],
+            vizuara_core::LineStyle::ALL.to_vec(),
+        );
+        let plot = (0..5)
+            .fold(MultiLinePlot::new().style_cycle(cycle), |plot, i| {
+                let y = i as f32;
+                plot.add_series(
+                    format!("s{i}"),
+                    LinePlot::new().xy_data(&[0.0, 10.0], &[y, y]),
+                )
+            })
+            .series_line_style(3, vizuara_core::LineStyle::Solid)
+            .series_color(4, Color::rgb(0.0, 1.0, 0.0));
+
+        let legend = plot.legend_items();
+        assert_eq!(legend.len(), 5);
+        assert_eq!(
+            legend[1],
+            (
+                "s1".to_string(),
+                Color::rgb(0.0, 0.0, 1.0),
+                vizuara_core::LineStyle::Dashed
+            )
+        );
+        assert_eq!(legend[2].1, Color::rgb(1.0, 0.0, 0.0));
+        assert_eq!(legend[2].2, vizuara_core::LineStyle::Dotted);
+        // 覆盖优先于样式循环，循环在第 5 个系列回到实线
+        assert_eq!(legend[3].2, vizuara_core::LineStyle::Solid);
+        assert_eq!(
+            plot.series_style(4),
+            Some((Color::rgb(0.0, 1.0, 0.0), vizuara_core::LineStyle::Solid))
+        );
+        assert_eq!(plot.data_bounds(), Some((0.0, 10.0, 0.0, 4.0)));
+
+        // 所有线条都带颜色，实线系列各生成一条多段线
+        let area = crate::PlotArea::new(0.0, 0.0, 200.0, 100.0);
+        let primitives = plot.generate_primitives(area);
+        assert!(primitives
+            .iter()
+            .all(|p| matches!(p, Primitive::Polyline { .. })));
+        let solid: Vec<Color> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::Polyline { points, color, .. } if points.len() == 2 => {
+                    let length = (points[1] - points[0]).norm();
+                    (length > 100.0).then_some(*color)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            solid,
+            [
+                Color::rgb(1.0, 0.0, 0.0),
+                Color::rgb(0.0, 0.0, 1.0),
+                Color::rgb(0.0, 1.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_line_respects_series_time_scale() {
+        const DAY: i64 = 86_400_000;
+        let start = 19_723 * DAY;
+        let time = TimeScale::new(start, start + 4 * DAY);
+        let daily = LinePlot::new()
+            .x_time_scale(time.clone())
+            .time_data(&[(start + DAY, 1.0), (start + 3 * DAY, 1.0)]);
+        let plain = LinePlot::new().xy_data(&[0.0, 1.0], &[0.0, 2.0]);
+        let plot = MultiLinePlot::new()
+            .add_series("daily", daily)
+            .add_series("plain", plain);
+
+        let area = crate::PlotArea::new(0.0, 0.0, 400.0, 100.0);
+        let first_x = |primitives: Vec<Primitive>| match &primitives[0] {
+            Primitive::Polyline { points, .. } => points[0].x,
+            other => panic!("unexpected primitive {other:?}"),
+        };
+
+        // 系列自身的时间轴优先于按所有系列拟合的比例尺：第 1 天位于 [0, 4] 天的 1/4 处
+        assert!((first_x(plot.generate_primitives(area)) - 100.0).abs() < 1e-3);
+
+        // 传入共用比例尺时所有系列都按它绘制
+        let shared = time.linear();
+        let x = first_x(plot.generate_with_scales(Some(&shared), None, area));
+        assert!((x - 100.0).abs() < 1e-3);
+        let two_days = LinearScale::new(0.0, 2.0 * DAY as f32);
+        let x = first_x(plot.generate_with_scales(Some(&two_days), None, area));
+        assert!((x - 200.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_line_plot_downsampling() {
+        // 一万个点的正弦曲线，中间有一个尖峰
+        let data: Vec<(f32, f32)> = (0..10_000)
+            .map(|i| {
+                let x = i as f32;
+                let y = if i == 5_003 { 10.0 } else { (x * 0.01).sin() };
+                (x, y)
+            })
+            .collect();
+        let area = crate::PlotArea::new(0.0, 0.0, 200.0, 100.0);
+        let strip = |plot: LinePlot| match plot.generate_primitives(area).as_slice() {
+            [Primitive::LineStrip(points)] => points.clone(),
+            other => panic!("expected a single line strip, got {:?}", other),
+        };
+
+        let full = strip(LinePlot::new().data(&data).auto_scale());
+        assert_eq!(full.len(), 10_000);
+        let peak = full[5_003];
+
+        let lttb = strip(
+            LinePlot::new()
+                .data(&data)
+                .auto_scale()
+                .downsample(DownsampleMode::Lttb),
+        );
+        assert_eq!(lttb.len(), 200);
+        assert_eq!(lttb[0], full[0]);
+        assert_eq!(lttb[199], full[9_999]);
+        assert!(lttb.contains(&peak));
+
+        let min_max = strip(
+            LinePlot::new()
+                .data(&data)
+                .auto_scale()
+                .downsample(DownsampleMode::MinMax),
+        );
+        assert!(min_max.len() <= 2 * 201);
+        assert!(min_max.contains(&peak));
+
+        // 未超过阈值时保持原样
+        let below = strip(
+            LinePlot::new()
+                .data(&data)
+                .auto_scale()
+                .downsample(DownsampleMode::Lttb)
+                .downsample_threshold(20_000),
+        );
+        assert_eq!(below.len(), 10_000);
+    }
+
+    #[test]
+    fn test_time_data() {
+        const DAY: i64 = 86_400_000;
+        let start = 19_723 * DAY; // 2024-01-01
+        let data = [(start + 2 * DAY, 3.0), (start, 1.0), (start + DAY, 2.0)];
+
+        let plot = LinePlot::new().time_data(&data).auto_scale();
+        let time = plot.time_scale().cloned().unwrap();
+        assert_eq!(time.origin, start);
+        assert_eq!(plot.x_scale, Some(time.linear()));
+        // 按时间排序，X 为相对 origin 的毫秒偏移
+        let xs: Vec<f32> = plot.data.iter().map(|p| p.x).collect();
+        assert_eq!(xs, [0.0, DAY as f32, (2 * DAY) as f32]);
+
+        // 更换时间比例尺后数据随 origin 平移，时间戳不变
+        let rebased = plot.x_time_scale(TimeScale::new(start - DAY, start + 3 * DAY));
+        let time = rebased.time_scale().unwrap();
+        assert_eq!(time.timestamp(rebased.data[0].x), start);
+        assert_eq!(time.timestamp(rebased.data[2].x), start + 2 * DAY);
+    }
 }
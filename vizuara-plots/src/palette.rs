@@ -0,0 +1,17 @@
+//! 图表共用的默认系列颜色
+
+use vizuara_core::Color;
+
+/// 未指定调色板时多系列图表依次使用的颜色
+///
+/// 需要与主题保持一致时，用主题或 `ColorCycle` 的颜色序列替换（如 [`StyleCycle::from_colors`]）
+///
+/// [`StyleCycle::from_colors`]: crate::StyleCycle::from_colors
+pub const DEFAULT_SERIES_COLORS: [Color; 6] = [
+    Color::rgb(0.2, 0.6, 0.9), // 蓝色
+    Color::rgb(0.9, 0.5, 0.2), // 橙色
+    Color::rgb(0.4, 0.8, 0.4), // 绿色
+    Color::rgb(0.9, 0.3, 0.3), // 红色
+    Color::rgb(0.7, 0.4, 0.9), // 紫色
+    Color::rgb(0.9, 0.9, 0.3), // 黄色
+];
@@ -4,7 +4,10 @@
 
 use crate::PlotArea;
 use nalgebra::Point2;
-use vizuara_core::{Color, HorizontalAlign, LinearScale, Primitive, Scale, VerticalAlign};
+use std::collections::HashSet;
+use vizuara_core::{
+    Color, GridStyle, HorizontalAlign, LinearScale, Primitive, Scale, TickFormatter, VerticalAlign,
+};
 
 /// 平行坐标轴
 #[derive(Debug, Clone)]
@@ -15,6 +18,8 @@ pub struct ParallelAxis {
     pub tick_count: usize,
     pub show_ticks: bool,
     pub show_labels: bool,
+    /// 刻度标签格式化器（None 时保留一位小数）
+    pub tick_formatter: Option<TickFormatter>,
 }
 
 impl ParallelAxis {
@@ -27,6 +32,7 @@ impl ParallelAxis {
             tick_count: 5,
             show_ticks: true,
             show_labels: true,
+            tick_formatter: None,
         }
     }
 
@@ -47,6 +53,12 @@ impl ParallelAxis {
         self.show_labels = show;
         self
     }
+
+    /// 设置刻度标签格式化器
+    pub fn tick_formatter(mut self, formatter: TickFormatter) -> Self {
+        self.tick_formatter = Some(formatter);
+        self
+    }
 }
 
 /// 数据系列
@@ -128,6 +140,15 @@ impl Default for ParallelStyle {
     }
 }
 
+/// 图例所在的绘图区域角落
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
 /// 平行坐标图
 #[derive(Debug, Clone)]
 pub struct ParallelCoordinates {
@@ -137,6 +158,7 @@ pub struct ParallelCoordinates {
     title: Option<String>,
     brushing_enabled: bool,
     selected_ranges: Vec<Option<(f32, f32)>>, // 每个轴的选择范围
+    legend: Option<LegendCorner>,
 }
 
 impl ParallelCoordinates {
@@ -149,6 +171,7 @@ impl ParallelCoordinates {
             title: None,
             brushing_enabled: false,
             selected_ranges: Vec::new(),
+            legend: None,
         }
     }
 
@@ -241,6 +264,20 @@ impl ParallelCoordinates {
         self
     }
 
+    /// 按统一的网格样式设置轴间网格线（仅使用主网格线设置）
+    pub fn grid_style(mut self, style: &GridStyle) -> Self {
+        self.style.show_grid = style.show_major;
+        self.style.grid_color = style.major_color;
+        self.style.grid_width = style.major_width;
+        self
+    }
+
+    /// 在指定角落显示系列图例，系列较多时分多列排布
+    pub fn legend(mut self, corner: LegendCorner) -> Self {
+        self.legend = Some(corner);
+        self
+    }
+
     /// 启用刷选功能
     pub fn enable_brushing(mut self, enabled: bool) -> Self {
         self.brushing_enabled = enabled;
@@ -273,8 +310,48 @@ impl ParallelCoordinates {
         true
     }
 
+    /// 系列的绘制透明度：未通过刷选或不在外部选择中的系列变淡
+    fn series_alpha(
+        &self,
+        index: usize,
+        series: &ParallelSeries,
+        selection: Option<&HashSet<usize>>,
+    ) -> f32 {
+        let is_selected = self.is_series_selected(series)
+            && match selection {
+                Some(selected) => selected.contains(&index),
+                None => true,
+            };
+        if is_selected {
+            series.alpha
+        } else {
+            series.alpha * 0.3 // 未选中的线条变淡
+        }
+    }
+
+    /// 获取通过刷选范围的系列索引（可写入共享选择，驱动其他图表联动）
+    pub fn brushed_indices(&self) -> Vec<usize> {
+        self.series
+            .iter()
+            .enumerate()
+            .filter(|(_, series)| self.is_series_selected(series))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        self.generate_primitives_with_selection(plot_area, None)
+    }
+
+    /// 生成渲染图元，外部选择中不包含的系列变淡（用于联动刷选）
+    ///
+    /// `selection` 中的索引对应系列的添加顺序；为 `None` 时仅使用本图的刷选范围。
+    pub fn generate_primitives_with_selection(
+        &self,
+        plot_area: PlotArea,
+        selection: Option<&HashSet<usize>>,
+    ) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
         if self.axes.is_empty() {
@@ -319,6 +396,7 @@ impl ParallelCoordinates {
                 color: self.style.label_color,
                 h_align: HorizontalAlign::Center,
                 v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
             });
 
             // 绘制刻度和刻度标签
@@ -342,11 +420,15 @@ impl ParallelCoordinates {
                     if axis.show_labels {
                         primitives.push(Primitive::Text {
                             position: Point2::new(x + 15.0, y),
-                            content: format!("{:.1}", value),
+                            content: match &axis.tick_formatter {
+                                Some(formatter) => formatter.format(value),
+                                None => format!("{:.1}", value),
+                            },
                             size: self.style.label_size * 0.8,
                             color: self.style.label_color,
                             h_align: HorizontalAlign::Left,
                             v_align: VerticalAlign::Middle,
+                            rotation: 0.0,
                         });
                     }
                 }
@@ -369,18 +451,12 @@ impl ParallelCoordinates {
         }
 
         // 绘制数据线
-        for series in &self.series {
+        for (index, series) in self.series.iter().enumerate() {
             if series.values.len() != axis_count {
                 continue;
             }
 
-            let is_selected = self.is_series_selected(series);
-            let alpha = if is_selected {
-                series.alpha
-            } else {
-                series.alpha * 0.3 // 未选中的线条变淡
-            };
-
+            let alpha = self.series_alpha(index, series, selection);
             let line_color = Color::rgba(series.color.r, series.color.g, series.color.b, alpha);
             let line_width = if series.highlighted {
                 series.line_width * 2.0
@@ -449,6 +525,95 @@ impl ParallelCoordinates {
                 color: Color::rgb(0.1, 0.1, 0.1),
                 h_align: HorizontalAlign::Center,
                 v_align: VerticalAlign::Top,
+                rotation: 0.0,
+            });
+        }
+
+        if let Some(corner) = self.legend {
+            primitives.extend(self.legend_primitives(plot_area, corner, selection));
+        }
+
+        primitives
+    }
+
+    /// 生成图例图元：每项为色块加系列名，超过绘图区域一半高度时换列，
+    /// 变淡的系列在图例中同样变淡
+    fn legend_primitives(
+        &self,
+        plot_area: PlotArea,
+        corner: LegendCorner,
+        selection: Option<&HashSet<usize>>,
+    ) -> Vec<Primitive> {
+        let axis_count = self.axes.len();
+        let entries: Vec<(&ParallelSeries, f32)> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter(|(_, series)| series.values.len() == axis_count)
+            .map(|(index, series)| (series, self.series_alpha(index, series, selection)))
+            .collect();
+        if entries.is_empty() {
+            return Vec::new();
+        }
+
+        let padding = 6.0;
+        let swatch = 10.0;
+        let text_size = self.style.label_size * 0.9;
+        let row_height = text_size + 6.0;
+        let max_chars = entries
+            .iter()
+            .map(|(series, _)| series.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let column_width = swatch + 6.0 + max_chars as f32 * text_size * 0.6 + padding;
+
+        let rows_per_column = ((plot_area.height * 0.5 / row_height).floor() as usize).max(1);
+        let columns = (entries.len() - 1) / rows_per_column + 1;
+        let rows = entries.len().min(rows_per_column);
+        let width = columns as f32 * column_width + padding;
+        let height = rows as f32 * row_height + 2.0 * padding;
+
+        let margin = 10.0;
+        let left = match corner {
+            LegendCorner::TopLeft | LegendCorner::BottomLeft => plot_area.x + margin,
+            LegendCorner::TopRight | LegendCorner::BottomRight => {
+                plot_area.x + plot_area.width - margin - width
+            }
+        };
+        let top = match corner {
+            LegendCorner::TopLeft | LegendCorner::TopRight => plot_area.y + margin,
+            LegendCorner::BottomLeft | LegendCorner::BottomRight => {
+                plot_area.y + plot_area.height - margin - height
+            }
+        };
+
+        let mut primitives = vec![Primitive::RectangleStyled {
+            min: Point2::new(left, top),
+            max: Point2::new(left + width, top + height),
+            fill: Color::rgba(1.0, 1.0, 1.0, 0.85),
+            stroke: Some((Color::rgb(0.8, 0.8, 0.8), 1.0)),
+        }];
+
+        for (i, (series, alpha)) in entries.into_iter().enumerate() {
+            let x = left + padding + (i / rows_per_column) as f32 * column_width;
+            let center_y = top + padding + ((i % rows_per_column) as f32 + 0.5) * row_height;
+            let fade = alpha / series.alpha.max(f32::EPSILON);
+            let label = self.style.label_color;
+
+            primitives.push(Primitive::RectangleStyled {
+                min: Point2::new(x, center_y - swatch / 2.0),
+                max: Point2::new(x + swatch, center_y + swatch / 2.0),
+                fill: Color::rgba(series.color.r, series.color.g, series.color.b, alpha),
+                stroke: None,
+            });
+            primitives.push(Primitive::Text {
+                position: Point2::new(x + swatch + 6.0, center_y),
+                content: series.name.clone(),
+                size: text_size,
+                color: Color::rgba(label.r, label.g, label.b, label.a * fade),
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Middle,
+                rotation: 0.0,
             });
         }
 
@@ -539,6 +704,33 @@ mod tests {
         assert_eq!(pc.selected_ranges[0], Some((1.0, 5.0)));
     }
 
+    #[test]
+    fn test_linked_selection() {
+        let pc = ParallelCoordinates::new()
+            .add_axis(ParallelAxis::new("A", 0.0, 10.0))
+            .add_axis(ParallelAxis::new("B", 0.0, 10.0))
+            .add_series(ParallelSeries::new("s0", vec![2.0, 3.0]).alpha(1.0))
+            .add_series(ParallelSeries::new("s1", vec![8.0, 9.0]).alpha(1.0))
+            .enable_brushing(true)
+            .set_axis_range(0, Some((0.0, 5.0)));
+        assert_eq!(pc.brushed_indices(), vec![0]);
+
+        // 外部选择只包含 s1，但 s1 不在本图刷选范围内，两条线都变淡
+        let selected: HashSet<usize> = [1].into_iter().collect();
+        let plot_area = PlotArea::new(0.0, 0.0, 400.0, 300.0);
+        let dimmed = |primitives: Vec<Primitive>| {
+            primitives
+                .iter()
+                .filter(|p| matches!(p, Primitive::Polyline { color, .. } if color.a < 0.5))
+                .count()
+        };
+        assert_eq!(dimmed(pc.generate_primitives(plot_area)), 1);
+        assert_eq!(
+            dimmed(pc.generate_primitives_with_selection(plot_area, Some(&selected))),
+            2
+        );
+    }
+
     #[test]
     fn test_parallel_coordinates_primitives() {
         let axes = vec![
@@ -583,4 +775,44 @@ mod tests {
         assert_eq!(stats[0].2, 9.0); // max
         assert_eq!(stats[0].3, 5.0); // mean
     }
+
+    #[test]
+    fn test_legend_columns_and_fading() {
+        let series: Vec<ParallelSeries> = (0..12)
+            .map(|i| ParallelSeries::new(format!("S{}", i), vec![i as f32, 0.0]).alpha(1.0))
+            .collect();
+        let pc = ParallelCoordinates::new()
+            .axes(vec![
+                ParallelAxis::new("A", 0.0, 20.0),
+                ParallelAxis::new("B", 0.0, 1.0),
+            ])
+            .series(series)
+            .enable_brushing(true)
+            .set_axis_range(0, Some((0.0, 5.5)))
+            .legend(LegendCorner::TopRight);
+
+        // 高度 200 时每列最多 100 / 16.8 = 5 行，12 个系列排成 3 列
+        let area = PlotArea::new(0.0, 0.0, 400.0, 200.0);
+        let labels: Vec<(String, f32, f32)> = pc
+            .generate_primitives(area)
+            .into_iter()
+            .filter_map(|p| match p {
+                Primitive::Text {
+                    content,
+                    position,
+                    color,
+                    ..
+                } if content.starts_with('S') => Some((content, position.x, color.a)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(labels.len(), 12);
+        let columns: HashSet<u32> = labels.iter().map(|l| l.1 as u32).collect();
+        assert_eq!(columns.len(), 3);
+        assert!(labels.iter().all(|l| l.1 < area.width));
+        // 刷选范围外的系列在图例中变淡
+        assert_eq!(labels[5].2, 1.0);
+        assert!(labels[6].2 < 0.5);
+    }
 }
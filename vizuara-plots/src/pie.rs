@@ -65,6 +65,18 @@ pub struct PieStyle {
     pub show_percentage: bool,
     /// 起始角度（弧度，0 = 右侧，PI/2 = 顶部）
     pub start_angle: f32,
+    /// 最小扇形占比（0.0-1.0），低于该占比的多个扇形合并为“其他”；0 表示不合并
+    pub min_fraction: f32,
+    /// 合并扇形的标签
+    pub other_label: String,
+    /// 合并扇形的颜色
+    pub other_color: Color,
+    /// 悬停扇形的颜色（如主题的 hover 颜色）；None 时按 `hover_brightness` 提亮原色
+    pub hover_color: Option<Color>,
+    /// 悬停扇形的提亮比例（0.0-1.0，向白色混合）
+    pub hover_brightness: f32,
+    /// 悬停扇形沿半径方向弹出的距离（像素）
+    pub hover_offset: f32,
 }
 
 impl Default for PieStyle {
@@ -81,10 +93,32 @@ impl Default for PieStyle {
             label_distance: 1.2,
             show_percentage: true,
             start_angle: -PI / 2.0, // 从顶部开始
+            min_fraction: 0.0,
+            other_label: "Other".to_string(),
+            other_color: Color::rgb(0.6, 0.6, 0.6),
+            hover_color: None,
+            hover_brightness: 0.2,
+            hover_offset: 8.0,
         }
     }
 }
 
+/// 计算后的扇形：占比按原始数值的总和归一化
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieSlice {
+    pub label: String,
+    /// 原始数值（合并扇形为被合并项之和）
+    pub value: f32,
+    /// 占全部正值总和的比例
+    pub fraction: f32,
+    pub color: Color,
+    /// 扇形起止角度（弧度，已扣除间隙）
+    pub start_angle: f32,
+    pub end_angle: f32,
+    /// 是否为合并小扇形得到的“其他”
+    pub is_other: bool,
+}
+
 /// 饼图
 #[derive(Debug, Clone)]
 pub struct PieChart {
@@ -93,6 +127,7 @@ pub struct PieChart {
     center: Point2<f32>,
     title: Option<String>,
     default_colors: Vec<Color>,
+    hovered: Option<usize>,
 }
 
 impl PieChart {
@@ -117,6 +152,7 @@ impl PieChart {
             center: Point2::new(200.0, 200.0),
             title: None,
             default_colors,
+            hovered: None,
         }
     }
 
@@ -202,6 +238,42 @@ impl PieChart {
         self
     }
 
+    /// 合并占比低于 `min_fraction`（如 0.02 即 2%）的扇形，至少有两个这样的扇形时才合并
+    pub fn group_small_slices(mut self, min_fraction: f32) -> Self {
+        self.style.min_fraction = min_fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// 设置合并扇形的标签与颜色
+    pub fn other_slice<S: Into<String>>(mut self, label: S, color: Color) -> Self {
+        self.style.other_label = label.into();
+        self.style.other_color = color;
+        self
+    }
+
+    /// 设置悬停的扇形（`slices()` 中的索引），绘制时提亮并沿半径方向弹出；None 取消悬停
+    pub fn hovered_slice(mut self, index: Option<usize>) -> Self {
+        self.hovered = index;
+        self
+    }
+
+    /// 设置悬停颜色，如主题组件的 hover 状态颜色
+    pub fn hover_color(mut self, color: Color) -> Self {
+        self.style.hover_color = Some(color);
+        self
+    }
+
+    /// 设置悬停扇形弹出的距离
+    pub fn hover_offset(mut self, offset: f32) -> Self {
+        self.style.hover_offset = offset;
+        self
+    }
+
+    /// 当前悬停的扇形
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
     /// 设置标题
     pub fn title<S: Into<String>>(mut self, title: S) -> Self {
         self.title = Some(title.into());
@@ -213,80 +285,159 @@ impl PieChart {
         self.data.len()
     }
 
-    /// 计算总值
+    /// 计算总值（只统计正值，零与负值不参与绘制）
     pub fn total_value(&self) -> f32 {
-        self.data.iter().map(|item| item.value).sum()
+        self.data
+            .iter()
+            .map(|item| item.value)
+            .filter(|&value| value > 0.0)
+            .sum()
     }
 
-    /// 生成饼图的渲染图元
-    pub fn generate_primitives(&self, _plot_area: super::PlotArea) -> Vec<Primitive> {
-        let mut primitives = Vec::new();
-
-        if self.data.is_empty() {
-            return primitives;
-        }
-
+    /// 按绘制顺序计算各扇形：原始数值按总和归一化，小扇形按 `min_fraction` 合并后放在最后
+    pub fn slices(&self) -> Vec<PieSlice> {
         let total = self.total_value();
         if total <= 0.0 {
-            return primitives;
+            return Vec::new();
         }
 
-        let mut current_angle = self.style.start_angle;
-        let gap_per_segment = if self.data.len() > 1 {
-            self.style.gap_angle / self.data.len() as f32
+        let positive = || {
+            self.data
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.value > 0.0)
+        };
+        let is_small = |value: f32| value / total < self.style.min_fraction;
+        let group = positive().filter(|(_, item)| is_small(item.value)).count() >= 2;
+
+        // (标签, 数值, 颜色, 是否合并)
+        let mut items: Vec<(String, f32, Color, bool)> = positive()
+            .filter(|(_, item)| !(group && is_small(item.value)))
+            .map(|(i, item)| {
+                let color = item
+                    .color
+                    .unwrap_or_else(|| self.default_colors[i % self.default_colors.len()]);
+                (item.label.clone(), item.value, color, false)
+            })
+            .collect();
+        if group {
+            let other: f32 = positive()
+                .map(|(_, item)| item.value)
+                .filter(|&value| is_small(value))
+                .sum();
+            items.push((
+                self.style.other_label.clone(),
+                other,
+                self.style.other_color,
+                true,
+            ));
+        }
+
+        let gap_per_segment = if items.len() > 1 {
+            self.style.gap_angle / items.len() as f32
         } else {
             0.0
         };
+        let mut current_angle = self.style.start_angle;
+        items
+            .into_iter()
+            .map(|(label, value, color, is_other)| {
+                let fraction = value / total;
+                let sector_angle = fraction * 2.0 * PI - gap_per_segment;
+                let slice = PieSlice {
+                    label,
+                    value,
+                    fraction,
+                    color,
+                    start_angle: current_angle,
+                    end_angle: current_angle + sector_angle,
+                    is_other,
+                };
+                current_angle += sector_angle + gap_per_segment;
+                slice
+            })
+            .collect()
+    }
 
-        // 生成扇形
-        for (i, item) in self.data.iter().enumerate() {
-            if item.value <= 0.0 {
-                continue;
-            }
+    /// 命中测试：`point` 相对 `center` 的角度落在哪个扇形内（`slices()` 中的索引）
+    ///
+    /// 只比较角度，不检查半径；落在扇形间隙中时返回 None
+    pub fn slice_at_angle(&self, center: Point2<f32>, point: Point2<f32>) -> Option<usize> {
+        let offset = point - center;
+        if offset.x == 0.0 && offset.y == 0.0 {
+            return None;
+        }
+
+        // 相对起始角度的角度，落在 [0, 2π) 内
+        let angle = (offset.y.atan2(offset.x) - self.style.start_angle).rem_euclid(2.0 * PI);
+        self.slices().iter().position(|slice| {
+            let start = slice.start_angle - self.style.start_angle;
+            let end = slice.end_angle - self.style.start_angle;
+            angle >= start && angle < end
+        })
+    }
+
+    /// 生成饼图的渲染图元
+    pub fn generate_primitives(&self, _plot_area: super::PlotArea) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
 
-            let percentage = item.value / total;
-            let sector_angle = percentage * 2.0 * PI - gap_per_segment;
+        if self.data.is_empty() || self.total_value() <= 0.0 {
+            return primitives;
+        }
 
-            // 选择颜色
-            let color = item
-                .color
-                .unwrap_or_else(|| self.default_colors[i % self.default_colors.len()]);
+        // 生成扇形
+        for (index, slice) in self.slices().into_iter().enumerate() {
+            let mid_angle = (slice.start_angle + slice.end_angle) / 2.0;
+            let (center, fill) = if self.hovered == Some(index) {
+                // 悬停扇形沿角平分线弹出
+                let pop = self.style.hover_offset;
+                let center = Point2::new(
+                    self.center.x + pop * mid_angle.cos(),
+                    self.center.y + pop * mid_angle.sin(),
+                );
+                let fill = self
+                    .style
+                    .hover_color
+                    .unwrap_or_else(|| brighten(slice.color, self.style.hover_brightness));
+                (center, fill)
+            } else {
+                (self.center, slice.color)
+            };
 
             // 生成扇形图元
             if self.style.inner_radius > 0.0 {
                 // 圆环模式
                 primitives.push(Primitive::ArcRing {
-                    center: self.center,
+                    center,
                     inner_radius: self.style.inner_radius,
                     outer_radius: self.style.outer_radius,
-                    start_angle: current_angle,
-                    end_angle: current_angle + sector_angle,
-                    fill: color,
+                    start_angle: slice.start_angle,
+                    end_angle: slice.end_angle,
+                    fill,
                     stroke: Some((self.style.stroke_color, self.style.stroke_width)),
                 });
             } else {
                 // 饼图模式
                 primitives.push(Primitive::ArcSector {
-                    center: self.center,
+                    center,
                     radius: self.style.outer_radius,
-                    start_angle: current_angle,
-                    end_angle: current_angle + sector_angle,
-                    fill: color,
+                    start_angle: slice.start_angle,
+                    end_angle: slice.end_angle,
+                    fill,
                     stroke: Some((self.style.stroke_color, self.style.stroke_width)),
                 });
             }
 
             // 添加标签
             if self.style.show_labels {
-                let label_angle = current_angle + sector_angle / 2.0;
                 let label_radius = self.style.outer_radius * self.style.label_distance;
-                let label_x = self.center.x + label_radius * label_angle.cos();
-                let label_y = self.center.y + label_radius * label_angle.sin();
+                let label_x = center.x + label_radius * mid_angle.cos();
+                let label_y = center.y + label_radius * mid_angle.sin();
 
                 let label_text = if self.style.show_percentage {
-                    format!("{}\n{:.1}%", item.label, percentage * 100.0)
+                    format!("{}\n{:.1}%", slice.label, slice.fraction * 100.0)
                 } else {
-                    item.label.clone()
+                    slice.label
                 };
 
                 primitives.push(Primitive::Text {
@@ -296,10 +447,9 @@ impl PieChart {
                     color: self.style.label_color,
                     h_align: vizuara_core::HorizontalAlign::Center,
                     v_align: vizuara_core::VerticalAlign::Middle,
+                    rotation: 0.0,
                 });
             }
-
-            current_angle += sector_angle + gap_per_segment;
         }
 
         // 添加标题
@@ -314,6 +464,7 @@ impl PieChart {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: vizuara_core::HorizontalAlign::Center,
                 v_align: vizuara_core::VerticalAlign::Middle,
+                rotation: 0.0,
             });
         }
 
@@ -321,6 +472,13 @@ impl PieChart {
     }
 }
 
+/// 向白色混合提亮颜色，保留透明度
+fn brighten(color: Color, amount: f32) -> Color {
+    let amount = amount.clamp(0.0, 1.0);
+    let lift = |c: f32| c + (1.0 - c) * amount;
+    Color::rgba(lift(color.r), lift(color.g), lift(color.b), color.a)
+}
+
 impl Default for PieChart {
     fn default() -> Self {
         Self::new()
@@ -374,6 +532,44 @@ mod tests {
         assert!(primitives.is_empty());
     }
 
+    #[test]
+    fn test_raw_values_and_other_grouping() {
+        let data = [
+            ("A", 60.0),
+            ("B", 37.0),
+            ("C", 1.0),
+            ("D", 1.5),
+            ("E", -5.0),
+        ];
+        let chart = PieChart::new().data(&data);
+
+        // 原始数值按正值总和归一化，负值忽略
+        let slices = chart.slices();
+        assert_eq!(slices.len(), 4);
+        assert!((slices[0].fraction - 60.0 / 99.5).abs() < 1e-6);
+        assert!((slices.last().unwrap().end_angle - slices[0].start_angle - 2.0 * PI).abs() < 1e-4);
+
+        // 低于 2% 的 C、D 合并为最后一个扇形
+        let grouped = chart
+            .clone()
+            .group_small_slices(0.02)
+            .other_slice("其他", Color::BLACK)
+            .slices();
+        let labels: Vec<&str> = grouped.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(labels, ["A", "B", "其他"]);
+        let other = &grouped[2];
+        assert!(other.is_other);
+        assert_eq!(other.value, 2.5);
+        assert_eq!(other.color, Color::BLACK);
+
+        // 只有一个小扇形时保留原样
+        let single = PieChart::new()
+            .data(&[("A", 99.0), ("B", 1.0)])
+            .group_small_slices(0.02)
+            .slices();
+        assert!(single.iter().all(|s| !s.is_other));
+    }
+
     #[test]
     fn test_single_item_primitives() {
         let data = [("全部", 100.0)];
@@ -381,4 +577,46 @@ mod tests {
         let primitives = chart.generate_primitives(PlotArea::new(0.0, 0.0, 400.0, 400.0));
         assert!(!primitives.is_empty());
     }
+
+    #[test]
+    fn test_hover_hit_test_and_highlight() {
+        // 两个等分扇形，从顶部顺时针（屏幕坐标 Y 向下）：右半为 A，左半为 B
+        let chart = PieChart::new()
+            .data(&[("A", 1.0), ("B", 1.0)])
+            .center(100.0, 100.0)
+            .labels(false, 12.0, Color::BLACK, 1.2);
+        let center = Point2::new(100.0, 100.0);
+        assert_eq!(
+            chart.slice_at_angle(center, Point2::new(150.0, 100.0)),
+            Some(0)
+        );
+        assert_eq!(
+            chart.slice_at_angle(center, Point2::new(50.0, 90.0)),
+            Some(1)
+        );
+        assert_eq!(chart.slice_at_angle(center, center), None);
+
+        let area = PlotArea::new(0.0, 0.0, 200.0, 200.0);
+        let hovered = chart.clone().hovered_slice(Some(0)).hover_offset(10.0);
+        let primitives = hovered.generate_primitives(area);
+        match &primitives[0] {
+            Primitive::ArcSector { center, fill, .. } => {
+                // A 的角平分线指向右侧
+                assert!((center.x - 110.0).abs() < 1e-4);
+                assert!((center.y - 100.0).abs() < 1e-4);
+                let base = chart.slices()[0].color;
+                assert!(fill.r > base.r && fill.g > base.g && fill.b > base.b);
+            }
+            other => panic!("expected sector, got {:?}", other),
+        }
+        assert_eq!(
+            primitives[1],
+            chart.generate_primitives(area)[1],
+            "未悬停的扇形保持原样"
+        );
+
+        // 主题提供的悬停颜色优先
+        let themed = hovered.hover_color(Color::RED).generate_primitives(area);
+        assert!(matches!(themed[0], Primitive::ArcSector { fill, .. } if fill == Color::RED));
+    }
 }
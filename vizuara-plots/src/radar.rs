@@ -1,6 +1,8 @@
 use nalgebra::Point2;
 use std::f32::consts::PI;
-use vizuara_core::{Color, Primitive};
+use vizuara_core::{Color, LinearScale, Primitive, Scale, TickFormatter};
+
+use crate::palette::DEFAULT_SERIES_COLORS;
 
 /// 雷达图维度
 #[derive(Debug, Clone)]
@@ -42,10 +44,10 @@ pub struct RadarSeries {
     pub name: String,
     /// 数据点值
     pub values: Vec<f32>,
-    /// 填充颜色
-    pub fill_color: Color,
-    /// 线条颜色
-    pub line_color: Color,
+    /// 填充颜色（`None` 时取调色板颜色并应用 `fill_alpha`）
+    pub fill_color: Option<Color>,
+    /// 线条颜色（`None` 时取调色板颜色）
+    pub line_color: Option<Color>,
     /// 线条宽度
     pub line_width: f32,
     /// 填充透明度
@@ -61,8 +63,8 @@ impl RadarSeries {
         Self {
             name: name.into(),
             values,
-            fill_color: Color::rgba(0.2, 0.6, 0.9, 0.3),
-            line_color: Color::rgb(0.2, 0.6, 0.9),
+            fill_color: None,
+            line_color: None,
             line_width: 2.0,
             fill_alpha: 0.3,
             show_points: true,
@@ -71,12 +73,12 @@ impl RadarSeries {
     }
 
     pub fn fill_color(mut self, color: Color) -> Self {
-        self.fill_color = color;
+        self.fill_color = Some(color);
         self
     }
 
     pub fn line_color(mut self, color: Color) -> Self {
-        self.line_color = color;
+        self.line_color = Some(color);
         self
     }
 
@@ -159,22 +161,13 @@ pub struct RadarChart {
     start_angle: f32,
     /// 标题
     title: Option<String>,
-    /// 默认颜色
-    default_colors: Vec<(Color, Color)>, // (fill, line)
+    /// 系列默认颜色
+    default_colors: Vec<Color>,
 }
 
 impl RadarChart {
     /// 创建新的雷达图
     pub fn new() -> Self {
-        let default_colors = vec![
-            (Color::rgba(0.2, 0.6, 0.9, 0.3), Color::rgb(0.2, 0.6, 0.9)), // 蓝色
-            (Color::rgba(0.9, 0.5, 0.2, 0.3), Color::rgb(0.9, 0.5, 0.2)), // 橙色
-            (Color::rgba(0.4, 0.8, 0.4, 0.3), Color::rgb(0.4, 0.8, 0.4)), // 绿色
-            (Color::rgba(0.9, 0.3, 0.3, 0.3), Color::rgb(0.9, 0.3, 0.3)), // 红色
-            (Color::rgba(0.7, 0.4, 0.9, 0.3), Color::rgb(0.7, 0.4, 0.9)), // 紫色
-            (Color::rgba(0.9, 0.9, 0.3, 0.3), Color::rgb(0.9, 0.9, 0.3)), // 黄色
-        ];
-
         Self {
             dimensions: Vec::new(),
             series: Vec::new(),
@@ -183,7 +176,7 @@ impl RadarChart {
             radius: 150.0,
             start_angle: -PI / 2.0, // 从顶部开始
             title: None,
-            default_colors,
+            default_colors: DEFAULT_SERIES_COLORS.to_vec(),
         }
     }
 
@@ -227,6 +220,14 @@ impl RadarChart {
         self
     }
 
+    /// 使用调色板（例如主题的 `ColorPalette::series`）设置系列默认颜色
+    pub fn palette(mut self, colors: &[Color]) -> Self {
+        if !colors.is_empty() {
+            self.default_colors = colors.to_vec();
+        }
+        self
+    }
+
     /// 设置中心点和半径
     pub fn center_radius(mut self, center_x: f32, center_y: f32, radius: f32) -> Self {
         self.center = Point2::new(center_x, center_y);
@@ -272,6 +273,92 @@ impl RadarChart {
         self.series.len()
     }
 
+    /// 图例条目：每个系列的名称和线条颜色
+    pub fn legend_items(&self) -> Vec<(String, Color)> {
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(i, series)| (series.name.clone(), self.series_colors(i, series).1))
+            .collect()
+    }
+
+    /// 网格环：(半径比例, 刻度标签)
+    ///
+    /// 所有维度共享同一范围时，环按该范围上的整齐数值分布并标注实际数值；
+    /// 否则按层数等分并标注比例。
+    pub fn grid_rings(&self) -> Vec<(f32, String)> {
+        let levels = self.style.grid_levels.max(1);
+
+        if let Some((min, max)) = self.shared_range() {
+            // 刻度数量含中心处的起点，环数为 levels
+            let ticks = LinearScale::new(min, max).ticks(levels + 1);
+            let step = match ticks.as_slice() {
+                [first, second, ..] => second - first,
+                _ => max - min,
+            };
+            let formatter = TickFormatter::fixed(step_decimals(step));
+            let tolerance = step * 1e-3;
+            let mut rings: Vec<(f32, String)> = ticks
+                .into_iter()
+                .filter(|&value| value > min + tolerance && value < max - tolerance)
+                .map(|value| ((value - min) / (max - min), formatter.format(value)))
+                .collect();
+            rings.push((1.0, formatter.format(max)));
+            return rings;
+        }
+
+        (1..=levels)
+            .map(|level| {
+                let ratio = level as f32 / levels as f32;
+                (ratio, format!("{:.1}", ratio))
+            })
+            .collect()
+    }
+
+    /// 所有维度共享的取值范围
+    fn shared_range(&self) -> Option<(f32, f32)> {
+        let first = self.dimensions.first()?;
+        if first.max_value <= first.min_value {
+            return None;
+        }
+        let shared = self
+            .dimensions
+            .iter()
+            .all(|d| d.min_value == first.min_value && d.max_value == first.max_value);
+        if shared {
+            Some((first.min_value, first.max_value))
+        } else {
+            None
+        }
+    }
+
+    /// 第 i 条辐射轴的角度
+    fn spoke_angle(&self, index: usize) -> f32 {
+        self.start_angle + (index as f32) * 2.0 * PI / (self.dimensions.len() as f32)
+    }
+
+    /// 第 i 条辐射轴上给定半径比例处的坐标
+    fn spoke_point(&self, index: usize, ratio: f32) -> Point2<f32> {
+        let angle = self.spoke_angle(index);
+        Point2::new(
+            self.center.x + self.radius * ratio * angle.cos(),
+            self.center.y + self.radius * ratio * angle.sin(),
+        )
+    }
+
+    /// 系列的 (填充, 线条) 颜色；未自定义时取调色板颜色
+    fn series_colors(&self, index: usize, series: &RadarSeries) -> (Color, Color) {
+        let default = self.default_colors[index % self.default_colors.len()];
+        let fill_color = series.fill_color.unwrap_or(Color {
+            a: series.fill_alpha,
+            ..default
+        });
+        let line_color = series
+            .line_color
+            .unwrap_or(Color::rgb(default.r, default.g, default.b));
+        (fill_color, line_color)
+    }
+
     /// 生成渲染图元
     pub fn generate_primitives(&self, _plot_area: super::PlotArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
@@ -301,6 +388,7 @@ impl RadarChart {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: vizuara_core::HorizontalAlign::Center,
                 v_align: vizuara_core::VerticalAlign::Middle,
+                rotation: 0.0,
             });
         }
 
@@ -314,43 +402,35 @@ impl RadarChart {
         }
 
         // 绘制同心多边形网格
-        for level in 1..=self.style.grid_levels {
-            let level_radius = self.radius * (level as f32) / (self.style.grid_levels as f32);
-            let mut grid_points = Vec::new();
-
-            for i in 0..dim_count {
-                let angle = self.start_angle + (i as f32) * 2.0 * PI / (dim_count as f32);
-                let x = self.center.x + level_radius * angle.cos();
-                let y = self.center.y + level_radius * angle.sin();
-                grid_points.push(Point2::new(x, y));
-            }
+        let rings = self.grid_rings();
+        let ring_count = rings.len();
+        for (ring_idx, (ratio, label)) in rings.into_iter().enumerate() {
+            let mut grid_points: Vec<Point2<f32>> =
+                (0..dim_count).map(|i| self.spoke_point(i, ratio)).collect();
 
             // 闭合多边形
             if let Some(first_point) = grid_points.first().cloned() {
                 grid_points.push(first_point);
             }
 
-            if grid_points.len() >= 2 {
-                primitives.push(Primitive::Polyline {
-                    points: grid_points,
-                    color: self.style.grid_color,
-                    width: self.style.grid_width,
-                });
-            }
+            primitives.push(Primitive::Polyline {
+                points: grid_points,
+                color: self.style.grid_color,
+                width: self.style.grid_width,
+            });
 
-            // 添加刻度值标签
-            if self.style.show_scale_values && level < self.style.grid_levels {
-                let value_ratio = level as f32 / self.style.grid_levels as f32;
-                let label_x = self.center.x + level_radius * self.start_angle.cos() + 5.0;
-                let label_y = self.center.y + level_radius * self.start_angle.sin();
+            // 添加刻度值标签（最外层与维度标签重叠，跳过）
+            if self.style.show_scale_values && ring_idx + 1 < ring_count {
+                let anchor = self.spoke_point(0, ratio);
 
                 primitives.push(Primitive::Text {
-                    position: Point2::new(label_x, label_y),
-                    content: format!("{:.1}", value_ratio),
+                    position: Point2::new(anchor.x + 5.0, anchor.y),
+                    content: label,
                     size: self.style.scale_value_size,
                     color: self.style.scale_value_color,
                     h_align: vizuara_core::HorizontalAlign::Left,
                     v_align: vizuara_core::VerticalAlign::Middle,
+                    rotation: 0.0,
                 });
             }
         }
@@ -360,26 +440,20 @@ impl RadarChart {
         let dim_count = self.dimensions.len();
 
         for i in 0..dim_count {
-            let angle = self.start_angle + (i as f32) * 2.0 * PI / (dim_count as f32);
-            let end_x = self.center.x + self.radius * angle.cos();
-            let end_y = self.center.y + self.radius * angle.sin();
-
             primitives.push(Primitive::Line {
                 start: self.center,
-                end: Point2::new(end_x, end_y),
+                end: self.spoke_point(i, 1.0),
             });
         }
     }
 
     fn draw_labels(&self, primitives: &mut Vec<Primitive>) {
-        let dim_count = self.dimensions.len();
-
         for (i, dimension) in self.dimensions.iter().enumerate() {
             if !dimension.show_label {
                 continue;
             }
 
-            let angle = self.start_angle + (i as f32) * 2.0 * PI / (dim_count as f32);
+            let angle = self.spoke_angle(i);
             let label_radius = self.radius * self.style.label_distance;
             let label_x = self.center.x + label_radius * angle.cos();
             let label_y = self.center.y + label_radius * angle.sin();
@@ -408,6 +482,7 @@ impl RadarChart {
                 color: self.style.label_color,
                 h_align,
                 v_align,
+                rotation: 0.0,
             });
         }
     }
@@ -423,72 +498,37 @@ impl RadarChart {
                 continue; // 跳过维度数量不匹配的系列
             }
 
-            // 选择颜色
-            let (default_fill, default_line) =
-                self.default_colors[series_idx % self.default_colors.len()];
-            let fill_color = if series.fill_color == Color::rgba(0.2, 0.6, 0.9, 0.3) {
-                default_fill
-            } else {
-                series.fill_color
-            };
-            let line_color = if series.line_color == Color::rgb(0.2, 0.6, 0.9) {
-                default_line
-            } else {
-                series.line_color
-            };
-
-            // 计算数据点坐标
-            let mut data_points = Vec::new();
-            for (i, &value) in series.values.iter().enumerate() {
-                let dimension = &self.dimensions[i];
-                let normalized_value = dimension.normalize(value);
-                let point_radius = self.radius * normalized_value;
-
-                let angle = self.start_angle + (i as f32) * 2.0 * PI / (dim_count as f32);
-                let x = self.center.x + point_radius * angle.cos();
-                let y = self.center.y + point_radius * angle.sin();
-                data_points.push(Point2::new(x, y));
-            }
+            let (fill_color, line_color) = self.series_colors(series_idx, series);
 
-            // 闭合多边形
-            if let Some(first_point) = data_points.first().cloned() {
-                data_points.push(first_point);
-            }
+            // 计算数据点坐标：同一维度上的数值对所有系列使用相同的径向比例
+            let data_points: Vec<Point2<f32>> = series
+                .values
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| self.spoke_point(i, self.dimensions[i].normalize(value)))
+                .collect();
 
             // 绘制填充区域
-            if data_points.len() >= 4 {
-                let mut fill_points = data_points.clone();
-                fill_points.pop(); // 移除重复的最后一个点
-
-                primitives.push(Primitive::Polygon {
-                    points: fill_points,
-                    fill: fill_color,
-                    stroke: None,
-                });
-            }
+            primitives.push(Primitive::Polygon {
+                points: data_points.clone(),
+                fill: fill_color,
+                stroke: None,
+            });
 
-            // 绘制边界线
-            if data_points.len() >= 2 {
-                primitives.push(Primitive::Polyline {
-                    points: data_points.clone(),
-                    color: line_color,
-                    width: series.line_width,
-                });
-            }
+            // 绘制边界线（闭合）
+            let mut outline = data_points.clone();
+            outline.push(data_points[0]);
+            primitives.push(Primitive::Polyline {
+                points: outline,
+                color: line_color,
+                width: series.line_width,
+            });
 
             // 绘制数据点
             if series.show_points {
-                for (i, &value) in series.values.iter().enumerate() {
-                    let dimension = &self.dimensions[i];
-                    let normalized_value = dimension.normalize(value);
-                    let point_radius = self.radius * normalized_value;
-
-                    let angle = self.start_angle + (i as f32) * 2.0 * PI / (dim_count as f32);
-                    let x = self.center.x + point_radius * angle.cos();
-                    let y = self.center.y + point_radius * angle.sin();
-
+                for point in data_points {
                     primitives.push(Primitive::Circle {
-                        center: Point2::new(x, y),
+                        center: point,
                         radius: series.point_size,
                     });
                 }
@@ -497,6 +537,16 @@ impl RadarChart {
     }
 }
 
+/// 完整显示步长所需的小数位数（如 2.5 为 1 位，0.25 为 2 位）
+fn step_decimals(step: f32) -> usize {
+    (0..6)
+        .find(|&decimals| {
+            let scaled = step * 10f32.powi(decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-3 * scaled.abs().max(1.0)
+        })
+        .unwrap_or(6)
+}
+
 impl Default for RadarChart {
     fn default() -> Self {
         Self::new()
@@ -555,4 +605,86 @@ mod tests {
         assert_eq!(chart.dimensions[0].min_value, 0.0);
         assert_eq!(chart.dimensions[0].max_value, 10.0);
     }
+
+    #[test]
+    fn test_multi_series_overlay() {
+        let palette = [Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 0.0, 1.0)];
+        let chart = RadarChart::new()
+            .simple_dimensions(&["A", "B", "C", "D"], 0.0, 100.0)
+            .palette(&palette)
+            .add_data("甲", vec![50.0, 80.0, 20.0, 60.0])
+            .add_data("乙", vec![50.0, 40.0, 90.0, 10.0]);
+
+        let primitives = chart.generate_primitives(crate::PlotArea::new(0.0, 0.0, 400.0, 400.0));
+        let polygons: Vec<(&Vec<Point2<f32>>, Color)> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::Polygon { points, fill, .. } => Some((points, *fill)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(polygons[0].1, Color::rgba(1.0, 0.0, 0.0, 0.3));
+        assert_eq!(polygons[1].1, Color::rgba(0.0, 0.0, 1.0, 0.3));
+        // 相同维度上的相同数值落在同一位置
+        assert_eq!(polygons[0].0[0], polygons[1].0[0]);
+
+        let legend = chart.legend_items();
+        assert_eq!(legend[0], ("甲".to_string(), palette[0]));
+        assert_eq!(legend[1], ("乙".to_string(), palette[1]));
+    }
+
+    #[test]
+    fn test_explicit_colors_override_palette() {
+        // 显式选择与默认调色板相同的蓝色，也不应被调色板颜色替换
+        let blue = Color::rgb(0.2, 0.6, 0.9);
+        let chart = RadarChart::new()
+            .simple_dimensions(&["A", "B", "C"], 0.0, 100.0)
+            .palette(&[Color::rgb(1.0, 0.0, 0.0)])
+            .add_series(
+                RadarSeries::new("甲", vec![10.0, 20.0, 30.0])
+                    .fill_color(Color::rgba(0.2, 0.6, 0.9, 0.3))
+                    .line_color(blue),
+            )
+            .add_series(RadarSeries::new("乙", vec![30.0, 20.0, 10.0]).fill_alpha(0.5));
+
+        let legend = chart.legend_items();
+        assert_eq!(legend[0].1, blue);
+        assert_eq!(legend[1].1, Color::rgb(1.0, 0.0, 0.0));
+
+        let fills: Vec<Color> = chart
+            .generate_primitives(crate::PlotArea::new(0.0, 0.0, 400.0, 400.0))
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::Polygon { fill, .. } => Some(*fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills[0], Color::rgba(0.2, 0.6, 0.9, 0.3));
+        assert_eq!(fills[1], Color::rgba(1.0, 0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn test_grid_rings_nice_values() {
+        let chart = RadarChart::new().simple_dimensions(&["A", "B", "C"], 0.0, 100.0);
+        let labels: Vec<String> = chart.grid_rings().into_iter().map(|(_, l)| l).collect();
+        assert_eq!(labels, vec!["20", "40", "60", "80", "100"]);
+
+        // 步长含小数时按步长精度标注，起点不在刻度上时从其后第一个刻度开始
+        let chart = RadarChart::new()
+            .simple_dimensions(&["A", "B", "C"], 0.3, 1.0)
+            .grid_style(Color::BLACK, 1.0, 3);
+        let labels: Vec<String> = chart.grid_rings().into_iter().map(|(_, l)| l).collect();
+        assert_eq!(labels, vec!["0.4", "0.6", "0.8", "1.0"]);
+
+        // 维度范围不同时按比例等分
+        let chart = RadarChart::new()
+            .add_dimension(RadarDimension::new("A", 0.0, 10.0))
+            .add_dimension(RadarDimension::new("B", 0.0, 50.0))
+            .add_dimension(RadarDimension::new("C", 0.0, 1.0));
+        let rings = chart.grid_rings();
+        assert_eq!(rings.len(), 5);
+        assert_eq!(rings[4].0, 1.0);
+    }
 }
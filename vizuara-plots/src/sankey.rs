@@ -4,13 +4,44 @@
 
 use crate::PlotArea;
 use nalgebra::Point2;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use vizuara_core::{Color, HorizontalAlign, Primitive, VerticalAlign};
 
-/// 布局计算结果类型别名
-/// 格式: (id, x, y, width, height)
-type NodeLayout = Vec<(String, f32, f32, f32, f32)>;
-/// 格式: (x1, y1, x2, y2, value)
-type LinkLayout = Vec<(f32, f32, f32, f32, f32)>;
+/// 布局外边距
+const LAYOUT_MARGIN: f32 = 50.0;
+/// 交叉最小化的重心排序迭代次数
+const ORDERING_ITERATIONS: usize = 6;
+/// 每段二次贝塞尔曲线的采样数
+const CURVE_SEGMENTS: usize = 12;
+
+/// 节点布局结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct SankeyNodeLayout {
+    /// 在节点列表中的索引
+    pub node_index: usize,
+    /// 所在列（按从源节点出发的最长路径分层）
+    pub layer: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// 节点流量：流入与流出总量中的较大者
+    pub throughput: f32,
+}
+
+/// 链接布局结果（条带的上边缘端点与厚度）
+#[derive(Debug, Clone, PartialEq)]
+pub struct SankeyLinkLayout {
+    /// 在链接列表中的索引
+    pub link_index: usize,
+    pub source_x: f32,
+    pub source_y: f32,
+    pub target_x: f32,
+    pub target_y: f32,
+    /// 条带厚度，与链接数值成正比，和节点高度使用同一比例
+    pub thickness: f32,
+}
 
 /// 桑基图节点
 #[derive(Debug, Clone)]
@@ -152,55 +183,298 @@ impl SankeyDiagram {
         self
     }
 
-    /// 计算简单的层次布局
-    fn compute_layout(&self, plot_area: PlotArea) -> (NodeLayout, LinkLayout) {
-        let mut nodes_layout = Vec::new();
-        let mut links_layout = Vec::new();
+    /// 有效链接：两端节点存在、数值为正且不是自环，返回 (链接索引, 源节点索引, 目标节点索引)
+    fn valid_links(&self) -> Vec<(usize, usize, usize)> {
+        let index: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id.as_str(), i))
+            .collect();
+
+        self.links
+            .iter()
+            .enumerate()
+            .filter(|(_, link)| link.value > 0.0)
+            .filter_map(|(i, link)| {
+                let source = *index.get(link.source.as_str())?;
+                let target = *index.get(link.target.as_str())?;
+                (source != target).then_some((i, source, target))
+            })
+            .collect()
+    }
 
-        // 简单的垂直分布
-        let available_height = plot_area.height - 100.0;
-        let node_height = available_height / self.nodes.len() as f32;
+    /// 构成环的回边（链接索引）
+    ///
+    /// 按节点顺序深度优先搜索，指向搜索栈中节点的链接即为回边。
+    /// 布局时忽略这些链接，以保证分层能够终止。
+    pub fn cyclic_links(&self) -> Vec<usize> {
+        let links = self.valid_links();
+        let mut outgoing: Vec<Vec<(usize, usize)>> = vec![Vec::new(); self.nodes.len()];
+        for &(link, source, target) in &links {
+            outgoing[source].push((link, target));
+        }
 
-        for (i, node) in self.nodes.iter().enumerate() {
-            let y = plot_area.y + 50.0 + i as f32 * node_height;
-            let x = if i < self.nodes.len() / 2 {
-                plot_area.x + 50.0 // 源节点
-            } else {
-                plot_area.x + plot_area.width - 50.0 - self.style.node_width // 目标节点
-            };
+        // 0 = 未访问, 1 = 在栈中, 2 = 已完成
+        let mut state = vec![0u8; self.nodes.len()];
+        let mut back_edges = Vec::new();
 
-            nodes_layout.push((
-                node.id.clone(),
-                x,
-                y,
-                self.style.node_width,
-                node_height * 0.8,
-            ));
+        for start in 0..self.nodes.len() {
+            if state[start] != 0 {
+                continue;
+            }
+            state[start] = 1;
+            let mut stack = vec![(start, 0usize)];
+
+            while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+                if let Some(&(link, target)) = outgoing[node].get(*next) {
+                    *next += 1;
+                    match state[target] {
+                        0 => {
+                            state[target] = 1;
+                            stack.push((target, 0));
+                        }
+                        1 => back_edges.push(link),
+                        _ => {}
+                    }
+                } else {
+                    state[node] = 2;
+                    stack.pop();
+                }
+            }
         }
 
-        // 简单的链接布局
-        for link in &self.links {
-            // 找到源和目标节点的位置
-            let source_pos = nodes_layout
-                .iter()
-                .find(|(id, _, _, _, _)| id == &link.source);
-            let target_pos = nodes_layout
-                .iter()
-                .find(|(id, _, _, _, _)| id == &link.target);
-
-            if let (Some((_, sx, sy, sw, sh)), Some((_, tx, ty, _, _))) = (source_pos, target_pos) {
-                links_layout.push((
-                    sx + sw,
-                    sy + sh / 2.0,
-                    *tx,
-                    ty + sh / 2.0,
-                    link.value / 100.0, // 简单的粗细映射
-                ));
+        back_edges.sort_unstable();
+        back_edges
+    }
+
+    /// 自动布局：最长路径分层、重心法减少交叉、按流量确定节点高度
+    pub fn layout(&self, plot_area: PlotArea) -> (Vec<SankeyNodeLayout>, Vec<SankeyLinkLayout>) {
+        let node_count = self.nodes.len();
+        if node_count == 0 {
+            return (Vec::new(), Vec::new());
+        }
+
+        let cyclic = self.cyclic_links();
+        let links: Vec<(usize, usize, usize)> = self
+            .valid_links()
+            .into_iter()
+            .filter(|(link, _, _)| cyclic.binary_search(link).is_err())
+            .collect();
+
+        // 节点流量
+        let mut inflow = vec![0.0f32; node_count];
+        let mut outflow = vec![0.0f32; node_count];
+        for &(link, source, target) in &links {
+            outflow[source] += self.links[link].value;
+            inflow[target] += self.links[link].value;
+        }
+        let throughput: Vec<f32> = (0..node_count).map(|i| inflow[i].max(outflow[i])).collect();
+
+        // 最长路径分层（Kahn 拓扑排序）
+        let mut in_degree = vec![0usize; node_count];
+        for &(_, _, target) in &links {
+            in_degree[target] += 1;
+        }
+        let mut layer = vec![0usize; node_count];
+        let mut queue: Vec<usize> = (0..node_count).filter(|&i| in_degree[i] == 0).collect();
+        while let Some(node) = queue.pop() {
+            for &(_, source, target) in &links {
+                if source == node {
+                    layer[target] = layer[target].max(layer[node] + 1);
+                    in_degree[target] -= 1;
+                    if in_degree[target] == 0 {
+                        queue.push(target);
+                    }
+                }
+            }
+        }
+
+        let layer_count = layer.iter().max().map_or(1, |m| m + 1);
+        let mut columns: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+        for node in 0..node_count {
+            columns[layer[node]].push(node);
+        }
+
+        self.order_columns(&mut columns, &links, &layer);
+
+        // 所有列共用同一个数值到像素的比例，保证高度可比
+        let left = plot_area.x + LAYOUT_MARGIN;
+        let top = plot_area.y + LAYOUT_MARGIN;
+        let available_width = (plot_area.width - 2.0 * LAYOUT_MARGIN).max(self.style.node_width);
+        let available_height = (plot_area.height - 2.0 * LAYOUT_MARGIN).max(0.0);
+
+        let scale = columns
+            .iter()
+            .filter(|column| !column.is_empty())
+            .map(|column| {
+                let total: f32 = column.iter().map(|&n| throughput[n]).sum();
+                let free = available_height - (column.len() - 1) as f32 * self.style.node_padding;
+                if total > 0.0 {
+                    (free / total).max(0.0)
+                } else {
+                    f32::INFINITY
+                }
+            })
+            .fold(f32::INFINITY, f32::min);
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+
+        let column_step = if layer_count > 1 {
+            (available_width - self.style.node_width) / (layer_count - 1) as f32
+        } else {
+            0.0
+        };
+
+        let mut node_layouts: Vec<Option<SankeyNodeLayout>> = vec![None; node_count];
+        for (column_index, column) in columns.iter().enumerate() {
+            let used: f32 = column.iter().map(|&n| throughput[n] * scale).sum::<f32>()
+                + column.len().saturating_sub(1) as f32 * self.style.node_padding;
+            let mut y = top + (available_height - used).max(0.0) / 2.0;
+
+            for &node in column {
+                let height = throughput[node] * scale;
+                node_layouts[node] = Some(SankeyNodeLayout {
+                    node_index: node,
+                    layer: column_index,
+                    x: left + column_index as f32 * column_step,
+                    y,
+                    width: self.style.node_width,
+                    height,
+                    throughput: throughput[node],
+                });
+                y += height + self.style.node_padding;
+            }
+        }
+        let node_layouts: Vec<SankeyNodeLayout> = node_layouts.into_iter().flatten().collect();
+
+        // 链接条带：在节点上按对端位置从上到下依次堆叠
+        let center_y = |node: usize| node_layouts[node].y + node_layouts[node].height / 2.0;
+        let mut by_source = links.clone();
+        by_source.sort_by(|a, b| {
+            (a.1, center_y(a.2))
+                .partial_cmp(&(b.1, center_y(b.2)))
+                .unwrap_or(Ordering::Equal)
+        });
+        let mut by_target = links.clone();
+        by_target.sort_by(|a, b| {
+            (a.2, center_y(a.1))
+                .partial_cmp(&(b.2, center_y(b.1)))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let mut source_offset = vec![0.0f32; node_count];
+        let mut source_y = HashMap::new();
+        for &(link, source, _) in &by_source {
+            source_y.insert(link, node_layouts[source].y + source_offset[source]);
+            source_offset[source] += self.links[link].value * scale;
+        }
+        let mut target_offset = vec![0.0f32; node_count];
+        let mut target_y = HashMap::new();
+        for &(link, _, target) in &by_target {
+            target_y.insert(link, node_layouts[target].y + target_offset[target]);
+            target_offset[target] += self.links[link].value * scale;
+        }
+
+        let link_layouts = links
+            .iter()
+            .map(|&(link, source, target)| SankeyLinkLayout {
+                link_index: link,
+                source_x: node_layouts[source].x + node_layouts[source].width,
+                source_y: source_y[&link],
+                target_x: node_layouts[target].x,
+                target_y: target_y[&link],
+                thickness: self.links[link].value * scale,
+            })
+            .collect();
+
+        (node_layouts, link_layouts)
+    }
+
+    /// 重心法排序：交替按上游/下游相邻节点的加权平均位置排序各列，减少链接交叉
+    fn order_columns(
+        &self,
+        columns: &mut [Vec<usize>],
+        links: &[(usize, usize, usize)],
+        layer: &[usize],
+    ) {
+        let mut position = vec![0.0f32; layer.len()];
+        let update_positions = |columns: &[Vec<usize>], position: &mut [f32]| {
+            for column in columns {
+                for (i, &node) in column.iter().enumerate() {
+                    position[node] = i as f32;
+                }
+            }
+        };
+        update_positions(columns, &mut position);
+
+        for iteration in 0..ORDERING_ITERATIONS {
+            let downstream = iteration % 2 == 0;
+            let column_order: Vec<usize> = if downstream {
+                (1..columns.len()).collect()
+            } else {
+                (0..columns.len().saturating_sub(1)).rev().collect()
+            };
+
+            for column_index in column_order {
+                let barycenter = |node: usize| {
+                    let mut weighted = 0.0;
+                    let mut total = 0.0;
+                    for &(link, source, target) in links {
+                        let neighbor = if downstream && target == node {
+                            source
+                        } else if !downstream && source == node {
+                            target
+                        } else {
+                            continue;
+                        };
+                        let value = self.links[link].value;
+                        weighted += position[neighbor] * value;
+                        total += value;
+                    }
+                    // 没有相邻节点时保持当前位置
+                    if total > 0.0 {
+                        weighted / total
+                    } else {
+                        position[node]
+                    }
+                };
+
+                let mut keyed: Vec<(f32, usize)> = columns[column_index]
+                    .iter()
+                    .map(|&node| (barycenter(node), node))
+                    .collect();
+                keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+                columns[column_index] = keyed.into_iter().map(|(_, node)| node).collect();
+
+                for (i, &node) in columns[column_index].iter().enumerate() {
+                    position[node] = i as f32;
+                }
             }
         }
+    }
+}
 
-        (nodes_layout, links_layout)
+/// 由两段二次贝塞尔曲线组成的 S 形曲线（中点处切线水平）
+fn flow_curve(x0: f32, y0: f32, x1: f32, y1: f32) -> Vec<Point2<f32>> {
+    let mid_x = (x0 + x1) / 2.0;
+    let mid = Point2::new(mid_x, (y0 + y1) / 2.0);
+    let segments = [
+        (Point2::new(x0, y0), Point2::new(mid_x, y0), mid),
+        (mid, Point2::new(mid_x, y1), Point2::new(x1, y1)),
+    ];
+
+    let mut points = vec![Point2::new(x0, y0)];
+    for (p0, c, p1) in segments {
+        for step in 1..=CURVE_SEGMENTS {
+            let t = step as f32 / CURVE_SEGMENTS as f32;
+            let u = 1.0 - t;
+            points.push(Point2::new(
+                u * u * p0.x + 2.0 * u * t * c.x + t * t * p1.x,
+                u * u * p0.y + 2.0 * u * t * c.y + t * t * p1.y,
+            ));
+        }
     }
+    points
 }
 
 impl SankeyDiagram {
@@ -208,26 +482,65 @@ impl SankeyDiagram {
     pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
-        let (nodes_layout, links_layout) = self.compute_layout(plot_area);
+        let (nodes_layout, links_layout) = self.layout(plot_area);
 
-        // 渲染链接（在节点下方）
-        for (i, (x1, y1, x2, y2, thickness)) in links_layout.iter().enumerate() {
-            let link = &self.links[i];
-            let color = link.color.unwrap_or(self.style.default_link_color);
-
-            // 简单的直线连接
-            let points = vec![Point2::new(*x1, *y1), Point2::new(*x2, *y2)];
+        // 渲染链接（在节点下方）：厚度等于链接数值的填充条带
+        for link_layout in &links_layout {
+            let link = &self.links[link_layout.link_index];
+            let color = link.color.unwrap_or_else(|| {
+                let source = self.nodes.iter().find(|n| n.id == link.source);
+                let base = source.map_or(self.style.default_link_color, |n| n.color);
+                Color::rgba(base.r, base.g, base.b, self.style.link_opacity)
+            });
 
-            primitives.push(Primitive::Polyline {
+            let top = flow_curve(
+                link_layout.source_x,
+                link_layout.source_y,
+                link_layout.target_x,
+                link_layout.target_y,
+            );
+            let mut points = top.clone();
+            points.extend(
+                top.iter()
+                    .rev()
+                    .map(|p| Point2::new(p.x, p.y + link_layout.thickness)),
+            );
+
+            primitives.push(Primitive::Polygon {
                 points,
-                color,
-                width: thickness.max(2.0),
+                fill: color,
+                stroke: None,
             });
+
+            if self.style.show_link_labels {
+                primitives.push(Primitive::Text {
+                    position: Point2::new(
+                        (link_layout.source_x + link_layout.target_x) / 2.0,
+                        (link_layout.source_y + link_layout.target_y + link_layout.thickness) / 2.0,
+                    ),
+                    content: link
+                        .label
+                        .clone()
+                        .unwrap_or_else(|| format!("{}", link.value)),
+                    size: self.style.label_size * 0.9,
+                    color: self.style.label_color,
+                    h_align: HorizontalAlign::Center,
+                    v_align: VerticalAlign::Middle,
+                    rotation: 0.0,
+                });
+            }
         }
 
         // 渲染节点
-        for (node_id, x, y, width, height) in nodes_layout {
-            let node = self.nodes.iter().find(|n| n.id == node_id).unwrap();
+        let last_layer = nodes_layout.iter().map(|n| n.layer).max().unwrap_or(0);
+        for node_layout in &nodes_layout {
+            let node = &self.nodes[node_layout.node_index];
+            let (x, y, width, height) = (
+                node_layout.x,
+                node_layout.y,
+                node_layout.width,
+                node_layout.height,
+            );
 
             primitives.push(Primitive::RectangleStyled {
                 min: Point2::new(x, y),
@@ -236,16 +549,22 @@ impl SankeyDiagram {
                 stroke: Some((Color::rgb(0.3, 0.3, 0.3), 1.0)),
             });
 
-            // 添加标签
+            // 添加标签：最后一列放在节点左侧，其余放在右侧
             if self.style.show_node_labels {
                 if let Some(label) = &node.label {
+                    let (label_x, h_align) = if node_layout.layer == last_layer && last_layer > 0 {
+                        (x - 6.0, HorizontalAlign::Right)
+                    } else {
+                        (x + width + 6.0, HorizontalAlign::Left)
+                    };
                     primitives.push(Primitive::Text {
-                        position: Point2::new(x + width / 2.0, y + height / 2.0),
+                        position: Point2::new(label_x, y + height / 2.0),
                         content: label.clone(),
                         size: self.style.label_size,
                         color: self.style.label_color,
-                        h_align: HorizontalAlign::Center,
+                        h_align,
                         v_align: VerticalAlign::Middle,
+                        rotation: 0.0,
                     });
                 }
             }
@@ -260,9 +579,106 @@ impl SankeyDiagram {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: HorizontalAlign::Center,
                 v_align: VerticalAlign::Middle,
+                rotation: 0.0,
             });
         }
 
         primitives
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str) -> SankeyNode {
+        SankeyNode::new(id.to_string(), Color::rgb(0.5, 0.5, 0.5))
+    }
+
+    fn link(source: &str, target: &str, value: f32) -> SankeyLink {
+        SankeyLink {
+            source: source.to_string(),
+            target: target.to_string(),
+            value,
+            color: None,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_longest_path_layering_and_heights() {
+        let sankey = SankeyDiagram::new()
+            .add_node(node("a"))
+            .add_node(node("b"))
+            .add_node(node("c"))
+            .add_link(link("a", "b", 10.0))
+            .add_link(link("b", "c", 4.0))
+            .add_link(link("a", "c", 6.0));
+
+        let (nodes, links) = sankey.layout(PlotArea::new(0.0, 0.0, 400.0, 300.0));
+        let layers: Vec<usize> = nodes.iter().map(|n| n.layer).collect();
+        // c 通过最长路径 a -> b -> c 落在第三列
+        assert_eq!(layers, vec![0, 1, 2]);
+
+        // 节点高度与流量成正比，链接厚度使用同一比例
+        let scale = nodes[0].height / nodes[0].throughput;
+        assert!((nodes[2].height - 10.0 * scale).abs() < 1e-3);
+        for l in &links {
+            assert!((l.thickness - sankey.links[l.link_index].value * scale).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_cycle_is_broken() {
+        let sankey = SankeyDiagram::new()
+            .add_node(node("a"))
+            .add_node(node("b"))
+            .add_node(node("c"))
+            .add_link(link("a", "b", 5.0))
+            .add_link(link("b", "c", 5.0))
+            .add_link(link("c", "a", 2.0));
+
+        assert_eq!(sankey.cyclic_links(), vec![2]);
+        let (nodes, links) = sankey.layout(PlotArea::new(0.0, 0.0, 400.0, 300.0));
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(links.len(), 2);
+        assert_eq!(nodes[2].layer, 2);
+    }
+
+    #[test]
+    fn test_barycenter_ordering_removes_crossing() {
+        // 初始顺序下 s1->t2、s2->t1 交叉，重心排序后应消除
+        let sankey = SankeyDiagram::new()
+            .add_node(node("s1"))
+            .add_node(node("s2"))
+            .add_node(node("t1"))
+            .add_node(node("t2"))
+            .add_link(link("s1", "t2", 5.0))
+            .add_link(link("s2", "t1", 5.0));
+
+        let (nodes, _) = sankey.layout(PlotArea::new(0.0, 0.0, 400.0, 300.0));
+        let y = |id: &str| {
+            nodes
+                .iter()
+                .find(|n| sankey.nodes[n.node_index].id == id)
+                .unwrap()
+                .y
+        };
+        assert_eq!(y("s1") < y("s2"), y("t2") < y("t1"));
+    }
+
+    #[test]
+    fn test_link_ribbons_are_polygons() {
+        let sankey = SankeyDiagram::new()
+            .add_node(node("a"))
+            .add_node(node("b"))
+            .add_link(link("a", "b", 3.0));
+
+        let primitives = sankey.generate_primitives(PlotArea::new(0.0, 0.0, 400.0, 300.0));
+        let ribbons = primitives
+            .iter()
+            .filter(|p| matches!(p, Primitive::Polygon { .. }))
+            .count();
+        assert_eq!(ribbons, 1);
+    }
+}
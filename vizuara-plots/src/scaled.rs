@@ -0,0 +1,93 @@
+use crate::scatter::point_bounds;
+use crate::PlotArea;
+use vizuara_core::{LinearScale, Primitive, Scale, Style};
+
+/// 绘制在笛卡尔坐标轴上的图表：可直接使用外部传入的比例尺（如场景的共享坐标轴，
+/// 包括对数轴与时间轴）生成图元，无需替换自身比例尺
+pub trait ScaledPlot {
+    /// 使用给定比例尺生成图元；某一方向为 None 时使用图表自身的比例尺
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive>;
+
+    /// 数据范围 (x_min, x_max, y_min, y_max)；没有数据或不适用时返回 None
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        None
+    }
+
+    /// X 值位于 [x_min, x_max] 内的数据的 Y 范围 (y_min, y_max)，用于按可见的 X 范围
+    /// 自动适配 Y 轴；默认取整体数据范围
+    fn y_range_within(&self, _x_min: f32, _x_max: f32) -> Option<(f32, f32)> {
+        let (_, _, y_min, y_max) = self.data_bounds()?;
+        Some((y_min, y_max))
+    }
+
+    /// 绘制本图表中不自带颜色的图元（点、点集、折线）时使用的样式
+    fn primitive_style(&self) -> Style {
+        Style::default()
+    }
+}
+
+/// 实际使用的比例尺：外部比例尺优先，其次是图表自身设置的比例尺，都没有时按数据拟合，
+/// 拟合结果存放在 `fitted` 中
+pub(crate) fn resolve_scale<'a>(
+    external: Option<&'a dyn Scale>,
+    own: Option<&'a LinearScale>,
+    fitted: &'a mut Option<LinearScale>,
+    values: impl FnOnce() -> Vec<f32>,
+) -> &'a dyn Scale {
+    match (external, own) {
+        (Some(scale), _) => scale,
+        (None, Some(scale)) => scale,
+        (None, None) => fitted.insert(LinearScale::from_data(&values())),
+    }
+}
+
+/// 落在 [x_min, x_max] 内的点的 Y 范围，忽略缺失值
+pub(crate) fn y_range_in<I: IntoIterator<Item = (f32, f32)>>(
+    points: I,
+    x_min: f32,
+    x_max: f32,
+) -> Option<(f32, f32)> {
+    let visible = points
+        .into_iter()
+        .filter(|&(x, _)| x >= x_min && x <= x_max);
+    let (_, _, y_min, y_max) = point_bounds(visible)?;
+    Some((y_min, y_max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_core::LogScale;
+
+    #[test]
+    fn test_resolve_scale_priority() {
+        let external = LogScale::base10(1.0, 100.0);
+        let own = LinearScale::new(0.0, 10.0);
+
+        let mut fitted = None;
+        let scale = resolve_scale(Some(&external), Some(&own), &mut fitted, || vec![0.0]);
+        assert!((scale.normalize(10.0) - 0.5).abs() < 1e-6);
+
+        let mut fitted = None;
+        let scale = resolve_scale(None, Some(&own), &mut fitted, || vec![0.0]);
+        assert_eq!(scale.normalize(5.0), 0.5);
+
+        let mut fitted = None;
+        let scale = resolve_scale(None, None, &mut fitted, || vec![2.0, 4.0]);
+        assert!(scale.normalize(3.0) > 0.0 && scale.normalize(3.0) < 1.0);
+        assert!(fitted.is_some());
+    }
+
+    #[test]
+    fn test_y_range_in_filters_by_x() {
+        let points = [(0.0, 5.0), (1.0, -1.0), (2.0, f32::NAN), (3.0, 9.0)];
+        assert_eq!(y_range_in(points, 0.5, 2.5), Some((-1.0, -1.0)));
+        assert_eq!(y_range_in(points, 0.0, 3.0), Some((-1.0, 9.0)));
+        assert_eq!(y_range_in(points, 4.0, 5.0), None);
+    }
+}
@@ -1,8 +1,12 @@
 use nalgebra::Point2;
-use vizuara_core::{Color, LinearScale, Primitive, Scale};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use vizuara_core::{Color, LinearScale, Primitive, Scale, Style, TimeScale};
+
+use crate::scaled::{resolve_scale, y_range_in, ScaledPlot};
 
 /// 散点图数据点
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataPoint {
     pub x: f32,
     pub y: f32,
@@ -12,6 +16,11 @@ impl DataPoint {
     pub fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
+
+    /// 坐标是否有效；含 NaN 或无穷的点视为缺失数据
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
 }
 
 impl From<(f32, f32)> for DataPoint {
@@ -21,11 +30,20 @@ impl From<(f32, f32)> for DataPoint {
 }
 
 /// 散点图配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScatterStyle {
     pub color: Color,
     pub size: f32,
     pub alpha: f32,
+    /// 标记描边颜色；为 None 时不描边（空心标记以填充色描边）
+    #[serde(default)]
+    pub stroke_color: Option<Color>,
+    /// 标记描边宽度
+    #[serde(default)]
+    pub stroke_width: f32,
+    /// 空心标记：不填充，只绘制描边
+    #[serde(default)]
+    pub hollow: bool,
 }
 
 impl Default for ScatterStyle {
@@ -34,17 +52,157 @@ impl Default for ScatterStyle {
             color: Color::rgb(0.2, 0.4, 0.8),
             size: 5.0,
             alpha: 1.0,
+            stroke_color: None,
+            stroke_width: 1.0,
+            hollow: false,
+        }
+    }
+}
+
+/// 抖动方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum JitterAxis {
+    /// 水平抖动（X 为类别时）
+    #[default]
+    X,
+    /// 垂直抖动（Y 为类别时）
+    Y,
+}
+
+/// 散点抖动：每个点沿一个方向随机偏移，避免类别数据的点重叠在一条线上（条带图）
+///
+/// 偏移由种子和点的序号决定，同一个图重复渲染时位置不变
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Jitter {
+    /// 偏移范围的总宽度（数据单位），偏移均匀分布在 ±width/2 内
+    pub width: f32,
+    /// 抖动方向
+    pub axis: JitterAxis,
+    /// 随机种子
+    pub seed: u64,
+}
+
+impl Jitter {
+    /// 水平抖动，种子为 0
+    pub fn new(width: f32) -> Self {
+        Self {
+            width: width.abs(),
+            axis: JitterAxis::X,
+            seed: 0,
+        }
+    }
+
+    /// 设置抖动方向
+    pub fn axis(mut self, axis: JitterAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// 设置随机种子
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// 第 index 个点的偏移量，位于 [-width/2, width/2)
+    pub fn offset(&self, index: usize) -> f32 {
+        // splitmix64：由 (种子, 序号) 直接得到随机数，与点的处理顺序无关
+        let mut z = self.seed.wrapping_add(
+            (index as u64)
+                .wrapping_add(1)
+                .wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        );
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // 取高 24 位得到 [0, 1) 内精确可表示的浮点数
+        let unit = (z >> 40) as f32 / (1u64 << 24) as f32;
+        (unit - 0.5) * self.width
+    }
+
+    /// 对数据点施加第 index 个偏移
+    fn apply(&self, index: usize, point: &DataPoint) -> DataPoint {
+        let offset = self.offset(index);
+        match self.axis {
+            JitterAxis::X => DataPoint::new(point.x + offset, point.y),
+            JitterAxis::Y => DataPoint::new(point.x, point.y + offset),
+        }
+    }
+}
+
+/// 点大小映射：数值按面积线性映射到直径区间（直径随数值的平方根增长），
+/// 散点图与大小图例共用，保证图例中的参考圆与数据点一致
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SizeScale {
+    pub value_min: f32,
+    pub value_max: f32,
+    pub size_min: f32,
+    pub size_max: f32,
+}
+
+impl SizeScale {
+    /// 创建大小映射，size 为点的直径（像素）
+    pub fn new(value_range: (f32, f32), size_range: (f32, f32)) -> Self {
+        Self {
+            value_min: value_range.0,
+            value_max: value_range.1,
+            size_min: size_range.0.max(0.0),
+            size_max: size_range.1.max(0.0),
+        }
+    }
+
+    /// 按数据范围创建大小映射（忽略 NaN）
+    pub fn from_data(values: &[f32], size_range: (f32, f32)) -> Self {
+        let finite = values.iter().copied().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f32::INFINITY, f32::min);
+        let max = finite.fold(f32::NEG_INFINITY, f32::max);
+        if min > max {
+            return Self::new((0.0, 1.0), size_range);
+        }
+        Self::new((min, max), size_range)
+    }
+
+    /// 数值对应的直径，超出范围时截断
+    pub fn size(&self, value: f32) -> f32 {
+        let span = self.value_max - self.value_min;
+        let t = if span.abs() < f32::EPSILON {
+            1.0
+        } else {
+            ((value - self.value_min) / span).clamp(0.0, 1.0)
+        };
+        let area_min = self.size_min * self.size_min;
+        let area_max = self.size_max * self.size_max;
+        (area_min + t * (area_max - area_min)).sqrt()
+    }
+
+    /// 用于图例的代表性数值：范围内的整齐刻度，不足两个时取两端
+    pub fn legend_values(&self, count: usize) -> Vec<f32> {
+        let (lo, hi) = (
+            self.value_min.min(self.value_max),
+            self.value_min.max(self.value_max),
+        );
+        let values = LinearScale::new(lo, hi).ticks(count);
+        if values.len() >= 2 || lo == hi {
+            values
+        } else {
+            vec![lo, hi]
         }
     }
 }
 
 /// 散点图
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScatterPlot {
     data: Vec<DataPoint>,
     style: ScatterStyle,
     x_scale: Option<LinearScale>,
     y_scale: Option<LinearScale>,
+    size_values: Vec<f32>,
+    size_scale: Option<SizeScale>,
+    #[serde(default)]
+    jitter: Option<Jitter>,
+    #[serde(default)]
+    x_time_scale: Option<TimeScale>,
 }
 
 impl ScatterPlot {
@@ -55,6 +213,10 @@ impl ScatterPlot {
             style: ScatterStyle::default(),
             x_scale: None,
             y_scale: None,
+            size_values: Vec::new(),
+            size_scale: None,
+            jitter: None,
+            x_time_scale: None,
         }
     }
 
@@ -98,6 +260,76 @@ impl ScatterPlot {
         self
     }
 
+    /// 设置标记描边样式
+    pub fn stroke(mut self, color: Color, width: f32) -> Self {
+        self.style.stroke_color = Some(color);
+        self.style.stroke_width = width;
+        self
+    }
+
+    /// 空心标记：只绘制描边，未设置描边颜色时使用点的颜色
+    pub fn hollow(mut self, hollow: bool) -> Self {
+        self.style.hollow = hollow;
+        self
+    }
+
+    /// 按数值编码点大小（气泡图），size_range 为直径范围（像素）
+    pub fn size_values(mut self, values: &[f32], size_range: (f32, f32)) -> Self {
+        self.size_scale = Some(SizeScale::from_data(values, size_range));
+        self.size_values = values.to_vec();
+        self
+    }
+
+    /// 指定点大小映射（多个图表或图例共用同一映射时使用）
+    pub fn with_size_scale(mut self, scale: SizeScale) -> Self {
+        self.size_scale = Some(scale);
+        self
+    }
+
+    /// 点大小映射；未按数值编码大小时为 None
+    pub fn size_scale(&self) -> Option<&SizeScale> {
+        self.size_scale.as_ref()
+    }
+
+    /// 第 index 个点的直径
+    fn point_size(&self, index: usize) -> f32 {
+        match (&self.size_scale, self.size_values.get(index)) {
+            (Some(scale), Some(&value)) => scale.size(value),
+            _ => self.style.size,
+        }
+    }
+
+    /// 水平抖动：每个点的 X 坐标在 ±width/2 内随机偏移（数据单位），保留已设置的种子
+    pub fn jitter(mut self, width: f32) -> Self {
+        let seed = self.jitter.map_or(0, |j| j.seed);
+        self.jitter = Some(Jitter::new(width).seed(seed));
+        self
+    }
+
+    /// 设置抖动的方向、宽度和种子
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// 抖动设置；未设置时为 None
+    pub fn jitter_config(&self) -> Option<&Jitter> {
+        self.jitter.as_ref()
+    }
+
+    /// 各点实际绘制的数据坐标（含抖动偏移）
+    pub fn positions(&self) -> Vec<DataPoint> {
+        match &self.jitter {
+            Some(jitter) => self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(index, point)| jitter.apply(index, point))
+                .collect(),
+            None => self.data.clone(),
+        }
+    }
+
     /// 设置 X 轴比例尺
     pub fn x_scale(mut self, scale: LinearScale) -> Self {
         self.x_scale = Some(scale);
@@ -110,13 +342,49 @@ impl ScatterPlot {
         self
     }
 
+    /// 设置时间序列数据：X 为纪元毫秒，经 X 轴时间比例尺（未设置时由数据创建）换算为数据值
+    pub fn time_data(mut self, data: &[(i64, f32)]) -> Self {
+        let time = self.x_time_scale.take().unwrap_or_else(|| {
+            let timestamps: Vec<i64> = data.iter().map(|&(t, _)| t).collect();
+            TimeScale::from_timestamps(&timestamps)
+        });
+        self.data = data
+            .iter()
+            .map(|&(t, y)| DataPoint::new(time.offset(t), y))
+            .collect();
+        self.x_scale = Some(time.linear());
+        self.x_time_scale = Some(time);
+        self
+    }
+
+    /// 设置 X 轴时间比例尺；已按旧比例尺换算的时间数据随 `origin` 平移
+    pub fn x_time_scale(mut self, scale: TimeScale) -> Self {
+        if let Some(previous) = &self.x_time_scale {
+            let shift = (previous.origin - scale.origin) as f32;
+            for point in &mut self.data {
+                point.x += shift;
+            }
+        }
+        self.x_scale = Some(scale.linear());
+        self.x_time_scale = Some(scale);
+        self
+    }
+
+    /// X 轴时间比例尺（未使用时间数据时为 None），可用于创建对应的时间坐标轴
+    pub fn time_scale(&self) -> Option<&TimeScale> {
+        self.x_time_scale.as_ref()
+    }
+
     /// 自动计算比例尺
     pub fn auto_scale(mut self) -> Self {
         if !self.data.is_empty() {
             let x_values: Vec<f32> = self.data.iter().map(|p| p.x).collect();
             let y_values: Vec<f32> = self.data.iter().map(|p| p.y).collect();
 
-            self.x_scale = Some(LinearScale::from_data(&x_values));
+            self.x_scale = Some(match &self.x_time_scale {
+                Some(time) => time.linear(),
+                None => LinearScale::from_data(&x_values),
+            });
             self.y_scale = Some(LinearScale::from_data(&y_values));
         }
         self
@@ -124,71 +392,161 @@ impl ScatterPlot {
 
     /// 生成渲染图元
     pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
-        let mut primitives = Vec::new();
-
-        if self.data.is_empty() {
-            return primitives;
-        }
+        self.generate_scaled(None, None, plot_area)
+    }
 
-        // 获取或创建比例尺
-        let x_scale = if let Some(ref scale) = self.x_scale {
-            scale.clone()
-        } else {
-            let x_values: Vec<f32> = self.data.iter().map(|p| p.x).collect();
-            LinearScale::from_data(&x_values)
+    /// 生成带样式的渲染图元，未被选中的点降低不透明度（用于联动刷选）
+    ///
+    /// 点仍按普通点集绘制，保留标记形状与描边样式：未选中与选中的点分为两组，
+    /// 选中的点绘制在上层。`selection` 为 `None` 时按未过滤状态绘制。
+    pub fn generate_styled_with_selection(
+        &self,
+        plot_area: PlotArea,
+        selection: Option<&HashSet<usize>>,
+    ) -> (Vec<Primitive>, Vec<Style>) {
+        let style = ScaledPlot::primitive_style(self);
+        let Some(selected) = selection else {
+            let primitives = self.generate_scaled(None, None, plot_area);
+            let styles = vec![style; primitives.len()];
+            return (primitives, styles);
         };
 
-        let y_scale = if let Some(ref scale) = self.y_scale {
-            scale.clone()
-        } else {
-            let y_values: Vec<f32> = self.data.iter().map(|p| p.y).collect();
-            LinearScale::from_data(&y_values)
+        let screen_points = self.screen_points(None, None, plot_area);
+        let mut primitives = Vec::new();
+        let mut styles = Vec::new();
+
+        // 按数值编码大小时逐点绘制圆，未选中的点使用变淡的填充色
+        if self.size_scale.is_some() {
+            for (index, center) in screen_points.into_iter().enumerate() {
+                let mut marker = self.marker(index, center);
+                if !selected.contains(&index) {
+                    if let Primitive::ArcSector { fill, stroke, .. } = &mut marker {
+                        *fill = dim(*fill);
+                        *stroke = stroke.map(|(color, width)| (dim(color), width));
+                    }
+                }
+                primitives.push(marker);
+                styles.push(style.clone());
+            }
+            return (primitives, styles);
+        }
+
+        let (chosen, others): (Vec<_>, Vec<_>) = screen_points
+            .into_iter()
+            .enumerate()
+            .partition(|(index, _)| selected.contains(index));
+        let dimmed = Style {
+            fill_color: style.fill_color.map(dim),
+            stroke_color: style.stroke_color.map(dim),
+            ..style.clone()
         };
+        for (points, style) in [(others, dimmed), (chosen, style)] {
+            if !points.is_empty() {
+                primitives.push(Primitive::Points(
+                    points.into_iter().map(|(_, point)| point).collect(),
+                ));
+                styles.push(style);
+            }
+        }
+        (primitives, styles)
+    }
 
-        // 转换数据点到屏幕坐标
-        let screen_points: Vec<Point2<f32>> = self
-            .data
+    /// 按比例尺将数据点（含抖动）转换为屏幕坐标
+    fn screen_points(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Point2<f32>> {
+        // 获取或创建比例尺
+        let (mut fitted_x, mut fitted_y) = (None, None);
+        let x_scale = resolve_scale(x, self.x_scale.as_ref(), &mut fitted_x, || {
+            self.data.iter().map(|p| p.x).collect()
+        });
+        let y_scale = resolve_scale(y, self.y_scale.as_ref(), &mut fitted_y, || {
+            self.data.iter().map(|p| p.y).collect()
+        });
+
+        self.positions()
             .iter()
             .map(|point| {
-                let x_norm = x_scale.normalize(point.x);
-                let y_norm = y_scale.normalize(point.y);
-
-                // 将归一化坐标映射到绘图区域
-                let screen_x = plot_area.x + x_norm * plot_area.width;
+                let screen_x =
+                    x_scale.to_pixel(point.x, plot_area.x, plot_area.x + plot_area.width);
                 // Y轴翻转：屏幕坐标系是从上到下，而数据坐标系是从下到上
-                let screen_y = plot_area.y + plot_area.height - y_norm * plot_area.height;
+                let screen_y =
+                    y_scale.to_pixel_inverted(point.y, plot_area.y, plot_area.y + plot_area.height);
 
                 Point2::new(screen_x, screen_y)
             })
-            .collect();
+            .collect()
+    }
 
-        // 创建点的图元
-        if !screen_points.is_empty() {
-            primitives.push(Primitive::Points(screen_points));
-        }
+    /// 点的颜色（含整体不透明度）
+    fn fill_color(&self) -> Color {
+        let color = self.style.color;
+        Color::rgba(color.r, color.g, color.b, color.a * self.style.alpha)
+    }
 
-        primitives
+    /// 标记描边（颜色、宽度）；空心标记未设置描边颜色时以点的颜色描边
+    fn marker_stroke(&self) -> Option<(Color, f32)> {
+        let color = match self.style.stroke_color {
+            Some(color) => Color::rgba(color.r, color.g, color.b, color.a * self.style.alpha),
+            None if self.style.hollow => self.fill_color(),
+            None => return None,
+        };
+        (self.style.stroke_width > 0.0).then_some((color, self.style.stroke_width))
     }
 
-    /// 获取数据的边界
-    pub fn data_bounds(&self) -> Option<(DataPoint, DataPoint)> {
+    /// 按数值编码大小时第 index 个点的圆形标记
+    fn marker(&self, index: usize, center: Point2<f32>) -> Primitive {
+        let fill = if self.style.hollow {
+            Color::TRANSPARENT
+        } else {
+            self.fill_color()
+        };
+        Primitive::ArcSector {
+            center,
+            radius: self.point_size(index) / 2.0,
+            start_angle: 0.0,
+            end_angle: std::f32::consts::TAU,
+            fill,
+            stroke: self.marker_stroke(),
+        }
+    }
+
+    fn generate_scaled(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
+
         if self.data.is_empty() {
-            return None;
+            return primitives;
         }
 
-        let mut min_x = self.data[0].x;
-        let mut max_x = self.data[0].x;
-        let mut min_y = self.data[0].y;
-        let mut max_y = self.data[0].y;
+        let screen_points = self.screen_points(x, y, plot_area);
 
-        for point in &self.data {
-            min_x = min_x.min(point.x);
-            max_x = max_x.max(point.x);
-            min_y = min_y.min(point.y);
-            max_y = max_y.max(point.y);
+        // 按数值编码大小时逐点绘制圆
+        if self.size_scale.is_some() {
+            for (index, center) in screen_points.into_iter().enumerate() {
+                primitives.push(self.marker(index, center));
+            }
+            return primitives;
+        }
+
+        // 创建点的图元
+        if !screen_points.is_empty() {
+            primitives.push(Primitive::Points(screen_points));
         }
 
-        Some((DataPoint::new(min_x, min_y), DataPoint::new(max_x, max_y)))
+        primitives
+    }
+
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)，忽略缺失值；没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        point_bounds(self.data.iter().map(|p| (p.x, p.y)))
     }
 
     /// 获取数据点数量
@@ -197,8 +555,63 @@ impl ScatterPlot {
     }
 }
 
+impl ScaledPlot for ScatterPlot {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_scaled(x, y, plot_area)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        ScatterPlot::data_bounds(self)
+    }
+
+    fn y_range_within(&self, x_min: f32, x_max: f32) -> Option<(f32, f32)> {
+        y_range_in(self.data.iter().map(|p| (p.x, p.y)), x_min, x_max)
+    }
+
+    /// 点集按散点颜色填充（空心标记不填充）并按描边设置描边，标记半径为点直径的一半
+    fn primitive_style(&self) -> Style {
+        let stroke = self.marker_stroke();
+        Style {
+            fill_color: (!self.style.hollow).then(|| self.fill_color()),
+            stroke_color: stroke.map(|(color, _)| color),
+            stroke_width: stroke.map_or(0.0, |(_, width)| width),
+            marker_style: vizuara_core::MarkerStyle::Circle,
+            marker_size: self.style.size / 2.0,
+            ..Style::default()
+        }
+    }
+}
+
+/// 刷选时未选中点的不透明度系数
+const UNSELECTED_ALPHA: f32 = 0.2;
+
+/// 降低颜色的不透明度，用于未被选中的点
+fn dim(color: Color) -> Color {
+    Color::rgba(color.r, color.g, color.b, color.a * UNSELECTED_ALPHA)
+}
+
+/// 点集的数据范围 (x_min, x_max, y_min, y_max)，跳过非有限值；没有有效点时为 None
+pub(crate) fn point_bounds<I: IntoIterator<Item = (f32, f32)>>(
+    points: I,
+) -> Option<(f32, f32, f32, f32)> {
+    points
+        .into_iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .fold(None, |bounds, (x, y)| {
+            Some(match bounds {
+                None => (x, x, y, y),
+                Some((x0, x1, y0, y1)) => (x0.min(x), x1.max(x), y0.min(y), y1.max(y)),
+            })
+        })
+}
+
 /// 绘图区域定义
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct PlotArea {
     pub x: f32,
     pub y: f32,
@@ -255,11 +668,8 @@ mod tests {
         let data = vec![(1.0, 2.0), (3.0, 4.0), (0.0, 1.0)];
         let plot = ScatterPlot::new().data(&data);
 
-        let bounds = plot.data_bounds().unwrap();
-        assert_eq!(bounds.0.x, 0.0);
-        assert_eq!(bounds.0.y, 1.0);
-        assert_eq!(bounds.1.x, 3.0);
-        assert_eq!(bounds.1.y, 4.0);
+        assert_eq!(plot.data_bounds(), Some((0.0, 3.0, 1.0, 4.0)));
+        assert!(ScatterPlot::new().data_bounds().is_none());
     }
 
     #[test]
@@ -272,4 +682,154 @@ mod tests {
 
         assert_eq!(primitives.len(), 1); // 应该有一个 Points 图元
     }
+
+    #[test]
+    fn test_selection_dims_unselected_points() {
+        let data = vec![(1.0, 2.0), (2.0, 3.0), (3.0, 1.0)];
+        let plot = ScatterPlot::new().data(&data).auto_scale();
+        let selected: HashSet<usize> = [1].into_iter().collect();
+
+        let plot_area = PlotArea::new(0.0, 0.0, 400.0, 300.0);
+        let (primitives, styles) = plot.generate_styled_with_selection(plot_area, Some(&selected));
+        assert_eq!(primitives.len(), 2);
+        assert_eq!(styles.len(), 2);
+
+        // 未选中的点在下层、变淡，选中的点在上层；均按普通点集绘制，保留标记样式
+        let counts: Vec<usize> = primitives
+            .iter()
+            .map(|p| match p {
+                Primitive::Points(points) => points.len(),
+                other => panic!("expected points, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(counts, vec![2, 1]);
+        let normal = ScaledPlot::primitive_style(&plot);
+        assert_eq!(styles[1].fill_color, normal.fill_color);
+        assert!(styles[0].fill_color.unwrap().a < styles[1].fill_color.unwrap().a);
+        assert_eq!(styles[0].marker_style, normal.marker_style);
+        assert_eq!(styles[0].marker_size, normal.marker_size);
+
+        // 全部选中时只有一组点
+        let all: HashSet<usize> = (0..3).collect();
+        let (primitives, _) = plot.generate_styled_with_selection(plot_area, Some(&all));
+        assert_eq!(primitives.len(), 1);
+
+        // 无选择时与普通绘制一致
+        let (primitives, styles) = plot.generate_styled_with_selection(plot_area, None);
+        assert_eq!(primitives, plot.generate_primitives(plot_area));
+        assert_eq!(styles, vec![normal]);
+    }
+
+    #[test]
+    fn test_marker_stroke_and_hollow() {
+        let data = vec![(1.0, 2.0), (2.0, 3.0)];
+        let red = Color::rgb(1.0, 0.0, 0.0);
+
+        // 默认只填充不描边
+        let plain = ScaledPlot::primitive_style(&ScatterPlot::new().data(&data));
+        assert!(plain.fill_color.is_some());
+        assert_eq!(plain.stroke_color, None);
+
+        // 填充与描边互相独立
+        let outlined = ScatterPlot::new().data(&data).stroke(red, 2.0);
+        let style = ScaledPlot::primitive_style(&outlined);
+        assert_eq!(style.fill_color, Some(Color::rgb(0.2, 0.4, 0.8)));
+        assert_eq!(style.stroke_color, Some(red));
+        assert_eq!(style.stroke_width, 2.0);
+
+        // 空心标记不填充；未设置描边颜色时以点的颜色描边
+        let hollow = ScatterPlot::new().data(&data).color(red).hollow(true);
+        let style = ScaledPlot::primitive_style(&hollow);
+        assert_eq!(style.fill_color, None);
+        assert_eq!(style.stroke_color, Some(red));
+        assert_eq!(style.stroke_width, 1.0);
+
+        // 按数值编码大小的圆同样带描边，空心时填充透明
+        let bubbles = hollow
+            .stroke(Color::BLACK, 1.5)
+            .size_values(&[1.0, 2.0], (4.0, 8.0));
+        let area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        for primitive in bubbles.generate_primitives(area) {
+            match primitive {
+                Primitive::ArcSector { fill, stroke, .. } => {
+                    assert_eq!(fill.a, 0.0);
+                    assert_eq!(stroke, Some((Color::BLACK, 1.5)));
+                }
+                other => panic!("expected arc sector, got {:?}", other),
+            }
+        }
+
+        // 刷选时未选中的气泡描边同样变淡
+        let selected: HashSet<usize> = [0].into_iter().collect();
+        let (primitives, _) = bubbles.generate_styled_with_selection(area, Some(&selected));
+        let strokes: Vec<f32> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::ArcSector {
+                    stroke: Some((color, _)),
+                    ..
+                } => Some(color.a),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(strokes, vec![1.0, UNSELECTED_ALPHA]);
+    }
+
+    #[test]
+    fn test_size_scale_maps_area() {
+        let scale = SizeScale::from_data(&[0.0, f32::NAN, 100.0], (0.0, 20.0));
+        assert_eq!(scale.value_min, 0.0);
+        assert_eq!(scale.value_max, 100.0);
+        assert_eq!(scale.size(100.0), 20.0);
+        // 面积与数值成正比：数值为四分之一时直径为一半
+        assert!((scale.size(25.0) - 10.0).abs() < 1e-4);
+        assert_eq!(scale.size(200.0), 20.0);
+
+        let plot = ScatterPlot::new()
+            .data(&[(0.0, 0.0), (1.0, 1.0)])
+            .size_values(&[0.0, 100.0], (0.0, 20.0));
+        let radii: Vec<f32> = plot
+            .generate_primitives(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .into_iter()
+            .filter_map(|p| match p {
+                Primitive::ArcSector { radius, .. } => Some(radius),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(radii, [0.0, 10.0]);
+    }
+
+    #[test]
+    fn test_jitter_is_bounded_and_reproducible() {
+        let data = vec![(1.0, 0.0); 50];
+        let plot = ScatterPlot::new().data(&data).jitter(0.4);
+
+        let positions = plot.positions();
+        assert!(positions
+            .iter()
+            .all(|p| (p.x - 1.0).abs() <= 0.2 && p.y == 0.0));
+        // 点被分散开，而不是都落在同一位置
+        assert!(positions
+            .iter()
+            .any(|p| (p.x - positions[0].x).abs() > 0.05));
+
+        // 重复计算与重复渲染的结果相同
+        assert_eq!(plot.clone().positions(), positions);
+        let area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(
+            plot.generate_primitives(area),
+            plot.generate_primitives(area)
+        );
+
+        // 不同种子与垂直方向
+        let reseeded = ScatterPlot::new()
+            .data(&data)
+            .with_jitter(Jitter::new(0.4).seed(7).axis(JitterAxis::Y));
+        let moved = reseeded.positions();
+        assert!(moved.iter().all(|p| p.x == 1.0));
+        assert_ne!(
+            moved.iter().map(|p| p.y).collect::<Vec<_>>(),
+            positions.iter().map(|p| p.x - 1.0).collect::<Vec<_>>()
+        );
+    }
 }
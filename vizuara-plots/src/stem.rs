@@ -0,0 +1,244 @@
+//! 茎叶图（火柴杆图）实现
+//!
+//! 用于展示离散信号：每个数据点从基线画一根竖线，顶部加圆形标记
+
+use crate::scaled::{resolve_scale, y_range_in, ScaledPlot};
+use crate::scatter::point_bounds;
+use crate::{DataPoint, PlotArea};
+use nalgebra::Point2;
+use vizuara_core::{LinearScale, Primitive, Scale};
+
+/// 茎叶图样式
+#[derive(Debug, Clone)]
+pub struct StemStyle {
+    pub marker_radius: f32,
+    pub show_baseline: bool,
+}
+
+impl Default for StemStyle {
+    fn default() -> Self {
+        Self {
+            marker_radius: 4.0,
+            show_baseline: true,
+        }
+    }
+}
+
+/// 茎叶图
+#[derive(Debug, Clone)]
+pub struct StemPlot {
+    data: Vec<DataPoint>,
+    baseline: f32,
+    style: StemStyle,
+    x_scale: Option<LinearScale>,
+    y_scale: Option<LinearScale>,
+}
+
+impl StemPlot {
+    /// 创建新的茎叶图
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            baseline: 0.0,
+            style: StemStyle::default(),
+            x_scale: None,
+            y_scale: None,
+        }
+    }
+
+    /// 设置数据
+    pub fn data<T: Into<DataPoint> + Clone>(mut self, data: &[T]) -> Self {
+        self.data = data.iter().cloned().map(|d| d.into()).collect();
+        self
+    }
+
+    /// 从两个向量设置 X 和 Y 数据
+    pub fn xy_data(mut self, x_data: &[f32], y_data: &[f32]) -> Self {
+        assert_eq!(
+            x_data.len(),
+            y_data.len(),
+            "X and Y data must have the same length"
+        );
+
+        self.data = x_data
+            .iter()
+            .zip(y_data.iter())
+            .map(|(&x, &y)| DataPoint::new(x, y))
+            .collect();
+        self
+    }
+
+    /// 设置基线值（竖线的起点）
+    pub fn baseline(mut self, baseline: f32) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// 设置样式
+    pub fn style(mut self, style: StemStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 设置标记半径
+    pub fn marker_radius(mut self, radius: f32) -> Self {
+        self.style.marker_radius = radius.max(0.0);
+        self
+    }
+
+    /// 设置 X 轴比例尺
+    pub fn x_scale(mut self, scale: LinearScale) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// 设置 Y 轴比例尺
+    pub fn y_scale(mut self, scale: LinearScale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// 获取数据点数量
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)，Y 包含基线；没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let (x_min, x_max, y_min, y_max) = point_bounds(self.data.iter().map(|p| (p.x, p.y)))?;
+        Some((
+            x_min,
+            x_max,
+            y_min.min(self.baseline),
+            y_max.max(self.baseline),
+        ))
+    }
+
+    /// 生成渲染图元
+    pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        self.generate_scaled(None, None, plot_area)
+    }
+
+    fn generate_scaled(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
+
+        if self.data.is_empty() {
+            return primitives;
+        }
+
+        let (mut fitted_x, mut fitted_y) = (None, None);
+        let x_scale = resolve_scale(x, self.x_scale.as_ref(), &mut fitted_x, || {
+            self.data.iter().map(|p| p.x).collect()
+        });
+        // 自动比例尺需要包含基线
+        let y_scale = resolve_scale(y, self.y_scale.as_ref(), &mut fitted_y, || {
+            let mut y_values: Vec<f32> = self.data.iter().map(|p| p.y).collect();
+            y_values.push(self.baseline);
+            y_values
+        });
+
+        let to_screen = |x: f32, y: f32| {
+            Point2::new(
+                x_scale.to_pixel(x, plot_area.x, plot_area.x + plot_area.width),
+                y_scale.to_pixel_inverted(y, plot_area.y, plot_area.y + plot_area.height),
+            )
+        };
+
+        let baseline_y = to_screen(0.0, self.baseline).y;
+        if self.style.show_baseline {
+            primitives.push(Primitive::Line {
+                start: Point2::new(plot_area.x, baseline_y),
+                end: Point2::new(plot_area.x + plot_area.width, baseline_y),
+            });
+        }
+
+        for point in &self.data {
+            let top = to_screen(point.x, point.y);
+            primitives.push(Primitive::Line {
+                start: Point2::new(top.x, baseline_y),
+                end: top,
+            });
+            primitives.push(Primitive::Circle {
+                center: top,
+                radius: self.style.marker_radius,
+            });
+        }
+
+        primitives
+    }
+}
+
+impl Default for StemPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScaledPlot for StemPlot {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_scaled(x, y, plot_area)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        StemPlot::data_bounds(self)
+    }
+
+    fn y_range_within(&self, x_min: f32, x_max: f32) -> Option<(f32, f32)> {
+        // 杆从基线画起，可见范围同样包含基线
+        let (y_min, y_max) = y_range_in(self.data.iter().map(|p| (p.x, p.y)), x_min, x_max)?;
+        Some((y_min.min(self.baseline), y_max.max(self.baseline)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem_plot_primitives() {
+        let plot = StemPlot::new()
+            .xy_data(&[0.0, 1.0, 2.0], &[1.0, -1.0, 2.0])
+            .y_scale(LinearScale::new(-2.0, 2.0));
+
+        let primitives = plot.generate_primitives(PlotArea::new(0.0, 0.0, 100.0, 100.0));
+        // 1 条基线 + 每个点一根竖线和一个标记
+        assert_eq!(primitives.len(), 7);
+
+        match (&primitives[3], &primitives[4]) {
+            (Primitive::Line { start, end }, Primitive::Circle { center, .. }) => {
+                assert_eq!(start.y, 50.0);
+                assert_eq!(end.y, 75.0);
+                assert_eq!(center, end);
+            }
+            other => panic!("unexpected primitives {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stem_plot_custom_baseline() {
+        let plot = StemPlot::new()
+            .data(&[(0.0, 3.0), (1.0, 4.0)])
+            .baseline(2.0)
+            .y_scale(LinearScale::new(0.0, 4.0));
+        assert_eq!(plot.data_bounds(), Some((0.0, 1.0, 2.0, 4.0)));
+
+        let primitives = plot.generate_primitives(PlotArea::new(0.0, 0.0, 100.0, 100.0));
+        match &primitives[0] {
+            Primitive::Line { start, end } => {
+                assert_eq!(start.y, 50.0);
+                assert_eq!(end.y, 50.0);
+            }
+            other => panic!("expected baseline, got {:?}", other),
+        }
+    }
+}
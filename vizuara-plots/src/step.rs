@@ -0,0 +1,243 @@
+//! 阶梯图实现
+//!
+//! 用于展示数字信号等分段恒定的数据，支持前/后/居中三种阶梯对齐
+
+use crate::scaled::{resolve_scale, y_range_in, ScaledPlot};
+use crate::scatter::point_bounds;
+use crate::{DataPoint, PlotArea};
+use nalgebra::Point2;
+use vizuara_core::{Color, LinearScale, Primitive, Scale};
+
+/// 阶梯对齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepAlign {
+    /// 水平段位于每个 x 之前（在 x 处跳变到新值之前已保持新值）
+    Pre,
+    /// 水平段位于每个 x 之后（保持到下一个 x 再跳变）
+    Post,
+    /// 在相邻 x 的中点跳变
+    Mid,
+}
+
+/// 阶梯图
+#[derive(Debug, Clone)]
+pub struct StepPlot {
+    data: Vec<DataPoint>,
+    align: StepAlign,
+    color: Color,
+    width: f32,
+    x_scale: Option<LinearScale>,
+    y_scale: Option<LinearScale>,
+}
+
+impl StepPlot {
+    /// 创建新的阶梯图
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            align: StepAlign::Post,
+            color: Color::rgb(0.2, 0.4, 0.8),
+            width: 2.0,
+            x_scale: None,
+            y_scale: None,
+        }
+    }
+
+    /// 设置数据（按 X 排序）
+    pub fn data<T: Into<DataPoint> + Clone>(mut self, data: &[T]) -> Self {
+        self.data = data.iter().cloned().map(|d| d.into()).collect();
+        self.data
+            .sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal));
+        self
+    }
+
+    /// 从两个向量设置 X 和 Y 数据
+    pub fn xy_data(self, x_data: &[f32], y_data: &[f32]) -> Self {
+        assert_eq!(
+            x_data.len(),
+            y_data.len(),
+            "X and Y data must have the same length"
+        );
+
+        let data: Vec<(f32, f32)> = x_data.iter().copied().zip(y_data.iter().copied()).collect();
+        self.data(&data)
+    }
+
+    /// 设置阶梯对齐方式
+    pub fn align(mut self, align: StepAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// 设置颜色
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// 设置线宽
+    pub fn line_width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// 设置 X 轴比例尺
+    pub fn x_scale(mut self, scale: LinearScale) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// 设置 Y 轴比例尺
+    pub fn y_scale(mut self, scale: LinearScale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// 获取数据范围 (x_min, x_max, y_min, y_max)，忽略缺失值；没有数据时为 None
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        point_bounds(self.data.iter().map(|p| (p.x, p.y)))
+    }
+
+    /// 计算阶梯折线的数据坐标顶点
+    pub fn step_points(&self) -> Vec<(f32, f32)> {
+        let mut points = Vec::with_capacity(self.data.len() * 2);
+        let Some(first) = self.data.first() else {
+            return points;
+        };
+        points.push((first.x, first.y));
+
+        for pair in self.data.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            match self.align {
+                StepAlign::Pre => points.push((a.x, b.y)),
+                StepAlign::Post => points.push((b.x, a.y)),
+                StepAlign::Mid => {
+                    let mid = (a.x + b.x) / 2.0;
+                    points.push((mid, a.y));
+                    points.push((mid, b.y));
+                }
+            }
+            points.push((b.x, b.y));
+        }
+
+        points
+    }
+
+    /// 生成渲染图元
+    pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        self.generate_scaled(None, None, plot_area)
+    }
+
+    fn generate_scaled(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        if self.data.len() < 2 {
+            return Vec::new();
+        }
+
+        let (mut fitted_x, mut fitted_y) = (None, None);
+        let x_scale = resolve_scale(x, self.x_scale.as_ref(), &mut fitted_x, || {
+            self.data.iter().map(|p| p.x).collect()
+        });
+        let y_scale = resolve_scale(y, self.y_scale.as_ref(), &mut fitted_y, || {
+            self.data.iter().map(|p| p.y).collect()
+        });
+
+        let points = self
+            .step_points()
+            .into_iter()
+            .map(|(x, y)| {
+                Point2::new(
+                    x_scale.to_pixel(x, plot_area.x, plot_area.x + plot_area.width),
+                    y_scale.to_pixel_inverted(y, plot_area.y, plot_area.y + plot_area.height),
+                )
+            })
+            .collect();
+
+        vec![Primitive::Polyline {
+            points,
+            color: self.color,
+            width: self.width,
+        }]
+    }
+}
+
+impl Default for StepPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScaledPlot for StepPlot {
+    fn generate_with_scales(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_scaled(x, y, plot_area)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        StepPlot::data_bounds(self)
+    }
+
+    fn y_range_within(&self, x_min: f32, x_max: f32) -> Option<(f32, f32)> {
+        y_range_in(self.data.iter().map(|p| (p.x, p.y)), x_min, x_max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plot(align: StepAlign) -> StepPlot {
+        StepPlot::new()
+            .xy_data(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.0])
+            .align(align)
+    }
+
+    #[test]
+    fn test_step_alignment() {
+        assert_eq!(
+            plot(StepAlign::Post).step_points(),
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0)]
+        );
+        assert_eq!(
+            plot(StepAlign::Pre).step_points(),
+            vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (2.0, 0.0)]
+        );
+        assert_eq!(
+            plot(StepAlign::Mid).step_points(),
+            vec![
+                (0.0, 0.0),
+                (0.5, 0.0),
+                (0.5, 1.0),
+                (1.0, 1.0),
+                (1.5, 1.0),
+                (1.5, 0.0),
+                (2.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_step_primitives() {
+        let primitives = plot(StepAlign::Post)
+            .y_scale(LinearScale::new(0.0, 1.0))
+            .x_scale(LinearScale::new(0.0, 2.0))
+            .generate_primitives(PlotArea::new(0.0, 0.0, 100.0, 100.0));
+
+        match &primitives[0] {
+            Primitive::Polyline { points, .. } => {
+                assert_eq!(points.len(), 5);
+                assert_eq!(points[1], Point2::new(50.0, 100.0));
+                assert_eq!(points[2], Point2::new(50.0, 0.0));
+            }
+            other => panic!("expected polyline, got {:?}", other),
+        }
+    }
+}
@@ -2,10 +2,25 @@
 //!
 //! 用于可视化层次数据，显示数据项的相对大小
 
-use crate::PlotArea;
+use crate::{ColorMap, PlotArea};
 use nalgebra::Point2;
+use std::cmp::Ordering;
 use vizuara_core::{Color, HorizontalAlign, Primitive, VerticalAlign};
 
+/// 分类配色
+fn category_color(index: usize) -> Color {
+    let colors = [
+        Color::rgb(0.8, 0.4, 0.4),
+        Color::rgb(0.4, 0.8, 0.4),
+        Color::rgb(0.4, 0.4, 0.8),
+        Color::rgb(0.8, 0.8, 0.4),
+        Color::rgb(0.8, 0.4, 0.8),
+        Color::rgb(0.4, 0.8, 0.8),
+        Color::rgb(0.6, 0.6, 0.6),
+    ];
+    colors[index % colors.len()]
+}
+
 /// 颜色方案
 #[derive(Debug, Clone)]
 pub enum ColorScheme {
@@ -40,6 +55,187 @@ impl TreemapItem {
     }
 }
 
+/// 层次树状图节点
+#[derive(Debug, Clone)]
+pub struct TreemapNode {
+    pub label: String,
+    /// 叶子节点的数值；有子节点时以子节点数值之和为准
+    pub value: f32,
+    pub children: Vec<TreemapNode>,
+    pub color: Option<Color>,
+}
+
+impl TreemapNode {
+    /// 创建叶子节点
+    pub fn leaf<S: Into<String>>(label: S, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            children: Vec::new(),
+            color: None,
+        }
+    }
+
+    /// 创建包含子节点的分支节点
+    pub fn branch<S: Into<String>>(label: S, children: Vec<TreemapNode>) -> Self {
+        Self {
+            label: label.into(),
+            value: 0.0,
+            children,
+            color: None,
+        }
+    }
+
+    /// 添加子节点
+    pub fn child(mut self, child: TreemapNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// 设置颜色
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// 是否为叶子节点
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// 节点总值（负值按 0 处理）
+    pub fn total_value(&self) -> f32 {
+        if self.is_leaf() {
+            self.value.max(0.0)
+        } else {
+            self.children.iter().map(|c| c.total_value()).sum()
+        }
+    }
+}
+
+/// 层次树状图的叶子着色方式
+#[derive(Debug, Clone)]
+pub enum TreemapColoring {
+    /// 按层级深度着色
+    Depth,
+    /// 按叶子数值经颜色映射着色
+    Value(ColorMap),
+}
+
+/// 层次布局结果中的矩形
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreemapRect {
+    pub label: String,
+    pub value: f32,
+    /// 层级深度（根节点的子节点为 0）
+    pub depth: usize,
+    pub is_leaf: bool,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub color: Option<Color>,
+}
+
+/// 方形化树图布局（Bruls 等人的 squarified 算法）
+///
+/// 按数值比例划分矩形 `(x, y, width, height)`，使子矩形的长宽比尽量接近 1。
+/// 返回的矩形与输入顺序一一对应；非正数值得到零面积矩形。
+pub fn squarify(
+    values: &[f32],
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+) -> Vec<(f32, f32, f32, f32)> {
+    let mut result = vec![(x, y, 0.0, 0.0); values.len()];
+
+    let total: f32 = values.iter().filter(|v| **v > 0.0).sum();
+    if total <= 0.0 || width <= 0.0 || height <= 0.0 {
+        return result;
+    }
+
+    // 按数值降序处理，面积按矩形总面积缩放
+    let scale = width * height / total;
+    let mut order: Vec<usize> = (0..values.len()).filter(|&i| values[i] > 0.0).collect();
+    order.sort_by(|&a, &b| values[b].partial_cmp(&values[a]).unwrap_or(Ordering::Equal));
+
+    let mut rect = (x, y, width, height);
+    let mut row: Vec<usize> = Vec::new();
+    let mut row_areas: Vec<f32> = Vec::new();
+
+    for &index in &order {
+        let area = values[index] * scale;
+        let side = rect.2.min(rect.3);
+
+        if !row_areas.is_empty() {
+            let current = worst_aspect_ratio(&row_areas, side);
+            row_areas.push(area);
+            let with_next = worst_aspect_ratio(&row_areas, side);
+            row_areas.pop();
+
+            // 加入后长宽比变差：固定当前行，在剩余矩形中开始新的一行
+            if with_next > current {
+                rect = layout_row(&row, &row_areas, rect, &mut result);
+                row.clear();
+                row_areas.clear();
+            }
+        }
+
+        row.push(index);
+        row_areas.push(area);
+    }
+
+    if !row.is_empty() {
+        layout_row(&row, &row_areas, rect, &mut result);
+    }
+
+    result
+}
+
+/// 一行矩形沿长度为 side 的边排列时的最差长宽比
+fn worst_aspect_ratio(areas: &[f32], side: f32) -> f32 {
+    let sum: f32 = areas.iter().sum();
+    let max = areas.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+    let min = areas.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+    let side_sq = side * side;
+    let sum_sq = sum * sum;
+    (side_sq * max / sum_sq).max(sum_sq / (side_sq * min))
+}
+
+/// 沿剩余矩形的短边放置一行，返回剩余矩形
+fn layout_row(
+    indices: &[usize],
+    areas: &[f32],
+    rect: (f32, f32, f32, f32),
+    result: &mut [(f32, f32, f32, f32)],
+) -> (f32, f32, f32, f32) {
+    let (x, y, width, height) = rect;
+    let sum: f32 = areas.iter().sum();
+
+    if width >= height {
+        // 竖直条带放在左侧
+        let strip = sum / height;
+        let mut cy = y;
+        for (&index, &area) in indices.iter().zip(areas) {
+            let h = area / strip;
+            result[index] = (x, cy, strip, h);
+            cy += h;
+        }
+        (x + strip, y, (width - strip).max(0.0), height)
+    } else {
+        // 水平条带放在顶部
+        let strip = sum / width;
+        let mut cx = x;
+        for (&index, &area) in indices.iter().zip(areas) {
+            let w = area / strip;
+            result[index] = (cx, y, w, strip);
+            cx += w;
+        }
+        (x, y + strip, width, (height - strip).max(0.0))
+    }
+}
+
 /// 树状图样式
 #[derive(Debug, Clone)]
 pub struct TreemapStyle {
@@ -50,6 +246,8 @@ pub struct TreemapStyle {
     pub label_size: f32,
     pub label_color: Color,
     pub border_color: Color,
+    /// 层次树状图中父节点标签条的高度
+    pub header_height: f32,
 }
 
 impl Default for TreemapStyle {
@@ -62,6 +260,7 @@ impl Default for TreemapStyle {
             label_size: 10.0,
             label_color: Color::rgb(0.2, 0.2, 0.2),
             border_color: Color::rgb(0.8, 0.8, 0.8),
+            header_height: 14.0,
         }
     }
 }
@@ -70,6 +269,8 @@ impl Default for TreemapStyle {
 #[derive(Debug, Clone)]
 pub struct Treemap {
     items: Vec<TreemapItem>,
+    root: Option<TreemapNode>,
+    coloring: TreemapColoring,
     style: TreemapStyle,
     color_scheme: ColorScheme,
     title: Option<String>,
@@ -86,6 +287,8 @@ impl Treemap {
     pub fn new() -> Self {
         Self {
             items: Vec::new(),
+            root: None,
+            coloring: TreemapColoring::Depth,
             style: TreemapStyle::default(),
             color_scheme: ColorScheme::Category,
             title: None,
@@ -98,6 +301,24 @@ impl Treemap {
         self
     }
 
+    /// 设置层次数据；设置后按嵌套的方形化布局绘制，忽略平铺项目
+    pub fn hierarchy(mut self, root: TreemapNode) -> Self {
+        self.root = Some(root);
+        self
+    }
+
+    /// 设置层次树状图的叶子着色方式
+    pub fn coloring(mut self, coloring: TreemapColoring) -> Self {
+        self.coloring = coloring;
+        self
+    }
+
+    /// 设置父节点标签条高度
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.style.header_height = height;
+        self
+    }
+
     /// 设置标题
     pub fn title(mut self, title: &str) -> Self {
         self.title = Some(title.to_string());
@@ -137,19 +358,7 @@ impl Treemap {
     /// 生成颜色
     fn get_item_color(&self, index: usize) -> Color {
         match self.color_scheme {
-            ColorScheme::Category => {
-                // 使用不同的颜色组合
-                let colors = [
-                    Color::rgb(0.8, 0.4, 0.4),
-                    Color::rgb(0.4, 0.8, 0.4),
-                    Color::rgb(0.4, 0.4, 0.8),
-                    Color::rgb(0.8, 0.8, 0.4),
-                    Color::rgb(0.8, 0.4, 0.8),
-                    Color::rgb(0.4, 0.8, 0.8),
-                    Color::rgb(0.6, 0.6, 0.6),
-                ];
-                colors[index % colors.len()]
-            }
+            ColorScheme::Category => category_color(index),
             ColorScheme::Blues => {
                 let intensity = 0.3 + (index as f32 / self.items.len() as f32) * 0.7;
                 Color::rgb(0.2, 0.4, intensity)
@@ -165,6 +374,155 @@ impl Treemap {
         }
     }
 
+    /// 计算层次布局：每一层在父矩形内（扣除内边距和标签条）做方形化划分
+    pub fn hierarchical_layout(&self, plot_area: PlotArea) -> Vec<TreemapRect> {
+        let mut rects = Vec::new();
+        let Some(root) = &self.root else {
+            return rects;
+        };
+
+        let title_offset = if self.title.is_some() { 40.0 } else { 0.0 };
+        let padding = self.style.padding;
+        let area = (
+            plot_area.x + padding,
+            plot_area.y + padding + title_offset,
+            plot_area.width - 2.0 * padding,
+            plot_area.height - 2.0 * padding - title_offset,
+        );
+
+        self.layout_children(root, area, 0, &mut rects);
+        rects
+    }
+
+    fn layout_children(
+        &self,
+        node: &TreemapNode,
+        area: (f32, f32, f32, f32),
+        depth: usize,
+        rects: &mut Vec<TreemapRect>,
+    ) {
+        let values: Vec<f32> = node.children.iter().map(|c| c.total_value()).collect();
+        let layout = squarify(&values, area.0, area.1, area.2, area.3);
+
+        for (child, (x, y, width, height)) in node.children.iter().zip(layout) {
+            if width <= 0.0 || height <= 0.0 {
+                continue;
+            }
+
+            rects.push(TreemapRect {
+                label: child.label.clone(),
+                value: child.total_value(),
+                depth,
+                is_leaf: child.is_leaf(),
+                x,
+                y,
+                width,
+                height,
+                color: child.color,
+            });
+
+            if !child.is_leaf() {
+                let padding = self.style.padding;
+                let header = if self.style.show_labels {
+                    self.style.header_height
+                } else {
+                    0.0
+                };
+                let inner = (
+                    x + padding,
+                    y + padding + header,
+                    width - 2.0 * padding,
+                    height - 2.0 * padding - header,
+                );
+                if inner.2 > 0.0 && inner.3 > 0.0 {
+                    self.layout_children(child, inner, depth + 1, rects);
+                }
+            }
+        }
+    }
+
+    /// 层次树状图中矩形的填充颜色
+    fn hierarchy_color(&self, rect: &TreemapRect, value_range: (f32, f32)) -> Color {
+        if let Some(color) = rect.color {
+            return color;
+        }
+
+        let depth_color = category_color(rect.depth);
+        if !rect.is_leaf {
+            // 父节点使用浅色背景，突出层次边界
+            return Color::rgba(depth_color.r, depth_color.g, depth_color.b, 0.35);
+        }
+
+        match &self.coloring {
+            TreemapColoring::Depth => depth_color,
+            TreemapColoring::Value(color_map) => {
+                let (min, max) = value_range;
+                let t = if max > min {
+                    (rect.value - min) / (max - min)
+                } else {
+                    0.5
+                };
+                color_map.get_color(t)
+            }
+        }
+    }
+
+    /// 生成层次树状图图元
+    fn hierarchy_primitives(&self, plot_area: PlotArea, primitives: &mut Vec<Primitive>) {
+        let rects = self.hierarchical_layout(plot_area);
+
+        let value_range = rects
+            .iter()
+            .filter(|r| r.is_leaf)
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), r| {
+                (min.min(r.value), max.max(r.value))
+            });
+
+        for rect in &rects {
+            primitives.push(Primitive::RectangleStyled {
+                min: Point2::new(rect.x, rect.y),
+                max: Point2::new(rect.x + rect.width, rect.y + rect.height),
+                fill: self.hierarchy_color(rect, value_range),
+                stroke: Some((self.style.border_color, self.style.border_width)),
+            });
+
+            if !self.style.show_labels {
+                continue;
+            }
+
+            if rect.is_leaf {
+                if rect.width > 20.0 && rect.height > 15.0 {
+                    primitives.push(Primitive::Text {
+                        position: Point2::new(
+                            rect.x + rect.width / 2.0,
+                            rect.y + rect.height / 2.0,
+                        ),
+                        content: rect.label.clone(),
+                        size: self.style.label_size,
+                        color: self.style.label_color,
+                        h_align: HorizontalAlign::Center,
+                        v_align: VerticalAlign::Middle,
+                        rotation: 0.0,
+                    });
+                }
+            } else {
+                // 父节点标签放在顶部标签条内
+                primitives.push(Primitive::Text {
+                    position: Point2::new(
+                        rect.x + self.style.padding + 2.0,
+                        rect.y + self.style.padding + self.style.header_height / 2.0,
+                    ),
+                    content: rect.label.clone(),
+                    size: self.style.label_size,
+                    color: self.style.label_color,
+                    h_align: HorizontalAlign::Left,
+                    v_align: VerticalAlign::Middle,
+                    rotation: 0.0,
+                });
+            }
+        }
+    }
+
     /// 简单的平铺布局算法
     fn compute_layout(&self, plot_area: PlotArea) -> Vec<(f32, f32, f32, f32, Color, String)> {
         let mut layouts = Vec::new();
@@ -228,7 +586,15 @@ impl Treemap {
     pub fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
         let mut primitives = Vec::new();
 
-        let layouts = self.compute_layout(plot_area);
+        if self.root.is_some() {
+            self.hierarchy_primitives(plot_area, &mut primitives);
+        }
+
+        let layouts = if self.root.is_some() {
+            Vec::new()
+        } else {
+            self.compute_layout(plot_area)
+        };
 
         // 渲染矩形
         for (x, y, width, height, color, label) in &layouts {
@@ -249,6 +615,7 @@ impl Treemap {
                     color: self.style.label_color,
                     h_align: HorizontalAlign::Center,
                     v_align: VerticalAlign::Middle,
+                    rotation: 0.0,
                 });
             }
         }
@@ -262,9 +629,71 @@ impl Treemap {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: HorizontalAlign::Center,
                 v_align: VerticalAlign::Middle,
+                rotation: 0.0,
             });
         }
 
         primitives
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_squarify_areas_and_bounds() {
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0];
+        let rects = squarify(&values, 0.0, 0.0, 6.0, 4.0);
+
+        for (&value, &(x, y, w, h)) in values.iter().zip(&rects) {
+            assert!((w * h - value).abs() < 1e-3);
+            assert!(x >= -1e-4 && y >= -1e-4);
+            assert!(x + w <= 6.0 + 1e-3 && y + h <= 4.0 + 1e-3);
+            // 方形化布局的长宽比应保持在较小范围内
+            assert!(w.max(h) / w.min(h) < 3.0);
+        }
+
+        // 非正数值得到零面积矩形
+        let rects = squarify(&[1.0, 0.0, -2.0], 0.0, 0.0, 10.0, 10.0);
+        assert!((rects[0].2 * rects[0].3 - 100.0).abs() < 1e-3);
+        assert_eq!(rects[1].2 * rects[1].3, 0.0);
+        assert_eq!(rects[2].2 * rects[2].3, 0.0);
+    }
+
+    #[test]
+    fn test_hierarchical_layout_nests_children() {
+        let root = TreemapNode::branch(
+            "root",
+            vec![
+                TreemapNode::branch(
+                    "A",
+                    vec![TreemapNode::leaf("A1", 3.0), TreemapNode::leaf("A2", 1.0)],
+                ),
+                TreemapNode::leaf("B", 4.0),
+            ],
+        );
+        assert_eq!(root.total_value(), 8.0);
+
+        let treemap = Treemap::new().hierarchy(root);
+        let rects = treemap.hierarchical_layout(PlotArea::new(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(rects.len(), 4);
+
+        let parent = rects.iter().find(|r| r.label == "A").unwrap();
+        assert!(!parent.is_leaf);
+        for child in rects.iter().filter(|r| r.depth == 1) {
+            // 子节点位于父节点内，并让出内边距和标签条
+            assert!(child.x >= parent.x + treemap.style.padding - 1e-3);
+            assert!(child.y >= parent.y + treemap.style.header_height - 1e-3);
+            assert!(child.x + child.width <= parent.x + parent.width + 1e-3);
+            assert!(child.y + child.height <= parent.y + parent.height + 1e-3);
+        }
+
+        let primitives = treemap.generate_primitives(PlotArea::new(0.0, 0.0, 200.0, 100.0));
+        let rect_count = primitives
+            .iter()
+            .filter(|p| matches!(p, Primitive::RectangleStyled { .. }))
+            .count();
+        assert_eq!(rect_count, 4);
+    }
+}
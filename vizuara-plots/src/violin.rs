@@ -1,3 +1,4 @@
+use crate::density::{kde, silverman_bandwidth, KernelType};
 use nalgebra::Point2;
 use vizuara_core::{Color, Primitive};
 
@@ -13,8 +14,16 @@ pub struct DensityEstimate {
 }
 
 impl DensityEstimate {
+    /// 默认评估点数量
+    pub const DEFAULT_RESOLUTION: usize = 100;
+
     /// 使用高斯核进行核密度估计
     pub fn from_data(data: &[f32], bandwidth: Option<f32>) -> Self {
+        Self::with_resolution(data, bandwidth, Self::DEFAULT_RESOLUTION)
+    }
+
+    /// 使用高斯核进行核密度估计，并指定评估网格的点数
+    pub fn with_resolution(data: &[f32], bandwidth: Option<f32>, resolution: usize) -> Self {
         if data.is_empty() {
             return Self {
                 points: Vec::new(),
@@ -27,15 +36,10 @@ impl DensityEstimate {
         let data_max = data.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
         let range = data_max - data_min;
 
-        // 自动计算带宽（Silverman's rule of thumb）
-        let bw = bandwidth.unwrap_or_else(|| {
-            let n = data.len() as f32;
-            let std_dev = Self::calculate_std_dev(data);
-            0.9 * std_dev * n.powf(-0.2)
-        });
+        let bw = bandwidth.unwrap_or_else(|| silverman_bandwidth(data));
 
         // 创建评估点
-        let num_points = 100;
+        let num_points = resolution.max(2);
         let margin = range * 0.2;
         let start = data_min - margin;
         let end = data_max + margin;
@@ -47,7 +51,7 @@ impl DensityEstimate {
 
         for i in 0..num_points {
             let x = start + i as f32 * step;
-            let density = Self::gaussian_kde(data, x, bw);
+            let density = kde(data, x, bw, KernelType::Gaussian);
 
             points.push(x);
             densities.push(density);
@@ -60,25 +64,6 @@ impl DensityEstimate {
             max_density,
         }
     }
-
-    fn calculate_std_dev(data: &[f32]) -> f32 {
-        let mean = data.iter().sum::<f32>() / data.len() as f32;
-        let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / data.len() as f32;
-        variance.sqrt()
-    }
-
-    fn gaussian_kde(data: &[f32], x: f32, bandwidth: f32) -> f32 {
-        let n = data.len() as f32;
-        let sum = data
-            .iter()
-            .map(|&xi| {
-                let u = (x - xi) / bandwidth;
-                (-0.5 * u * u).exp()
-            })
-            .sum::<f32>();
-
-        sum / (n * bandwidth * (2.0 * std::f32::consts::PI).sqrt())
-    }
 }
 
 /// 小提琴图统计数据
@@ -104,7 +89,12 @@ pub struct ViolinStatistics {
 
 impl ViolinStatistics {
     /// 从原始数据计算统计信息
-    pub fn from_data(mut data: Vec<f32>) -> Self {
+    pub fn from_data(data: Vec<f32>) -> Self {
+        Self::from_data_with(data, None, DensityEstimate::DEFAULT_RESOLUTION)
+    }
+
+    /// 从原始数据计算统计信息，并指定密度估计的带宽与网格分辨率
+    pub fn from_data_with(mut data: Vec<f32>, bandwidth: Option<f32>, resolution: usize) -> Self {
         if data.is_empty() {
             return Self {
                 min: 0.0,
@@ -147,7 +137,7 @@ impl ViolinStatistics {
             .collect();
 
         // 计算密度估计
-        let density = DensityEstimate::from_data(&data, None);
+        let density = DensityEstimate::with_resolution(&data, bandwidth, resolution);
 
         Self {
             min,
@@ -167,8 +157,12 @@ impl ViolinStatistics {
 pub struct ViolinGroup {
     /// 组标签
     pub label: String,
-    /// 统计数据
+    /// 统计数据（分裂小提琴时为左半部分）
     pub statistics: ViolinStatistics,
+    /// 原始样本，用于按图表的带宽与分辨率重新估计密度
+    samples: Vec<f32>,
+    /// 分裂小提琴右半部分的统计数据与原始样本
+    right: Option<(ViolinStatistics, Vec<f32>)>,
 }
 
 impl ViolinGroup {
@@ -176,6 +170,8 @@ impl ViolinGroup {
         Self {
             label: label.into(),
             statistics,
+            samples: Vec::new(),
+            right: None,
         }
     }
 
@@ -183,7 +179,42 @@ impl ViolinGroup {
     pub fn from_data<S: Into<String>>(label: S, data: Vec<f32>) -> Self {
         Self {
             label: label.into(),
-            statistics: ViolinStatistics::from_data(data),
+            statistics: ViolinStatistics::from_data(data.clone()),
+            samples: data,
+            right: None,
+        }
+    }
+
+    /// 创建分裂小提琴：左右两半分别展示两组数据的密度
+    pub fn split<S: Into<String>>(label: S, left_data: Vec<f32>, right_data: Vec<f32>) -> Self {
+        Self {
+            label: label.into(),
+            statistics: ViolinStatistics::from_data(left_data.clone()),
+            samples: left_data,
+            right: Some((ViolinStatistics::from_data(right_data.clone()), right_data)),
+        }
+    }
+
+    /// 是否为分裂小提琴
+    pub fn is_split(&self) -> bool {
+        self.right.is_some()
+    }
+
+    /// 分裂小提琴右半部分的统计数据
+    pub fn right_statistics(&self) -> Option<&ViolinStatistics> {
+        self.right.as_ref().map(|(stats, _)| stats)
+    }
+
+    /// 按给定带宽与分辨率重新估计缓存的密度；没有原始样本时保留原有结果
+    fn estimate_density(&mut self, bandwidth: Option<f32>, resolution: usize) {
+        if !self.samples.is_empty() {
+            self.statistics.density =
+                DensityEstimate::with_resolution(&self.samples, bandwidth, resolution);
+        }
+        if let Some((stats, samples)) = &mut self.right {
+            if !samples.is_empty() {
+                stats.density = DensityEstimate::with_resolution(samples, bandwidth, resolution);
+            }
         }
     }
 }
@@ -266,6 +297,12 @@ pub struct ViolinPlot {
     style: ViolinStyle,
     /// 数值范围 (用于 Y 轴缩放)
     value_range: Option<(f32, f32)>,
+    /// 核密度估计带宽，None 时使用 Silverman 经验法则
+    bandwidth: Option<f32>,
+    /// 密度评估网格点数
+    resolution: usize,
+    /// 分裂小提琴左右两半的填充颜色
+    split_colors: (Color, Color),
     /// 标题
     title: Option<String>,
 }
@@ -277,25 +314,76 @@ impl ViolinPlot {
             groups: Vec::new(),
             style: ViolinStyle::default(),
             value_range: None,
+            bandwidth: None,
+            resolution: DensityEstimate::DEFAULT_RESOLUTION,
+            split_colors: (
+                Color::rgba(0.2, 0.6, 0.9, 0.6),
+                Color::rgba(0.9, 0.5, 0.2, 0.6),
+            ),
             title: None,
         }
     }
 
     /// 添加数据组
     pub fn add_group(mut self, group: ViolinGroup) -> Self {
-        self.groups.push(group);
+        self.push_group(group);
         self
     }
 
     /// 从多组原始数据创建小提琴图
     pub fn from_data_groups(mut self, data_groups: &[(&str, Vec<f32>)]) -> Self {
         for &(label, ref data) in data_groups {
-            let group = ViolinGroup::from_data(label, data.clone());
-            self.groups.push(group);
+            self.push_group(ViolinGroup::from_data(label, data.clone()));
         }
         self
     }
 
+    /// 添加组；图表使用非默认的带宽或分辨率时按图表设置重新估计其密度
+    fn push_group(&mut self, mut group: ViolinGroup) {
+        if self.bandwidth.is_some() || self.resolution != DensityEstimate::DEFAULT_RESOLUTION {
+            group.estimate_density(self.bandwidth, self.resolution);
+        }
+        self.groups.push(group);
+    }
+
+    /// 添加分裂小提琴，左右两半分别展示两组数据
+    pub fn split<S: Into<String>>(
+        mut self,
+        label: S,
+        left_data: Vec<f32>,
+        right_data: Vec<f32>,
+    ) -> Self {
+        self.push_group(ViolinGroup::split(label, left_data, right_data));
+        self
+    }
+
+    /// 设置核密度估计带宽，并重新估计已添加各组的密度
+    pub fn bandwidth(mut self, bandwidth: f32) -> Self {
+        self.bandwidth = Some(bandwidth.max(f32::EPSILON));
+        self.refresh_densities();
+        self
+    }
+
+    /// 设置密度评估网格点数，并重新估计已添加各组的密度
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(2);
+        self.refresh_densities();
+        self
+    }
+
+    /// 按当前带宽与分辨率重新估计所有组的密度
+    fn refresh_densities(&mut self) {
+        for group in &mut self.groups {
+            group.estimate_density(self.bandwidth, self.resolution);
+        }
+    }
+
+    /// 设置分裂小提琴左右两半的填充颜色
+    pub fn split_colors(mut self, left: Color, right: Color) -> Self {
+        self.split_colors = (left, right);
+        self
+    }
+
     /// 设置样式
     pub fn style(mut self, style: ViolinStyle) -> Self {
         self.style = style;
@@ -369,6 +457,10 @@ impl ViolinPlot {
             let stats = &group.statistics;
             min_val = min_val.min(stats.min);
             max_val = max_val.max(stats.max);
+            if let Some((right, _)) = &group.right {
+                min_val = min_val.min(right.min);
+                max_val = max_val.max(right.max);
+            }
         }
 
         let margin = (max_val - min_val) * 0.1;
@@ -403,8 +495,27 @@ impl ViolinPlot {
                 plot_area.y + plot_area.height - normalized * plot_area.height
             };
 
+            if let Some(right_stats) = group.right_statistics() {
+                self.draw_split_violin(
+                    &mut primitives,
+                    center_x,
+                    violin_width,
+                    group_width * self.style.box_width,
+                    (stats, right_stats),
+                    normalize_y,
+                );
+                self.draw_group_label(&mut primitives, center_x, &plot_area, &group.label);
+                continue;
+            }
+
             // 绘制小提琴形状
-            self.draw_violin_shape(&mut primitives, center_x, violin_width, stats, normalize_y);
+            self.draw_violin_shape(
+                &mut primitives,
+                center_x,
+                violin_width,
+                &stats.density,
+                normalize_y,
+            );
 
             // 绘制箱线图（如果启用）
             if self.style.show_box {
@@ -442,14 +553,7 @@ impl ViolinPlot {
             }
 
             // 添加组标签
-            primitives.push(Primitive::Text {
-                position: Point2::new(center_x, plot_area.y + plot_area.height + 20.0),
-                content: group.label.clone(),
-                size: 12.0,
-                color: Color::rgb(0.2, 0.2, 0.2),
-                h_align: vizuara_core::HorizontalAlign::Center,
-                v_align: vizuara_core::VerticalAlign::Top,
-            });
+            self.draw_group_label(&mut primitives, center_x, &plot_area, &group.label);
         }
 
         // 添加标题
@@ -461,27 +565,121 @@ impl ViolinPlot {
                 color: Color::rgb(0.2, 0.2, 0.2),
                 h_align: vizuara_core::HorizontalAlign::Center,
                 v_align: vizuara_core::VerticalAlign::Bottom,
+                rotation: 0.0,
             });
         }
 
         primitives
     }
 
+    fn draw_group_label(
+        &self,
+        primitives: &mut Vec<Primitive>,
+        center_x: f32,
+        plot_area: &super::PlotArea,
+        label: &str,
+    ) {
+        primitives.push(Primitive::Text {
+            position: Point2::new(center_x, plot_area.y + plot_area.height + 20.0),
+            content: label.to_string(),
+            size: 12.0,
+            color: Color::rgb(0.2, 0.2, 0.2),
+            h_align: vizuara_core::HorizontalAlign::Center,
+            v_align: vizuara_core::VerticalAlign::Top,
+            rotation: 0.0,
+        });
+    }
+
+    /// 绘制分裂小提琴：左右半边镜像于中线，按两者共同的最大密度归一化
+    fn draw_split_violin<F>(
+        &self,
+        primitives: &mut Vec<Primitive>,
+        center_x: f32,
+        violin_width: f32,
+        box_width: f32,
+        (left, right): (&ViolinStatistics, &ViolinStatistics),
+        normalize_y: F,
+    ) where
+        F: Fn(f32) -> f32,
+    {
+        let max_density = left.density.max_density.max(right.density.max_density);
+        if max_density <= 0.0 {
+            return;
+        }
+
+        let (left_color, right_color) = self.split_colors;
+        let halves = [(-1.0, left, left_color), (1.0, right, right_color)];
+
+        for (side, stats, fill) in halves {
+            let density = &stats.density;
+            let mut points: Vec<Point2<f32>> = density
+                .points
+                .iter()
+                .zip(&density.densities)
+                .map(|(point, d)| {
+                    let half_width = violin_width * (d / max_density) / 2.0;
+                    Point2::new(center_x + side * half_width, normalize_y(*point))
+                })
+                .collect();
+
+            if points.len() >= 2 {
+                // 沿中线闭合半边轮廓
+                let first_y = points[0].y;
+                let last_y = points[points.len() - 1].y;
+                points.push(Point2::new(center_x, last_y));
+                points.push(Point2::new(center_x, first_y));
+
+                primitives.push(Primitive::Polygon {
+                    points,
+                    fill,
+                    stroke: Some((
+                        self.style.violin_stroke_color,
+                        self.style.violin_stroke_width,
+                    )),
+                });
+            }
+
+            // 半边内的四分位箱与中位数
+            if self.style.show_box {
+                let edge_x = center_x + side * box_width / 2.0;
+                let q1_y = normalize_y(stats.q1);
+                let q3_y = normalize_y(stats.q3);
+                primitives.push(Primitive::RectangleStyled {
+                    min: Point2::new(center_x.min(edge_x), q3_y),
+                    max: Point2::new(center_x.max(edge_x), q1_y),
+                    fill: Color::rgba(1.0, 1.0, 1.0, 0.8),
+                    stroke: Some((self.style.box_color, 1.0)),
+                });
+            }
+
+            let median_y = normalize_y(stats.median);
+            primitives.push(Primitive::Polyline {
+                points: vec![
+                    Point2::new(center_x, median_y),
+                    Point2::new(center_x + side * box_width / 2.0, median_y),
+                ],
+                color: self.style.median_color,
+                width: self.style.median_width,
+            });
+        }
+    }
+
     fn draw_violin_shape<F>(
         &self,
         primitives: &mut Vec<Primitive>,
         center_x: f32,
         violin_width: f32,
-        stats: &ViolinStatistics,
+        density: &DensityEstimate,
         normalize_y: F,
     ) where
         F: Fn(f32) -> f32,
     {
-        if stats.density.points.is_empty() {
+        if density.points.is_empty() {
             return;
         }
 
-        let max_density = stats.density.max_density;
+        // 以最大密度归一化，使最宽处始终达到 violin_width，与样本量无关
+        let max_density = density.max_density;
         if max_density <= 0.0 {
             return;
         }
@@ -490,7 +688,7 @@ impl ViolinPlot {
         let mut left_points = Vec::new();
         let mut right_points = Vec::new();
 
-        for (point, density) in stats.density.points.iter().zip(&stats.density.densities) {
+        for (point, density) in density.points.iter().zip(&density.densities) {
             let y = normalize_y(*point);
             let width_factor = density / max_density;
             let half_width = violin_width * width_factor / 2.0;
@@ -597,4 +795,95 @@ mod tests {
         let plot = ViolinPlot::new().from_data_groups(&data_groups);
         assert_eq!(plot.group_count(), 2);
     }
+
+    #[test]
+    fn test_bandwidth_and_resolution() {
+        let data = vec![1.0, 2.0, 2.5, 3.0, 4.0, 8.0];
+        let density = DensityEstimate::with_resolution(&data, Some(0.5), 40);
+        assert_eq!(density.points.len(), 40);
+
+        // 带宽越小，密度峰值越高
+        let narrow = DensityEstimate::from_data(&data, Some(0.2));
+        let wide = DensityEstimate::from_data(&data, Some(2.0));
+        assert!(narrow.max_density > wide.max_density);
+    }
+
+    #[test]
+    fn test_plot_density_settings_cached_in_groups() {
+        let data = vec![1.0, 2.0, 2.5, 3.0, 4.0, 8.0];
+        let expected = DensityEstimate::with_resolution(&data, Some(0.5), 40);
+
+        // 设置带宽/分辨率前后添加组，缓存的密度一致
+        let before = ViolinPlot::new()
+            .bandwidth(0.5)
+            .resolution(40)
+            .split("A", data.clone(), data.clone());
+        let after = ViolinPlot::new()
+            .split("A", data.clone(), data.clone())
+            .bandwidth(0.5)
+            .resolution(40);
+
+        for plot in [&before, &after] {
+            let group = &plot.groups[0];
+            assert_eq!(group.statistics.density.densities, expected.densities);
+            let right = group.right_statistics().unwrap();
+            assert_eq!(right.density.densities, expected.densities);
+        }
+    }
+
+    #[test]
+    fn test_violin_width_independent_of_sample_size() {
+        let plot_area = crate::PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let widest = |data: Vec<f32>| {
+            let plot = ViolinPlot::new()
+                .from_data_groups(&[("A", data)])
+                .value_range(-5.0, 15.0)
+                .show_box(false, 0.1);
+            let primitives = plot.generate_primitives(plot_area);
+            match &primitives[0] {
+                Primitive::Polygon { points, .. } => {
+                    let min_x = points.iter().fold(f32::INFINITY, |a, p| a.min(p.x));
+                    let max_x = points.iter().fold(f32::NEG_INFINITY, |a, p| a.max(p.x));
+                    max_x - min_x
+                }
+                other => panic!("expected violin polygon, got {:?}", other),
+            }
+        };
+
+        let small = widest(vec![4.0, 5.0, 6.0]);
+        let large = widest((0..200).map(|i| (i % 11) as f32).collect());
+        assert!((small - 80.0).abs() < 1e-3);
+        assert!((large - 80.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_split_violin() {
+        let left = Color::rgb(1.0, 0.0, 0.0);
+        let right = Color::rgb(0.0, 0.0, 1.0);
+        let plot = ViolinPlot::new()
+            .split("A", vec![1.0, 2.0, 3.0, 4.0], vec![3.0, 4.0, 5.0, 6.0])
+            .split_colors(left, right)
+            .bandwidth(0.8)
+            .auto_range();
+
+        let primitives = plot.generate_primitives(crate::PlotArea::new(0.0, 0.0, 100.0, 100.0));
+        let halves: Vec<(Color, f32, f32)> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::Polygon { points, fill, .. } => {
+                    let min_x = points.iter().fold(f32::INFINITY, |a, p| a.min(p.x));
+                    let max_x = points.iter().fold(f32::NEG_INFINITY, |a, p| a.max(p.x));
+                    Some((*fill, min_x, max_x))
+                }
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(halves.len(), 2);
+        // 左半边位于中线左侧，右半边位于中线右侧
+        assert_eq!(halves[0].0, left);
+        assert!((halves[0].2 - 50.0).abs() < 1e-3);
+        assert_eq!(halves[1].0, right);
+        assert!((halves[1].1 - 50.0).abs() < 1e-3);
+    }
 }
@@ -0,0 +1,205 @@
+//! 图表标注
+//!
+//! 以数据坐标指定的文本、箭头、参考线和矩形区域，渲染时经比例尺转换到屏幕坐标
+
+use nalgebra::{Point2, Vector2};
+use serde::{Deserialize, Serialize};
+use vizuara_core::{Color, HorizontalAlign, LinearScale, Primitive, Scale, VerticalAlign};
+use vizuara_plots::PlotArea;
+
+/// 箭头头部长度（像素）
+const ARROW_HEAD_LENGTH: f32 = 10.0;
+/// 箭头头部半宽（像素）
+const ARROW_HEAD_HALF_WIDTH: f32 = 4.0;
+
+/// 标注，坐标均为数据坐标
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Annotation {
+    /// 在指定位置显示文本
+    TextAt {
+        world_pos: Point2<f32>,
+        text: String,
+    },
+    /// 从 from 指向 to 的箭头
+    Arrow { from: Point2<f32>, to: Point2<f32> },
+    /// 贯穿绘图区域的水平参考线
+    HLine { y: f32 },
+    /// 贯穿绘图区域的垂直参考线
+    VLine { x: f32 },
+    /// 矩形区域，bounds 为 (最小角, 最大角)
+    Rect { bounds: (Point2<f32>, Point2<f32>) },
+}
+
+impl Annotation {
+    /// 创建文本标注
+    pub fn text<S: Into<String>>(x: f32, y: f32, text: S) -> Self {
+        Self::TextAt {
+            world_pos: Point2::new(x, y),
+            text: text.into(),
+        }
+    }
+
+    /// 创建箭头标注
+    pub fn arrow(from: (f32, f32), to: (f32, f32)) -> Self {
+        Self::Arrow {
+            from: Point2::new(from.0, from.1),
+            to: Point2::new(to.0, to.1),
+        }
+    }
+
+    /// 创建矩形区域标注
+    pub fn rect(min: (f32, f32), max: (f32, f32)) -> Self {
+        Self::Rect {
+            bounds: (Point2::new(min.0, min.1), Point2::new(max.0, max.1)),
+        }
+    }
+
+    /// 转换为渲染图元
+    pub fn to_primitives(
+        &self,
+        plot_area: PlotArea,
+        x_scale: &LinearScale,
+        y_scale: &LinearScale,
+    ) -> Vec<Primitive> {
+        let color = Color::rgb(0.2, 0.2, 0.2);
+        let to_screen = |p: Point2<f32>| {
+            Point2::new(
+                plot_area.x + x_scale.normalize(p.x) * plot_area.width,
+                plot_area.y + plot_area.height - y_scale.normalize(p.y) * plot_area.height,
+            )
+        };
+
+        match self {
+            Annotation::TextAt { world_pos, text } => vec![Primitive::Text {
+                position: to_screen(*world_pos),
+                content: text.clone(),
+                size: 12.0,
+                color,
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Bottom,
+                rotation: 0.0,
+            }],
+            Annotation::Arrow { from, to } => {
+                let start = to_screen(*from);
+                let tip = to_screen(*to);
+                let delta = tip - start;
+                let length = delta.norm();
+                if length <= f32::EPSILON {
+                    return Vec::new();
+                }
+
+                // 线段止于箭头底边，箭头为指向 tip 的三角形
+                let direction = delta / length;
+                let normal = Vector2::new(-direction.y, direction.x);
+                let head_length = ARROW_HEAD_LENGTH.min(length);
+                let base = tip - direction * head_length;
+
+                vec![
+                    Primitive::Polyline {
+                        points: vec![start, base],
+                        color,
+                        width: 1.5,
+                    },
+                    Primitive::Polygon {
+                        points: vec![
+                            tip,
+                            base + normal * ARROW_HEAD_HALF_WIDTH,
+                            base - normal * ARROW_HEAD_HALF_WIDTH,
+                        ],
+                        fill: color,
+                        stroke: None,
+                    },
+                ]
+            }
+            Annotation::HLine { y } => {
+                let screen_y = to_screen(Point2::new(0.0, *y)).y;
+                if screen_y < plot_area.y || screen_y > plot_area.y + plot_area.height {
+                    return Vec::new();
+                }
+                vec![Primitive::Polyline {
+                    points: vec![
+                        Point2::new(plot_area.x, screen_y),
+                        Point2::new(plot_area.x + plot_area.width, screen_y),
+                    ],
+                    color: Color::rgb(0.5, 0.5, 0.5),
+                    width: 1.0,
+                }]
+            }
+            Annotation::VLine { x } => {
+                let screen_x = to_screen(Point2::new(*x, 0.0)).x;
+                if screen_x < plot_area.x || screen_x > plot_area.x + plot_area.width {
+                    return Vec::new();
+                }
+                vec![Primitive::Polyline {
+                    points: vec![
+                        Point2::new(screen_x, plot_area.y),
+                        Point2::new(screen_x, plot_area.y + plot_area.height),
+                    ],
+                    color: Color::rgb(0.5, 0.5, 0.5),
+                    width: 1.0,
+                }]
+            }
+            Annotation::Rect { bounds } => {
+                let a = to_screen(bounds.0);
+                let b = to_screen(bounds.1);
+                vec![Primitive::RectangleStyled {
+                    min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+                    max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+                    fill: Color::rgba(0.95, 0.75, 0.2, 0.2),
+                    stroke: Some((Color::rgb(0.85, 0.6, 0.1), 1.0)),
+                }]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area() -> PlotArea {
+        PlotArea::new(100.0, 50.0, 200.0, 100.0)
+    }
+
+    #[test]
+    fn test_annotation_uses_data_coordinates() {
+        let x_scale = LinearScale::new(0.0, 10.0);
+        let y_scale = LinearScale::new(0.0, 10.0);
+
+        let primitives =
+            Annotation::text(5.0, 5.0, "峰值").to_primitives(area(), &x_scale, &y_scale);
+        match &primitives[0] {
+            Primitive::Text { position, .. } => assert_eq!(*position, Point2::new(200.0, 100.0)),
+            other => panic!("expected text, got {:?}", other),
+        }
+
+        // 参考线贯穿整个绘图区域，超出范围时不绘制
+        let primitives = Annotation::HLine { y: 2.0 }.to_primitives(area(), &x_scale, &y_scale);
+        match &primitives[0] {
+            Primitive::Polyline { points, .. } => {
+                assert_eq!(points[0], Point2::new(100.0, 130.0));
+                assert_eq!(points[1], Point2::new(300.0, 130.0));
+            }
+            other => panic!("expected reference line, got {:?}", other),
+        }
+        assert!(Annotation::VLine { x: 20.0 }
+            .to_primitives(area(), &x_scale, &y_scale)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_arrow_has_head() {
+        let scale = LinearScale::new(0.0, 10.0);
+        let primitives =
+            Annotation::arrow((0.0, 5.0), (10.0, 5.0)).to_primitives(area(), &scale, &scale);
+
+        assert_eq!(primitives.len(), 2);
+        match &primitives[1] {
+            Primitive::Polygon { points, .. } => {
+                assert_eq!(points.len(), 3);
+                assert_eq!(points[0], Point2::new(300.0, 100.0));
+            }
+            other => panic!("expected arrow head, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! 二维坐标轴的坐标变换
+//!
+//! 比例尺加上一段像素范围。场景按当前坐标轴为绘图区域构建 X/Y 两个变换，
+//! 刻度、网格线与数据点都经由同一变换映射，对数轴、时间轴与线性轴的用法一致
+
+use std::fmt;
+use vizuara_core::Scale;
+
+/// 二维坐标轴变换：持有比例尺与像素范围
+///
+/// 定义域下限映射到 `pixel_range.0`，上限映射到 `pixel_range.1`；
+/// 屏幕 Y 轴向下增长，因此 Y 轴通常传入 (底部, 顶部)
+pub struct SharedAxis {
+    scale: Box<dyn Scale>,
+    pixel_range: (f32, f32),
+}
+
+impl SharedAxis {
+    /// 由比例尺与像素范围创建坐标轴
+    pub fn new<S: Scale + 'static>(scale: S, pixel_range: (f32, f32)) -> Self {
+        Self::from_boxed(Box::new(scale), pixel_range)
+    }
+
+    /// 由已装箱的比例尺创建坐标轴
+    pub fn from_boxed(scale: Box<dyn Scale>, pixel_range: (f32, f32)) -> Self {
+        Self { scale, pixel_range }
+    }
+
+    /// 比例尺
+    pub fn scale(&self) -> &dyn Scale {
+        self.scale.as_ref()
+    }
+
+    /// 像素范围 (定义域下限对应的像素, 定义域上限对应的像素)
+    pub fn pixel_range(&self) -> (f32, f32) {
+        self.pixel_range
+    }
+
+    /// 数据值映射到像素坐标
+    pub fn value_to_pixel(&self, value: f32) -> f32 {
+        let (start, end) = self.pixel_range;
+        self.scale.to_pixel(value, start, end)
+    }
+
+    /// 像素坐标映射回数据值，与 [`value_to_pixel`](Self::value_to_pixel) 互逆
+    pub fn pixel_to_value(&self, pixel: f32) -> f32 {
+        let (start, end) = self.pixel_range;
+        self.scale.from_pixel(pixel, start, end)
+    }
+
+    /// 主刻度：(数据值, 像素坐标)
+    pub fn ticks(&self, count: usize) -> Vec<(f32, f32)> {
+        self.scale
+            .ticks(count)
+            .into_iter()
+            .map(|value| (value, self.value_to_pixel(value)))
+            .collect()
+    }
+}
+
+impl fmt::Debug for SharedAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedAxis")
+            .field("pixel_range", &self.pixel_range)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_core::{LinearScale, LogScale};
+
+    #[test]
+    fn test_axis_round_trip() {
+        // 屏幕 Y 轴：定义域下限在底部
+        let axis = SharedAxis::new(LinearScale::new(0.0, 10.0), (200.0, 100.0));
+        assert_eq!(axis.value_to_pixel(0.0), 200.0);
+        assert_eq!(axis.value_to_pixel(10.0), 100.0);
+        assert_eq!(axis.pixel_to_value(150.0), 5.0);
+
+        let log = SharedAxis::new(LogScale::base10(1.0, 100.0), (0.0, 100.0));
+        assert!((log.value_to_pixel(10.0) - 50.0).abs() < 1e-4);
+        assert!((log.pixel_to_value(50.0) - 10.0).abs() < 1e-3);
+        assert!(log
+            .ticks(5)
+            .iter()
+            .all(|&(value, pixel)| (log.value_to_pixel(value) - pixel).abs() < 1e-6));
+    }
+}
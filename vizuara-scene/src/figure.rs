@@ -1,5 +1,28 @@
-use crate::Scene;
-use vizuara_core::{Primitive, Result};
+use crate::{Annotation, FigureSpec, Scene};
+use serde::{Deserialize, Serialize};
+use vizuara_core::{Color, Primitive, Result, Style, VizuaraError};
+use vizuara_plots::PlotArea;
+
+/// 图形四周的留白（像素），用于放置标题、坐标轴标签和图例
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Margins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Margins {
+    /// 创建留白
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
 
 /// 图形对象：整个可视化的顶层容器
 pub struct Figure {
@@ -7,6 +30,9 @@ pub struct Figure {
     width: f32,
     height: f32,
     title: Option<String>,
+    annotations: Vec<Annotation>,
+    margins: Option<Margins>,
+    background: Option<Color>,
 }
 
 impl Figure {
@@ -17,6 +43,9 @@ impl Figure {
             width,
             height,
             title: None,
+            annotations: Vec::new(),
+            margins: None,
+            background: None,
         }
     }
 
@@ -28,16 +57,67 @@ impl Figure {
         self
     }
 
-    /// 添加场景
-    pub fn add_scene(mut self, scene: Scene) -> Self {
+    /// 设置四周留白；此后所有场景（包括已添加的）都使用留白内的绘图区域
+    pub fn margins(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.margins = Some(Margins::new(top, right, bottom, left));
+        let area = self.plot_area();
+        for scene in &mut self.scenes {
+            scene.set_plot_area(area);
+        }
+        self
+    }
+
+    /// 设置背景色，填充整个图形
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// 添加场景；设置了留白时场景的绘图区域替换为留白内的区域
+    pub fn add_scene(mut self, mut scene: Scene) -> Self {
+        if self.margins.is_some() {
+            scene.set_plot_area(self.plot_area());
+        }
         self.scenes.push(scene);
         self
     }
 
-    /// 生成所有渲染图元
+    /// 图形尺寸减去留白后的绘图区域；未设置留白时为整个图形
+    pub fn plot_area(&self) -> PlotArea {
+        let m = self.margins.unwrap_or(Margins::new(0.0, 0.0, 0.0, 0.0));
+        PlotArea::new(
+            m.left,
+            m.top,
+            (self.width - m.left - m.right).max(0.0),
+            (self.height - m.top - m.bottom).max(0.0),
+        )
+    }
+
+    /// 添加标注，渲染时使用最后一个场景的绘图区域和坐标轴比例尺
+    pub fn annotate(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// 生成所有渲染图元：背景在最下方，各场景按自身的层顺序依次绘制
+    /// （子图之间互不穿插），图形级标注在最上方
     pub fn generate_primitives(&self) -> Vec<Primitive> {
+        self.generate_styled_primitives().0
+    }
+
+    /// 与 [`generate_primitives`](Self::generate_primitives) 相同，并给出每个图元的样式
+    pub fn generate_styled_primitives(&self) -> (Vec<Primitive>, Vec<Style>) {
         let mut primitives = Vec::new();
 
+        if let Some(color) = self.background {
+            primitives.push(Primitive::RectangleStyled {
+                min: nalgebra::Point2::new(0.0, 0.0),
+                max: nalgebra::Point2::new(self.width, self.height),
+                fill: color,
+                stroke: None,
+            });
+        }
+
         // 添加整体标题
         if let Some(ref title) = self.title {
             primitives.push(Primitive::Text {
@@ -47,15 +127,25 @@ impl Figure {
                 color: vizuara_core::Color::rgb(0.1, 0.1, 0.1),
                 h_align: vizuara_core::HorizontalAlign::Center,
                 v_align: vizuara_core::VerticalAlign::Bottom,
+                rotation: 0.0,
             });
         }
 
+        let mut styles = vec![Style::default(); primitives.len()];
+
         // 添加所有场景的图元
         for scene in &self.scenes {
-            primitives.extend(scene.generate_primitives());
+            let (scene_primitives, scene_styles) = scene.generate_styled_primitives();
+            primitives.extend(scene_primitives);
+            styles.extend(scene_styles);
         }
 
-        primitives
+        if let Some(scene) = self.scenes.last() {
+            primitives.extend(scene.annotation_primitives(&self.annotations));
+        }
+
+        styles.resize(primitives.len(), Style::default());
+        (primitives, styles)
     }
 
     /// 在窗口中渲染
@@ -76,6 +166,49 @@ impl Figure {
     pub fn scene_count(&self) -> usize {
         self.scenes.len()
     }
+
+    /// 导出图形配置（而非生成的图元）；含暂不支持序列化的内容时返回错误
+    pub fn to_spec(&self) -> Result<FigureSpec> {
+        Ok(FigureSpec {
+            width: self.width,
+            height: self.height,
+            title: self.title.clone(),
+            margins: self.margins,
+            background: self.background,
+            annotations: self.annotations.clone(),
+            scenes: self
+                .scenes
+                .iter()
+                .map(Scene::to_spec)
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+
+    /// 由图形配置重建图形
+    pub fn from_spec(spec: FigureSpec) -> Result<Self> {
+        let mut figure = Figure::new(spec.width, spec.height);
+        figure.title = spec.title;
+        figure.margins = spec.margins;
+        figure.background = spec.background;
+        figure.annotations = spec.annotations;
+        for scene in spec.scenes {
+            figure = figure.add_scene(Scene::from_spec(scene)?);
+        }
+        Ok(figure)
+    }
+
+    /// 序列化为 JSON，保存后可用 `from_json` 重建，无需重新运行构建代码
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.to_spec()?)
+            .map_err(|e| VizuaraError::ConfigError(format!("图形序列化失败: {}", e)))
+    }
+
+    /// 从 `to_json` 生成的 JSON 重建图形
+    pub fn from_json(json: &str) -> Result<Self> {
+        let spec: FigureSpec = serde_json::from_str(json)
+            .map_err(|e| VizuaraError::ConfigError(format!("图形配置解析失败: {}", e)))?;
+        Self::from_spec(spec)
+    }
 }
 
 impl Default for Figure {
@@ -149,4 +282,124 @@ mod tests {
             primitives.len()
         );
     }
+
+    #[test]
+    fn test_figure_margins_and_background() {
+        let scene = Scene::new(PlotArea::new(100.0, 100.0, 400.0, 300.0))
+            .add_x_axis(LinearScale::new(0.0, 1.0), None);
+
+        let figure = Figure::new(800.0, 600.0)
+            .background(Color::rgb(0.9, 0.9, 0.9))
+            .add_scene(scene)
+            .margins(50.0, 20.0, 60.0, 80.0);
+
+        let expected = PlotArea::new(80.0, 50.0, 700.0, 490.0);
+        assert_eq!(figure.plot_area(), expected);
+        assert_eq!(figure.scenes[0].plot_area(), expected);
+
+        // 之后添加的场景同样使用留白内的区域
+        let figure = figure.add_scene(Scene::new(PlotArea::new(0.0, 0.0, 10.0, 10.0)));
+        assert_eq!(figure.scenes[1].plot_area(), expected);
+
+        let primitives = figure.generate_primitives();
+        match &primitives[0] {
+            Primitive::RectangleStyled { min, max, fill, .. } => {
+                assert_eq!(*min, nalgebra::Point2::new(0.0, 0.0));
+                assert_eq!(*max, nalgebra::Point2::new(800.0, 600.0));
+                assert_eq!(*fill, Color::rgb(0.9, 0.9, 0.9));
+            }
+            other => panic!("expected background, got {:?}", other),
+        }
+
+        // 坐标轴随绘图区域重新定位到其下方
+        let axis_y = 50.0 + 490.0 + 20.0;
+        assert!(primitives.iter().any(|p| matches!(
+            p,
+            Primitive::Line { start, end } if start.y == axis_y && end.y == axis_y
+        )));
+
+        assert_eq!(
+            Figure::new(800.0, 600.0).plot_area(),
+            PlotArea::new(0.0, 0.0, 800.0, 600.0)
+        );
+    }
+
+    #[test]
+    fn test_figure_annotations_follow_scene_scales() {
+        let plot_area = PlotArea::new(100.0, 100.0, 400.0, 200.0);
+        let scene = Scene::new(plot_area)
+            .add_x_axis(LinearScale::new(0.0, 4.0), None)
+            .add_y_axis(LinearScale::new(0.0, 2.0), None);
+
+        let figure = Figure::new(800.0, 600.0)
+            .add_scene(scene)
+            .annotate(Annotation::VLine { x: 1.0 })
+            .annotate(Annotation::text(2.0, 1.0, "中心"));
+
+        let primitives = figure.generate_primitives();
+        let n = primitives.len();
+        match &primitives[n - 2] {
+            Primitive::Polyline { points, .. } => {
+                assert_eq!(points[0], nalgebra::Point2::new(200.0, 100.0));
+                assert_eq!(points[1], nalgebra::Point2::new(200.0, 300.0));
+            }
+            other => panic!("expected reference line, got {:?}", other),
+        }
+        match &primitives[n - 1] {
+            Primitive::Text { position, .. } => {
+                assert_eq!(*position, nalgebra::Point2::new(300.0, 200.0))
+            }
+            other => panic!("expected annotation text, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_figure_json_round_trip() {
+        use crate::YAxisSide;
+        use vizuara_plots::{BarPlot, HistOverlay, Histogram, LinePlot, PieChart};
+
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 10.0, 10.0))
+            .add_x_axis(LinearScale::new(0.0, 5.0), Some("时间".to_string()))
+            .add_y_axis(LinearScale::new(0.0, 10.0), None)
+            .add_secondary_y_axis(LinearScale::new(0.0, 100.0), Some("占比".to_string()))
+            .add_scatter_plot(
+                ScatterPlot::new()
+                    .data(&[(1.0, 2.0), (3.0, 4.0)])
+                    .auto_scale(),
+            )
+            .add_line_plot(LinePlot::new().data(&[(0.0, 1.0), (5.0, 9.0)]).auto_scale())
+            .add_histogram(
+                Histogram::new()
+                    .data(&[1.0, 2.0, 2.5, 3.0, 4.0])
+                    .overlay(HistOverlay::MeanLine),
+            )
+            .add_secondary_bar_plot(BarPlot::new().data(&[("A", 40.0), ("B", 70.0)]))
+            .legend_entry("样本", Color::rgb(0.2, 0.4, 0.8), YAxisSide::Left)
+            .annotate(Annotation::HLine { y: 5.0 })
+            .grid(true)
+            .title("场景");
+
+        let figure = Figure::new(640.0, 480.0)
+            .title("可复现的图形")
+            .background(Color::rgb(1.0, 1.0, 1.0))
+            .margins(40.0, 60.0, 50.0, 70.0)
+            .add_scene(scene)
+            .annotate(Annotation::text(1.0, 1.0, "备注"));
+
+        let json = figure.to_json().unwrap();
+        assert!(json.contains("\"type\": \"histogram\""));
+        let restored = Figure::from_json(&json).unwrap();
+        assert_eq!(restored.size(), (640.0, 480.0));
+        assert_eq!(restored.plot_area(), figure.plot_area());
+        assert_eq!(restored.generate_primitives(), figure.generate_primitives());
+
+        // 暂不支持序列化的图表导出时报错，而不是被静默丢弃
+        let pie = Scene::new(PlotArea::new(0.0, 0.0, 10.0, 10.0)).add_pie_chart(PieChart::new());
+        let figure = Figure::new(100.0, 100.0).add_scene(pie);
+        assert!(matches!(
+            figure.to_json(),
+            Err(VizuaraError::UnsupportedFeature(_))
+        ));
+        assert!(Figure::from_json("{").is_err());
+    }
 }
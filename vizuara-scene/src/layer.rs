@@ -0,0 +1,81 @@
+//! 图元绘制层
+//!
+//! 2D 渲染器没有深度，按提交顺序绘制。场景为生成的每个图元标注所属的层，
+//! 交给渲染器之前按层排序，保证网格线总在数据之下、图例总在最上方，
+//! 而不依赖各图表自己的生成顺序
+
+use vizuara_core::{Primitive, Style};
+
+/// 图元所属的绘制层，按声明顺序从下到上绘制：
+/// 背景、网格线、数据、标注、坐标轴（含轴线、刻度标签与标题）、图例
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrimitiveLayer {
+    Background,
+    Grid,
+    Data,
+    Annotation,
+    Axis,
+    Legend,
+}
+
+/// 带层标记的图元集合
+#[derive(Debug, Clone, Default)]
+pub struct LayeredPrimitives {
+    items: Vec<(PrimitiveLayer, Primitive)>,
+    // 与 items 一一对应的样式，未指定时为 Style::default()
+    styles: Vec<Style>,
+}
+
+impl LayeredPrimitives {
+    /// 创建空集合
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加单个图元
+    pub fn push(&mut self, layer: PrimitiveLayer, primitive: Primitive) {
+        self.items.push((layer, primitive));
+        self.styles.push(Style::default());
+    }
+
+    /// 添加同一层的多个图元
+    pub fn extend<I: IntoIterator<Item = Primitive>>(
+        &mut self,
+        layer: PrimitiveLayer,
+        primitives: I,
+    ) {
+        self.extend_styled(layer, primitives, Style::default());
+    }
+
+    /// 添加同一层、使用同一样式的多个图元
+    pub fn extend_styled<I: IntoIterator<Item = Primitive>>(
+        &mut self,
+        layer: PrimitiveLayer,
+        primitives: I,
+        style: Style,
+    ) {
+        self.items
+            .extend(primitives.into_iter().map(|primitive| (layer, primitive)));
+        self.styles.resize(self.items.len(), style);
+    }
+
+    /// 所有带层标记的图元（按添加顺序）
+    pub fn items(&self) -> &[(PrimitiveLayer, Primitive)] {
+        &self.items
+    }
+
+    /// 按层排序后的图元；同一层内保持添加顺序
+    pub fn into_sorted(self) -> Vec<Primitive> {
+        self.into_sorted_styled().0
+    }
+
+    /// 按层排序后的图元及其样式；同一层内保持添加顺序
+    pub fn into_sorted_styled(self) -> (Vec<Primitive>, Vec<Style>) {
+        let mut items: Vec<_> = self.items.into_iter().zip(self.styles).collect();
+        items.sort_by_key(|((layer, _), _)| *layer);
+        items
+            .into_iter()
+            .map(|((_, primitive), style)| (primitive, style))
+            .unzip()
+    }
+}
@@ -2,8 +2,22 @@
 //!
 //! 提供高级API来组合图表、坐标轴等组件
 
+pub mod annotation;
+pub mod axis;
 pub mod figure;
+pub mod layer;
+pub mod link;
+pub mod positioned;
 pub mod scene;
+pub mod size_legend;
+pub mod spec;
 
+pub use annotation::*;
+pub use axis::*;
 pub use figure::*;
+pub use layer::*;
+pub use link::*;
+pub use positioned::*;
 pub use scene::*;
+pub use size_legend::*;
+pub use spec::*;
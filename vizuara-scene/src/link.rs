@@ -0,0 +1,249 @@
+//! 坐标轴联动
+//!
+//! 同一联动组内的场景共享同一份比例尺：任一成员设置或自动计算数据范围、
+//! 或在交互层中平移/缩放视口时，其余成员随之更新，不会出现漂移
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use vizuara_core::LinearScale;
+use vizuara_interactivity::Viewport;
+
+/// 联动的坐标轴
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkAxes {
+    /// 仅联动 X 轴
+    X,
+    /// 仅联动 Y 轴
+    Y,
+    /// 同时联动 X 轴和 Y 轴
+    Both,
+}
+
+impl LinkAxes {
+    /// 是否联动 X 轴
+    pub fn links_x(self) -> bool {
+        matches!(self, LinkAxes::X | LinkAxes::Both)
+    }
+
+    /// 是否联动 Y 轴
+    pub fn links_y(self) -> bool {
+        matches!(self, LinkAxes::Y | LinkAxes::Both)
+    }
+}
+
+/// 联动组的共享状态
+#[derive(Debug, Default)]
+struct LinkState {
+    x_scale: Option<LinearScale>,
+    y_scale: Option<LinearScale>,
+    version: u64,
+}
+
+/// 坐标轴联动组；克隆得到的句柄指向同一份共享比例尺
+#[derive(Debug, Clone)]
+pub struct AxisLink {
+    axes: LinkAxes,
+    state: Rc<RefCell<LinkState>>,
+}
+
+impl AxisLink {
+    /// 创建新的联动组
+    pub fn new(axes: LinkAxes) -> Self {
+        Self {
+            axes,
+            state: Rc::new(RefCell::new(LinkState::default())),
+        }
+    }
+
+    /// 仅联动 X 轴的联动组
+    pub fn x() -> Self {
+        Self::new(LinkAxes::X)
+    }
+
+    /// 仅联动 Y 轴的联动组
+    pub fn y() -> Self {
+        Self::new(LinkAxes::Y)
+    }
+
+    /// 同时联动两个轴的联动组
+    pub fn both() -> Self {
+        Self::new(LinkAxes::Both)
+    }
+
+    /// 联动的坐标轴
+    pub fn axes(&self) -> LinkAxes {
+        self.axes
+    }
+
+    /// 两个句柄是否属于同一联动组
+    pub fn same_group(&self, other: &AxisLink) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+
+    /// 设置共享的 X 范围；未联动 X 轴时忽略
+    pub fn set_x_range(&self, min: f32, max: f32) {
+        if self.axes.links_x() {
+            self.update(Some(LinearScale::new(min, max)), None);
+        }
+    }
+
+    /// 设置共享的 Y 范围；未联动 Y 轴时忽略
+    pub fn set_y_range(&self, min: f32, max: f32) {
+        if self.axes.links_y() {
+            self.update(None, Some(LinearScale::new(min, max)));
+        }
+    }
+
+    /// 同步交互层的视口范围（平移/缩放后调用），只更新联动的坐标轴
+    pub fn set_view_bounds(&self, min_x: f32, max_x: f32, min_y: f32, max_y: f32) {
+        let x = self.axes.links_x().then(|| LinearScale::new(min_x, max_x));
+        let y = self.axes.links_y().then(|| LinearScale::new(min_y, max_y));
+        self.update(x, y);
+    }
+
+    /// 将视口的可见范围写入联动组（视口平移/缩放后调用），只更新联动的坐标轴
+    pub fn sync_from_viewport(&self, viewport: &Viewport) {
+        let bounds = viewport.bounds();
+        self.set_view_bounds(
+            bounds.min_x as f32,
+            bounds.max_x as f32,
+            bounds.min_y as f32,
+            bounds.max_y as f32,
+        );
+    }
+
+    /// 将共享范围应用到视口；未联动或尚未确定范围的坐标轴保持视口原有范围
+    pub fn apply_to_viewport(&self, viewport: &mut Viewport) {
+        let mut bounds = viewport.bounds().clone();
+        if let Some(x) = self.x_scale().filter(|_| self.axes.links_x()) {
+            bounds.min_x = x.domain_min as f64;
+            bounds.max_x = x.domain_max as f64;
+        }
+        if let Some(y) = self.y_scale().filter(|_| self.axes.links_y()) {
+            bounds.min_y = y.domain_min as f64;
+            bounds.max_y = y.domain_max as f64;
+        }
+        if &bounds != viewport.bounds() {
+            viewport.fit_bounds(bounds);
+        }
+    }
+
+    /// 自动计算范围：将共享范围扩展到包含给定数据，空切片表示该轴无数据
+    pub fn include_data(&self, x_values: &[f32], y_values: &[f32]) {
+        let x = (self.axes.links_x() && !x_values.is_empty())
+            .then(|| union(self.x_scale(), &LinearScale::from_data(x_values)));
+        let y = (self.axes.links_y() && !y_values.is_empty())
+            .then(|| union(self.y_scale(), &LinearScale::from_data(y_values)));
+        self.update(x, y);
+    }
+
+    /// 将共享范围扩展到包含给定比例尺（成员加入联动组时使用）
+    pub fn include_scales(&self, x: Option<&LinearScale>, y: Option<&LinearScale>) {
+        let x = x
+            .filter(|_| self.axes.links_x())
+            .map(|scale| union(self.x_scale(), scale));
+        let y = y
+            .filter(|_| self.axes.links_y())
+            .map(|scale| union(self.y_scale(), scale));
+        self.update(x, y);
+    }
+
+    /// 共享的 X 比例尺；未联动 X 轴或尚未确定范围时为 None
+    pub fn x_scale(&self) -> Option<LinearScale> {
+        self.state.borrow().x_scale.clone()
+    }
+
+    /// 共享的 Y 比例尺；未联动 Y 轴或尚未确定范围时为 None
+    pub fn y_scale(&self) -> Option<LinearScale> {
+        self.state.borrow().y_scale.clone()
+    }
+
+    /// 版本号，每次范围变化递增，可用于判断是否需要重绘
+    pub fn version(&self) -> u64 {
+        self.state.borrow().version
+    }
+
+    fn update(&self, x: Option<LinearScale>, y: Option<LinearScale>) {
+        let mut state = self.state.borrow_mut();
+        let mut changed = false;
+
+        if let Some(x) = x {
+            if state.x_scale.as_ref() != Some(&x) {
+                state.x_scale = Some(x);
+                changed = true;
+            }
+        }
+        if let Some(y) = y {
+            if state.y_scale.as_ref() != Some(&y) {
+                state.y_scale = Some(y);
+                changed = true;
+            }
+        }
+
+        if changed {
+            state.version += 1;
+        }
+    }
+}
+
+/// 两个比例尺定义域的并集
+fn union(current: Option<LinearScale>, other: &LinearScale) -> LinearScale {
+    match current {
+        Some(scale) => LinearScale::new(
+            scale.domain_min.min(other.domain_min),
+            scale.domain_max.max(other.domain_max),
+        ),
+        None => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_interactivity::ViewBounds;
+
+    #[test]
+    fn test_link_shares_single_scale() {
+        let link = AxisLink::both();
+        let other = link.clone();
+        assert!(link.same_group(&other));
+        assert!(!link.same_group(&AxisLink::both()));
+
+        link.set_x_range(0.0, 10.0);
+        other.set_view_bounds(2.0, 4.0, -1.0, 1.0);
+
+        assert_eq!(link.x_scale(), Some(LinearScale::new(2.0, 4.0)));
+        assert_eq!(link.y_scale(), Some(LinearScale::new(-1.0, 1.0)));
+        assert_eq!(link.version(), 2);
+    }
+
+    #[test]
+    fn test_link_x_only_ignores_y() {
+        let link = AxisLink::x();
+        link.set_y_range(0.0, 5.0);
+        link.include_data(&[1.0, 3.0], &[10.0, 20.0]);
+        link.include_data(&[-2.0, 2.0], &[]);
+
+        let expected = LinearScale::new(
+            LinearScale::from_data(&[-2.0, 2.0]).domain_min,
+            LinearScale::from_data(&[1.0, 3.0]).domain_max,
+        );
+        assert_eq!(link.x_scale(), Some(expected));
+        assert_eq!(link.y_scale(), None);
+    }
+
+    #[test]
+    fn test_link_mirrors_viewports() {
+        let link = AxisLink::x();
+        let mut left = Viewport::new(100, 100, ViewBounds::new(0.0, 10.0, 0.0, 1.0));
+        let mut right = Viewport::new(100, 100, ViewBounds::new(0.0, 10.0, -5.0, 5.0));
+
+        // 在一个视口中平移后，另一个视口只同步联动的 X 范围
+        left.fit_bounds(ViewBounds::new(2.0, 6.0, 0.5, 1.5));
+        link.sync_from_viewport(&left);
+        link.apply_to_viewport(&mut right);
+
+        assert_eq!(right.bounds(), &ViewBounds::new(2.0, 6.0, -5.0, 5.0));
+        assert_eq!(link.y_scale(), None);
+    }
+}
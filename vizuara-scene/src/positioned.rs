@@ -0,0 +1,153 @@
+//! 按坐标空间定位的图元
+//!
+//! 手动添加的图元与标注可以用像素、绘图区域归一化坐标或数据坐标定位，
+//! 场景在生成图元时按当前绘图区域与比例尺换算为像素，
+//! 因此窗口尺寸或坐标轴范围改变后位置随之更新
+
+use nalgebra::Point2;
+use serde::{Deserialize, Serialize};
+use vizuara_core::{LinearScale, Primitive, Scale};
+use vizuara_plots::PlotArea;
+
+/// 坐标空间
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CoordSpace {
+    /// 像素坐标，原样使用
+    #[default]
+    Pixel,
+    /// 绘图区域归一化坐标：(0, 0) 为左下角，(1, 1) 为右上角
+    PlotArea,
+    /// 数据坐标，按坐标轴比例尺换算
+    Data,
+}
+
+impl CoordSpace {
+    /// 将该空间中的点换算为像素坐标
+    pub fn to_pixel(
+        self,
+        point: Point2<f32>,
+        plot_area: PlotArea,
+        x_scale: &LinearScale,
+        y_scale: &LinearScale,
+    ) -> Point2<f32> {
+        let (u, v) = match self {
+            CoordSpace::Pixel => return point,
+            CoordSpace::PlotArea => (point.x, point.y),
+            CoordSpace::Data => (x_scale.normalize(point.x), y_scale.normalize(point.y)),
+        };
+        Point2::new(
+            plot_area.x + u * plot_area.width,
+            plot_area.y + plot_area.height - v * plot_area.height,
+        )
+    }
+
+    /// 与该空间等价的 X、Y 比例尺：按数据坐标定位的标注经它们换算后落在该空间指定的位置
+    pub fn scales(
+        self,
+        plot_area: PlotArea,
+        x_scale: &LinearScale,
+        y_scale: &LinearScale,
+    ) -> (LinearScale, LinearScale) {
+        match self {
+            // Y 轴向下：定义域下限对应绘图区域底边
+            CoordSpace::Pixel => (
+                LinearScale::new(plot_area.x, plot_area.x + plot_area.width),
+                LinearScale::new(plot_area.y + plot_area.height, plot_area.y),
+            ),
+            CoordSpace::PlotArea => (LinearScale::new(0.0, 1.0), LinearScale::new(0.0, 1.0)),
+            CoordSpace::Data => (x_scale.clone(), y_scale.clone()),
+        }
+    }
+}
+
+/// 按坐标空间定位的图元；只换算位置，半径、线宽、字号等尺寸始终为像素
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Positioned {
+    pub primitive: Primitive,
+    pub space: CoordSpace,
+}
+
+impl Positioned {
+    /// 创建定位图元
+    pub fn new(primitive: Primitive, space: CoordSpace) -> Self {
+        Self { primitive, space }
+    }
+
+    /// 以绘图区域归一化坐标定位，如 (1, 1) 处的右上角标签
+    pub fn plot_area(primitive: Primitive) -> Self {
+        Self::new(primitive, CoordSpace::PlotArea)
+    }
+
+    /// 以数据坐标定位，随坐标轴缩放、平移
+    pub fn data(primitive: Primitive) -> Self {
+        Self::new(primitive, CoordSpace::Data)
+    }
+
+    /// 换算为像素坐标的图元
+    pub fn resolve(
+        &self,
+        plot_area: PlotArea,
+        x_scale: &LinearScale,
+        y_scale: &LinearScale,
+    ) -> Primitive {
+        self.primitive
+            .map_points(|p| self.space.to_pixel(p, plot_area, x_scale, y_scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_core::Color;
+
+    #[test]
+    fn test_resolve_coordinate_spaces() {
+        let area = PlotArea::new(100.0, 50.0, 200.0, 100.0);
+        let scale = LinearScale::new(0.0, 10.0);
+        let marker = Primitive::Circle {
+            center: Point2::new(1.0, 1.0),
+            radius: 4.0,
+        };
+
+        let resolve = |space| Positioned::new(marker.clone(), space).resolve(area, &scale, &scale);
+        assert_eq!(resolve(CoordSpace::Pixel), marker);
+        assert_eq!(
+            resolve(CoordSpace::PlotArea),
+            Primitive::Circle {
+                center: Point2::new(300.0, 50.0),
+                radius: 4.0,
+            }
+        );
+        assert_eq!(
+            resolve(CoordSpace::Data),
+            Primitive::Circle {
+                center: Point2::new(120.0, 140.0),
+                radius: 4.0,
+            }
+        );
+
+        // Y 轴翻转后矩形仍为 (最小角, 最大角)
+        let band = Positioned::plot_area(Primitive::RectangleStyled {
+            min: Point2::new(0.0, 0.0),
+            max: Point2::new(1.0, 0.5),
+            fill: Color::rgba(0.0, 0.0, 1.0, 0.2),
+            stroke: None,
+        });
+        match band.resolve(area, &scale, &scale) {
+            Primitive::RectangleStyled { min, max, .. } => {
+                assert_eq!(min, Point2::new(100.0, 100.0));
+                assert_eq!(max, Point2::new(300.0, 150.0));
+            }
+            other => panic!("expected rectangle, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_pixel_scales_are_identity() {
+        let area = PlotArea::new(100.0, 50.0, 200.0, 100.0);
+        let unit = LinearScale::new(0.0, 1.0);
+        let (x, y) = CoordSpace::Pixel.scales(area, &unit, &unit);
+        let p = Point2::new(130.0, 70.0);
+        assert_eq!(CoordSpace::Data.to_pixel(p, area, &x, &y), p);
+    }
+}
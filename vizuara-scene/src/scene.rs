@@ -1,10 +1,18 @@
+use crate::{
+    Annotation, AxisLink, AxisSpec, CoordSpace, LayeredPrimitives, PlotSpec, Positioned,
+    PrimitiveLayer, SceneSpec, SharedAxis, SizeLegend,
+};
 use nalgebra::Point2;
+use serde::{Deserialize, Serialize};
 use vizuara_components::{Axis, AxisDirection};
-use vizuara_core::{LinearScale, Primitive};
+use vizuara_core::{
+    Color, GridStyle, LinearScale, LogScale, Primitive, Result, Scale, Style, TimeScale,
+    VizuaraError,
+};
 use vizuara_plots::{
-    AreaChart, BarPlot, BoxPlot, ContourPlot, DensityPlot, Heatmap, Histogram, LinePlot,
-    ParallelCoordinates, PieChart, PlotArea, RadarChart, SankeyDiagram, ScatterPlot, Treemap,
-    ViolinPlot,
+    AreaChart, BarPlot, BoxPlot, ContourPlot, DensityPlot, GaugePlot, Heatmap, Hexbin, Histogram,
+    LinePlot, MultiLinePlot, ParallelCoordinates, PieChart, PlotArea, RadarChart, SankeyDiagram,
+    ScaledPlot, ScatterPlot, StemPlot, StepPlot, Treemap, ViolinPlot,
 };
 
 /// 场景：包含坐标轴和多个图表的绘图区域
@@ -12,41 +20,160 @@ pub struct Scene {
     plot_area: PlotArea,
     x_axis: Option<Axis>,
     y_axis: Option<Axis>,
+    secondary_y_axis: Option<Axis>,
     plots: Vec<Box<dyn PlotRenderer>>,
+    secondary_plots: Vec<Box<dyn SecondaryAxisPlot>>,
+    link: Option<AxisLink>,
+    linked_plots: Vec<Box<dyn LinkedPlot>>,
+    legend: Vec<LegendEntry>,
+    size_legend: Option<SizeLegend>,
+    annotations: Vec<Annotation>,
+    placed_annotations: Vec<(Annotation, CoordSpace)>,
+    positioned: Vec<Positioned>,
     title: Option<String>,
+    show_grid: bool,
+    grid_style: GridStyle,
+    zebra_bands: Option<(Color, Color)>,
+    equal_aspect: bool,
+}
+
+/// 系列所使用的 Y 轴
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum YAxisSide {
+    Left,
+    Right,
+}
+
+/// 图例条目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LegendEntry {
+    pub label: String,
+    pub color: Color,
+    pub side: YAxisSide,
 }
 
 /// 图表渲染器 trait
 pub trait PlotRenderer {
     fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive>;
-}
 
-// 为 ScatterPlot 实现 PlotRenderer
-impl PlotRenderer for ScatterPlot {
-    fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+    /// 按场景坐标轴的比例尺（含对数轴、时间轴）生成图元，使数据与刻度、网格线共用同一映射；
+    /// 自行布局（不依赖坐标轴）的图表忽略坐标轴，按自身方式绘制
+    fn generate_on_axes(
+        &self,
+        _x: Option<&dyn Scale>,
+        _y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
         self.generate_primitives(plot_area)
     }
+
+    /// 可序列化的图表配置；不支持序列化的图表返回 None
+    fn spec(&self) -> Option<PlotSpec> {
+        None
+    }
+
+    /// 绘制本图表中不自带颜色的图元（点、点集、折线）时使用的样式
+    fn primitive_style(&self) -> Style {
+        Style::default()
+    }
+
+    /// 数据范围 (x_min, x_max, y_min, y_max)；没有数据或不适用时返回 None
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        None
+    }
+
+    /// X 值位于 [x_min, x_max] 内的数据的 Y 范围；默认取整体数据范围
+    fn y_range_within(&self, _x_min: f32, _x_max: f32) -> Option<(f32, f32)> {
+        self.data_bounds()
+            .map(|(_, _, y_min, y_max)| (y_min, y_max))
+    }
 }
 
-// 为 LinePlot 实现 PlotRenderer
-impl PlotRenderer for LinePlot {
-    fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
-        self.generate_primitives(plot_area)
+/// 图表的可序列化配置；绘制在坐标轴上的图表（[`ScaledPlot`]）实现它即获得 [`PlotRenderer`]
+pub trait PlotSpecSource {
+    fn spec(&self) -> Option<PlotSpec> {
+        None
     }
 }
 
-// 为 BarPlot 实现 PlotRenderer
-impl PlotRenderer for BarPlot {
+impl<T: ScaledPlot + PlotSpecSource> PlotRenderer for T {
     fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
-        self.generate_primitives(plot_area)
+        self.generate_with_scales(None, None, plot_area)
+    }
+
+    fn generate_on_axes(
+        &self,
+        x: Option<&dyn Scale>,
+        y: Option<&dyn Scale>,
+        plot_area: PlotArea,
+    ) -> Vec<Primitive> {
+        self.generate_with_scales(x, y, plot_area)
+    }
+
+    fn spec(&self) -> Option<PlotSpec> {
+        PlotSpecSource::spec(self)
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        ScaledPlot::data_bounds(self)
+    }
+
+    fn y_range_within(&self, x_min: f32, x_max: f32) -> Option<(f32, f32)> {
+        ScaledPlot::y_range_within(self, x_min, x_max)
+    }
+
+    fn primitive_style(&self) -> Style {
+        ScaledPlot::primitive_style(self)
+    }
+}
+
+impl PlotSpecSource for ScatterPlot {
+    fn spec(&self) -> Option<PlotSpec> {
+        Some(PlotSpec::Scatter(self.clone()))
+    }
+}
+
+impl PlotSpecSource for LinePlot {
+    fn spec(&self) -> Option<PlotSpec> {
+        Some(PlotSpec::Line(self.clone()))
+    }
+}
+
+impl PlotSpecSource for BarPlot {
+    fn spec(&self) -> Option<PlotSpec> {
+        Some(PlotSpec::Bar(self.clone()))
     }
 }
 
+impl PlotSpecSource for MultiLinePlot {}
+impl PlotSpecSource for AreaChart {}
+impl PlotSpecSource for Hexbin {}
+impl PlotSpecSource for StemPlot {}
+impl PlotSpecSource for StepPlot {}
+
+/// 可绘制在右侧次 Y 轴上的图表：渲染时以次轴比例尺作为 Y 比例尺
+pub trait SecondaryAxisPlot: PlotRenderer {}
+
+impl<T: ScaledPlot + PlotSpecSource> SecondaryAxisPlot for T {}
+
+/// 可参与坐标轴联动的图表：数据并入联动组的共享范围，渲染时使用共享比例尺
+pub trait LinkedPlot: PlotRenderer {}
+
+impl<T: ScaledPlot + PlotSpecSource> LinkedPlot for T {}
+
 // 为 Histogram 实现 PlotRenderer
 impl PlotRenderer for Histogram {
     fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
         self.generate_primitives(plot_area)
     }
+
+    fn spec(&self) -> Option<PlotSpec> {
+        Some(PlotSpec::Histogram(self.clone()))
+    }
+
+    fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        self.data_bounds()
+    }
 }
 
 // 为 Heatmap 实现 PlotRenderer
@@ -63,13 +190,6 @@ impl PlotRenderer for BoxPlot {
     }
 }
 
-// 为 AreaChart 实现 PlotRenderer
-impl PlotRenderer for AreaChart {
-    fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
-        self.generate_primitives(plot_area)
-    }
-}
-
 // 为 ViolinPlot 实现 PlotRenderer
 impl PlotRenderer for ViolinPlot {
     fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
@@ -130,6 +250,13 @@ impl PlotRenderer for ParallelCoordinates {
     }
 }
 
+// 为 GaugePlot 实现 PlotRenderer
+impl PlotRenderer for GaugePlot {
+    fn generate_primitives(&self, plot_area: PlotArea) -> Vec<Primitive> {
+        self.generate_primitives(plot_area)
+    }
+}
+
 impl Scene {
     /// 创建新的场景
     pub fn new(plot_area: PlotArea) -> Self {
@@ -137,44 +264,182 @@ impl Scene {
             plot_area,
             x_axis: None,
             y_axis: None,
+            secondary_y_axis: None,
             plots: Vec::new(),
+            secondary_plots: Vec::new(),
+            link: None,
+            linked_plots: Vec::new(),
+            legend: Vec::new(),
+            size_legend: None,
+            annotations: Vec::new(),
+            placed_annotations: Vec::new(),
+            positioned: Vec::new(),
             title: None,
+            show_grid: false,
+            grid_style: GridStyle::default(),
+            zebra_bands: None,
+            equal_aspect: false,
         }
     }
 
     /// 添加 X 轴
     pub fn add_x_axis(mut self, scale: LinearScale, title: Option<String>) -> Self {
-        let axis_y = self.plot_area.y + self.plot_area.height + 20.0; // 轴在绘图区域下方
-        let mut axis = Axis::new(
-            AxisDirection::Horizontal,
-            scale,
-            (self.plot_area.x, axis_y),
-            self.plot_area.width,
-        );
+        self.x_axis = Some(self.build_x_axis(scale, title));
+        self.sync_link();
+        self
+    }
 
-        if let Some(title) = title {
-            axis = axis.title(title);
+    /// 添加 Y 轴
+    pub fn add_y_axis(mut self, scale: LinearScale, title: Option<String>) -> Self {
+        self.y_axis = Some(self.build_y_axis(scale, title));
+        self.sync_link();
+        self
+    }
+
+    /// 添加对数 X 轴；绘制在坐标轴上的图表直接使用原始数据，由场景按对数比例尺映射
+    pub fn add_log_x_axis(mut self, scale: LogScale, title: Option<String>) -> Self {
+        let axis = self.build_x_axis(LinearScale::new(0.0, 1.0), title);
+        self.x_axis = Some(axis.log_scale(scale));
+        self.sync_link();
+        self
+    }
+
+    /// 添加对数 Y 轴；绘制在坐标轴上的图表直接使用原始数据，由场景按对数比例尺映射
+    pub fn add_log_y_axis(mut self, scale: LogScale, title: Option<String>) -> Self {
+        let axis = self.build_y_axis(LinearScale::new(0.0, 1.0), title);
+        self.y_axis = Some(axis.log_scale(scale));
+        self.sync_link();
+        self
+    }
+
+    /// 添加时间 X 轴；图表的 X 数据为相对 `scale.origin` 的毫秒偏移（如 `LinePlot::time_data`），
+    /// 应与图表使用同一 origin
+    pub fn add_time_x_axis(mut self, scale: TimeScale, title: Option<String>) -> Self {
+        let axis = self.build_x_axis(scale.linear(), title);
+        self.x_axis = Some(axis.time_scale(scale));
+        self.sync_link();
+        self
+    }
+
+    fn build_x_axis(&self, scale: LinearScale, title: Option<String>) -> Axis {
+        let (position, length) = x_axis_placement(self.plot_area);
+        let axis = Axis::new(AxisDirection::Horizontal, scale, position, length);
+
+        match title {
+            Some(title) => axis.title(title),
+            None => axis,
+        }
+    }
+
+    fn build_y_axis(&self, scale: LinearScale, title: Option<String>) -> Axis {
+        let (position, length) = y_axis_placement(self.plot_area);
+        let axis = Axis::new(AxisDirection::Vertical, scale, position, length);
+
+        match title {
+            Some(title) => axis.title(title),
+            None => axis,
         }
+    }
 
-        self.x_axis = Some(axis);
+    /// 是否在坐标轴刻度处绘制网格线
+    pub fn grid(mut self, show: bool) -> Self {
+        self.show_grid = show;
         self
     }
 
-    /// 添加 Y 轴
-    pub fn add_y_axis(mut self, scale: LinearScale, title: Option<String>) -> Self {
-        let axis_x = self.plot_area.x - 20.0; // 轴在绘图区域左侧
-        let mut axis = Axis::new(
-            AxisDirection::Vertical,
-            scale,
-            (axis_x, self.plot_area.y),
-            self.plot_area.height,
+    /// 设置网格线样式（主/次网格线的颜色、宽度与显隐）并开启网格
+    pub fn grid_style(mut self, style: GridStyle) -> Self {
+        self.grid_style = style;
+        self.show_grid = true;
+        self
+    }
+
+    /// 在 Y 轴相邻主刻度之间交替填充两种颜色的横向背景条带（斑马纹），
+    /// 条带横跨绘图区域宽度、边界与主刻度对齐，绘制在网格线与数据之下
+    pub fn zebra_bands(mut self, first: Color, second: Color) -> Self {
+        self.zebra_bands = Some((first, second));
+        self
+    }
+
+    /// 等比例坐标（类似 Matlab 的 `axis equal`）：X、Y 每像素对应相同的数据单位，
+    /// 绘图区域按数据范围的宽高比缩小并居中（留白而非裁剪），圆形不会被拉伸
+    pub fn equal_aspect(mut self, equal: bool) -> Self {
+        self.equal_aspect = equal;
+        self
+    }
+
+    /// 实际用于绘制的区域：开启等比例坐标时为按数据宽高比缩小并居中的区域
+    pub fn effective_plot_area(&self) -> PlotArea {
+        let area = self.plot_area;
+        if !self.equal_aspect {
+            return area;
+        }
+
+        let span = |scale: Option<LinearScale>| scale.map(|s| (s.domain_max - s.domain_min).abs());
+        let x_span = span(
+            self.linked_x_scale()
+                .or_else(|| self.x_axis.as_ref().map(|a| a.scale().clone())),
         );
+        let y_span = span(
+            self.linked_y_scale()
+                .or_else(|| self.y_axis.as_ref().map(|a| a.scale().clone())),
+        );
+        let (x_span, y_span) = match (x_span, y_span) {
+            (Some(x), Some(y)) if x > 0.0 && y > 0.0 && x.is_finite() && y.is_finite() => (x, y),
+            _ => return area,
+        };
+
+        // 取两轴中较大的"每像素数据单位"，另一轴相应缩短
+        let units_per_pixel = (x_span / area.width).max(y_span / area.height);
+        let width = x_span / units_per_pixel;
+        let height = y_span / units_per_pixel;
+        PlotArea::new(
+            area.x + (area.width - width) / 2.0,
+            area.y + (area.height - height) / 2.0,
+            width,
+            height,
+        )
+    }
+
+    /// 添加右侧次 Y 轴，次轴上的图表按该比例尺转换 Y 值
+    pub fn add_secondary_y_axis(mut self, scale: LinearScale, title: Option<String>) -> Self {
+        let (position, length) = secondary_y_axis_placement(self.plot_area);
+        let mut axis =
+            Axis::new(AxisDirection::Vertical, scale, position, length).opposite_side(true);
 
         if let Some(title) = title {
             axis = axis.title(title);
         }
 
-        self.y_axis = Some(axis);
+        self.secondary_y_axis = Some(axis);
+        self
+    }
+
+    /// 调整次轴范围，使其零点与主轴零点位于同一高度（网格线对齐）
+    pub fn align_secondary_zero(mut self) -> Self {
+        let primary = match self.y_axis {
+            Some(ref axis) => axis.scale().clone(),
+            None => return self,
+        };
+        let target = primary.normalize(0.0);
+        if !(target > 0.0 && target < 1.0) {
+            return self;
+        }
+
+        if let Some(ref mut axis) = self.secondary_y_axis {
+            let mut scale = axis.scale().clone();
+            let current = scale.normalize(0.0);
+            if current < target && scale.domain_max > 0.0 {
+                // 向下扩展最小值
+                let domain_max = scale.domain_max;
+                scale = scale.with_domain(-target * domain_max / (1.0 - target), domain_max);
+            } else if current > target && scale.domain_min < 0.0 {
+                // 向上扩展最大值
+                let domain_min = scale.domain_min;
+                scale = scale.with_domain(domain_min, domain_min * (target - 1.0) / target);
+            }
+            axis.set_scale(scale);
+        }
         self
     }
 
@@ -190,6 +455,12 @@ impl Scene {
         self
     }
 
+    /// 添加多系列折线图
+    pub fn add_multi_line_plot(mut self, plot: MultiLinePlot) -> Self {
+        self.plots.push(Box::new(plot));
+        self
+    }
+
     /// 添加柱状图
     pub fn add_bar_plot(mut self, plot: BarPlot) -> Self {
         self.plots.push(Box::new(plot));
@@ -244,6 +515,12 @@ impl Scene {
         self
     }
 
+    /// 添加六边形分箱图
+    pub fn add_hexbin(mut self, plot: Hexbin) -> Self {
+        self.plots.push(Box::new(plot));
+        self
+    }
+
     /// 添加等高线图
     pub fn add_contour_plot(mut self, plot: ContourPlot) -> Self {
         self.plots.push(Box::new(plot));
@@ -268,61 +545,662 @@ impl Scene {
         self
     }
 
+    /// 添加茎叶图
+    pub fn add_stem_plot(mut self, plot: StemPlot) -> Self {
+        self.plots.push(Box::new(plot));
+        self
+    }
+
+    /// 添加阶梯图
+    pub fn add_step_plot(mut self, plot: StepPlot) -> Self {
+        self.plots.push(Box::new(plot));
+        self
+    }
+
+    /// 添加仪表盘
+    pub fn add_gauge(mut self, plot: GaugePlot) -> Self {
+        self.plots.push(Box::new(plot));
+        self
+    }
+
+    /// 添加绘制在次 Y 轴上的折线图
+    pub fn add_secondary_line_plot(mut self, plot: LinePlot) -> Self {
+        self.secondary_plots.push(Box::new(plot));
+        self
+    }
+
+    /// 添加绘制在次 Y 轴上的散点图
+    pub fn add_secondary_scatter_plot(mut self, plot: ScatterPlot) -> Self {
+        self.secondary_plots.push(Box::new(plot));
+        self
+    }
+
+    /// 添加绘制在次 Y 轴上的柱状图
+    pub fn add_secondary_bar_plot(mut self, plot: BarPlot) -> Self {
+        self.secondary_plots.push(Box::new(plot));
+        self
+    }
+
+    /// 添加绘制在次 Y 轴上的面积图
+    pub fn add_secondary_area_chart(mut self, plot: AreaChart) -> Self {
+        self.secondary_plots.push(Box::new(plot));
+        self
+    }
+
+    /// 加入坐标轴联动组，已有坐标轴的范围并入组内共享的比例尺
+    pub fn link_axes(mut self, link: AxisLink) -> Self {
+        self.link = Some(link);
+        self.sync_link();
+        self
+    }
+
+    /// 添加参与联动的图表：数据范围并入联动组的共享范围，渲染时使用共享比例尺
+    pub fn add_linked_plot<P: LinkedPlot + 'static>(mut self, plot: P) -> Self {
+        self.linked_plots.push(Box::new(plot));
+        self.sync_link();
+        self
+    }
+
+    /// 所属的坐标轴联动组
+    pub fn link(&self) -> Option<&AxisLink> {
+        self.link.as_ref()
+    }
+
+    /// 当前生效的 X 比例尺：联动组已确定范围时为共享比例尺，否则为 X 轴比例尺
+    pub fn x_scale(&self) -> Option<LinearScale> {
+        self.current_x_axis().map(|axis| axis.scale().clone())
+    }
+
+    /// 当前生效的 Y 比例尺：联动组已确定范围时为共享比例尺，否则为 Y 轴比例尺
+    pub fn y_scale(&self) -> Option<LinearScale> {
+        self.current_y_axis().map(|axis| axis.scale().clone())
+    }
+
+    /// 主 Y 轴上所有图表（含联动图表）数据范围的并集 (x_min, x_max, y_min, y_max)，
+    /// 可用于为坐标轴设置包含全部数据的范围；不提供数据范围的图表不计入
+    pub fn data_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        self.plots
+            .iter()
+            .filter_map(|plot| plot.data_bounds())
+            .chain(
+                self.linked_plots
+                    .iter()
+                    .filter_map(|plot| plot.data_bounds()),
+            )
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1), a.2.min(b.2), a.3.max(b.3)))
+    }
+
+    /// 将坐标轴范围与联动图表的数据范围并入联动组的共享范围
+    fn sync_link(&self) {
+        let Some(ref link) = self.link else {
+            return;
+        };
+        link.include_scales(
+            self.x_axis.as_ref().map(|axis| axis.scale()),
+            self.y_axis.as_ref().map(|axis| axis.scale()),
+        );
+        for (x_min, x_max, y_min, y_max) in self
+            .linked_plots
+            .iter()
+            .filter_map(|plot| plot.data_bounds())
+        {
+            link.include_data(&[x_min, x_max], &[y_min, y_max]);
+        }
+    }
+
+    /// 当前 X 轴在绘图区域上的坐标变换（对数轴使用对数比例尺）
+    pub fn shared_x_axis(&self) -> Option<SharedAxis> {
+        let area = self.effective_plot_area();
+        self.current_x_axis()
+            .map(|axis| x_axis_transform(&axis, area))
+    }
+
+    /// 当前主 Y 轴在绘图区域上的坐标变换；定义域下限位于绘图区域底部
+    pub fn shared_y_axis(&self) -> Option<SharedAxis> {
+        let area = self.effective_plot_area();
+        self.current_y_axis()
+            .map(|axis| y_axis_transform(&axis, area))
+    }
+
+    fn linked_x_scale(&self) -> Option<LinearScale> {
+        self.link.as_ref().and_then(|link| link.x_scale())
+    }
+
+    /// 联动组共享的 Y 比例尺；仅联动 X 轴时按联动图表在共享 X 范围内的数据自动适配
+    fn linked_y_scale(&self) -> Option<LinearScale> {
+        let link = self.link.as_ref()?;
+        if link.axes().links_y() {
+            return link.y_scale();
+        }
+
+        let x = link.x_scale()?;
+        let (x_min, x_max) = (
+            x.domain_min.min(x.domain_max),
+            x.domain_min.max(x.domain_max),
+        );
+        let (y_min, y_max) = self
+            .linked_plots
+            .iter()
+            .filter_map(|plot| plot.y_range_within(x_min, x_max))
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))?;
+        Some(if y_min < y_max {
+            LinearScale::from_data(&[y_min, y_max])
+        } else {
+            // 可见数据只有一个 Y 值时以它为中心留出单位范围
+            LinearScale::new(y_min - 0.5, y_max + 0.5)
+        })
+    }
+
+    fn current_x_axis(&self) -> Option<Axis> {
+        let mut axis = self.x_axis.clone()?;
+        if let Some(scale) = self.linked_x_scale() {
+            axis.set_scale(scale);
+        }
+        if self.equal_aspect {
+            let (position, length) = x_axis_placement(self.effective_plot_area());
+            axis.set_placement(position, length);
+        }
+        Some(axis)
+    }
+
+    fn current_y_axis(&self) -> Option<Axis> {
+        let mut axis = self.y_axis.clone()?;
+        if let Some(scale) = self.linked_y_scale() {
+            axis.set_scale(scale);
+        }
+        if self.equal_aspect {
+            let (position, length) = y_axis_placement(self.effective_plot_area());
+            axis.set_placement(position, length);
+        }
+        Some(axis)
+    }
+
+    fn current_secondary_y_axis(&self) -> Option<Axis> {
+        let mut axis = self.secondary_y_axis.clone()?;
+        if self.equal_aspect {
+            let (position, length) = secondary_y_axis_placement(self.effective_plot_area());
+            axis.set_placement(position, length);
+        }
+        Some(axis)
+    }
+
+    /// 添加图例条目，并标明该系列使用的 Y 轴
+    pub fn legend_entry<S: Into<String>>(
+        mut self,
+        label: S,
+        color: Color,
+        side: YAxisSide,
+    ) -> Self {
+        self.legend.push(LegendEntry {
+            label: label.into(),
+            color,
+            side,
+        });
+        self
+    }
+
+    /// 设置点大小图例，显示在绘图区域右下角内侧
+    pub fn size_legend(mut self, legend: SizeLegend) -> Self {
+        self.size_legend = Some(legend);
+        self
+    }
+
+    /// 图例显示文本；存在次 Y 轴时在系列名后注明所用坐标轴
+    pub fn legend_labels(&self) -> Vec<String> {
+        self.legend
+            .iter()
+            .map(
+                |entry| match (self.secondary_y_axis.is_some(), entry.side) {
+                    (false, _) => entry.label.clone(),
+                    (true, YAxisSide::Left) => format!("{} (左轴)", entry.label),
+                    (true, YAxisSide::Right) => format!("{} (右轴)", entry.label),
+                },
+            )
+            .collect()
+    }
+
+    /// 添加标注（数据坐标，按坐标轴比例尺转换）
+    pub fn annotate(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// 添加标注，坐标按 `space` 解释；数据坐标时等同于 [`annotate`](Self::annotate)
+    pub fn annotate_in(mut self, annotation: Annotation, space: CoordSpace) -> Self {
+        match space {
+            CoordSpace::Data => self.annotations.push(annotation),
+            _ => self.placed_annotations.push((annotation, space)),
+        }
+        self
+    }
+
+    /// 添加按坐标空间定位的图元，绘制在标注层，生成图元时换算为像素坐标
+    pub fn place(mut self, positioned: Positioned) -> Self {
+        self.positioned.push(positioned);
+        self
+    }
+
     /// 设置标题
     pub fn title<S: Into<String>>(mut self, title: S) -> Self {
         self.title = Some(title.into());
         self
     }
 
-    /// 生成所有渲染图元
+    /// 生成所有渲染图元，按 [`PrimitiveLayer`] 的顺序从下到上排列：
+    /// 网格线、数据、标注、坐标轴与标题、图例
     pub fn generate_primitives(&self) -> Vec<Primitive> {
-        let mut primitives = Vec::new();
+        self.layered_primitives().into_sorted()
+    }
+
+    /// 与 [`generate_primitives`](Self::generate_primitives) 相同，并给出每个图元的样式：
+    /// 图表的图元使用图表的 [`PlotRenderer::primitive_style`]，其余为 `Style::default()`
+    pub fn generate_styled_primitives(&self) -> (Vec<Primitive>, Vec<Style>) {
+        self.layered_primitives().into_sorted_styled()
+    }
+
+    /// 生成带层标记的图元（按生成顺序，未排序）
+    pub fn layered_primitives(&self) -> LayeredPrimitives {
+        let mut layers = LayeredPrimitives::new();
+        let plot_area = self.effective_plot_area();
 
         // 1. 绘制标题
         if let Some(ref title) = self.title {
-            primitives.push(Primitive::Text {
-                position: Point2::new(
-                    self.plot_area.x + self.plot_area.width / 2.0,
-                    self.plot_area.y - 40.0,
-                ),
-                content: title.clone(),
-                size: 16.0,
-                color: vizuara_core::Color::rgb(0.1, 0.1, 0.1),
-                h_align: vizuara_core::HorizontalAlign::Center,
-                v_align: vizuara_core::VerticalAlign::Bottom,
-            });
+            layers.push(
+                PrimitiveLayer::Axis,
+                Primitive::Text {
+                    position: Point2::new(plot_area.x + plot_area.width / 2.0, plot_area.y - 40.0),
+                    content: title.clone(),
+                    size: 16.0,
+                    color: vizuara_core::Color::rgb(0.1, 0.1, 0.1),
+                    h_align: vizuara_core::HorizontalAlign::Center,
+                    v_align: vizuara_core::VerticalAlign::Bottom,
+                    rotation: 0.0,
+                },
+            );
+        }
+
+        // 2. 绘制坐标轴（联动时使用共享比例尺）
+        let x_axis = self.current_x_axis();
+        let y_axis = self.current_y_axis();
+
+        if let Some(ref x_axis) = x_axis {
+            layers.extend(PrimitiveLayer::Axis, x_axis.generate_primitives());
         }
 
-        // 2. 绘制坐标轴
-        if let Some(ref x_axis) = self.x_axis {
-            primitives.extend(x_axis.generate_primitives());
+        if let Some(ref y_axis) = y_axis {
+            layers.extend(PrimitiveLayer::Axis, y_axis.generate_primitives());
         }
 
-        if let Some(ref y_axis) = self.y_axis {
-            primitives.extend(y_axis.generate_primitives());
+        let secondary_y_axis = self.current_secondary_y_axis();
+        if let Some(ref secondary_y_axis) = secondary_y_axis {
+            layers.extend(PrimitiveLayer::Axis, secondary_y_axis.generate_primitives());
         }
 
-        // 3. 绘制绘图区域边框
-        primitives.push(Primitive::Rectangle {
-            min: Point2::new(self.plot_area.x, self.plot_area.y),
-            max: Point2::new(
-                self.plot_area.x + self.plot_area.width,
-                self.plot_area.y + self.plot_area.height,
-            ),
-        });
+        // 3. 绘制绘图区域边框：渲染器会填充普通矩形，放在背景层以免覆盖数据
+        layers.push(
+            PrimitiveLayer::Background,
+            Primitive::Rectangle {
+                min: Point2::new(plot_area.x, plot_area.y),
+                max: Point2::new(
+                    plot_area.x + plot_area.width,
+                    plot_area.y + plot_area.height,
+                ),
+            },
+        );
+
+        if let Some(colors) = self.zebra_bands {
+            layers.extend(
+                PrimitiveLayer::Background,
+                self.zebra_band_primitives(y_axis.as_ref(), colors),
+            );
+        }
+
+        if self.show_grid {
+            layers.extend(
+                PrimitiveLayer::Grid,
+                self.grid_primitives(x_axis.as_ref(), y_axis.as_ref()),
+            );
+        }
+
+        // 4. 绘制所有图表：依赖坐标轴的图表使用坐标轴的比例尺（联动时为共享比例尺），
+        //    与刻度和网格线对齐；没有坐标轴时使用联动组的比例尺或图表自身的比例尺
+        let shared_x = x_axis
+            .as_ref()
+            .map(|axis| x_axis_transform(axis, plot_area));
+        let shared_y = y_axis
+            .as_ref()
+            .map(|axis| y_axis_transform(axis, plot_area));
+        let (linked_x, linked_y) = (self.linked_x_scale(), self.linked_y_scale());
+        let x_scale = shared_x
+            .as_ref()
+            .map(SharedAxis::scale)
+            .or(linked_x.as_ref().map(|scale| scale as &dyn Scale));
+        let y_scale = shared_y
+            .as_ref()
+            .map(SharedAxis::scale)
+            .or(linked_y.as_ref().map(|scale| scale as &dyn Scale));
 
-        // 4. 绘制所有图表
         for plot in &self.plots {
-            primitives.extend(plot.generate_primitives(self.plot_area));
+            layers.extend_styled(
+                PrimitiveLayer::Data,
+                plot.generate_on_axes(x_scale, y_scale, plot_area),
+                plot.primitive_style(),
+            );
+        }
+
+        // 次轴图表使用次轴比例尺；未设置次轴时按自身的 Y 比例尺绘制
+        let secondary_y_scale = secondary_y_axis
+            .as_ref()
+            .map(|axis| axis.scale() as &dyn Scale);
+        for plot in &self.secondary_plots {
+            layers.extend_styled(
+                PrimitiveLayer::Data,
+                plot.generate_on_axes(x_scale, secondary_y_scale, plot_area),
+                plot.primitive_style(),
+            );
+        }
+
+        for plot in &self.linked_plots {
+            layers.extend_styled(
+                PrimitiveLayer::Data,
+                plot.generate_on_axes(x_scale, y_scale, plot_area),
+                plot.primitive_style(),
+            );
+        }
+
+        // 5. 绘制标注
+        layers.extend(
+            PrimitiveLayer::Annotation,
+            self.annotation_primitives(&self.annotations),
+        );
+        layers.extend(PrimitiveLayer::Annotation, self.positioned_primitives());
+
+        // 6. 绘制图例
+        layers.extend(PrimitiveLayer::Legend, self.legend_primitives());
+        if let Some(ref legend) = self.size_legend {
+            let (width, height) = legend.size();
+            let origin = Point2::new(
+                plot_area.x + plot_area.width - width - 10.0,
+                plot_area.y + plot_area.height - height - 10.0,
+            );
+            layers.extend(PrimitiveLayer::Legend, legend.generate_primitives(origin));
+        }
+
+        layers
+    }
+
+    /// 生成网格线图元：按网格样式在各坐标轴刻度处贯穿绘图区域
+    fn grid_primitives(&self, x_axis: Option<&Axis>, y_axis: Option<&Axis>) -> Vec<Primitive> {
+        let area = self.effective_plot_area();
+        let mut primitives = Vec::new();
+
+        if let Some(x_axis) = x_axis {
+            primitives
+                .extend(x_axis.grid_primitives(&self.grid_style, (area.y, area.y + area.height)));
+        }
+        if let Some(y_axis) = y_axis {
+            primitives
+                .extend(y_axis.grid_primitives(&self.grid_style, (area.x, area.x + area.width)));
         }
 
         primitives
     }
 
+    /// 生成斑马纹背景：相邻主刻度之间的条带从第一个刻度起交替使用两种颜色
+    fn zebra_band_primitives(
+        &self,
+        y_axis: Option<&Axis>,
+        (first, second): (Color, Color),
+    ) -> Vec<Primitive> {
+        let Some(y_axis) = y_axis else {
+            return Vec::new();
+        };
+        let area = self.effective_plot_area();
+
+        y_axis
+            .tick_positions()
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| Primitive::RectangleStyled {
+                min: Point2::new(area.x, pair[0].min(pair[1])),
+                max: Point2::new(area.x + area.width, pair[0].max(pair[1])),
+                fill: [first, second][i % 2],
+                stroke: None,
+            })
+            .collect()
+    }
+
+    /// 生成图例图元：位于绘图区域右上角内侧，每项为色块加文本
+    fn legend_primitives(&self) -> Vec<Primitive> {
+        let mut primitives = Vec::new();
+        let area = self.effective_plot_area();
+        let right = area.x + area.width - 10.0;
+        let swatch = 10.0;
+        let row_height = 18.0;
+
+        for (i, label) in self.legend_labels().into_iter().enumerate() {
+            let center_y = area.y + 10.0 + (i as f32 + 0.5) * row_height;
+            primitives.push(Primitive::RectangleStyled {
+                min: Point2::new(right - swatch, center_y - swatch / 2.0),
+                max: Point2::new(right, center_y + swatch / 2.0),
+                fill: self.legend[i].color,
+                stroke: None,
+            });
+            primitives.push(Primitive::Text {
+                position: Point2::new(right - swatch - 6.0, center_y),
+                content: label,
+                size: 12.0,
+                color: Color::rgb(0.1, 0.1, 0.1),
+                h_align: vizuara_core::HorizontalAlign::Right,
+                v_align: vizuara_core::VerticalAlign::Middle,
+                rotation: 0.0,
+            });
+        }
+
+        primitives
+    }
+
+    /// 将标注转换为图元；未设置坐标轴时使用 [0, 1] 的归一化坐标
+    pub fn annotation_primitives(&self, annotations: &[Annotation]) -> Vec<Primitive> {
+        let (x_scale, y_scale) = self.annotation_scales();
+
+        annotations
+            .iter()
+            .flat_map(|a| a.to_primitives(self.effective_plot_area(), &x_scale, &y_scale))
+            .collect()
+    }
+
+    /// 将按坐标空间定位的标注与图元换算为像素坐标的图元
+    fn positioned_primitives(&self) -> Vec<Primitive> {
+        let area = self.effective_plot_area();
+        let (x_scale, y_scale) = self.annotation_scales();
+
+        let annotations = self
+            .placed_annotations
+            .iter()
+            .flat_map(|(annotation, space)| {
+                let (x, y) = space.scales(area, &x_scale, &y_scale);
+                annotation.to_primitives(area, &x, &y)
+            });
+        let primitives = self
+            .positioned
+            .iter()
+            .map(|positioned| positioned.resolve(area, &x_scale, &y_scale));
+        annotations.chain(primitives).collect()
+    }
+
+    /// 数据坐标使用的比例尺（含联动缩放）；未设置坐标轴时为 [0, 1]
+    fn annotation_scales(&self) -> (LinearScale, LinearScale) {
+        let unit = LinearScale::new(0.0, 1.0);
+        (
+            self.x_scale().unwrap_or_else(|| unit.clone()),
+            self.y_scale().unwrap_or(unit),
+        )
+    }
+
     /// 获取绘图区域
     pub fn plot_area(&self) -> PlotArea {
         self.plot_area
     }
+
+    /// 替换绘图区域，已添加的坐标轴随之重新定位
+    pub fn set_plot_area(&mut self, plot_area: PlotArea) {
+        self.plot_area = plot_area;
+        if let Some(axis) = self.x_axis.as_mut() {
+            let (position, length) = x_axis_placement(plot_area);
+            axis.set_placement(position, length);
+        }
+        if let Some(axis) = self.y_axis.as_mut() {
+            let (position, length) = y_axis_placement(plot_area);
+            axis.set_placement(position, length);
+        }
+        if let Some(axis) = self.secondary_y_axis.as_mut() {
+            let (position, length) = secondary_y_axis_placement(plot_area);
+            axis.set_placement(position, length);
+        }
+    }
+
+    /// 导出场景配置；含坐标轴联动、点大小图例或暂不支持序列化的图表时返回错误
+    pub fn to_spec(&self) -> Result<SceneSpec> {
+        if self.link.is_some() || !self.linked_plots.is_empty() {
+            return Err(VizuaraError::UnsupportedFeature(
+                "坐标轴联动无法序列化".to_string(),
+            ));
+        }
+        if self.size_legend.is_some() {
+            return Err(VizuaraError::UnsupportedFeature(
+                "点大小图例无法序列化".to_string(),
+            ));
+        }
+
+        let unsupported =
+            || VizuaraError::UnsupportedFeature("场景包含暂不支持序列化的图表".to_string());
+        let plots = self
+            .plots
+            .iter()
+            .map(|plot| plot.spec().ok_or_else(unsupported))
+            .collect::<Result<Vec<_>>>()?;
+        let secondary_plots = self
+            .secondary_plots
+            .iter()
+            .map(|plot| plot.spec().ok_or_else(unsupported))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(SceneSpec {
+            plot_area: self.plot_area,
+            title: self.title.clone(),
+            x_axis: self.x_axis.as_ref().map(axis_spec),
+            y_axis: self.y_axis.as_ref().map(axis_spec),
+            secondary_y_axis: self.secondary_y_axis.as_ref().map(axis_spec),
+            plots,
+            secondary_plots,
+            legend: self.legend.clone(),
+            annotations: self.annotations.clone(),
+            placed_annotations: self.placed_annotations.clone(),
+            positioned: self.positioned.clone(),
+            show_grid: self.show_grid,
+            grid_style: self.grid_style,
+            equal_aspect: self.equal_aspect,
+            zebra_bands: self.zebra_bands,
+        })
+    }
+
+    /// 由场景配置重建场景，依次调用对应的构建方法
+    pub fn from_spec(spec: SceneSpec) -> Result<Self> {
+        let mut scene = Scene::new(spec.plot_area)
+            .grid_style(spec.grid_style)
+            .grid(spec.show_grid)
+            .equal_aspect(spec.equal_aspect);
+        scene.zebra_bands = spec.zebra_bands;
+
+        if let Some(axis) = spec.x_axis {
+            scene = match (axis.log_scale, axis.time_scale) {
+                (Some(log), _) => scene.add_log_x_axis(log, axis.title),
+                (None, Some(time)) => scene.add_time_x_axis(time, axis.title),
+                (None, None) => scene.add_x_axis(axis.scale, axis.title),
+            };
+        }
+        if let Some(axis) = spec.y_axis {
+            scene = match axis.log_scale {
+                Some(log) => scene.add_log_y_axis(log, axis.title),
+                None => scene.add_y_axis(axis.scale, axis.title),
+            };
+        }
+        if let Some(axis) = spec.secondary_y_axis {
+            scene = scene.add_secondary_y_axis(axis.scale, axis.title);
+        }
+
+        for plot in spec.plots {
+            scene = match plot {
+                PlotSpec::Scatter(plot) => scene.add_scatter_plot(plot),
+                PlotSpec::Line(plot) => scene.add_line_plot(plot),
+                PlotSpec::Bar(plot) => scene.add_bar_plot(plot),
+                PlotSpec::Histogram(plot) => scene.add_histogram(plot),
+            };
+        }
+        for plot in spec.secondary_plots {
+            scene = match plot {
+                PlotSpec::Scatter(plot) => scene.add_secondary_scatter_plot(plot),
+                PlotSpec::Line(plot) => scene.add_secondary_line_plot(plot),
+                PlotSpec::Bar(plot) => scene.add_secondary_bar_plot(plot),
+                PlotSpec::Histogram(_) => {
+                    return Err(VizuaraError::ConfigError(
+                        "直方图不能绘制在次 Y 轴上".to_string(),
+                    ))
+                }
+            };
+        }
+
+        scene.legend = spec.legend;
+        scene.annotations = spec.annotations;
+        scene.placed_annotations = spec.placed_annotations;
+        scene.positioned = spec.positioned;
+        scene.title = spec.title;
+        Ok(scene)
+    }
+}
+
+/// 坐标轴的可序列化配置
+fn axis_spec(axis: &Axis) -> AxisSpec {
+    AxisSpec {
+        scale: axis.scale().clone(),
+        log_scale: axis.get_log_scale().cloned(),
+        time_scale: axis.get_time_scale().cloned(),
+        title: axis.get_title().map(str::to_string),
+    }
+}
+
+/// X 轴位置与长度：位于绘图区域下方
+fn x_axis_placement(area: PlotArea) -> ((f32, f32), f32) {
+    ((area.x, area.y + area.height + 20.0), area.width)
+}
+
+/// 由坐标轴组件构建坐标变换
+fn axis_transform(axis: &Axis, pixel_range: (f32, f32)) -> SharedAxis {
+    match axis.get_log_scale() {
+        Some(log) => SharedAxis::new(log.clone(), pixel_range),
+        None => SharedAxis::new(axis.scale().clone(), pixel_range),
+    }
+}
+
+/// X 轴在绘图区域上的坐标变换
+fn x_axis_transform(axis: &Axis, area: PlotArea) -> SharedAxis {
+    axis_transform(axis, (area.x, area.x + area.width))
+}
+
+/// Y 轴在绘图区域上的坐标变换；定义域下限位于绘图区域底部
+fn y_axis_transform(axis: &Axis, area: PlotArea) -> SharedAxis {
+    axis_transform(axis, (area.y + area.height, area.y))
+}
+
+/// Y 轴位置与长度：位于绘图区域左侧
+fn y_axis_placement(area: PlotArea) -> ((f32, f32), f32) {
+    ((area.x - 20.0, area.y), area.height)
+}
+
+/// 次 Y 轴位置与长度：位于绘图区域右侧
+fn secondary_y_axis_placement(area: PlotArea) -> ((f32, f32), f32) {
+    ((area.x + area.width + 20.0, area.y), area.height)
 }
 
 #[cfg(test)]
@@ -368,4 +1246,424 @@ mod tests {
         let primitives = scene.generate_primitives();
         assert!(!primitives.is_empty());
     }
+
+    #[test]
+    fn test_scene_data_bounds() {
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0));
+        assert!(scene.data_bounds().is_none());
+
+        let scene = scene
+            .add_line_plot(LinePlot::new().xy_data(&[0.0, 5.0], &[2.0, 3.0]))
+            .add_scatter_plot(ScatterPlot::new().data(&[(-1.0, 1.0), (4.0, 8.0)]))
+            .add_heatmap(Heatmap::new().data(&[vec![1.0]]));
+        assert_eq!(scene.data_bounds(), Some((-1.0, 5.0, 1.0, 8.0)));
+    }
+
+    #[test]
+    fn test_secondary_axis_rescales_plot() {
+        let plot_area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let line = LinePlot::new().xy_data(&[0.0, 1.0], &[0.0, 50.0]);
+
+        let scene = Scene::new(plot_area)
+            .add_secondary_y_axis(LinearScale::new(0.0, 100.0), Some("Right".to_string()))
+            .add_secondary_line_plot(line);
+
+        let strip = scene
+            .generate_primitives()
+            .into_iter()
+            .find_map(|p| match p {
+                Primitive::LineStrip(points) => Some(points),
+                _ => None,
+            })
+            .expect("secondary line should be drawn");
+        // 50 在 [0, 100] 次轴上位于中间高度
+        assert!((strip[1].y - 50.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_align_secondary_zero() {
+        let plot_area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let scene = Scene::new(plot_area)
+            .add_y_axis(LinearScale::new(-10.0, 30.0), None)
+            .add_secondary_y_axis(LinearScale::new(0.0, 300.0), None)
+            .align_secondary_zero();
+
+        let secondary = scene.secondary_y_axis.as_ref().unwrap().scale();
+        assert!((secondary.normalize(0.0) - 0.25).abs() < 1e-5);
+        assert_eq!(secondary.domain_max, 300.0);
+    }
+
+    #[test]
+    fn test_legend_labels_mark_axis() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let blue = Color::rgb(0.0, 0.0, 1.0);
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .legend_entry("温度", red, YAxisSide::Left)
+            .legend_entry("降水", blue, YAxisSide::Right);
+        assert_eq!(scene.legend_labels(), vec!["温度", "降水"]);
+
+        let scene = scene.add_secondary_y_axis(LinearScale::new(0.0, 1.0), None);
+        assert_eq!(scene.legend_labels(), vec!["温度 (左轴)", "降水 (右轴)"]);
+    }
+
+    #[test]
+    fn test_grid_lines_follow_ticks() {
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .add_x_axis(LinearScale::new(0.0, 10.0), None)
+            .add_log_y_axis(LogScale::base10(1.0, 100.0), None)
+            .grid(true);
+
+        let grid_lines = scene
+            .generate_primitives()
+            .into_iter()
+            .filter(|p| matches!(p, Primitive::Polyline { .. }))
+            .count();
+        // X 轴 5 个刻度 + Y 轴 5 个刻度
+        assert_eq!(grid_lines, 10);
+    }
+
+    #[test]
+    fn test_plots_draw_against_shared_axes() {
+        // 折线图自身自动缩放到 [1, 3]，加入场景后改用坐标轴的 [0, 10]
+        let line = LinePlot::new()
+            .xy_data(&[1.0, 3.0], &[1.0, 3.0])
+            .auto_scale();
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .add_x_axis(LinearScale::new(0.0, 10.0), None)
+            .add_y_axis(LinearScale::new(0.0, 10.0), None)
+            .add_line_plot(line);
+
+        let x_axis = scene.shared_x_axis().unwrap();
+        let y_axis = scene.shared_y_axis().unwrap();
+        assert_eq!(y_axis.value_to_pixel(0.0), 100.0);
+        assert_eq!(x_axis.pixel_to_value(50.0), 5.0);
+
+        let points = scene
+            .generate_primitives()
+            .into_iter()
+            .find_map(|p| match p {
+                Primitive::LineStrip(points) => Some(points),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(points[0], Point2::new(10.0, 90.0));
+        assert_eq!(
+            points[1],
+            Point2::new(x_axis.value_to_pixel(3.0), y_axis.value_to_pixel(3.0))
+        );
+    }
+
+    #[test]
+    fn test_plots_draw_raw_data_on_log_axis() {
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .add_x_axis(LinearScale::new(0.0, 2.0), None)
+            .add_log_y_axis(LogScale::base10(1.0, 100.0), None)
+            .add_scatter_plot(ScatterPlot::new().data(&[(1.0, 10.0), (2.0, 100.0)]));
+
+        let y_axis = scene.shared_y_axis().unwrap();
+        let points = scene
+            .generate_primitives()
+            .into_iter()
+            .find_map(|p| match p {
+                Primitive::Points(points) => Some(points),
+                _ => None,
+            })
+            .unwrap();
+        // 10 位于 [1, 100] 对数轴的正中，与刻度使用同一映射
+        assert!((points[0].y - 50.0).abs() < 1e-3);
+        assert!((points[1].y - y_axis.value_to_pixel(100.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_time_axis() {
+        const DAY: i64 = 86_400_000;
+        let start = 19_723 * DAY; // 2024-01-01
+        let line = LinePlot::new().time_data(&[(start, 1.0), (start + 90 * DAY, 2.0)]);
+        let time = line.time_scale().cloned().unwrap();
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .add_time_x_axis(time.clone(), None)
+            .add_y_axis(LinearScale::new(0.0, 3.0), None)
+            .add_line_plot(line);
+        let has_label = |scene: &Scene, label: &str| {
+            scene
+                .generate_primitives()
+                .iter()
+                .any(|p| matches!(p, Primitive::Text { content, .. } if content.as_str() == label))
+        };
+        assert!(has_label(&scene, "Feb 2024"));
+
+        let rebuilt = Scene::from_spec(scene.to_spec().unwrap()).unwrap();
+        let x_axis = rebuilt.to_spec().unwrap().x_axis.unwrap();
+        assert_eq!(x_axis.time_scale, Some(time));
+        assert!(has_label(&rebuilt, "Mar 2024"));
+    }
+
+    #[test]
+    fn test_positioned_primitives_follow_plot_area() {
+        let label = Primitive::Text {
+            position: Point2::new(1.0, 1.0),
+            content: "右上角".to_string(),
+            size: 12.0,
+            color: Color::rgb(0.1, 0.1, 0.1),
+            h_align: vizuara_core::HorizontalAlign::Right,
+            v_align: vizuara_core::VerticalAlign::Top,
+            rotation: 0.0,
+        };
+        let marker = Primitive::Circle {
+            center: Point2::new(5.0, 5.0),
+            radius: 3.0,
+        };
+        let mut scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .add_x_axis(LinearScale::new(0.0, 10.0), None)
+            .add_y_axis(LinearScale::new(0.0, 10.0), None)
+            .place(Positioned::plot_area(label))
+            .place(Positioned::data(marker))
+            .annotate_in(Annotation::text(0.0, 0.0, "左下角"), CoordSpace::PlotArea);
+
+        let positions = |scene: &Scene| -> Vec<Point2<f32>> {
+            scene
+                .layered_primitives()
+                .items()
+                .iter()
+                .filter(|(layer, _)| *layer == PrimitiveLayer::Annotation)
+                .filter_map(|(_, p)| match p {
+                    Primitive::Text { position, .. } => Some(*position),
+                    Primitive::Circle { center, .. } => Some(*center),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(
+            positions(&scene),
+            [
+                Point2::new(0.0, 100.0),
+                Point2::new(100.0, 0.0),
+                Point2::new(50.0, 50.0)
+            ]
+        );
+
+        // 绘图区域改变后重新换算，无需手动更新坐标
+        scene.set_plot_area(PlotArea::new(50.0, 20.0, 300.0, 200.0));
+        assert_eq!(
+            positions(&scene),
+            [
+                Point2::new(50.0, 220.0),
+                Point2::new(350.0, 20.0),
+                Point2::new(200.0, 120.0)
+            ]
+        );
+
+        let rebuilt = Scene::from_spec(scene.to_spec().unwrap()).unwrap();
+        assert_eq!(positions(&rebuilt), positions(&scene));
+    }
+
+    #[test]
+    fn test_zebra_bands_align_with_ticks() {
+        let (light, dark) = (Color::rgb(1.0, 1.0, 1.0), Color::rgb(0.9, 0.9, 0.9));
+        let scene = Scene::new(PlotArea::new(10.0, 0.0, 100.0, 100.0))
+            .add_y_axis(LinearScale::new(0.0, 10.0), None)
+            .add_line_plot(LinePlot::new().xy_data(&[0.0, 10.0], &[0.0, 10.0]))
+            .zebra_bands(light, dark);
+
+        let primitives = scene.generate_primitives();
+        let bands: Vec<(Point2<f32>, Point2<f32>, Color)> = primitives
+            .iter()
+            .filter_map(|p| match p {
+                Primitive::RectangleStyled { min, max, fill, .. } => Some((*min, *max, *fill)),
+                _ => None,
+            })
+            .collect();
+
+        // 刻度 0, 2.5, 5, 7.5, 10 之间 4 条条带，交替着色并覆盖整个宽度
+        let mut ticks = scene.y_axis.as_ref().unwrap().tick_positions();
+        ticks.sort_by(f32::total_cmp);
+        assert_eq!(bands.len(), 4);
+        let mut edges: Vec<f32> = bands
+            .iter()
+            .flat_map(|(min, max, _)| [min.y, max.y])
+            .collect();
+        edges.sort_by(f32::total_cmp);
+        edges.dedup();
+        assert_eq!(edges, ticks);
+        assert!(bands
+            .iter()
+            .all(|(min, max, _)| min.x == 10.0 && max.x == 110.0));
+        assert_eq!(bands[0].2, light);
+        assert_eq!(bands[1].2, dark);
+
+        // 条带位于数据之下
+        let data = primitives
+            .iter()
+            .position(|p| matches!(p, Primitive::LineStrip(_)))
+            .unwrap();
+        let last_band = primitives
+            .iter()
+            .rposition(|p| matches!(p, Primitive::RectangleStyled { .. }))
+            .unwrap();
+        assert!(last_band < data);
+    }
+
+    #[test]
+    fn test_primitives_drawn_in_layer_order() {
+        let line = LinePlot::new().xy_data(&[0.0, 10.0], &[0.0, 10.0]);
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .add_x_axis(LinearScale::new(0.0, 10.0), None)
+            .add_y_axis(LinearScale::new(0.0, 10.0), None)
+            .add_line_plot(line)
+            .legend_entry("数据", Color::rgb(1.0, 0.0, 0.0), YAxisSide::Left)
+            .grid(true);
+
+        // 网格线在生成时晚于坐标轴，但排序后位于数据之下；填充的边框位于最底层
+        let layers: Vec<PrimitiveLayer> = scene
+            .layered_primitives()
+            .items()
+            .iter()
+            .map(|(layer, _)| *layer)
+            .collect();
+        assert!(!layers.windows(2).all(|w| w[0] <= w[1]));
+
+        let primitives = scene.generate_primitives();
+        let position = |f: fn(&Primitive) -> bool| primitives.iter().position(f).unwrap();
+        let last_grid = primitives
+            .iter()
+            .rposition(|p| matches!(p, Primitive::Polyline { .. }))
+            .unwrap();
+        let data = position(|p| matches!(p, Primitive::LineStrip(_)));
+        let frame = position(|p| matches!(p, Primitive::Rectangle { .. }));
+        let legend = position(|p| matches!(p, Primitive::RectangleStyled { .. }));
+        assert!(frame < last_grid);
+        assert!(last_grid < data);
+        assert!(data < legend);
+    }
+
+    #[test]
+    fn test_linked_scenes_share_x_range() {
+        let plot_area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let link = AxisLink::x();
+
+        let top = Scene::new(plot_area)
+            .add_x_axis(LinearScale::new(0.0, 10.0), None)
+            .add_y_axis(LinearScale::new(0.0, 1.0), None)
+            .link_axes(link.clone())
+            .add_linked_plot(LinePlot::new().xy_data(&[0.0, 10.0, 20.0], &[0.0, 1.0, 3.0]));
+        let bottom = Scene::new(plot_area)
+            .add_x_axis(LinearScale::new(5.0, 20.0), None)
+            .add_y_axis(LinearScale::new(-5.0, 5.0), None)
+            .link_axes(link.clone());
+
+        // 加入联动组时坐标轴范围与联动图表的数据范围取并集，Y 轴保持各自独立
+        let data_x = LinearScale::from_data(&[0.0, 20.0]);
+        assert_eq!(top.x_scale(), Some(data_x));
+        assert_eq!(bottom.x_scale(), top.x_scale());
+        assert_eq!(bottom.y_scale(), Some(LinearScale::new(-5.0, 5.0)));
+
+        // 在一个成员上平移后另一个成员同步，Y 轴适配共享 X 范围内的数据
+        link.set_view_bounds(10.0, 20.0, 0.0, 0.0);
+        assert_eq!(top.x_scale(), Some(LinearScale::new(10.0, 20.0)));
+        assert_eq!(top.y_scale(), Some(LinearScale::from_data(&[1.0, 3.0])));
+        assert_eq!(bottom.y_scale(), Some(LinearScale::new(-5.0, 5.0)));
+
+        let strip = top
+            .generate_primitives()
+            .into_iter()
+            .find_map(|p| match p {
+                Primitive::LineStrip(points) => Some(points),
+                _ => None,
+            })
+            .expect("linked line should be drawn");
+        // x = 20 位于 [10, 20] 的右端
+        assert!((strip.last().unwrap().x - 100.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_log_axis_joins_link() {
+        let plot_area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let link = AxisLink::x();
+
+        let linear = Scene::new(plot_area)
+            .link_axes(link.clone())
+            .add_x_axis(LinearScale::new(1.0, 10.0), None);
+        let log = Scene::new(plot_area)
+            .link_axes(link.clone())
+            .add_log_x_axis(LogScale::base10(5.0, 1000.0), None);
+
+        // 对数轴的定义域并入共享范围，两个场景使用相同范围
+        assert_eq!(link.x_scale(), Some(LinearScale::new(1.0, 1000.0)));
+        assert_eq!(linear.x_scale(), log.x_scale());
+
+        // 对数轴按共享范围映射：定义域两端位于绘图区域两端
+        let axis = log.shared_x_axis().unwrap();
+        assert!((axis.value_to_pixel(1.0) - 0.0).abs() < 1e-3);
+        assert!((axis.value_to_pixel(1000.0) - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_linked_plot_extends_shared_range() {
+        let plot_area = PlotArea::new(0.0, 0.0, 100.0, 100.0);
+        let link = AxisLink::both();
+
+        // 联动组已有范围时，新加入图表的数据范围并入共享范围
+        link.set_view_bounds(0.0, 1.0, 0.0, 1.0);
+        let scene = Scene::new(plot_area)
+            .link_axes(link.clone())
+            .add_linked_plot(ScatterPlot::new().data(&[(-4.0, 2.0), (6.0, 8.0)]));
+
+        let x = LinearScale::from_data(&[-4.0, 6.0]);
+        let y = LinearScale::from_data(&[2.0, 8.0]);
+        assert_eq!(link.x_scale(), Some(x.clone()));
+        assert_eq!(link.y_scale(), Some(LinearScale::new(0.0, y.domain_max)));
+
+        // 没有坐标轴时联动图表同样按共享比例尺绘制
+        let points = scene
+            .generate_primitives()
+            .into_iter()
+            .find_map(|p| match p {
+                Primitive::Points(points) => Some(points),
+                _ => None,
+            })
+            .expect("linked scatter should be drawn");
+        assert!((points[0].x - x.to_pixel(-4.0, 0.0, 100.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_x_only_link_fits_single_value() {
+        let link = AxisLink::x();
+        let scene = Scene::new(PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .link_axes(link.clone())
+            .add_linked_plot(LinePlot::new().xy_data(&[0.0, 1.0, 2.0], &[5.0, 7.0, 7.0]));
+
+        // 可见范围内只有一个 Y 值时不会得到零宽度的比例尺
+        link.set_x_range(1.5, 3.0);
+        assert_eq!(scene.y_scale(), None);
+        assert_eq!(scene.linked_y_scale(), Some(LinearScale::new(6.5, 7.5)));
+    }
+
+    #[test]
+    fn test_equal_aspect_letterboxes() {
+        let plot_area = PlotArea::new(0.0, 0.0, 400.0, 200.0);
+        let scene = Scene::new(plot_area)
+            .add_x_axis(LinearScale::new(0.0, 10.0), None)
+            .add_y_axis(LinearScale::new(0.0, 10.0), None);
+        assert_eq!(scene.effective_plot_area(), plot_area);
+
+        // X 方向留白，绘图区域变为居中的 200x200
+        let scene = scene.equal_aspect(true);
+        assert_eq!(
+            scene.effective_plot_area(),
+            PlotArea::new(100.0, 0.0, 200.0, 200.0)
+        );
+        let ticks = scene.current_x_axis().unwrap().tick_positions();
+        assert_eq!(ticks.first(), Some(&100.0));
+        assert_eq!(ticks.last(), Some(&300.0));
+
+        // 范围差异很大时按较长的一轴留白，不裁剪数据
+        let tall = Scene::new(plot_area)
+            .add_x_axis(LinearScale::new(0.0, 1.0), None)
+            .add_y_axis(LinearScale::new(0.0, 100.0), None)
+            .equal_aspect(true);
+        assert_eq!(
+            tall.effective_plot_area(),
+            PlotArea::new(199.0, 0.0, 2.0, 200.0)
+        );
+    }
 }
@@ -0,0 +1,214 @@
+//! 点大小图例
+//!
+//! 以嵌套的参考圆和数值标签说明气泡图中点大小的含义。图例与散点图共用同一个
+//! `SizeScale`，参考圆的直径与相同数值的数据点完全一致
+
+use nalgebra::Point2;
+use vizuara_core::{Color, HorizontalAlign, Primitive, VerticalAlign};
+use vizuara_plots::{ScatterPlot, SizeScale};
+
+/// 图例内边距（像素）
+const PADDING: f32 = 8.0;
+/// 标题行高（像素）
+const TITLE_HEIGHT: f32 = 18.0;
+/// 参考圆与标签之间的引线长度（像素）
+const LEADER_LENGTH: f32 = 10.0;
+/// 标签字号
+const LABEL_SIZE: f32 = 12.0;
+
+/// 点大小图例
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeLegend {
+    scale: SizeScale,
+    count: usize,
+    title: Option<String>,
+    color: Color,
+}
+
+impl SizeLegend {
+    /// 使用给定的大小映射创建图例
+    pub fn new(scale: SizeScale) -> Self {
+        Self {
+            scale,
+            count: 3,
+            title: None,
+            color: Color::rgb(0.3, 0.3, 0.3),
+        }
+    }
+
+    /// 使用散点图的大小映射创建图例；散点图未按数值编码大小时返回 None
+    pub fn from_scatter(plot: &ScatterPlot) -> Option<Self> {
+        plot.size_scale().cloned().map(Self::new)
+    }
+
+    /// 设置参考圆的目标数量（实际数量取整齐数值）
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count.max(1);
+        self
+    }
+
+    /// 设置标题
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 设置参考圆与标签颜色
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// 参考圆对应的数值（从小到大）
+    pub fn values(&self) -> Vec<f32> {
+        self.scale.legend_values(self.count)
+    }
+
+    /// 图例占用的尺寸 (宽, 高)
+    pub fn size(&self) -> (f32, f32) {
+        let values = self.values();
+        let diameter = self.max_diameter(&values);
+        let label_chars = values
+            .iter()
+            .map(|&v| format_value(v).chars().count())
+            .max()
+            .unwrap_or(0);
+        let label_width = label_chars as f32 * LABEL_SIZE * 0.6;
+        let title_width = self
+            .title
+            .as_ref()
+            .map_or(0.0, |t| t.chars().count() as f32 * LABEL_SIZE * 0.6);
+
+        let width = (diameter + LEADER_LENGTH + 4.0 + label_width).max(title_width);
+        (
+            width + 2.0 * PADDING,
+            diameter + self.title_height() + 2.0 * PADDING,
+        )
+    }
+
+    /// 以 origin 为左上角生成图元：参考圆底部对齐嵌套，大圆在下层
+    pub fn generate_primitives(&self, origin: Point2<f32>) -> Vec<Primitive> {
+        let values = self.values();
+        let diameter = self.max_diameter(&values);
+        let (width, height) = self.size();
+        let mut primitives = vec![Primitive::RectangleStyled {
+            min: origin,
+            max: Point2::new(origin.x + width, origin.y + height),
+            fill: Color::rgba(1.0, 1.0, 1.0, 0.85),
+            stroke: Some((Color::rgb(0.8, 0.8, 0.8), 1.0)),
+        }];
+
+        if let Some(ref title) = self.title {
+            primitives.push(Primitive::Text {
+                position: Point2::new(origin.x + PADDING, origin.y + PADDING),
+                content: title.clone(),
+                size: LABEL_SIZE,
+                color: self.color,
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Top,
+                rotation: 0.0,
+            });
+        }
+
+        let center_x = origin.x + PADDING + diameter / 2.0;
+        let baseline = origin.y + PADDING + self.title_height() + diameter;
+        let label_x = origin.x + PADDING + diameter + LEADER_LENGTH;
+
+        for &value in values.iter().rev() {
+            let size = self.scale.size(value);
+            let top = baseline - size;
+            primitives.push(Primitive::ArcSector {
+                center: Point2::new(center_x, baseline - size / 2.0),
+                radius: size / 2.0,
+                start_angle: 0.0,
+                end_angle: std::f32::consts::TAU,
+                fill: Color::TRANSPARENT,
+                stroke: Some((self.color, 1.0)),
+            });
+            primitives.push(Primitive::Line {
+                start: Point2::new(center_x, top),
+                end: Point2::new(label_x, top),
+            });
+            primitives.push(Primitive::Text {
+                position: Point2::new(label_x + 4.0, top),
+                content: format_value(value),
+                size: LABEL_SIZE,
+                color: self.color,
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Middle,
+                rotation: 0.0,
+            });
+        }
+
+        primitives
+    }
+
+    fn max_diameter(&self, values: &[f32]) -> f32 {
+        values
+            .iter()
+            .map(|&v| self.scale.size(v))
+            .fold(0.0, f32::max)
+    }
+
+    fn title_height(&self) -> f32 {
+        if self.title.is_some() {
+            TITLE_HEIGHT
+        } else {
+            0.0
+        }
+    }
+}
+
+/// 标签文本：整数不带小数，其余最多保留两位小数
+fn format_value(value: f32) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value)
+    } else {
+        let text = format!("{:.2}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_legend_matches_scatter_sizes() {
+        let scatter = ScatterPlot::new()
+            .data(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)])
+            .size_values(&[0.0, 40.0, 100.0], (4.0, 40.0));
+        let legend = SizeLegend::from_scatter(&scatter).unwrap().count(3);
+        assert_eq!(legend.values(), [0.0, 50.0, 100.0]);
+
+        let scatter_radii: Vec<f32> = scatter
+            .generate_primitives(vizuara_plots::PlotArea::new(0.0, 0.0, 100.0, 100.0))
+            .into_iter()
+            .filter_map(|p| match p {
+                Primitive::ArcSector { radius, .. } => Some(radius),
+                _ => None,
+            })
+            .collect();
+        let legend_circles: Vec<(f32, f32)> = legend
+            .generate_primitives(Point2::new(0.0, 0.0))
+            .into_iter()
+            .filter_map(|p| match p {
+                Primitive::ArcSector { center, radius, .. } => Some((center.y + radius, radius)),
+                _ => None,
+            })
+            .collect();
+
+        // 最大值与最小值的参考圆与对应数据点大小一致，大圆先绘制
+        assert_eq!(legend_circles.len(), 3);
+        assert_eq!(legend_circles[0].1, scatter_radii[2]);
+        assert_eq!(legend_circles[2].1, scatter_radii[0]);
+        // 所有参考圆底部对齐
+        assert!(legend_circles
+            .iter()
+            .all(|c| (c.0 - legend_circles[0].0).abs() < 1e-4));
+
+        assert!(SizeLegend::from_scatter(&ScatterPlot::new()).is_none());
+        assert_eq!(format_value(2.5), "2.5");
+        assert_eq!(format_value(20.0), "20");
+    }
+}
@@ -0,0 +1,86 @@
+//! 图形的声明式描述
+//!
+//! 记录构建图形时使用的配置（坐标轴、图表、图例、标注），而非生成的图元，
+//! 可序列化为 JSON 保存，之后经同样的构建方法重建出相同的图形
+
+use crate::{Annotation, CoordSpace, LegendEntry, Margins, Positioned};
+use serde::{Deserialize, Serialize};
+use vizuara_core::{Color, GridStyle, LinearScale, LogScale, TimeScale};
+use vizuara_plots::{BarPlot, Histogram, LinePlot, PlotArea, ScatterPlot};
+
+/// 图表配置，`type` 字段标明图表类型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PlotSpec {
+    Scatter(ScatterPlot),
+    Line(LinePlot),
+    Bar(BarPlot),
+    Histogram(Histogram),
+}
+
+/// 坐标轴配置；刻度数量、样式与格式化器不在其中，重建后使用默认值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisSpec {
+    pub scale: LinearScale,
+    #[serde(default)]
+    pub log_scale: Option<LogScale>,
+    #[serde(default)]
+    pub time_scale: Option<TimeScale>,
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+/// 场景配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneSpec {
+    pub plot_area: PlotArea,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub x_axis: Option<AxisSpec>,
+    #[serde(default)]
+    pub y_axis: Option<AxisSpec>,
+    #[serde(default)]
+    pub secondary_y_axis: Option<AxisSpec>,
+    #[serde(default)]
+    pub plots: Vec<PlotSpec>,
+    /// 绘制在次 Y 轴上的图表，仅支持散点图、折线图与柱状图
+    #[serde(default)]
+    pub secondary_plots: Vec<PlotSpec>,
+    #[serde(default)]
+    pub legend: Vec<LegendEntry>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+    /// 以像素或绘图区域归一化坐标定位的标注
+    #[serde(default)]
+    pub placed_annotations: Vec<(Annotation, CoordSpace)>,
+    /// 按坐标空间定位的图元
+    #[serde(default)]
+    pub positioned: Vec<Positioned>,
+    #[serde(default)]
+    pub show_grid: bool,
+    #[serde(default)]
+    pub grid_style: GridStyle,
+    #[serde(default)]
+    pub equal_aspect: bool,
+    /// 主刻度之间交替填充的两种背景色
+    #[serde(default)]
+    pub zebra_bands: Option<(Color, Color)>,
+}
+
+/// 图形配置，`Figure::to_json`/`Figure::from_json` 读写的即是该结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FigureSpec {
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub margins: Option<Margins>,
+    #[serde(default)]
+    pub background: Option<Color>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+    #[serde(default)]
+    pub scenes: Vec<SceneSpec>,
+}
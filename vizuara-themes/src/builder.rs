@@ -556,12 +556,164 @@ impl PaletteBuilder {
         self
     }
 
+    /// 从像素颜色中提取 k 个代表色（RGB 空间 k-means）
+    ///
+    /// 聚类按像素数量从多到少排序，前三个依次作为主要、次要和强调颜色，
+    /// 全部作为系列颜色。低方差图片聚类不足 k 个时用最大聚类的浅色补齐。
+    pub fn from_pixels(name: impl Into<String>, pixels: &[Color], k: usize) -> Self {
+        let mut builder = Self::new(name).description("Palette extracted from image");
+        if k == 0 || pixels.is_empty() {
+            return builder;
+        }
+
+        let mut colors = kmeans_colors(pixels, k);
+
+        // 聚类不足时补充主色的浅色
+        let base = colors[0];
+        let missing = k - colors.len();
+        for i in 1..=missing {
+            let t = i as f32 / (missing + 1) as f32;
+            colors.push(Color::rgb(
+                base.r + (1.0 - base.r) * t,
+                base.g + (1.0 - base.g) * t,
+                base.b + (1.0 - base.b) * t,
+            ));
+        }
+
+        builder = builder.primary(colors[0]);
+        if let Some(&color) = colors.get(1) {
+            builder = builder.secondary(color);
+        }
+        if let Some(&color) = colors.get(2) {
+            builder = builder.accent(color);
+        }
+        builder.series(colors)
+    }
+
+    /// 从图片文件提取 k 个代表色构建调色板
+    ///
+    /// 大图会先缩小到最长边不超过 128 像素再聚类；完全透明的像素被忽略。
+    #[cfg(feature = "image-palette")]
+    pub fn from_image(path: impl AsRef<std::path::Path>, k: usize) -> ThemeResult<Self> {
+        let path = path.as_ref();
+        let image = image::open(path)
+            .map_err(|e| crate::ThemeError::IoError(format!("读取图片失败: {}", e)))?
+            .thumbnail(128, 128)
+            .to_rgba8();
+
+        let pixels: Vec<Color> = image
+            .pixels()
+            .filter(|p| p[3] > 0)
+            .map(|p| {
+                Color::rgb(
+                    p[0] as f32 / 255.0,
+                    p[1] as f32 / 255.0,
+                    p[2] as f32 / 255.0,
+                )
+            })
+            .collect();
+
+        if pixels.is_empty() {
+            return Err(crate::ThemeError::InvalidColor(format!(
+                "图片中没有可用的像素: {}",
+                path.display()
+            )));
+        }
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("image_palette");
+        Ok(Self::from_pixels(name, &pixels, k))
+    }
+
     /// 构建调色板
     pub fn build(self) -> ColorPalette {
         self.palette
     }
 }
 
+/// RGB 空间 k-means 聚类，返回按聚类大小降序排列的中心颜色（可能少于 k 个）
+fn kmeans_colors(pixels: &[Color], k: usize) -> Vec<Color> {
+    const MAX_ITERATIONS: usize = 20;
+    const MERGE_DISTANCE: f32 = 1e-3;
+
+    let distance = |a: &[f32; 3], b: &[f32; 3]| {
+        (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+    };
+    let points: Vec<[f32; 3]> = pixels.iter().map(|c| [c.r, c.g, c.b]).collect();
+
+    // 最远点初始化（确定性）：从第一个像素开始，每次选离已有中心最远的点
+    let mut centers = vec![points[0]];
+    while centers.len() < k {
+        let (farthest, max_distance) = points
+            .iter()
+            .map(|p| {
+                centers
+                    .iter()
+                    .map(|c| distance(p, c))
+                    .fold(f32::INFINITY, f32::min)
+            })
+            .enumerate()
+            .fold(
+                (0, 0.0),
+                |best, (i, d)| if d > best.1 { (i, d) } else { best },
+            );
+
+        // 剩余的点都与已有中心重合，无法形成新的聚类
+        if max_distance <= MERGE_DISTANCE {
+            break;
+        }
+        centers.push(points[farthest]);
+    }
+
+    let mut counts = vec![0usize; centers.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let mut sums = vec![[0.0f32; 3]; centers.len()];
+        counts.iter_mut().for_each(|c| *c = 0);
+
+        for point in &points {
+            let nearest = (0..centers.len())
+                .min_by(|&a, &b| {
+                    distance(point, &centers[a]).total_cmp(&distance(point, &centers[b]))
+                })
+                .unwrap_or(0);
+            counts[nearest] += 1;
+            for channel in 0..3 {
+                sums[nearest][channel] += point[channel];
+            }
+        }
+
+        let mut moved = false;
+        for (i, center) in centers.iter_mut().enumerate() {
+            if counts[i] == 0 {
+                continue;
+            }
+            let updated = sums[i].map(|sum| sum / counts[i] as f32);
+            if distance(center, &updated) > 1e-8 {
+                moved = true;
+            }
+            *center = updated;
+        }
+
+        if !moved {
+            break;
+        }
+    }
+
+    let mut clusters: Vec<([f32; 3], usize)> = centers
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    clusters.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    clusters
+        .into_iter()
+        .map(|(c, _)| Color::rgb(c[0], c[1], c[2]))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -648,6 +800,53 @@ mod tests {
         assert!(palette.series.len() >= 2); // 至少包含我们添加的两个颜色
     }
 
+    #[test]
+    fn test_palette_from_pixels() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let blue = Color::rgb(0.0, 0.0, 1.0);
+        let mut pixels = vec![red; 30];
+        pixels.extend(vec![blue; 10]);
+        pixels.push(Color::rgb(0.95, 0.05, 0.0));
+
+        let palette = PaletteBuilder::from_pixels("brand", &pixels, 2).build();
+        assert_eq!(palette.series.len(), 2);
+        // 按聚类大小排序：红色像素最多
+        assert!(palette.primary.r > 0.9 && palette.primary.b < 0.1);
+        assert!(palette.secondary.b > 0.9);
+    }
+
+    #[test]
+    fn test_palette_from_low_variance_pixels() {
+        let gray = Color::rgb(0.5, 0.5, 0.5);
+        let palette = PaletteBuilder::from_pixels("gray", &[gray; 16], 3).build();
+
+        // 只有一个聚类，其余用浅色补齐
+        assert_eq!(palette.series.len(), 3);
+        assert_eq!(palette.series[0], gray);
+        assert!(palette.series[1].r > gray.r && palette.series[2].r > palette.series[1].r);
+    }
+
+    #[cfg(feature = "image-palette")]
+    #[test]
+    fn test_palette_from_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("brand.png");
+        let image = image::RgbaImage::from_fn(8, 8, |x, _| {
+            if x < 6 {
+                image::Rgba([0, 128, 255, 255])
+            } else {
+                image::Rgba([255, 64, 0, 255])
+            }
+        });
+        image.save(&path).unwrap();
+
+        let palette = PaletteBuilder::from_image(&path, 2).unwrap().build();
+        assert_eq!(palette.name, "brand");
+        assert_eq!(palette.series.len(), 2);
+        assert!(palette.primary.b > 0.9);
+        assert!(PaletteBuilder::from_image(dir.path().join("missing.png"), 2).is_err());
+    }
+
     #[test]
     fn test_theme_with_palette() {
         let palette = PaletteBuilder::new("custom_palette")
@@ -0,0 +1,128 @@
+//! 系列颜色循环
+//!
+//! 按顺序从主题调色板的 `series` 颜色中取色，用完后从头循环，
+//! 第 N 个系列总是得到同一个颜色，图表、图例与颜色条据此保持一致
+
+use crate::{ColorPalette, Theme, ThemeManager};
+use vizuara_core::Color;
+
+/// 系列颜色循环
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorCycle {
+    colors: Vec<Color>,
+    position: usize,
+}
+
+impl ColorCycle {
+    /// 使用给定颜色序列创建；序列为空时使用默认调色板的系列颜色
+    pub fn new(colors: Vec<Color>) -> Self {
+        let colors = if colors.is_empty() {
+            default_series()
+        } else {
+            colors
+        };
+        Self {
+            colors,
+            position: 0,
+        }
+    }
+
+    /// 使用调色板的系列颜色创建
+    pub fn from_palette(palette: &ColorPalette) -> Self {
+        Self::new(palette.series.clone())
+    }
+
+    /// 使用主题的系列颜色创建
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self::new(theme.series_colors())
+    }
+
+    /// 使用当前活动主题的系列颜色创建
+    pub fn from_current_theme() -> Self {
+        Self::from_theme(&ThemeManager::instance().current_theme())
+    }
+
+    /// 取出下一个颜色
+    pub fn next_color(&mut self) -> Color {
+        let color = self.color_at(self.position);
+        self.position += 1;
+        color
+    }
+
+    /// 预览接下来的 n 个颜色（不前进）
+    pub fn peek(&self, n: usize) -> Vec<Color> {
+        (self.position..self.position + n)
+            .map(|i| self.color_at(i))
+            .collect()
+    }
+
+    /// 第 index 个系列（从 0 开始）的颜色，与已取出多少颜色无关
+    pub fn color_at(&self, index: usize) -> Color {
+        self.colors[index % self.colors.len()]
+    }
+
+    /// 回到第一个颜色
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    /// 已取出的颜色数量
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// 循环使用的颜色序列
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
+
+/// 默认调色板的系列颜色
+fn default_series() -> Vec<Color> {
+    ColorPalette::new("Default", "").series
+}
+
+impl Default for ColorCycle {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Iterator for ColorCycle {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Color> {
+        Some(self.next_color())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_cycle_order_and_reset() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let green = Color::rgb(0.0, 1.0, 0.0);
+        let blue = Color::rgb(0.0, 0.0, 1.0);
+        let mut cycle = ColorCycle::new(vec![red, green, blue]);
+
+        // 预览不前进
+        assert_eq!(cycle.peek(4), [red, green, blue, red]);
+        assert_eq!(cycle.next_color(), red);
+        assert_eq!(cycle.next_color(), green);
+        assert_eq!(cycle.peek(2), [blue, red]);
+        assert_eq!(cycle.next_color(), blue);
+        // 用完后循环
+        assert_eq!(cycle.next_color(), red);
+        assert_eq!(cycle.position(), 4);
+        assert_eq!(cycle.color_at(5), blue);
+
+        cycle.reset();
+        assert_eq!(cycle.take(2).collect::<Vec<_>>(), [red, green]);
+
+        // 空序列回退到默认调色板
+        let fallback = ColorCycle::new(Vec::new());
+        assert_eq!(fallback.colors(), default_series());
+    }
+}
@@ -10,19 +10,22 @@
 //!
 
 pub mod builder;
+pub mod cycle;
 pub mod manager;
 pub mod palette;
 pub mod presets;
 pub mod theme;
 
 pub use builder::{ComponentThemeBuilder, PaletteBuilder, ThemeBuilder};
+pub use cycle::ColorCycle;
 pub use manager::ThemeManager;
 pub use palette::ColorPalette;
 pub use presets::ThemePresets;
 pub use theme::{ComponentTheme, Theme};
 
 use serde::{Deserialize, Serialize};
-use vizuara_core::Color;
+use std::collections::HashMap;
+use vizuara_core::{Color, Shadow, VizuaraError};
 
 /// 主题系统的错误类型
 #[derive(Debug, Clone)]
@@ -53,6 +56,16 @@ impl std::fmt::Display for ThemeError {
 
 impl std::error::Error for ThemeError {}
 
+/// 转换为核心错误类型，便于跨 crate 使用 `?`
+impl From<ThemeError> for VizuaraError {
+    fn from(error: ThemeError) -> Self {
+        match error {
+            ThemeError::InvalidColor(color) => VizuaraError::InvalidColor(color),
+            other => VizuaraError::ConfigError(other.to_string()),
+        }
+    }
+}
+
 /// 主题系统的结果类型
 pub type ThemeResult<T> = Result<T, ThemeError>;
 
@@ -128,7 +141,7 @@ pub enum ThemeProperty {
     BorderRadius,
     /// 透明度
     Opacity,
-    /// 阴影
+    /// 阴影：颜色值为投影颜色，数值为模糊半径，布尔值开关默认投影
     Shadow,
     /// 动画持续时间
     AnimationDuration,
@@ -139,8 +152,8 @@ pub enum ThemeProperty {
 /// 主题值类型
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ThemeValue {
-    /// 颜色值
-    Color(Color),
+    /// 颜色值（序列化为 "#rrggbb" 或 "#rrggbbaa"）
+    Color(#[serde(with = "color_hex")] Color),
     /// 数值
     Number(f32),
     /// 字符串
@@ -181,12 +194,151 @@ impl ThemeValue {
             _ => None,
         }
     }
+
+    /// 作为阴影属性解释：颜色指定投影颜色，数值指定模糊半径，`true` 使用默认投影
+    pub fn as_shadow(&self) -> Option<Shadow> {
+        match self {
+            ThemeValue::Color(color) => Some(Shadow {
+                color: *color,
+                ..Shadow::default()
+            }),
+            ThemeValue::Number(blur) => Some(Shadow {
+                blur: blur.max(0.0),
+                ..Shadow::default()
+            }),
+            ThemeValue::Boolean(true) => Some(Shadow::default()),
+            _ => None,
+        }
+    }
+
+    /// 在两个值之间插值
+    ///
+    /// 颜色按通道、数值线性插值；字符串、布尔值以及类型不同的值在 t = 0.5 处切换。
+    pub fn lerp(&self, other: &ThemeValue, t: f32) -> ThemeValue {
+        let t = t.clamp(0.0, 1.0);
+        match (self, other) {
+            (ThemeValue::Color(a), ThemeValue::Color(b)) => ThemeValue::Color(Color::rgba(
+                a.r + (b.r - a.r) * t,
+                a.g + (b.g - a.g) * t,
+                a.b + (b.b - a.b) * t,
+                a.a + (b.a - a.a) * t,
+            )),
+            (ThemeValue::Number(a), ThemeValue::Number(b)) => ThemeValue::Number(a + (b - a) * t),
+            _ => {
+                if t < 0.5 {
+                    self.clone()
+                } else {
+                    other.clone()
+                }
+            }
+        }
+    }
+}
+
+/// 按键插值两组主题属性，只在一侧存在的属性原样保留
+pub(crate) fn lerp_values<K>(
+    from: &HashMap<K, ThemeValue>,
+    to: &HashMap<K, ThemeValue>,
+    t: f32,
+) -> HashMap<K, ThemeValue>
+where
+    K: Clone + Eq + std::hash::Hash,
+{
+    let mut result = to.clone();
+    for (key, value) in from {
+        let value = match to.get(key) {
+            Some(target) => value.lerp(target, t),
+            None => value.clone(),
+        };
+        result.insert(key.clone(), value);
+    }
+    result
+}
+
+/// 颜色的十六进制序列化
+///
+/// 写出 "#rrggbb"（不透明）或 "#rrggbbaa"；读取时同时兼容旧的 `{ r, g, b, a }` 结构。
+pub(crate) mod color_hex {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use vizuara_core::Color;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ColorRepr {
+        Hex(String),
+        Rgba(Color),
+    }
+
+    fn channel(value: f32) -> u8 {
+        (value.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
+    /// 颜色转十六进制字符串
+    pub fn to_hex(color: &Color) -> String {
+        let rgb = format!(
+            "#{:02x}{:02x}{:02x}",
+            channel(color.r),
+            channel(color.g),
+            channel(color.b)
+        );
+        if channel(color.a) == 255 {
+            rgb
+        } else {
+            format!("{}{:02x}", rgb, channel(color.a))
+        }
+    }
+
+    /// 解析 "#rrggbb" 或 "#rrggbbaa"
+    pub fn parse(hex: &str) -> Option<Color> {
+        let digits = hex.trim().trim_start_matches('#');
+        if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+            return None;
+        }
+
+        let component = |i: usize| {
+            u8::from_str_radix(&digits[i..i + 2], 16)
+                .ok()
+                .map(|v| v as f32 / 255.0)
+        };
+        let alpha = if digits.len() == 8 {
+            component(6)?
+        } else {
+            1.0
+        };
+        Some(Color::rgba(
+            component(0)?,
+            component(2)?,
+            component(4)?,
+            alpha,
+        ))
+    }
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        match ColorRepr::deserialize(deserializer)? {
+            ColorRepr::Hex(hex) => {
+                parse(&hex).ok_or_else(|| de::Error::custom(format!("无效的颜色格式: {}", hex)))
+            }
+            ColorRepr::Rgba(color) => Ok(color),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
+
+    #[test]
+    fn test_theme_error_into_vizuara_error() {
+        let error: VizuaraError = ThemeError::InvalidColor("#zz".to_string()).into();
+        assert!(matches!(error, VizuaraError::InvalidColor(ref c) if c == "#zz"));
+
+        let error: VizuaraError = ThemeError::ThemeNotFound("ocean".to_string()).into();
+        assert!(matches!(error, VizuaraError::ConfigError(ref m) if m.contains("ocean")));
+    }
 
     #[test]
     fn test_theme_value_conversion() {
@@ -207,6 +359,39 @@ mod tests {
         assert_eq!(bool_value.as_string(), None);
     }
 
+    #[test]
+    fn test_color_hex_round_trip() {
+        let color = Color::rgba(1.0, 0.2, 0.0, 0.5);
+        let hex = color_hex::to_hex(&color);
+        assert_eq!(hex, "#ff330080");
+        assert_eq!(color_hex::to_hex(&Color::rgb(0.0, 0.0, 1.0)), "#0000ff");
+
+        let parsed = color_hex::parse(&hex).unwrap();
+        assert!((parsed.g - 0.2).abs() < 1.0 / 255.0);
+        assert!((parsed.a - 0.5).abs() < 1.0 / 255.0);
+        assert!(color_hex::parse("#12345").is_none());
+        assert!(color_hex::parse("#gg0000").is_none());
+    }
+
+    #[test]
+    fn test_theme_value_lerp() {
+        let black = ThemeValue::Color(Color::rgb(0.0, 0.0, 0.0));
+        let white = ThemeValue::Color(Color::rgb(1.0, 1.0, 1.0));
+        assert_eq!(
+            black.lerp(&white, 0.25),
+            ThemeValue::Color(Color::rgb(0.25, 0.25, 0.25))
+        );
+        assert_eq!(
+            ThemeValue::Number(10.0).lerp(&ThemeValue::Number(20.0), 0.5),
+            ThemeValue::Number(15.0)
+        );
+
+        let on = ThemeValue::Boolean(true);
+        let off = ThemeValue::Boolean(false);
+        assert_eq!(on.lerp(&off, 0.49), on);
+        assert_eq!(on.lerp(&off, 0.5), off);
+    }
+
     #[test]
     fn test_component_type_equality() {
         assert_eq!(ComponentType::ScatterPlot, ComponentType::ScatterPlot);
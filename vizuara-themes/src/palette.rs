@@ -62,6 +62,107 @@ impl ColorPalette {
         }
     }
 
+    /// Okabe-Ito 色盲友好分类调色板（8 色）
+    ///
+    /// 顺序：黑、橙、天蓝、蓝绿、黄、蓝、朱红、红紫。
+    pub fn okabe_ito() -> Self {
+        let series = colors_from_hex(&[
+            0x000000, 0xE69F00, 0x56B4E9, 0x009E73, 0xF0E442, 0x0072B2, 0xD55E00, 0xCC79A7,
+        ]);
+        Self::new("Okabe-Ito", "色盲友好的 8 色分类调色板")
+            .with_primary(series[5])
+            .with_secondary(series[1])
+            .with_accent(series[6])
+            .with_series(series)
+    }
+
+    /// ColorBrewer Blues 顺序色阶（浅到深，n 个颜色）
+    pub fn color_brewer_blues(n: usize) -> Self {
+        Self::color_brewer(
+            "Blues",
+            &[
+                0xF7FBFF, 0xDEEBF7, 0xC6DBEF, 0x9ECAE1, 0x6BAED6, 0x4292C6, 0x2171B5, 0x08519C,
+                0x08306B,
+            ],
+            n,
+        )
+    }
+
+    /// ColorBrewer Greens 顺序色阶（浅到深，n 个颜色）
+    pub fn color_brewer_greens(n: usize) -> Self {
+        Self::color_brewer(
+            "Greens",
+            &[
+                0xF7FCF5, 0xE5F5E0, 0xC7E9C0, 0xA1D99B, 0x74C476, 0x41AB5D, 0x238B45, 0x006D2C,
+                0x00441B,
+            ],
+            n,
+        )
+    }
+
+    /// ColorBrewer RdBu 发散色阶（红到蓝，n 个颜色）
+    pub fn color_brewer_rd_bu(n: usize) -> Self {
+        Self::color_brewer(
+            "RdBu",
+            &[
+                0x67001F, 0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xF7F7F7, 0xD1E5F0, 0x92C5DE,
+                0x4393C3, 0x2166AC, 0x053061,
+            ],
+            n,
+        )
+    }
+
+    /// ColorBrewer PuOr 发散色阶（橙到紫，n 个颜色）
+    pub fn color_brewer_pu_or(n: usize) -> Self {
+        Self::color_brewer(
+            "PuOr",
+            &[
+                0x7F3B08, 0xB35806, 0xE08214, 0xFDB863, 0xFEE0B6, 0xF7F7F7, 0xD8DAEB, 0xB2ABD2,
+                0x8073AC, 0x542788, 0x2D004B,
+            ],
+            n,
+        )
+    }
+
+    /// 由 ColorBrewer 最大分级色表构建调色板
+    ///
+    /// n 等于色表长度时与 ColorBrewer 完全一致，其余情况沿色阶均匀插值。
+    fn color_brewer(name: &str, ramp: &[u32], n: usize) -> Self {
+        let anchors = colors_from_hex(ramp);
+        let series = sample_ramp(&anchors, n);
+        let primary = anchors[anchors.len() * 3 / 4];
+
+        Self::new(
+            format!("ColorBrewer {}", name),
+            format!("ColorBrewer {} 色盲友好色阶", name),
+        )
+        .with_primary(primary)
+        .with_series(series)
+    }
+
+    /// 两个颜色之间的感知距离（CIE76 ΔE，基于 CIELAB）
+    ///
+    /// ΔE 约小于 10 时，相邻系列在图表中通常难以区分。
+    pub fn delta_e(a: Color, b: Color) -> f32 {
+        let (l1, a1, b1) = color_to_lab(a);
+        let (l2, a2, b2) = color_to_lab(b);
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// 找出感知距离小于阈值的相邻系列颜色
+    ///
+    /// 返回 `(i, i + 1, ΔE)`，为空表示所有相邻系列都可区分。
+    pub fn indistinguishable_pairs(&self, min_delta_e: f32) -> Vec<(usize, usize, f32)> {
+        self.series
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let distance = Self::delta_e(pair[0], pair[1]);
+                (distance < min_delta_e).then_some((i, i + 1, distance))
+            })
+            .collect()
+    }
+
     /// 设置主要颜色
     pub fn with_primary(mut self, color: Color) -> Self {
         self.primary = color;
@@ -292,6 +393,68 @@ impl ColorPalette {
     }
 }
 
+/// 由 0xRRGGBB 整数列表创建颜色
+fn colors_from_hex(values: &[u32]) -> Vec<Color> {
+    values
+        .iter()
+        .map(|&v| {
+            Color::rgb(
+                ((v >> 16) & 0xFF) as f32 / 255.0,
+                ((v >> 8) & 0xFF) as f32 / 255.0,
+                (v & 0xFF) as f32 / 255.0,
+            )
+        })
+        .collect()
+}
+
+/// 沿色阶均匀采样 n 个颜色（线性插值）
+fn sample_ramp(anchors: &[Color], n: usize) -> Vec<Color> {
+    match n {
+        0 => vec![],
+        1 => vec![anchors[anchors.len() / 2]],
+        _ => (0..n)
+            .map(|i| {
+                let position = i as f32 / (n - 1) as f32 * (anchors.len() - 1) as f32;
+                let index = (position.floor() as usize).min(anchors.len() - 2);
+                let t = position - index as f32;
+                let (from, to) = (anchors[index], anchors[index + 1]);
+                Color::rgb(
+                    from.r + (to.r - from.r) * t,
+                    from.g + (to.g - from.g) * t,
+                    from.b + (to.b - from.b) * t,
+                )
+            })
+            .collect(),
+    }
+}
+
+/// sRGB 颜色转 CIELAB（D65 白点）
+fn color_to_lab(color: Color) -> (f32, f32, f32) {
+    let linear = |c: f32| {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = (linear(color.r), linear(color.g), linear(color.b));
+
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+    let f = |t: f32| {
+        if t > 0.008856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
 /// HSV 转 RGB 实用函数
 fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color {
     let h = h % 360.0;
@@ -426,6 +589,50 @@ mod tests {
         assert_eq!(colors[2], palette.series[2]);
     }
 
+    #[test]
+    fn test_colorblind_palettes() {
+        let okabe_ito = ColorPalette::okabe_ito();
+        assert_eq!(okabe_ito.series_count(), 8);
+        assert_eq!(
+            okabe_ito.series[1],
+            ColorPalette::parse_hex_color("#E69F00").unwrap()
+        );
+        assert!(okabe_ito.indistinguishable_pairs(10.0).is_empty());
+
+        let blues = ColorPalette::color_brewer_blues(9);
+        assert_eq!(
+            blues.series[0],
+            ColorPalette::parse_hex_color("#F7FBFF").unwrap()
+        );
+        assert_eq!(
+            blues.series[8],
+            ColorPalette::parse_hex_color("#08306B").unwrap()
+        );
+
+        // 奇数个发散色阶的中点是中性色
+        let rd_bu = ColorPalette::color_brewer_rd_bu(5);
+        assert_eq!(rd_bu.series_count(), 5);
+        assert_eq!(
+            rd_bu.series[2],
+            ColorPalette::parse_hex_color("#F7F7F7").unwrap()
+        );
+        assert!(ColorPalette::color_brewer_greens(0).series.is_empty());
+    }
+
+    #[test]
+    fn test_delta_e_warning() {
+        assert!(ColorPalette::delta_e(Color::rgb(0.0, 0.0, 0.0), Color::rgb(1.0, 1.0, 1.0)) > 99.0);
+
+        let palette = ColorPalette::new("Test", "Test").with_series(vec![
+            Color::rgb(0.2, 0.4, 0.8),
+            Color::rgb(0.21, 0.41, 0.8),
+            Color::rgb(0.9, 0.5, 0.1),
+        ]);
+        let pairs = palette.indistinguishable_pairs(10.0);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].0, pairs[0].1), (0, 1));
+    }
+
     #[test]
     fn test_heatmap_palette() {
         let palette = ColorPalette::new("Test", "Test");
@@ -1,6 +1,8 @@
-use crate::{ComponentType, ThemeError, ThemeProperty, ThemeResult, ThemeValue};
+use crate::{lerp_values, ComponentType, ThemeError, ThemeProperty, ThemeResult, ThemeValue};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 use vizuara_core::{Color, Style};
 
 /// 主题定义
@@ -89,6 +91,16 @@ impl Theme {
         self.custom.get(key)
     }
 
+    /// 获取系列颜色（依次读取 series_color_0、series_color_1 ……）
+    pub fn series_colors(&self) -> Vec<Color> {
+        (0..)
+            .map_while(|i| {
+                self.get_custom(&format!("series_color_{}", i))
+                    .and_then(|v| v.as_color())
+            })
+            .collect()
+    }
+
     /// 将主题应用到样式
     pub fn apply_to_style(&self, component_type: &ComponentType, base_style: Style) -> Style {
         let mut style = base_style;
@@ -104,6 +116,13 @@ impl Theme {
             }
         }
 
+        if let Some(shadow) = self
+            .get_global(&ThemeProperty::Shadow)
+            .and_then(|v| v.as_shadow())
+        {
+            style = style.shadow(shadow);
+        }
+
         // 应用组件特定样式
         if let Some(component_theme) = self.get_component(component_type) {
             style = component_theme.apply_to_style(style);
@@ -161,6 +180,64 @@ impl Theme {
         Color::rgb(0.2, 0.2, 0.2) // 默认深灰色文本
     }
 
+    /// 在两个主题之间插值，用于主题的平滑过渡
+    ///
+    /// 全局属性、自定义属性和组件样式（按组件类型递归）逐项插值；
+    /// 只在一个主题中存在的属性原样保留。名称等元数据在 t = 0.5 处切换。
+    pub fn lerp(&self, other: &Theme, t: f32) -> Theme {
+        let t = t.clamp(0.0, 1.0);
+        let base = if t < 0.5 { self } else { other };
+
+        let mut components = other.components.clone();
+        for (component_type, component) in &self.components {
+            let component = match other.components.get(component_type) {
+                Some(target) => component.lerp(target, t),
+                None => component.clone(),
+            };
+            components.insert(component_type.clone(), component);
+        }
+
+        Theme {
+            name: base.name.clone(),
+            description: base.description.clone(),
+            version: base.version.clone(),
+            parent: base.parent.clone(),
+            author: base.author.clone(),
+            components,
+            globals: lerp_values(&self.globals, &other.globals, t),
+            custom: lerp_values(&self.custom, &other.custom, t),
+        }
+    }
+
+    /// 从 JSON 字符串解析主题
+    pub fn from_json_str(json: &str) -> ThemeResult<Self> {
+        serde_json::from_str(json).map_err(|e| ThemeError::ParseError(e.to_string()))
+    }
+
+    /// 将主题序列化为 JSON 字符串（颜色写为十六进制）
+    pub fn to_json_str(&self) -> ThemeResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ThemeError::ParseError(e.to_string()))
+    }
+
+    /// 从 JSON 文件加载主题，并验证其有效性
+    pub fn from_json_file(path: impl AsRef<Path>) -> ThemeResult<Self> {
+        let content = fs::read_to_string(path.as_ref())
+            .map_err(|e| ThemeError::IoError(format!("读取主题文件失败: {}", e)))?;
+
+        let theme = Self::from_json_str(&content)?;
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// 验证主题后保存为 JSON 文件
+    pub fn to_json_file(&self, path: impl AsRef<Path>) -> ThemeResult<()> {
+        self.validate()?;
+
+        let content = self.to_json_str()?;
+        fs::write(path.as_ref(), content)
+            .map_err(|e| ThemeError::IoError(format!("写入主题文件失败: {}", e)))
+    }
+
     /// 验证主题配置的有效性
     pub fn validate(&self) -> ThemeResult<()> {
         if self.name.is_empty() {
@@ -252,6 +329,28 @@ impl ComponentTheme {
         self.states.get(state)?.get(property)
     }
 
+    /// 在两个组件主题之间插值（包括各状态样式）
+    pub fn lerp(&self, other: &ComponentTheme, t: f32) -> ComponentTheme {
+        let mut states = other.states.clone();
+        for (state, properties) in &self.states {
+            let properties = match other.states.get(state) {
+                Some(target) => lerp_values(properties, target, t),
+                None => properties.clone(),
+            };
+            states.insert(state.clone(), properties);
+        }
+
+        ComponentTheme {
+            name: if t < 0.5 {
+                self.name.clone()
+            } else {
+                other.name.clone()
+            },
+            properties: lerp_values(&self.properties, &other.properties, t),
+            states,
+        }
+    }
+
     /// 将组件主题应用到样式
     pub fn apply_to_style(&self, mut style: Style) -> Style {
         // 应用填充颜色
@@ -265,6 +364,14 @@ impl ComponentTheme {
             style = style.stroke(color, width);
         }
 
+        // 应用投影
+        if let Some(shadow) = self
+            .get_property(&ThemeProperty::Shadow)
+            .and_then(|v| v.as_shadow())
+        {
+            style = style.shadow(shadow);
+        }
+
         style
     }
 
@@ -315,6 +422,52 @@ mod tests {
         assert_eq!(theme.author, Some("Test Author".to_string()));
     }
 
+    #[test]
+    fn test_series_colors() {
+        let mut theme = Theme::new("Test", "Test");
+        assert!(theme.series_colors().is_empty());
+
+        theme.set_custom(
+            "series_color_0",
+            ThemeValue::Color(Color::rgb(1.0, 0.0, 0.0)),
+        );
+        theme.set_custom(
+            "series_color_1",
+            ThemeValue::Color(Color::rgb(0.0, 1.0, 0.0)),
+        );
+        theme.set_custom(
+            "series_color_3",
+            ThemeValue::Color(Color::rgb(0.0, 0.0, 1.0)),
+        );
+
+        // 序号中断处停止
+        assert_eq!(
+            theme.series_colors(),
+            vec![Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 1.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_shadow_property_applies_to_style() {
+        let mut theme = Theme::new("Test", "Test");
+        let base = Style::new();
+        assert_eq!(
+            theme
+                .apply_to_style(&ComponentType::BarPlot, base.clone())
+                .shadow,
+            None
+        );
+
+        let color = Color::rgba(0.0, 0.0, 0.0, 0.5);
+        theme.set_global(ThemeProperty::Shadow, ThemeValue::Color(color));
+        let shadow = theme
+            .apply_to_style(&ComponentType::BarPlot, base)
+            .shadow
+            .unwrap();
+        assert_eq!(shadow.color, color);
+        assert_eq!(ThemeValue::Boolean(false).as_shadow(), None);
+    }
+
     #[test]
     fn test_theme_global_properties() {
         let mut theme = Theme::new("Test", "Test");
@@ -420,4 +573,84 @@ mod tests {
             Color::rgb(0.0, 1.0, 0.0)
         );
     }
+
+    #[test]
+    fn test_json_round_trip() {
+        let theme = crate::ThemePresets::dark();
+        let json = theme.to_json_str().unwrap();
+        assert!(json.contains("\"#"));
+
+        let loaded = Theme::from_json_str(&json).unwrap();
+        assert_eq!(loaded.name, theme.name);
+        assert_eq!(loaded.globals.len(), theme.globals.len());
+
+        let original = theme.get_background_color();
+        let restored = loaded.get_background_color();
+        assert!((original.r - restored.r).abs() <= 1.0 / 255.0);
+    }
+
+    #[test]
+    fn test_json_errors() {
+        assert!(matches!(
+            Theme::from_json_str("{ not json"),
+            Err(ThemeError::ParseError(_))
+        ));
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let missing = temp_dir.path().join("missing.json");
+        assert!(matches!(
+            Theme::from_json_file(&missing),
+            Err(ThemeError::IoError(_))
+        ));
+
+        let path = temp_dir.path().join("theme.json");
+        crate::ThemePresets::scientific()
+            .to_json_file(&path)
+            .unwrap();
+        assert!(Theme::from_json_file(&path).is_ok());
+    }
+
+    #[test]
+    fn test_theme_lerp() {
+        let mut light = Theme::new("light", "Light");
+        light.set_global(
+            ThemeProperty::BackgroundColor,
+            ThemeValue::Color(Color::rgb(1.0, 1.0, 1.0)),
+        );
+        light.set_global(ThemeProperty::FontSize, ThemeValue::Number(12.0));
+        light.add_component(
+            ComponentType::ScatterPlot,
+            ComponentTheme::new("ScatterPlot").with_primary_color(Color::rgb(1.0, 0.0, 0.0)),
+        );
+
+        let mut dark = Theme::new("dark", "Dark");
+        dark.set_global(
+            ThemeProperty::BackgroundColor,
+            ThemeValue::Color(Color::rgb(0.0, 0.0, 0.0)),
+        );
+        dark.set_global(ThemeProperty::LineWidth, ThemeValue::Number(2.0));
+        dark.add_component(
+            ComponentType::ScatterPlot,
+            ComponentTheme::new("ScatterPlot").with_primary_color(Color::rgb(0.0, 0.0, 1.0)),
+        );
+
+        let mid = light.lerp(&dark, 0.5);
+        assert_eq!(mid.name, "dark");
+        assert_eq!(mid.get_background_color(), Color::rgb(0.5, 0.5, 0.5));
+        // 只在一侧存在的属性保留
+        assert_eq!(
+            mid.get_global(&ThemeProperty::FontSize),
+            Some(&ThemeValue::Number(12.0))
+        );
+        assert_eq!(
+            mid.get_global(&ThemeProperty::LineWidth),
+            Some(&ThemeValue::Number(2.0))
+        );
+        assert_eq!(
+            mid.get_primary_color(&ComponentType::ScatterPlot),
+            Color::rgb(0.5, 0.0, 0.5)
+        );
+
+        assert_eq!(light.lerp(&dark, 0.0).name, "light");
+    }
 }
@@ -0,0 +1,117 @@
+// 点精灵着色器：每个实例一个四边形，在片段着色器中按符号距离场绘制标记形状
+struct Instance {
+    @location(0) center: vec2<f32>,
+    @location(1) extent: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) radius: f32,
+    @location(4) shape: u32,
+    @location(5) stroke_color: vec4<f32>,
+    @location(6) stroke_width: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    // 相对标记中心的像素坐标（y 向下）
+    @location(1) local: vec2<f32>,
+    @location(2) radius: f32,
+    @location(3) @interpolate(flat) shape: u32,
+    @location(4) stroke_color: vec4<f32>,
+    @location(5) stroke_width: f32,
+}
+
+// 四边形向外多留的像素，容纳抗锯齿边缘
+const AA_MARGIN: f32 = 1.0;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, instance: Instance) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+    );
+    let corner = corners[vertex_index];
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(instance.center + corner * instance.extent, 0.0, 1.0);
+    out.color = instance.color;
+    // NDC 的 y 向上，像素坐标的 y 向下；居中的描边有一半位于形状之外
+    out.local = vec2<f32>(corner.x, -corner.y)
+        * (instance.radius + instance.stroke_width * 0.5 + AA_MARGIN);
+    out.radius = instance.radius;
+    out.shape = instance.shape;
+    out.stroke_color = instance.stroke_color;
+    out.stroke_width = instance.stroke_width;
+    return out;
+}
+
+fn sd_box(p: vec2<f32>, half_size: vec2<f32>) -> f32 {
+    let d = abs(p) - half_size;
+    return length(max(d, vec2<f32>(0.0))) + min(max(d.x, d.y), 0.0);
+}
+
+// 顶点朝上的正三角形，r 为半边长
+fn sd_triangle(p_in: vec2<f32>, r: f32) -> f32 {
+    let k = sqrt(3.0);
+    var p = vec2<f32>(abs(p_in.x) - r, -p_in.y + r / k);
+    if (p.x + k * p.y > 0.0) {
+        p = vec2<f32>(p.x - k * p.y, -k * p.x - p.y) / 2.0;
+    }
+    p.x = p.x - clamp(p.x, -2.0 * r, 0.0);
+    return -length(p) * sign(p.y);
+}
+
+fn sd_plus(p: vec2<f32>, r: f32) -> f32 {
+    let t = r * 0.3;
+    return min(sd_box(p, vec2<f32>(r, t)), sd_box(p, vec2<f32>(t, r)));
+}
+
+// shape 与 MarkerStyle 的顺序一致：0 圆 1 方 2 三角 3 叉 4 加号 5 菱形
+fn marker_distance(p: vec2<f32>, r: f32, shape: u32) -> f32 {
+    var d: f32;
+    switch shape {
+        case 1u: {
+            d = sd_box(p, vec2<f32>(r));
+        }
+        case 2u: {
+            d = sd_triangle(p, r);
+        }
+        case 3u: {
+            let s = 0.70710678;
+            d = sd_plus(vec2<f32>(p.x + p.y, p.y - p.x) * s, r);
+        }
+        case 4u: {
+            d = sd_plus(p, r);
+        }
+        case 5u: {
+            d = (abs(p.x) + abs(p.y) - r) * 0.70710678;
+        }
+        default: {
+            d = length(p) - r;
+        }
+    }
+    return d;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // 距离以像素为单位，边缘一个像素内线性过渡
+    let d = marker_distance(in.local, in.radius, in.shape);
+    let fill_alpha = in.color.a * clamp(0.5 - d, 0.0, 1.0);
+    // 描边是以形状边缘为中心、宽 stroke_width 的环带
+    var stroke_alpha = 0.0;
+    if (in.stroke_width > 0.0) {
+        stroke_alpha = in.stroke_color.a * clamp(0.5 - (abs(d) - in.stroke_width * 0.5), 0.0, 1.0);
+    }
+
+    // 描边叠加在填充之上
+    let alpha = stroke_alpha + fill_alpha * (1.0 - stroke_alpha);
+    if (alpha <= 0.0) {
+        discard;
+    }
+    let rgb = (in.stroke_color.rgb * stroke_alpha + in.color.rgb * fill_alpha * (1.0 - stroke_alpha)) / alpha;
+    return vec4<f32>(rgb, alpha);
+}
@@ -45,7 +45,7 @@ struct Material {
     ao: f32,
     _padding1: vec2<f32>,
     emissive: vec3<f32>,
-    _padding2: f32,
+    alpha: f32, // 不透明度，占用 emissive 之后的对齐空位
 }
 
 // 光照统一缓冲区（头部 32 字节，lights 数组 8*80 = 640 字节，总共 672 字节）
@@ -225,5 +225,5 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     final_color = final_color / (final_color + vec3<f32>(1.0, 1.0, 1.0));
     final_color = pow(final_color, vec3<f32>(1.0/2.2, 1.0/2.2, 1.0/2.2));
     
-    return vec4<f32>(final_color, 1.0);
+    return vec4<f32>(final_color, material.alpha);
 }
@@ -2,16 +2,20 @@
 //!
 //! 使用 WGPU 提供高性能的 GPU 渲染功能
 
+mod point_sprite;
+pub mod present;
 pub mod renderer;
 pub mod renderer_3d;
 #[cfg(feature = "lit3d")]
 pub mod renderer_3d_lit;
 pub mod shader;
+mod text_cache;
 pub mod vertex;
 
-pub use renderer::WgpuRenderer;
+pub use present::select_present_mode;
+pub use renderer::{resolve_style, WgpuRenderer};
 pub use renderer_3d::{Vertex3D, Wgpu3DRenderer};
 #[cfg(feature = "lit3d")]
-pub use renderer_3d_lit::{Vertex3DLit, Wgpu3DLitRenderer};
+pub use renderer_3d_lit::{RenderMode, Vertex3DLit, Wgpu3DLitRenderer};
 pub use shader::*;
 pub use vertex::*;
@@ -0,0 +1,253 @@
+//! 点精灵：点标记以实例化四边形绘制，形状由片段着色器中的符号距离场决定
+//!
+//! 边缘按像素距离做抗锯齿，任意大小都清晰；切换形状只是着色器中的分支，
+//! 每个点只占一个实例，适合大量散点。描边沿形状边缘居中绘制，叠加在填充之上
+
+use bytemuck::{Pod, Zeroable};
+use std::ops::Range;
+use vizuara_core::MarkerStyle;
+
+/// 四边形向外多留的像素，容纳抗锯齿边缘（与着色器中的 AA_MARGIN 一致）
+const AA_MARGIN: f32 = 1.0;
+
+/// 点精灵实例
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub(crate) struct PointSprite {
+    /// 中心（NDC）
+    center: [f32; 2],
+    /// 四边形的半宽、半高（NDC），含抗锯齿边距
+    extent: [f32; 2],
+    color: [f32; 4],
+    /// 标记半径（像素）
+    radius: f32,
+    /// 形状编号，见 [`shape_index`]
+    shape: u32,
+    stroke_color: [f32; 4],
+    /// 描边宽度（像素），0 表示不描边
+    stroke_width: f32,
+}
+
+impl PointSprite {
+    const ATTRIBS: [wgpu::VertexAttribute; 7] = wgpu::vertex_attr_array![
+        0 => Float32x2, // center
+        1 => Float32x2, // extent
+        2 => Float32x4, // color
+        3 => Float32,   // radius
+        4 => Uint32,    // shape
+        5 => Float32x4, // stroke_color
+        6 => Float32,   // stroke_width
+    ];
+
+    /// 由像素坐标的中心与半径创建，`viewport` 为渲染目标的像素尺寸；
+    /// `stroke` 为描边颜色与宽度，填充色 alpha 为 0 时只绘制描边（空心标记）
+    pub(crate) fn new(
+        center: (f32, f32),
+        radius: f32,
+        shape: MarkerStyle,
+        color: [f32; 4],
+        stroke: Option<([f32; 4], f32)>,
+        viewport: (f32, f32),
+    ) -> Self {
+        let (width, height) = viewport;
+        let (stroke_color, stroke_width) = stroke
+            .filter(|&(_, width)| width > 0.0)
+            .unwrap_or(([0.0; 4], 0.0));
+        // 居中的描边有一半位于形状之外
+        let half = radius + stroke_width / 2.0 + AA_MARGIN;
+        Self {
+            center: [center.0 / width * 2.0 - 1.0, 1.0 - center.1 / height * 2.0],
+            extent: [half * 2.0 / width, half * 2.0 / height],
+            color,
+            radius,
+            shape: shape_index(shape),
+            stroke_color,
+            stroke_width,
+        }
+    }
+
+    /// 实例缓冲区布局
+    pub(crate) fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<PointSprite>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+/// 标记形状在着色器中的编号
+fn shape_index(shape: MarkerStyle) -> u32 {
+    match shape {
+        MarkerStyle::Circle => 0,
+        MarkerStyle::Square => 1,
+        MarkerStyle::Triangle => 2,
+        MarkerStyle::Cross => 3,
+        MarkerStyle::Plus => 4,
+        MarkerStyle::Diamond => 5,
+    }
+}
+
+/// 一帧中的点精灵及其与三角形之间的绘制顺序
+///
+/// 精灵使用单独的管线；为保持与其他图元的先后关系，按分段交替绘制，
+/// 每段先画三角形再画精灵
+#[derive(Debug, Default)]
+pub(crate) struct SpriteBatches {
+    sprites: Vec<PointSprite>,
+    /// 已结束分段的 (三角形顶点结束位置, 精灵结束位置)
+    breaks: Vec<(u32, u32)>,
+    /// 最近一个精灵加入时已生成的三角形顶点数
+    vertices_at_last_sprite: u32,
+}
+
+impl SpriteBatches {
+    /// 加入精灵；`vertex_count` 为此时已生成的三角形顶点数
+    pub(crate) fn push(&mut self, vertex_count: usize, sprite: PointSprite) {
+        let vertex_count = vertex_count as u32;
+        let segment_start = self.breaks.last().map_or(0, |&(_, end)| end);
+        // 上一个精灵之后又生成了三角形：这些三角形必须画在已有精灵之上，另起一段
+        if self.sprites.len() as u32 > segment_start && vertex_count > self.vertices_at_last_sprite
+        {
+            self.breaks
+                .push((self.vertices_at_last_sprite, self.sprites.len() as u32));
+        }
+        self.sprites.push(sprite);
+        self.vertices_at_last_sprite = vertex_count;
+    }
+
+    /// 全部精灵实例
+    pub(crate) fn sprites(&self) -> &[PointSprite] {
+        &self.sprites
+    }
+
+    /// 逐个变换精灵的填充与描边颜色
+    pub(crate) fn map_colors(&mut self, f: impl Fn([f32; 4]) -> [f32; 4]) {
+        for sprite in &mut self.sprites {
+            sprite.color = f(sprite.color);
+            sprite.stroke_color = f(sprite.stroke_color);
+        }
+    }
+
+    /// 按绘制顺序的分段：(三角形顶点范围, 精灵实例范围)
+    pub(crate) fn segments(&self, vertex_count: usize) -> Vec<(Range<u32>, Range<u32>)> {
+        let vertex_count = vertex_count as u32;
+        let sprite_count = self.sprites.len() as u32;
+        let mut ends = self.breaks.clone();
+        // 最后一个精灵之后生成的三角形画在这些精灵之上，单独成段
+        let segment_start = self.breaks.last().map_or(0, |&(_, end)| end);
+        if sprite_count > segment_start && vertex_count > self.vertices_at_last_sprite {
+            ends.push((self.vertices_at_last_sprite, sprite_count));
+        }
+        ends.push((vertex_count, sprite_count));
+
+        let mut start = (0, 0);
+        ends.into_iter()
+            .map(|end| {
+                let segment = (start.0..end.0, start.1..end.1);
+                start = end;
+                segment
+            })
+            .collect()
+    }
+}
+
+/// 创建点精灵管线：无顶点缓冲区，四边形顶点由 vertex_index 生成，启用 alpha 混合
+pub(crate) fn create_pipeline(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+) -> wgpu::RenderPipeline {
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Point Sprite Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/point_sprite.wgsl").into()),
+    });
+
+    let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("Point Sprite Pipeline Layout"),
+        bind_group_layouts: &[],
+        push_constant_ranges: &[],
+    });
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Point Sprite Pipeline"),
+        layout: Some(&layout),
+        vertex: wgpu::VertexState {
+            module: &shader,
+            entry_point: "vs_main",
+            buffers: &[PointSprite::desc()],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: &shader,
+            entry_point: "fs_main",
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            cull_mode: None,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: 1,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        multiview: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprite() -> PointSprite {
+        PointSprite::new(
+            (10.0, 10.0),
+            3.0,
+            MarkerStyle::Circle,
+            [1.0; 4],
+            None,
+            (100.0, 100.0),
+        )
+    }
+
+    #[test]
+    fn test_segments_without_sprites() {
+        let batches = SpriteBatches::default();
+        assert_eq!(batches.segments(6), vec![(0..6, 0..0)]);
+    }
+
+    #[test]
+    fn test_segments_trailing_triangles_drawn_last() {
+        // 三角形、精灵、三角形：后面的三角形必须画在精灵之上
+        let mut batches = SpriteBatches::default();
+        batches.push(3, sprite());
+        assert_eq!(batches.segments(9), vec![(0..3, 0..1), (3..9, 1..1)]);
+
+        // 精灵之后没有新三角形时只有一段
+        assert_eq!(batches.segments(3), vec![(0..3, 0..1)]);
+    }
+
+    #[test]
+    fn test_segments_interleaved() {
+        // 三角形 0..3、精灵 0..2、三角形 3..6、精灵 2、三角形 6..12
+        let mut batches = SpriteBatches::default();
+        batches.push(3, sprite());
+        batches.push(3, sprite());
+        batches.push(6, sprite());
+        assert_eq!(
+            batches.segments(12),
+            vec![(0..3, 0..2), (3..6, 2..3), (6..12, 3..3)]
+        );
+
+        // 以精灵开头：第一段没有三角形
+        let mut batches = SpriteBatches::default();
+        batches.push(0, sprite());
+        batches.push(6, sprite());
+        assert_eq!(batches.segments(6), vec![(0..0, 0..1), (0..6, 1..2)]);
+    }
+}
@@ -0,0 +1,29 @@
+//! 呈现模式选择
+//!
+//! 2D 与 3D 渲染器共用同一套规则，创建表面与运行时切换时保持一致
+
+use wgpu::PresentMode;
+
+/// 在表面支持的呈现模式中选择
+///
+/// 指定的模式受支持时直接使用，否则给出警告并回退到所有平台都支持的 Fifo；
+/// 未指定时依次优先 Mailbox、Immediate、Fifo，以获得较低的延迟
+pub fn select_present_mode(
+    supported: &[PresentMode],
+    requested: Option<PresentMode>,
+) -> PresentMode {
+    match requested {
+        Some(mode) if supported.contains(&mode) => mode,
+        Some(mode) => {
+            eprintln!(
+                "⚠️  表面不支持呈现模式 {:?}（支持: {:?}），回退到 Fifo",
+                mode, supported
+            );
+            PresentMode::Fifo
+        }
+        None => [PresentMode::Mailbox, PresentMode::Immediate]
+            .into_iter()
+            .find(|mode| supported.contains(mode))
+            .unwrap_or(PresentMode::Fifo),
+    }
+}
@@ -1,5 +1,11 @@
+use crate::point_sprite::{self, PointSprite, SpriteBatches};
+use crate::present::select_present_mode;
+use crate::text_cache::{TextCache, DEFAULT_TEXT_CACHE_CAPACITY};
 use bytemuck::{Pod, Zeroable};
-use vizuara_core::{Color, HorizontalAlign, Primitive, Result, Style, VerticalAlign, VizuaraError};
+use vizuara_core::{
+    Color, GradientDirection, HorizontalAlign, LineCap, LineJoin, Primitive, Result, Shadow, Style,
+    VerticalAlign, VizuaraError,
+};
 use wgpu::util::DeviceExt;
 use winit::window::Window;
 //use nalgebra::Point2;
@@ -7,7 +13,6 @@ use glyphon::{
     Attrs, Buffer, Family, FontSystem, Metrics, Resolution, Shaping, SwashCache, TextArea,
     TextAtlas, TextBounds, TextRenderer, Wrap,
 };
-use std::collections::HashMap;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -22,29 +27,78 @@ impl Vertex {
     }
 }
 
+/// 文本缓存键：(content, size, h_align, v_align)
+type TextKey = (String, u32, u8, u8);
+
+/// 待绘制的文本，在 glyphon pass 中统一绘制
+struct TextItem {
+    content: String,
+    x: f32,
+    y: f32,
+    size: f32,
+    color: Color,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    rotation: f32,
+}
+
 /// WGPU 渲染器
 pub struct WgpuRenderer {
     _instance: wgpu::Instance,
-    _adapter: wgpu::Adapter,
+    adapter: wgpu::Adapter,
     device: wgpu::Device,
     queue: wgpu::Queue,
     config: wgpu::SurfaceConfiguration,
     size: winit::dpi::PhysicalSize<u32>,
     render_pipeline: wgpu::RenderPipeline,
+    // 点标记：SDF 点精灵
+    sprite_pipeline: wgpu::RenderPipeline,
     // 文本渲染
     font_system: FontSystem,
     swash_cache: SwashCache,
     text_atlas: TextAtlas,
     text_renderer: TextRenderer,
-    // 文本缓存：key=(content,size,h_align,v_align)，值=(Buffer, color)
-    text_cache: HashMap<(String, u32, u8, u8), Buffer>,
+    // 文本缓存：key=(content,size,h_align,v_align)，按最近使用淘汰
+    text_cache: TextCache<TextKey, Buffer>,
+    // 截图请求：下一次 `render` 在呈现前把交换链纹理复制到读回缓冲区
+    capture_requested: bool,
+    // 已复制、等待读回的截图
+    pending_capture: Option<PendingCapture>,
+    // 清屏颜色；None 表示透明背景（与导出选项的 background_color 含义一致）
+    clear_color: Option<Color>,
+    // 是否在线性空间中插值与混合颜色（仅 sRGB 表面生效）
+    linear_color_space: bool,
+}
+
+/// 第 `index` 个图元使用的样式：样式数量少于图元数量时以 `Style::default()` 兜底，
+/// 避免丢弃后续图元；导出时按同一规则补齐样式即可与窗口渲染一致
+pub fn resolve_style(styles: &[Style], index: usize) -> Style {
+    styles.get(index).cloned().unwrap_or_default()
+}
+
+/// 已复制到读回缓冲区、尚未映射的一帧
+struct PendingCapture {
+    buffer: wgpu::Buffer,
+    format: wgpu::TextureFormat,
+    unpadded_bytes_per_row: u32,
+    padded_bytes_per_row: u32,
+    height: u32,
 }
 
 impl WgpuRenderer {
-    /// 创建新的渲染器
+    /// 创建新的渲染器，自动选择呈现模式（见 [`select_present_mode`]）
     pub async fn new(
         window: &Window,
         size: winit::dpi::PhysicalSize<u32>,
+    ) -> Result<(Self, wgpu::Surface<'_>)> {
+        Self::new_with_present_mode(window, size, None).await
+    }
+
+    /// 创建新的渲染器并指定呈现模式；表面不支持该模式时回退到 Fifo
+    pub async fn new_with_present_mode(
+        window: &Window,
+        size: winit::dpi::PhysicalSize<u32>,
+        present_mode: Option<wgpu::PresentMode>,
     ) -> Result<(Self, wgpu::Surface<'_>)> {
         // 尝试不同后端以适配更多环境（优先 GL，再尝试 Vulkan）
         let backend_candidates = [
@@ -99,19 +153,7 @@ impl WgpuRenderer {
                 .find(|f| f.is_srgb())
                 .unwrap_or(surface_caps.formats[0]);
 
-            let present_mode = if surface_caps
-                .present_modes
-                .contains(&wgpu::PresentMode::Mailbox)
-            {
-                wgpu::PresentMode::Mailbox
-            } else if surface_caps
-                .present_modes
-                .contains(&wgpu::PresentMode::Immediate)
-            {
-                wgpu::PresentMode::Immediate
-            } else {
-                wgpu::PresentMode::Fifo
-            };
+            let present_mode = select_present_mode(&surface_caps.present_modes, present_mode);
 
             let alpha_mode = surface_caps
                 .alpha_modes
@@ -138,8 +180,12 @@ impl WgpuRenderer {
                 }
             };
 
+            // 表面支持时允许复制交换链纹理，用于截图
+            let usage = wgpu::TextureUsages::RENDER_ATTACHMENT
+                | (surface_caps.usages & wgpu::TextureUsages::COPY_SRC);
+
             let config = wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                usage,
                 format: surface_format,
                 width: size.width,
                 height: size.height,
@@ -154,6 +200,7 @@ impl WgpuRenderer {
 
             // 创建渲染管线
             let render_pipeline = Self::create_render_pipeline(&device, &config)?;
+            let sprite_pipeline = point_sprite::create_pipeline(&device, config.format);
 
             // 初始化文本渲染
             let mut font_system = FontSystem::new();
@@ -186,17 +233,22 @@ impl WgpuRenderer {
 
             let renderer = WgpuRenderer {
                 _instance: instance,
-                _adapter: adapter,
+                adapter,
                 device,
                 queue,
                 config,
                 size,
                 render_pipeline,
+                sprite_pipeline,
                 font_system,
                 swash_cache,
                 text_atlas,
                 text_renderer,
-                text_cache: HashMap::new(),
+                text_cache: TextCache::new(DEFAULT_TEXT_CACHE_CAPACITY),
+                capture_requested: false,
+                pending_capture: None,
+                clear_color: Some(Color::rgb(0.1, 0.1, 0.2)),
+                linear_color_space: true,
             };
 
             return Ok((renderer, surface));
@@ -253,7 +305,7 @@ impl WgpuRenderer {
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
                     format: config.format,
-                    blend: Some(wgpu::BlendState::REPLACE),
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
             }),
@@ -289,6 +341,11 @@ impl WgpuRenderer {
         &self.queue
     }
 
+    /// 获取当前渲染尺寸
+    pub fn size(&self) -> winit::dpi::PhysicalSize<u32> {
+        self.size
+    }
+
     /// 获取当前表面格式
     pub fn surface_format(&self) -> wgpu::TextureFormat {
         self.config.format
@@ -299,6 +356,71 @@ impl WgpuRenderer {
         surface.configure(&self.device, &self.config);
     }
 
+    /// 设置清屏（背景）颜色
+    pub fn set_clear_color(&mut self, color: Color) {
+        self.clear_color = Some(color);
+    }
+
+    /// 使用透明背景（alpha 为 0），用于叠加到其他界面（如 egui）之上
+    pub fn set_transparent_background(&mut self) {
+        self.clear_color = None;
+    }
+
+    /// 获取清屏颜色；None 表示透明背景
+    pub fn clear_color(&self) -> Option<Color> {
+        self.clear_color
+    }
+
+    /// 设置文本缓存容量（条目数），超出时淘汰最久未使用的文本
+    pub fn set_text_cache_capacity(&mut self, capacity: usize) {
+        self.text_cache.set_capacity(capacity);
+    }
+
+    /// 文本缓存容量
+    pub fn text_cache_capacity(&self) -> usize {
+        self.text_cache.capacity()
+    }
+
+    /// 当前缓存的文本条目数
+    pub fn text_cache_len(&self) -> usize {
+        self.text_cache.len()
+    }
+
+    /// 设置是否在线性空间中处理颜色（默认开启）
+    ///
+    /// 开启时图元颜色按 sRGB 编码解释，先转换为线性值再插值与混合，由 sRGB 表面完成
+    /// 最终编码；关闭时颜色原样写入。表面格式不是 sRGB 时始终原样写入
+    pub fn set_linear_color_space(&mut self, enabled: bool) {
+        self.linear_color_space = enabled;
+    }
+
+    /// 是否在线性空间中处理颜色
+    pub fn linear_color_space(&self) -> bool {
+        self.linear_color_space
+    }
+
+    /// 当前是否需要把颜色转换到线性空间
+    fn converts_to_linear(&self) -> bool {
+        self.linear_color_space && self.config.format.is_srgb()
+    }
+
+    fn wgpu_clear_color(&self) -> wgpu::Color {
+        let clear_color = if self.converts_to_linear() {
+            self.clear_color.map(|color| color.to_linear())
+        } else {
+            self.clear_color
+        };
+        match clear_color {
+            Some(color) => wgpu::Color {
+                r: color.r as f64,
+                g: color.g as f64,
+                b: color.b as f64,
+                a: color.a as f64,
+            },
+            None => wgpu::Color::TRANSPARENT,
+        }
+    }
+
     /// 调整窗口大小
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>, surface: &wgpu::Surface) {
         if new_size.width > 0 && new_size.height > 0 {
@@ -311,6 +433,25 @@ impl WgpuRenderer {
         }
     }
 
+    /// 切换呈现模式并重新配置表面，返回实际使用的模式
+    ///
+    /// Fifo 即垂直同步；Immediate/Mailbox 不等待垂直同步，适合性能测试与低延迟交互
+    pub fn set_present_mode(
+        &mut self,
+        present_mode: wgpu::PresentMode,
+        surface: &wgpu::Surface,
+    ) -> wgpu::PresentMode {
+        let supported = surface.get_capabilities(&self.adapter).present_modes;
+        self.config.present_mode = select_present_mode(&supported, Some(present_mode));
+        surface.configure(&self.device, &self.config);
+        self.config.present_mode
+    }
+
+    /// 当前的呈现模式
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.config.present_mode
+    }
+
     /// 在给定的视图上渲染（不获取/呈现交换链）。
     /// 典型用法：你的外部代码先获取 `SurfaceTexture` 和 `TextureView`，
     /// 使用该方法完成 Vizuara 的绘制，然后在同一帧上叠加 egui。
@@ -321,19 +462,35 @@ impl WgpuRenderer {
         styles: &[Style],
         encoder: &mut wgpu::CommandEncoder,
     ) -> Result<()> {
-        // 转换图元为顶点，同时收集文本
-        let mut texts: Vec<(String, f32, f32, f32, Color, HorizontalAlign, VerticalAlign)> =
-            Vec::new();
-        let vertices = self.primitives_to_vertices_collect_text(primitives, styles, &mut texts);
-
-        if !vertices.is_empty() {
-            let vertex_buffer = self
-                .device
-                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Vertex Buffer"),
-                    contents: bytemuck::cast_slice(&vertices),
-                    usage: wgpu::BufferUsages::VERTEX,
-                });
+        // 转换图元为顶点与点精灵，同时收集文本
+        let mut texts: Vec<TextItem> = Vec::new();
+        let mut sprites = SpriteBatches::default();
+        let mut vertices =
+            self.primitives_to_vertices_collect_text(primitives, styles, &mut texts, &mut sprites);
+        if self.converts_to_linear() {
+            for vertex in &mut vertices {
+                vertex.color = linear_rgba(vertex.color);
+            }
+            sprites.map_colors(linear_rgba);
+        }
+
+        if !vertices.is_empty() || !sprites.sprites().is_empty() {
+            let vertex_buffer = (!vertices.is_empty()).then(|| {
+                self.device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("Vertex Buffer"),
+                        contents: bytemuck::cast_slice(&vertices),
+                        usage: wgpu::BufferUsages::VERTEX,
+                    })
+            });
+            let sprite_buffer = (!sprites.sprites().is_empty()).then(|| {
+                self.device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("Point Sprite Buffer"),
+                        contents: bytemuck::cast_slice(sprites.sprites()),
+                        usage: wgpu::BufferUsages::VERTEX,
+                    })
+            });
 
             {
                 let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -342,12 +499,7 @@ impl WgpuRenderer {
                         view,
                         resolve_target: None,
                         ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color {
-                                r: 0.1,
-                                g: 0.1,
-                                b: 0.2,
-                                a: 1.0,
-                            }),
+                            load: wgpu::LoadOp::Clear(self.wgpu_clear_color()),
                             store: wgpu::StoreOp::Store,
                         },
                     })],
@@ -356,9 +508,19 @@ impl WgpuRenderer {
                     timestamp_writes: None,
                 });
 
-                render_pass.set_pipeline(&self.render_pipeline);
-                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-                render_pass.draw(0..vertices.len() as u32, 0..1);
+                // 按分段交替绘制三角形与点精灵，保持图元的先后顺序
+                for (triangles, instances) in sprites.segments(vertices.len()) {
+                    if let (Some(buffer), false) = (&vertex_buffer, triangles.is_empty()) {
+                        render_pass.set_pipeline(&self.render_pipeline);
+                        render_pass.set_vertex_buffer(0, buffer.slice(..));
+                        render_pass.draw(triangles, 0..1);
+                    }
+                    if let (Some(buffer), false) = (&sprite_buffer, instances.is_empty()) {
+                        render_pass.set_pipeline(&self.sprite_pipeline);
+                        render_pass.set_vertex_buffer(0, buffer.slice(..));
+                        render_pass.draw(0..6, instances);
+                    }
+                }
             }
 
             // 文本 pass：在已清屏并绘制图形后，加载颜色叠加文本
@@ -371,12 +533,7 @@ impl WgpuRenderer {
                     view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.1,
-                            g: 0.1,
-                            b: 0.2,
-                            a: 1.0,
-                        }),
+                        load: wgpu::LoadOp::Clear(self.wgpu_clear_color()),
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -427,102 +584,264 @@ impl WgpuRenderer {
         // 复用通用路径在视图上绘制
         self.render_to_view(&view, primitives, styles, &mut encoder)?;
 
+        if std::mem::take(&mut self.capture_requested) {
+            self.pending_capture = Some(self.copy_to_readback(&output.texture, &mut encoder));
+        }
+
         self.queue.submit(std::iter::once(encoder.finish()));
         output.present();
 
         Ok(())
     }
 
+    /// 请求截图：下一次 [`render`](Self::render) 会在呈现前复制交换链纹理，
+    /// 之后可用 [`capture_frame`](Self::capture_frame) 读回像素
+    ///
+    /// 只对 `render` 生效；使用 `render_to_view` 时纹理由调用方持有，需自行复制。
+    /// 表面不支持 COPY_SRC 时返回错误。
+    pub fn request_capture(&mut self) -> Result<()> {
+        if !self.config.usage.contains(wgpu::TextureUsages::COPY_SRC) {
+            return Err(VizuaraError::RenderError(
+                "Surface does not support copying frames (COPY_SRC)".to_string(),
+            ));
+        }
+        self.capture_requested = true;
+        Ok(())
+    }
+
+    /// 是否有已渲染、等待读回的截图
+    pub fn has_capture(&self) -> bool {
+        self.pending_capture.is_some()
+    }
+
+    /// 读回通过 [`request_capture`](Self::request_capture) 截取的一帧，
+    /// 返回 RGBA8 像素（行优先，无填充）
+    pub fn capture_frame(&mut self) -> Result<Vec<u8>> {
+        let capture = self.pending_capture.take().ok_or_else(|| {
+            VizuaraError::RenderError(
+                "No captured frame; call request_capture() and render a frame first".to_string(),
+            )
+        })?;
+
+        let slice = capture.buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .map_err(|e| VizuaraError::RenderError(format!("Capture readback failed: {}", e)))?
+            .map_err(|e| {
+                VizuaraError::RenderError(format!("Failed to map capture buffer: {}", e))
+            })?;
+
+        let pixels = {
+            let data = slice.get_mapped_range();
+            Self::unpad_to_rgba(
+                &data,
+                capture.format,
+                capture.unpadded_bytes_per_row as usize,
+                capture.padded_bytes_per_row as usize,
+                capture.height as usize,
+            )?
+        };
+        capture.buffer.unmap();
+
+        Ok(pixels)
+    }
+
+    /// 把交换链纹理复制到可映射的读回缓冲区（表面需支持 COPY_SRC）
+    fn copy_to_readback(
+        &self,
+        texture: &wgpu::Texture,
+        encoder: &mut wgpu::CommandEncoder,
+    ) -> PendingCapture {
+        let width = texture.width();
+        let height = texture.height();
+
+        // 每行字节数必须按 COPY_BYTES_PER_ROW_ALIGNMENT (256) 对齐
+        let unpadded_bytes_per_row = width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row =
+            unpadded_bytes_per_row + (align - unpadded_bytes_per_row % align) % align;
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Capture Readback Buffer"),
+            size: (padded_bytes_per_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size(),
+        );
+
+        PendingCapture {
+            buffer,
+            format: texture.format(),
+            unpadded_bytes_per_row,
+            padded_bytes_per_row,
+            height,
+        }
+    }
+
+    /// 去除行填充并转换为 RGBA8
+    ///
+    /// sRGB 格式读回的字节已经是 sRGB 编码值，与 PNG 的约定一致，只需调整通道顺序。
+    fn unpad_to_rgba(
+        data: &[u8],
+        format: wgpu::TextureFormat,
+        unpadded_bytes_per_row: usize,
+        padded_bytes_per_row: usize,
+        height: usize,
+    ) -> Result<Vec<u8>> {
+        let swap_red_blue = match format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            other => {
+                return Err(VizuaraError::RenderError(format!(
+                    "Unsupported surface format for capture: {:?}",
+                    other
+                )));
+            }
+        };
+
+        let mut pixels = Vec::with_capacity(unpadded_bytes_per_row * height);
+        for row in data.chunks(padded_bytes_per_row).take(height) {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row]);
+        }
+
+        if swap_red_blue {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        Ok(pixels)
+    }
+
     /// 绘制文本：使用 glyphon
     fn draw_texts(
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
         view: &wgpu::TextureView,
-        texts: &mut [(String, f32, f32, f32, Color, HorizontalAlign, VerticalAlign)],
+        texts: &mut [TextItem],
     ) -> Result<()> {
         if texts.is_empty() {
             return Ok(());
         }
 
-        // 第一阶段：确保缓存存在（只做插入，不持有引用，避免与后续不可变借用冲突）
-        let mut keys: Vec<(String, u32, u8, u8)> = Vec::with_capacity(texts.len());
-        for (content, _x, _y, size, _color, h, v) in texts.iter() {
-            let h_code = match h {
+        // 第一阶段：计算每个文本区域的位置，并确保缓存存在
+        // （只做插入，不持有引用，避免与后续不可变借用冲突）
+        self.text_cache.begin_frame();
+        // (缓存键, 字号, left, top, 颜色)
+        let mut placements: Vec<(TextKey, f32, f32, f32, Color)> = Vec::with_capacity(texts.len());
+        for text in texts.iter() {
+            let h_code = match text.h_align {
                 HorizontalAlign::Left => 0u8,
                 HorizontalAlign::Center => 1u8,
                 HorizontalAlign::Right => 2u8,
             };
-            let v_code = match v {
+            let v_code = match text.v_align {
                 VerticalAlign::Top => 0u8,
                 VerticalAlign::Middle => 1u8,
                 VerticalAlign::Baseline => 2u8,
                 VerticalAlign::Bottom => 3u8,
             };
-            let key = (content.clone(), (*size as u32), h_code, v_code);
-            if !self.text_cache.contains_key(&key) {
-                let mut buf = Buffer::new(&mut self.font_system, Metrics::new(*size, *size));
-                buf.set_size(
-                    &mut self.font_system,
-                    self.size.width as f32,
-                    self.size.height as f32,
-                );
+
+            // 简单锚点偏移：按字号估算 em 高度，左中右/上中下
+            let em = text.size; // 以 size 作为高度估计
+            let avg_w = if !text.content.is_ascii() {
+                text.size * 0.9
+            } else {
+                text.size * 0.6
+            };
+            let width_est = text.content.chars().count() as f32 * avg_w;
+            // 文本框左上角相对锚点的偏移
+            let dx = match text.h_align {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => -width_est / 2.0,
+                HorizontalAlign::Right => -width_est,
+            };
+            // 垂直（top 为文本行框的上边）
+            let dy = match text.v_align {
+                VerticalAlign::Top => 0.0,
+                VerticalAlign::Middle => -em / 2.0,
+                VerticalAlign::Baseline => -em * 0.8,
+                VerticalAlign::Bottom => -em,
+            };
+
+            if text.rotation == 0.0 {
+                let key = (text.content.clone(), text.size as u32, h_code, v_code);
+                placements.push((key, text.size, text.x + dx, text.y + dy, text.color));
+            } else {
+                // glyphon 不支持旋转字形：逐字符排版，把每个字符的中心放到
+                // 绕锚点旋转后的文本框内对应位置，字形本身保持直立
+                let (sin, cos) = text.rotation.to_radians().sin_cos();
+                for (i, ch) in text.content.chars().enumerate() {
+                    let cx = dx + (i as f32 + 0.5) * avg_w;
+                    let cy = dy + em / 2.0;
+                    let rx = cx * cos - cy * sin;
+                    let ry = cx * sin + cy * cos;
+                    let key = (ch.to_string(), text.size as u32, 0, 0);
+                    placements.push((
+                        key,
+                        text.size,
+                        text.x + rx - avg_w / 2.0,
+                        text.y + ry - em / 2.0,
+                        text.color,
+                    ));
+                }
+            }
+        }
+
+        for (key, size, _, _, _) in placements.iter() {
+            let size = *size;
+            let font_system = &mut self.font_system;
+            let (width, height) = (self.size.width as f32, self.size.height as f32);
+            self.text_cache.get_or_insert_with(key, || {
+                let mut buf = Buffer::new(font_system, Metrics::new(size, size));
+                buf.set_size(font_system, width, height);
                 buf.set_text(
-                    &mut self.font_system,
-                    content,
+                    font_system,
+                    &key.0,
                     Attrs::new().family(Family::SansSerif),
                     Shaping::Advanced,
                 );
-                buf.set_wrap(&mut self.font_system, Wrap::None);
-                self.text_cache.insert(key.clone(), buf);
-            }
-            keys.push(key);
+                buf.set_wrap(font_system, Wrap::None);
+                buf
+            });
         }
+        // 本帧用到的文本不会被淘汰
+        self.text_cache.trim();
 
         // 构造文本区域
         let to_u8 = |v: f32| -> u8 { (v.clamp(0.0, 1.0) * 255.0).round() as u8 };
         let mut areas: Vec<TextArea> = Vec::new();
-        for ((content, x, y, size, color, h, v), key) in texts.iter().zip(keys.iter()) {
+        for (key, _, left, top, color) in placements.iter() {
             let buf = self
                 .text_cache
                 .get(key)
                 .expect("text buffer must exist after first pass");
-            // 简单锚点偏移：按字号估算 em 高度，左中右/上中下
-            let em = *size; // 以 size 作为高度估计
-            let avg_w = if !content.is_ascii() {
-                *size * 0.9
-            } else {
-                *size * 0.6
-            };
-            let width_est = content.chars().count() as f32 * avg_w;
-            let mut left = *x;
-            let mut top = *y;
-            // 水平
-            match h {
-                HorizontalAlign::Left => {}
-                HorizontalAlign::Center => {
-                    left -= width_est / 2.0;
-                }
-                HorizontalAlign::Right => {
-                    left -= width_est;
-                }
-            }
-            // 垂直（top 为文本行框的上边）
-            match v {
-                VerticalAlign::Top => {}
-                VerticalAlign::Middle => {
-                    top -= em / 2.0;
-                }
-                VerticalAlign::Baseline => {
-                    top -= em * 0.8;
-                }
-                VerticalAlign::Bottom => {
-                    top -= em;
-                }
-            }
             areas.push(TextArea {
                 buffer: buf,
-                left,
-                top,
+                left: *left,
+                top: *top,
                 scale: 1.0,
                 bounds: TextBounds {
                     left: 0,
@@ -587,49 +906,116 @@ impl WgpuRenderer {
         Ok(())
     }
 
+    /// 像素坐标转换为 NDC
+    fn pixel_to_ndc(&self, (x, y): (f32, f32)) -> [f32; 2] {
+        [
+            (x / self.size.width as f32) * 2.0 - 1.0,
+            1.0 - (y / self.size.height as f32) * 2.0,
+        ]
+    }
+
+    /// 绘制矩形与圆形的投影：偏移后的形状按模糊采样多次叠加，其他图元忽略
+    fn push_shadow(
+        &self,
+        primitive: &Primitive,
+        shadow: &Shadow,
+        style: &Style,
+        vertices: &mut Vec<Vertex>,
+    ) {
+        let mut shape: Vec<(f32, f32)> = Vec::new();
+        match primitive {
+            Primitive::Rectangle { min, max } | Primitive::RectangleStyled { min, max, .. } => {
+                let (x0, y0) = (min.x.min(max.x), min.y.min(max.y));
+                let (x1, y1) = (min.x.max(max.x), min.y.max(max.y));
+                shape.extend_from_slice(&[
+                    (x0, y0),
+                    (x0, y1),
+                    (x1, y1),
+                    (x0, y0),
+                    (x1, y1),
+                    (x1, y0),
+                ]);
+            }
+            Primitive::Circle { center, radius } => {
+                let segments = arc_segments(*radius, std::f32::consts::TAU, style.circle_segments);
+                let point_at = |k: usize| {
+                    let angle = k as f32 * std::f32::consts::TAU / segments as f32;
+                    (
+                        center.x + radius * angle.cos(),
+                        center.y + radius * angle.sin(),
+                    )
+                };
+                for k in 0..segments {
+                    shape.extend_from_slice(&[(center.x, center.y), point_at(k), point_at(k + 1)]);
+                }
+            }
+            _ => return,
+        }
+
+        let samples = shadow_samples(shadow);
+        let alpha = shadow_sample_alpha(shadow.color.a * style.opacity, samples.len());
+        let color = [shadow.color.r, shadow.color.g, shadow.color.b, alpha];
+        for (dx, dy) in samples {
+            for &(x, y) in &shape {
+                vertices.push(Vertex::new(self.pixel_to_ndc((x + dx, y + dy)), color));
+            }
+        }
+    }
+
     /// 将图元转换为顶点数据，同时收集文本
     fn primitives_to_vertices_collect_text(
         &self,
         primitives: &[Primitive],
         styles: &[Style],
-        texts: &mut Vec<(String, f32, f32, f32, Color, HorizontalAlign, VerticalAlign)>,
+        texts: &mut Vec<TextItem>,
+        sprites: &mut SpriteBatches,
     ) -> Vec<Vertex> {
         let mut vertices = Vec::new();
-
-        for (i, primitive) in primitives.iter().enumerate() {
-            // 当样式数量少于图元数量时，使用默认样式兜底，避免丢弃后续图元
-            let style = styles.get(i).cloned().unwrap_or_else(Style::default);
+        let viewport = (self.size.width as f32, self.size.height as f32);
+
+        // 按 z 稳定排序：z 大的后绘制（位于前方），相同 z 保持插入顺序
+        let z_of = |i: usize| styles.get(i).map_or(0.0, |style| style.z);
+        let mut order: Vec<usize> = (0..primitives.len()).collect();
+        order.sort_by(|&a, &b| z_of(a).total_cmp(&z_of(b)));
+
+        for i in order {
+            let primitive = &primitives[i];
+            let style = resolve_style(styles, i);
+            // 投影先于图元本身绘制，位于其下方
+            if let Some(shadow) = &style.shadow {
+                self.push_shadow(primitive, shadow, &style, &mut vertices);
+            }
             match primitive {
                 Primitive::Point(point) => {
-                    // 将点渲染为小三角形
-                    let size = style.marker_size / 100.0; // 标准化大小
-                    let color = style.fill_color.unwrap_or(Color::BLUE);
-                    let color_array = [color.r, color.g, color.b, color.a * style.opacity];
-
-                    // 将数据坐标转换为 NDC 坐标 (-1 到 1)
-                    let x = (point.x / self.size.width as f32) * 2.0 - 1.0;
-                    let y = 1.0 - (point.y / self.size.height as f32) * 2.0;
-
-                    vertices.extend_from_slice(&[
-                        Vertex::new([x, y + size], color_array),
-                        Vertex::new([x - size, y - size], color_array),
-                        Vertex::new([x + size, y - size], color_array),
-                    ]);
+                    // 点标记绘制为 SDF 点精灵，marker_size 为像素半径
+                    let (color_array, stroke) = marker_colors(&style);
+                    sprites.push(
+                        vertices.len(),
+                        PointSprite::new(
+                            (point.x, point.y),
+                            style.marker_size,
+                            style.marker_style,
+                            color_array,
+                            stroke,
+                            viewport,
+                        ),
+                    );
                 }
                 Primitive::Points(points) => {
-                    let size = style.marker_size / 100.0;
-                    let color = style.fill_color.unwrap_or(Color::BLUE);
-                    let color_array = [color.r, color.g, color.b, color.a * style.opacity];
+                    let (color_array, stroke) = marker_colors(&style);
 
                     for point in points {
-                        let x = (point.x / self.size.width as f32) * 2.0 - 1.0;
-                        let y = 1.0 - (point.y / self.size.height as f32) * 2.0;
-
-                        vertices.extend_from_slice(&[
-                            Vertex::new([x, y + size], color_array),
-                            Vertex::new([x - size, y - size], color_array),
-                            Vertex::new([x + size, y - size], color_array),
-                        ]);
+                        sprites.push(
+                            vertices.len(),
+                            PointSprite::new(
+                                (point.x, point.y),
+                                style.marker_size,
+                                style.marker_style,
+                                color_array,
+                                stroke,
+                                viewport,
+                            ),
+                        );
                     }
                 }
                 Primitive::Line { start, end } => {
@@ -642,43 +1028,10 @@ impl WgpuRenderer {
 
                     // 线宽（像素）转换为偏移（像素）
                     let half_w = (style.stroke_width.max(1.0)) / 2.0;
-
-                    // 计算法线偏移（像素空间）
-                    let dx = end.x - start.x;
-                    let dy = end.y - start.y;
-                    let len = (dx * dx + dy * dy).sqrt().max(1e-6);
-                    let nx = -dy / len;
-                    let ny = dx / len;
-                    let ox = nx * half_w;
-                    let oy = ny * half_w;
-
-                    // 四个角（像素空间）
-                    let p0 = (start.x + ox, start.y + oy);
-                    let p1 = (end.x + ox, end.y + oy);
-                    let p2 = (end.x - ox, end.y - oy);
-                    let p3 = (start.x - ox, start.y - oy);
-
-                    // 像素转 NDC
-                    let to_ndc = |(x, y): (f32, f32)| -> [f32; 2] {
-                        let xn = (x / self.size.width as f32) * 2.0 - 1.0;
-                        let yn = 1.0 - (y / self.size.height as f32) * 2.0;
-                        [xn, yn]
-                    };
-
-                    let v0 = to_ndc(p0);
-                    let v1 = to_ndc(p1);
-                    let v2 = to_ndc(p2);
-                    let v3 = to_ndc(p3);
-
-                    // 两个三角形
-                    vertices.extend_from_slice(&[
-                        Vertex::new(v0, color_array),
-                        Vertex::new(v1, color_array),
-                        Vertex::new(v2, color_array),
-                        Vertex::new(v0, color_array),
-                        Vertex::new(v2, color_array),
-                        Vertex::new(v3, color_array),
-                    ]);
+                    let path = [(start.x, start.y), (end.x, end.y)];
+                    for p in stroke_triangles(&path, half_w, style.line_cap, style.line_join) {
+                        vertices.push(Vertex::new(self.pixel_to_ndc(p), color_array));
+                    }
                 }
                 Primitive::LineStrip(points) => {
                     if points.len() < 2 {
@@ -691,41 +1044,9 @@ impl WgpuRenderer {
                     let color_array = [color.r, color.g, color.b, color.a * style.opacity];
                     let half_w = (style.stroke_width.max(1.0)) / 2.0;
 
-                    let to_ndc = |(x, y): (f32, f32)| -> [f32; 2] {
-                        let xn = (x / self.size.width as f32) * 2.0 - 1.0;
-                        let yn = 1.0 - (y / self.size.height as f32) * 2.0;
-                        [xn, yn]
-                    };
-
-                    for seg in points.windows(2) {
-                        let a = seg[0];
-                        let b = seg[1];
-                        let dx = b.x - a.x;
-                        let dy = b.y - a.y;
-                        let len = (dx * dx + dy * dy).sqrt().max(1e-6);
-                        let nx = -dy / len;
-                        let ny = dx / len;
-                        let ox = nx * half_w;
-                        let oy = ny * half_w;
-
-                        let p0 = (a.x + ox, a.y + oy);
-                        let p1 = (b.x + ox, b.y + oy);
-                        let p2 = (b.x - ox, b.y - oy);
-                        let p3 = (a.x - ox, a.y - oy);
-
-                        let v0 = to_ndc(p0);
-                        let v1 = to_ndc(p1);
-                        let v2 = to_ndc(p2);
-                        let v3 = to_ndc(p3);
-
-                        vertices.extend_from_slice(&[
-                            Vertex::new(v0, color_array),
-                            Vertex::new(v1, color_array),
-                            Vertex::new(v2, color_array),
-                            Vertex::new(v0, color_array),
-                            Vertex::new(v2, color_array),
-                            Vertex::new(v3, color_array),
-                        ]);
+                    let path: Vec<(f32, f32)> = points.iter().map(|p| (p.x, p.y)).collect();
+                    for p in stroke_triangles(&path, half_w, style.line_cap, style.line_join) {
+                        vertices.push(Vertex::new(self.pixel_to_ndc(p), color_array));
                     }
                 }
                 Primitive::Rectangle { min, max } => {
@@ -801,15 +1122,8 @@ impl WgpuRenderer {
                     // 描边（如果有）
                     if let Some((stroke_color, stroke_w)) = stroke {
                         let style_line = Style::new().stroke(*stroke_color, *stroke_w);
-                        let mut dummy_texts: Vec<(
-                            String,
-                            f32,
-                            f32,
-                            f32,
-                            Color,
-                            HorizontalAlign,
-                            VerticalAlign,
-                        )> = Vec::new();
+                        let mut dummy_texts: Vec<TextItem> = Vec::new();
+                        let mut dummy_sprites = SpriteBatches::default();
                         // 左
                         vertices.extend(self.primitives_to_vertices_collect_text(
                             &[Primitive::Line {
@@ -818,6 +1132,7 @@ impl WgpuRenderer {
                             }],
                             std::slice::from_ref(&style_line),
                             &mut dummy_texts,
+                            &mut dummy_sprites,
                         ));
                         // 右
                         vertices.extend(self.primitives_to_vertices_collect_text(
@@ -827,6 +1142,7 @@ impl WgpuRenderer {
                             }],
                             std::slice::from_ref(&style_line),
                             &mut dummy_texts,
+                            &mut dummy_sprites,
                         ));
                         // 上
                         vertices.extend(self.primitives_to_vertices_collect_text(
@@ -836,6 +1152,7 @@ impl WgpuRenderer {
                             }],
                             std::slice::from_ref(&style_line),
                             &mut dummy_texts,
+                            &mut dummy_sprites,
                         ));
                         // 下
                         vertices.extend(self.primitives_to_vertices_collect_text(
@@ -845,56 +1162,72 @@ impl WgpuRenderer {
                             }],
                             std::slice::from_ref(&style_line),
                             &mut dummy_texts,
+                            &mut dummy_sprites,
                         ));
                     }
                 }
-                Primitive::Polyline {
-                    points,
-                    color,
-                    width,
+                Primitive::GradientRect {
+                    min,
+                    max,
+                    stops,
+                    direction,
                 } => {
-                    if points.len() < 2 {
+                    let stops = vizuara_core::normalize_gradient_stops(stops);
+                    if stops.is_empty() {
                         continue;
                     }
-                    let color_array = [color.r, color.g, color.b, color.a * style.opacity];
-                    let half_w = (width.max(1.0)) / 2.0;
 
                     let to_ndc = |(x, y): (f32, f32)| -> [f32; 2] {
                         let xn = (x / self.size.width as f32) * 2.0 - 1.0;
                         let yn = 1.0 - (y / self.size.height as f32) * 2.0;
                         [xn, yn]
                     };
+                    let to_array = |c: Color| -> [f32; 4] { [c.r, c.g, c.b, c.a * style.opacity] };
 
-                    for seg in points.windows(2) {
-                        let start = &seg[0];
-                        let end = &seg[1];
-
-                        let dx = end.x - start.x;
-                        let dy = end.y - start.y;
-                        let len = (dx * dx + dy * dy).sqrt().max(1e-6);
-                        let nx = -dy / len;
-                        let ny = dx / len;
-                        let ox = nx * half_w;
-                        let oy = ny * half_w;
-
-                        let p0 = (start.x + ox, start.y + oy);
-                        let p1 = (end.x + ox, end.y + oy);
-                        let p2 = (end.x - ox, end.y - oy);
-                        let p3 = (start.x - ox, start.y - oy);
+                    let x0 = min.x.min(max.x);
+                    let y0 = min.y.min(max.y);
+                    let x1 = max.x.max(min.x);
+                    let y1 = max.y.max(min.y);
 
-                        let v0 = to_ndc(p0);
-                        let v1 = to_ndc(p1);
-                        let v2 = to_ndc(p2);
-                        let v3 = to_ndc(p3);
+                    // 相邻色标之间各生成一个四边形，依靠逐顶点颜色插值形成渐变
+                    for pair in stops.windows(2) {
+                        let (a, b) = (pair[0], pair[1]);
+                        let ca = to_array(a.color);
+                        let cb = to_array(b.color);
+
+                        // 四角顺序：左上、右上、右下、左下
+                        let (corners, colors) = match direction {
+                            GradientDirection::Horizontal => {
+                                let xa = x0 + (x1 - x0) * a.offset;
+                                let xb = x0 + (x1 - x0) * b.offset;
+                                ([(xa, y0), (xb, y0), (xb, y1), (xa, y1)], [ca, cb, cb, ca])
+                            }
+                            GradientDirection::Vertical => {
+                                let ya = y0 + (y1 - y0) * a.offset;
+                                let yb = y0 + (y1 - y0) * b.offset;
+                                ([(x0, ya), (x1, ya), (x1, yb), (x0, yb)], [ca, ca, cb, cb])
+                            }
+                        };
+
+                        for &k in &[0, 3, 2, 0, 2, 1] {
+                            vertices.push(Vertex::new(to_ndc(corners[k]), colors[k]));
+                        }
+                    }
+                }
+                Primitive::Polyline {
+                    points,
+                    color,
+                    width,
+                } => {
+                    if points.len() < 2 {
+                        continue;
+                    }
+                    let color_array = [color.r, color.g, color.b, color.a * style.opacity];
+                    let half_w = (width.max(1.0)) / 2.0;
 
-                        vertices.extend_from_slice(&[
-                            Vertex::new(v0, color_array),
-                            Vertex::new(v1, color_array),
-                            Vertex::new(v2, color_array),
-                            Vertex::new(v0, color_array),
-                            Vertex::new(v2, color_array),
-                            Vertex::new(v3, color_array),
-                        ]);
+                    let path: Vec<(f32, f32)> = points.iter().map(|p| (p.x, p.y)).collect();
+                    for p in stroke_triangles(&path, half_w, style.line_cap, style.line_join) {
+                        vertices.push(Vertex::new(self.pixel_to_ndc(p), color_array));
                     }
                 }
                 Primitive::Polygon {
@@ -914,17 +1247,13 @@ impl WgpuRenderer {
                         [x, y]
                     };
 
-                    // 简单的扇形三角化：从第一个点向所有其他点连线
-                    let first_vertex = to_ndc(&points[0]);
-                    for i in 1..points.len() - 1 {
-                        let v1 = to_ndc(&points[i]);
-                        let v2 = to_ndc(&points[i + 1]);
-
-                        vertices.extend_from_slice(&[
-                            Vertex::new(first_vertex, fill_color_array),
-                            Vertex::new(v1, fill_color_array),
-                            Vertex::new(v2, fill_color_array),
-                        ]);
+                    // 耳切法三角化，凹多边形也能正确填充
+                    for triangle in vizuara_core::triangulate_polygon(points) {
+                        vertices.extend(
+                            triangle
+                                .iter()
+                                .map(|&i| Vertex::new(to_ndc(&points[i]), fill_color_array)),
+                        );
                     }
 
                     // 如果有边框，绘制边框
@@ -984,8 +1313,8 @@ impl WgpuRenderer {
                     fill,
                     stroke,
                 } => {
-                    let segments = ((end_angle - start_angle).abs() * 32.0 / std::f32::consts::PI)
-                        .max(8.0) as usize;
+                    let segments =
+                        arc_segments(*radius, end_angle - start_angle, style.circle_segments);
                     let fill_color_array = [fill.r, fill.g, fill.b, fill.a * style.opacity];
 
                     let to_ndc = |(x, y): (f32, f32)| -> [f32; 2] {
@@ -1079,8 +1408,11 @@ impl WgpuRenderer {
                     fill,
                     stroke,
                 } => {
-                    let segments = ((end_angle - start_angle).abs() * 32.0 / std::f32::consts::PI)
-                        .max(8.0) as usize;
+                    let segments = arc_segments(
+                        *outer_radius,
+                        end_angle - start_angle,
+                        style.circle_segments,
+                    );
                     let fill_color_array = [fill.r, fill.g, fill.b, fill.a * style.opacity];
 
                     let to_ndc = |(x, y): (f32, f32)| -> [f32; 2] {
@@ -1177,7 +1509,8 @@ impl WgpuRenderer {
                     }
                 }
                 Primitive::Circle { center, radius } => {
-                    let segments = 32; // 圆的分段数
+                    let segments =
+                        arc_segments(*radius, std::f32::consts::TAU, style.circle_segments);
                     let color = style.fill_color.unwrap_or(Color::BLUE);
                     let color_array = [color.r, color.g, color.b, color.a * style.opacity];
 
@@ -1217,17 +1550,19 @@ impl WgpuRenderer {
                     color,
                     h_align,
                     v_align,
+                    rotation,
                 } => {
                     // 收集文本，实际绘制在 glyphon pass 中（克隆内容以延长生命周期）
-                    texts.push((
-                        content.clone(),
-                        position.x,
-                        position.y,
-                        *size,
-                        *color,
-                        *h_align,
-                        *v_align,
-                    ));
+                    texts.push(TextItem {
+                        content: content.clone(),
+                        x: position.x,
+                        y: position.y,
+                        size: *size,
+                        color: *color,
+                        h_align: *h_align,
+                        v_align: *v_align,
+                        rotation: *rotation,
+                    });
                 }
                 // 其他图元类型暂不渲染（如 Circle 等）
                 _ => {}
@@ -1237,3 +1572,341 @@ impl WgpuRenderer {
         vertices
     }
 }
+
+/// sRGB 编码的顶点颜色转换为线性值，透明度不变
+fn linear_rgba([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    let linear = Color::new(r, g, b, a).to_linear();
+    [linear.r, linear.g, linear.b, linear.a]
+}
+
+/// 点标记的填充色与描边（颜色、宽度），均已乘上样式透明度
+///
+/// 只设置描边时填充透明，绘制为空心标记；两者都未设置时回退为蓝色填充
+fn marker_colors(style: &Style) -> ([f32; 4], Option<([f32; 4], f32)>) {
+    let with_opacity = |c: Color| [c.r, c.g, c.b, c.a * style.opacity];
+    let stroke = style
+        .stroke_color
+        .filter(|_| style.stroke_width > 0.0)
+        .map(|color| (with_opacity(color), style.stroke_width));
+    let fill = match (style.fill_color, stroke) {
+        (Some(color), _) => with_opacity(color),
+        (None, Some(_)) => [0.0; 4],
+        (None, None) => with_opacity(Color::BLUE),
+    };
+    (fill, stroke)
+}
+
+/// 自适应细分时弦与圆弧之间允许的最大偏差（像素）
+const ARC_TOLERANCE: f32 = 0.25;
+/// 自适应细分时整圆段数的下限与上限
+const MIN_CIRCLE_SEGMENTS: u32 = 8;
+const MAX_CIRCLE_SEGMENTS: u32 = 256;
+
+/// 圆弧的细分段数
+///
+/// 未指定 `circle_segments` 时按像素半径选取整圆段数，使弦高不超过
+/// [`ARC_TOLERANCE`]（大圆更平滑、小标记更省三角形），再按扫过的角度等比例分配
+fn arc_segments(radius: f32, sweep: f32, circle_segments: Option<u32>) -> usize {
+    let full = circle_segments.unwrap_or_else(|| {
+        if radius <= ARC_TOLERANCE {
+            return MIN_CIRCLE_SEGMENTS;
+        }
+        let n = (std::f32::consts::PI / (1.0 - ARC_TOLERANCE / radius).acos()).ceil();
+        (n as u32).clamp(MIN_CIRCLE_SEGMENTS, MAX_CIRCLE_SEGMENTS)
+    });
+    let fraction = (sweep.abs() / std::f32::consts::TAU).min(1.0);
+    ((full.max(3) as f32 * fraction).ceil() as usize).max(1)
+}
+
+/// 投影模糊的采样偏移
+///
+/// 硬边投影只有一个采样；否则在中心与半径为 blur/2、blur 的两圈各 8 个方向上取样，
+/// 叠加后边缘由实到虚，近似高斯模糊
+fn shadow_samples(shadow: &Shadow) -> Vec<(f32, f32)> {
+    let (dx, dy) = shadow.offset;
+    let mut samples = vec![(dx, dy)];
+    if shadow.blur > 0.0 {
+        for ring in [0.5, 1.0] {
+            let r = shadow.blur * ring;
+            for k in 0..8 {
+                let angle = k as f32 * std::f32::consts::FRAC_PI_4;
+                samples.push((dx + r * angle.cos(), dy + r * angle.sin()));
+            }
+        }
+    }
+    samples
+}
+
+/// 单个采样的透明度，使全部采样重叠处的合成透明度等于 `alpha`
+fn shadow_sample_alpha(alpha: f32, samples: usize) -> f32 {
+    1.0 - (1.0 - alpha.clamp(0.0, 1.0)).powf(1.0 / samples.max(1) as f32)
+}
+
+/// 尖角长度与半线宽之比的上限，超出时退化为斜切（与 SVG 默认 miterlimit 一致）
+const MITER_LIMIT: f32 = 4.0;
+/// 圆头/圆角中每个扇形三角形的最大张角
+const ROUND_STEP: f32 = std::f32::consts::PI / 8.0;
+
+/// 将折线描边为三角形列表（像素坐标），包含线段之间的连接和两端的线端
+fn stroke_triangles(
+    points: &[(f32, f32)],
+    half_w: f32,
+    cap: LineCap,
+    join: LineJoin,
+) -> Vec<(f32, f32)> {
+    // 去除重合的相邻点，避免方向无法确定
+    let mut path: Vec<(f32, f32)> = Vec::with_capacity(points.len());
+    for &p in points {
+        let duplicate = path
+            .last()
+            .is_some_and(|&(x, y)| (p.0 - x).hypot(p.1 - y) < 1e-6);
+        if !duplicate {
+            path.push(p);
+        }
+    }
+    if path.len() < 2 {
+        return Vec::new();
+    }
+
+    let offset = |p: (f32, f32), v: (f32, f32), k: f32| (p.0 + v.0 * k, p.1 + v.1 * k);
+    // 单位方向及其法线 (-dy, dx)
+    let directions: Vec<((f32, f32), (f32, f32))> = path
+        .windows(2)
+        .map(|seg| {
+            let (dx, dy) = (seg[1].0 - seg[0].0, seg[1].1 - seg[0].1);
+            let len = dx.hypot(dy);
+            let d = (dx / len, dy / len);
+            (d, (-d.1, d.0))
+        })
+        .collect();
+
+    let mut out = Vec::new();
+
+    // 线段主体
+    for (seg, &(_, n)) in path.windows(2).zip(&directions) {
+        let (a, b) = (seg[0], seg[1]);
+        let (p0, p1) = (offset(a, n, half_w), offset(b, n, half_w));
+        let (p2, p3) = (offset(b, n, -half_w), offset(a, n, -half_w));
+        out.extend_from_slice(&[p0, p1, p2, p0, p2, p3]);
+    }
+
+    // 内部顶点的连接：只需填补外侧的缺口
+    for (i, pair) in directions.windows(2).enumerate() {
+        let ((d0, n0), (d1, n1)) = (pair[0], pair[1]);
+        let cross = d0.0 * d1.1 - d0.1 * d1.0;
+        if cross.abs() < 1e-6 {
+            continue;
+        }
+        let p = path[i + 1];
+        // 向法线正方向转弯时外侧在法线负方向
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let (v0, v1) = ((n0.0 * side, n0.1 * side), (n1.0 * side, n1.1 * side));
+        let (a, b) = (offset(p, v0, half_w), offset(p, v1, half_w));
+
+        match join {
+            LineJoin::Bevel => out.extend_from_slice(&[p, a, b]),
+            LineJoin::Miter => {
+                let (mx, my) = (v0.0 + v1.0, v0.1 + v1.1);
+                let m_len = mx.hypot(my);
+                // 尖角长度 = 半线宽 / cos(夹角/2)
+                let cos_half = (mx * v0.0 + my * v0.1) / m_len;
+                let ratio = 1.0 / cos_half.max(1e-6);
+                if ratio <= MITER_LIMIT {
+                    let tip = offset(p, (mx / m_len, my / m_len), half_w * ratio);
+                    out.extend_from_slice(&[p, a, tip, p, tip, b]);
+                } else {
+                    out.extend_from_slice(&[p, a, b]);
+                }
+            }
+            LineJoin::Round => {
+                let start = v0.1.atan2(v0.0);
+                let sweep = (v0.0 * v1.1 - v0.1 * v1.0).atan2(v0.0 * v1.0 + v0.1 * v1.1);
+                push_fan(&mut out, p, half_w, start, sweep);
+            }
+        }
+    }
+
+    // 线端
+    let (first, last) = (path[0], path[path.len() - 1]);
+    let ((d_first, n_first), (d_last, n_last)) = (directions[0], directions[directions.len() - 1]);
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Square => {
+            for (p, d, n) in [
+                (first, (-d_first.0, -d_first.1), n_first),
+                (last, d_last, n_last),
+            ] {
+                let e = offset(p, d, half_w);
+                let (p0, p1) = (offset(p, n, half_w), offset(e, n, half_w));
+                let (p2, p3) = (offset(e, n, -half_w), offset(p, n, -half_w));
+                out.extend_from_slice(&[p0, p1, p2, p0, p2, p3]);
+            }
+        }
+        LineCap::Round => {
+            // 从法线一侧经过线段外方向旋转到另一侧
+            push_fan(
+                &mut out,
+                first,
+                half_w,
+                n_first.1.atan2(n_first.0),
+                std::f32::consts::PI,
+            );
+            push_fan(
+                &mut out,
+                last,
+                half_w,
+                (-n_last.1).atan2(-n_last.0),
+                std::f32::consts::PI,
+            );
+        }
+    }
+
+    out
+}
+
+/// 以 center 为圆心追加扇形三角形，从 start 角度转过 sweep 弧度
+fn push_fan(out: &mut Vec<(f32, f32)>, center: (f32, f32), radius: f32, start: f32, sweep: f32) {
+    let steps = (sweep.abs() / ROUND_STEP).ceil().max(1.0) as usize;
+    let point_at = |k: usize| {
+        let angle = start + sweep * k as f32 / steps as f32;
+        (
+            center.0 + radius * angle.cos(),
+            center.1 + radius * angle.sin(),
+        )
+    };
+    for k in 0..steps {
+        out.extend_from_slice(&[center, point_at(k), point_at(k + 1)]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shadow_samples() {
+        // 硬边投影只在偏移处取样
+        let hard = Shadow::new((3.0, 4.0), 0.0, Color::BLACK);
+        assert_eq!(shadow_samples(&hard), vec![(3.0, 4.0)]);
+
+        // 模糊投影：中心加两圈各 8 个采样，分别位于 blur/2 与 blur 处
+        let soft = Shadow::new((3.0, 4.0), 6.0, Color::BLACK);
+        let samples = shadow_samples(&soft);
+        assert_eq!(samples.len(), 17);
+        assert_eq!(samples[0], (3.0, 4.0));
+        for (i, &(x, y)) in samples[1..].iter().enumerate() {
+            let expected = if i < 8 { 3.0 } else { 6.0 };
+            assert!(((x - 3.0).hypot(y - 4.0) - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_shadow_sample_alpha() {
+        assert_eq!(shadow_sample_alpha(0.5, 1), 0.5);
+        assert_eq!(shadow_sample_alpha(0.0, 17), 0.0);
+        assert_eq!(shadow_sample_alpha(2.0, 0), 1.0);
+
+        // 全部采样重叠处的合成透明度等于目标值
+        let alpha = shadow_sample_alpha(0.6, 17);
+        let combined = 1.0 - (1.0 - alpha).powi(17);
+        assert!((combined - 0.6).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_marker_colors() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+
+        // 只有填充
+        let style = Style {
+            fill_color: Some(red),
+            stroke_color: None,
+            ..Style::default()
+        };
+        assert_eq!(marker_colors(&style), ([1.0, 0.0, 0.0, 1.0], None));
+
+        // 填充与描边独立，均乘上样式透明度
+        let style = Style::new()
+            .fill_color(red)
+            .stroke(Color::BLUE, 2.0)
+            .opacity(0.5);
+        assert_eq!(
+            marker_colors(&style),
+            ([1.0, 0.0, 0.0, 0.5], Some(([0.0, 0.0, 1.0, 0.5], 2.0)))
+        );
+
+        // 只有描边：填充透明，绘制为空心标记
+        let style = Style {
+            fill_color: None,
+            ..Style::new().stroke(Color::BLACK, 1.0)
+        };
+        assert_eq!(
+            marker_colors(&style),
+            ([0.0; 4], Some(([0.0, 0.0, 0.0, 1.0], 1.0)))
+        );
+
+        // 描边宽度为 0 视为不描边
+        let style = Style::new().fill_color(red).stroke(Color::BLACK, 0.0);
+        assert_eq!(marker_colors(&style).1, None);
+
+        // 两者都未设置时回退为蓝色填充
+        let style = Style {
+            fill_color: None,
+            stroke_color: None,
+            ..Style::default()
+        };
+        assert_eq!(marker_colors(&style), ([0.0, 0.0, 1.0, 1.0], None));
+    }
+
+    #[test]
+    fn test_arc_segments_radius_bounds() {
+        use std::f32::consts::TAU;
+
+        // 极小半径使用下限段数
+        assert_eq!(arc_segments(0.0, TAU, None), MIN_CIRCLE_SEGMENTS as usize);
+        assert_eq!(arc_segments(0.1, TAU, None), MIN_CIRCLE_SEGMENTS as usize);
+        assert_eq!(arc_segments(1.0, TAU, None), MIN_CIRCLE_SEGMENTS as usize);
+
+        // 极大半径被限制在上限
+        assert_eq!(arc_segments(1.0e6, TAU, None), MAX_CIRCLE_SEGMENTS as usize);
+
+        // 中间半径的弦高不超过容差，且段数随半径单调不减
+        let mut previous = 0;
+        for radius in [5.0, 10.0, 20.0, 50.0, 100.0] {
+            let n = arc_segments(radius, TAU, None);
+            let sagitta = radius * (1.0 - (std::f32::consts::PI / n as f32).cos());
+            assert!(
+                sagitta <= ARC_TOLERANCE + 1e-4,
+                "radius {radius}: {n} segments"
+            );
+            assert!(n >= previous);
+            previous = n;
+        }
+    }
+
+    #[test]
+    fn test_arc_segments_partial_sweep() {
+        use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+        let full = arc_segments(1.0e6, TAU, None);
+        assert_eq!(arc_segments(1.0e6, PI, None), full / 2);
+        assert_eq!(arc_segments(1.0e6, FRAC_PI_2, None), full / 4);
+        // 反向扫过与正向相同，超过整圆按整圆计
+        assert_eq!(arc_segments(1.0e6, -PI, None), full / 2);
+        assert_eq!(arc_segments(1.0e6, 2.0 * TAU, None), full);
+        // 极小的扫角至少一段
+        assert_eq!(arc_segments(1.0e6, 1e-6, None), 1);
+        assert_eq!(arc_segments(1.0e6, 0.0, None), 1);
+    }
+
+    #[test]
+    fn test_arc_segments_override() {
+        use std::f32::consts::{PI, TAU};
+
+        // 指定段数时忽略半径
+        assert_eq!(arc_segments(0.1, TAU, Some(32)), 32);
+        assert_eq!(arc_segments(1.0e6, TAU, Some(32)), 32);
+        assert_eq!(arc_segments(1.0e6, PI, Some(32)), 16);
+        // 整圆至少三段
+        assert_eq!(arc_segments(10.0, TAU, Some(1)), 3);
+    }
+}
@@ -1,9 +1,15 @@
 use bytemuck::{Pod, Zeroable};
 use nalgebra::{Matrix4, Point3, Vector3};
+use vizuara_3d::FloorContour;
 use vizuara_core::{Result, VizuaraError};
 use wgpu::util::DeviceExt;
 use winit::window::Window;
 
+/// 相机初始位置
+const DEFAULT_EYE: [f32; 3] = [4.0, 3.0, 2.0];
+/// 相机与目标点距离的范围
+const ZOOM_LIMITS: (f32, f32) = (1.0, 50.0);
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Vertex3D {
@@ -48,6 +54,9 @@ pub struct Wgpu3DRenderer {
     pub config: wgpu::SurfaceConfiguration,
     pub size: winit::dpi::PhysicalSize<u32>,
     render_pipeline: wgpu::RenderPipeline,
+    line_pipeline: wgpu::RenderPipeline,
+    /// 底面等高线的线段顶点（每两个顶点一条线段）
+    floor_contours: Vec<Vertex3D>,
 
     // 深度缓冲
     depth_texture: wgpu::Texture,
@@ -191,66 +200,77 @@ impl Wgpu3DRenderer {
                 push_constant_ranges: &[],
             });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("3D Render Pipeline"),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main",
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: std::mem::size_of::<Vertex3D>() as wgpu::BufferAddress,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &[
-                        wgpu::VertexAttribute {
-                            offset: 0,
-                            shader_location: 0,
-                            format: wgpu::VertexFormat::Float32x3,
-                        },
-                        wgpu::VertexAttribute {
-                            offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
-                            shader_location: 1,
-                            format: wgpu::VertexFormat::Float32x4,
-                        },
-                    ],
-                }],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::Less,
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-        });
+        let create_pipeline =
+            |label: &str, topology: wgpu::PrimitiveTopology, cull_mode: Option<wgpu::Face>| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: Some(label),
+                    layout: Some(&render_pipeline_layout),
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: "vs_main",
+                        buffers: &[wgpu::VertexBufferLayout {
+                            array_stride: std::mem::size_of::<Vertex3D>() as wgpu::BufferAddress,
+                            step_mode: wgpu::VertexStepMode::Vertex,
+                            attributes: &[
+                                wgpu::VertexAttribute {
+                                    offset: 0,
+                                    shader_location: 0,
+                                    format: wgpu::VertexFormat::Float32x3,
+                                },
+                                wgpu::VertexAttribute {
+                                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                                    shader_location: 1,
+                                    format: wgpu::VertexFormat::Float32x4,
+                                },
+                            ],
+                        }],
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: "fs_main",
+                        targets: &[Some(wgpu::ColorTargetState {
+                            format: config.format,
+                            blend: Some(wgpu::BlendState::REPLACE),
+                            write_mask: wgpu::ColorWrites::ALL,
+                        })],
+                    }),
+                    primitive: wgpu::PrimitiveState {
+                        topology,
+                        strip_index_format: None,
+                        front_face: wgpu::FrontFace::Ccw,
+                        cull_mode,
+                        polygon_mode: wgpu::PolygonMode::Fill,
+                        unclipped_depth: false,
+                        conservative: false,
+                    },
+                    depth_stencil: Some(wgpu::DepthStencilState {
+                        format: wgpu::TextureFormat::Depth32Float,
+                        depth_write_enabled: true,
+                        depth_compare: wgpu::CompareFunction::Less,
+                        stencil: wgpu::StencilState::default(),
+                        bias: wgpu::DepthBiasState::default(),
+                    }),
+                    multisample: wgpu::MultisampleState {
+                        count: 1,
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                    multiview: None,
+                })
+            };
+        let render_pipeline = create_pipeline(
+            "3D Render Pipeline",
+            wgpu::PrimitiveTopology::TriangleList,
+            Some(wgpu::Face::Back),
+        );
+        // 底面等高线等线段使用线段列表，不做背面剔除
+        let line_pipeline =
+            create_pipeline("3D Line Pipeline", wgpu::PrimitiveTopology::LineList, None);
 
         // 初始相机参数
-        let camera_eye = Point3::new(4.0, 3.0, 2.0);
-        let camera_target = Point3::new(0.0, 0.0, 0.0);
-        let camera_up = Vector3::new(0.0, 1.0, 0.0);
+        let camera_eye = Point3::from(DEFAULT_EYE);
+        let camera_target = Point3::origin();
+        let camera_up = Vector3::y();
         let fov = 45.0_f32.to_radians();
         let near = 0.1;
         let far = 100.0;
@@ -261,6 +281,8 @@ impl Wgpu3DRenderer {
             config,
             size,
             render_pipeline,
+            line_pipeline,
+            floor_contours: Vec::new(),
             depth_texture,
             depth_view,
             uniform_buffer,
@@ -301,7 +323,7 @@ impl Wgpu3DRenderer {
         self.update_uniforms();
     }
 
-    /// 旋转相机
+    /// 旋转相机（绕目标点的方位角与俯仰角增量，单位为弧度）
     pub fn rotate_camera(&mut self, delta_x: f32, delta_y: f32) {
         let distance = (self.camera_eye - self.camera_target).magnitude();
 
@@ -310,8 +332,8 @@ impl Wgpu3DRenderer {
             .atan2(self.camera_eye.z - self.camera_target.z);
         let mut phi = ((self.camera_eye.y - self.camera_target.y) / distance).acos();
 
-        theta -= delta_x * 0.01;
-        phi = (phi + delta_y * 0.01).clamp(0.1, std::f32::consts::PI - 0.1);
+        theta -= delta_x;
+        phi = (phi + delta_y).clamp(0.1, std::f32::consts::PI - 0.1);
 
         self.camera_eye.x = self.camera_target.x + distance * phi.sin() * theta.cos();
         self.camera_eye.z = self.camera_target.z + distance * phi.sin() * theta.sin();
@@ -320,16 +342,39 @@ impl Wgpu3DRenderer {
         self.update_uniforms();
     }
 
-    /// 缩放相机
-    pub fn zoom_camera(&mut self, delta: f32) {
+    /// 缩放相机（按倍数调整与目标点的距离，小于 1 拉近）
+    pub fn zoom_camera(&mut self, factor: f32) {
         let direction = (self.camera_eye - self.camera_target).normalize();
         let distance = (self.camera_eye - self.camera_target).magnitude();
-        let new_distance = (distance + delta * 0.5).clamp(1.0, 50.0);
+        let (min, max) = ZOOM_LIMITS;
+        let new_distance = (distance * factor).clamp(min, max);
 
         self.camera_eye = self.camera_target + direction * new_distance;
         self.update_uniforms();
     }
 
+    /// 平移相机：dx/dy 为屏幕方向上的偏移，以相机距离为单位
+    pub fn pan_camera(&mut self, dx: f32, dy: f32) {
+        let forward = self.camera_target - self.camera_eye;
+        let distance = forward.magnitude();
+        let right = forward.cross(&self.camera_up);
+        if right.norm() < 1e-6 {
+            return;
+        }
+        let right = right.normalize();
+        let up = right.cross(&forward).normalize();
+
+        let offset = (right * -dx + up * dy) * distance;
+        self.camera_eye += offset;
+        self.camera_target += offset;
+        self.update_uniforms();
+    }
+
+    /// 重置相机到初始视角
+    pub fn reset_camera(&mut self) {
+        self.set_camera(Point3::from(DEFAULT_EYE), Point3::origin(), Vector3::y());
+    }
+
     /// 调整窗口大小
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
@@ -361,12 +406,17 @@ impl Wgpu3DRenderer {
         }
     }
 
+    /// 设置底面等高线（数据坐标），在表面之后以线段绘制
+    pub fn set_floor_contours(&mut self, contours: &[FloorContour]) {
+        self.floor_contours = contour_vertices(contours);
+    }
+
     /// 渲染3D场景
     pub fn render_3d(
         &mut self,
         surface: &wgpu::Surface,
         vertices: &[Vertex3D],
-        indices: &[u16],
+        indices: &[u32],
     ) -> Result<()> {
         let output = surface.get_current_texture().map_err(|e| {
             VizuaraError::RenderError(format!("Failed to get surface texture: {}", e))
@@ -394,6 +444,15 @@ impl Wgpu3DRenderer {
                 usage: wgpu::BufferUsages::INDEX,
             });
 
+        let contour_buffer = (!self.floor_contours.is_empty()).then(|| {
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("Contour Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&self.floor_contours),
+                    usage: wgpu::BufferUsages::VERTEX,
+                })
+        });
+
         let mut encoder = self
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -431,8 +490,14 @@ impl Wgpu3DRenderer {
             render_pass.set_pipeline(&self.render_pipeline);
             render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
             render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
             render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+
+            if let Some(contour_buffer) = &contour_buffer {
+                render_pass.set_pipeline(&self.line_pipeline);
+                render_pass.set_vertex_buffer(0, contour_buffer.slice(..));
+                render_pass.draw(0..self.floor_contours.len() as u32, 0..1);
+            }
         }
 
         self.queue.submit(std::iter::once(encoder.finish()));
@@ -441,3 +506,47 @@ impl Wgpu3DRenderer {
         Ok(())
     }
 }
+
+/// 等高线线段转换为线段列表顶点，丢弃不成对的末尾点
+fn contour_vertices(contours: &[FloorContour]) -> Vec<Vertex3D> {
+    contours
+        .iter()
+        .flat_map(|contour| {
+            let color = [
+                contour.color.r,
+                contour.color.g,
+                contour.color.b,
+                contour.color.a,
+            ];
+            contour
+                .lines
+                .chunks_exact(2)
+                .flatten()
+                .map(move |p| Vertex3D::new([p.x, p.y, p.z], color))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_core::Color;
+
+    #[test]
+    fn test_contour_vertices_pairs_segments() {
+        let contours = vec![FloorContour {
+            level: 0.5,
+            color: Color::rgb(1.0, 0.0, 0.0),
+            lines: vec![
+                Point3::new(0.0, 0.0, -1.0),
+                Point3::new(1.0, 0.0, -1.0),
+                Point3::new(2.0, 0.0, -1.0),
+            ],
+        }];
+
+        let vertices = contour_vertices(&contours);
+        assert_eq!(vertices.len(), 2);
+        assert_eq!(vertices[1].position, [1.0, 0.0, -1.0]);
+        assert_eq!(vertices[0].color, [1.0, 0.0, 0.0, 1.0]);
+    }
+}
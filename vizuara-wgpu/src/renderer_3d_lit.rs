@@ -2,7 +2,7 @@
 //!
 //! 基于物理的渲染(PBR)和多光源系统
 use nalgebra::{Matrix4, Point3, Vector3, Vector4};
-use vizuara_3d::{Axis3DDirection, Axis3DRenderData, CoordinateSystem3D, Light, LightType, Material};
+use vizuara_3d::{Axis3DDirection, Axis3DRenderData, BoundingBox3D, CoordinateSystem3D, FloorContour, Light, LightType, Material, SurfaceVertex};
 use vizuara_core::{Color, Result, VizuaraError};
 use glyphon::{
     Attrs, Buffer as GlyphBuffer, Family, FontSystem, Metrics, Resolution, SwashCache, TextArea,
@@ -13,6 +13,18 @@ use wgpu::{
     BufferUsages, RenderPipeline, ShaderStages, Surface, SurfaceConfiguration,
 };
 use winit::window::Window;
+use crate::present::select_present_mode;
+use crate::text_cache::{TextCache, DEFAULT_TEXT_CACHE_CAPACITY};
+
+/// 自动取景时在包围球外保留的余量比例
+const FRAME_MARGIN: f32 = 1.1;
+/// 默认的相机距离缩放范围
+const DEFAULT_ZOOM_LIMITS: (f32, f32) = (2.0, 100.0);
+/// 光照 uniform 中光源数组的长度
+const MAX_LIGHTS: usize = 8;
+/// 标签遮挡检测中忽略的端点附近比例，避免标签所在的面遮挡自身
+const OCCLUSION_EPSILON: f32 = 1e-3;
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Vertex3DLit {
@@ -37,6 +49,16 @@ impl Vertex3DLit {
     }
 }
 
+impl From<SurfaceVertex> for Vertex3DLit {
+    fn from(vertex: SurfaceVertex) -> Self {
+        Self {
+            position: vertex.position,
+            normal: vertex.normal,
+            color: vertex.color,
+        }
+    }
+}
+
 /// 3D文本顶点结构
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -157,7 +179,7 @@ struct LightingUniform {
     num_lights: f32,           // 4 bytes
     _padding: f32,             // 4 bytes
     _padding2: [f32; 2],       // 8 bytes -> 32 字节头部（去除隐式填充）
-    lights: [LightUniform; 8], // 8 * 80字节 = 640字节，总共672字节
+    lights: [LightUniform; MAX_LIGHTS], // 8 * 80字节 = 640字节，总共672字节
 }
 
 /// 材质统一缓冲区 (WGSL 16字节对齐)
@@ -170,21 +192,69 @@ struct MaterialUniform {
     ao: f32,
     _padding1: [f32; 2],
     emissive: [f32; 3],
-    _padding2: f32,
+    alpha: f32,
 }
 
 // 注意：通过显式 padding 确保 Rust 端与 WGSL 的布局一致：
 // - LightUniform: 80 bytes
 // - LightingUniform: 32 (header) + 8*80 = 672 bytes
 
+/// 网格渲染模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// 填充三角面
+    #[default]
+    Solid,
+    /// 仅绘制三角形边
+    Wireframe,
+    /// 先填充再叠加线框
+    SolidWithWireframe,
+}
+
+/// 从三角形索引中提取去重后的边（用于线框渲染）
+pub fn wireframe_edges(indices: &[u16]) -> Vec<(u16, u16)> {
+    let mut seen = std::collections::HashSet::new();
+    let mut edges = Vec::new();
+
+    for triangle in indices.chunks_exact(3) {
+        for (a, b) in [
+            (triangle[0], triangle[1]),
+            (triangle[1], triangle[2]),
+            (triangle[2], triangle[0]),
+        ] {
+            let edge = (a.min(b), a.max(b));
+            if seen.insert(edge) {
+                edges.push(edge);
+            }
+        }
+    }
+
+    edges
+}
+
+/// 单个物体在 GPU 上的资源
+struct ObjectBuffers {
+    vertex_buffer: Buffer,
+    index_buffer: Buffer,
+    material_bind_group: BindGroup,
+    index_count: u32,
+    // 是否使用半透明混合管线绘制
+    transparent: bool,
+}
+
 /// 支持光照的3D渲染器
 pub struct Wgpu3DLitRenderer {
     device: wgpu::Device,
     queue: wgpu::Queue,
     adapter: wgpu::Adapter, // 保存adapter引用
+    // 表面配置，调整大小与切换呈现模式时沿用格式等设置
+    config: SurfaceConfiguration,
 
     // 管线
     render_pipeline: RenderPipeline,
+    biased_render_pipeline: RenderPipeline,
+    transparent_pipeline: RenderPipeline,
+    wireframe_pipeline: RenderPipeline,
     axis_pipeline: RenderPipeline,
     plane_pipeline: RenderPipeline,
     text_pipeline: RenderPipeline,
@@ -194,45 +264,97 @@ pub struct Wgpu3DLitRenderer {
     swash_cache: SwashCache,
     text_atlas: TextAtlas,
     text_renderer: TextRenderer,
-    // 简单的文本缓存，避免每帧重塑形
-    text_cache: std::collections::HashMap<(String, u32), GlyphBuffer>,
+    // 文本缓存，避免每帧重塑形；按最近使用淘汰
+    text_cache: TextCache<(String, u32), GlyphBuffer>,
 
     // 绑定组布局
     _camera_bind_group_layout: BindGroupLayout,
     _lighting_bind_group_layout: BindGroupLayout,
-    _material_bind_group_layout: BindGroupLayout,
+    material_bind_group_layout: BindGroupLayout,
 
     // 统一缓冲区
     camera_buffer: Buffer,
     lighting_buffer: Buffer,
-    material_buffer: Buffer,
 
     // 绑定组
     camera_bind_group: BindGroup,
     lighting_bind_group: BindGroup,
-    material_bind_group: BindGroup,
 
     // 相机参数
     camera_position: Point3<f32>,
+    camera_target: Point3<f32>,
     camera_rotation: (f32, f32), // (yaw, pitch)
     camera_distance: f32,
+    scene_bounds: Option<BoundingBox3D>,
+    zoom_limits: (f32, f32), // 相机距离的缩放范围 (min, max)
 
     // 光照系统
     lights: Vec<Light>,
     ambient_color: [f32; 3],
     ambient_intensity: f32,
     
+    // 渲染模式
+    render_mode: RenderMode,
+    wireframe_color: [f32; 3],
+    // 是否对半透明材质的物体启用混合
+    transparency: bool,
+    // 表面底面等高线（数据坐标），随坐标轴一起绘制
+    floor_contours: Vec<FloorContour>,
+    // 屏幕空间标签是否做深度测试（被几何体遮挡时不绘制）
+    label_depth_test: bool,
+
     // 状态跟踪以避免不必要的更新
     camera_dirty: bool,
     lights_dirty: bool,
     last_aspect_ratio: f32,
 }
 
+/// 线段 origin -> origin + direction 是否穿过三角形（Möller–Trumbore），
+/// 忽略紧贴两端的交点
+fn segment_hits_triangle(
+    origin: Point3<f32>,
+    direction: Vector3<f32>,
+    [a, b, c]: [Point3<f32>; 3],
+) -> bool {
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let p = direction.cross(&edge2);
+    let det = edge1.dot(&p);
+    if det.abs() < f32::EPSILON {
+        // 线段与三角形平行
+        return false;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = origin - a;
+    let u = s.dot(&p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return false;
+    }
+    let q = s.cross(&edge1);
+    let v = direction.dot(&q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return false;
+    }
+
+    let t = edge2.dot(&q) * inv_det;
+    t > OCCLUSION_EPSILON && t < 1.0 - OCCLUSION_EPSILON
+}
+
 impl Wgpu3DLitRenderer {
-    /// 创建新的光照渲染器
+    /// 创建新的光照渲染器，自动选择呈现模式（见 [`select_present_mode`]）
     pub async fn new(
         window: &Window,
         size: winit::dpi::PhysicalSize<u32>,
+    ) -> Result<(Self, Surface<'_>)> {
+        Self::new_with_present_mode(window, size, None).await
+    }
+
+    /// 创建新的光照渲染器并指定呈现模式；表面不支持该模式时回退到 Fifo
+    pub async fn new_with_present_mode(
+        window: &Window,
+        size: winit::dpi::PhysicalSize<u32>,
+        present_mode: Option<wgpu::PresentMode>,
     ) -> Result<(Self, Surface<'_>)> {
         // 创建wgpu实例
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
@@ -284,7 +406,7 @@ impl Wgpu3DLitRenderer {
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: surface_caps.present_modes[0],
+            present_mode: select_present_mode(&surface_caps.present_modes, present_mode),
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
@@ -366,13 +488,6 @@ impl Wgpu3DLitRenderer {
             mapped_at_creation: false,
         });
 
-        let material_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Material Buffer"),
-            size: std::mem::size_of::<MaterialUniform>() as u64,
-            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
         // 创建绑定组
         let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             layout: &camera_bind_group_layout,
@@ -392,14 +507,6 @@ impl Wgpu3DLitRenderer {
             label: Some("lighting_bind_group"),
         });
 
-        let material_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            layout: &material_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: material_buffer.as_entire_binding(),
-            }],
-            label: Some("material_bind_group"),
-        });
 
         // 创建渲染管线
         let render_pipeline_layout =
@@ -413,28 +520,111 @@ impl Wgpu3DLitRenderer {
                 push_constant_ranges: &[],
             });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("3D Lit Render Pipeline"),
-            layout: Some(&render_pipeline_layout),
+        // 填充管线；实体+线框模式使用带深度偏移的版本，把三角面略微推远以避免 z-fighting。
+        // 半透明版本使用 alpha 混合、不写深度，并绘制背面以便透过表面看到其内侧
+        let create_lit_pipeline = |label: &str, bias: wgpu::DepthBiasState, transparent: bool| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&render_pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[Vertex3DLit::desc()],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: config.format,
+                        blend: Some(if transparent {
+                            wgpu::BlendState::ALPHA_BLENDING
+                        } else {
+                            wgpu::BlendState::REPLACE
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: if transparent { None } else { Some(wgpu::Face::Back) },
+                    unclipped_depth: false,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    conservative: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: !transparent,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias,
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+        };
+
+        let render_pipeline = create_lit_pipeline(
+            "3D Lit Render Pipeline",
+            wgpu::DepthBiasState::default(),
+            false,
+        );
+        let biased_render_pipeline = create_lit_pipeline(
+            "3D Lit Biased Render Pipeline",
+            wgpu::DepthBiasState {
+                constant: 2,
+                slope_scale: 1.0,
+                clamp: 0.0,
+            },
+            false,
+        );
+        let transparent_pipeline = create_lit_pipeline(
+            "3D Lit Transparent Render Pipeline",
+            wgpu::DepthBiasState::default(),
+            true,
+        );
+
+        // 创建坐标轴着色器
+        let axis_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Axis Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/axis3d.wgsl").into()),
+        });
+
+        // 创建坐标轴管线布局（只需要相机绑定组）
+        let axis_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Axis Pipeline Layout"),
+            bind_group_layouts: &[&camera_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // 创建坐标轴渲染管线
+        let axis_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Axis Render Pipeline"),
+            layout: Some(&axis_pipeline_layout),
             vertex: wgpu::VertexState {
-                module: &shader,
+                module: &axis_shader,
                 entry_point: "vs_main",
-                buffers: &[Vertex3DLit::desc()],
+                buffers: &[AxisVertex::desc()],
             },
             fragment: Some(wgpu::FragmentState {
-                module: &shader,
+                module: &axis_shader,
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
                     format: config.format,
-                    blend: Some(wgpu::BlendState::REPLACE),
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
+                topology: wgpu::PrimitiveTopology::LineList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
+                cull_mode: None, // 不剔除背面，因为线条没有面
                 unclipped_depth: false,
                 polygon_mode: wgpu::PolygonMode::Fill,
                 conservative: false,
@@ -454,22 +644,9 @@ impl Wgpu3DLitRenderer {
             multiview: None,
         });
 
-        // 创建坐标轴着色器
-        let axis_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Axis Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/axis3d.wgsl").into()),
-        });
-
-        // 创建坐标轴管线布局（只需要相机绑定组）
-        let axis_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some("Axis Pipeline Layout"),
-            bind_group_layouts: &[&camera_bind_group_layout],
-            push_constant_ranges: &[],
-        });
-
-        // 创建坐标轴渲染管线
-        let axis_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Axis Render Pipeline"),
+        // 创建线框渲染管线（复用坐标轴着色器，不写深度，允许与填充面深度相等）
+        let wireframe_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Wireframe Render Pipeline"),
             layout: Some(&axis_pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &axis_shader,
@@ -489,15 +666,15 @@ impl Wgpu3DLitRenderer {
                 topology: wgpu::PrimitiveTopology::LineList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
-                cull_mode: None, // 不剔除背面，因为线条没有面
+                cull_mode: None,
                 unclipped_depth: false,
                 polygon_mode: wgpu::PolygonMode::Fill,
                 conservative: false,
             },
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::Less,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::LessEqual,
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
@@ -607,6 +784,7 @@ impl Wgpu3DLitRenderer {
 
         // 初始化默认值
         let camera_position = Point3::new(0.0, 0.0, 5.0);
+        let camera_target = Point3::origin();
         let camera_rotation = (0.0, 0.0);
         let camera_distance = 5.0;
 
@@ -640,7 +818,11 @@ impl Wgpu3DLitRenderer {
             device,
             queue,
             adapter,
+            config,
             render_pipeline,
+            biased_render_pipeline,
+            transparent_pipeline,
+            wireframe_pipeline,
             axis_pipeline,
             plane_pipeline,
             text_pipeline,
@@ -648,22 +830,28 @@ impl Wgpu3DLitRenderer {
             swash_cache,
             text_atlas,
             text_renderer,
-            text_cache: std::collections::HashMap::new(),
+            text_cache: TextCache::new(DEFAULT_TEXT_CACHE_CAPACITY),
             _camera_bind_group_layout: camera_bind_group_layout,
             _lighting_bind_group_layout: lighting_bind_group_layout,
-            _material_bind_group_layout: material_bind_group_layout,
+            material_bind_group_layout,
             camera_buffer,
             lighting_buffer,
-            material_buffer,
             camera_bind_group,
             lighting_bind_group,
-            material_bind_group,
             camera_position,
+            camera_target,
             camera_rotation,
             camera_distance,
+            scene_bounds: None,
+            zoom_limits: DEFAULT_ZOOM_LIMITS,
             lights,
             ambient_color,
             ambient_intensity,
+            render_mode: RenderMode::default(),
+            wireframe_color: [0.9, 0.9, 0.9],
+            transparency: false,
+            floor_contours: Vec::new(),
+            label_depth_test: false,
             camera_dirty: true,
             lights_dirty: true,
             last_aspect_ratio: size.width as f32 / size.height as f32,
@@ -672,7 +860,6 @@ impl Wgpu3DLitRenderer {
         // 初始化统一缓冲区
         renderer.update_camera_buffer(size.width as f32 / size.height as f32);
         renderer.update_lighting_buffer();
-        renderer.update_material_buffer(&Material::data_visualization()[0]); // 使用默认材质
 
         Ok((renderer, surface))
     }
@@ -686,8 +873,9 @@ impl Wgpu3DLitRenderer {
         height: u32,
     ) -> Option<(f32, f32)> {
         // 构造与 uniform 一致的视图投影
-    let view = Matrix4::look_at_rh(&self.camera_position, &Point3::origin(), &Vector3::z());
-        let proj = Matrix4::new_perspective(aspect_ratio, 45.0_f32.to_radians(), 0.1, 100.0);
+    let view = Matrix4::look_at_rh(&self.camera_position, &self.camera_target, &Vector3::z());
+        let proj =
+            Matrix4::new_perspective(aspect_ratio, 45.0_f32.to_radians(), 0.1, self.far_plane());
         let mvp = proj * view;
         let hp = Vector4::new(p.x, p.y, p.z, 1.0);
         let cp = mvp * hp;
@@ -695,7 +883,7 @@ impl Wgpu3DLitRenderer {
         let ndc_x = cp.x / cp.w;
         let ndc_y = cp.y / cp.w;
         let ndc_z = cp.z / cp.w;
-        if ndc_z < -1.0 || ndc_z > 1.0 { return None; }
+        if !(-1.0..=1.0).contains(&ndc_z) { return None; }
         // 转屏幕像素
         let sx = (ndc_x * 0.5 + 0.5) * width as f32;
         let sy = (1.0 - (ndc_y * 0.5 + 0.5)) * height as f32;
@@ -706,22 +894,27 @@ impl Wgpu3DLitRenderer {
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
         view: &wgpu::TextureView,
-        aspect: f32,
         width: u32,
         height: u32,
         render_data: &Axis3DRenderData,
+        objects: &[(Vec<Vertex3DLit>, Vec<u16>, Material)],
     ) -> Result<()> {
+        // 与相机 uniform 使用相同的宽高比
+        let aspect = self.last_aspect_ratio;
         // 收集屏幕文本 (content, x, y, size, color)
         let mut texts: Vec<(String, f32, f32, f32, Color)> = Vec::new();
+        let visible = |pos: Point3<f32>| !self.label_depth_test || !self.is_occluded(pos, objects);
 
         // 刻度标签
         for (pos, content, _dir) in &render_data.tick_labels {
+            if !visible(*pos) { continue; }
             if let Some((x, y)) = self.world_to_screen(*pos, aspect, width, height) {
                 texts.push((content.clone(), x, y, 16.0, Color::WHITE));
             }
         }
         // 轴标题
         for (pos, content, _dir) in &render_data.axis_titles {
+            if !visible(*pos) { continue; }
             if let Some((x, y)) = self.world_to_screen(*pos, aspect, width, height) {
                 texts.push((content.clone(), x, y, 18.0, Color::rgb(1.0, 1.0, 0.8)));
             }
@@ -730,21 +923,25 @@ impl Wgpu3DLitRenderer {
         if texts.is_empty() { return Ok(()); }
 
         // 第一阶段：确保/更新缓存
+        self.text_cache.begin_frame();
         for (content, _x, _y, size, _color) in texts.iter() {
             let key = (content.clone(), *size as u32);
-            if !self.text_cache.contains_key(&key) {
-                let mut buf = GlyphBuffer::new(&mut self.font_system, Metrics::new(*size, *size));
-                buf.set_size(&mut self.font_system, width as f32, height as f32);
+            let font_system = &mut self.font_system;
+            self.text_cache.get_or_insert_with(&key, || {
+                let mut buf = GlyphBuffer::new(font_system, Metrics::new(*size, *size));
+                buf.set_size(font_system, width as f32, height as f32);
                 buf.set_text(
-                    &mut self.font_system,
+                    font_system,
                     content,
                     Attrs::new().family(Family::SansSerif),
                     Shaping::Advanced,
                 );
-                buf.set_wrap(&mut self.font_system, Wrap::None);
-                self.text_cache.insert(key, buf);
-            }
+                buf.set_wrap(font_system, Wrap::None);
+                buf
+            });
         }
+        // 本帧用到的文本不会被淘汰
+        self.text_cache.trim();
 
         // 构造 TextArea
         let mut areas: Vec<TextArea> = Vec::new();
@@ -792,13 +989,35 @@ impl Wgpu3DLitRenderer {
         Ok(())
     }
 
+    /// 从相机到该点的视线是否被物体的三角形挡住（相当于在 CPU 上做该点的深度测试）；
+    /// 启用混合时半透明物体不遮挡
+    fn is_occluded(
+        &self,
+        point: Point3<f32>,
+        objects: &[(Vec<Vertex3DLit>, Vec<u16>, Material)],
+    ) -> bool {
+        let origin = self.camera_position;
+        let direction = point - origin;
+
+        objects
+            .iter()
+            .filter(|(_, _, material)| !(self.transparency && material.is_transparent()))
+            .any(|(vertices, indices, _)| {
+                indices.chunks_exact(3).any(|triangle| {
+                    let corner = |i: usize| Point3::from(vertices[triangle[i] as usize].position);
+                    segment_hits_triangle(origin, direction, [corner(0), corner(1), corner(2)])
+                })
+            })
+    }
+
     /// 更新相机缓冲区
     fn update_camera_buffer(&self, aspect_ratio: f32) {
         // 计算视图矩阵
-    let view = Matrix4::look_at_rh(&self.camera_position, &Point3::origin(), &Vector3::z());
+    let view = Matrix4::look_at_rh(&self.camera_position, &self.camera_target, &Vector3::z());
 
         // 计算投影矩阵
-        let proj = Matrix4::new_perspective(aspect_ratio, 45.0_f32.to_radians(), 0.1, 100.0);
+        let proj =
+            Matrix4::new_perspective(aspect_ratio, 45.0_f32.to_radians(), 0.1, self.far_plane());
 
         let camera_uniform = CameraUniform {
             view_proj: (proj * view).into(),
@@ -827,9 +1046,9 @@ impl Wgpu3DLitRenderer {
             _padding: [0.0; 2],
             _extra_pad: [0.0; 3],
             _pad_end: 0.0,
-        }; 8];
+        }; MAX_LIGHTS];
 
-        for (i, light) in self.lights.iter().take(8).enumerate() {
+        for (i, light) in self.lights.iter().enumerate() {
             let (position, direction, light_type_id, radius, inner_angle) = match &light.light_type
             {
                 LightType::Directional { direction } => (
@@ -874,7 +1093,7 @@ impl Wgpu3DLitRenderer {
         let lighting_uniform = LightingUniform {
             ambient_color: self.ambient_color,
             ambient_intensity: self.ambient_intensity,
-            num_lights: self.lights.len().min(8) as f32,
+            num_lights: self.lights.len() as f32,
             _padding: 0.0,
             _padding2: [0.0; 2],
             lights: light_uniforms,
@@ -887,8 +1106,8 @@ impl Wgpu3DLitRenderer {
             .write_buffer(&self.lighting_buffer, 0, buffer_data);
     }
 
-    /// 更新材质缓冲区
-    fn update_material_buffer(&self, material: &Material) {
+    /// 为物体创建材质绑定组；每个物体使用独立的缓冲区，绘制时材质互不覆盖
+    fn create_material_bind_group(&self, material: &Material) -> BindGroup {
         let material_uniform = MaterialUniform {
             albedo: [material.albedo.r, material.albedo.g, material.albedo.b],
             metallic: material.metallic,
@@ -900,14 +1119,25 @@ impl Wgpu3DLitRenderer {
                 material.emissive.g,
                 material.emissive.b,
             ],
-            _padding2: 0.0,
+            alpha: material.albedo.a,
         };
 
-        self.queue.write_buffer(
-            &self.material_buffer,
-            0,
-            bytemuck::cast_slice(&[material_uniform]),
-        );
+        let buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Material Buffer"),
+                contents: bytemuck::cast_slice(&[material_uniform]),
+                usage: BufferUsages::UNIFORM,
+            });
+
+        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &self.material_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+            label: Some("material_bind_group"),
+        })
     }
 
     /// 设置相机位置
@@ -920,63 +1150,171 @@ impl Wgpu3DLitRenderer {
         self.camera_rotation.0 += delta_yaw;
         self.camera_rotation.1 = (self.camera_rotation.1 + delta_pitch).clamp(-1.5, 1.5);
 
-        // 更新相机位置 (轨道相机 - 围绕原点旋转)
-        let cos_pitch = self.camera_rotation.1.cos();
-        let sin_pitch = self.camera_rotation.1.sin();
-        let cos_yaw = self.camera_rotation.0.cos();
-        let sin_yaw = self.camera_rotation.0.sin();
-
-        self.camera_position = Point3::new(
-            self.camera_distance * cos_pitch * cos_yaw,
-            self.camera_distance * cos_pitch * sin_yaw,
-            self.camera_distance * sin_pitch,
-        );
-        
-        self.camera_dirty = true;
+        // 更新相机位置 (轨道相机 - 围绕目标点旋转)
+        self.update_orbit_position();
     }
 
     /// 缩放相机 (调整距离)
     pub fn zoom_camera(&mut self, factor: f32) {
-        self.camera_distance = (self.camera_distance * factor).clamp(2.0, 100.0);
+        let (min, max) = self.zoom_limits;
+        self.camera_distance = (self.camera_distance * factor).clamp(min, max);
 
         // 更新相机位置
-        let cos_pitch = self.camera_rotation.1.cos();
-        let sin_pitch = self.camera_rotation.1.sin();
-        let cos_yaw = self.camera_rotation.0.cos();
-        let sin_yaw = self.camera_rotation.0.sin();
-
-        self.camera_position = Point3::new(
-            self.camera_distance * cos_pitch * cos_yaw,
-            self.camera_distance * cos_pitch * sin_yaw,
-            self.camera_distance * sin_pitch,
-        );
-        
-        self.camera_dirty = true;
+        self.update_orbit_position();
     }
 
-    /// 重置相机
+    /// 重置相机；设置了场景边界时自动取景到该边界
     pub fn reset_camera(&mut self) {
         self.camera_rotation = (0.7, 0.5); // 更好的初始角度
-        self.camera_distance = 10.0;
-        
+        match self.scene_bounds {
+            Some(bounds) => self.frame_bounds(bounds),
+            None => {
+                self.camera_distance = 10.0;
+                self.camera_target = Point3::origin();
+                self.zoom_limits = DEFAULT_ZOOM_LIMITS;
+                self.update_orbit_position();
+            }
+        }
+    }
+
+    /// 设置场景边界，`reset_camera` 时据此自动取景
+    pub fn set_scene_bounds(&mut self, bounds: Option<BoundingBox3D>) {
+        self.scene_bounds = bounds;
+    }
+
+    /// 自动取景：观察目标移到边界框中心，并调整相机距离使整个边界框留有余量地落在当前视野内
+    pub fn frame_bounds(&mut self, bounds: BoundingBox3D) {
+        let ((x_min, x_max), (y_min, y_max), (z_min, z_max)) = bounds;
+        let center = Point3::new(
+            (x_min + x_max) / 2.0,
+            (y_min + y_max) / 2.0,
+            (z_min + z_max) / 2.0,
+        );
+        // 使用包围球半径，保证任意旋转角度下都完整可见
+        let radius = (Vector3::new(x_max - x_min, y_max - y_min, z_max - z_min).norm() / 2.0)
+            .max(1e-3);
+
+        // 与投影矩阵一致的 45° 垂直视野；窄窗口时以水平视野为准
+        let half_fov_y = 45.0_f32.to_radians() / 2.0;
+        let half_fov_x = (half_fov_y.tan() * self.last_aspect_ratio).atan();
+        let half_fov = half_fov_y.min(half_fov_x);
+
+        self.camera_target = center;
+        self.camera_distance = radius * FRAME_MARGIN / half_fov.sin();
+        // 缩放范围随取景距离调整，避免缩放时跳回默认范围
+        self.zoom_limits = (
+            (self.camera_distance * 0.05).min(DEFAULT_ZOOM_LIMITS.0),
+            (self.camera_distance * 10.0).max(DEFAULT_ZOOM_LIMITS.1),
+        );
+        self.update_orbit_position();
+    }
+
+    /// 平移相机：在相机的右/上平面内移动观察目标，位移按相机距离缩放
+    pub fn pan_camera(&mut self, dx: f32, dy: f32) {
+        let forward = self.camera_target - self.camera_position;
+        let right = forward.cross(&Vector3::z());
+        if right.norm() < 1e-6 {
+            return;
+        }
+        let right = right.normalize();
+        let up = right.cross(&forward).normalize();
+
+        self.camera_target += (right * -dx + up * dy) * self.camera_distance;
+        self.update_orbit_position();
+    }
+
+    /// 设置相机观察目标
+    pub fn set_camera_target(&mut self, target: Point3<f32>) {
+        self.camera_target = target;
+        self.update_orbit_position();
+    }
+
+    /// 获取相机观察目标
+    pub fn camera_target(&self) -> Point3<f32> {
+        self.camera_target
+    }
+
+    /// 远裁剪面：随相机距离增大，避免自动取景后的大场景被裁掉
+    fn far_plane(&self) -> f32 {
+        (self.camera_distance * 4.0).max(100.0)
+    }
+
+    /// 根据目标点、旋转角和距离重新计算相机位置
+    fn update_orbit_position(&mut self) {
         let cos_pitch = self.camera_rotation.1.cos();
         let sin_pitch = self.camera_rotation.1.sin();
         let cos_yaw = self.camera_rotation.0.cos();
         let sin_yaw = self.camera_rotation.0.sin();
 
-        self.camera_position = Point3::new(
-            self.camera_distance * cos_pitch * cos_yaw,
-            self.camera_distance * cos_pitch * sin_yaw,
-            self.camera_distance * sin_pitch,
-        );
-        
+        self.camera_position = self.camera_target
+            + Vector3::new(
+                self.camera_distance * cos_pitch * cos_yaw,
+                self.camera_distance * cos_pitch * sin_yaw,
+                self.camera_distance * sin_pitch,
+            );
+
         self.camera_dirty = true;
     }
 
-    /// 添加光源
-    pub fn add_light(&mut self, light: Light) {
+    /// 添加光源；超过 GPU uniform 支持的 8 个光源时返回错误
+    pub fn add_light(&mut self, light: Light) -> Result<()> {
+        if self.lights.len() >= MAX_LIGHTS {
+            return Err(VizuaraError::ConfigError(format!(
+                "Cannot add light: at most {} lights are supported",
+                MAX_LIGHTS
+            )));
+        }
         self.lights.push(light);
         self.lights_dirty = true;
+        Ok(())
+    }
+
+    /// 移除指定索引的光源
+    pub fn remove_light(&mut self, index: usize) -> Result<Light> {
+        if index >= self.lights.len() {
+            return Err(VizuaraError::ConfigError(format!(
+                "Light index {} out of range ({} lights)",
+                index,
+                self.lights.len()
+            )));
+        }
+        self.lights_dirty = true;
+        Ok(self.lights.remove(index))
+    }
+
+    /// 替换指定索引的光源
+    pub fn set_light(&mut self, index: usize, light: Light) -> Result<()> {
+        let count = self.lights.len();
+        let slot = self.lights.get_mut(index).ok_or_else(|| {
+            VizuaraError::ConfigError(format!(
+                "Light index {} out of range ({} lights)",
+                index, count
+            ))
+        })?;
+        *slot = light;
+        self.lights_dirty = true;
+        Ok(())
+    }
+
+    /// 移除所有光源（仅保留环境光）
+    pub fn clear_lights(&mut self) {
+        self.lights.clear();
+        self.lights_dirty = true;
+    }
+
+    /// 当前光源列表
+    pub fn lights(&self) -> &[Light] {
+        &self.lights
+    }
+
+    /// 设置文本缓存容量（条目数），超出时淘汰最久未使用的文本
+    pub fn set_text_cache_capacity(&mut self, capacity: usize) {
+        self.text_cache.set_capacity(capacity);
+    }
+
+    /// 当前缓存的文本条目数
+    pub fn text_cache_len(&self) -> usize {
+        self.text_cache.len()
     }
 
     /// 设置环境光
@@ -986,6 +1324,161 @@ impl Wgpu3DLitRenderer {
         self.lights_dirty = true;
     }
 
+    /// 设置网格渲染模式
+    pub fn set_render_mode(&mut self, mode: RenderMode) {
+        self.render_mode = mode;
+    }
+
+    /// 获取当前渲染模式
+    pub fn render_mode(&self) -> RenderMode {
+        self.render_mode
+    }
+
+    /// 设置线框颜色
+    pub fn set_wireframe_color(&mut self, color: Color) {
+        self.wireframe_color = [color.r, color.g, color.b];
+    }
+
+    /// 是否对半透明材质（alpha < 1）的物体启用 alpha 混合；
+    /// 关闭时所有物体按不透明绘制
+    pub fn set_transparency(&mut self, enabled: bool) {
+        self.transparency = enabled;
+    }
+
+    /// 是否启用了半透明混合
+    pub fn transparency(&self) -> bool {
+        self.transparency
+    }
+
+    /// 是否对坐标轴刻度标签与轴标题做深度测试：开启后，从相机看被物体遮挡的标签不再绘制；
+    /// 关闭时（默认）标签始终覆盖在最上层
+    pub fn set_label_depth_test(&mut self, enabled: bool) {
+        self.label_depth_test = enabled;
+    }
+
+    /// 是否启用了标签深度测试
+    pub fn label_depth_test(&self) -> bool {
+        self.label_depth_test
+    }
+
+    /// 设置表面的底面等高线（见 `Surface3D::floor_contour_lines`），
+    /// 在 `render_with_axes` 中经坐标系换算后与坐标轴线条一起绘制
+    pub fn set_floor_contours(&mut self, contours: Vec<FloorContour>) {
+        self.floor_contours = contours;
+    }
+
+    /// 物体的绘制顺序：不透明物体保持原顺序在前；启用混合时半透明物体排在最后，
+    /// 按顶点中心到相机的距离由远及近排列（半透明面不写深度，需从后往前混合）
+    fn draw_order(&self, objects: &[(Vec<Vertex3DLit>, Vec<u16>, Material)]) -> Vec<(usize, bool)> {
+        let blended = |material: &Material| self.transparency && material.is_transparent();
+
+        let mut order: Vec<(usize, bool)> = objects
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, _, material))| !blended(material))
+            .map(|(i, _)| (i, false))
+            .collect();
+
+        let mut transparent: Vec<(usize, f32)> = objects
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, _, material))| blended(material))
+            .map(|(i, (vertices, _, _))| (i, self.distance_to_camera(vertices)))
+            .collect();
+        transparent.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        order.extend(transparent.into_iter().map(|(i, _)| (i, true)));
+        order
+    }
+
+    /// 顶点中心到相机的距离
+    fn distance_to_camera(&self, vertices: &[Vertex3DLit]) -> f32 {
+        if vertices.is_empty() {
+            return 0.0;
+        }
+        let sum = vertices.iter().fold(Vector3::zeros(), |acc, v| {
+            acc + Vector3::from(v.position)
+        });
+        let center = Point3::from(sum / vertices.len() as f32);
+        nalgebra::distance(&center, &self.camera_position)
+    }
+
+    /// 按绘制顺序为物体创建顶点、索引与材质资源
+    fn create_object_buffers(
+        &self,
+        objects: &[(Vec<Vertex3DLit>, Vec<u16>, Material)],
+    ) -> Vec<ObjectBuffers> {
+        self.draw_order(objects)
+            .into_iter()
+            .map(|(i, transparent)| {
+                let (vertices, indices, material) = &objects[i];
+                ObjectBuffers {
+                    vertex_buffer: self.device.create_buffer_init(
+                        &wgpu::util::BufferInitDescriptor {
+                            label: Some("Vertex Buffer"),
+                            contents: bytemuck::cast_slice(vertices),
+                            usage: BufferUsages::VERTEX,
+                        },
+                    ),
+                    index_buffer: self.device.create_buffer_init(
+                        &wgpu::util::BufferInitDescriptor {
+                            label: Some("Index Buffer"),
+                            contents: bytemuck::cast_slice(indices),
+                            usage: BufferUsages::INDEX,
+                        },
+                    ),
+                    material_bind_group: self.create_material_bind_group(material),
+                    index_count: indices.len() as u32,
+                    transparent,
+                }
+            })
+            .collect()
+    }
+
+    /// 为所有物体生成线框顶点（LineList）
+    fn create_wireframe_vertices(
+        &self,
+        objects: &[(Vec<Vertex3DLit>, Vec<u16>, Material)],
+    ) -> Vec<AxisVertex> {
+        let mut vertices = Vec::new();
+        for (object_vertices, indices, _) in objects {
+            for (a, b) in wireframe_edges(indices) {
+                if let (Some(start), Some(end)) = (
+                    object_vertices.get(a as usize),
+                    object_vertices.get(b as usize),
+                ) {
+                    vertices.push(AxisVertex::new(start.position, self.wireframe_color));
+                    vertices.push(AxisVertex::new(end.position, self.wireframe_color));
+                }
+            }
+        }
+        vertices
+    }
+
+    /// 创建线框顶点缓冲区（实体模式下不需要）
+    fn create_wireframe_buffer(
+        &self,
+        objects: &[(Vec<Vertex3DLit>, Vec<u16>, Material)],
+    ) -> Option<(Buffer, u32)> {
+        if self.render_mode == RenderMode::Solid {
+            return None;
+        }
+
+        let vertices = self.create_wireframe_vertices(objects);
+        if vertices.is_empty() {
+            return None;
+        }
+
+        let buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Wireframe Vertex Buffer"),
+                contents: bytemuck::cast_slice(&vertices),
+                usage: BufferUsages::VERTEX,
+            });
+        Some((buffer, vertices.len() as u32))
+    }
+
     /// 渲染多个物体（新的批量渲染方法）
     pub fn render_multiple(
         &mut self,
@@ -1005,27 +1498,10 @@ impl Wgpu3DLitRenderer {
             self.lights_dirty = false;
         }
 
-        // 为所有物体预先创建缓冲区
-        let mut buffers = Vec::new();
-        for (vertices, indices, material) in objects {
-            let vertex_buffer = self
-                .device
-                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Vertex Buffer"),
-                    contents: bytemuck::cast_slice(vertices),
-                    usage: BufferUsages::VERTEX,
-                });
-
-            let index_buffer = self
-                .device
-                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Index Buffer"),
-                    contents: bytemuck::cast_slice(indices),
-                    usage: BufferUsages::INDEX,
-                });
-
-            buffers.push((vertex_buffer, index_buffer, material.clone(), indices.len()));
-        }
+        // 为所有物体预先创建缓冲区（按绘制顺序）
+        let buffers = self.create_object_buffers(objects);
+
+        let wireframe_buffer = self.create_wireframe_buffer(objects);
 
         // 获取当前帧
         let output = surface.get_current_texture().map_err(|e| {
@@ -1092,18 +1568,37 @@ impl Wgpu3DLitRenderer {
             });
 
             // 渲染所有物体
-            for (vertex_buffer, index_buffer, material, index_count) in &buffers {
-                // 更新材质缓冲区
-                self.update_material_buffer(material);
+            let fill_pipeline = match self.render_mode {
+                RenderMode::Solid => Some(&self.render_pipeline),
+                RenderMode::SolidWithWireframe => Some(&self.biased_render_pipeline),
+                RenderMode::Wireframe => None,
+            };
+            if let Some(fill_pipeline) = fill_pipeline {
+                for object in &buffers {
+                    // 半透明物体排在最后，使用混合管线
+                    let pipeline = if object.transparent {
+                        &self.transparent_pipeline
+                    } else {
+                        fill_pipeline
+                    };
+
+                    // 设置渲染状态并绘制
+                    render_pass.set_pipeline(pipeline);
+                    render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+                    render_pass.set_bind_group(1, &self.lighting_bind_group, &[]);
+                    render_pass.set_bind_group(2, &object.material_bind_group, &[]);
+                    render_pass.set_vertex_buffer(0, object.vertex_buffer.slice(..));
+                    render_pass.set_index_buffer(object.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+                    render_pass.draw_indexed(0..object.index_count, 0, 0..1);
+                }
+            }
 
-                // 设置渲染状态并绘制
-                render_pass.set_pipeline(&self.render_pipeline);
+            // 线框叠加在填充面之后绘制
+            if let Some((ref wireframe_buffer, vertex_count)) = wireframe_buffer {
+                render_pass.set_pipeline(&self.wireframe_pipeline);
                 render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
-                render_pass.set_bind_group(1, &self.lighting_bind_group, &[]);
-                render_pass.set_bind_group(2, &self.material_bind_group, &[]);
-                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-                render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-                render_pass.draw_indexed(0..*index_count as u32, 0, 0..1);
+                render_pass.set_vertex_buffer(0, wireframe_buffer.slice(..));
+                render_pass.draw(0..vertex_count, 0..1);
             }
         }
 
@@ -1134,31 +1629,13 @@ impl Wgpu3DLitRenderer {
             self.lights_dirty = false;
         }
 
-        // 为所有物体预先创建缓冲区
-        let mut buffers = Vec::new();
-        for (vertices, indices, material) in objects {
-            let vertex_buffer = self
-                .device
-                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Vertex Buffer"),
-                    contents: bytemuck::cast_slice(vertices),
-                    usage: BufferUsages::VERTEX,
-                });
-
-            let index_buffer = self
-                .device
-                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Index Buffer"),
-                    contents: bytemuck::cast_slice(indices),
-                    usage: BufferUsages::INDEX,
-                });
-
-            buffers.push((vertex_buffer, index_buffer, material.clone(), indices.len()));
-        }
+        // 为所有物体预先创建缓冲区（按绘制顺序）
+        let buffers = self.create_object_buffers(objects);
 
         // 生成坐标轴渲染数据
         let axis_render_data = coordinate_system.generate_render_data();
-        let axis_vertices = self.create_axis_vertices(&axis_render_data);
+        let mut axis_vertices = self.create_axis_vertices(&axis_render_data);
+        axis_vertices.extend(self.create_contour_vertices(coordinate_system));
         let plane_vertices = self.create_plane_vertices(&axis_render_data);
         let text_vertices = self.create_text_vertices(&axis_render_data);
         
@@ -1192,6 +1669,8 @@ impl Wgpu3DLitRenderer {
             None
         };
 
+        let wireframe_buffer = self.create_wireframe_buffer(objects);
+
         // 获取当前帧
         let output = surface.get_current_texture().map_err(|e| {
             VizuaraError::RenderError(format!("Failed to get surface texture: {}", e))
@@ -1273,18 +1752,37 @@ impl Wgpu3DLitRenderer {
             }
 
             // 渲染所有物体
-            for (vertex_buffer, index_buffer, material, index_count) in &buffers {
-                // 更新材质缓冲区
-                self.update_material_buffer(material);
+            let fill_pipeline = match self.render_mode {
+                RenderMode::Solid => Some(&self.render_pipeline),
+                RenderMode::SolidWithWireframe => Some(&self.biased_render_pipeline),
+                RenderMode::Wireframe => None,
+            };
+            if let Some(fill_pipeline) = fill_pipeline {
+                for object in &buffers {
+                    // 半透明物体排在最后，使用混合管线
+                    let pipeline = if object.transparent {
+                        &self.transparent_pipeline
+                    } else {
+                        fill_pipeline
+                    };
+
+                    // 设置渲染状态并绘制
+                    render_pass.set_pipeline(pipeline);
+                    render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+                    render_pass.set_bind_group(1, &self.lighting_bind_group, &[]);
+                    render_pass.set_bind_group(2, &object.material_bind_group, &[]);
+                    render_pass.set_vertex_buffer(0, object.vertex_buffer.slice(..));
+                    render_pass.set_index_buffer(object.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+                    render_pass.draw_indexed(0..object.index_count, 0, 0..1);
+                }
+            }
 
-                // 设置渲染状态并绘制
-                render_pass.set_pipeline(&self.render_pipeline);
+            // 线框叠加在填充面之后绘制
+            if let Some((ref wireframe_buffer, vertex_count)) = wireframe_buffer {
+                render_pass.set_pipeline(&self.wireframe_pipeline);
                 render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
-                render_pass.set_bind_group(1, &self.lighting_bind_group, &[]);
-                render_pass.set_bind_group(2, &self.material_bind_group, &[]);
-                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-                render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-                render_pass.draw_indexed(0..*index_count as u32, 0, 0..1);
+                render_pass.set_vertex_buffer(0, wireframe_buffer.slice(..));
+                render_pass.draw(0..vertex_count, 0..1);
             }
         }
 
@@ -1296,10 +1794,10 @@ impl Wgpu3DLitRenderer {
             let _ = self.draw_overlay_texts_for_axes(
                 &mut encoder,
                 &view,
-                aspect_ratio,
                 width,
                 height,
                 &axis_render_data,
+                objects,
             );
         }
 
@@ -1340,6 +1838,25 @@ impl Wgpu3DLitRenderer {
         Ok(())
     }
 
+    /// 将底面等高线换算到坐标系中，生成线条顶点
+    fn create_contour_vertices(&self, coordinate_system: &CoordinateSystem3D) -> Vec<AxisVertex> {
+        let mut vertices = Vec::new();
+
+        for contour in &self.floor_contours {
+            let color = [contour.color.r, contour.color.g, contour.color.b];
+            for chunk in contour.lines.chunks(2) {
+                if chunk.len() == 2 {
+                    let start = coordinate_system.data_to_coords(chunk[0]);
+                    let end = coordinate_system.data_to_coords(chunk[1]);
+                    vertices.push(AxisVertex::new(start.coords.into(), color));
+                    vertices.push(AxisVertex::new(end.coords.into(), color));
+                }
+            }
+        }
+
+        vertices
+    }
+
     /// 从坐标轴渲染数据创建顶点
     fn create_axis_vertices(&self, render_data: &Axis3DRenderData) -> Vec<AxisVertex> {
         let mut vertices = Vec::new();
@@ -1524,21 +2041,32 @@ impl Wgpu3DLitRenderer {
         vertices
     }
 
+    /// 切换呈现模式并重新配置表面，返回实际使用的模式
+    ///
+    /// Fifo 即垂直同步；Immediate/Mailbox 不等待垂直同步，适合性能测试与低延迟交互
+    pub fn set_present_mode(
+        &mut self,
+        present_mode: wgpu::PresentMode,
+        surface: &Surface,
+    ) -> wgpu::PresentMode {
+        let supported = surface.get_capabilities(&self.adapter).present_modes;
+        self.config.present_mode = select_present_mode(&supported, Some(present_mode));
+        surface.configure(&self.device, &self.config);
+        self.config.present_mode
+    }
+
+    /// 当前的呈现模式
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.config.present_mode
+    }
+
     /// 调整渲染器大小
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>, surface: &Surface) {
         if new_size.width > 0 && new_size.height > 0 {
-            let surface_caps = surface.get_capabilities(&self.adapter);
-            let config = SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                format: surface_caps.formats[0],
-                width: new_size.width,
-                height: new_size.height,
-                present_mode: wgpu::PresentMode::Fifo,
-                alpha_mode: wgpu::CompositeAlphaMode::Auto,
-                view_formats: vec![],
-                desired_maximum_frame_latency: 2,
-            };
-            surface.configure(&self.device, &config);
+            // 沿用创建时的格式与呈现模式，管线按该格式创建
+            self.config.width = new_size.width;
+            self.config.height = new_size.height;
+            surface.configure(&self.device, &self.config);
             // 更新文本缓存尺寸
             // glyphon 的 Buffer 在 prepare 时会被重新适配
         }
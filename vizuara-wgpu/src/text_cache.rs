@@ -0,0 +1,181 @@
+//! 文本排版缓存
+//!
+//! 按最近使用时间淘汰（LRU），避免每帧生成新文本（如动画中的数值标签）时缓存无限增长
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 默认缓存容量（条目数）
+pub(crate) const DEFAULT_TEXT_CACHE_CAPACITY: usize = 512;
+
+/// 带容量上限的文本缓存
+///
+/// 以帧为单位记录使用时间：当前帧用到的条目不会被淘汰，
+/// 因此单帧所需文本多于容量时缓存会暂时超出上限
+pub(crate) struct TextCache<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    capacity: usize,
+    frame: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> TextCache<K, V> {
+    /// 创建指定容量的缓存
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity,
+            frame: 0,
+        }
+    }
+
+    /// 开始新的一帧
+    pub(crate) fn begin_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// 获取条目，不存在时创建；同时标记为当前帧使用
+    pub(crate) fn get_or_insert_with(&mut self, key: &K, create: impl FnOnce() -> V) -> &mut V {
+        let frame = self.frame;
+        let entry = self
+            .entries
+            .entry(key.clone())
+            .or_insert_with(|| (create(), frame));
+        entry.1 = frame;
+        &mut entry.0
+    }
+
+    /// 获取条目（不更新使用时间）
+    pub(crate) fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    /// 淘汰最久未使用的条目直到不超过容量，当前帧使用过的条目保留
+    pub(crate) fn trim(&mut self) {
+        if self.entries.len() <= self.capacity {
+            return;
+        }
+
+        let mut stale: Vec<(u64, K)> = self
+            .entries
+            .iter()
+            .filter(|(_, (_, used))| *used < self.frame)
+            .map(|(key, (_, used))| (*used, key.clone()))
+            .collect();
+        stale.sort_by_key(|(used, _)| *used);
+
+        let excess = self.entries.len() - self.capacity;
+        for (_, key) in stale.into_iter().take(excess) {
+            self.entries.remove(&key);
+        }
+    }
+
+    /// 设置容量并立即淘汰多余条目
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.trim();
+    }
+
+    /// 缓存容量
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 当前条目数
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 清空缓存
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在新的一帧中依次使用给定的键
+    fn use_frame(cache: &mut TextCache<&'static str, usize>, keys: &[&'static str]) {
+        cache.begin_frame();
+        for key in keys {
+            cache.get_or_insert_with(key, || key.len());
+        }
+    }
+
+    #[test]
+    fn test_get_or_insert_creates_once() {
+        let mut cache = TextCache::new(4);
+        let mut created = 0;
+        for _ in 0..3 {
+            cache.get_or_insert_with(&"a", || {
+                created += 1;
+                1
+            });
+        }
+        assert_eq!(created, 1);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_trim_evicts_least_recently_used() {
+        let mut cache = TextCache::new(2);
+        use_frame(&mut cache, &["a"]);
+        use_frame(&mut cache, &["b"]);
+        use_frame(&mut cache, &["c"]);
+        // 不超过容量时不淘汰
+        use_frame(&mut cache, &["a"]);
+        assert_eq!(cache.len(), 3);
+
+        // "b" 最久未使用，先被淘汰；"a" 刚被使用而保留
+        cache.trim();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&"b").is_none());
+        assert!(cache.get(&"a").is_some());
+        assert!(cache.get(&"c").is_some());
+    }
+
+    #[test]
+    fn test_trim_keeps_current_frame_entries() {
+        let mut cache = TextCache::new(2);
+        use_frame(&mut cache, &["old"]);
+        use_frame(&mut cache, &["a", "b", "c"]);
+
+        // 当前帧的文本多于容量：只淘汰旧条目，缓存暂时超出上限
+        cache.trim();
+        assert_eq!(cache.len(), 3);
+        assert!(cache.get(&"old").is_none());
+
+        // 下一帧不再使用时恢复到容量以内
+        use_frame(&mut cache, &["c"]);
+        cache.trim();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&"c").is_some());
+    }
+
+    #[test]
+    fn test_set_capacity_shrinks_immediately() {
+        let mut cache = TextCache::new(8);
+        use_frame(&mut cache, &["a"]);
+        use_frame(&mut cache, &["b"]);
+        use_frame(&mut cache, &["c"]);
+        use_frame(&mut cache, &["d"]);
+        assert_eq!(cache.len(), 4);
+
+        cache.set_capacity(2);
+        assert_eq!(cache.capacity(), 2);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&"c").is_some());
+        assert!(cache.get(&"d").is_some());
+
+        // 容量为 0 时仍保留当前帧的条目
+        cache.set_capacity(0);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&"d").is_some());
+
+        cache.clear();
+        assert_eq!(cache.len(), 0);
+    }
+}
@@ -4,6 +4,7 @@
 
 use std::sync::Arc;
 use vizuara_core::{Result, Style, VizuaraError};
+use vizuara_export::png::PngExporter;
 use vizuara_scene::Figure;
 use vizuara_wgpu::WgpuRenderer;
 use winit::{
@@ -14,12 +15,9 @@ use winit::{
 
 /// 专门用于渲染 Figure 的窗口应用
 pub struct FigureWindow {
-    #[allow(dead_code)]
-    title: String,
-    #[allow(dead_code)]
-    width: u32,
-    #[allow(dead_code)]
-    height: u32,
+    pub(crate) title: String,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
 }
 
 impl FigureWindow {
@@ -99,11 +97,7 @@ impl FigureWindowRunner {
         let primitives = self.figure.generate_primitives();
         println!("📊 生成了 {} 个渲染图元", primitives.len());
 
-        // 创建统一的样式（后续可以从 Figure 中获取）
-        let styles = vec![Style::new()
-            .fill_color(vizuara_core::Color::rgb(0.2, 0.4, 0.8))
-            .stroke(vizuara_core::Color::rgb(0.9, 0.2, 0.2), 2.0)
-            .marker(vizuara_core::MarkerStyle::Circle, 6.0)];
+        let styles = default_styles();
 
         let window_id = window.id();
         let window_for_redraw = Arc::clone(&window);
@@ -125,22 +119,25 @@ impl FigureWindowRunner {
                                 control_flow.exit();
                             }
 
-                            WindowEvent::Resized(physical_size) => {
-                                if physical_size.width > 0 && physical_size.height > 0 {
-                                    println!(
-                                        "📏 调整窗口大小: {}x{}",
-                                        physical_size.width, physical_size.height
-                                    );
-                                    renderer.resize(physical_size, &surface);
-                                    window_for_redraw.request_redraw();
-                                }
+                            WindowEvent::Resized(physical_size)
+                                if physical_size.width > 0 && physical_size.height > 0 =>
+                            {
+                                println!(
+                                    "📏 调整窗口大小: {}x{}",
+                                    physical_size.width, physical_size.height
+                                );
+                                renderer.resize(physical_size, &surface);
+                                window_for_redraw.request_redraw();
                             }
 
                             WindowEvent::RedrawRequested => {
                                 // 渲染 Figure 的图元
                                 match renderer.render(&surface, &primitives, &styles) {
                                     Ok(_) => {
-                                        // 渲染成功
+                                        // 渲染成功；若本帧带有截图请求则保存
+                                        if renderer.has_capture() {
+                                            report_screenshot(save_screenshot(&mut renderer));
+                                        }
                                     }
                                     Err(e) => {
                                         eprintln!("❌ 渲染错误: {}", e);
@@ -148,23 +145,29 @@ impl FigureWindowRunner {
                                 }
                             }
 
-                            WindowEvent::KeyboardInput { event, .. } => {
-                                if event.state == winit::event::ElementState::Pressed {
-                                    match event.physical_key {
-                                        winit::keyboard::PhysicalKey::Code(
-                                            winit::keyboard::KeyCode::Escape,
-                                        ) => {
-                                            println!("🔑 ESC 键退出");
-                                            control_flow.exit();
-                                        }
-                                        winit::keyboard::PhysicalKey::Code(
-                                            winit::keyboard::KeyCode::KeyR,
-                                        ) => {
-                                            println!("🔄 R 键刷新");
-                                            window_for_redraw.request_redraw();
-                                        }
-                                        _ => {}
+                            WindowEvent::KeyboardInput { event, .. }
+                                if event.state == winit::event::ElementState::Pressed =>
+                            {
+                                match event.physical_key {
+                                    winit::keyboard::PhysicalKey::Code(
+                                        winit::keyboard::KeyCode::Escape,
+                                    ) => {
+                                        println!("🔑 ESC 键退出");
+                                        control_flow.exit();
                                     }
+                                    winit::keyboard::PhysicalKey::Code(
+                                        winit::keyboard::KeyCode::KeyR,
+                                    ) => {
+                                        println!("🔄 R 键刷新");
+                                        window_for_redraw.request_redraw();
+                                    }
+                                    winit::keyboard::PhysicalKey::Code(
+                                        winit::keyboard::KeyCode::KeyS,
+                                    ) => match renderer.request_capture() {
+                                        Ok(()) => window_for_redraw.request_redraw(),
+                                        Err(e) => eprintln!("❌ 截图失败: {}", e),
+                                    },
+                                    _ => {}
                                 }
                             }
 
@@ -184,6 +187,37 @@ impl FigureWindowRunner {
     }
 }
 
+/// 渲染 Figure 使用的统一样式（后续可以从 Figure 中获取）
+pub(crate) fn default_styles() -> Vec<Style> {
+    vec![Style::new()
+        .fill_color(vizuara_core::Color::rgb(0.2, 0.4, 0.8))
+        .stroke(vizuara_core::Color::rgb(0.9, 0.2, 0.2), 2.0)
+        .marker(vizuara_core::MarkerStyle::Circle, 6.0)]
+}
+
+/// 输出截图结果
+pub(crate) fn report_screenshot(result: Result<String>) {
+    match result {
+        Ok(path) => println!("📸 截图已保存: {}", path),
+        Err(e) => eprintln!("❌ 截图失败: {}", e),
+    }
+}
+
+/// 读回已截取的帧并保存为带时间戳的 PNG 文件，返回文件路径
+pub(crate) fn save_screenshot(renderer: &mut WgpuRenderer) -> Result<String> {
+    let pixels = renderer.capture_frame()?;
+    let size = renderer.size();
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let path = format!("vizuara_screenshot_{}.png", timestamp);
+
+    PngExporter::save_rgba(&pixels, size.width, size.height, &path)?;
+    Ok(path)
+}
+
 /// 便捷方法：直接显示 Figure
 pub async fn show_figure_async(figure: Figure) -> Result<()> {
     let window = FigureWindowRunner::new(figure);
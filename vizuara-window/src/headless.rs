@@ -0,0 +1,153 @@
+//! 无 GPU/显示环境下的降级显示
+//!
+//! CI 与服务器上通常既没有显示服务器也没有可用的 GPU 适配器，
+//! 此时改用 CPU 光栅化导出图片，而不是在创建窗口或渲染器时失败
+
+use crate::figure_window::show_figure;
+use std::path::{Path, PathBuf};
+use vizuara_core::{Primitive, Result, Style, VizuaraError};
+use vizuara_export::ExportManager;
+use vizuara_scene::Figure;
+
+/// 当前环境可用的渲染方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderBackend {
+    /// 有显示环境和 GPU 适配器，可以打开窗口
+    Gpu,
+    /// 只能通过 CPU 光栅化导出图片
+    CpuRaster,
+}
+
+/// [`show_figure_or_export`] 实际采用的显示方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShowOutcome {
+    /// 已在窗口中显示，窗口关闭后返回
+    Window,
+    /// 无法打开窗口，已导出到该路径
+    Exported(PathBuf),
+}
+
+/// 检测当前环境能否打开 GPU 窗口：需要显示服务器与至少一个 wgpu 适配器
+///
+/// 只做快速检测，不创建窗口；检测通过后窗口创建仍可能失败
+pub fn render_mode() -> RenderBackend {
+    if has_display() && has_gpu_adapter() {
+        RenderBackend::Gpu
+    } else {
+        RenderBackend::CpuRaster
+    }
+}
+
+/// 尝试在窗口中显示 Figure；GPU 或窗口初始化失败时改为导出到 `fallback_path`
+///
+/// 导出格式按扩展名选择（.png / .svg / .jpg / .webp），返回实际采用的方式
+pub fn show_figure_or_export<P: AsRef<Path>>(
+    figure: Figure,
+    fallback_path: P,
+) -> Result<ShowOutcome> {
+    let fallback_path = fallback_path.as_ref();
+    let (width, height) = figure.size();
+    // 窗口会取得 Figure 的所有权，先生成导出所需的图元及各图表的样式
+    let (primitives, styles) = figure.generate_styled_primitives();
+
+    if render_mode() == RenderBackend::Gpu {
+        match show_figure(figure) {
+            Ok(()) => return Ok(ShowOutcome::Window),
+            Err(e) => eprintln!("⚠️ 无法打开窗口（{}），改为导出图片", e),
+        }
+    } else {
+        println!("ℹ️ 未检测到显示环境或 GPU，改为导出图片");
+    }
+
+    export_primitives(
+        &primitives,
+        &styles,
+        width as u32,
+        height as u32,
+        fallback_path,
+    )?;
+    println!("💾 已导出: {}", fallback_path.display());
+    Ok(ShowOutcome::Exported(fallback_path.to_path_buf()))
+}
+
+/// 按图元各自的样式导出，保留图表的颜色与线宽
+fn export_primitives(
+    primitives: &[Primitive],
+    styles: &[Style],
+    width: u32,
+    height: u32,
+    path: &Path,
+) -> Result<()> {
+    let path_str = path.to_string_lossy();
+    ExportManager::export_auto(primitives, styles, width, height, &path_str, None).map_err(|e| {
+        VizuaraError::RenderError(format!("Failed to export figure to '{}': {}", path_str, e))
+    })
+}
+
+/// 是否有可连接的显示服务器；Linux 等平台依据 DISPLAY / WAYLAND_DISPLAY，其余平台总有桌面会话
+fn has_display() -> bool {
+    if cfg!(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android"
+        ))
+    )) {
+        ["DISPLAY", "WAYLAND_DISPLAY"]
+            .iter()
+            .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty()))
+    } else {
+        true
+    }
+}
+
+/// 是否存在任意后端的 wgpu 适配器
+fn has_gpu_adapter() -> bool {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::all(),
+        ..Default::default()
+    });
+    !instance
+        .enumerate_adapters(wgpu::Backends::all())
+        .is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizuara_core::Color;
+    use vizuara_plots::{LinePlot, PlotArea, ScatterPlot};
+    use vizuara_scene::Scene;
+
+    #[test]
+    fn test_export_fallback_writes_file() {
+        let scatter = ScatterPlot::new()
+            .data(&[(1.0, 2.0), (2.0, 3.0)])
+            .color(Color::rgb(0.0, 1.0, 0.0))
+            .auto_scale();
+        let line = LinePlot::new()
+            .data(&[(1.0, 3.0), (2.0, 2.0)])
+            .color(Color::rgb(1.0, 0.0, 0.0))
+            .line_width(3.0)
+            .auto_scale();
+        let figure = Figure::new(320.0, 240.0).add_scene(
+            Scene::new(PlotArea::new(40.0, 40.0, 240.0, 160.0))
+                .add_scatter_plot(scatter)
+                .add_line_plot(line),
+        );
+
+        let path = std::env::temp_dir().join("vizuara_headless_fallback.svg");
+        let _ = std::fs::remove_file(&path);
+        let (primitives, styles) = figure.generate_styled_primitives();
+        export_primitives(&primitives, &styles, 320, 240, &path).unwrap();
+
+        // 导出的图片保留各图表自己的颜色与线宽，而不是统一的默认样式
+        let svg = std::fs::read_to_string(&path).unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("rgb(0, 255, 0)"), "{}", svg);
+        assert!(svg.contains("rgb(255, 0, 0)"), "{}", svg);
+        assert!(svg.contains("stroke-width=\"3\""), "{}", svg);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -4,18 +4,22 @@
 
 pub mod event;
 pub mod figure_window;
+pub mod headless;
 pub mod window;
 pub mod window_3d;
+pub mod window_manager;
 
 pub use event::*;
 pub use figure_window::FigureWindow;
+pub use headless::{render_mode, show_figure_or_export, RenderBackend, ShowOutcome};
 pub use window::VizuaraWindow;
 pub use window_3d::Window3D;
+pub use window_manager::{show_figures, WindowManager};
 
 use vizuara_scene::Figure;
 
 /// 显示图形的便捷函数
-pub fn show_figure(figure: Figure) -> Result<(), Box<dyn std::error::Error>> {
+pub fn show_figure(figure: Figure) -> vizuara_core::Result<()> {
     let window = FigureWindow::new("Vizuara".to_string(), 800, 600)?;
     window.show_figure(figure)?;
     Ok(())
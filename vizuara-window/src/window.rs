@@ -69,14 +69,14 @@ impl VizuaraWindow {
                                 control_flow.exit();
                             }
 
-                            WindowEvent::Resized(physical_size) => {
-                                if physical_size.width > 0 && physical_size.height > 0 {
-                                    println!(
-                                        "📏 调整窗口大小: {}x{}",
-                                        physical_size.width, physical_size.height
-                                    );
-                                    renderer.resize(physical_size, &surface);
-                                }
+                            WindowEvent::Resized(physical_size)
+                                if physical_size.width > 0 && physical_size.height > 0 =>
+                            {
+                                println!(
+                                    "📏 调整窗口大小: {}x{}",
+                                    physical_size.width, physical_size.height
+                                );
+                                renderer.resize(physical_size, &surface);
                             }
 
                             WindowEvent::RedrawRequested => {
@@ -1,16 +1,26 @@
-use nalgebra::Point3;
 use std::sync::Arc;
 use vizuara_3d::{Mesh3D, Scatter3D, Surface3D};
 use vizuara_core::{Color, Result, VizuaraError};
 use vizuara_wgpu::{Vertex3D, Wgpu3DRenderer};
 use winit::{
-    event::{DeviceEvent, ElementState, Event, MouseButton, WindowEvent},
+    event::{ElementState, Event, MouseButton, WindowEvent},
     event_loop::EventLoop,
     keyboard::{Key, NamedKey},
     window::WindowBuilder,
 };
 
+/// 左键拖拽旋转灵敏度（弧度/像素）
+const ROTATE_SENSITIVITY: f32 = 0.01;
+/// 右键/中键拖拽平移灵敏度（相机距离/像素）
+const PAN_SENSITIVITY: f32 = 0.002;
+/// 滚轮每格的缩放倍数
+const ZOOM_STEP: f32 = 1.1;
+/// 触控板像素滚动量折算为滚轮格数的比例
+const PIXELS_PER_SCROLL_LINE: f32 = 100.0;
+
 /// 3D可视化窗口应用
+///
+/// 默认交互：左键拖拽旋转，右键或中键拖拽平移，滚轮缩放，R 键重置相机，Esc 退出
 pub struct Window3D {
     scatter_data: Option<Scatter3D>,
     surface_data: Option<Surface3D>,
@@ -69,6 +79,9 @@ impl Window3D {
 
         // 初始化3D渲染器
         let (mut renderer, surface) = Wgpu3DRenderer::new(&window, size).await?;
+        if let Some(surface_data) = &self.surface_data {
+            renderer.set_floor_contours(&surface_data.floor_contour_lines());
+        }
 
         // 生成3D几何数据
         let (vertices, indices) = self.generate_3d_geometry();
@@ -78,7 +91,9 @@ impl Window3D {
             indices.len() / 3
         );
 
-        let mut mouse_pressed = false;
+        // 当前拖拽方式：左键旋转，右键或中键平移
+        let mut rotating = false;
+        let mut panning = false;
         let mut last_mouse_pos: Option<(f32, f32)> = None;
         let window_clone = window.clone();
 
@@ -102,60 +117,64 @@ impl Window3D {
                                 renderer.resize(*physical_size);
                                 surface.configure(&renderer.device, &renderer.config);
                             }
-                            WindowEvent::MouseInput {
-                                button: MouseButton::Left,
-                                state,
-                                ..
-                            } => {
-                                mouse_pressed = *state == ElementState::Pressed;
-                                if !mouse_pressed {
+                            WindowEvent::MouseInput { button, state, .. } => {
+                                let pressed = *state == ElementState::Pressed;
+                                match button {
+                                    MouseButton::Left => rotating = pressed,
+                                    MouseButton::Right | MouseButton::Middle => panning = pressed,
+                                    _ => {}
+                                }
+                                if !rotating && !panning {
                                     last_mouse_pos = None;
                                 }
                             }
-                            WindowEvent::CursorMoved { position, .. } => {
-                                if mouse_pressed {
-                                    if let Some((last_x, last_y)) = last_mouse_pos {
-                                        let delta_x = position.x as f32 - last_x;
-                                        let delta_y = position.y as f32 - last_y;
-                                        renderer.rotate_camera(delta_x, delta_y);
-                                        window_clone.request_redraw();
+                            WindowEvent::CursorMoved { position, .. } if rotating || panning => {
+                                let current = (position.x as f32, position.y as f32);
+                                if let Some((last_x, last_y)) = last_mouse_pos {
+                                    let delta_x = current.0 - last_x;
+                                    let delta_y = current.1 - last_y;
+                                    if rotating {
+                                        renderer.rotate_camera(
+                                            delta_x * ROTATE_SENSITIVITY,
+                                            delta_y * ROTATE_SENSITIVITY,
+                                        );
+                                    } else {
+                                        renderer.pan_camera(
+                                            delta_x * PAN_SENSITIVITY,
+                                            delta_y * PAN_SENSITIVITY,
+                                        );
                                     }
-                                    last_mouse_pos = Some((position.x as f32, position.y as f32));
+                                    window_clone.request_redraw();
                                 }
+                                last_mouse_pos = Some(current);
                             }
                             WindowEvent::MouseWheel { delta, .. } => {
-                                let scroll_delta = match delta {
+                                let scroll_lines = match delta {
                                     winit::event::MouseScrollDelta::LineDelta(_, y) => *y,
                                     winit::event::MouseScrollDelta::PixelDelta(pos) => {
-                                        pos.y as f32 * 0.01
+                                        pos.y as f32 / PIXELS_PER_SCROLL_LINE
                                     }
                                 };
-                                renderer.zoom_camera(-scroll_delta);
+                                // 向上滚动拉近
+                                renderer.zoom_camera(ZOOM_STEP.powf(-scroll_lines));
                                 window_clone.request_redraw();
                             }
-                            WindowEvent::KeyboardInput { event, .. } => {
-                                if event.state == ElementState::Pressed {
-                                    match event.logical_key {
-                                        Key::Named(NamedKey::Escape) => {
-                                            elwt.exit();
-                                        }
-                                        Key::Character(ref c) => {
-                                            match c.as_str() {
-                                                "r" | "R" => {
-                                                    // 重置相机
-                                                    renderer.set_camera(
-                                                        Point3::new(4.0, 3.0, 2.0),
-                                                        Point3::new(0.0, 0.0, 0.0),
-                                                        nalgebra::Vector3::new(0.0, 1.0, 0.0),
-                                                    );
-                                                    window_clone.request_redraw();
-                                                    println!("📷 相机已重置");
-                                                }
-                                                _ => {}
-                                            }
+                            WindowEvent::KeyboardInput { event, .. }
+                                if event.state == ElementState::Pressed =>
+                            {
+                                match event.logical_key {
+                                    Key::Named(NamedKey::Escape) => {
+                                        elwt.exit();
+                                    }
+                                    Key::Character(ref c) => match c.as_str() {
+                                        "r" | "R" => {
+                                            renderer.reset_camera();
+                                            window_clone.request_redraw();
+                                            println!("📷 相机已重置");
                                         }
                                         _ => {}
-                                    }
+                                    },
+                                    _ => {}
                                 }
                             }
                             WindowEvent::RedrawRequested => {
@@ -169,15 +188,6 @@ impl Window3D {
                             _ => {}
                         }
                     }
-                    Event::DeviceEvent {
-                        event: DeviceEvent::MouseMotion { delta },
-                        ..
-                    } => {
-                        if mouse_pressed {
-                            renderer.rotate_camera(delta.0 as f32, delta.1 as f32);
-                            window_clone.request_redraw();
-                        }
-                    }
                     Event::AboutToWait => {
                         window_clone.request_redraw();
                     }
@@ -190,19 +200,19 @@ impl Window3D {
     }
 
     /// 生成3D几何数据
-    fn generate_3d_geometry(&self) -> (Vec<Vertex3D>, Vec<u16>) {
+    fn generate_3d_geometry(&self) -> (Vec<Vertex3D>, Vec<u32>) {
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
 
         // 添加散点图数据
         if let Some(scatter) = &self.scatter_data {
             let scatter_vertices = self.generate_scatter_vertices(scatter);
-            let base_index = vertices.len() as u16;
+            let base_index = vertices.len() as u32;
             vertices.extend(scatter_vertices);
 
             // 为每个点生成小立方体
             for i in 0..scatter.point_count() {
-                let base = base_index + (i * 8) as u16;
+                let base = base_index + (i * 8) as u32;
                 // 立方体的12个三角形 (每个面2个三角形)
                 let cube_indices = [
                     // 前面
@@ -255,7 +265,7 @@ impl Window3D {
         // 添加表面数据
         if let Some(surface) = &self.surface_data {
             let (surface_vertices, surface_indices) = self.generate_surface_geometry(surface);
-            let base_index = vertices.len() as u16;
+            let base_index = vertices.len() as u32;
             vertices.extend(surface_vertices);
             indices.extend(surface_indices.iter().map(|&i| i + base_index));
         }
@@ -263,7 +273,7 @@ impl Window3D {
         // 添加网格数据
         if let Some(mesh) = &self.mesh_data {
             let (mesh_vertices, mesh_indices) = self.generate_mesh_geometry(mesh);
-            let base_index = vertices.len() as u16;
+            let base_index = vertices.len() as u32;
             vertices.extend(mesh_vertices);
             indices.extend(mesh_indices.iter().map(|&i| i + base_index));
         }
@@ -307,43 +317,19 @@ impl Window3D {
         vertices
     }
 
-    /// 生成表面几何数据
-    fn generate_surface_geometry(&self, surface: &Surface3D) -> (Vec<Vertex3D>, Vec<u16>) {
-        let mut vertices = Vec::new();
-        let mut indices = Vec::new();
-
-        let mesh = surface.mesh();
-        let width = mesh.width;
-        let height = mesh.height;
-
-        // 生成顶点
-        for y in 0..height {
-            for x in 0..width {
-                if let Some(point) = mesh.point_at(x, y) {
-                    let color = [0.3, 0.7, 1.0, 1.0]; // 蓝色表面
-                    vertices.push(Vertex3D::new([point.x, point.y, point.z], color));
-                }
-            }
-        }
-
-        // 生成三角形索引
-        for y in 0..height - 1 {
-            for x in 0..width - 1 {
-                let i0 = (y * width + x) as u16;
-                let i1 = (y * width + x + 1) as u16;
-                let i2 = ((y + 1) * width + x) as u16;
-                let i3 = ((y + 1) * width + x + 1) as u16;
-
-                // 两个三角形组成一个四边形
-                indices.extend_from_slice(&[i0, i1, i2, i1, i3, i2]);
-            }
-        }
+    /// 生成表面几何数据（顶点颜色按表面的颜色映射烘焙，空洞处不生成三角形）
+    fn generate_surface_geometry(&self, surface: &Surface3D) -> (Vec<Vertex3D>, Vec<u32>) {
+        let (surface_vertices, indices) = surface.triangle_mesh();
+        let vertices = surface_vertices
+            .iter()
+            .map(|v| Vertex3D::new(v.position, [v.color[0], v.color[1], v.color[2], 1.0]))
+            .collect();
 
         (vertices, indices)
     }
 
     /// 生成网格几何数据
-    fn generate_mesh_geometry(&self, mesh: &Mesh3D) -> (Vec<Vertex3D>, Vec<u16>) {
+    fn generate_mesh_geometry(&self, mesh: &Mesh3D) -> (Vec<Vertex3D>, Vec<u32>) {
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
 
@@ -357,9 +343,9 @@ impl Window3D {
         for i in 0..mesh.triangle_count() {
             if let Some(triangle) = mesh.triangle_at(i) {
                 indices.extend_from_slice(&[
-                    triangle.0 as u16,
-                    triangle.1 as u16,
-                    triangle.2 as u16,
+                    triangle.0 as u32,
+                    triangle.1 as u32,
+                    triangle.2 as u32,
                 ]);
             }
         }
@@ -368,7 +354,7 @@ impl Window3D {
     }
 
     /// 生成测试立方体
-    fn generate_test_cube(&self) -> (Vec<Vertex3D>, Vec<u16>) {
+    fn generate_test_cube(&self) -> (Vec<Vertex3D>, Vec<u32>) {
         let vertices = vec![
             // 前面 (红色)
             Vertex3D::new([-1.0, -1.0, 1.0], [1.0, 0.0, 0.0, 1.0]),
@@ -0,0 +1,262 @@
+//! 多窗口管理
+//!
+//! 在同一个事件循环中以独立的系统窗口显示多个 Figure，
+//! 每个窗口拥有自己的渲染器与表面，按 `WindowId` 分发事件，全部关闭后退出
+
+use crate::figure_window::{default_styles, report_screenshot, save_screenshot};
+use crate::FigureWindow;
+use std::collections::HashMap;
+use vizuara_core::{Primitive, Result, VizuaraError};
+use vizuara_scene::Figure;
+use vizuara_wgpu::WgpuRenderer;
+use winit::{
+    event::{ElementState, Event, WindowEvent},
+    event_loop::EventLoop,
+    keyboard::{KeyCode, PhysicalKey},
+    window::{Window, WindowBuilder, WindowId},
+};
+
+/// 待显示的窗口配置
+struct ManagedFigure {
+    id: String,
+    window: FigureWindow,
+    figure: Figure,
+}
+
+/// 事件循环中已打开的窗口
+struct OpenWindow<'a> {
+    id: &'a str,
+    window: &'a Window,
+    renderer: WgpuRenderer,
+    surface: wgpu::Surface<'a>,
+    primitives: Vec<Primitive>,
+}
+
+/// 多窗口管理器：每个 Figure 一个系统窗口，按 id 区分
+///
+/// 每个窗口的交互与 [`FigureWindow`] 相同：R 键刷新，S 键截图，Esc 关闭当前窗口
+#[derive(Default)]
+pub struct WindowManager {
+    figures: Vec<ManagedFigure>,
+}
+
+impl WindowManager {
+    /// 创建空的窗口管理器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加 Figure，窗口标题为 id、尺寸与 Figure 一致；id 已存在时替换
+    pub fn add_figure<S: Into<String>>(self, id: S, figure: Figure) -> Self {
+        let id = id.into();
+        let (width, height) = figure.size();
+        let window = FigureWindow {
+            title: id.clone(),
+            width: width as u32,
+            height: height as u32,
+        };
+        self.add_window(id, window, figure)
+    }
+
+    /// 使用指定的窗口配置添加 Figure；id 已存在时替换
+    pub fn add_window<S: Into<String>>(
+        mut self,
+        id: S,
+        window: FigureWindow,
+        figure: Figure,
+    ) -> Self {
+        let id = id.into();
+        let managed = ManagedFigure {
+            id: id.clone(),
+            window,
+            figure,
+        };
+        match self.figures.iter_mut().find(|f| f.id == id) {
+            Some(existing) => *existing = managed,
+            None => self.figures.push(managed),
+        }
+        self
+    }
+
+    /// 按添加顺序排列的窗口 id
+    pub fn ids(&self) -> Vec<&str> {
+        self.figures.iter().map(|f| f.id.as_str()).collect()
+    }
+
+    /// 窗口数量
+    pub fn len(&self) -> usize {
+        self.figures.len()
+    }
+
+    /// 是否没有任何窗口
+    pub fn is_empty(&self) -> bool {
+        self.figures.is_empty()
+    }
+
+    /// 打开所有窗口并运行事件循环，全部窗口关闭后返回
+    pub fn show(self) -> Result<()> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.show_async())
+    }
+
+    /// 异步打开所有窗口并运行事件循环
+    pub async fn show_async(self) -> Result<()> {
+        if self.figures.is_empty() {
+            return Ok(());
+        }
+
+        let event_loop = EventLoop::new().map_err(|e| {
+            VizuaraError::RenderError(format!("Failed to create event loop: {}", e))
+        })?;
+
+        // 窗口须比借用它的表面活得更久，因此先全部创建，事件循环结束后再释放
+        let windows = self
+            .figures
+            .iter()
+            .map(|managed| {
+                WindowBuilder::new()
+                    .with_title(managed.window.title.clone())
+                    .with_inner_size(winit::dpi::LogicalSize::new(
+                        managed.window.width,
+                        managed.window.height,
+                    ))
+                    .with_min_inner_size(winit::dpi::LogicalSize::new(400, 300))
+                    .build(&event_loop)
+                    .map_err(|e| {
+                        VizuaraError::RenderError(format!("Failed to create window: {}", e))
+                    })
+            })
+            .collect::<Result<Vec<Window>>>()?;
+
+        let mut open: HashMap<WindowId, OpenWindow> = HashMap::new();
+        for (managed, window) in self.figures.iter().zip(&windows) {
+            let (renderer, surface) = WgpuRenderer::new(window, window.inner_size()).await?;
+            open.insert(
+                window.id(),
+                OpenWindow {
+                    id: &managed.id,
+                    window,
+                    renderer,
+                    surface,
+                    primitives: managed.figure.generate_primitives(),
+                },
+            );
+        }
+        println!("✅ 已打开 {} 个窗口", open.len());
+
+        let styles = default_styles();
+
+        event_loop
+            .run(move |event, control_flow| match event {
+                Event::WindowEvent { event, window_id } => {
+                    let Some(target) = open.get_mut(&window_id) else {
+                        return;
+                    };
+
+                    let close = match event {
+                        WindowEvent::CloseRequested => true,
+
+                        WindowEvent::Resized(physical_size) => {
+                            if physical_size.width > 0 && physical_size.height > 0 {
+                                target.renderer.resize(physical_size, &target.surface);
+                                target.window.request_redraw();
+                            }
+                            false
+                        }
+
+                        WindowEvent::RedrawRequested => {
+                            if let Err(e) =
+                                target
+                                    .renderer
+                                    .render(&target.surface, &target.primitives, &styles)
+                            {
+                                eprintln!("❌ 窗口 {} 渲染错误: {}", target.id, e);
+                            } else if target.renderer.has_capture() {
+                                report_screenshot(save_screenshot(&mut target.renderer));
+                            }
+                            false
+                        }
+
+                        WindowEvent::KeyboardInput { event, .. }
+                            if event.state == ElementState::Pressed =>
+                        {
+                            match event.physical_key {
+                                PhysicalKey::Code(KeyCode::Escape) => true,
+                                PhysicalKey::Code(KeyCode::KeyR) => {
+                                    target.window.request_redraw();
+                                    false
+                                }
+                                PhysicalKey::Code(KeyCode::KeyS) => {
+                                    match target.renderer.request_capture() {
+                                        Ok(()) => target.window.request_redraw(),
+                                        Err(e) => eprintln!("❌ 截图失败: {}", e),
+                                    }
+                                    false
+                                }
+                                _ => false,
+                            }
+                        }
+
+                        _ => false,
+                    };
+
+                    if close {
+                        // 窗口对象在事件循环结束后才释放，先隐藏并释放其渲染资源
+                        target.window.set_visible(false);
+                        println!("🔴 关闭窗口 {}", target.id);
+                        open.remove(&window_id);
+                        if open.is_empty() {
+                            control_flow.exit();
+                        }
+                    }
+                }
+
+                Event::AboutToWait => {
+                    for target in open.values() {
+                        target.window.request_redraw();
+                    }
+                }
+
+                _ => {}
+            })
+            .map_err(|e| VizuaraError::RenderError(format!("Event loop error: {}", e)))
+    }
+}
+
+/// 便捷方法：每个 Figure 一个窗口同时显示，id 依次为 "Figure 1"、"Figure 2"……
+pub fn show_figures(figures: Vec<Figure>) -> Result<()> {
+    figures
+        .into_iter()
+        .enumerate()
+        .fold(WindowManager::new(), |manager, (i, figure)| {
+            manager.add_figure(format!("Figure {}", i + 1), figure)
+        })
+        .show()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_manager_keys_by_id() {
+        let manager = WindowManager::new()
+            .add_figure("left", Figure::new(640.0, 480.0))
+            .add_figure("right", Figure::new(800.0, 600.0))
+            .add_window(
+                "left",
+                FigureWindow::new("Left".to_string(), 400, 300).unwrap(),
+                Figure::new(400.0, 300.0),
+            );
+
+        // 重复的 id 替换原有窗口并保持顺序
+        assert_eq!(manager.ids(), vec!["left", "right"]);
+        assert_eq!(manager.len(), 2);
+        assert_eq!(manager.figures[0].window.title, "Left");
+        assert_eq!(manager.figures[1].window.width, 800);
+
+        // 没有窗口时直接返回，不创建事件循环
+        assert!(WindowManager::new().show().is_ok());
+    }
+}